@@ -5,6 +5,7 @@ use core::gas::withdraw_gas;
 use core::option::OptionTrait;
 use core::serde::Serde;
 use core::metaprogramming::TypeEqual;
+use core::iter::{IntoIterator, Iterator};
 
 #[derive(Drop)]
 pub extern type Array<T>;
@@ -268,3 +269,45 @@ impl SpanPartialEq<T, +PartialEq<T>> of PartialEq<Span<T>> {
         !(lhs == rhs)
     }
 }
+
+/// An iterator over the elements of an array, consuming it.
+#[derive(Drop)]
+pub struct ArrayIter<T> {
+    array: Array<T>,
+}
+
+impl ArrayIterator<T> of Iterator<ArrayIter<T>, T> {
+    #[inline(always)]
+    fn next(ref self: ArrayIter<T>) -> Option<T> {
+        self.array.pop_front()
+    }
+}
+
+impl ArrayIntoIterator<T> of IntoIterator<Array<T>, ArrayIter<T>> {
+    #[inline(always)]
+    fn into_iter(self: Array<T>) -> ArrayIter<T> {
+        ArrayIter { array: self }
+    }
+}
+
+/// An iterator over snapshots of the elements of a span.
+pub struct SpanIter<T> {
+    span: Span<T>,
+}
+
+impl SpanIterCopy<T> of Copy<SpanIter<T>>;
+impl SpanIterDrop<T> of Drop<SpanIter<T>>;
+
+impl SpanIterator<T> of Iterator<SpanIter<T>, @T> {
+    #[inline(always)]
+    fn next(ref self: SpanIter<T>) -> Option<@T> {
+        self.span.pop_front()
+    }
+}
+
+impl SpanIntoIterator<T> of IntoIterator<Span<T>, SpanIter<T>> {
+    #[inline(always)]
+    fn into_iter(self: Span<T>) -> SpanIter<T> {
+        SpanIter { span: self }
+    }
+}
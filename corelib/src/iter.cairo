@@ -0,0 +1,38 @@
+use core::array::{ArrayTrait, SpanIter};
+
+/// An iterator over a sequence of values of type `Item`.
+///
+/// Iterators are used by `for` loops, which call `next` until it returns `Option::None`.
+pub trait Iterator<T, Item> {
+    /// Advances the iterator and returns the next value, or `Option::None` when the iteration is
+    /// finished.
+    fn next(ref self: T) -> Option<Item>;
+}
+
+/// Conversion of a value into an [Iterator].
+///
+/// `for pattern in expr { ... }` iterates over `IntoIterator::into_iter(expr)`.
+pub trait IntoIterator<T, IntoIter> {
+    /// Creates an iterator from a value.
+    fn into_iter(self: T) -> IntoIter;
+}
+
+/// Every iterator may be iterated over directly.
+impl IteratorIntoIterator<T, Item, +Iterator<T, Item>> of IntoIterator<T, T> {
+    #[inline(always)]
+    fn into_iter(self: T) -> T {
+        self
+    }
+}
+
+/// Iterating over a snapshot of an array iterates over snapshots of its elements.
+///
+/// Impls are looked up at the defining modules of the trait and of the types it is implemented
+/// for, and a snapshot type has no defining module, so this impl is defined next to the trait
+/// rather than next to `Array`.
+impl ArraySnapshotIntoIterator<T> of IntoIterator<@Array<T>, SpanIter<T>> {
+    #[inline(always)]
+    fn into_iter(self: @Array<T>) -> SpanIter<T> {
+        self.span().into_iter()
+    }
+}
@@ -230,6 +230,10 @@ use array::{Array, ArrayTrait};
 // Span.
 use array::{Span, SpanTrait};
 
+// Iterators.
+pub mod iter;
+use iter::{IntoIterator, Iterator};
+
 // Dictionary.
 pub mod dict;
 use dict::{
@@ -273,6 +277,10 @@ pub mod num;
 // Cmp.
 pub mod cmp;
 
+// Ops.
+pub mod ops;
+use ops::{Fn, FnOnce, RangeInclusiveOp, RangeOp};
+
 // Gas.
 pub mod gas;
 use gas::{BuiltinCosts, GasBuiltin, get_builtin_costs};
@@ -0,0 +1,6 @@
+pub mod function;
+pub use function::{Fn, FnOnce};
+pub mod range;
+pub use range::{
+    Range, RangeInclusive, RangeInclusiveIterator, RangeInclusiveOp, RangeIterator, RangeOp
+};
@@ -0,0 +1,17 @@
+/// The version of the call operator that takes a by-value receiver.
+///
+/// Every closure implements `FnOnce`. `f(a, b)` for a value `f` of a type implementing only
+/// `FnOnce` is a call to `FnOnce::call(f, (a, b))`, which consumes `f`.
+pub trait FnOnce<T, Args, R> {
+    /// Performs the call operation.
+    fn call(self: T, args: Args) -> R;
+}
+
+/// The version of the call operator that takes a by-snapshot receiver.
+///
+/// Closures implement `Fn` if all their captured variables are copyable. `f(a, b)` for a value `f`
+/// of a type implementing `Fn` is a call to `Fn::call(@f, (a, b))`, so `f` may be called again.
+pub trait Fn<T, Args, R> {
+    /// Performs the call operation.
+    fn call(self: @T, args: Args) -> R;
+}
@@ -0,0 +1,138 @@
+use core::iter::{IntoIterator, Iterator};
+use core::num::traits::One;
+use core::traits::IndexView;
+
+/// The range `start..end`: all the values `x` such that `start <= x < end`.
+#[derive(Clone, Drop, PartialEq)]
+pub struct Range<T> {
+    /// The lower bound of the range (inclusive).
+    pub start: T,
+    /// The upper bound of the range (exclusive).
+    pub end: T,
+}
+
+/// An iterator over the values of a [Range], in increasing order.
+#[derive(Clone, Drop)]
+pub struct RangeIterator<T> {
+    cur: T,
+    end: T,
+}
+
+/// Handles the range operator (`..`).
+pub trait RangeOp<T> {
+    /// Handles the `..` operator. Returns the value of the expression `start..end`.
+    fn range(start: T, end: T) -> Range<T>;
+}
+
+impl RangeOpImpl<T> of RangeOp<T> {
+    fn range(start: T, end: T) -> Range<T> {
+        Range { start, end }
+    }
+}
+
+impl RangeIteratorImpl<
+    T, +One<T>, +Add<T>, +Copy<T>, +Drop<T>, +PartialOrd<T>
+> of Iterator<RangeIterator<T>, T> {
+    fn next(ref self: RangeIterator<T>) -> Option<T> {
+        if self.cur < self.end {
+            let value = self.cur;
+            self.cur = value + One::one();
+            Option::Some(value)
+        } else {
+            Option::None
+        }
+    }
+}
+
+/// Returns the sub-span of the elements in the range `start..end`.
+/// Panics if `end < start` or if the range is out of bounds.
+impl SpanIndexRange<T> of IndexView<Span<T>, Range<usize>, Span<T>> {
+    #[inline(always)]
+    fn index(self: @Span<T>, index: Range<usize>) -> Span<T> {
+        (*self).slice(index.start, index.end - index.start)
+    }
+}
+
+impl ArrayIndexRange<T> of IndexView<Array<T>, Range<usize>, Span<T>> {
+    #[inline(always)]
+    fn index(self: @Array<T>, index: Range<usize>) -> Span<T> {
+        self.span()[index]
+    }
+}
+
+impl RangeIntoIterator<T> of IntoIterator<Range<T>, RangeIterator<T>> {
+    #[inline(always)]
+    fn into_iter(self: Range<T>) -> RangeIterator<T> {
+        RangeIterator { cur: self.start, end: self.end }
+    }
+}
+
+/// The range `start..=end`: all the values `x` such that `start <= x <= end`.
+#[derive(Clone, Drop, PartialEq)]
+pub struct RangeInclusive<T> {
+    /// The lower bound of the range (inclusive).
+    pub start: T,
+    /// The upper bound of the range (inclusive).
+    pub end: T,
+}
+
+/// An iterator over the values of a [RangeInclusive], in increasing order.
+#[derive(Clone, Drop)]
+pub struct RangeInclusiveIterator<T> {
+    cur: T,
+    end: T,
+    /// Whether `end` was already yielded. Avoids computing `end + 1`, which may overflow.
+    exhausted: bool,
+}
+
+/// Handles the inclusive range operator (`..=`).
+pub trait RangeInclusiveOp<T> {
+    /// Handles the `..=` operator. Returns the value of the expression `start..=end`.
+    fn range_inclusive(start: T, end: T) -> RangeInclusive<T>;
+}
+
+impl RangeInclusiveOpImpl<T> of RangeInclusiveOp<T> {
+    fn range_inclusive(start: T, end: T) -> RangeInclusive<T> {
+        RangeInclusive { start, end }
+    }
+}
+
+impl RangeInclusiveIteratorImpl<
+    T, +One<T>, +Add<T>, +Copy<T>, +Drop<T>, +PartialOrd<T>
+> of Iterator<RangeInclusiveIterator<T>, T> {
+    fn next(ref self: RangeInclusiveIterator<T>) -> Option<T> {
+        if self.exhausted || self.end < self.cur {
+            return Option::None;
+        }
+        let value = self.cur;
+        if value < self.end {
+            self.cur = value + One::one();
+        } else {
+            self.exhausted = true;
+        }
+        Option::Some(value)
+    }
+}
+
+impl RangeInclusiveIntoIterator<T> of IntoIterator<RangeInclusive<T>, RangeInclusiveIterator<T>> {
+    #[inline(always)]
+    fn into_iter(self: RangeInclusive<T>) -> RangeInclusiveIterator<T> {
+        RangeInclusiveIterator { cur: self.start, end: self.end, exhausted: false }
+    }
+}
+
+/// Returns the sub-span of the elements in the range `start..=end`.
+/// Panics if `end < start` or if the range is out of bounds.
+impl SpanIndexRangeInclusive<T> of IndexView<Span<T>, RangeInclusive<usize>, Span<T>> {
+    #[inline(always)]
+    fn index(self: @Span<T>, index: RangeInclusive<usize>) -> Span<T> {
+        (*self).slice(index.start, index.end + 1 - index.start)
+    }
+}
+
+impl ArrayIndexRangeInclusive<T> of IndexView<Array<T>, RangeInclusive<usize>, Span<T>> {
+    #[inline(always)]
+    fn index(self: @Array<T>, index: RangeInclusive<usize>) -> Span<T> {
+        self.span()[index]
+    }
+}
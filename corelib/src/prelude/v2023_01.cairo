@@ -43,9 +43,11 @@ use core::{
     }
 };
 use core::internal;
+use core::{iter, iter::{IntoIterator, Iterator}};
 use core::keccak;
 use core::math;
 use core::{nullable, nullable::{Nullable, NullableTrait, match_nullable, null, nullable_from_box}};
+use core::{ops, ops::{Fn, FnOnce}};
 use core::{option, option::{Option, OptionTrait}};
 use core::{panics, panics::{Panic, PanicResult, panic}};
 use core::{pedersen, pedersen::Pedersen};
@@ -1,3 +1,10 @@
+use core::hash::{HashStateTrait, HashStateExTrait, Hash};
+use core::poseidon::{PoseidonTrait, HashState as PoseidonHashState};
+use starknet::storage_access::{
+    StorageBaseAddress, storage_base_address_from_felt252, storage_address_from_base,
+    storage_address_to_felt252
+};
+
 /// Trait for getting the address of any contract/component storage member.
 pub trait StorageMemberAddressTrait<TMemberState, TValue> {
     fn address(self: @TMemberState) -> starknet::StorageBaseAddress nopanic;
@@ -69,3 +76,148 @@ pub impl StorageMapMemberAccessImpl<
         )
     }
 }
+
+/// Returns the base address of the value of `key` in a `Map` storage member at `address`.
+///
+/// The address is the Poseidon hash of `address` followed by the hashed key, so the value of `key1`
+/// in the inner map of `key0` in a `Map<K0, Map<K1, V>>` is at the address of the key
+/// `(key0, key1)`.
+pub fn storage_map_entry_address<TKey, +Hash<TKey, PoseidonHashState>, +Drop<TKey>>(
+    address: felt252, key: TKey
+) -> StorageBaseAddress {
+    let state = PoseidonTrait::new().update(address).update_with(key);
+    storage_base_address_from_felt252(state.finalize())
+}
+
+/// Trait for getting the address of any contract/component vector storage member.
+pub trait StorageVecMemberAddressTrait<TMemberState, TValue> {
+    /// Returns the address of the length of the vector. The elements of the vector are stored as
+    /// the values of a `Map<u64, TValue>` at this address.
+    fn address(self: @TMemberState) -> StorageBaseAddress nopanic;
+}
+
+/// Trait for accessing any contract/component vector storage member.
+pub trait StorageVecMemberAccessTrait<TMemberState, TValue> {
+    /// Returns the number of elements in the vector.
+    fn len(self: @TMemberState) -> u64;
+    /// Returns the element at `index`. Panics if `index` is out of bounds.
+    fn read(self: @TMemberState, index: u64) -> TValue;
+    /// Replaces the element at `index`. Panics if `index` is out of bounds.
+    fn write(ref self: TMemberState, index: u64, value: TValue);
+    /// Adds an element to the end of the vector.
+    fn append(ref self: TMemberState, value: TValue);
+}
+
+/// Implementation of StorageVecMemberAccessTrait for types that implement
+/// StorageVecMemberAddressTrait.
+pub impl StorageVecMemberAccessImpl<
+    TMemberState,
+    TValue,
+    +StorageVecMemberAddressTrait<TMemberState, TValue>,
+    +starknet::Store<TValue>,
+    +Drop<TMemberState>,
+    +Destruct<TValue>,
+> of StorageVecMemberAccessTrait<TMemberState, TValue> {
+    fn len(self: @TMemberState) -> u64 {
+        vec_len(self.address())
+    }
+    fn read(self: @TMemberState, index: u64) -> TValue {
+        let address = self.address();
+        assert(index < vec_len(address), 'Index out of bounds');
+        // Only address_domain 0 is currently supported.
+        let address_domain = 0_u32;
+        starknet::SyscallResultTrait::unwrap_syscall(
+            starknet::Store::<TValue>::read(address_domain, vec_element_address(address, index))
+        )
+    }
+    fn write(ref self: TMemberState, index: u64, value: TValue) {
+        let address = self.address();
+        assert(index < vec_len(address), 'Index out of bounds');
+        // Only address_domain 0 is currently supported.
+        let address_domain = 0_u32;
+        let element_address = vec_element_address(address, index);
+        starknet::SyscallResultTrait::unwrap_syscall(
+            starknet::Store::<TValue>::write(address_domain, element_address, value)
+        )
+    }
+    fn append(ref self: TMemberState, value: TValue) {
+        let address = self.address();
+        let len = vec_len(address);
+        // Only address_domain 0 is currently supported.
+        let address_domain = 0_u32;
+        let element_address = vec_element_address(address, len);
+        starknet::SyscallResultTrait::unwrap_syscall(
+            starknet::Store::<TValue>::write(address_domain, element_address, value)
+        );
+        starknet::SyscallResultTrait::unwrap_syscall(
+            starknet::Store::<u64>::write(address_domain, address, len + 1)
+        )
+    }
+}
+
+/// Returns the length of a vector storage member at `address`.
+fn vec_len(address: StorageBaseAddress) -> u64 {
+    // Only address_domain 0 is currently supported.
+    let address_domain = 0_u32;
+    starknet::SyscallResultTrait::unwrap_syscall(
+        starknet::Store::<u64>::read(address_domain, address)
+    )
+}
+
+/// Returns the base address of the element at `index` of a vector storage member at `address`.
+fn vec_element_address(address: StorageBaseAddress, index: u64) -> StorageBaseAddress {
+    storage_map_entry_address(storage_address_to_felt252(storage_address_from_base(address)), index)
+}
+
+/// Trait for a storage node - a struct with a `StorageNodeMember` for each member of a stored
+/// struct, so that each member can be accessed without accessing the others. Implemented by
+/// `#[derive(starknet::Store)]` for structs annotated with `#[storage_node]`.
+pub trait StorageNodeTrait<TNode> {
+    /// Returns the storage node of a struct stored at `address`.
+    fn new(address: StorageBaseAddress) -> TNode;
+    /// Returns the offset of the member at `index` from the address of the stored struct.
+    fn member_offset(index: u8) -> u8;
+}
+
+/// Trait for a struct with a storage node, giving the type of its node. Implemented by
+/// `#[derive(starknet::Store)]` for structs annotated with `#[storage_node]`.
+pub trait HasStorageNode<T> {
+    /// The storage node of the struct.
+    type Node;
+}
+
+/// The member at `index` of a struct stored at `address`, accessed through its storage node.
+pub struct StorageNodeMember<TNode, TValue> {
+    pub address: StorageBaseAddress,
+    pub index: u8,
+}
+impl StorageNodeMemberCopy<TNode, TValue> of Copy<StorageNodeMember<TNode, TValue>>;
+impl StorageNodeMemberDrop<TNode, TValue> of Drop<StorageNodeMember<TNode, TValue>>;
+
+/// Trait for accessing a member of a struct through its storage node.
+pub trait StorageNodeMemberAccessTrait<TNode, TValue> {
+    fn read(self: @StorageNodeMember<TNode, TValue>) -> TValue;
+    fn write(ref self: StorageNodeMember<TNode, TValue>, value: TValue);
+}
+
+/// Implementation of StorageNodeMemberAccessTrait for `Store` implementing types.
+pub impl StorageNodeMemberAccessImpl<
+    TNode, TValue, +StorageNodeTrait<TNode>, +starknet::Store<TValue>, +Drop<TValue>,
+> of StorageNodeMemberAccessTrait<TNode, TValue> {
+    fn read(self: @StorageNodeMember<TNode, TValue>) -> TValue {
+        // Only address_domain 0 is currently supported.
+        let address_domain = 0_u32;
+        let offset = StorageNodeTrait::<TNode>::member_offset(*self.index);
+        starknet::SyscallResultTrait::unwrap_syscall(
+            starknet::Store::<TValue>::read_at_offset(address_domain, *self.address, offset)
+        )
+    }
+    fn write(ref self: StorageNodeMember<TNode, TValue>, value: TValue) {
+        // Only address_domain 0 is currently supported.
+        let address_domain = 0_u32;
+        let offset = StorageNodeTrait::<TNode>::member_offset(self.index);
+        starknet::SyscallResultTrait::unwrap_syscall(
+            starknet::Store::<TValue>::write_at_offset(address_domain, self.address, offset, value)
+        )
+    }
+}
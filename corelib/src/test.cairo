@@ -1,8 +1,10 @@
 mod array_test;
+mod associated_items_test;
 mod bool_test;
 mod box_test;
 mod byte_array_test;
 mod bytes31_test;
+mod closure_test;
 mod cmp_test;
 mod dict_test;
 mod ec_test;
@@ -10,7 +12,9 @@ mod felt_test;
 mod fmt_test;
 mod hash_test;
 mod integer_test;
+mod iter_test;
 mod keccak_test;
+mod let_else_test;
 mod num_test;
 mod math_test;
 mod nullable_test;
@@ -20,6 +24,7 @@ mod print_test;
 mod result_test;
 mod secp256k1_test;
 mod secp256r1_test;
+mod struct_test;
 mod test_utils;
 mod testing_test;
 mod to_byte_array_test;
@@ -144,3 +144,31 @@ fn test_tuple_span_from_tuple() {
     assert!(*span[2] == (70, 80, 90));
 }
 
+
+#[test]
+fn test_span_index_range() {
+    let span = array![10_felt252, 11, 12, 13, 14, 15].span();
+    let sub = span[2..5];
+    assert_eq(@sub.len(), @3, 'sub.len() != 3');
+    assert_eq(sub[0], @12, 'sub[0] != 12');
+    assert_eq(sub[2], @14, 'sub[2] != 14');
+    assert_eq(@span[1..=1].len(), @1, 'len != 1');
+    assert_eq(@span[3..3].len(), @0, 'len != 0');
+}
+
+#[test]
+fn test_array_index_range() {
+    let arr = array![10_felt252, 11, 12, 13];
+    let sub = arr[1..=3];
+    assert_eq(@sub.len(), @3, 'sub.len() != 3');
+    assert_eq(sub[0], @11, 'sub[0] != 11');
+    assert_eq(sub[2], @13, 'sub[2] != 13');
+    assert_eq(arr[0], @10, 'arr[0] != 10');
+}
+
+#[test]
+#[should_panic]
+fn test_span_index_range_out_of_bounds() {
+    let span = array![10_felt252, 11, 12].span();
+    span[1..4];
+}
@@ -0,0 +1,73 @@
+use core::test::test_utils::assert_eq;
+
+trait Hasher<T> {
+    type State;
+    const SIZE: usize;
+    fn init(value: T) -> Self::State;
+    fn finalize(state: Self::State) -> felt252;
+}
+
+impl FeltHasher of Hasher<felt252> {
+    type State = felt252;
+    const SIZE: usize = 1;
+    fn init(value: felt252) -> felt252 {
+        value * 2
+    }
+    fn finalize(state: felt252) -> felt252 {
+        state + 1
+    }
+}
+
+impl U8Hasher of Hasher<u8> {
+    type State = u32;
+    const SIZE: usize = 4;
+    fn init(value: u8) -> u32 {
+        value.into() * Self::SIZE
+    }
+    fn finalize(state: u32) -> felt252 {
+        state.into()
+    }
+}
+
+fn hash<T, impl H: Hasher<T>, +Drop<H::State>>(value: T) -> felt252 {
+    let state: H::State = H::init(value);
+    H::finalize(state)
+}
+
+fn size<T, impl H: Hasher<T>>() -> usize {
+    H::SIZE
+}
+
+#[test]
+fn test_associated_types() {
+    assert_eq(@hash(5_felt252), @11, 'felt252 hash');
+    assert_eq(@hash(3_u8), @12, 'u8 hash');
+    let state: u32 = U8Hasher::init(2);
+    assert_eq(@state, @8, 'u8 state');
+}
+
+#[test]
+fn test_associated_constants() {
+    assert_eq(@FeltHasher::SIZE, @1, 'FeltHasher::SIZE');
+    assert_eq(@U8Hasher::SIZE, @4, 'U8Hasher::SIZE');
+    assert_eq(@size::<felt252>(), @1, 'size::<felt252>');
+    assert_eq(@size::<u8>(), @4, 'size::<u8>');
+}
+
+trait Limit<T> {
+    const MAX: u256;
+}
+
+impl U128Limit of Limit<u128> {
+    const MAX: u256 = 0x100000000000000000000000000000000;
+}
+
+fn limit_high<T, impl L: Limit<T>>() -> u128 {
+    L::MAX.high
+}
+
+#[test]
+fn test_associated_u256_constant() {
+    assert_eq(@U128Limit::MAX.low, @0, 'MAX.low');
+    assert_eq(@limit_high::<u128>(), @1, 'MAX.high');
+}
@@ -0,0 +1,100 @@
+use core::test::test_utils::assert_eq;
+
+#[test]
+fn test_closure_no_captures() {
+    let add = |a: u32, b: u32| a + b;
+    assert_eq(@add(1, 2), @3, 'add(1, 2) != 3');
+    assert_eq(@add(3, 4), @7, 'add(3, 4) != 7');
+}
+
+#[test]
+fn test_closure_inferred_params() {
+    let double = |x| x * 2_u64;
+    assert_eq(@double(21), @42, 'double(21) != 42');
+}
+
+#[test]
+fn test_closure_captures() {
+    let offset = 10_u32;
+    let scale = 3_u32;
+    let f = |x: u32| -> u32 {
+        x * scale + offset
+    };
+    assert_eq(@f(1), @13, 'f(1) != 13');
+    assert_eq(@f(2), @16, 'f(2) != 16');
+    // Copyable captured variables are still usable after capture.
+    assert_eq(@offset, @10, 'offset != 10');
+}
+
+#[test]
+fn test_closure_captures_non_copyable() {
+    let arr = array![1_u32, 2, 3];
+    let len_plus = |x: u32| arr.len() + x;
+    assert_eq(@len_plus(1), @4, 'len_plus(1) != 4');
+}
+
+#[test]
+fn test_closure_nullary() {
+    let value = 5_u8;
+    let get = || value;
+    assert_eq(@get(), @5, 'get() != 5');
+}
+
+fn apply<F, +Fn<F, (u32,), u32>, +Drop<F>>(f: F, x: u32) -> u32 {
+    f(f(x))
+}
+
+#[test]
+fn test_closure_generic_fn() {
+    let step = 7_u32;
+    assert_eq(@apply(|x| x + step, 1), @15, 'apply != 15');
+}
+
+fn apply_once<F, +FnOnce<F, (), Array<u32>>>(f: F) -> Array<u32> {
+    f()
+}
+
+#[test]
+fn test_closure_fn_once() {
+    let arr = array![1_u32, 2];
+    let result = apply_once(|| arr);
+    assert_eq(@result.len(), @2, 'len != 2');
+}
+
+fn map<F, +Fn<F, (u32,), u32>, +Drop<F>>(span: Span<u32>, f: F) -> Array<u32> {
+    let mut result = array![];
+    for x in span {
+        result.append(f(*x));
+    };
+    result
+}
+
+fn fold<F, +Fn<F, (u32, u32), u32>, +Drop<F>>(span: Span<u32>, init: u32, f: F) -> u32 {
+    let mut acc = init;
+    for x in span {
+        acc = f(acc, *x);
+    };
+    acc
+}
+
+#[test]
+fn test_closure_map_fold() {
+    let factor = 2_u32;
+    let doubled = map(array![1, 2, 3].span(), |x| x * factor);
+    assert_eq(@doubled, @array![2, 4, 6], 'wrong map result');
+    assert_eq(@fold(doubled.span(), 0, |acc, x| acc + x), @12, 'wrong fold result');
+}
+
+#[test]
+fn test_closure_with_loop() {
+    let sum_to = |n: u32| {
+        let mut sum = 0;
+        let mut i = 0;
+        while i <= n {
+            sum += i;
+            i += 1;
+        };
+        sum
+    };
+    assert_eq(@sum_to(4), @10, 'sum_to(4) != 10');
+}
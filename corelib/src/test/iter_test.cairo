@@ -0,0 +1,117 @@
+use core::ops::Range;
+use core::test::test_utils::assert_eq;
+
+#[test]
+fn test_for_array() {
+    let mut sum = 0;
+    for x in array![1, 2, 3] {
+        sum += x;
+    };
+    assert_eq(@sum, @6, 'sum != 6');
+}
+
+#[test]
+fn test_for_array_snapshot() {
+    let arr = array![1_u32, 2, 3];
+    let mut sum = 0;
+    for x in @arr {
+        sum += *x;
+    };
+    assert_eq(@sum, @6, 'sum != 6');
+    assert_eq(@arr.len(), @3, 'array len != 3');
+}
+
+#[test]
+fn test_for_span() {
+    let mut count = 0_u8;
+    for _ in array![10, 11, 12, 13].span() {
+        count += 1;
+    };
+    assert_eq(@count, @4, 'count != 4');
+}
+
+#[test]
+fn test_for_range() {
+    let mut sum = 0_u64;
+    for i in (Range { start: 2_u64, end: 5 }) {
+        sum += i;
+    };
+    assert_eq(@sum, @9, 'sum != 9');
+}
+
+#[test]
+fn test_for_empty_range() {
+    let mut count = 0_u8;
+    for _ in (Range { start: 5_u8, end: 5 }) {
+        count += 1;
+    };
+    assert_eq(@count, @0, 'count != 0');
+}
+
+#[test]
+fn test_for_break() {
+    let mut last = 0_u32;
+    for i in (Range { start: 0_u32, end: 10 }) {
+        if i == 4 {
+            break;
+        }
+        last = i;
+    };
+    assert_eq(@last, @3, 'last != 3');
+}
+
+#[test]
+fn test_for_tuple_pattern() {
+    let mut sum = 0;
+    for (a, b) in array![(1, 2), (3, 4)] {
+        sum += a * b;
+    };
+    assert_eq(@sum, @14, 'sum != 14');
+}
+
+#[test]
+fn test_for_iterator() {
+    let mut iter = array![1, 2, 3].into_iter();
+    let _ = iter.next();
+    let mut sum = 0;
+    for x in iter {
+        sum += x;
+    };
+    assert_eq(@sum, @5, 'sum != 5');
+}
+
+#[test]
+fn test_for_range_expr() {
+    let mut sum = 0_u32;
+    for i in 2..5_u32 {
+        sum += i;
+    };
+    assert_eq(@sum, @9, 'sum != 9');
+}
+
+#[test]
+fn test_for_range_inclusive_expr() {
+    let mut sum = 0_u32;
+    for i in 2..=5_u32 {
+        sum += i;
+    };
+    assert_eq(@sum, @14, 'sum != 14');
+}
+
+#[test]
+fn test_for_range_inclusive_max() {
+    let mut count = 0_u16;
+    for _ in 250..=255_u8 {
+        count += 1;
+    };
+    assert_eq(@count, @6, 'count != 6');
+}
+
+#[test]
+fn test_for_empty_range_inclusive() {
+    let mut count = 0_u8;
+    for _ in 5..=4_u8 {
+        count += 1;
+    };
+    assert_eq(@count, @0, 'count != 0');
+}
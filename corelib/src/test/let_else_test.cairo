@@ -0,0 +1,79 @@
+use core::test::test_utils::assert_eq;
+
+fn unwrap_or_zero(x: Option<u32>) -> u32 {
+    let Option::Some(value) = x else {
+        return 0;
+    };
+    value
+}
+
+#[test]
+fn test_let_else_option() {
+    assert_eq(@unwrap_or_zero(Option::Some(5)), @5, 'Some(5) != 5');
+    assert_eq(@unwrap_or_zero(Option::None), @0, 'None != 0');
+}
+
+fn sum_ok(a: Result<u32, felt252>, b: Result<u32, felt252>) -> Result<u32, felt252> {
+    let Result::Ok(a) = a else {
+        return Result::Err('bad a');
+    };
+    let Result::Ok(b) = b else {
+        return Result::Err('bad b');
+    };
+    Result::Ok(a + b)
+}
+
+#[test]
+fn test_let_else_result() {
+    assert_eq(@sum_ok(Result::Ok(1), Result::Ok(2)), @Result::Ok(3), 'ok + ok');
+    assert_eq(@sum_ok(Result::Err(0), Result::Ok(2)), @Result::Err('bad a'), 'err + ok');
+    assert_eq(@sum_ok(Result::Ok(1), Result::Err(0)), @Result::Err('bad b'), 'ok + err');
+}
+
+fn both(x: (Option<u32>, Option<felt252>)) -> Option<(u32, felt252)> {
+    let (Option::Some(a), Option::Some(b)) = x else {
+        return Option::None;
+    };
+    Option::Some((a, b))
+}
+
+#[test]
+fn test_let_else_tuple() {
+    assert_eq(@both((Option::Some(1), Option::Some(2))), @Option::Some((1, 2)), 'some, some');
+    assert!(both((Option::Some(1), Option::None)).is_none());
+    assert!(both((Option::None, Option::Some(2))).is_none());
+}
+
+#[test]
+fn test_let_else_snapshot() {
+    let arr = array![1_u32, 2, 3];
+    let Option::Some(first) = arr.get(0) else {
+        core::panic_with_felt252('empty')
+    };
+    assert_eq(first.unbox(), @1, 'first != 1');
+}
+
+#[test]
+fn test_let_else_in_loop() {
+    let mut values = array![Option::Some(1_u32), Option::None, Option::Some(3)];
+    let mut sum = 0;
+    loop {
+        let Option::Some(value) = values.pop_front() else {
+            break;
+        };
+        let Option::Some(value) = value else {
+            continue;
+        };
+        sum += value;
+    };
+    assert_eq(@sum, @4, 'sum != 4');
+}
+
+#[test]
+#[should_panic(expected: ('no value',))]
+fn test_let_else_panic() {
+    let x: Option<u32> = Option::None;
+    let Option::Some(_) = x else {
+        core::panic_with_felt252('no value')
+    };
+}
@@ -0,0 +1,61 @@
+use core::hash::{HashStateTrait, HashStateExTrait};
+use core::poseidon::PoseidonTrait;
+
+#[derive(Copy, Drop, Debug, Default, PartialEq, Serde, Hash)]
+struct Wei(u256, u8);
+
+#[derive(Clone, Drop, Debug, Default, PartialEq, Serde, Hash)]
+struct Marker;
+
+#[derive(Drop, Debug, PartialEq)]
+struct Wrapper<T>(T);
+
+fn value_of(w: Wei) -> u256 {
+    let Wei(value, _) = w;
+    value
+}
+
+#[test]
+fn test_tuple_struct_ctor_and_access() {
+    let w = Wei(5, 2);
+    assert_eq!(w.0, 5);
+    assert_eq!(w.1, 2);
+    assert_eq!(value_of(w), 5);
+    let Wrapper(inner) = Wrapper(w);
+    assert_eq!(inner, w);
+}
+
+#[test]
+fn test_unit_struct() {
+    let m = Marker;
+    let Marker{} = m.clone();
+    assert_eq!(m, Marker {});
+    assert_eq!(m, Default::default());
+}
+
+#[test]
+fn test_derives() {
+    let w = Wei(7, 1);
+    assert_eq!(w, w.clone());
+    assert_ne!(w, Wei(7, 2));
+    assert_eq!(Default::default(), Wei(0, 0));
+    assert_eq!(format!("{:?}", w), "Wei(7, 1)");
+    assert_eq!(format!("{:?}", Marker), "Marker");
+
+    let mut serialized = array![];
+    w.serialize(ref serialized);
+    Marker.serialize(ref serialized);
+    assert_eq!(serialized, array![7, 0, 1]);
+    let mut span = serialized.span();
+    assert_eq!(Serde::deserialize(ref span), Option::Some(w));
+    assert_eq!(Serde::deserialize(ref span), Option::Some(Marker));
+    assert!(span.is_empty());
+
+    assert_eq!(
+        PoseidonTrait::new().update_with(w).finalize(),
+        PoseidonTrait::new().update_with(7_u256).update_with(1_u8).finalize()
+    );
+    assert_eq!(
+        PoseidonTrait::new().update_with(Marker).finalize(), PoseidonTrait::new().finalize()
+    );
+}
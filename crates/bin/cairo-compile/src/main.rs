@@ -2,7 +2,9 @@ use std::fs;
 use std::path::PathBuf;
 
 use anyhow::Context;
-use cairo_lang_compiler::project::check_compiler_path;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::diagnostics_format::DiagnosticsFormat;
+use cairo_lang_compiler::project::{check_compiler_path, lint_levels_from_flags};
 use cairo_lang_compiler::{compile_cairo_project_at_path, CompilerConfig};
 use cairo_lang_utils::logging::init_logging;
 use clap::Parser;
@@ -22,6 +24,21 @@ struct Args {
     /// Replaces sierra ids with human-readable ones.
     #[arg(short, long, default_value_t = false)]
     replace_ids: bool,
+    /// The format of the reported diagnostics: `human`, `json` or `sarif`.
+    #[arg(long, default_value_t)]
+    diagnostics_format: DiagnosticsFormat,
+    /// Sets the level of a lint (or of the `warnings` lint group) to allow.
+    #[arg(short = 'A', long = "allow", value_name = "LINT")]
+    allow_lints: Vec<String>,
+    /// Sets the level of a lint (or of the `warnings` lint group) to warn.
+    #[arg(short = 'W', long = "warn", value_name = "LINT")]
+    warn_lints: Vec<String>,
+    /// Sets the level of a lint (or of the `warnings` lint group) to deny.
+    #[arg(short = 'D', long = "deny", value_name = "LINT")]
+    deny_lints: Vec<String>,
+    /// Fails the compilation on warnings. Same as `--deny warnings`.
+    #[arg(long)]
+    deny_warnings: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -33,9 +50,21 @@ fn main() -> anyhow::Result<()> {
     // Check if args.path is a file or a directory.
     check_compiler_path(args.single_file, &args.path)?;
 
+    let lint_levels = lint_levels_from_flags(
+        args.allow_lints,
+        args.warn_lints,
+        args.deny_lints,
+        args.deny_warnings,
+    );
+
     let sierra_program = compile_cairo_project_at_path(
         &args.path,
-        CompilerConfig { replace_ids: args.replace_ids, ..CompilerConfig::default() },
+        CompilerConfig {
+            replace_ids: args.replace_ids,
+            diagnostics_reporter: DiagnosticsReporter::stderr_with_format(args.diagnostics_format),
+            lint_levels,
+            ..CompilerConfig::default()
+        },
     )?;
 
     match args.output {
@@ -69,7 +69,8 @@ fn main() -> anyhow::Result<()> {
         anyhow::bail!("Program requires gas counter, please provide `--available-gas` argument.");
     }
 
-    let contracts_info = get_contracts_info(db, main_crate_ids, &replacer)?;
+    // The program is compiled without the starknet plugin, so it has no contracts to hash.
+    let contracts_info = get_contracts_info(db, main_crate_ids, &replacer, &Default::default())?;
     let sierra_program = replacer.apply(&sierra_program);
 
     let runner = SierraCasmRunner::new(
@@ -3,7 +3,8 @@
 use std::path::PathBuf;
 
 use anyhow::Ok;
-use cairo_lang_compiler::project::check_compiler_path;
+use cairo_lang_compiler::diagnostics_format::DiagnosticsFormat;
+use cairo_lang_compiler::project::{check_compiler_path, lint_levels_from_flags};
 use cairo_lang_test_runner::{RunProfilerConfig, TestRunConfig, TestRunner};
 use clap::{Parser, ValueEnum};
 use serde::Serialize;
@@ -56,6 +57,21 @@ struct Args {
     /// [cairo_lang_test_runner::RunProfilerConfig]
     #[clap(short, long, default_value_t, value_enum)]
     run_profiler: RunProfilerConfigArg,
+    /// The format of the reported diagnostics: `human`, `json` or `sarif`.
+    #[arg(long, default_value_t)]
+    diagnostics_format: DiagnosticsFormat,
+    /// Sets the level of a lint (or of the `warnings` lint group) to allow.
+    #[arg(short = 'A', long = "allow", value_name = "LINT")]
+    allow_lints: Vec<String>,
+    /// Sets the level of a lint (or of the `warnings` lint group) to warn.
+    #[arg(short = 'W', long = "warn", value_name = "LINT")]
+    warn_lints: Vec<String>,
+    /// Sets the level of a lint (or of the `warnings` lint group) to deny.
+    #[arg(short = 'D', long = "deny", value_name = "LINT")]
+    deny_lints: Vec<String>,
+    /// Fails the compilation on warnings. Same as `--deny warnings`.
+    #[arg(long)]
+    deny_warnings: bool,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -64,6 +80,12 @@ fn main() -> anyhow::Result<()> {
     // Check if args.path is a file or a directory.
     check_compiler_path(args.single_file, &args.path)?;
 
+    let lint_levels = lint_levels_from_flags(
+        args.allow_lints,
+        args.warn_lints,
+        args.deny_lints,
+        args.deny_warnings,
+    );
     let config = TestRunConfig {
         filter: args.filter,
         ignored: args.ignored,
@@ -71,7 +93,14 @@ fn main() -> anyhow::Result<()> {
         run_profiler: args.run_profiler.into(),
     };
 
-    let runner = TestRunner::new(&args.path, args.starknet, args.allow_warnings, config)?;
+    let runner = TestRunner::new(
+        &args.path,
+        args.starknet,
+        args.allow_warnings,
+        args.diagnostics_format,
+        &lint_levels,
+        config,
+    )?;
     runner.run()?;
 
     Ok(())
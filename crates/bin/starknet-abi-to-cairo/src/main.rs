@@ -0,0 +1,44 @@
+use std::fs;
+
+use anyhow::Context;
+use cairo_lang_starknet_classes::abi::Contract;
+use cairo_lang_starknet_classes::abi_to_cairo::abi_to_cairo;
+use clap::Parser;
+
+/// Command line args parser.
+/// Generates the Cairo interfaces, types and events of a Starknet contract from its ABI.
+#[derive(Parser, Debug)]
+#[clap(version, verbatim_doc_comment)]
+struct Args {
+    /// The file of the ABI - either the ABI json or a contract class json containing it.
+    file: String,
+    /// The name of the interface declaring the functions that are not part of an interface.
+    #[arg(long, default_value = "IContract")]
+    interface_name: String,
+    /// The output file name (default: stdout).
+    output: Option<String>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let mut json: serde_json::Value = serde_json::from_str(
+        &fs::read_to_string(&args.file)
+            .with_context(|| format!("Failed to read {}.", &args.file))?,
+    )
+    .with_context(|| "deserialization Failed.")?;
+    // A contract class holds the ABI in its `abi` field.
+    if let Some(abi) = json.get_mut("abi") {
+        json = abi.take();
+    }
+    let abi: Contract =
+        serde_json::from_value(json).with_context(|| "ABI deserialization Failed.")?;
+    let cairo_code = abi_to_cairo(&abi, &args.interface_name)
+        .with_context(|| "Failed to generate the Cairo interface.")?;
+    match args.output {
+        Some(path) => {
+            fs::write(path, cairo_code).with_context(|| "Failed to write the Cairo interface.")?
+        }
+        None => print!("{cairo_code}"),
+    }
+    Ok(())
+}
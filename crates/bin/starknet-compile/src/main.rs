@@ -3,9 +3,12 @@ use std::path::PathBuf;
 
 use anyhow::Context;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
-use cairo_lang_compiler::project::check_compiler_path;
+use cairo_lang_compiler::diagnostics_format::DiagnosticsFormat;
+use cairo_lang_compiler::project::{check_compiler_path, lint_levels_from_flags};
 use cairo_lang_compiler::CompilerConfig;
-use cairo_lang_starknet::compile::starknet_compile;
+use cairo_lang_starknet::compile::{
+    starknet_compile_contract_class, starknet_compile_contract_class_with_storage_layout,
+};
 use cairo_lang_starknet_classes::allowed_libfuncs::ListSelector;
 use clap::Parser;
 
@@ -36,6 +39,27 @@ struct Args {
     /// A file of the allowed libfuncs list to use.
     #[arg(long)]
     allowed_libfuncs_list_file: Option<String>,
+    /// The format of the reported diagnostics: `human`, `json` or `sarif`.
+    #[arg(long, default_value_t)]
+    diagnostics_format: DiagnosticsFormat,
+    /// Sets the level of a lint (or of the `warnings` lint group) to allow.
+    #[arg(short = 'A', long = "allow", value_name = "LINT")]
+    allow_lints: Vec<String>,
+    /// Sets the level of a lint (or of the `warnings` lint group) to warn.
+    #[arg(short = 'W', long = "warn", value_name = "LINT")]
+    warn_lints: Vec<String>,
+    /// Sets the level of a lint (or of the `warnings` lint group) to deny.
+    #[arg(short = 'D', long = "deny", value_name = "LINT")]
+    deny_lints: Vec<String>,
+    /// Fails the compilation on warnings. Same as `--deny warnings`.
+    #[arg(long)]
+    deny_warnings: bool,
+    /// Outputs the class hash of the contract instead of the contract class.
+    #[arg(long)]
+    class_hash: bool,
+    /// A file to write the storage layout of the contract to, in addition to the output.
+    #[arg(long)]
+    storage_layout_output: Option<PathBuf>,
 }
 
 fn main() -> anyhow::Result<()> {
@@ -44,23 +68,45 @@ fn main() -> anyhow::Result<()> {
     // Check if args.path is a file or a directory.
     check_compiler_path(args.single_file, &args.path)?;
 
+    let lint_levels = lint_levels_from_flags(
+        args.allow_lints,
+        args.warn_lints,
+        args.deny_lints,
+        args.deny_warnings,
+    );
     let list_selector =
         ListSelector::new(args.allowed_libfuncs_list_name, args.allowed_libfuncs_list_file)
             .expect("Both allowed libfunc list name and file were supplied.");
-    let mut diagnostics_reporter = DiagnosticsReporter::stderr();
+    let mut diagnostics_reporter = DiagnosticsReporter::stderr_with_format(args.diagnostics_format);
     if args.allow_warnings {
         diagnostics_reporter = diagnostics_reporter.allow_warnings();
     }
-    let res = starknet_compile(
-        args.path,
-        args.contract_path,
-        Some(CompilerConfig {
-            replace_ids: args.replace_ids,
-            diagnostics_reporter,
-            ..CompilerConfig::default()
-        }),
-        Some(list_selector),
-    )?;
+    let config = Some(CompilerConfig {
+        replace_ids: args.replace_ids,
+        diagnostics_reporter,
+        lint_levels,
+        ..CompilerConfig::default()
+    });
+    let contract = if let Some(storage_layout_output) = args.storage_layout_output {
+        let (contract, storage_layout) = starknet_compile_contract_class_with_storage_layout(
+            args.path,
+            args.contract_path,
+            config,
+            Some(list_selector),
+        )?;
+        let storage_layout = serde_json::to_string_pretty(&storage_layout)
+            .with_context(|| "Serialization failed.")?;
+        fs::write(storage_layout_output, storage_layout)
+            .with_context(|| "Failed to write storage layout.")?;
+        contract
+    } else {
+        starknet_compile_contract_class(args.path, args.contract_path, config, Some(list_selector))?
+    };
+    let res = if args.class_hash {
+        format!("{:#x}", contract.class_hash().to_biguint())
+    } else {
+        serde_json::to_string_pretty(&contract).with_context(|| "Serialization failed.")?
+    };
     match args.output {
         Some(path) => fs::write(path, res).with_context(|| "Failed to write output.")?,
         None => println!("{res}"),
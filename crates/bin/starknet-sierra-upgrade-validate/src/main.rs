@@ -9,6 +9,7 @@ use cairo_lang_starknet_classes::casm_contract_class::{
 };
 use cairo_lang_starknet_classes::compiler_version::VersionId;
 use cairo_lang_starknet_classes::contract_class::{ContractClass, ContractEntryPoints};
+use cairo_lang_starknet_classes::storage_layout::{check_upgrade_compatibility, StorageLayout};
 use cairo_lang_utils::bigint::BigUintAsHex;
 use clap::Parser;
 use indicatif::{ProgressBar, ProgressState, ProgressStyle};
@@ -21,7 +22,8 @@ use serde::Deserialize;
 #[clap(version, verbatim_doc_comment)]
 struct Args {
     /// The file to compile
-    file: String,
+    #[arg(required_unless_present = "storage_layouts")]
+    file: Option<String>,
     /// The allowed libfuncs list to use (default: most recent audited list).
     #[arg(long)]
     allowed_libfuncs_list_name: Option<String>,
@@ -31,6 +33,11 @@ struct Args {
     /// Sierra version to override to prior to compilation.
     #[arg(long)]
     override_version: Option<String>,
+    /// Instead of compiling, validates that a class with the storage layout in the second file can
+    /// replace a class with the storage layout in the first, as emitted by
+    /// `starknet-compile --storage-layout-output`.
+    #[arg(long, num_args = 2, value_names = ["OLD_LAYOUT", "NEW_LAYOUT"], conflicts_with = "file")]
+    storage_layouts: Option<Vec<String>>,
 }
 
 /// Parses version id from string.
@@ -86,8 +93,33 @@ struct CompilationMismatch {
     new: BigUintAsHex,
 }
 
+/// Reads a storage layout from a file.
+fn read_storage_layout(file: &str) -> anyhow::Result<StorageLayout> {
+    serde_json::from_str(
+        &fs::read_to_string(file).with_context(|| format!("Failed to read {file}."))?,
+    )
+    .with_context(|| format!("Failed to deserialize the storage layout in {file}."))
+}
+
+/// Validates that a class with the `new` storage layout can replace a class with the `old` one.
+fn validate_storage_layouts(old: &str, new: &str) -> anyhow::Result<()> {
+    let issues =
+        check_upgrade_compatibility(&read_storage_layout(old)?, &read_storage_layout(new)?);
+    for issue in &issues {
+        println!("{issue}");
+    }
+    if !issues.is_empty() {
+        anyhow::bail!("Storage layouts are incompatible.");
+    }
+    Ok(())
+}
+
 fn main() -> anyhow::Result<()> {
     let args: Args = Args::parse();
+    if let Some([old, new]) = args.storage_layouts.as_deref() {
+        return validate_storage_layouts(old, new);
+    }
+    let file = args.file.expect("Either a file or storage layouts are required.");
     let list_selector =
         ListSelector::new(args.allowed_libfuncs_list_name, args.allowed_libfuncs_list_file)
             .expect("Both allowed libfunc list name and file were supplied.");
@@ -97,8 +129,7 @@ fn main() -> anyhow::Result<()> {
     };
     // Reading the contract classes from the file.
     let tested_classes: Vec<ContractClassInfo> = serde_json::from_str(
-        &fs::read_to_string(&args.file)
-            .with_context(|| format!("Failed to read {}.", &args.file))?,
+        &fs::read_to_string(&file).with_context(|| format!("Failed to read {file}."))?,
     )
     .with_context(|| "deserialization Failed.")?;
     let num_of_classes = tested_classes.len();
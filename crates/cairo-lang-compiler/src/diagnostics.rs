@@ -1,6 +1,6 @@
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::ids::ModuleId;
-use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, Severity};
+use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, ResolvedDiagnostic, Severity};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::{CrateId, FileLongId};
 use cairo_lang_lowering::db::LoweringGroup;
@@ -10,6 +10,9 @@ use cairo_lang_utils::Upcast;
 use thiserror::Error;
 
 use crate::db::RootDatabase;
+use crate::diagnostics_format::{
+    diagnostic_to_human, diagnostic_to_json, diagnostics_to_sarif, DiagnosticsFormat,
+};
 
 #[cfg(test)]
 #[path = "diagnostics_test.rs"]
@@ -20,13 +23,22 @@ mod test;
 pub struct DiagnosticsError;
 
 trait DiagnosticCallback {
-    fn on_diagnostic(&mut self, severity: Severity, diagnostic: String);
+    fn on_diagnostic(&mut self, diagnostic: ResolvedDiagnostic);
+
+    /// Called after all the diagnostics of a check were reported.
+    fn on_finish(&mut self) {}
 }
 
 impl<'a> DiagnosticCallback for Option<Box<dyn DiagnosticCallback + 'a>> {
-    fn on_diagnostic(&mut self, severity: Severity, diagnostic: String) {
+    fn on_diagnostic(&mut self, diagnostic: ResolvedDiagnostic) {
+        if let Some(callback) = self {
+            callback.on_diagnostic(diagnostic)
+        }
+    }
+
+    fn on_finish(&mut self) {
         if let Some(callback) = self {
-            callback.on_diagnostic(severity, diagnostic)
+            callback.on_finish()
         }
     }
 }
@@ -51,6 +63,11 @@ impl DiagnosticsReporter<'static> {
             eprint!("{severity}: {diagnostic}");
         })
     }
+
+    /// Create a reporter which prints all diagnostics to [`std::io::Stderr`] in the given format.
+    pub fn stderr_with_format(format: DiagnosticsFormat) -> Self {
+        Self::formatted(format, |output| eprint!("{output}"))
+    }
 }
 
 impl<'a> DiagnosticsReporter<'a> {
@@ -65,14 +82,51 @@ impl<'a> DiagnosticsReporter<'a> {
         where
             F: FnMut(Severity, String),
         {
-            fn on_diagnostic(&mut self, severity: Severity, diagnostic: String) {
-                (self.0)(severity, diagnostic)
+            fn on_diagnostic(&mut self, diagnostic: ResolvedDiagnostic) {
+                (self.0)(diagnostic.severity, diagnostic.formatted)
             }
         }
 
         Self::new(Func(callback))
     }
 
+    /// Create a reporter which renders all diagnostics in the given format and passes the output
+    /// to `sink`.
+    ///
+    /// With [DiagnosticsFormat::Json] every diagnostic is passed as a separate line, while with
+    /// [DiagnosticsFormat::Sarif] a single log is passed once a check is done.
+    pub fn formatted(format: DiagnosticsFormat, sink: impl FnMut(String) + 'a) -> Self {
+        struct Formatted<F> {
+            format: DiagnosticsFormat,
+            sink: F,
+            pending: Vec<ResolvedDiagnostic>,
+        }
+
+        impl<F> DiagnosticCallback for Formatted<F>
+        where
+            F: FnMut(String),
+        {
+            fn on_diagnostic(&mut self, diagnostic: ResolvedDiagnostic) {
+                match self.format {
+                    DiagnosticsFormat::Human => (self.sink)(diagnostic_to_human(&diagnostic)),
+                    DiagnosticsFormat::Json => {
+                        (self.sink)(format!("{}\n", diagnostic_to_json(&diagnostic)))
+                    }
+                    DiagnosticsFormat::Sarif => self.pending.push(diagnostic),
+                }
+            }
+
+            fn on_finish(&mut self) {
+                if self.format == DiagnosticsFormat::Sarif {
+                    let pending = std::mem::take(&mut self.pending);
+                    (self.sink)(format!("{}\n", diagnostics_to_sarif(&pending)));
+                }
+            }
+        }
+
+        Self::new(Formatted { format, sink, pending: vec![] })
+    }
+
     /// Create a reporter which appends all diagnostics to provided string.
     pub fn write_to_string(string: &'a mut String) -> Self {
         Self::callback(|severity, diagnostic| {
@@ -105,16 +159,21 @@ impl<'a> DiagnosticsReporter<'a> {
         for crate_id in crates {
             let Ok(module_file) = db.module_main_file(ModuleId::CrateRoot(crate_id)) else {
                 found_diagnostics = true;
-                self.callback
-                    .on_diagnostic(Severity::Error, "Failed to get main module file".to_string());
+                self.callback.on_diagnostic(ResolvedDiagnostic::without_location(
+                    Severity::Error,
+                    "Failed to get main module file".to_string(),
+                ));
                 continue;
             };
 
             if db.file_content(module_file).is_none() {
                 match db.lookup_intern_file(module_file) {
-                    FileLongId::OnDisk(path) => self
-                        .callback
-                        .on_diagnostic(Severity::Error, format!("{} not found\n", path.display())),
+                    FileLongId::OnDisk(path) => {
+                        self.callback.on_diagnostic(ResolvedDiagnostic::without_location(
+                            Severity::Error,
+                            format!("{} not found\n", path.display()),
+                        ))
+                    }
                     FileLongId::Virtual(_) => panic!("Missing virtual file."),
                 }
                 found_diagnostics = true;
@@ -135,20 +194,21 @@ impl<'a> DiagnosticsReporter<'a> {
                 }
             }
         }
+        self.callback.on_finish();
         found_diagnostics
     }
 
     /// Checks if a diagnostics group contains any diagnostics and reports them to the provided
-    /// callback as strings. Returns `true` if diagnostics were found.
+    /// callback. Returns `true` if diagnostics were found.
     fn check_diag_group<TEntry: DiagnosticEntry>(
         &mut self,
         db: &TEntry::DbType,
         group: Diagnostics<TEntry>,
     ) -> bool {
         let mut found: bool = false;
-        for entry in group.format_with_severity(db) {
-            if !entry.is_empty() {
-                self.callback.on_diagnostic(entry.severity(), entry.message().to_string());
+        for entry in group.resolve(db) {
+            if !entry.formatted.is_empty() {
+                self.callback.on_diagnostic(entry);
                 found |= !self.allow_warnings || group.check_error_free().is_err();
             }
         }
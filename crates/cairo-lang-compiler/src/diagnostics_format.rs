@@ -0,0 +1,283 @@
+//! Machine-readable renderings of compilation diagnostics.
+
+use std::fmt;
+use std::str::FromStr;
+
+use cairo_lang_diagnostics::{ResolvedDiagnostic, ResolvedLocation, Severity};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use serde::Serialize;
+
+#[cfg(test)]
+#[path = "diagnostics_format_test.rs"]
+mod test;
+
+/// The format in which a [crate::diagnostics::DiagnosticsReporter] reports diagnostics.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum DiagnosticsFormat {
+    /// Human readable messages with source snippets.
+    #[default]
+    Human,
+    /// A JSON object per diagnostic, one per line.
+    Json,
+    /// A single SARIF 2.1.0 log containing all the diagnostics.
+    Sarif,
+}
+impl FromStr for DiagnosticsFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            _ => Err(format!(
+                "Unknown diagnostics format `{s}`. Expected one of: human, json, sarif."
+            )),
+        }
+    }
+}
+impl fmt::Display for DiagnosticsFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Human => write!(f, "human"),
+            Self::Json => write!(f, "json"),
+            Self::Sarif => write!(f, "sarif"),
+        }
+    }
+}
+
+/// Formats a diagnostic the way it is printed by the human readable reporter.
+pub fn diagnostic_to_human(diagnostic: &ResolvedDiagnostic) -> String {
+    format!("{}: {}", diagnostic.severity, diagnostic.formatted)
+}
+
+/// A location in a JSON diagnostic. Lines and columns are 1 based, byte offsets are 0 based.
+#[derive(Serialize)]
+struct JsonLocation {
+    file: String,
+    byte_start: u32,
+    byte_end: u32,
+    line_start: Option<usize>,
+    column_start: Option<usize>,
+    line_end: Option<usize>,
+    column_end: Option<usize>,
+}
+impl From<&ResolvedLocation> for JsonLocation {
+    fn from(location: &ResolvedLocation) -> Self {
+        Self {
+            file: location.file_path.clone(),
+            byte_start: location.span.start.as_u32(),
+            byte_end: location.span.end.as_u32(),
+            line_start: location.start.as_ref().map(|position| position.line + 1),
+            column_start: location.start.as_ref().map(|position| position.col + 1),
+            line_end: location.end.as_ref().map(|position| position.line + 1),
+            column_end: location.end.as_ref().map(|position| position.col + 1),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonNote {
+    message: String,
+    location: Option<JsonLocation>,
+}
+
+#[derive(Serialize)]
+struct JsonDiagnostic {
+    severity: String,
+    code: Option<&'static str>,
+    message: String,
+    location: Option<JsonLocation>,
+    notes: Vec<JsonNote>,
+    rendered: String,
+}
+
+/// Formats a diagnostic as a single line JSON object.
+pub fn diagnostic_to_json(diagnostic: &ResolvedDiagnostic) -> String {
+    let json = JsonDiagnostic {
+        severity: diagnostic.severity.to_string(),
+        code: diagnostic.error_code.map(|code| code.as_str()),
+        message: diagnostic.message.clone(),
+        location: diagnostic.location.as_ref().map(JsonLocation::from),
+        notes: diagnostic
+            .notes
+            .iter()
+            .map(|note| JsonNote {
+                message: note.text.clone(),
+                location: note.location.as_ref().map(JsonLocation::from),
+            })
+            .collect(),
+        rendered: diagnostic_to_human(diagnostic),
+    };
+    serde_json::to_string(&json).expect("Serializing a diagnostic should never fail.")
+}
+
+const SARIF_VERSION: &str = "2.1.0";
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const SARIF_TOOL_NAME: &str = "cairo";
+const SARIF_TOOL_URI: &str = "https://github.com/starkware-libs/cairo";
+
+#[derive(Serialize)]
+struct SarifLog {
+    version: &'static str,
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Serialize)]
+struct SarifRun {
+    tool: SarifTool,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+    version: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Serialize)]
+struct SarifRule {
+    id: &'static str,
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rule_id: Option<&'static str>,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<SarifLocation>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<SarifMessage>,
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+/// A region in a SARIF location. Lines and columns are 1 based, byte offsets are 0 based.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    byte_offset: u32,
+    byte_length: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end_column: Option<usize>,
+}
+impl From<&ResolvedLocation> for SarifPhysicalLocation {
+    fn from(location: &ResolvedLocation) -> Self {
+        Self {
+            artifact_location: SarifArtifactLocation { uri: location.file_path.clone() },
+            region: SarifRegion {
+                byte_offset: location.span.start.as_u32(),
+                byte_length: location.span.width().as_u32(),
+                start_line: location.start.as_ref().map(|position| position.line + 1),
+                start_column: location.start.as_ref().map(|position| position.col + 1),
+                end_line: location.end.as_ref().map(|position| position.line + 1),
+                end_column: location.end.as_ref().map(|position| position.col + 1),
+            },
+        }
+    }
+}
+
+/// Formats the given diagnostics as a SARIF 2.1.0 log with a single run.
+///
+/// Notes with a location are reported as related locations, while notes without one are appended
+/// to the message text.
+pub fn diagnostics_to_sarif(diagnostics: &[ResolvedDiagnostic]) -> String {
+    let rules: OrderedHashSet<&'static str> = diagnostics
+        .iter()
+        .filter_map(|diagnostic| diagnostic.error_code.map(|code| code.as_str()))
+        .collect();
+    let results = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let mut text = diagnostic.message.clone();
+            let mut related_locations = vec![];
+            for note in &diagnostic.notes {
+                match &note.location {
+                    Some(location) => related_locations.push(SarifLocation {
+                        id: Some(related_locations.len()),
+                        message: Some(SarifMessage { text: note.text.clone() }),
+                        physical_location: location.into(),
+                    }),
+                    None => text += &format!("\nnote: {}", note.text),
+                }
+            }
+            SarifResult {
+                rule_id: diagnostic.error_code.map(|code| code.as_str()),
+                level: match diagnostic.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                },
+                message: SarifMessage { text },
+                locations: diagnostic
+                    .location
+                    .iter()
+                    .map(|location| SarifLocation {
+                        id: None,
+                        message: None,
+                        physical_location: location.into(),
+                    })
+                    .collect(),
+                related_locations,
+            }
+        })
+        .collect();
+    let log = SarifLog {
+        version: SARIF_VERSION,
+        schema: SARIF_SCHEMA,
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: SARIF_TOOL_NAME,
+                    information_uri: SARIF_TOOL_URI,
+                    version: env!("CARGO_PKG_VERSION"),
+                    rules: rules.into_iter().map(|id| SarifRule { id }).collect(),
+                },
+            },
+            results,
+        }],
+    };
+    serde_json::to_string_pretty(&log).expect("Serializing diagnostics should never fail.")
+}
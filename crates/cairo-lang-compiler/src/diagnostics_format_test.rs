@@ -0,0 +1,149 @@
+use cairo_lang_diagnostics::{
+    error_code, ResolvedDiagnostic, ResolvedLocation, ResolvedNote, Severity,
+};
+use cairo_lang_filesystem::span::{TextOffset, TextPosition, TextSpan, TextWidth};
+use indoc::indoc;
+use serde_json::json;
+
+use super::{diagnostic_to_json, diagnostics_to_sarif, DiagnosticsFormat};
+
+fn test_diagnostic() -> ResolvedDiagnostic {
+    let start = TextOffset::default().add_width(TextWidth::new_for_testing(4));
+    let location = ResolvedLocation {
+        file_path: "src/lib.cairo".into(),
+        span: TextSpan { start, end: start.add_width(TextWidth::new_for_testing(3)) },
+        start: Some(TextPosition { line: 1, col: 0 }),
+        end: Some(TextPosition { line: 1, col: 3 }),
+    };
+    ResolvedDiagnostic {
+        severity: Severity::Warning,
+        error_code: Some(error_code!("E0123")),
+        message: "Unused variable.".into(),
+        location: Some(location.clone()),
+        notes: vec![
+            ResolvedNote { text: "Defined here".into(), location: Some(location) },
+            ResolvedNote { text: "Consider prefixing with `_`".into(), location: None },
+        ],
+        formatted: "Unused variable.\n".into(),
+    }
+}
+
+#[test]
+fn test_diagnostics_format_from_str() {
+    for format in [DiagnosticsFormat::Human, DiagnosticsFormat::Json, DiagnosticsFormat::Sarif] {
+        assert_eq!(format.to_string().parse::<DiagnosticsFormat>(), Ok(format));
+    }
+    assert!("xml".parse::<DiagnosticsFormat>().is_err());
+}
+
+#[test]
+fn test_diagnostic_to_json() {
+    let location = json!({
+        "file": "src/lib.cairo",
+        "byte_start": 4,
+        "byte_end": 7,
+        "line_start": 2,
+        "column_start": 1,
+        "line_end": 2,
+        "column_end": 4,
+    });
+    let json = diagnostic_to_json(&test_diagnostic());
+    assert!(!json.contains('\n'));
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+        json!({
+            "severity": "warning",
+            "code": "E0123",
+            "message": "Unused variable.",
+            "location": location,
+            "notes": [
+                {"message": "Defined here", "location": location},
+                {"message": "Consider prefixing with `_`", "location": null},
+            ],
+            "rendered": "warning: Unused variable.\n",
+        })
+    );
+}
+
+#[test]
+fn test_diagnostics_to_sarif() {
+    let without_location =
+        ResolvedDiagnostic::without_location(Severity::Error, "lib.cairo not found\n".into());
+    let sarif = diagnostics_to_sarif(&[test_diagnostic(), without_location]);
+    let expected = indoc! {r#"
+        {
+          "version": "2.1.0",
+          "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+          "runs": [
+            {
+              "tool": {
+                "driver": {
+                  "name": "cairo",
+                  "informationUri": "https://github.com/starkware-libs/cairo",
+                  "version": "VERSION",
+                  "rules": [
+                    {
+                      "id": "E0123"
+                    }
+                  ]
+                }
+              },
+              "results": [
+                {
+                  "ruleId": "E0123",
+                  "level": "warning",
+                  "message": {
+                    "text": "Unused variable.\nnote: Consider prefixing with `_`"
+                  },
+                  "locations": [
+                    {
+                      "physicalLocation": {
+                        "artifactLocation": {
+                          "uri": "src/lib.cairo"
+                        },
+                        "region": {
+                          "byteOffset": 4,
+                          "byteLength": 3,
+                          "startLine": 2,
+                          "startColumn": 1,
+                          "endLine": 2,
+                          "endColumn": 4
+                        }
+                      }
+                    }
+                  ],
+                  "relatedLocations": [
+                    {
+                      "id": 0,
+                      "message": {
+                        "text": "Defined here"
+                      },
+                      "physicalLocation": {
+                        "artifactLocation": {
+                          "uri": "src/lib.cairo"
+                        },
+                        "region": {
+                          "byteOffset": 4,
+                          "byteLength": 3,
+                          "startLine": 2,
+                          "startColumn": 1,
+                          "endLine": 2,
+                          "endColumn": 4
+                        }
+                      }
+                    }
+                  ]
+                },
+                {
+                  "level": "error",
+                  "message": {
+                    "text": "lib.cairo not found\n"
+                  },
+                  "locations": []
+                }
+              ]
+            }
+          ]
+        }"#};
+    assert_eq!(sarif, expected.replace("VERSION", env!("CARGO_PKG_VERSION")));
+}
@@ -1,8 +1,16 @@
+use std::sync::Arc;
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::ModuleId;
 use cairo_lang_filesystem::db::{CrateConfiguration, FilesGroup, FilesGroupEx};
 use cairo_lang_filesystem::ids::{CrateLongId, Directory};
+use indoc::indoc;
+use serde_json::json;
 
 use crate::db::RootDatabase;
-use crate::diagnostics::get_diagnostics_as_string;
+use crate::diagnostics::{get_diagnostics_as_string, DiagnosticsReporter};
+use crate::diagnostics_format::DiagnosticsFormat;
+use crate::project::{lint_levels_from_flags, update_crate_lint_levels, ProjectError};
 
 #[test]
 fn test_diagnostics() {
@@ -16,3 +24,68 @@ fn test_diagnostics() {
 
     assert_eq!(get_diagnostics_as_string(&db, &[]), "error: no/such/path/lib.cairo not found\n");
 }
+
+#[test]
+fn test_diagnostics_json_format() {
+    let mut db = RootDatabase::default();
+
+    let crate_id = db.intern_crate(CrateLongId::Real("bad_create".into()));
+    db.set_crate_config(
+        crate_id,
+        Some(CrateConfiguration::default_for_root(Directory::Real("no/such/path".into()))),
+    );
+
+    let mut output = String::new();
+    DiagnosticsReporter::formatted(DiagnosticsFormat::Json, |line| output.push_str(&line))
+        .check(&db);
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&output).unwrap(),
+        json!({
+            "severity": "error",
+            "code": null,
+            "message": "no/such/path/lib.cairo not found\n",
+            "location": null,
+            "notes": [],
+            "rendered": "error: no/such/path/lib.cairo not found\n",
+        })
+    );
+}
+
+#[test]
+fn test_crate_lint_levels() {
+    let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+
+    let crate_id = db.intern_crate(CrateLongId::Real("test".into()));
+    db.set_crate_config(
+        crate_id,
+        Some(CrateConfiguration::default_for_root(Directory::Real("src".into()))),
+    );
+    let file_id = db.module_main_file(ModuleId::CrateRoot(crate_id)).unwrap();
+    db.override_file_content(
+        file_id,
+        Some(Arc::new(
+            "fn foo() { let x = 5; }\n#[allow(unused_variables)]\nfn bar() { let y = 5; }".into(),
+        )),
+    );
+
+    assert!(!DiagnosticsReporter::stderr().with_crates(&[crate_id]).allow_warnings().check(&db));
+
+    let lint_levels = lint_levels_from_flags(vec![], vec![], vec![], true);
+    update_crate_lint_levels(&mut db, &[crate_id], &lint_levels).unwrap();
+    assert_eq!(
+        get_diagnostics_as_string(&db, &[crate_id]),
+        indoc! {"
+            error: Unused variable. Consider ignoring by prefixing with `_`.
+             --> src/lib.cairo:1:16
+            fn foo() { let x = 5; }
+                           ^
+
+        "}
+    );
+
+    let lint_levels = lint_levels_from_flags(vec!["unused_variable".into()], vec![], vec![], false);
+    assert!(matches!(
+        update_crate_lint_levels(&mut db, &[crate_id], &lint_levels),
+        Err(ProjectError::UnknownLint { name }) if name == "unused_variable"
+    ));
+}
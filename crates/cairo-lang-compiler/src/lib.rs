@@ -6,6 +6,7 @@ use std::path::Path;
 
 use ::cairo_lang_diagnostics::ToOption;
 use anyhow::{Context, Result};
+use cairo_lang_filesystem::db::LintLevel;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_sierra::program::Program;
 use cairo_lang_sierra_generator::db::SierraGenGroup;
@@ -15,10 +16,13 @@ use cairo_lang_utils::arc_unwrap_or_clone;
 
 use crate::db::RootDatabase;
 use crate::diagnostics::DiagnosticsReporter;
-use crate::project::{get_main_crate_ids_from_project, setup_project, ProjectConfig};
+use crate::project::{
+    get_main_crate_ids_from_project, setup_project, update_crate_lint_levels, ProjectConfig,
+};
 
 pub mod db;
 pub mod diagnostics;
+pub mod diagnostics_format;
 pub mod project;
 
 /// Configuration for the compiler.
@@ -31,6 +35,10 @@ pub struct CompilerConfig<'c> {
     /// The name of the allowed libfuncs list to use in compilation.
     /// If None the default list of audited libfuncs will be used.
     pub allowed_libfuncs_list_name: Option<String>,
+
+    /// Lint levels to set for the main crates, overriding the project config. Applied only when
+    /// the compiler sets up the project itself, e.g. by [compile_cairo_project_at_path].
+    pub lint_levels: Vec<(String, LintLevel)>,
 }
 
 /// The default compiler configuration.
@@ -40,6 +48,7 @@ impl Default for CompilerConfig<'static> {
             diagnostics_reporter: DiagnosticsReporter::default(),
             replace_ids: false,
             allowed_libfuncs_list_name: None,
+            lint_levels: vec![],
         }
     }
 }
@@ -60,6 +69,7 @@ pub fn compile_cairo_project_at_path(
 ) -> Result<Program> {
     let mut db = RootDatabase::builder().detect_corelib().build()?;
     let main_crate_ids = setup_project(&mut db, path)?;
+    update_crate_lint_levels(&mut db, &main_crate_ids, &compiler_config.lint_levels)?;
     compile_prepared_db(&mut db, main_crate_ids, compiler_config)
 }
 
@@ -78,6 +88,7 @@ pub fn compile(
 ) -> Result<Program> {
     let mut db = RootDatabase::builder().with_project_config(project_config.clone()).build()?;
     let main_crate_ids = get_main_crate_ids_from_project(&mut db, &project_config);
+    update_crate_lint_levels(&mut db, &main_crate_ids, &compiler_config.lint_levels)?;
 
     compile_prepared_db(&mut db, main_crate_ids, compiler_config)
 }
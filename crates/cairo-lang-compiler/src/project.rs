@@ -3,10 +3,11 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use cairo_lang_defs::ids::ModuleId;
-use cairo_lang_filesystem::db::{CrateConfiguration, FilesGroupEx};
+use cairo_lang_filesystem::db::{CrateConfiguration, FilesGroupEx, LintLevel};
 use cairo_lang_filesystem::ids::{CrateId, CrateLongId, Directory};
 pub use cairo_lang_project::*;
 use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::lints::{is_known_lint_name, WARNINGS_LINT_GROUP};
 use smol_str::SmolStr;
 
 #[derive(thiserror::Error, Debug)]
@@ -19,6 +20,8 @@ pub enum ProjectError {
     BadPath { path: String },
     #[error("Failed to load project config.")]
     LoadProjectError,
+    #[error("Unknown lint `{name}`.")]
+    UnknownLint { name: String },
 }
 
 /// Setup to 'db' to compile the file at the given path.
@@ -143,3 +146,44 @@ pub fn get_main_crate_ids_from_project(
         .map(|crate_id| db.intern_crate(CrateLongId::Real(crate_id.clone())))
         .collect()
 }
+
+/// Returns the lint levels set by `--allow`, `--warn`, `--deny` and `--deny-warnings` command line
+/// flags, in the order they should be applied - a lint named by several flags gets the most severe
+/// level.
+pub fn lint_levels_from_flags(
+    allow: Vec<String>,
+    warn: Vec<String>,
+    mut deny: Vec<String>,
+    deny_warnings: bool,
+) -> Vec<(String, LintLevel)> {
+    if deny_warnings {
+        deny.push(WARNINGS_LINT_GROUP.into());
+    }
+    let allow = allow.into_iter().map(|name| (name, LintLevel::Allow));
+    let warn = warn.into_iter().map(|name| (name, LintLevel::Warn));
+    let deny = deny.into_iter().map(|name| (name, LintLevel::Deny));
+    allow.chain(warn).chain(deny).collect()
+}
+
+/// Sets the given lint levels in the settings of the given crates, overriding the levels set by
+/// the project config. Later levels of the same lint take precedence over earlier ones.
+pub fn update_crate_lint_levels(
+    db: &mut dyn SemanticGroup,
+    crate_ids: &[CrateId],
+    lint_levels: &[(String, LintLevel)],
+) -> Result<(), ProjectError> {
+    if lint_levels.is_empty() {
+        return Ok(());
+    }
+    if let Some((name, _)) = lint_levels.iter().find(|(name, _)| !is_known_lint_name(name)) {
+        return Err(ProjectError::UnknownLint { name: name.clone() });
+    }
+    for crate_id in crate_ids {
+        let Some(mut config) = db.crate_config(*crate_id) else {
+            continue;
+        };
+        config.settings.lints.extend(lint_levels.iter().cloned());
+        db.set_crate_config(*crate_id, Some(config));
+    }
+    Ok(())
+}
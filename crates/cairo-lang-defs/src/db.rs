@@ -1,13 +1,13 @@
 use std::collections::VecDeque;
 use std::sync::Arc;
 
-use cairo_lang_diagnostics::{Maybe, ToMaybe};
+use cairo_lang_diagnostics::{error_code, Maybe, ToMaybe};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::{CrateId, Directory, FileId, FileKind, FileLongId, VirtualFile};
 use cairo_lang_parser::db::ParserGroup;
 use cairo_lang_syntax::attribute::consts::{
-    FEATURE_ATTR, FMT_SKIP_ATTR, IMPLICIT_PRECEDENCE_ATTR, INLINE_ATTR, MUST_USE_ATTR,
-    STARKNET_INTERFACE_ATTR, UNSTABLE_ATTR,
+    ALLOW_ATTR, DENY_ATTR, FEATURE_ATTR, FMT_SKIP_ATTR, IMPLICIT_PRECEDENCE_ATTR, INLINE_ATTR,
+    MUST_USE_ATTR, STARKNET_INTERFACE_ATTR, UNSTABLE_ATTR, WARN_ATTR,
 };
 use cairo_lang_syntax::node::ast::MaybeModuleBody;
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -38,9 +38,13 @@ pub trait DefsGroup:
     #[salsa::interned]
     fn intern_use(&self, id: UseLongId) -> UseId;
     #[salsa::interned]
+    fn intern_global_use(&self, id: GlobalUseLongId) -> GlobalUseId;
+    #[salsa::interned]
     fn intern_free_function(&self, id: FreeFunctionLongId) -> FreeFunctionId;
     #[salsa::interned]
-    fn intern_impl_type(&self, id: ImplTypeLongId) -> ImplTypeId;
+    fn intern_impl_type_def(&self, id: ImplTypeDefLongId) -> ImplTypeDefId;
+    #[salsa::interned]
+    fn intern_impl_constant_def(&self, id: ImplConstantDefLongId) -> ImplConstantDefId;
     #[salsa::interned]
     fn intern_impl_function(&self, id: ImplFunctionLongId) -> ImplFunctionId;
     #[salsa::interned]
@@ -60,6 +64,8 @@ pub trait DefsGroup:
     #[salsa::interned]
     fn intern_trait_type(&self, id: TraitTypeLongId) -> TraitTypeId;
     #[salsa::interned]
+    fn intern_trait_constant(&self, id: TraitConstantLongId) -> TraitConstantId;
+    #[salsa::interned]
     fn intern_trait_function(&self, id: TraitFunctionLongId) -> TraitFunctionId;
     #[salsa::interned]
     fn intern_impl(&self, id: ImplDefLongId) -> ImplDefId;
@@ -140,6 +146,14 @@ pub trait DefsGroup:
     ) -> Maybe<Arc<OrderedHashMap<UseId, ast::UsePathLeaf>>>;
     fn module_uses_ids(&self, module_id: ModuleId) -> Maybe<Arc<Vec<UseId>>>;
     fn module_use_by_id(&self, use_id: UseId) -> Maybe<Option<ast::UsePathLeaf>>;
+    fn module_global_uses(
+        &self,
+        module_id: ModuleId,
+    ) -> Maybe<Arc<OrderedHashMap<GlobalUseId, ast::UsePathStar>>>;
+    fn module_global_use_by_id(
+        &self,
+        global_use_id: GlobalUseId,
+    ) -> Maybe<Option<ast::UsePathStar>>;
     fn module_structs(
         &self,
         module_id: ModuleId,
@@ -220,6 +234,9 @@ fn allowed_attributes(db: &dyn DefsGroup) -> Arc<OrderedHashSet<String>> {
         FEATURE_ATTR.into(),
         IMPLICIT_PRECEDENCE_ATTR.into(),
         FMT_SKIP_ATTR.into(),
+        ALLOW_ATTR.into(),
+        WARN_ATTR.into(),
+        DENY_ATTR.into(),
         // TODO(orizi): Remove this once `starknet` is removed from corelib.
         STARKNET_INTERFACE_ATTR.into(),
     ]);
@@ -230,7 +247,13 @@ fn allowed_attributes(db: &dyn DefsGroup) -> Arc<OrderedHashSet<String>> {
 }
 
 fn allowed_statement_attributes(_db: &dyn DefsGroup) -> Arc<OrderedHashSet<String>> {
-    let all_attributes = OrderedHashSet::from_iter([FMT_SKIP_ATTR.into(), FEATURE_ATTR.into()]);
+    let all_attributes = OrderedHashSet::from_iter([
+        FMT_SKIP_ATTR.into(),
+        FEATURE_ATTR.into(),
+        ALLOW_ATTR.into(),
+        WARN_ATTR.into(),
+        DENY_ATTR.into(),
+    ]);
     Arc::new(all_attributes)
 }
 
@@ -338,6 +361,7 @@ pub struct ModuleData {
     constants: Arc<OrderedHashMap<ConstantId, ast::ItemConstant>>,
     submodules: Arc<OrderedHashMap<SubmoduleId, ast::ItemModule>>,
     uses: Arc<OrderedHashMap<UseId, ast::UsePathLeaf>>,
+    global_uses: Arc<OrderedHashMap<GlobalUseId, ast::UsePathStar>>,
     free_functions: Arc<OrderedHashMap<FreeFunctionId, ast::FunctionWithBody>>,
     structs: Arc<OrderedHashMap<StructId, ast::ItemStruct>>,
     enums: Arc<OrderedHashMap<EnumId, ast::ItemEnum>>,
@@ -392,6 +416,7 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
     let mut constants = OrderedHashMap::default();
     let mut submodules = OrderedHashMap::default();
     let mut uses = OrderedHashMap::default();
+    let mut global_uses = OrderedHashMap::default();
     let mut free_functions = OrderedHashMap::default();
     let mut structs = OrderedHashMap::default();
     let mut enums = OrderedHashMap::default();
@@ -483,6 +508,13 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
                         uses.insert(path_leaf_id, path_leaf);
                         items.push(ModuleItemId::Use(path_leaf_id));
                     }
+                    for path_star in get_all_path_stars(db.upcast(), us.use_path(syntax_db)) {
+                        let path_star_id = db.intern_global_use(GlobalUseLongId(
+                            module_file_id,
+                            path_star.stable_ptr(),
+                        ));
+                        global_uses.insert(path_star_id, path_star);
+                    }
                 }
                 ast::ModuleItem::FreeFunction(function) => {
                     let item_id = db.intern_free_function(FreeFunctionLongId(
@@ -553,7 +585,8 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
                             "Unknown inline item macro: '{}'.",
                             inline_macro_ast.name(db.upcast()).text(db.upcast())
                         ),
-                    ),
+                    )
+                    .with_error_code(error_code!("E0500")),
                 )),
                 ast::ModuleItem::Missing(_) => {}
             }
@@ -564,6 +597,7 @@ fn priv_module_data(db: &dyn DefsGroup, module_id: ModuleId) -> Maybe<ModuleData
         constants: constants.into(),
         submodules: submodules.into(),
         uses: uses.into(),
+        global_uses: global_uses.into(),
         free_functions: free_functions.into(),
         structs: structs.into(),
         enums: enums.into(),
@@ -596,7 +630,8 @@ pub fn validate_attributes_flat(
                 PluginDiagnostic::error(
                     attr.stable_ptr().untyped(),
                     "Unsupported attribute.".to_string(),
-                ),
+                )
+                .with_error_code(error_code!("E0501")),
             ));
         }
     }
@@ -650,13 +685,15 @@ fn validate_attributes(
             }
         }
         ast::ModuleItem::Struct(item) => {
-            validate_attributes_element_list(
-                db,
-                allowed_attributes,
-                module_file_id,
-                &item.members(db),
-                plugin_diagnostics,
-            );
+            for member in item.members(db) {
+                validate_attributes_flat(
+                    db,
+                    allowed_attributes,
+                    module_file_id,
+                    &member,
+                    plugin_diagnostics,
+                );
+            }
         }
         ast::ModuleItem::Enum(item) => {
             validate_attributes_element_list(
@@ -674,26 +711,42 @@ fn validate_attributes(
 /// Returns all the path leaves under a given use path.
 pub fn get_all_path_leaves(db: &dyn SyntaxGroup, use_path: ast::UsePath) -> Vec<ast::UsePathLeaf> {
     let mut res = vec![];
-    get_all_path_leaves_inner(db, use_path, &mut res);
+    let mut stars = vec![];
+    get_all_path_leaves_inner(db, use_path, &mut res, &mut stars);
+    res
+}
+
+/// Returns all the path stars (glob imports) under a given use path.
+pub fn get_all_path_stars(db: &dyn SyntaxGroup, use_path: ast::UsePath) -> Vec<ast::UsePathStar> {
+    let mut leaves = vec![];
+    let mut res = vec![];
+    get_all_path_leaves_inner(db, use_path, &mut leaves, &mut res);
     res
 }
 
-/// Finds all the path leaves under a given use path and adds them to the given vector.
+/// Finds all the path leaves and path stars under a given use path and adds them to the given
+/// vectors.
 fn get_all_path_leaves_inner(
     db: &dyn SyntaxGroup,
     use_path: ast::UsePath,
-    res: &mut Vec<ast::UsePathLeaf>,
+    leaves: &mut Vec<ast::UsePathLeaf>,
+    stars: &mut Vec<ast::UsePathStar>,
 ) {
     match use_path {
         ast::UsePath::Leaf(use_path) => {
-            res.push(use_path);
+            leaves.push(use_path);
+        }
+        ast::UsePath::Single(use_path) => {
+            get_all_path_leaves_inner(db, use_path.use_path(db), leaves, stars)
         }
-        ast::UsePath::Single(use_path) => get_all_path_leaves_inner(db, use_path.use_path(db), res),
         ast::UsePath::Multi(use_path) => {
             for use_path in use_path.use_paths(db).elements(db) {
-                get_all_path_leaves_inner(db, use_path, res);
+                get_all_path_leaves_inner(db, use_path, leaves, stars);
             }
         }
+        ast::UsePath::Star(use_path) => {
+            stars.push(use_path);
+        }
     }
 }
 
@@ -774,6 +827,21 @@ pub fn module_use_by_id(db: &dyn DefsGroup, use_id: UseId) -> Maybe<Option<ast::
     Ok(module_uses.get(&use_id).cloned())
 }
 
+/// Returns all the global uses (glob imports) of the given module.
+pub fn module_global_uses(
+    db: &dyn DefsGroup,
+    module_id: ModuleId,
+) -> Maybe<Arc<OrderedHashMap<GlobalUseId, ast::UsePathStar>>> {
+    Ok(db.priv_module_data(module_id)?.global_uses)
+}
+pub fn module_global_use_by_id(
+    db: &dyn DefsGroup,
+    global_use_id: GlobalUseId,
+) -> Maybe<Option<ast::UsePathStar>> {
+    let module_global_uses = db.module_global_uses(global_use_id.module_file_id(db.upcast()).0)?;
+    Ok(module_global_uses.get(&global_use_id).cloned())
+}
+
 /// Returns all the structs of the given module.
 pub fn module_structs(
     db: &dyn DefsGroup,
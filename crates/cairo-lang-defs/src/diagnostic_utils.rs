@@ -14,6 +14,11 @@ impl StableLocation {
         Self(stable_ptr)
     }
 
+    /// Returns the [SyntaxStablePtrId] of the location.
+    pub fn stable_ptr(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+
     pub fn file_id(&self, db: &dyn DefsGroup) -> FileId {
         self.0.file_id(db.upcast())
     }
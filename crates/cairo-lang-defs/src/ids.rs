@@ -334,6 +334,17 @@ define_language_element_id!(
     name
 );
 define_language_element_id!(UseId, UseLongId, ast::UsePathLeaf, lookup_intern_use, name);
+define_language_element_id!(
+    GlobalUseId,
+    GlobalUseLongId,
+    ast::UsePathStar,
+    lookup_intern_global_use
+);
+impl DebugWithDb<dyn DefsGroup> for GlobalUseLongId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>, db: &dyn DefsGroup) -> std::fmt::Result {
+        write!(f, "GlobalUseId({}::*)", self.0.0.full_path(db))
+    }
+}
 define_language_element_id!(
     FreeFunctionId,
     FreeFunctionLongId,
@@ -351,30 +362,57 @@ impl UnstableSalsaId for FreeFunctionId {
 // --- Impls ---
 define_language_element_id!(ImplDefId, ImplDefLongId, ast::ItemImpl, lookup_intern_impl, name);
 
+// --- Impl constant items ---
+define_language_element_id_partial!(
+    ImplConstantDefId,
+    ImplConstantDefLongId,
+    ast::ItemConstant,
+    lookup_intern_impl_constant_def,
+    name
+);
+impl ImplConstantDefId {
+    pub fn impl_def_id(&self, db: &dyn DefsGroup) -> ImplDefId {
+        let ImplConstantDefLongId(module_file_id, ptr) = db.lookup_intern_impl_constant_def(*self);
+
+        // Impl constant ast lies 3 levels below the impl ast.
+        let impl_ptr = ast::ItemImplPtr(ptr.untyped().nth_parent(db.upcast(), 3));
+        db.intern_impl(ImplDefLongId(module_file_id, impl_ptr))
+    }
+}
+impl TopLevelLanguageElementId for ImplConstantDefId {
+    fn full_path(&self, db: &dyn DefsGroup) -> String {
+        format!("{}::{}", self.impl_def_id(db).name(db), self.name(db))
+    }
+
+    fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        db.lookup_intern_impl_constant_def(*self).name(db)
+    }
+}
+
 // --- Impl type items ---
 define_language_element_id_partial!(
-    ImplTypeId,
-    ImplTypeLongId,
+    ImplTypeDefId,
+    ImplTypeDefLongId,
     ast::ItemTypeAlias,
-    lookup_intern_impl_type,
+    lookup_intern_impl_type_def,
     name
 );
-impl ImplTypeId {
+impl ImplTypeDefId {
     pub fn impl_def_id(&self, db: &dyn DefsGroup) -> ImplDefId {
-        let ImplTypeLongId(module_file_id, ptr) = db.lookup_intern_impl_type(*self);
+        let ImplTypeDefLongId(module_file_id, ptr) = db.lookup_intern_impl_type_def(*self);
 
         // Impl type ast lies 3 levels below the impl ast.
         let impl_ptr = ast::ItemImplPtr(ptr.untyped().nth_parent(db.upcast(), 3));
         db.intern_impl(ImplDefLongId(module_file_id, impl_ptr))
     }
 }
-impl TopLevelLanguageElementId for ImplTypeId {
+impl TopLevelLanguageElementId for ImplTypeDefId {
     fn full_path(&self, db: &dyn DefsGroup) -> String {
         format!("{}::{}", self.impl_def_id(db).name(db), self.name(db))
     }
 
     fn name(&self, db: &dyn DefsGroup) -> SmolStr {
-        db.lookup_intern_impl_type(*self).name(db)
+        db.lookup_intern_impl_type_def(*self).name(db)
     }
 }
 
@@ -499,6 +537,32 @@ impl TopLevelLanguageElementId for TraitTypeId {
     }
 }
 
+// --- Trait constant items ---
+define_language_element_id_partial!(
+    TraitConstantId,
+    TraitConstantLongId,
+    ast::TraitItemConstant,
+    lookup_intern_trait_constant,
+    name
+);
+impl TraitConstantId {
+    pub fn trait_id(&self, db: &dyn DefsGroup) -> TraitId {
+        let TraitConstantLongId(module_file_id, ptr) = db.lookup_intern_trait_constant(*self);
+        // Trait constant ast lies 3 levels below the trait ast.
+        let trait_ptr = ast::ItemTraitPtr(ptr.untyped().nth_parent(db.upcast(), 3));
+        db.intern_trait(TraitLongId(module_file_id, trait_ptr))
+    }
+}
+impl TopLevelLanguageElementId for TraitConstantId {
+    fn full_path(&self, db: &dyn DefsGroup) -> String {
+        format!("{}::{}", self.trait_id(db).name(db), self.name(db))
+    }
+
+    fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        db.lookup_intern_trait_constant(*self).name(db)
+    }
+}
+
 // --- Trait functions ---
 define_language_element_id_partial!(
     TraitFunctionId,
@@ -527,7 +591,35 @@ impl TopLevelLanguageElementId for TraitFunctionId {
 
 // --- Struct items ---
 // TODO(spapini): Override full_path for to include parents, for better debug.
-define_language_element_id!(MemberId, MemberLongId, ast::Member, lookup_intern_member, name);
+define_language_element_id_partial!(
+    MemberId,
+    MemberLongId,
+    ast::StructMember,
+    lookup_intern_member
+);
+impl MemberLongId {
+    /// Returns the name of the member - its identifier, or its position for tuple struct members.
+    pub fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        let syntax_db = db.upcast();
+        self.1.lookup(syntax_db).name(syntax_db)
+    }
+}
+impl<'a, T: ?Sized + cairo_lang_utils::Upcast<dyn DefsGroup + 'a>> DebugWithDb<T> for MemberLongId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>, db: &T) -> std::fmt::Result {
+        let db: &(dyn DefsGroup + 'a) = db.upcast();
+        write!(f, "MemberId({}::{})", self.0.0.full_path(db), self.name(db))
+    }
+}
+impl MemberId {
+    pub fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        db.lookup_intern_member(*self).name(db)
+    }
+}
+impl TopLevelLanguageElementId for MemberId {
+    fn name(&self, db: &dyn DefsGroup) -> SmolStr {
+        db.lookup_intern_member(*self).name(db)
+    }
+}
 define_language_element_id!(VariantId, VariantLongId, ast::Variant, lookup_intern_variant, name);
 
 define_language_element_id_as_enum! {
@@ -635,7 +727,7 @@ impl DebugWithDb<dyn DefsGroup> for GenericParamLongId {
 }
 
 // TODO(yuval): make this hierarchical: module/trait/impl and then subitems. Add
-// ImplItem(ImplTypeId) and TraitItem(ImplTypeId).
+// ImplItem(ImplTypeDefId) and TraitItem(ImplTypeDefId).
 define_language_element_id_as_enum! {
     #[toplevel]
     /// The ID of an item with generic parameters.
@@ -868,6 +960,7 @@ define_language_element_id_as_enum! {
     pub enum TraitItemId {
         Function(TraitFunctionId),
         Type(TraitTypeId),
+        Constant(TraitConstantId),
     }
 }
 impl TraitItemId {
@@ -875,12 +968,14 @@ impl TraitItemId {
         match self {
             TraitItemId::Function(id) => id.name(db),
             TraitItemId::Type(id) => id.name(db),
+            TraitItemId::Constant(id) => id.name(db),
         }
     }
     pub fn trait_id(&self, db: &dyn DefsGroup) -> TraitId {
         match self {
             TraitItemId::Function(id) => id.trait_id(db),
             TraitItemId::Type(id) => id.trait_id(db),
+            TraitItemId::Constant(id) => id.trait_id(db),
         }
     }
 }
@@ -890,7 +985,8 @@ define_language_element_id_as_enum! {
     /// Id for direct children of an impl.
     pub enum ImplItemId {
         Function(ImplFunctionId),
-        Type(ImplTypeId),
+        Type(ImplTypeDefId),
+        Constant(ImplConstantDefId),
     }
 }
 impl ImplItemId {
@@ -898,12 +994,14 @@ impl ImplItemId {
         match self {
             ImplItemId::Function(id) => id.name(db),
             ImplItemId::Type(id) => id.name(db),
+            ImplItemId::Constant(id) => id.name(db),
         }
     }
     pub fn impl_def_id(&self, db: &dyn DefsGroup) -> ImplDefId {
         match self {
             ImplItemId::Function(id) => id.impl_def_id(db),
             ImplItemId::Type(id) => id.impl_def_id(db),
+            ImplItemId::Constant(id) => id.impl_def_id(db),
         }
     }
 }
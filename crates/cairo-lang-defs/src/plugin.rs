@@ -2,7 +2,7 @@ use std::any::Any;
 use std::ops::Deref;
 use std::sync::Arc;
 
-use cairo_lang_diagnostics::Severity;
+use cairo_lang_diagnostics::{ErrorCode, Severity};
 use cairo_lang_filesystem::cfg::CfgSet;
 use cairo_lang_filesystem::ids::CodeMapping;
 use cairo_lang_syntax::node::ast;
@@ -61,18 +61,30 @@ pub struct PluginResult {
     pub remove_original_item: bool,
 }
 
+/// A diagnostic reported by a plugin.
+///
+/// The built-in plugins use the codes `E0500`-`E0899`: `E0500`-`E0519` for the defs crate,
+/// `E0520`-`E0559` for the basic plugins, `E0560`-`E0599` for the semantic inline macros,
+/// `E0600`-`E0699` for the test plugin and `E0700`-`E0899` for the Starknet plugin. New diagnostics
+/// must be given new codes.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct PluginDiagnostic {
     pub stable_ptr: SyntaxStablePtrId,
     pub message: String,
     pub severity: Severity,
+    /// The stable code identifying the kind of this diagnostic, if the plugin assigned one.
+    pub error_code: Option<ErrorCode>,
 }
 impl PluginDiagnostic {
     pub fn error(stable_ptr: SyntaxStablePtrId, message: String) -> PluginDiagnostic {
-        PluginDiagnostic { stable_ptr, message, severity: Severity::Error }
+        PluginDiagnostic { stable_ptr, message, severity: Severity::Error, error_code: None }
     }
     pub fn warning(stable_ptr: SyntaxStablePtrId, message: String) -> PluginDiagnostic {
-        PluginDiagnostic { stable_ptr, message, severity: Severity::Warning }
+        PluginDiagnostic { stable_ptr, message, severity: Severity::Warning, error_code: None }
+    }
+    /// Assigns a stable code to the diagnostic.
+    pub fn with_error_code(self, error_code: ErrorCode) -> PluginDiagnostic {
+        PluginDiagnostic { error_code: Some(error_code), ..self }
     }
 }
 
@@ -121,6 +133,21 @@ pub trait InlineMacroExprPlugin: std::fmt::Debug + Sync + Send {
         db: &dyn SyntaxGroup,
         item_ast: &ast::ExprInlineMacro,
     ) -> InlinePluginResult;
+
+    /// The signature of the macro, shown to users while they type its arguments. None if the
+    /// macro has no fixed form of arguments.
+    fn signature(&self) -> Option<InlineMacroSignature> {
+        None
+    }
+}
+
+/// The form of the arguments of an inline macro.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InlineMacroSignature {
+    /// The parameters of the macro, e.g. `cond: bool`.
+    pub params: Vec<&'static str>,
+    /// Whether the last parameter stands for any number of arguments.
+    pub variadic: bool,
 }
 
 /// A trait for easier addition of macro plugins.
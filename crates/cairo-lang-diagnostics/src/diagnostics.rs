@@ -8,7 +8,7 @@ use std::sync::Arc;
 use cairo_lang_debug::debug::DebugWithDb;
 use cairo_lang_filesystem::db::{get_originating_location, FilesGroup};
 use cairo_lang_filesystem::ids::FileId;
-use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_filesystem::span::{TextPosition, TextSpan};
 use cairo_lang_utils::Upcast;
 use itertools::Itertools;
 
@@ -29,6 +29,44 @@ impl std::fmt::Display for Severity {
     }
 }
 
+/// A stable identifier of a kind of diagnostic, e.g. `E0042`.
+///
+/// Valid error codes start with a capital `E` followed by 4 decimal digits. Once assigned, a code
+/// must never be reused for a different kind of diagnostic, as it is consumed by external tooling.
+/// Use the [`error_code!`](crate::error_code!) macro to construct error codes, which validates
+/// them at compile time.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct ErrorCode(&'static str);
+impl ErrorCode {
+    #[doc(hidden)]
+    pub const fn new(code: &'static str) -> Self {
+        assert!(
+            matches!(code.as_bytes(), [b'E', b'0'..=b'9', b'0'..=b'9', b'0'..=b'9', b'0'..=b'9']),
+            "Error codes must start with capital `E` followed by 4 decimal digits."
+        );
+        Self(code)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        self.0
+    }
+}
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Constructs an [ErrorCode] from a string literal, validating it at compile time.
+#[macro_export]
+macro_rules! error_code {
+    ($code:expr) => {{
+        // Evaluating in a const context forces the validation to happen at compile time.
+        const ERROR_CODE: $crate::ErrorCode = $crate::ErrorCode::new($code);
+        ERROR_CODE
+    }};
+}
+
 /// A trait for diagnostics (i.e., errors and warnings) across the compiler.
 /// Meant to be implemented by each module that may produce diagnostics.
 pub trait DiagnosticEntry: Clone + std::fmt::Debug + Eq + std::hash::Hash {
@@ -41,6 +79,10 @@ pub trait DiagnosticEntry: Clone + std::fmt::Debug + Eq + std::hash::Hash {
     fn severity(&self) -> Severity {
         Severity::Error
     }
+    /// The stable code identifying the kind of this diagnostic, if one was assigned.
+    fn error_code(&self) -> Option<ErrorCode> {
+        None
+    }
 
     // TODO(spapini): Add a way to inspect the diagnostic programmatically, e.g, downcast.
 }
@@ -62,6 +104,30 @@ impl DiagnosticLocation {
         let (file_id, span) = get_originating_location(db, self.file_id, self.span);
         Self { file_id, span }
     }
+
+    /// Resolves the location of the originating user code into a file path and positions.
+    pub fn resolve(&self, db: &dyn FilesGroup) -> ResolvedLocation {
+        let user_location = self.user_location(db);
+        let file_id = user_location.file_id;
+        ResolvedLocation {
+            file_path: file_id.full_path(db),
+            span: user_location.span,
+            start: user_location.span.start.position_in_file(db, file_id),
+            end: user_location.span.end.position_in_file(db, file_id),
+        }
+    }
+}
+
+/// A diagnostic location resolved to the originating user file, holding both the byte span and
+/// the human readable positions of its ends.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolvedLocation {
+    pub file_path: String,
+    pub span: TextSpan,
+    /// The position of the start of the span, if the file content is available.
+    pub start: Option<TextPosition>,
+    /// The position of the end of the span, if the file content is available.
+    pub end: Option<TextPosition>,
 }
 
 impl DebugWithDb<dyn FilesGroup> for DiagnosticLocation {
@@ -94,6 +160,13 @@ impl DiagnosticNote {
     }
 }
 
+/// A [DiagnosticNote] with its location resolved.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolvedNote {
+    pub text: String,
+    pub location: Option<ResolvedLocation>,
+}
+
 impl DebugWithDb<dyn FilesGroup> for DiagnosticNote {
     fn fmt(
         &self,
@@ -220,6 +293,34 @@ impl From<(Severity, String)> for FormattedDiagnosticEntry {
     }
 }
 
+/// A diagnostic with all of its data resolved, for consumers that need more than the rendered
+/// message (e.g. machine-readable reporters).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolvedDiagnostic {
+    pub severity: Severity,
+    pub error_code: Option<ErrorCode>,
+    /// The diagnostic message, without location or notes.
+    pub message: String,
+    /// The primary location, or `None` for diagnostics not related to a source location.
+    pub location: Option<ResolvedLocation>,
+    pub notes: Vec<ResolvedNote>,
+    /// The human readable rendering of the diagnostic, as in [FormattedDiagnosticEntry].
+    pub formatted: String,
+}
+impl ResolvedDiagnostic {
+    /// Creates a diagnostic that is not related to any source location.
+    pub fn without_location(severity: Severity, message: String) -> Self {
+        Self {
+            severity,
+            error_code: None,
+            formatted: message.clone(),
+            message,
+            location: None,
+            notes: vec![],
+        }
+    }
+}
+
 /// A set of diagnostic entries that arose during a computation.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Diagnostics<TEntry: DiagnosticEntry>(pub Arc<DiagnosticsBuilder<TEntry>>);
@@ -235,24 +336,59 @@ impl<TEntry: DiagnosticEntry> Diagnostics<TEntry> {
 
     /// Format entries to pairs of severity and message.
     pub fn format_with_severity(&self, db: &TEntry::DbType) -> Vec<FormattedDiagnosticEntry> {
-        let mut res: Vec<FormattedDiagnosticEntry> = Vec::new();
+        let mut res = self.format_leaves_with_severity(db);
+        // Format subtrees.
+        res.extend(self.0.subtrees.iter().flat_map(|subtree| subtree.format_with_severity(db)));
+        res
+    }
 
+    /// Resolves all entries, including their locations and notes, in the same order as
+    /// [Self::format_with_severity].
+    pub fn resolve(&self, db: &TEntry::DbType) -> Vec<ResolvedDiagnostic> {
         let files_db = db.upcast();
-        // Format leaves.
-        for entry in &self.0.leaves {
-            let mut msg = String::new();
-            msg += &format_diagnostics(files_db, &entry.format(db), entry.location(db));
-            for note in entry.notes(db) {
-                msg += &format!("note: {:?}\n", note.debug(files_db))
-            }
-            msg += "\n";
-            res.push((entry.severity(), msg).into());
+        let mut res: Vec<ResolvedDiagnostic> = Vec::new();
+        for (entry, formatted) in
+            self.0.leaves.iter().zip(self.format_leaves_with_severity(db).into_iter())
+        {
+            let notes = entry
+                .notes(db)
+                .iter()
+                .map(|note| ResolvedNote {
+                    text: note.text.clone(),
+                    location: note.location.as_ref().map(|location| location.resolve(files_db)),
+                })
+                .collect();
+            res.push(ResolvedDiagnostic {
+                severity: entry.severity(),
+                error_code: entry.error_code(),
+                message: entry.format(db),
+                location: Some(entry.location(db).resolve(files_db)),
+                notes,
+                formatted: formatted.message().to_string(),
+            });
         }
-        // Format subtrees.
-        res.extend(self.0.subtrees.iter().flat_map(|subtree| subtree.format_with_severity(db)));
+        res.extend(self.0.subtrees.iter().flat_map(|subtree| subtree.resolve(db)));
         res
     }
 
+    /// Format the leaves of this diagnostics tree to pairs of severity and message.
+    fn format_leaves_with_severity(&self, db: &TEntry::DbType) -> Vec<FormattedDiagnosticEntry> {
+        let files_db = db.upcast();
+        self.0
+            .leaves
+            .iter()
+            .map(|entry| {
+                let mut msg = String::new();
+                msg += &format_diagnostics(files_db, &entry.format(db), entry.location(db));
+                for note in entry.notes(db) {
+                    msg += &format!("note: {:?}\n", note.debug(files_db))
+                }
+                msg += "\n";
+                (entry.severity(), msg).into()
+            })
+            .collect()
+    }
+
     /// Format entries to a String with messages prefixed by severity.
     pub fn format(&self, db: &TEntry::DbType) -> String {
         self.format_with_severity(db)
@@ -2,12 +2,16 @@ use std::sync::Arc;
 
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::{FileId, FileKind, FileLongId, VirtualFile};
-use cairo_lang_filesystem::span::{TextOffset, TextSpan, TextWidth};
+use cairo_lang_filesystem::span::{TextOffset, TextPosition, TextSpan, TextWidth};
 use cairo_lang_filesystem::test_utils::FilesDatabaseForTesting;
 use indoc::indoc;
 use test_log::test;
 
-use super::{DiagnosticEntry, DiagnosticLocation, DiagnosticsBuilder};
+use super::{
+    DiagnosticEntry, DiagnosticLocation, DiagnosticsBuilder, ErrorCode, ResolvedDiagnostic,
+    ResolvedLocation, Severity,
+};
+use crate::error_code;
 
 // Test diagnostic.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -30,6 +34,10 @@ impl DiagnosticEntry for SimpleDiag {
             },
         }
     }
+
+    fn error_code(&self) -> Option<ErrorCode> {
+        Some(error_code!("E0001"))
+    }
 }
 
 fn setup() -> (FilesDatabaseForTesting, FileId) {
@@ -63,3 +71,38 @@ fn test_diagnostics() {
         " }
     );
 }
+
+#[test]
+fn test_resolve_diagnostics() {
+    let (db_val, file_id) = setup();
+
+    let mut diagnostics: DiagnosticsBuilder<SimpleDiag> = DiagnosticsBuilder::default();
+    diagnostics.add(SimpleDiag { file_id });
+
+    assert_eq!(
+        diagnostics.build().resolve(&db_val),
+        vec![ResolvedDiagnostic {
+            severity: Severity::Error,
+            error_code: Some(error_code!("E0001")),
+            message: "Simple diagnostic.".into(),
+            location: Some(ResolvedLocation {
+                file_path: "dummy_file.sierra".into(),
+                span: TextSpan {
+                    start: TextOffset::default(),
+                    end: TextOffset::default().add_width(TextWidth::new_for_testing(6)),
+                },
+                start: Some(TextPosition { line: 0, col: 0 }),
+                end: Some(TextPosition { line: 1, col: 1 }),
+            }),
+            notes: vec![],
+            formatted: indoc! { "
+                Simple diagnostic.
+                 --> dummy_file.sierra:1:1
+                abcd
+                ^**^
+
+            " }
+            .into(),
+        }]
+    );
+}
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -43,6 +44,34 @@ pub struct CrateSettings {
 
     #[serde(default)]
     pub experimental_features: ExperimentalFeaturesConfig,
+
+    /// The default levels of lints in the crate, by lint name. May be overridden by `#[allow]`,
+    /// `#[warn]` and `#[deny]` attributes in the code.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub lints: BTreeMap<String, LintLevel>,
+}
+
+/// The level of a lint, controlling how its diagnostics are reported.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    /// The lint diagnostics are not reported.
+    Allow,
+    /// The lint diagnostics are reported as warnings.
+    Warn,
+    /// The lint diagnostics are reported as errors.
+    Deny,
+}
+impl LintLevel {
+    /// Returns the level named by the given attribute or command line name, if any.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "allow" => Some(Self::Allow),
+            "warn" => Some(Self::Warn),
+            "deny" => Some(Self::Deny),
+            _ => None,
+        }
+    }
 }
 
 /// The Cairo edition of a crate.
@@ -157,6 +186,7 @@ pub fn init_dev_corelib(db: &mut (dyn FilesGroup + 'static), core_lib_dir: PathB
                 edition: Edition::V2023_11,
                 cfg_set: Default::default(),
                 experimental_features: ExperimentalFeaturesConfig { negative_impls: true },
+                lints: Default::default(),
             },
         }),
     );
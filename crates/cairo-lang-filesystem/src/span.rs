@@ -23,6 +23,10 @@ impl TextWidth {
     pub fn new_for_testing(value: u32) -> Self {
         Self(value)
     }
+    /// Returns the width in bytes.
+    pub fn as_u32(self) -> u32 {
+        self.0
+    }
 }
 impl Add for TextWidth {
     type Output = Self;
@@ -57,6 +61,10 @@ impl TextOffset {
     pub fn take_from<'a>(&self, content: &'a str) -> &'a str {
         &content[(self.0.0 as usize)..]
     }
+    /// Returns the offset in bytes from the beginning of the content.
+    pub fn as_u32(self) -> u32 {
+        self.0.as_u32()
+    }
 }
 impl Sub for TextOffset {
     type Output = TextWidth;
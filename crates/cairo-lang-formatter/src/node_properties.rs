@@ -31,7 +31,7 @@ impl SyntaxNodeFormat for SyntaxNode {
                 if matches!(grandparent_kind(db, self), Some(SyntaxKind::FunctionSignature))
                     | matches!(
                         grandparent_kind(db, self),
-                        Some(SyntaxKind::VisibilityPubArgumentClause)
+                        Some(SyntaxKind::VisibilityPubArgumentClause | SyntaxKind::StructBodyTuple)
                     ) =>
             {
                 true
@@ -56,6 +56,12 @@ impl SyntaxNodeFormat for SyntaxNode {
             {
                 true
             }
+            SyntaxKind::TokenDotDot | SyntaxKind::TokenDotDotEq
+                if grandparent_kind(db, self) == Some(SyntaxKind::ExprBinary) =>
+            {
+                true
+            }
+            SyntaxKind::TokenOr if is_closure_bar(db, self, ClosureBar::Right) => true,
             SyntaxKind::TokenLT | SyntaxKind::TokenGT
                 if matches!(
                     grandparent_kind(db, self),
@@ -91,6 +97,7 @@ impl SyntaxNodeFormat for SyntaxNode {
                             | SyntaxKind::ItemExternFunction
                             | SyntaxKind::ExprFunctionCall
                             | SyntaxKind::PatternStruct
+                            | SyntaxKind::PatternTupleStruct
                             | SyntaxKind::Attribute
                     )
                 ) =>
@@ -138,10 +145,19 @@ impl SyntaxNodeFormat for SyntaxNode {
                 true
             }
             SyntaxKind::TokenDotDot
-                if grandparent_kind(db, self) == Some(SyntaxKind::StructArgTail) =>
+                if matches!(
+                    grandparent_kind(db, self),
+                    Some(SyntaxKind::StructArgTail | SyntaxKind::ExprBinary)
+                ) =>
+            {
+                true
+            }
+            SyntaxKind::TokenDotDotEq
+                if grandparent_kind(db, self) == Some(SyntaxKind::ExprBinary) =>
             {
                 true
             }
+            SyntaxKind::TokenOr if is_closure_bar(db, self, ClosureBar::Left) => true,
             _ => false,
         }
     }
@@ -206,7 +222,7 @@ impl SyntaxNodeFormat for SyntaxNode {
             },
             Some(SyntaxKind::ItemStruct) => match self.kind(db) {
                 SyntaxKind::AttributeList => Some(1),
-                SyntaxKind::MemberList => Some(2),
+                SyntaxKind::StructBodyNamed | SyntaxKind::StructBodyTuple => Some(2),
                 SyntaxKind::WrappedGenericParamList => Some(3),
                 _ => None,
             },
@@ -223,7 +239,7 @@ impl SyntaxNodeFormat for SyntaxNode {
                 SyntaxKind::ExprPath => Some(3),
                 _ => None,
             },
-            Some(SyntaxKind::ExprWhile) => match self.kind(db) {
+            Some(SyntaxKind::ExprWhile | SyntaxKind::ExprFor) => match self.kind(db) {
                 SyntaxKind::ExprBlock => Some(1),
                 SyntaxKind::ExprBinary
                 | SyntaxKind::ExprErrorPropagate
@@ -285,6 +301,7 @@ impl SyntaxNodeFormat for SyntaxNode {
                 | SyntaxKind::ArgListBraced
                 | SyntaxKind::ArgListBracketed
                 | SyntaxKind::ExprUnary => Some(1),
+                SyntaxKind::LetElseClause => Some(2),
                 SyntaxKind::TerminalEq => Some(10),
                 SyntaxKind::PatternEnum | SyntaxKind::PatternTuple | SyntaxKind::PatternStruct => {
                     Some(11)
@@ -310,6 +327,7 @@ impl SyntaxNodeFormat for SyntaxNode {
                 | SyntaxKind::ImplicitsList
                 | SyntaxKind::ImplicitsClause
                 | SyntaxKind::MemberList
+                | SyntaxKind::TupleMemberList
                 | SyntaxKind::VariantList
                 | SyntaxKind::ArgList
                 | SyntaxKind::Arg
@@ -375,6 +393,7 @@ impl SyntaxNodeFormat for SyntaxNode {
             }
             _ => match self.kind(db) {
                 SyntaxKind::ParamList
+                | SyntaxKind::TupleMemberList
                 | SyntaxKind::ExprList
                 | SyntaxKind::ImplicitsList
                 | SyntaxKind::PatternList => {
@@ -484,7 +503,9 @@ impl SyntaxNodeFormat for SyntaxNode {
                         true,
                     ))
                 }
-                SyntaxKind::TerminalMul if parent_kind(db, self) != Some(SyntaxKind::ExprUnary) => {
+                SyntaxKind::TerminalMul
+                    if parent_kind(db, self) == Some(SyntaxKind::ExprBinary) =>
+                {
                     BreakLinePointsPositions::Leading(BreakLinePointProperties::new(
                         9,
                         BreakLinePointIndentation::Indented,
@@ -508,7 +529,9 @@ impl SyntaxNodeFormat for SyntaxNode {
                         true,
                     ))
                 }
-                SyntaxKind::TerminalOrOr => {
+                SyntaxKind::TerminalOrOr
+                    if parent_kind(db, self) == Some(SyntaxKind::ExprBinary) =>
+                {
                     BreakLinePointsPositions::Leading(BreakLinePointProperties::new(
                         11,
                         BreakLinePointIndentation::Indented,
@@ -524,9 +547,7 @@ impl SyntaxNodeFormat for SyntaxNode {
                         true,
                     ))
                 }
-                SyntaxKind::TerminalOr
-                    if parent_kind(db, self) != Some(SyntaxKind::PatternListOr) =>
-                {
+                SyntaxKind::TerminalOr if parent_kind(db, self) == Some(SyntaxKind::ExprBinary) => {
                     BreakLinePointsPositions::Leading(BreakLinePointProperties::new(
                         13,
                         BreakLinePointIndentation::Indented,
@@ -574,6 +595,8 @@ impl SyntaxNodeFormat for SyntaxNode {
         match self.kind(db) {
             SyntaxKind::ImplicitsList
             | SyntaxKind::ParamList
+            | SyntaxKind::TupleMemberList
+            | SyntaxKind::ClosureParamList
             | SyntaxKind::PatternList
             | SyntaxKind::PatternStructParamList
             | SyntaxKind::StructArgList
@@ -649,3 +672,23 @@ fn is_statement_list_break_point_optional(db: &dyn SyntaxGroup, node: &SyntaxNod
                     .all(|t| !matches!(t, ast::Trivium::SingleLineComment(_)))
         })
 }
+
+/// The bars wrapping the parameters of a closure.
+enum ClosureBar {
+    Left,
+    Right,
+}
+
+/// Returns true if the given token is the given bar of a closure parameter list.
+fn is_closure_bar(db: &dyn SyntaxGroup, node: &SyntaxNode, bar: ClosureBar) -> bool {
+    if grandparent_kind(db, node) != Some(SyntaxKind::ClosureParamWrapperNAry) {
+        return false;
+    }
+    let terminal = node.parent().unwrap();
+    let wrapper = ast::ClosureParamWrapperNAry::from_syntax_node(db, terminal.parent().unwrap());
+    let bar_ptr = match bar {
+        ClosureBar::Left => wrapper.leftor(db).stable_ptr().untyped(),
+        ClosureBar::Right => wrapper.rightor(db).stable_ptr().untyped(),
+    };
+    terminal.stable_ptr() == bar_ptr
+}
@@ -1,36 +1,49 @@
-
 // try
-                // try2
-fn foo (x:T) -> S { let x = -    5 + 3; let y : T = x         * 2 + 3-5;// Comment.
-
-
-
-let z = 5; z +=4; 5 + Struct{a: 5, b: Struct2{_gg: ()}}; let df =6;{let k = 1; let z = 1; let block={y+1; x+5};}
-
- { // Comment.
-
-
-
-   6;
-
-
-// 445.
-
+// try2
+fn foo(x: T) -> S {
+    let x = -5 + 3;
+    let y: T = x * 2 + 3 - 5; // Comment.
+
+    let z = 5;
+    z += 4;
+    5 + Struct { a: 5, b: Struct2 { _gg: () } };
+    let df = 6;
+    {
+        let k = 1;
+        let z = 1;
+        let block = {
+            y + 1;
+            x + 5
+        };
+    }
 
+    { // Comment.
+        6;
 
-   5; // Comment.
+        // 445.
 
-   } // Comment.
+        5; // Comment.
+    } // Comment.
 
-   // A long comment that shouldn't affect the breaking of the next line. Make it a little longer.
+    // A long comment that shouldn't affect the breaking of the next line. Make it a little longer.
     let var = 1 + 2 + 3;
 }
 
-fn bar < T > (x:T)->T{
-let x:T=1;}
+fn bar<T>(x: T) -> T {
+    let x: T = 1;
+}
 
 
-struct A{} struct B{}
+struct A {}
+struct B {}
+struct C(u8, pub felt252);
+struct D;
+struct E<T>(T,);
+fn tuple_structs(c: C) -> u8 {
+    let C(x, _) = c;
+    let D = D;
+    x
+}
 
 // Calculates fib, but all variables are boxes.
 fn fib(a: Box::<felt252>, b: Box::<felt252>, n: Box::<felt252>) -> Box::<felt252> {
@@ -40,7 +53,6 @@ fn fib(a: Box::<felt252>, b: Box::<felt252>, n: Box::<felt252>) -> Box::<felt252
             fib(
                 b,
                 into_box::<felt252>(unbox::<felt252>(a) + unbox::<felt252>(b)),
-
                 into_box::<felt252>(unbox::<felt252>(n) - 1),
             )
         },
@@ -48,7 +60,30 @@ fn fib(a: Box::<felt252>, b: Box::<felt252>, n: Box::<felt252>) -> Box::<felt252
 }
 
 fn if_let_test() {
-    if let (x, y) = (x + x + x + x + x + x + x + x + x + x + x + x + x + x + x + x + x + x,y + y + y + y + y + y) {
+    if let (x, y) =
+        (
+            x + x + x + x + x + x + x + x + x + x + x + x + x + x + x + x + x + x,
+            y + y + y + y + y + y
+        ) {
         if_let_block_content();
     }
-}
\ No newline at end of file
+}
+
+fn closures() {
+    let add = |x, y| x + y;
+    let typed = |x: u32, (a, b): (u32, u32)| -> u32 {
+        x + a + b
+    };
+    call(|| 5, |x| x || false);
+}
+
+fn let_else() {
+    let Option::Some(x) = foo() else {
+        return;
+    };
+    let (Option::Some(a), b) = bar() else {
+        panic_with_felt252('bar')
+    };
+}
+use a::*;
+use b::{c, *};
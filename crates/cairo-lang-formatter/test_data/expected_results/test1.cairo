@@ -36,6 +36,14 @@ fn bar<T>(x: T) -> T {
 
 struct A {}
 struct B {}
+struct C(u8, pub felt252);
+struct D;
+struct E<T>(T,);
+fn tuple_structs(c: C) -> u8 {
+    let C(x, _) = c;
+    let D = D;
+    x
+}
 
 // Calculates fib, but all variables are boxes.
 fn fib(a: Box::<felt252>, b: Box::<felt252>, n: Box::<felt252>) -> Box::<felt252> {
@@ -60,3 +68,22 @@ fn if_let_test() {
         if_let_block_content();
     }
 }
+
+fn closures() {
+    let add = |x, y| x + y;
+    let typed = |x: u32, (a, b): (u32, u32)| -> u32 {
+        x + a + b
+    };
+    call(|| 5, |x| x || false);
+}
+
+fn let_else() {
+    let Option::Some(x) = foo() else {
+        return;
+    };
+    let (Option::Some(a), b) = bar() else {
+        panic_with_felt252('bar')
+    };
+}
+use a::*;
+use b::{c, *};
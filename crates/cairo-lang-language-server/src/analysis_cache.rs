@@ -0,0 +1,259 @@
+//! An optional on-disk cache of the analysis results of the corelib and dependency crates.
+//!
+//! These crates are not edited in the workspace, so their results only change when their sources
+//! do. For each crate, the cache holds its diagnostics and the index of its items (the parsed and
+//! defs-level results the workspace symbol search is built from). Serving them from the cache
+//! spares parsing and analysing (and in particular lowering) the crates on every cold start. Their
+//! items are only analysed on demand, when a request needs them (e.g. hover or go to definition).
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use cairo_lang_filesystem::db::{FilesGroup, CORELIB_CRATE_NAME};
+use cairo_lang_filesystem::ids::{CrateId, CrateLongId, Directory};
+use log::warn;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+use tower_lsp::lsp_types::{Diagnostic, SymbolInformation, Url};
+
+#[cfg(test)]
+#[path = "analysis_cache_test.rs"]
+mod test;
+
+/// The environment variable enabling the cache, holding the directory it is stored in.
+const CACHE_DIR_ENV: &str = "CAIRO_LSP_CACHE_DIR";
+const CACHE_FILE_NAME: &str = "analysis-cache.json";
+/// The version of the format of the cache file. Must be bumped whenever the format, or the way
+/// fingerprints are computed, changes.
+const CACHE_FORMAT_VERSION: u32 = 2;
+
+/// The diagnostics of the files of a crate.
+pub type CrateDiagnostics = Vec<(Url, Vec<Diagnostic>)>;
+
+/// A fingerprint of the inputs of an analysis: the hex encoded SHA3-256 hash of their bytes.
+pub type Fingerprint = String;
+
+/// The persisted content of the cache.
+#[derive(Default, Serialize, Deserialize)]
+struct CacheFile {
+    /// The version of the format of the file.
+    format_version: u32,
+    /// The version of the compiler that produced the cached results.
+    version: String,
+    crates: HashMap<String, CrateEntry>,
+}
+
+/// The cached results of a crate.
+#[derive(Clone, Serialize, Deserialize)]
+struct CrateEntry {
+    /// The fingerprint of the crate sources the results were computed from.
+    fingerprint: Fingerprint,
+    diagnostics: CrateDiagnostics,
+    /// The items of the crate, as found by the workspace symbol search.
+    symbols: Vec<SymbolInformation>,
+}
+
+#[derive(Clone, Default)]
+pub struct AnalysisCache {
+    path: PathBuf,
+    crates: HashMap<String, CrateEntry>,
+    /// The fingerprints of the crates, by settings fingerprint and crate, computed once per
+    /// session. Shared between the copies of the cache the requests work on.
+    fingerprints: Arc<Mutex<HashMap<(Fingerprint, String), Fingerprint>>>,
+    /// The crates whose cached diagnostics the client already has.
+    served: HashSet<String>,
+    /// The fingerprint of the server settings the results depend on.
+    settings_fingerprint: Fingerprint,
+    dirty: bool,
+}
+impl AnalysisCache {
+    /// Loads the cache from the directory set in `CAIRO_LSP_CACHE_DIR`, if set.
+    pub fn from_env() -> Option<Self> {
+        let dir = PathBuf::from(std::env::var_os(CACHE_DIR_ENV)?);
+        Some(Self::load(&dir))
+    }
+
+    /// Loads the cache stored in a directory. Missing, corrupt or outdated caches are empty.
+    pub fn load(dir: &Path) -> Self {
+        let path = dir.join(CACHE_FILE_NAME);
+        let crates = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|cache_file| {
+                cache_file.format_version == CACHE_FORMAT_VERSION
+                    && cache_file.version == env!("CARGO_PKG_VERSION")
+            })
+            .map(|cache_file| cache_file.crates)
+            .unwrap_or_default();
+        Self { path, crates, ..Self::default() }
+    }
+
+    /// Writes the cache to disk, if it changed.
+    pub fn save(&mut self) {
+        if !self.dirty {
+            return;
+        }
+        let cache_file = CacheFile {
+            format_version: CACHE_FORMAT_VERSION,
+            version: env!("CARGO_PKG_VERSION").into(),
+            crates: self.crates.clone(),
+        };
+        let result = self
+            .path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| std::fs::write(&self.path, serde_json::to_vec(&cache_file)?));
+        match result {
+            Ok(()) => self.dirty = false,
+            Err(err) => warn!("Failed to write the analysis cache: {err:?}"),
+        }
+    }
+
+    /// Returns the fingerprint of the server settings the results depend on.
+    pub fn settings_fingerprint(&self) -> &Fingerprint {
+        &self.settings_fingerprint
+    }
+
+    /// Sets the fingerprint of the server settings the results depend on. Results computed under
+    /// other settings are outdated.
+    pub fn set_settings_fingerprint(&mut self, settings_fingerprint: Fingerprint) {
+        if self.settings_fingerprint != settings_fingerprint {
+            self.settings_fingerprint = settings_fingerprint;
+            self.served.clear();
+        }
+    }
+
+    /// Returns whether the results of a crate may be cached: the crate must be on disk, and must
+    /// not contain any of the given editable roots (the workspace folders and open files), nor be
+    /// contained in one of them.
+    pub fn is_cacheable(
+        db: &dyn FilesGroup,
+        crate_id: CrateId,
+        editable_roots: &[PathBuf],
+    ) -> bool {
+        let Some(root) = crate_root(db, crate_id) else {
+            return false;
+        };
+        !editable_roots.iter().any(|editable_root| {
+            editable_root.starts_with(&root) || root.starts_with(editable_root)
+        })
+    }
+
+    /// Returns the cached diagnostics of a crate, if they are up to date.
+    pub fn get(&mut self, db: &dyn FilesGroup, crate_id: CrateId) -> Option<CrateDiagnostics> {
+        let key = crate_key(db, crate_id)?;
+        let diagnostics = self.entry(db, crate_id)?.diagnostics.clone();
+        self.served.insert(key);
+        Some(diagnostics)
+    }
+
+    /// Returns the cached items of a crate, if they are up to date.
+    pub fn get_symbols(
+        &self,
+        db: &dyn FilesGroup,
+        crate_id: CrateId,
+    ) -> Option<Vec<SymbolInformation>> {
+        Some(self.entry(db, crate_id)?.symbols.clone())
+    }
+
+    /// Returns whether the client already has the cached diagnostics of a crate.
+    pub fn is_served(&self, db: &dyn FilesGroup, crate_id: CrateId) -> bool {
+        crate_key(db, crate_id).map_or(false, |key| self.served.contains(&key))
+    }
+
+    /// Caches the results of a crate. Its diagnostics were just published to the client.
+    pub fn insert(
+        &mut self,
+        db: &dyn FilesGroup,
+        crate_id: CrateId,
+        diagnostics: CrateDiagnostics,
+        symbols: Vec<SymbolInformation>,
+    ) {
+        let (Some(key), Some(fingerprint)) =
+            (crate_key(db, crate_id), self.fingerprint(db, crate_id))
+        else {
+            return;
+        };
+        self.served.insert(key.clone());
+        self.crates.insert(key, CrateEntry { fingerprint, diagnostics, symbols });
+        self.dirty = true;
+    }
+
+    /// Returns the cached results of a crate, if they are up to date.
+    fn entry(&self, db: &dyn FilesGroup, crate_id: CrateId) -> Option<&CrateEntry> {
+        let fingerprint = self.fingerprint(db, crate_id)?;
+        self.crates.get(&crate_key(db, crate_id)?).filter(|entry| entry.fingerprint == fingerprint)
+    }
+
+    /// Computes the fingerprint of the inputs of a crate's analysis: its sources, and those of the
+    /// corelib, along with the crate and server settings.
+    fn fingerprint(&self, db: &dyn FilesGroup, crate_id: CrateId) -> Option<Fingerprint> {
+        let key = (self.settings_fingerprint.clone(), crate_key(db, crate_id)?);
+        if let Some(fingerprint) = self.fingerprints.lock().unwrap().get(&key) {
+            return Some(fingerprint.clone());
+        }
+        let config = db.crate_config(crate_id)?;
+        let mut hasher = FingerprintHasher::default();
+        hasher.update(self.settings_fingerprint.as_bytes());
+        hasher.update(serde_json::to_string(&config.settings).ok()?.as_bytes());
+        hash_sources(&crate_root(db, crate_id)?, &mut hasher).ok()?;
+        let core = db.intern_crate(CrateLongId::Real(CORELIB_CRATE_NAME.into()));
+        if core != crate_id {
+            hasher.update(self.fingerprint(db, core)?.as_bytes());
+        }
+        let fingerprint = hasher.finish();
+        self.fingerprints.lock().unwrap().insert(key, fingerprint.clone());
+        Some(fingerprint)
+    }
+}
+
+/// Computes a fingerprint of a sequence of byte strings.
+#[derive(Default)]
+pub struct FingerprintHasher(Sha3_256);
+impl FingerprintHasher {
+    /// Adds a byte string. It is length prefixed, so that the boundaries between the byte strings
+    /// are part of the fingerprint.
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.0.update((bytes.len() as u64).to_le_bytes());
+        self.0.update(bytes);
+    }
+
+    pub fn finish(self) -> Fingerprint {
+        self.0.finalize().iter().fold(String::new(), |mut fingerprint, byte| {
+            write!(fingerprint, "{byte:02x}").unwrap();
+            fingerprint
+        })
+    }
+}
+
+/// Returns the root directory of a crate, if it is on disk.
+fn crate_root(db: &dyn FilesGroup, crate_id: CrateId) -> Option<PathBuf> {
+    match db.crate_config(crate_id)?.root {
+        Directory::Real(root) => Some(root),
+        Directory::Virtual { .. } => None,
+    }
+}
+
+/// Returns the key identifying a crate in the cache.
+fn crate_key(db: &dyn FilesGroup, crate_id: CrateId) -> Option<String> {
+    Some(format!("{}@{}", crate_id.name(db), crate_root(db, crate_id)?.display()))
+}
+
+/// Hashes the paths and contents of the Cairo files in a directory, recursively.
+fn hash_sources(dir: &Path, hasher: &mut FingerprintHasher) -> std::io::Result<()> {
+    let mut entries = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            hash_sources(&path, hasher)?;
+        } else if path.extension().map_or(false, |extension| extension == "cairo") {
+            hasher.update(path.to_string_lossy().as_bytes());
+            hasher.update(&std::fs::read(&path)?);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,131 @@
+use std::path::PathBuf;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_filesystem::db::{CrateConfiguration, FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::ids::{CrateId, CrateLongId, Directory};
+use pretty_assertions::assert_eq;
+use tower_lsp::lsp_types::{Diagnostic, Location, Range, SymbolInformation, SymbolKind, Url};
+
+use super::{AnalysisCache, CrateDiagnostics, FingerprintHasher, CACHE_FILE_NAME};
+
+/// A crate on disk, along with the directory its analysis cache is stored in.
+struct TestCrate {
+    dir: PathBuf,
+    db: RootDatabase,
+    crate_id: CrateId,
+}
+impl TestCrate {
+    fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir()
+            .join(format!("cairo-lang-ls-analysis-cache-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("src").join("lib.cairo"), "fn foo() {}\n").unwrap();
+        let mut db = RootDatabase::builder().detect_corelib().build().unwrap();
+        let crate_id = db.intern_crate(CrateLongId::Real("dep".into()));
+        db.set_crate_config(
+            crate_id,
+            Some(CrateConfiguration::default_for_root(Directory::Real(dir.join("src")))),
+        );
+        Self { dir, db, crate_id }
+    }
+
+    fn cache_dir(&self) -> PathBuf {
+        self.dir.join("cache")
+    }
+
+    /// Loads the cache from disk, under the given settings fingerprint.
+    fn load_cache(&self, settings_fingerprint: &str) -> AnalysisCache {
+        let mut cache = AnalysisCache::load(&self.cache_dir());
+        cache.set_settings_fingerprint(settings_fingerprint.into());
+        cache
+    }
+
+    /// Caches the results of the crate, and writes the cache to disk.
+    fn save_results(&self) {
+        let mut cache = self.load_cache("settings");
+        cache.insert(&self.db, self.crate_id, diagnostics(), symbols());
+        cache.save();
+    }
+}
+impl Drop for TestCrate {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir);
+    }
+}
+
+fn uri() -> Url {
+    Url::parse("file:///dep/src/lib.cairo").unwrap()
+}
+
+fn diagnostics() -> CrateDiagnostics {
+    vec![(uri(), vec![Diagnostic { message: "Unused variable.".into(), ..Diagnostic::default() }])]
+}
+
+#[allow(deprecated)]
+fn symbols() -> Vec<SymbolInformation> {
+    vec![SymbolInformation {
+        name: "foo".into(),
+        kind: SymbolKind::FUNCTION,
+        tags: None,
+        deprecated: None,
+        location: Location { uri: uri(), range: Range::default() },
+        container_name: Some("dep".into()),
+    }]
+}
+
+#[test]
+fn test_cached_results() {
+    let test_crate = TestCrate::new("cached");
+    test_crate.save_results();
+
+    let mut cache = test_crate.load_cache("settings");
+    assert_eq!(cache.get_symbols(&test_crate.db, test_crate.crate_id), Some(symbols()));
+    assert!(!cache.is_served(&test_crate.db, test_crate.crate_id));
+    assert_eq!(cache.get(&test_crate.db, test_crate.crate_id), Some(diagnostics()));
+    assert!(cache.is_served(&test_crate.db, test_crate.crate_id));
+}
+
+#[test]
+fn test_changed_sources() {
+    let test_crate = TestCrate::new("changed-sources");
+    test_crate.save_results();
+    std::fs::write(test_crate.dir.join("src").join("lib.cairo"), "fn bar() {}\n").unwrap();
+
+    let mut cache = test_crate.load_cache("settings");
+    assert_eq!(cache.get_symbols(&test_crate.db, test_crate.crate_id), None);
+    assert_eq!(cache.get(&test_crate.db, test_crate.crate_id), None);
+}
+
+#[test]
+fn test_changed_settings() {
+    let test_crate = TestCrate::new("changed-settings");
+    test_crate.save_results();
+
+    let mut cache = test_crate.load_cache("other settings");
+    assert_eq!(cache.get_symbols(&test_crate.db, test_crate.crate_id), None);
+    assert_eq!(cache.get(&test_crate.db, test_crate.crate_id), None);
+}
+
+#[test]
+fn test_other_format_version() {
+    let test_crate = TestCrate::new("format-version");
+    test_crate.save_results();
+    let path = test_crate.cache_dir().join(CACHE_FILE_NAME);
+    let content = std::fs::read_to_string(&path).unwrap();
+    let mut cache_file: serde_json::Value = serde_json::from_str(&content).unwrap();
+    cache_file["format_version"] = 1.into();
+    std::fs::write(&path, cache_file.to_string()).unwrap();
+
+    let mut cache = test_crate.load_cache("settings");
+    assert_eq!(cache.get(&test_crate.db, test_crate.crate_id), None);
+}
+
+#[test]
+fn test_fingerprint() {
+    // The fingerprints are persisted, so they must not change between builds of the server.
+    let mut hasher = FingerprintHasher::default();
+    hasher.update(b"cairo");
+    hasher.update(b"");
+    assert_eq!(hasher.finish(), "2f18ca8b7e9e4a7385c8c01a1497112e12999f1ca3afdbf63611ee1446495ed0");
+}
@@ -0,0 +1,723 @@
+//! Quick fixes for the diagnostics of a file.
+
+use std::collections::HashMap;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::ids::{
+    FunctionWithBodyId, ImplItemId, LanguageElementId, LookupItemId, ModuleId, ModuleItemId,
+    TraitId,
+};
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_lowering::diagnostic::{
+    LoweringDiagnosticKind, MatchDiagnostic, MatchError, MatchKind,
+};
+use cairo_lang_semantic::corelib::{core_crate, unit_ty};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::diagnostic::SemanticDiagnosticKind;
+use cairo_lang_semantic::expr::inference::InferenceError;
+use cairo_lang_semantic::items::enm::SemanticEnumEx;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::items::visibility::{peek_visible_in, Visibility};
+use cairo_lang_semantic::resolve::{extract_edition, ignore_visibility_checks};
+use cairo_lang_semantic::{ConcreteTypeId, Expr, GenericArgumentId, Pattern, TypeId, TypeLongId};
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+use tower_lsp::lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, Diagnostic, Range, TextEdit, WorkspaceEdit,
+};
+
+use crate::references::{is_generated_file, lsp_range, SymbolLocation};
+use crate::{find_node_module, get_lookup_items, get_uri};
+
+#[cfg(test)]
+#[path = "code_actions_test.rs"]
+mod test;
+
+/// The body of generated code the user is expected to fill in.
+const STUB_BODY: &str = "panic!(\"Not implemented.\")";
+
+/// A titled set of edits fixing a diagnostic, possibly spanning several files.
+struct QuickFix {
+    title: String,
+    edits: Vec<(FileId, TextEdit)>,
+    is_preferred: bool,
+}
+
+/// Returns the quick fixes for the diagnostics of a file that overlap the given range.
+pub fn code_actions(
+    db: &RootDatabase,
+    file: FileId,
+    range: Range,
+    context_diagnostics: &[Diagnostic],
+) -> Vec<CodeActionOrCommand> {
+    quick_fixes(db, file, range)
+        .into_iter()
+        .map(|(diagnostic_range, fix)| {
+            let mut changes = HashMap::<_, Vec<TextEdit>>::new();
+            for (edit_file, edit) in fix.edits {
+                changes.entry(get_uri(db.upcast(), edit_file)).or_default().push(edit);
+            }
+            let diagnostics = context_diagnostics
+                .iter()
+                .filter(|diagnostic| diagnostic.range.start == diagnostic_range.start)
+                .cloned()
+                .collect::<Vec<_>>();
+            CodeActionOrCommand::CodeAction(CodeAction {
+                title: fix.title,
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: (!diagnostics.is_empty()).then_some(diagnostics),
+                edit: Some(WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() }),
+                is_preferred: fix.is_preferred.then_some(true),
+                ..CodeAction::default()
+            })
+        })
+        .collect()
+}
+
+/// Returns the quick fixes for the diagnostics of a file that overlap the given range, along with
+/// the range of the diagnostic each one fixes.
+fn quick_fixes(db: &RootDatabase, file: FileId, range: Range) -> Vec<(Range, QuickFix)> {
+    let mut fixes = vec![];
+
+    let semantic_diagnostics = db.file_semantic_diagnostics(file).unwrap_or_default();
+    let semantic_diagnostics = semantic_diagnostics.get_all();
+    for diagnostic in semantic_diagnostics.iter() {
+        let location = diagnostic.stable_location;
+        let Some(diagnostic_range) = location_range(db, file, location) else {
+            continue;
+        };
+        if !overlaps(diagnostic_range, range) {
+            continue;
+        }
+        let diagnostic_fixes = match &diagnostic.kind {
+            SemanticDiagnosticKind::UnusedVariable => {
+                prefix_unused_variable(db, file, location).into_iter().collect()
+            }
+            SemanticDiagnosticKind::PathNotFound(_) => import_path(db, file, location),
+            SemanticDiagnosticKind::MissingMember { .. } => {
+                // The members missing from the same constructor or pattern are added together.
+                let member_names: Vec<_> = semantic_diagnostics
+                    .iter()
+                    .filter(|other| other.stable_location == location)
+                    .filter_map(|other| match &other.kind {
+                        SemanticDiagnosticKind::MissingMember { member_name } => {
+                            Some(member_name.to_string())
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                add_missing_members(db, file, location, &member_names).into_iter().collect()
+            }
+            SemanticDiagnosticKind::MissingItemsInImpl { item_names } => {
+                let item_names: Vec<_> = item_names.iter().map(|name| name.to_string()).collect();
+                implement_missing_functions(db, file, location, &item_names).into_iter().collect()
+            }
+            _ => vec![],
+        };
+        fixes.extend(diagnostic_fixes.into_iter().map(|fix| (diagnostic_range, fix)));
+    }
+
+    let lowering_diagnostics = db.file_lowering_diagnostics(file).unwrap_or_default();
+    for diagnostic in lowering_diagnostics.get_all() {
+        let location = diagnostic.location.stable_location;
+        let Some(diagnostic_range) = location_range(db, file, location) else {
+            continue;
+        };
+        if !overlaps(diagnostic_range, range) {
+            continue;
+        }
+        let diagnostic_fixes = match &diagnostic.kind {
+            LoweringDiagnosticKind::VariableNotDropped { drop_err, destruct_err } => {
+                [derive_trait(db, drop_err, "Drop"), derive_trait(db, destruct_err, "Destruct")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+            LoweringDiagnosticKind::VariableMoved { inference_error }
+            | LoweringDiagnosticKind::DesnappingANonCopyableType { inference_error } => {
+                derive_trait(db, inference_error, "Copy").into_iter().collect()
+            }
+            LoweringDiagnosticKind::MatchError(MatchError {
+                kind: MatchKind::Match,
+                error: MatchDiagnostic::MissingMatchArm(_),
+            }) => add_missing_match_arms(db, file, location).into_iter().collect(),
+            _ => vec![],
+        };
+        fixes.extend(diagnostic_fixes.into_iter().map(|fix| (diagnostic_range, fix)));
+    }
+
+    // The same fix may be suggested by several diagnostics, e.g. a derive for a type whose values
+    // are not dropped in several places.
+    let mut seen_fixes = vec![];
+    fixes.retain(|(_, fix)| {
+        let key = (fix.title.clone(), fix.edits.clone());
+        if seen_fixes.contains(&key) {
+            return false;
+        }
+        seen_fixes.push(key);
+        true
+    });
+    fixes
+}
+
+/// Renames an unused variable so that its name starts with an underscore.
+fn prefix_unused_variable(
+    db: &RootDatabase,
+    file: FileId,
+    location: StableLocation,
+) -> Option<QuickFix> {
+    let syntax_db = db.upcast();
+    // The name is the first identifier of the variable, after modifiers such as `ref` or `mut`.
+    let identifier = location
+        .syntax_node(db)
+        .descendants(syntax_db)
+        .find(|node| node.kind(syntax_db) == SyntaxKind::TerminalIdentifier)?;
+    let name = identifier.clone().get_text_without_trivia(syntax_db);
+    let start = identifier.span_start_without_trivia(syntax_db);
+    Some(QuickFix {
+        title: format!("Rename to `_{name}`"),
+        edits: vec![(file, text_edit(db, file, TextSpan { start, end: start }, "_".into())?)],
+        is_preferred: true,
+    })
+}
+
+/// Adds a `use` of each item visible from the current module named like the first segment of an
+/// unresolved path.
+fn import_path(db: &RootDatabase, file: FileId, location: StableLocation) -> Vec<QuickFix> {
+    let syntax_db = db.upcast();
+    let identifier = location.syntax_node(db);
+    // Only the first segment of a path is looked up in the current module.
+    let Some(path) = ancestor_of_kind(db, identifier.clone(), SyntaxKind::ExprPath) else {
+        return vec![];
+    };
+    let path = ast::ExprPath::from_syntax_node(syntax_db, path);
+    let is_first_segment = path.elements(syntax_db).first().map_or(false, |segment| {
+        segment.as_syntax_node().span_start_without_trivia(syntax_db)
+            == identifier.span_start_without_trivia(syntax_db)
+    });
+    let (true, Some(user_module_id), Some(item_list)) = (
+        is_first_segment,
+        find_node_module(db, file, identifier.clone()),
+        ancestor_of_kind(db, identifier.clone(), SyntaxKind::ModuleItemList),
+    ) else {
+        return vec![];
+    };
+    let name = identifier.get_text_without_trivia(syntax_db);
+
+    let mut item_paths = vec![];
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter().copied() {
+            // Modules generated by plugins are not meant to be used directly.
+            let in_generated_file =
+                db.module_main_file(module_id).map_or(true, |file| is_generated_file(db, file));
+            if module_id == user_module_id || in_generated_file {
+                continue;
+            }
+            let Ok(Some(item_info)) = db.module_item_info_by_name(module_id, name.clone().into())
+            else {
+                continue;
+            };
+            if matches!(item_info.item_id, ModuleItemId::Use(_))
+                || !is_visible(db, module_id, item_info.visibility, user_module_id)
+            {
+                continue;
+            }
+            item_paths.push(format!("{}::{name}", module_id.full_path(db)));
+        }
+    }
+    item_paths.sort_by_key(|item_path| (item_path.matches("::").count(), item_path.clone()));
+
+    // Add the `use` after the last one of the module, or before its first item.
+    let items: Vec<_> = ast::ModuleItemList::from_syntax_node(syntax_db, item_list.clone())
+        .elements(syntax_db)
+        .into_iter()
+        .map(|item| item.as_syntax_node())
+        .collect();
+    let last_use = items.iter().filter(|item| item.kind(syntax_db) == SyntaxKind::ItemUse).last();
+    let (offset, prefix, suffix) = match (last_use, items.first()) {
+        (Some(last_use), _) => {
+            let end = last_use.span_end_without_trivia(syntax_db);
+            (end, format!("\n{}", line_indent(db, file, end)), String::new())
+        }
+        (None, Some(first_item)) => {
+            let indent = line_indent(db, file, first_item.span_start_without_trivia(syntax_db));
+            (first_item.span(syntax_db).start, indent, "\n\n".into())
+        }
+        (None, None) => (item_list.span(syntax_db).start, String::new(), "\n".into()),
+    };
+    item_paths
+        .into_iter()
+        .filter_map(|item_path| {
+            Some(QuickFix {
+                title: format!("Import `{item_path}`"),
+                edits: vec![(
+                    file,
+                    text_edit(
+                        db,
+                        file,
+                        TextSpan { start: offset, end: offset },
+                        format!("{prefix}use {item_path};{suffix}"),
+                    )?,
+                )],
+                is_preferred: false,
+            })
+        })
+        .collect()
+}
+
+/// Returns whether an item with the given visibility in a module can be used from a user module,
+/// taking the visibility of the ancestors of the module into account.
+fn is_visible(
+    db: &RootDatabase,
+    module_id: ModuleId,
+    visibility: Visibility,
+    user_module_id: ModuleId,
+) -> bool {
+    let user_edition = extract_edition(db, user_module_id.owning_crate(db));
+    if !ignore_visibility_checks(db, user_edition, module_id)
+        && !peek_visible_in(db, visibility, module_id, user_module_id)
+    {
+        return false;
+    }
+    match module_id {
+        ModuleId::CrateRoot(_) => true,
+        ModuleId::Submodule(submodule_id) => {
+            let parent_module_id = submodule_id.parent_module(db);
+            let Ok(Some(module_info)) =
+                db.module_item_info_by_name(parent_module_id, submodule_id.name(db))
+            else {
+                return false;
+            };
+            is_visible(db, parent_module_id, module_info.visibility, user_module_id)
+        }
+    }
+}
+
+/// Adds the missing members to a struct constructor, with stub values, or to a struct pattern.
+fn add_missing_members(
+    db: &RootDatabase,
+    file: FileId,
+    location: StableLocation,
+    member_names: &[String],
+) -> Option<QuickFix> {
+    let syntax_db = db.upcast();
+    let node = location.syntax_node(db);
+    let (lbrace, list, members) = match node.kind(syntax_db) {
+        SyntaxKind::ExprStructCtorCall => {
+            let arguments =
+                ast::ExprStructCtorCall::from_syntax_node(syntax_db, node).arguments(syntax_db);
+            let members = member_names.iter().map(|name| format!("{name}: {STUB_BODY}"));
+            (
+                arguments.lbrace(syntax_db).as_syntax_node(),
+                arguments.arguments(syntax_db).as_syntax_node(),
+                members.collect::<Vec<_>>(),
+            )
+        }
+        SyntaxKind::PatternStruct => {
+            let pattern = ast::PatternStruct::from_syntax_node(syntax_db, node);
+            let members = member_names.iter().map(|name| format!("{name}: _"));
+            (
+                pattern.lbrace(syntax_db).as_syntax_node(),
+                pattern.params(syntax_db).as_syntax_node(),
+                members.collect(),
+            )
+        }
+        _ => return None,
+    };
+    let members = members.join(", ");
+    let list_text = list.clone().get_text_without_trivia(syntax_db);
+    let edit = if list_text.is_empty() {
+        let end = lbrace.span_end_without_trivia(syntax_db);
+        text_edit(db, file, TextSpan { start: end, end }, format!(" {members} "))?
+    } else {
+        let end = list.span_end_without_trivia(syntax_db);
+        let separator = if list_text.ends_with(',') { " " } else { ", " };
+        text_edit(db, file, TextSpan { start: end, end }, format!("{separator}{members}"))?
+    };
+    Some(QuickFix {
+        title: "Add missing members".into(),
+        edits: vec![(file, edit)],
+        is_preferred: true,
+    })
+}
+
+/// Adds stubs of the trait functions missing from an impl.
+fn implement_missing_functions(
+    db: &RootDatabase,
+    file: FileId,
+    location: StableLocation,
+    item_names: &[String],
+) -> Option<QuickFix> {
+    let syntax_db = db.upcast();
+    let node = location.syntax_node(db);
+    let impl_def_id =
+        get_lookup_items(db, file, node.clone())?.into_iter().find_map(|item| match item {
+            LookupItemId::ModuleItem(ModuleItemId::Impl(impl_def_id)) => Some(impl_def_id),
+            _ => None,
+        })?;
+    let impl_ast = impl_def_id.stable_ptr(db).lookup(syntax_db);
+    let ast::MaybeImplBody::Some(body) = impl_ast.body(syntax_db) else {
+        return None;
+    };
+    let trait_id = db.impl_def_trait(impl_def_id).ok()?;
+    let trait_functions = db.trait_functions(trait_id).ok()?;
+
+    let substitution = trait_generic_args_substitution(db, trait_id, &impl_ast);
+    let impl_indent =
+        line_indent(db, file, impl_ast.as_syntax_node().span_start_without_trivia(syntax_db));
+    let indent = format!("{impl_indent}    ");
+    let stubs: Vec<_> = trait_functions
+        .iter()
+        .filter(|(name, _)| item_names.iter().any(|item_name| item_name == name.as_str()))
+        .map(|(_, trait_function_id)| {
+            let declaration =
+                trait_function_id.stable_ptr(db).lookup(syntax_db).declaration(syntax_db);
+            let declaration =
+                substitute_identifiers(db, declaration.as_syntax_node(), &substitution);
+            format!("{declaration} {{\n{indent}    {STUB_BODY}\n{indent}}}")
+        })
+        .collect();
+    if stubs.is_empty() {
+        return None;
+    }
+    let stubs = stubs.join(&format!("\n\n{indent}"));
+
+    let items = body.items(syntax_db).elements(syntax_db);
+    let edit = match items.last() {
+        Some(last_item) => {
+            let end = last_item.as_syntax_node().span_end_without_trivia(syntax_db);
+            text_edit(db, file, TextSpan { start: end, end }, format!("\n\n{indent}{stubs}"))?
+        }
+        None => {
+            let span = TextSpan {
+                start: body.lbrace(syntax_db).as_syntax_node().span_end_without_trivia(syntax_db),
+                end: body.rbrace(syntax_db).as_syntax_node().span_start_without_trivia(syntax_db),
+            };
+            text_edit(db, file, span, format!("\n{indent}{stubs}\n{impl_indent}"))?
+        }
+    };
+    Some(QuickFix {
+        title: "Implement missing trait functions".into(),
+        edits: vec![(file, edit)],
+        is_preferred: true,
+    })
+}
+
+/// Maps the names of the generic parameters of a trait to the text of the generic arguments an
+/// impl gives them, e.g. `T` to `Point` for `impl PointShape of Shape<Point>`.
+fn trait_generic_args_substitution(
+    db: &RootDatabase,
+    trait_id: TraitId,
+    impl_ast: &ast::ItemImpl,
+) -> Vec<(String, String)> {
+    let syntax_db = db.upcast();
+    let Ok(generic_params) = db.trait_generic_params(trait_id) else {
+        return vec![];
+    };
+    let Some(ast::PathSegment::WithGenericArgs(segment)) =
+        impl_ast.trait_path(syntax_db).elements(syntax_db).last().cloned()
+    else {
+        return vec![];
+    };
+    let generic_args = segment.generic_args(syntax_db).generic_args(syntax_db).elements(syntax_db);
+    generic_params
+        .iter()
+        .zip(generic_args)
+        .filter_map(|(generic_param, generic_arg)| {
+            let ast::GenericArg::Unnamed(generic_arg) = generic_arg else {
+                return None;
+            };
+            Some((
+                generic_param.id().name(db)?.to_string(),
+                generic_arg.value(syntax_db).as_syntax_node().get_text_without_trivia(syntax_db),
+            ))
+        })
+        .collect()
+}
+
+/// Returns the text of a node, with the identifiers named like a key of the substitution replaced
+/// by its value.
+fn substitute_identifiers(
+    db: &RootDatabase,
+    node: SyntaxNode,
+    substitution: &[(String, String)],
+) -> String {
+    let syntax_db = db.upcast();
+    let span = node.span_without_trivia(syntax_db);
+    let text = node.clone().get_text_without_trivia(syntax_db);
+    let mut result = String::new();
+    let mut copied_until = 0;
+    for identifier in node
+        .descendants(syntax_db)
+        .filter(|descendant| descendant.kind(syntax_db) == SyntaxKind::TerminalIdentifier)
+    {
+        let identifier_span = identifier.span_without_trivia(syntax_db);
+        let name = identifier.get_text_without_trivia(syntax_db);
+        let Some((_, replacement)) = substitution.iter().find(|(key, _)| *key == name) else {
+            continue;
+        };
+        let start = (identifier_span.start - span.start).as_u32() as usize;
+        let end = (identifier_span.end - span.start).as_u32() as usize;
+        result.push_str(&text[copied_until..start]);
+        result.push_str(replacement);
+        copied_until = end;
+    }
+    result.push_str(&text[copied_until..]);
+    result
+}
+
+/// Adds a derive of a trait to the user type an impl of the trait was not found for.
+fn derive_trait(
+    db: &RootDatabase,
+    inference_error: &InferenceError,
+    trait_name: &str,
+) -> Option<QuickFix> {
+    let syntax_db = db.upcast();
+    let InferenceError::NoImplsFound { concrete_trait_id } = inference_error else {
+        return None;
+    };
+    let GenericArgumentId::Type(ty) = *concrete_trait_id.generic_args(db).first()? else {
+        return None;
+    };
+    let (name, attributes, item_start) = match user_type_item(db, ty)? {
+        ModuleItemId::Struct(struct_id) => {
+            let item = struct_id.stable_ptr(db).lookup(syntax_db);
+            (struct_id.name(db), item.attributes(syntax_db), item.as_syntax_node())
+        }
+        ModuleItemId::Enum(enum_id) => {
+            let item = enum_id.stable_ptr(db).lookup(syntax_db);
+            (enum_id.name(db), item.attributes(syntax_db), item.as_syntax_node())
+        }
+        _ => return None,
+    };
+    let file = item_start.stable_ptr().file_id(syntax_db);
+
+    let derive_arguments = attributes.elements(syntax_db).into_iter().find_map(|attribute| {
+        if attribute.attr(syntax_db).as_syntax_node().get_text_without_trivia(syntax_db) != "derive"
+        {
+            return None;
+        }
+        match attribute.arguments(syntax_db) {
+            ast::OptionArgListParenthesized::ArgListParenthesized(arguments) => Some(arguments),
+            ast::OptionArgListParenthesized::Empty(_) => None,
+        }
+    });
+    let edit = match derive_arguments {
+        // Extend an existing derive.
+        Some(arguments) => {
+            let start =
+                arguments.rparen(syntax_db).as_syntax_node().span_start_without_trivia(syntax_db);
+            let separator = if arguments.arguments(syntax_db).elements(syntax_db).is_empty() {
+                ""
+            } else {
+                ", "
+            };
+            text_edit(db, file, TextSpan { start, end: start }, format!("{separator}{trait_name}"))?
+        }
+        None => {
+            let start = item_start.span_start_without_trivia(syntax_db);
+            let indent = line_indent(db, file, start);
+            text_edit(
+                db,
+                file,
+                TextSpan { start, end: start },
+                format!("#[derive({trait_name})]\n{indent}"),
+            )?
+        }
+    };
+    Some(QuickFix {
+        title: format!("Derive `{trait_name}` for `{name}`"),
+        edits: vec![(file, edit)],
+        is_preferred: trait_name != "Destruct",
+    })
+}
+
+/// Returns the struct or enum item of a type defined in the user's code.
+fn user_type_item(db: &RootDatabase, ty: TypeId) -> Option<ModuleItemId> {
+    let item = match db.lookup_intern_type(ty) {
+        TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => {
+            ModuleItemId::Struct(concrete_struct_id.struct_id(db))
+        }
+        TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => {
+            ModuleItemId::Enum(concrete_enum_id.enum_id(db))
+        }
+        _ => return None,
+    };
+    let module_file_id = item.module_file_id(db);
+    let file = module_file_id.file_id(db).ok()?;
+    if module_file_id.0.owning_crate(db) == core_crate(db) || is_generated_file(db, file) {
+        return None;
+    }
+    Some(item)
+}
+
+/// Adds arms for the variants not covered by a match on an enum.
+fn add_missing_match_arms(
+    db: &RootDatabase,
+    file: FileId,
+    location: StableLocation,
+) -> Option<QuickFix> {
+    let syntax_db = db.upcast();
+    let node = ancestor_of_kind(db, location.syntax_node(db), SyntaxKind::ExprMatch)?;
+    let function_id =
+        get_lookup_items(db, file, node.clone())?.into_iter().find_map(|item| match item {
+            LookupItemId::ModuleItem(ModuleItemId::FreeFunction(free_function_id)) => {
+                Some(FunctionWithBodyId::Free(free_function_id))
+            }
+            LookupItemId::ImplItem(ImplItemId::Function(impl_function_id)) => {
+                Some(FunctionWithBodyId::Impl(impl_function_id))
+            }
+            _ => None,
+        })?;
+    let match_ast = ast::ExprMatch::from_syntax_node(syntax_db, node);
+    let expr_id = db
+        .lookup_expr_by_ptr(function_id, ast::Expr::Match(match_ast.clone()).stable_ptr())
+        .ok()?;
+    let Expr::Match(match_expr) = db.expr_semantic(function_id, expr_id) else {
+        return None;
+    };
+    let body = db.function_body(function_id).ok()?;
+
+    // Snapshots of enums are matched like the enums themselves.
+    let mut ty = body.exprs[match_expr.matched_expr].ty();
+    while let TypeLongId::Snapshot(inner_ty) = db.lookup_intern_type(ty) {
+        ty = inner_ty;
+    }
+    let TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) = db.lookup_intern_type(ty)
+    else {
+        return None;
+    };
+    let mut covered_variants = vec![];
+    for pattern_id in match_expr.arms.iter().flat_map(|arm| arm.patterns.iter()) {
+        match &body.patterns[*pattern_id] {
+            Pattern::EnumVariant(pattern) => covered_variants.push(pattern.variant.id),
+            Pattern::Otherwise(_) | Pattern::Variable(_) => return None,
+            _ => {}
+        }
+    }
+
+    // Name the variants like the existing arms do, falling back to the name of the enum.
+    let arms = match_ast.arms(syntax_db);
+    let enum_path = arms
+        .elements(syntax_db)
+        .into_iter()
+        .flat_map(|arm| arm.patterns(syntax_db).elements(syntax_db))
+        .find_map(|pattern| {
+            let ast::Pattern::Enum(pattern) = pattern else {
+                return None;
+            };
+            let segments = pattern.path(syntax_db).elements(syntax_db);
+            let prefix = &segments[..segments.len().checked_sub(1)?];
+            (!prefix.is_empty()).then(|| {
+                prefix
+                    .iter()
+                    .map(|segment| segment.as_syntax_node().get_text_without_trivia(syntax_db))
+                    .collect::<Vec<_>>()
+                    .join("::")
+            })
+        })
+        .unwrap_or_else(|| concrete_enum_id.enum_id(db).name(db).to_string());
+    let new_arms: Vec<_> = db
+        .concrete_enum_variants(concrete_enum_id)
+        .ok()?
+        .into_iter()
+        .filter(|variant| !covered_variants.contains(&variant.id))
+        .map(|variant| {
+            let inner_pattern = if variant.ty == unit_ty(db) { "" } else { "(_)" };
+            format!("{enum_path}::{}{inner_pattern} => {STUB_BODY},", variant.id.name(db))
+        })
+        .collect();
+    if new_arms.is_empty() {
+        return None;
+    }
+
+    let match_indent =
+        line_indent(db, file, match_ast.as_syntax_node().span_start_without_trivia(syntax_db));
+    let edit = if arms.elements(syntax_db).is_empty() {
+        let indent = format!("{match_indent}    ");
+        let span = TextSpan {
+            start: match_ast.lbrace(syntax_db).as_syntax_node().span_end_without_trivia(syntax_db),
+            end: match_ast.rbrace(syntax_db).as_syntax_node().span_start_without_trivia(syntax_db),
+        };
+        let new_arms = new_arms.join(&format!("\n{indent}"));
+        text_edit(db, file, span, format!("\n{indent}{new_arms}\n{match_indent}"))?
+    } else {
+        let arms_node = arms.as_syntax_node();
+        let end = arms_node.span_end_without_trivia(syntax_db);
+        let indent = line_indent(db, file, arms_node.span_start_without_trivia(syntax_db));
+        let separator = if arms_node.clone().get_text_without_trivia(syntax_db).ends_with(',') {
+            ""
+        } else {
+            ","
+        };
+        let new_arms = new_arms.join(&format!("\n{indent}"));
+        text_edit(
+            db,
+            file,
+            TextSpan { start: end, end },
+            format!("{separator}\n{indent}{new_arms}"),
+        )?
+    };
+    Some(QuickFix {
+        title: "Add missing match arms".into(),
+        edits: vec![(file, edit)],
+        is_preferred: true,
+    })
+}
+
+/// Returns the LSP range of a diagnostic location in a file, if it is in the file.
+fn location_range(db: &RootDatabase, file: FileId, location: StableLocation) -> Option<Range> {
+    if location.file_id(db) != file {
+        return None;
+    }
+    let span = location.syntax_node(db).span_without_trivia(db.upcast());
+    lsp_range(db, SymbolLocation { file, span })
+}
+
+/// Returns whether two ranges overlap, including touching at their ends.
+fn overlaps(range: Range, other: Range) -> bool {
+    range.start <= other.end && other.start <= range.end
+}
+
+/// Returns the closest ancestor of a node, or the node itself, of the given kind.
+fn ancestor_of_kind(db: &RootDatabase, node: SyntaxNode, kind: SyntaxKind) -> Option<SyntaxNode> {
+    let syntax_db = db.upcast();
+    let mut node = Some(node);
+    while let Some(current) = node {
+        if current.kind(syntax_db) == kind {
+            return Some(current);
+        }
+        node = current.parent();
+    }
+    None
+}
+
+/// Returns the leading whitespace of the line containing an offset.
+fn line_indent(db: &RootDatabase, file: FileId, offset: TextOffset) -> String {
+    let (Some(position), Some(content)) =
+        (offset.position_in_file(db, file), db.file_content(file))
+    else {
+        return String::new();
+    };
+    content
+        .lines()
+        .nth(position.line)
+        .map(|line| line.chars().take_while(|c| c.is_whitespace()).collect())
+        .unwrap_or_default()
+}
+
+/// Returns an edit replacing a span of a file with the given text.
+fn text_edit(
+    db: &RootDatabase,
+    file: FileId,
+    span: TextSpan,
+    new_text: String,
+) -> Option<TextEdit> {
+    Some(TextEdit { range: lsp_range(db, SymbolLocation { file, span })?, new_text })
+}
@@ -0,0 +1,229 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+use tower_lsp::lsp_types::{Position, Range};
+
+use super::quick_fixes;
+use crate::test_utils::{apply_text_edits, setup_test_file};
+
+/// Applies the quick fix with the given title, offered for the diagnostics of the given code, and
+/// returns the fixed code.
+fn apply_fix(code: &str, title: &str) -> String {
+    let (db, file) = setup_test_file(code);
+    let whole_file = Range {
+        start: Position { line: 0, character: 0 },
+        end: Position { line: u32::MAX, character: u32::MAX },
+    };
+    let fixes = quick_fixes(&db, file, whole_file);
+    let titles: Vec<_> = fixes.iter().map(|(_, fix)| fix.title.clone()).collect();
+    let (_, fix) = fixes
+        .into_iter()
+        .find(|(_, fix)| fix.title == title)
+        .unwrap_or_else(|| panic!("No fix titled `{title}`, found: {titles:?}."));
+    let edits = fix
+        .edits
+        .into_iter()
+        .map(|(edit_file, edit)| {
+            assert_eq!(edit_file, file);
+            edit
+        })
+        .collect();
+    apply_text_edits(code, edits)
+}
+
+#[test_case(
+    indoc! {"
+        fn foo() {
+            let x = 5;
+        }
+    "},
+    "Rename to `_x`",
+    indoc! {"
+        fn foo() {
+            let _x = 5;
+        }
+    "};
+    "unused variable"
+)]
+#[test_case(
+    indoc! {"
+        fn foo() {
+            let mut x = 5;
+        }
+    "},
+    "Rename to `_x`",
+    indoc! {"
+        fn foo() {
+            let mut _x = 5;
+        }
+    "};
+    "unused mutable variable"
+)]
+#[test_case(
+    indoc! {"
+        #[derive(Drop)]
+        enum Shape {
+            Circle: u32,
+            Square: u32,
+            Dot,
+        }
+        fn area(shape: Shape) -> u32 {
+            match shape {
+                Shape::Circle(r) => 3 * r * r,
+            }
+        }
+    "},
+    "Add missing match arms",
+    indoc! {"
+        #[derive(Drop)]
+        enum Shape {
+            Circle: u32,
+            Square: u32,
+            Dot,
+        }
+        fn area(shape: Shape) -> u32 {
+            match shape {
+                Shape::Circle(r) => 3 * r * r,
+                Shape::Square(_) => panic!(\"Not implemented.\"),
+                Shape::Dot => panic!(\"Not implemented.\"),
+            }
+        }
+    "};
+    "missing match arms"
+)]
+#[test_case(
+    indoc! {"
+        #[derive(Drop)]
+        struct Point {
+            x: u32,
+            y: u32,
+        }
+        trait Shape<T> {
+            fn area(self: @T) -> u32;
+            fn name(self: @T) -> felt252;
+        }
+        impl PointShape of Shape<Point> {
+            fn name(self: @Point) -> felt252 {
+                'point'
+            }
+        }
+    "},
+    "Implement missing trait functions",
+    indoc! {"
+        #[derive(Drop)]
+        struct Point {
+            x: u32,
+            y: u32,
+        }
+        trait Shape<T> {
+            fn area(self: @T) -> u32;
+            fn name(self: @T) -> felt252;
+        }
+        impl PointShape of Shape<Point> {
+            fn name(self: @Point) -> felt252 {
+                'point'
+            }
+
+            fn area(self: @Point) -> u32 {
+                panic!(\"Not implemented.\")
+            }
+        }
+    "};
+    "missing trait functions"
+)]
+#[test_case(
+    indoc! {"
+        struct S {
+            x: felt252,
+        }
+        fn foo() {
+            let _s = S { x: 1 };
+        }
+    "},
+    "Derive `Drop` for `S`",
+    indoc! {"
+        #[derive(Drop)]
+        struct S {
+            x: felt252,
+        }
+        fn foo() {
+            let _s = S { x: 1 };
+        }
+    "};
+    "new derive"
+)]
+#[test_case(
+    indoc! {"
+        #[derive(Drop)]
+        struct S {
+            x: felt252,
+        }
+        fn foo() -> felt252 {
+            let s = S { x: 1 };
+            let t = s;
+            s.x + t.x
+        }
+    "},
+    "Derive `Copy` for `S`",
+    indoc! {"
+        #[derive(Drop, Copy)]
+        struct S {
+            x: felt252,
+        }
+        fn foo() -> felt252 {
+            let s = S { x: 1 };
+            let t = s;
+            s.x + t.x
+        }
+    "};
+    "extended derive"
+)]
+#[test_case(
+    indoc! {"
+        #[derive(Drop)]
+        struct S {
+            x: felt252,
+            y: felt252,
+        }
+        fn foo() -> S {
+            S { x: 1 }
+        }
+    "},
+    "Add missing members",
+    indoc! {"
+        #[derive(Drop)]
+        struct S {
+            x: felt252,
+            y: felt252,
+        }
+        fn foo() -> S {
+            S { x: 1, y: panic!(\"Not implemented.\") }
+        }
+    "};
+    "missing members"
+)]
+#[test_case(
+    indoc! {"
+        mod a {
+            pub fn f() {}
+        }
+        fn foo() {
+            f();
+        }
+    "},
+    "Import `test::a::f`",
+    indoc! {"
+        use test::a::f;
+
+        mod a {
+            pub fn f() {}
+        }
+        fn foo() {
+            f();
+        }
+    "};
+    "import"
+)]
+fn test_quick_fix(code: &str, title: &str, expected: &str) {
+    assert_eq!(apply_fix(code, title), expected);
+}
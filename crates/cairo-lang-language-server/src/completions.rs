@@ -91,7 +91,9 @@ pub fn generic_completions(
 
 fn resolved_generic_item_completion_kind(item: ResolvedGenericItem) -> CompletionItemKind {
     match item {
-        ResolvedGenericItem::Constant(_) => CompletionItemKind::CONSTANT,
+        ResolvedGenericItem::Constant(_) | ResolvedGenericItem::TraitConstant(_) => {
+            CompletionItemKind::CONSTANT
+        }
         ResolvedGenericItem::Module(_) => CompletionItemKind::MODULE,
         ResolvedGenericItem::GenericFunction(_) | ResolvedGenericItem::TraitFunction(_) => {
             CompletionItemKind::FUNCTION
@@ -308,7 +310,7 @@ fn module_has_trait(
 }
 
 /// Finds all methods that can be called on a type.
-fn find_methods_for_type(
+pub fn find_methods_for_type(
     db: &dyn SemanticGroup,
     mut resolver: Resolver<'_>,
     ty: cairo_lang_semantic::TypeId,
@@ -0,0 +1,97 @@
+//! The configuration of the server, read from the `cairo1` section of the client configuration.
+
+use std::path::PathBuf;
+
+use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_formatter::FormatterConfig;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::analysis_cache::{Fingerprint, FingerprintHasher};
+use crate::inlay_hints::InlayHintsConfig;
+
+#[cfg(test)]
+#[path = "config_test.rs"]
+mod test;
+
+/// The section of the client configuration the server is configured by.
+pub const CONFIG_SECTION: &str = "cairo1";
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Config {
+    /// The corelib to use instead of the one of the project, or of the one found next to the
+    /// server. The path may omit the `corelib/src` or `src` suffix.
+    pub corelib_path: Option<PathBuf>,
+    /// The cfgs enabled in addition to `test`, either as a name or as a `[key, value]` pair.
+    pub cfg: Vec<Cfg>,
+    pub plugins: PluginsConfig,
+    pub diagnostics_level: DiagnosticsLevel,
+    /// The formatter settings, under the same kebab-case keys as in the formatter configuration.
+    pub formatter: FormatterConfig,
+    pub inlay_hints: InlayHintsConfig,
+}
+impl Config {
+    /// Parses the configuration from the value of its section. Invalid configurations fall back to
+    /// the defaults.
+    pub fn from_value(value: Value) -> Self {
+        if value.is_null() {
+            return Self::default();
+        }
+        serde_json::from_value(value).unwrap_or_else(|err| {
+            warn!("Invalid configuration under `{CONFIG_SECTION}` key: {err}");
+            Self::default()
+        })
+    }
+
+    /// Returns whether the database must be rebuilt to apply the configuration, when changing from
+    /// `old`.
+    pub fn requires_db_rebuild(&self, old: &Config) -> bool {
+        self.corelib_path != old.corelib_path || self.cfg != old.cfg || self.plugins != old.plugins
+    }
+
+    /// Returns the cfg set of the crates that don't set their own.
+    pub fn cfg_set(&self) -> CfgSet {
+        CfgSet::from_iter([Cfg::name("test")].into_iter().chain(self.cfg.iter().cloned()))
+    }
+
+    /// Returns the corelib sources directory at the configured path, if it exists.
+    pub fn corelib(&self) -> Option<PathBuf> {
+        let root_path = self.corelib_path.as_ref().filter(|path| !path.as_os_str().is_empty())?;
+        [root_path.join("corelib").join("src"), root_path.join("src"), root_path.clone()]
+            .into_iter()
+            .find(|path| path.exists())
+    }
+
+    /// Returns a fingerprint of the settings the analysis results of a crate depend on.
+    pub fn analysis_fingerprint(&self) -> Fingerprint {
+        let mut hasher = FingerprintHasher::default();
+        let settings = (&self.cfg, &self.plugins, self.diagnostics_level);
+        hasher.update(&serde_json::to_vec(&settings).expect("The settings are serializable."));
+        hasher.finish()
+    }
+}
+
+/// The plugin suites the code is analyzed with.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct PluginsConfig {
+    pub starknet: bool,
+    pub test: bool,
+}
+impl Default for PluginsConfig {
+    fn default() -> Self {
+        Self { starknet: true, test: true }
+    }
+}
+
+/// The last compilation phase whose diagnostics are reported.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "camelCase")]
+pub enum DiagnosticsLevel {
+    Syntax,
+    Semantic,
+    #[default]
+    Lowering,
+}
@@ -0,0 +1,122 @@
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_filesystem::db::{FilesGroup, CORELIB_CRATE_NAME};
+use cairo_lang_filesystem::ids::{CrateLongId, Directory};
+use cairo_lang_formatter::FormatterConfig;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use test_case::test_case;
+use tower_lsp::lsp_types::Url;
+
+use super::{Config, DiagnosticsLevel, PluginsConfig};
+use crate::inlay_hints::InlayHintsConfig;
+use crate::{configured_db, file, reconfigured_db};
+
+/// Returns the root directory of the repository, which contains the corelib.
+fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../..").canonicalize().unwrap()
+}
+
+#[test]
+fn test_parse_config() {
+    let config = Config::from_value(json!({
+        "corelibPath": "/path/to/cairo",
+        "cfg": ["feature", ["target", "starknet"]],
+        "plugins": {"test": false},
+        "diagnosticsLevel": "semantic",
+        "formatter": {"max-line-length": 80},
+        "inlayHints": {"parameterNames": false},
+    }));
+    assert_eq!(
+        config,
+        Config {
+            corelib_path: Some("/path/to/cairo".into()),
+            cfg: vec![Cfg::name("feature"), Cfg::kv("target", "starknet")],
+            plugins: PluginsConfig { starknet: true, test: false },
+            diagnostics_level: DiagnosticsLevel::Semantic,
+            formatter: FormatterConfig::new(4, 80, false),
+            inlay_hints: InlayHintsConfig { parameter_names: false, ..InlayHintsConfig::default() },
+        }
+    );
+}
+
+#[test_case(json!(null); "missing")]
+#[test_case(json!({}); "empty")]
+#[test_case(json!({"cfg": "feature"}); "invalid")]
+#[test_case(json!({"diagnosticsLevel": "typing"}); "unknown variant")]
+fn test_parse_default_config(value: serde_json::Value) {
+    assert_eq!(Config::from_value(value), Config::default());
+}
+
+#[test]
+fn test_cfg_set() {
+    let config = Config::from_value(json!({"cfg": [["target", "starknet"]]}));
+    assert_eq!(
+        config.cfg_set(),
+        CfgSet::from_iter([Cfg::name("test"), Cfg::kv("target", "starknet")])
+    );
+}
+
+#[test_case(""; "repository")]
+#[test_case("corelib"; "corelib")]
+#[test_case("corelib/src"; "corelib sources")]
+fn test_corelib_override(path: &str) {
+    let config = Config { corelib_path: Some(repo_root().join(path)), ..Config::default() };
+    assert_eq!(config.corelib(), Some(repo_root().join("corelib/src")));
+}
+
+#[test_case(None; "unset")]
+#[test_case(Some(""); "empty")]
+#[test_case(Some("/nonexistent/cairo"); "missing")]
+fn test_no_corelib_override(path: Option<&str>) {
+    let config = Config { corelib_path: path.map(PathBuf::from), ..Config::default() };
+    assert_eq!(config.corelib(), None);
+}
+
+#[test_case(json!({"corelibPath": "/path/to/cairo"}), true; "corelib")]
+#[test_case(json!({"cfg": ["feature"]}), true; "cfg")]
+#[test_case(json!({"plugins": {"starknet": false}}), true; "plugins")]
+#[test_case(json!({"diagnosticsLevel": "syntax"}), false; "diagnostics level")]
+#[test_case(json!({"formatter": {"tab-size": 2}}), false; "formatter")]
+#[test_case(json!({"inlayHints": {"types": false}}), false; "inlay hints")]
+fn test_requires_db_rebuild(value: serde_json::Value, expected: bool) {
+    assert_eq!(Config::from_value(value).requires_db_rebuild(&Config::default()), expected);
+}
+
+#[test]
+fn test_reconfigured_db() {
+    let mut old_db = configured_db(&Config::default());
+    let uri = Url::from_file_path(repo_root().join("src/lib.cairo")).unwrap();
+    let old_file = file(&old_db, uri.clone());
+    old_db.set_file_overrides(Arc::new(OrderedHashMap::from_iter([(
+        old_file,
+        Arc::new("fn foo() {}".to_string()),
+    )])));
+
+    let config = Config::from_value(json!({
+        "corelibPath": repo_root(),
+        "cfg": ["feature"],
+        "plugins": {"starknet": false},
+    }));
+    let db = reconfigured_db(&config, &old_db, [uri.clone()].into_iter());
+
+    // The corelib override is set up.
+    let core = db.intern_crate(CrateLongId::Real(CORELIB_CRATE_NAME.into()));
+    assert_eq!(
+        db.crate_config(core).map(|config| config.root),
+        Some(Directory::Real(repo_root().join("corelib/src")))
+    );
+    // The extra cfgs are set.
+    assert_eq!(*db.cfg_set(), CfgSet::from_iter([Cfg::name("test"), Cfg::name("feature")]));
+    // Only the selected plugin suites are used.
+    let plugins: Vec<_> = db.macro_plugins().iter().map(|plugin| format!("{plugin:?}")).collect();
+    assert!(!plugins.iter().any(|plugin| plugin.starts_with("StarkNetPlugin")), "{plugins:?}");
+    assert!(plugins.iter().any(|plugin| plugin.starts_with("TestPlugin")), "{plugins:?}");
+    // The changes of the open files are kept.
+    let new_file = file(&db, uri);
+    assert_eq!(db.file_content(new_file).as_deref().map(String::as_str), Some("fn foo() {}"));
+}
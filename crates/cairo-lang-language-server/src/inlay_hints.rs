@@ -0,0 +1,255 @@
+//! Inlay hints for inferred types, parameter names and the impls trait function calls resolve to.
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{FunctionWithBodyId, LanguageElementId};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::FunctionBody;
+use cairo_lang_semantic::items::functions::{GenericFunctionId, ImplGenericFunctionId};
+use cairo_lang_semantic::items::imp::ImplId;
+use cairo_lang_semantic::{Expr, ExprFunctionCall, Statement, StatementLet};
+use cairo_lang_syntax::node::helpers::GetIdentifier;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+use serde::Deserialize;
+use tower_lsp::lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Position, Range};
+
+use crate::from_pos;
+use crate::references::is_generated_file;
+
+#[cfg(test)]
+#[path = "inlay_hints_test.rs"]
+mod test;
+
+/// The kinds of inlay hints to show, read from the `cairo1.inlayHints` configuration section.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(default, rename_all = "camelCase")]
+pub struct InlayHintsConfig {
+    /// Show the inferred types of `let` bindings without a type annotation.
+    pub types: bool,
+    /// Show the parameter names of positional call arguments.
+    pub parameter_names: bool,
+    /// Show the impl a trait function call resolves to.
+    pub impls: bool,
+}
+impl Default for InlayHintsConfig {
+    fn default() -> Self {
+        Self { types: true, parameter_names: true, impls: true }
+    }
+}
+
+/// Returns the inlay hints of the functions in a file, within the given range.
+pub fn inlay_hints(
+    db: &RootDatabase,
+    file: FileId,
+    range: Range,
+    config: &InlayHintsConfig,
+) -> Vec<InlayHint> {
+    let mut hints = vec![];
+    for function_id in file_functions(db, file) {
+        let Ok(body) = db.function_body(function_id) else {
+            continue;
+        };
+        if config.types {
+            for (_, statement) in body.statements.iter() {
+                if let Statement::Let(let_statement) = statement {
+                    hints.extend(let_type_hints(db, &body, let_statement));
+                }
+            }
+        }
+        for (_, expr) in body.exprs.iter() {
+            if let Expr::FunctionCall(call) = expr {
+                hints.extend(call_hints(db, call, config));
+            }
+        }
+    }
+
+    let syntax_db = db.upcast();
+    let mut hints: Vec<_> = hints
+        .into_iter()
+        .filter(|(node, _)| node.stable_ptr().file_id(syntax_db) == file)
+        .filter_map(|(node, hint)| {
+            let offset = match hint.placement {
+                Placement::Before => node.span_without_trivia(syntax_db).start,
+                Placement::After => node.span_without_trivia(syntax_db).end,
+            };
+            let position = offset_position(db, file, offset)?;
+            let in_range = (range.start.line, range.start.character)
+                <= (position.line, position.character)
+                && (position.line, position.character) <= (range.end.line, range.end.character);
+            in_range.then_some(InlayHint {
+                position,
+                label: InlayHintLabel::String(hint.label),
+                kind: Some(hint.kind),
+                text_edits: None,
+                tooltip: None,
+                padding_left: None,
+                padding_right: Some(hint.kind == InlayHintKind::PARAMETER),
+                data: None,
+            })
+        })
+        .collect();
+    hints.sort_by_key(|hint| (hint.position.line, hint.position.character));
+    hints
+}
+
+/// Where a hint is shown relative to the node it annotates.
+enum Placement {
+    Before,
+    After,
+}
+
+/// A hint to show next to a syntax node.
+struct Hint {
+    label: String,
+    kind: InlayHintKind,
+    placement: Placement,
+}
+
+/// Returns the functions defined in a file.
+fn file_functions(db: &RootDatabase, file: FileId) -> Vec<FunctionWithBodyId> {
+    let mut functions = vec![];
+    for module_id in db.file_modules(file).unwrap_or_default().iter() {
+        for free_function_id in db.module_free_functions_ids(*module_id).unwrap_or_default().iter()
+        {
+            functions.push(FunctionWithBodyId::Free(*free_function_id));
+        }
+        for impl_def_id in db.module_impls_ids(*module_id).unwrap_or_default().iter() {
+            for impl_function_id in db.impl_functions(*impl_def_id).unwrap_or_default().values() {
+                functions.push(FunctionWithBodyId::Impl(*impl_function_id));
+            }
+        }
+    }
+    functions.retain(|function_id| function_id.untyped_stable_ptr(db).file_id(db) == file);
+    functions
+}
+
+/// Returns the type hints of the variables bound by a `let` statement without a type annotation.
+fn let_type_hints(
+    db: &RootDatabase,
+    body: &FunctionBody,
+    let_statement: &StatementLet,
+) -> Vec<(SyntaxNode, Hint)> {
+    let syntax_db = db.upcast();
+    let ast::Statement::Let(let_ast) = let_statement.stable_ptr.lookup(syntax_db) else {
+        return vec![];
+    };
+    if !matches!(let_ast.type_clause(syntax_db), ast::OptionTypeClause::Empty(_)) {
+        return vec![];
+    }
+    body.patterns[let_statement.pattern]
+        .variables(&body.patterns)
+        .into_iter()
+        .filter(|variable| !variable.var.ty.is_missing(db))
+        .map(|variable| {
+            (
+                variable.stable_ptr.lookup(syntax_db).as_syntax_node(),
+                Hint {
+                    label: format!(": {}", variable.var.ty.format(db)),
+                    kind: InlayHintKind::TYPE,
+                    placement: Placement::After,
+                },
+            )
+        })
+        .collect()
+}
+
+/// Returns the parameter name hints of the arguments of a call, and the impl the called function
+/// resolves to.
+fn call_hints(
+    db: &RootDatabase,
+    call: &ExprFunctionCall,
+    config: &InlayHintsConfig,
+) -> Vec<(SyntaxNode, Hint)> {
+    let syntax_db = db.upcast();
+    // Calls are either plain (`f(x)`) or method calls (`a.f(x)`), whose receiver is passed as the
+    // first argument. Other expressions, such as operators, may also be desugared into calls.
+    let node = call.stable_ptr.lookup(syntax_db).as_syntax_node();
+    let (call_ast, is_method) = match node.kind(syntax_db) {
+        SyntaxKind::ExprFunctionCall => {
+            (ast::ExprFunctionCall::from_syntax_node(syntax_db, node), false)
+        }
+        SyntaxKind::ExprBinary => {
+            let binary_ast = ast::ExprBinary::from_syntax_node(syntax_db, node);
+            match (binary_ast.op(syntax_db), binary_ast.rhs(syntax_db)) {
+                (ast::BinaryOperator::Dot(_), ast::Expr::FunctionCall(call_ast)) => {
+                    (call_ast, true)
+                }
+                _ => return vec![],
+            }
+        }
+        _ => return vec![],
+    };
+
+    let mut hints = vec![];
+    if config.parameter_names {
+        if let Ok(signature) = db.concrete_function_signature(call.function) {
+            let params = signature.params.get(usize::from(is_method)..).unwrap_or_default();
+            let args = call_ast.arguments(syntax_db).arguments(syntax_db).elements(syntax_db);
+            if params.len() == args.len() {
+                for (param, arg) in params.iter().zip(args) {
+                    let ast::ArgClause::Unnamed(arg_clause) = arg.arg_clause(syntax_db) else {
+                        continue;
+                    };
+                    let arg_text = arg_clause
+                        .value(syntax_db)
+                        .as_syntax_node()
+                        .get_text_without_trivia(syntax_db);
+                    // Arguments named like their parameter need no hint.
+                    if param.name.starts_with('_') || arg_text.trim_start_matches('@') == param.name
+                    {
+                        continue;
+                    }
+                    hints.push((
+                        arg.as_syntax_node(),
+                        Hint {
+                            label: format!("{}:", param.name),
+                            kind: InlayHintKind::PARAMETER,
+                            placement: Placement::Before,
+                        },
+                    ));
+                }
+            }
+        }
+    }
+    if config.impls {
+        if let GenericFunctionId::Impl(ImplGenericFunctionId {
+            impl_id: ImplId::Concrete(concrete_impl_id),
+            ..
+        }) = call.function.get_concrete(db).generic_function
+        {
+            let impl_def_id = concrete_impl_id.impl_def_id(db);
+            // Impls generated by plugins are an implementation detail of the expanded code.
+            let impl_file = impl_def_id.module_file_id(db).file_id(db);
+            if impl_file.map_or(true, |impl_file| is_generated_file(db, impl_file)) {
+                return hints;
+            }
+            let impl_name = impl_def_id.name(db);
+            let segments = call_ast.path(syntax_db).elements(syntax_db);
+            let mut segments = segments.iter().rev();
+            let function_segment = segments.next();
+            // Calls through the impl itself already name it.
+            let names_impl =
+                segments.next().map_or(false, |segment| segment.identifier(syntax_db) == impl_name);
+            if let (Some(function_segment), false) = (function_segment, names_impl) {
+                hints.push((
+                    function_segment.as_syntax_node(),
+                    Hint {
+                        label: format!("{impl_name}::"),
+                        kind: InlayHintKind::TYPE,
+                        placement: Placement::Before,
+                    },
+                ));
+            }
+        }
+    }
+    hints
+}
+
+/// Converts an offset in a file to an LSP position.
+fn offset_position(db: &RootDatabase, file: FileId, offset: TextOffset) -> Option<Position> {
+    Some(from_pos(offset.position_in_file(db.upcast(), file)?))
+}
@@ -0,0 +1,223 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use test_case::test_case;
+use tower_lsp::lsp_types::{InlayHintLabel, Position, Range, TextEdit};
+
+use super::{inlay_hints, InlayHintsConfig};
+use crate::config::Config;
+use crate::test_utils::{apply_text_edits, setup_test_file};
+
+const CODE: &str = indoc! {"
+    #[derive(Copy, Drop)]
+    struct Point {
+        x: felt252,
+        y: felt252,
+    }
+    trait Shift<T> {
+        fn shift(self: T, dx: felt252) -> T;
+    }
+    impl PointShift of Shift<Point> {
+        fn shift(self: Point, dx: felt252) -> Point {
+            Point { x: self.x + dx, y: self.y }
+        }
+    }
+    fn make(x: felt252, y: felt252) -> Point {
+        Point { x, y }
+    }
+    fn foo() -> Point {
+        let x = 1;
+        let p = make(x, 2);
+        let (a, _b) = (p.x, 3_u8);
+        let q: Point = p.shift(a);
+        PointShift::shift(Shift::shift(q, 4), dx: 5)
+    }
+"};
+
+/// Returns the code with the inlay hints within the given lines inserted, enclosed in `<` and `>`.
+fn code_with_hints(
+    code: &str,
+    config: &InlayHintsConfig,
+    start_line: u32,
+    end_line: u32,
+) -> String {
+    let (db, file) = setup_test_file(code);
+    let range = Range {
+        start: Position { line: start_line, character: 0 },
+        end: Position { line: end_line, character: 0 },
+    };
+    let edits = inlay_hints(&db, file, range, config)
+        .into_iter()
+        .map(|hint| {
+            let InlayHintLabel::String(label) = hint.label else {
+                panic!("Unexpected label parts.");
+            };
+            TextEdit {
+                range: Range { start: hint.position, end: hint.position },
+                new_text: format!("<{label}>"),
+            }
+        })
+        .collect();
+    apply_text_edits(code, edits)
+}
+
+#[test]
+fn test_inlay_hints() {
+    assert_eq!(
+        code_with_hints(CODE, &InlayHintsConfig::default(), 0, u32::MAX),
+        indoc! {"
+            #[derive(Copy, Drop)]
+            struct Point {
+                x: felt252,
+                y: felt252,
+            }
+            trait Shift<T> {
+                fn shift(self: T, dx: felt252) -> T;
+            }
+            impl PointShift of Shift<Point> {
+                fn shift(self: Point, dx: felt252) -> Point {
+                    Point { x: self.x + dx, y: self.y }
+                }
+            }
+            fn make(x: felt252, y: felt252) -> Point {
+                Point { x, y }
+            }
+            fn foo() -> Point {
+                let x<: core::felt252> = 1;
+                let p<: test::Point> = make(x, <y:>2);
+                let (a<: core::felt252>, _b<: core::integer::u8>) = (p.x, 3_u8);
+                let q: Point = p.<PointShift::>shift(<dx:>a);
+                PointShift::shift(<self:>Shift::<PointShift::>shift(<self:>q, <dx:>4), dx: 5)
+            }
+        "}
+    );
+}
+
+#[test]
+fn test_inlay_hints_in_range() {
+    assert_eq!(
+        code_with_hints(CODE, &InlayHintsConfig::default(), 17, 19),
+        indoc! {"
+            #[derive(Copy, Drop)]
+            struct Point {
+                x: felt252,
+                y: felt252,
+            }
+            trait Shift<T> {
+                fn shift(self: T, dx: felt252) -> T;
+            }
+            impl PointShift of Shift<Point> {
+                fn shift(self: Point, dx: felt252) -> Point {
+                    Point { x: self.x + dx, y: self.y }
+                }
+            }
+            fn make(x: felt252, y: felt252) -> Point {
+                Point { x, y }
+            }
+            fn foo() -> Point {
+                let x<: core::felt252> = 1;
+                let p<: test::Point> = make(x, <y:>2);
+                let (a, _b) = (p.x, 3_u8);
+                let q: Point = p.shift(a);
+                PointShift::shift(Shift::shift(q, 4), dx: 5)
+            }
+        "}
+    );
+}
+
+#[test_case(
+    json!({"inlayHints": {"types": false}}),
+    indoc! {"
+        #[derive(Copy, Drop)]
+        struct Point {
+            x: felt252,
+            y: felt252,
+        }
+        trait Shift<T> {
+            fn shift(self: T, dx: felt252) -> T;
+        }
+        impl PointShift of Shift<Point> {
+            fn shift(self: Point, dx: felt252) -> Point {
+                Point { x: self.x + dx, y: self.y }
+            }
+        }
+        fn make(x: felt252, y: felt252) -> Point {
+            Point { x, y }
+        }
+        fn foo() -> Point {
+            let x = 1;
+            let p = make(x, <y:>2);
+            let (a, _b) = (p.x, 3_u8);
+            let q: Point = p.<PointShift::>shift(<dx:>a);
+            PointShift::shift(<self:>Shift::<PointShift::>shift(<self:>q, <dx:>4), dx: 5)
+        }
+    "};
+    "without types"
+)]
+#[test_case(
+    json!({"inlayHints": {"parameterNames": false}}),
+    indoc! {"
+        #[derive(Copy, Drop)]
+        struct Point {
+            x: felt252,
+            y: felt252,
+        }
+        trait Shift<T> {
+            fn shift(self: T, dx: felt252) -> T;
+        }
+        impl PointShift of Shift<Point> {
+            fn shift(self: Point, dx: felt252) -> Point {
+                Point { x: self.x + dx, y: self.y }
+            }
+        }
+        fn make(x: felt252, y: felt252) -> Point {
+            Point { x, y }
+        }
+        fn foo() -> Point {
+            let x<: core::felt252> = 1;
+            let p<: test::Point> = make(x, 2);
+            let (a<: core::felt252>, _b<: core::integer::u8>) = (p.x, 3_u8);
+            let q: Point = p.<PointShift::>shift(a);
+            PointShift::shift(Shift::<PointShift::>shift(q, 4), dx: 5)
+        }
+    "};
+    "without parameter names"
+)]
+#[test_case(
+    json!({"inlayHints": {"impls": false}}),
+    indoc! {"
+        #[derive(Copy, Drop)]
+        struct Point {
+            x: felt252,
+            y: felt252,
+        }
+        trait Shift<T> {
+            fn shift(self: T, dx: felt252) -> T;
+        }
+        impl PointShift of Shift<Point> {
+            fn shift(self: Point, dx: felt252) -> Point {
+                Point { x: self.x + dx, y: self.y }
+            }
+        }
+        fn make(x: felt252, y: felt252) -> Point {
+            Point { x, y }
+        }
+        fn foo() -> Point {
+            let x<: core::felt252> = 1;
+            let p<: test::Point> = make(x, <y:>2);
+            let (a<: core::felt252>, _b<: core::integer::u8>) = (p.x, 3_u8);
+            let q: Point = p.shift(<dx:>a);
+            PointShift::shift(<self:>Shift::shift(<self:>q, <dx:>4), dx: 5)
+        }
+    "};
+    "without impls"
+)]
+#[test_case(
+    json!({"inlayHints": {"types": false, "parameterNames": false, "impls": false}}),
+    CODE;
+    "without any"
+)]
+fn test_inlay_hints_config(config: serde_json::Value, expected: &str) {
+    let config = Config::from_value(config).inlay_hints;
+    assert_eq!(code_with_hints(CODE, &config, 0, u32::MAX), expected);
+}
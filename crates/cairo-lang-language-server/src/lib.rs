@@ -5,6 +5,7 @@
 use std::collections::{HashMap, HashSet};
 use std::panic::AssertUnwindSafe;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 
@@ -22,7 +23,6 @@ use cairo_lang_defs::ids::{
 use cairo_lang_diagnostics::{
     DiagnosticEntry, DiagnosticLocation, Diagnostics, Severity, ToOption,
 };
-use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_filesystem::db::{
     get_originating_location, init_dev_corelib, AsFilesGroupMut, CrateConfiguration, CrateSettings,
     FilesGroup, FilesGroupEx, PrivRawFileContentQuery,
@@ -30,7 +30,7 @@ use cairo_lang_filesystem::db::{
 use cairo_lang_filesystem::detect::detect_corelib;
 use cairo_lang_filesystem::ids::{CrateId, CrateLongId, Directory, FileId, FileLongId};
 use cairo_lang_filesystem::span::{FileSummary, TextOffset, TextPosition, TextWidth};
-use cairo_lang_formatter::{get_formatted_file, FormatterConfig};
+use cairo_lang_formatter::get_formatted_file;
 use cairo_lang_lowering::db::LoweringGroup;
 use cairo_lang_lowering::diagnostic::LoweringDiagnostic;
 use cairo_lang_parser::db::ParserGroup;
@@ -56,48 +56,85 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::{try_extract_matches, OptionHelper, Upcast};
 use log::warn;
-use salsa::InternKey;
+use salsa::{Database, InternKey};
 use semantic_highlighting::token_kind::SemanticTokenKind;
 use semantic_highlighting::SemanticTokensTraverser;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tower_lsp::jsonrpc::{Error as LSPError, Result as LSPResult};
 use tower_lsp::lsp_types::notification::Notification;
+use tower_lsp::lsp_types::request::{
+    GotoImplementationParams, GotoImplementationResponse, GotoTypeDefinitionParams,
+    GotoTypeDefinitionResponse,
+};
 use tower_lsp::lsp_types::*;
 use tower_lsp::{Client, LanguageServer, LspService, Server};
-use vfs::{ProvideVirtualFileRequest, ProvideVirtualFileResponse};
+use vfs::{
+    apply_content_change, ExpandMacroResponse, ProvideVirtualFileRequest,
+    ProvideVirtualFileResponse,
+};
 
+use crate::analysis_cache::AnalysisCache;
+use crate::code_actions::code_actions;
 use crate::completions::{colon_colon_completions, dot_completions, generic_completions};
+use crate::config::{Config, DiagnosticsLevel, CONFIG_SECTION};
+use crate::inlay_hints::inlay_hints;
+use crate::macro_expansion::{expand_macro, macro_name_expansion};
+use crate::navigation::{
+    implementations, incoming_calls, outgoing_calls, prepare_call_hierarchy, type_definition,
+};
+use crate::references::{
+    document_highlights, find_references, is_valid_identifier, lsp_location, lsp_range,
+};
 use crate::scarb_service::{is_scarb_manifest_path, ScarbService};
-
+use crate::signature_help::signature_help;
+use crate::structure::{folding_ranges, selection_ranges};
+use crate::symbols::{crate_symbols, document_symbols, matching_symbols};
+
+mod analysis_cache;
+mod code_actions;
+mod config;
+mod inlay_hints;
+mod macro_expansion;
+mod navigation;
+mod references;
 mod scarb_service;
 mod semantic_highlighting;
+mod signature_help;
+mod structure;
+mod symbols;
+#[cfg(test)]
+mod test_utils;
 
 pub mod completions;
 pub mod vfs;
 
 const MAX_CRATE_DETECTION_DEPTH: usize = 20;
 const DEFAULT_CAIRO_LSP_DB_REPLACE_INTERVAL: u64 = 300;
+const DEFAULT_CAIRO_LSP_DIAGNOSTICS_DELAY: u64 = 200;
 
 pub async fn serve_language_service() {
     let (stdin, stdout) = (tokio::io::stdin(), tokio::io::stdout());
 
-    let db = configured_db();
+    let db = configured_db(&Config::default());
 
     let (service, socket) = LspService::build(|client| Backend::new(client, db))
         .custom_method("vfs/provide", Backend::vfs_provide)
+        .custom_method("cairo/expandMacro", Backend::expand_macro)
         .finish();
     Server::new(stdin, stdout, socket).serve(service).await;
 }
 
-fn configured_db() -> RootDatabase {
-    let db = RootDatabase::builder()
-        .with_cfg(CfgSet::from_iter([Cfg::name("test")]))
-        .with_plugin_suite(starknet_plugin_suite())
-        .with_plugin_suite(test_plugin_suite())
-        .build()
-        .expect("Failed to initialize Cairo compiler database.");
-    db
+fn configured_db(config: &Config) -> RootDatabase {
+    let mut builder = RootDatabase::builder();
+    builder.with_cfg(config.cfg_set());
+    if config.plugins.starknet {
+        builder.with_plugin_suite(starknet_plugin_suite());
+    }
+    if config.plugins.test {
+        builder.with_plugin_suite(test_plugin_suite());
+    }
+    builder.build().expect("Failed to initialize Cairo compiler database.")
 }
 
 /// Makes sure that all open files exist in the new db, with their current changes.
@@ -118,6 +155,21 @@ fn ensure_exists_in_db(
     new_db.set_file_overrides(Arc::new(new_overrides));
 }
 
+/// Builds a database for a new configuration, keeping the open files of the old one and setting up
+/// the corelib override, if any.
+fn reconfigured_db(
+    config: &Config,
+    old_db: &RootDatabase,
+    open_files: impl Iterator<Item = Url>,
+) -> RootDatabase {
+    let mut new_db = configured_db(config);
+    ensure_exists_in_db(&mut new_db, old_db, open_files);
+    if let Some(corelib) = config.corelib() {
+        init_dev_corelib(&mut new_db, corelib);
+    }
+    new_db
+}
+
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct FileDiagnostics {
     pub parser: Diagnostics<ParserDiagnostic>,
@@ -129,6 +181,12 @@ impl std::panic::UnwindSafe for FileDiagnostics {}
 pub struct State {
     pub file_diagnostics: HashMap<Url, FileDiagnostics>,
     pub open_files: HashSet<Url>,
+    pub config: Config,
+    /// Files changed since their diagnostics were last refreshed.
+    pub changed_files: HashSet<Url>,
+    /// The workspace folders opened by the client.
+    pub workspace_roots: Vec<PathBuf>,
+    pub analysis_cache: Option<AnalysisCache>,
 }
 impl std::panic::UnwindSafe for State {}
 
@@ -160,6 +218,11 @@ pub struct Backend {
     pub notification: NotificationService,
     last_replace: tokio::sync::Mutex<SystemTime>,
     db_replace_interval: Duration,
+    /// Bumped on every document change, to tell when changes settle.
+    changes_generation: AtomicU64,
+    /// Whether a diagnostics refresh is already waiting for changes to settle.
+    diagnostics_scheduled: AtomicBool,
+    diagnostics_delay: Duration,
 }
 fn from_pos(pos: TextPosition) -> Position {
     Position { line: pos.line as u32, character: pos.col as u32 }
@@ -167,11 +230,15 @@ fn from_pos(pos: TextPosition) -> Position {
 impl Backend {
     pub fn new(client: Client, db: RootDatabase) -> Self {
         let notification = NotificationService::new(client.clone());
+        let mut analysis_cache = AnalysisCache::from_env();
+        if let Some(cache) = &mut analysis_cache {
+            cache.set_settings_fingerprint(Config::default().analysis_fingerprint());
+        }
         Self {
             client,
             db_mutex: db.into(),
             notification: notification.clone(),
-            state_mutex: State::default().into(),
+            state_mutex: State { analysis_cache, ..State::default() }.into(),
             scarb: ScarbService::new(notification),
             last_replace: tokio::sync::Mutex::new(SystemTime::now()),
             db_replace_interval: Duration::from_secs(
@@ -180,11 +247,21 @@ impl Backend {
                     .and_then(|value| value.parse::<u64>().ok())
                     .unwrap_or(DEFAULT_CAIRO_LSP_DB_REPLACE_INTERVAL),
             ),
+            changes_generation: AtomicU64::new(0),
+            diagnostics_scheduled: AtomicBool::new(false),
+            diagnostics_delay: Duration::from_millis(
+                std::env::var("CAIRO_LSP_DIAGNOSTICS_DELAY")
+                    .ok()
+                    .and_then(|value| value.parse::<u64>().ok())
+                    .unwrap_or(DEFAULT_CAIRO_LSP_DIAGNOSTICS_DELAY),
+            ),
         }
     }
 
     /// Runs a function with a database snapshot.
     /// Catches panics and returns Err.
+    /// Results computed while a change waits for the snapshot to be released are stale, and are
+    /// discarded with a `ContentModified` error.
     async fn with_db<F, T>(&self, f: F) -> LSPResult<T>
     where
         F: FnOnce(&RootDatabase) -> T + std::panic::UnwindSafe,
@@ -192,10 +269,14 @@ impl Backend {
         let db_mut = self.db_mut().await;
         let db = db_mut.snapshot();
         drop(db_mut);
-        std::panic::catch_unwind(AssertUnwindSafe(|| f(&db))).map_err(|_| {
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| f(&db))).map_err(|_| {
             eprintln!("Caught panic in LSP worker thread.");
             LSPError::internal_error()
-        })
+        })?;
+        if db.salsa_runtime().is_current_revision_canceled() {
+            return Err(LSPError::content_modified());
+        }
+        Ok(result)
     }
 
     /// Locks and gets a database instance.
@@ -211,13 +292,31 @@ impl Backend {
         let real_state = self.state_mutex.lock().await;
         let state = real_state.clone();
         drop(real_state);
-        let (state, res) = self
+        let Some((state, res)) = self
             .with_db(|db| {
                 let mut state = state;
                 let mut res = vec![];
+                let editable_roots = editable_roots(&state);
+                // Files whose diagnostics are served from the analysis cache.
+                let mut cached_files = HashSet::new();
+                let mut crates_to_cache = vec![];
                 // Get all files. Try to go over open files first.
                 let mut files_set: OrderedHashSet<_> = state.open_files.iter().cloned().collect();
                 for crate_id in db.crates() {
+                    if let Some(cache) = &mut state.analysis_cache {
+                        if AnalysisCache::is_cacheable(db.upcast(), crate_id, &editable_roots) {
+                            let was_served = cache.is_served(db.upcast(), crate_id);
+                            if let Some(diagnostics) = cache.get(db.upcast(), crate_id) {
+                                cached_files.extend(diagnostics.iter().map(|(uri, _)| uri.clone()));
+                                // Cached diagnostics are published once per session.
+                                if !was_served {
+                                    res.extend(diagnostics);
+                                }
+                                continue;
+                            }
+                            crates_to_cache.push(crate_id);
+                        }
+                    }
                     for module_id in db.crate_modules(crate_id).iter() {
                         for file_id in
                             db.module_files(*module_id).unwrap_or_default().iter().copied()
@@ -229,42 +328,74 @@ impl Backend {
 
                 // Get all diagnostics.
                 for uri in files_set.iter().cloned() {
-                    let file_id = file(db, uri.clone());
-                    let new_file_diagnostics = FileDiagnostics {
-                        parser: db.file_syntax_diagnostics(file_id),
-                        semantic: db.file_semantic_diagnostics(file_id).unwrap_or_default(),
-                        lowering: db.file_lowering_diagnostics(file_id).unwrap_or_default(),
-                    };
-                    // Since we are using Arcs, this comparison should be efficient.
-                    if let Some(old_file_diagnostics) = state.file_diagnostics.get(&uri) {
-                        if old_file_diagnostics == &new_file_diagnostics {
-                            continue;
-                        }
+                    // A newer change makes the results stale, and is followed by another refresh.
+                    if db.salsa_runtime().is_current_revision_canceled() {
+                        return None;
                     }
-                    let mut diags = Vec::new();
-                    get_diagnostics(db.upcast(), &mut diags, &new_file_diagnostics.parser);
-                    get_diagnostics(db.upcast(), &mut diags, &new_file_diagnostics.semantic);
-                    get_diagnostics(db.upcast(), &mut diags, &new_file_diagnostics.lowering);
-                    state.file_diagnostics.insert(uri.clone(), new_file_diagnostics);
+                    let old_file_diagnostics = state.file_diagnostics.get(&uri);
+                    if let Some((new_file_diagnostics, diags)) = updated_file_diagnostics(
+                        db,
+                        &uri,
+                        old_file_diagnostics,
+                        state.config.diagnostics_level,
+                    ) {
+                        state.file_diagnostics.insert(uri.clone(), new_file_diagnostics);
+                        res.push((uri, diags));
+                    }
+                }
 
-                    res.push((uri, diags));
+                if let Some(cache) = &mut state.analysis_cache {
+                    for crate_id in crates_to_cache {
+                        let mut diagnostics = vec![];
+                        for module_id in db.crate_modules(crate_id).iter() {
+                            for file_id in
+                                db.module_files(*module_id).unwrap_or_default().iter().copied()
+                            {
+                                let uri = get_uri(db, file_id);
+                                let diags = state
+                                    .file_diagnostics
+                                    .get(&uri)
+                                    .map(|file_diagnostics| lsp_diagnostics(db, file_diagnostics))
+                                    .unwrap_or_default();
+                                diagnostics.push((uri, diags));
+                            }
+                        }
+                        cache.insert(
+                            db.upcast(),
+                            crate_id,
+                            diagnostics,
+                            crate_symbols(db, crate_id),
+                        );
+                    }
                 }
 
                 // Clear old diagnostics.
                 let old_files: Vec<_> = state.file_diagnostics.keys().cloned().collect();
                 for uri in old_files {
-                    if files_set.contains(&uri) {
+                    if files_set.contains(&uri) || cached_files.contains(&uri) {
                         continue;
                     }
                     state.file_diagnostics.remove(&uri);
                     res.push((uri, Vec::new()));
                 }
 
-                (state, res)
+                Some((state, res))
             })
-            .await?;
+            .await?
+        else {
+            return Err(LSPError::content_modified());
+        };
+        // Only the results of the refresh are written back, as the rest of the state (e.g. the open
+        // files or the config) may have changed while it ran.
         let mut real_state = self.state_mutex.lock().await;
-        *real_state = state;
+        real_state.file_diagnostics = state.file_diagnostics;
+        let mut analysis_cache = state.analysis_cache;
+        if let (Some(cache), Some(real_cache)) = (&mut analysis_cache, &real_state.analysis_cache) {
+            // Results computed under settings changed during the refresh are outdated.
+            cache.set_settings_fingerprint(real_cache.settings_fingerprint().clone());
+            cache.save();
+        }
+        real_state.analysis_cache = analysis_cache;
         drop(real_state);
 
         for (uri, diags) in res {
@@ -275,6 +406,68 @@ impl Backend {
         self.maybe_swap_database().await
     }
 
+    /// Refreshes the diagnostics of the given files only, and sends diffs to client.
+    async fn refresh_file_diagnostics(&self, uris: Vec<Url>) -> LSPResult<()> {
+        let state = self.state_mutex.lock().await;
+        let diagnostics_level = state.config.diagnostics_level;
+        let old_file_diagnostics: HashMap<_, _> = uris
+            .iter()
+            .filter_map(|uri| Some((uri.clone(), state.file_diagnostics.get(uri)?.clone())))
+            .collect();
+        drop(state);
+        let res = self
+            .with_db(|db| {
+                let mut res = vec![];
+                for uri in uris {
+                    if db.salsa_runtime().is_current_revision_canceled() {
+                        break;
+                    }
+                    if let Some((new_file_diagnostics, diags)) = updated_file_diagnostics(
+                        db,
+                        &uri,
+                        old_file_diagnostics.get(&uri),
+                        diagnostics_level,
+                    ) {
+                        res.push((uri, new_file_diagnostics, diags));
+                    }
+                }
+                res
+            })
+            .await?;
+        let mut state = self.state_mutex.lock().await;
+        for (uri, new_file_diagnostics, _) in &res {
+            state.file_diagnostics.insert(uri.clone(), new_file_diagnostics.clone());
+        }
+        drop(state);
+
+        for (uri, _, diags) in res {
+            self.client.publish_diagnostics(uri, diags, None).await
+        }
+        Ok(())
+    }
+
+    /// Refreshes diagnostics once document changes settle for the diagnostics delay: first of the
+    /// changed files, then of all the others.
+    async fn schedule_diagnostics_refresh(&self) {
+        self.changes_generation.fetch_add(1, Ordering::SeqCst);
+        if self.diagnostics_scheduled.swap(true, Ordering::SeqCst) {
+            // The scheduled refresh will also cover this change.
+            return;
+        }
+        loop {
+            let generation = self.changes_generation.load(Ordering::SeqCst);
+            tokio::time::sleep(self.diagnostics_delay).await;
+            if self.changes_generation.load(Ordering::SeqCst) == generation {
+                break;
+            }
+        }
+        self.diagnostics_scheduled.store(false, Ordering::SeqCst);
+        let changed_files = self.state_mutex.lock().await.changed_files.drain().collect();
+        if self.refresh_file_diagnostics(changed_files).await.is_ok() {
+            self.refresh_diagnostics().await.ok();
+        }
+    }
+
     /// Checks if enough time passed since last db swap, and if so, swaps the database.
     async fn maybe_swap_database(&self) -> LSPResult<()> {
         let Ok(mut last_replace) = self.last_replace.try_lock() else {
@@ -285,17 +478,19 @@ impl Backend {
             // Not enough time passed since last swap.
             return Ok(());
         }
-        let open_files = self.state_mutex.lock().await.open_files.clone();
+        let state = self.state_mutex.lock().await.clone();
+        let open_files = state.open_files.clone();
         eprintln!("DB swap - scheduled.");
         let mut new_db = self
             .with_db(|db| {
-                let mut new_db = configured_db();
+                let mut new_db = configured_db(&state.config);
                 ensure_exists_in_db(&mut new_db, db, open_files.iter().cloned());
                 new_db
             })
             .await?;
         eprintln!("DB swap - initial setup done.");
-        self.ensure_diagnostics_queries_up_to_date(&mut new_db, open_files.into_iter()).await;
+        self.ensure_diagnostics_queries_up_to_date(&mut new_db, open_files.into_iter(), &state)
+            .await;
         eprintln!("DB swap - initial compilation done.");
         let mut db = self.db_mut().await;
         eprintln!("DB swap - starting.");
@@ -307,16 +502,23 @@ impl Backend {
         Ok(())
     }
 
-    /// Ensures that all diagnostics are up to date.
+    /// Ensures that all diagnostics are up to date, except for the crates served from the analysis
+    /// cache.
     async fn ensure_diagnostics_queries_up_to_date(
         &self,
         db: &mut RootDatabase,
         open_files: impl Iterator<Item = Url>,
+        state: &State,
     ) {
+        let level = state.config.diagnostics_level;
         let query_diags = |db: &RootDatabase, file_id| {
             db.file_syntax_diagnostics(file_id);
-            let _ = db.file_semantic_diagnostics(file_id);
-            let _ = db.file_lowering_diagnostics(file_id);
+            if level >= DiagnosticsLevel::Semantic {
+                let _ = db.file_semantic_diagnostics(file_id);
+            }
+            if level >= DiagnosticsLevel::Lowering {
+                let _ = db.file_lowering_diagnostics(file_id);
+            }
         };
         for uri in open_files {
             let file_id = file(db, uri.clone());
@@ -325,7 +527,15 @@ impl Backend {
             }
             query_diags(db, file_id);
         }
+        let editable_roots = editable_roots(state);
         for crate_id in db.crates() {
+            if let Some(cache) = &state.analysis_cache {
+                if AnalysisCache::is_cacheable(db, crate_id, &editable_roots)
+                    && cache.is_served(db, crate_id)
+                {
+                    continue;
+                }
+            }
             for module_id in db.crate_modules(crate_id).iter() {
                 for file_id in db.module_files(*module_id).unwrap_or_default().iter().copied() {
                     query_diags(db, file_id);
@@ -345,56 +555,70 @@ impl Backend {
         .await
     }
 
-    /// Get corelib path fallback from the client configuration.
-    ///
-    /// The value is set by the user under the `cairo1.corelibPath` key in client configuration.
-    /// The value is not required to be set.
-    /// The path may omit the `corelib/src` or `src` suffix.
-    async fn get_corelib_fallback_path(&self) -> Option<PathBuf> {
-        const CORELIB_CONFIG_SECTION: &str = "cairo1.corelibPath";
-        let item = vec![ConfigurationItem {
-            scope_uri: None,
-            section: Some(CORELIB_CONFIG_SECTION.to_string()),
-        }];
-        let corelib_response = self.client.configuration(item).await;
-        match corelib_response.map_err(Error::from) {
-            Ok(value_vec) => {
-                if let Some(Value::String(value)) = value_vec.first() {
-                    if !value.is_empty() {
-                        let root_path: PathBuf = value.into();
-
-                        let mut path = root_path.clone();
-                        path.push("corelib");
-                        path.push("src");
-                        if path.exists() {
-                            return Some(path);
-                        }
+    /// Returns the code generated by plugins for the item or inline macro at a position.
+    pub async fn expand_macro(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> LSPResult<ExpandMacroResponse> {
+        eprintln!("Expand macro");
+        self.with_db(|db| {
+            let file = file(db, params.text_document.uri);
+            ExpandMacroResponse { content: expand_macro(db, file, params.position) }
+        })
+        .await
+    }
 
-                        let mut path = root_path.clone();
-                        path.push("src");
-                        if path.exists() {
-                            return Some(path);
-                        }
+    /// Reads the server configuration from the client.
+    async fn fetch_config(&self) -> Option<Config> {
+        let item =
+            vec![ConfigurationItem { scope_uri: None, section: Some(CONFIG_SECTION.to_string()) }];
+        match self.client.configuration(item).await.map_err(Error::from) {
+            Ok(mut value_vec) => Some(Config::from_value(value_vec.pop().unwrap_or_default())),
+            Err(err) => {
+                let err = err
+                    .context(format!("Failed to get configuration under `{CONFIG_SECTION}` key."));
+                warn!("{err:?}");
+                None
+            }
+        }
+    }
 
-                        if root_path.exists() {
-                            return Some(root_path);
-                        }
-                    }
+    /// Applies a new server configuration, rebuilding the database if the analysis settings
+    /// changed, and refreshing what the client shows.
+    async fn apply_config(&self, config: Config) {
+        let mut db = self.db_mut().await;
+        let mut state = self.state_mutex.lock().await;
+        let old_config = std::mem::replace(&mut state.config, config.clone());
+        if let Some(cache) = &mut state.analysis_cache {
+            cache.set_settings_fingerprint(config.analysis_fingerprint());
+        }
+        let open_files = state.open_files.clone();
+        drop(state);
+        if config.requires_db_rebuild(&old_config) {
+            eprintln!("Rebuilding the database for the new configuration.");
+            *db = reconfigured_db(&config, &db, open_files.iter().cloned());
+            for uri in open_files {
+                let file_id = file(&db, uri);
+                if let FileLongId::OnDisk(file_path) = db.lookup_intern_file(file_id) {
+                    self.detect_crate_for(&mut db, file_path).await;
                 }
             }
-            Err(err) => {
-                let err =
-                    err.context("Failed to get configuration under `cairo1.corelibPath` key.");
-                warn!("{err:?}");
+        }
+        drop(db);
+        if config.inlay_hints != old_config.inlay_hints {
+            if let Err(err) = self.client.inlay_hint_refresh().await {
+                warn!("Failed to refresh inlay hints: {err:?}");
             }
-        };
-        None
+        }
+        if config != old_config {
+            self.refresh_diagnostics().await.ok();
+        }
     }
 
     /// Tries to detect the crate root the config that contains a cairo file, and add it to the
     /// system.
     async fn detect_crate_for(&self, db: &mut RootDatabase, file_path: PathBuf) {
-        let corelib_fallback = self.get_corelib_fallback_path().await;
+        let corelib_override = self.state_mutex.lock().await.config.corelib();
         if self.scarb.is_scarb_project(file_path.clone()) {
             if self.scarb.is_scarb_found() {
                 // Carrying out Scarb based setup.
@@ -407,7 +631,7 @@ impl Backend {
                         None
                     }
                 };
-                if let Some(corelib) = corelib.or_else(detect_corelib).or(corelib_fallback) {
+                if let Some(corelib) = corelib_override.or(corelib).or_else(detect_corelib) {
                     init_dev_corelib(db, corelib);
                 } else {
                     warn!("Failed to find corelib path.");
@@ -431,7 +655,7 @@ impl Backend {
         }
 
         // Scarb based setup not possible.
-        if let Some(corelib) = detect_corelib().or(corelib_fallback) {
+        if let Some(corelib) = corelib_override.or_else(detect_corelib) {
             init_dev_corelib(db, corelib);
         } else {
             warn!("Failed to find corelib path.");
@@ -458,8 +682,9 @@ impl Backend {
     /// Reload crate detection for all open files.
     pub async fn reload(&self) -> LSPResult<()> {
         let mut db = self.db_mut().await;
-        for uri in self.state_mutex.lock().await.open_files.iter() {
-            let file_id = file(&db, uri.clone());
+        let open_files = self.state_mutex.lock().await.open_files.clone();
+        for uri in open_files {
+            let file_id = file(&db, uri);
             if let FileLongId::OnDisk(file_path) = db.lookup_intern_file(file_id) {
                 self.detect_crate_for(&mut db, file_path).await;
             }
@@ -519,12 +744,25 @@ impl TryFrom<String> for ServerCommands {
 
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
-    async fn initialize(&self, _: InitializeParams) -> LSPResult<InitializeResult> {
+    async fn initialize(&self, params: InitializeParams) -> LSPResult<InitializeResult> {
+        let workspace_uris = match params.workspace_folders {
+            Some(workspace_folders) => {
+                workspace_folders.into_iter().map(|workspace_folder| workspace_folder.uri).collect()
+            }
+            None => params.root_uri.into_iter().collect::<Vec<_>>(),
+        };
+        self.state_mutex.lock().await.workspace_roots =
+            workspace_uris.into_iter().filter_map(|uri| uri.to_file_path().ok()).collect();
+        // The configuration is pulled from the client once initialized, but clients may already
+        // provide it as the initialization options.
+        if let Some(options) = params.initialization_options {
+            self.apply_config(Config::from_value(options)).await;
+        }
         Ok(InitializeResult {
             server_info: None,
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Kind(
-                    TextDocumentSyncKind::FULL,
+                    TextDocumentSyncKind::INCREMENTAL,
                 )),
                 completion_provider: Some(CompletionOptions {
                     resolve_provider: Some(false),
@@ -558,6 +796,26 @@ impl LanguageServer for Backend {
                 document_formatting_provider: Some(OneOf::Left(true)),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 definition_provider: Some(OneOf::Left(true)),
+                type_definition_provider: Some(TypeDefinitionProviderCapability::Simple(true)),
+                implementation_provider: Some(ImplementationProviderCapability::Simple(true)),
+                call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
+                references_provider: Some(OneOf::Left(true)),
+                document_highlight_provider: Some(OneOf::Left(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Left(true)),
+                inlay_hint_provider: Some(OneOf::Left(true)),
+                code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+                signature_help_provider: Some(SignatureHelpOptions {
+                    trigger_characters: Some(vec!["(".into(), "[".into(), ",".into()]),
+                    retrigger_characters: None,
+                    work_done_progress_options: Default::default(),
+                }),
                 ..ServerCapabilities::default()
             },
         })
@@ -584,6 +842,9 @@ impl LanguageServer for Backend {
         if let Err(err) = result {
             warn!("Failed to register workspace/didChangeWatchedFiles event: {:#?}", err);
         }
+        if let Some(config) = self.fetch_config().await {
+            self.apply_config(config).await;
+        }
     }
 
     async fn shutdown(&self) -> LSPResult<()> {
@@ -592,7 +853,17 @@ impl LanguageServer for Backend {
 
     async fn did_change_workspace_folders(&self, _: DidChangeWorkspaceFoldersParams) {}
 
-    async fn did_change_configuration(&self, _: DidChangeConfigurationParams) {}
+    async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
+        // Clients either push the new settings, or only notify that they changed.
+        let config = match params.settings.get(CONFIG_SECTION) {
+            Some(value) => Config::from_value(value.clone()),
+            None => match self.fetch_config().await {
+                Some(config) => config,
+                None => return,
+            },
+        };
+        self.apply_config(config).await;
+    }
 
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
         // Invalidate changed cairo files.
@@ -652,19 +923,17 @@ impl LanguageServer for Backend {
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
-        let text =
-            if let [TextDocumentContentChangeEvent { text, .. }] = &params.content_changes[..] {
-                text
-            } else {
-                eprintln!("Unexpected format of document change.");
-                return;
-            };
         let mut db = self.db_mut().await;
         let uri = params.text_document.uri;
         let file = file(&db, uri.clone());
-        db.override_file_content(file, Some(Arc::new(text.into())));
+        let mut text = db.file_content(file).map(|content| (*content).clone()).unwrap_or_default();
+        for change in &params.content_changes {
+            apply_content_change(&mut text, change);
+        }
+        db.override_file_content(file, Some(Arc::new(text)));
+        self.state_mutex.lock().await.changed_files.insert(uri);
         drop(db);
-        self.refresh_diagnostics().await.ok();
+        self.schedule_diagnostics_refresh().await;
     }
 
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
@@ -761,6 +1030,7 @@ impl LanguageServer for Backend {
         &self,
         params: DocumentFormattingParams,
     ) -> LSPResult<Option<Vec<TextEdit>>> {
+        let config = self.state_mutex.lock().await.config.formatter.clone();
         self.with_db(|db| {
             let file_uri = params.text_document.uri;
             let file = file(db, file_uri.clone());
@@ -770,7 +1040,7 @@ impl LanguageServer for Backend {
             db.file_syntax_diagnostics(file).check_error_free().ok().on_none(|| {
                 eprintln!("Formatting failed. Cannot properly parse '{file_uri}' exist.");
             })?;
-            let new_text = get_formatted_file(db.upcast(), &node, FormatterConfig::default());
+            let new_text = get_formatted_file(db.upcast(), &node, config);
 
             let file_summary = db.file_summary(file).on_none(|| {
                 eprintln!("Formatting failed. Cannot get summary for file '{file_uri}'.");
@@ -832,33 +1102,260 @@ impl LanguageServer for Backend {
     ) -> LSPResult<Option<GotoDefinitionResponse>> {
         eprintln!("Goto definition");
         self.with_db(|db| {
-            let syntax_db = db.upcast();
-            let file_uri = params.text_document_position_params.text_document.uri;
-            let file = file(db, file_uri.clone());
+            let file = file(db, params.text_document_position_params.text_document.uri);
             let position = params.text_document_position_params.position;
-            let (node, lookup_items) = get_node_and_lookup_items(db, file, position)?;
-            if node.kind(syntax_db) != SyntaxKind::TokenIdentifier {
+            find_definition_location(db, file, position).map(GotoDefinitionResponse::Scalar)
+        })
+        .await
+    }
+
+    async fn goto_type_definition(
+        &self,
+        params: GotoTypeDefinitionParams,
+    ) -> LSPResult<Option<GotoTypeDefinitionResponse>> {
+        eprintln!("Goto type definition");
+        let text_document_position_params = params.text_document_position_params;
+        self.with_db(|db| {
+            let file = file(db, text_document_position_params.text_document.uri);
+            let location = type_definition(db, file, text_document_position_params.position)?;
+            Some(GotoTypeDefinitionResponse::Scalar(location))
+        })
+        .await
+    }
+
+    async fn goto_implementation(
+        &self,
+        params: GotoImplementationParams,
+    ) -> LSPResult<Option<GotoImplementationResponse>> {
+        eprintln!("Goto implementation");
+        let text_document_position_params = params.text_document_position_params;
+        self.with_db(|db| {
+            let file = file(db, text_document_position_params.text_document.uri);
+            let locations = implementations(db, file, text_document_position_params.position)?;
+            Some(GotoImplementationResponse::Array(locations))
+        })
+        .await
+    }
+
+    async fn prepare_call_hierarchy(
+        &self,
+        params: CallHierarchyPrepareParams,
+    ) -> LSPResult<Option<Vec<CallHierarchyItem>>> {
+        eprintln!("Prepare call hierarchy");
+        let text_document_position_params = params.text_document_position_params;
+        self.with_db(|db| {
+            let file = file(db, text_document_position_params.text_document.uri);
+            prepare_call_hierarchy(db, file, text_document_position_params.position)
+        })
+        .await
+    }
+
+    async fn incoming_calls(
+        &self,
+        params: CallHierarchyIncomingCallsParams,
+    ) -> LSPResult<Option<Vec<CallHierarchyIncomingCall>>> {
+        eprintln!("Incoming calls");
+        self.with_db(|db| incoming_calls(db, &params.item)).await
+    }
+
+    async fn outgoing_calls(
+        &self,
+        params: CallHierarchyOutgoingCallsParams,
+    ) -> LSPResult<Option<Vec<CallHierarchyOutgoingCall>>> {
+        eprintln!("Outgoing calls");
+        self.with_db(|db| outgoing_calls(db, &params.item)).await
+    }
+
+    async fn signature_help(
+        &self,
+        params: SignatureHelpParams,
+    ) -> LSPResult<Option<SignatureHelp>> {
+        let text_document_position_params = params.text_document_position_params;
+        self.with_db(|db| {
+            let file = file(db, text_document_position_params.text_document.uri);
+            signature_help(db, file, text_document_position_params.position)
+        })
+        .await
+    }
+
+    async fn code_action(&self, params: CodeActionParams) -> LSPResult<Option<CodeActionResponse>> {
+        self.with_db(|db| {
+            let file = file(db, params.text_document.uri);
+            Some(code_actions(db, file, params.range, &params.context.diagnostics))
+        })
+        .await
+    }
+
+    async fn inlay_hint(&self, params: InlayHintParams) -> LSPResult<Option<Vec<InlayHint>>> {
+        let config = self.state_mutex.lock().await.config.inlay_hints.clone();
+        self.with_db(|db| {
+            let file = file(db, params.text_document.uri);
+            Some(inlay_hints(db, file, params.range, &config))
+        })
+        .await
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> LSPResult<Option<DocumentSymbolResponse>> {
+        eprintln!("Document symbol");
+        self.with_db(|db| {
+            let file = file(db, params.text_document.uri);
+            Some(DocumentSymbolResponse::Nested(document_symbols(db, file)?))
+        })
+        .await
+    }
+
+    async fn symbol(
+        &self,
+        params: WorkspaceSymbolParams,
+    ) -> LSPResult<Option<Vec<SymbolInformation>>> {
+        eprintln!("Workspace symbol");
+        let state = self.state_mutex.lock().await;
+        let analysis_cache = state.analysis_cache.clone();
+        let editable_roots = editable_roots(&state);
+        drop(state);
+        self.with_db(|db| {
+            // The items of the cached crates are served from the cache, sparing their analysis.
+            let symbols = db.crates().into_iter().flat_map(|crate_id| {
+                analysis_cache
+                    .as_ref()
+                    .filter(|_| AnalysisCache::is_cacheable(db, crate_id, &editable_roots))
+                    .and_then(|cache| cache.get_symbols(db, crate_id))
+                    .unwrap_or_else(|| crate_symbols(db, crate_id))
+            });
+            Some(matching_symbols(symbols, &params.query))
+        })
+        .await
+    }
+
+    async fn folding_range(
+        &self,
+        params: FoldingRangeParams,
+    ) -> LSPResult<Option<Vec<FoldingRange>>> {
+        eprintln!("Folding range");
+        self.with_db(|db| {
+            let file = file(db, params.text_document.uri);
+            folding_ranges(db, file)
+        })
+        .await
+    }
+
+    async fn selection_range(
+        &self,
+        params: SelectionRangeParams,
+    ) -> LSPResult<Option<Vec<SelectionRange>>> {
+        eprintln!("Selection range");
+        self.with_db(|db| {
+            let file = file(db, params.text_document.uri);
+            selection_ranges(db, file, &params.positions)
+        })
+        .await
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> LSPResult<Option<Vec<DocumentHighlight>>> {
+        self.with_db(|db| {
+            let file_uri = params.text_document_position_params.text_document.uri;
+            let file = file(db, file_uri);
+            document_highlights(db, file, params.text_document_position_params.position)
+        })
+        .await
+    }
+
+    async fn references(&self, params: ReferenceParams) -> LSPResult<Option<Vec<Location>>> {
+        eprintln!("References");
+        self.with_db(|db| {
+            let file_uri = params.text_document_position.text_document.uri;
+            let file = file(db, file_uri);
+            let position = params.text_document_position.position;
+            let references = find_references(db, file, position)?;
+            Some(
+                references
+                    .locations(params.context.include_declaration)
+                    .into_iter()
+                    .filter_map(|location| lsp_location(db, location))
+                    .collect(),
+            )
+        })
+        .await
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> LSPResult<Option<PrepareRenameResponse>> {
+        eprintln!("Prepare rename");
+        self.with_db(|db| {
+            let file = file(db, params.text_document.uri);
+            let references = find_references(db, file, params.position)?;
+            if !references.is_renameable(db) {
                 return None;
             }
-            let identifier =
-                ast::TerminalIdentifier::from_syntax_node(syntax_db, node.parent().unwrap());
-            let stable_ptr = find_definition(db, file, &identifier, &lookup_items)?;
-            let node = stable_ptr.lookup(syntax_db);
-            let found_file = stable_ptr.file_id(syntax_db);
-            let span = node.span_without_trivia(syntax_db);
-            let (found_file, span) = get_originating_location(db.upcast(), found_file, span);
-            let found_uri = get_uri(db, found_file);
-
-            let start = from_pos(span.start.position_in_file(db.upcast(), found_file).unwrap());
-            let end = from_pos(span.end.position_in_file(db.upcast(), found_file).unwrap());
-
-            Some(GotoDefinitionResponse::Scalar(Location {
-                uri: found_uri,
-                range: Range { start, end },
-            }))
+            let range = lsp_range(db, references.cursor_location())?;
+            Some(PrepareRenameResponse::Range(range))
         })
         .await
     }
+
+    async fn rename(&self, params: RenameParams) -> LSPResult<Option<WorkspaceEdit>> {
+        eprintln!("Rename");
+        self.with_db(|db| {
+            let file_uri = params.text_document_position.text_document.uri;
+            let file = file(db, file_uri);
+            let position = params.text_document_position.position;
+            let new_name = params.new_name;
+            if !is_valid_identifier(db, file, &new_name) {
+                return Err(LSPError::invalid_params(format!(
+                    "`{new_name}` is not a valid identifier."
+                )));
+            }
+            let Some(references) = find_references(db, file, position) else {
+                return Ok(None);
+            };
+            if !references.is_renameable(db) {
+                return Err(LSPError::invalid_params("This symbol cannot be renamed."));
+            }
+            let mut changes = HashMap::<Url, Vec<TextEdit>>::new();
+            for (location, new_text) in references.rename_edits(&new_name) {
+                let Some(Location { uri, range }) = lsp_location(db, location) else {
+                    continue;
+                };
+                changes.entry(uri).or_default().push(TextEdit { range, new_text });
+            }
+            Ok(Some(WorkspaceEdit { changes: Some(changes), ..WorkspaceEdit::default() }))
+        })
+        .await?
+    }
+}
+
+/// Returns the location of the definition of the identifier at the given position, mapped back to
+/// the user's code where possible. Macro names lead to the code they generate.
+fn find_definition_location(
+    db: &RootDatabase,
+    file: FileId,
+    position: Position,
+) -> Option<Location> {
+    let syntax_db = db.upcast();
+    let (node, lookup_items) = get_node_and_lookup_items(db, file, position)?;
+    if node.kind(syntax_db) != SyntaxKind::TokenIdentifier {
+        return None;
+    }
+    let identifier = ast::TerminalIdentifier::from_syntax_node(syntax_db, node.parent().unwrap());
+    let Some(stable_ptr) = find_definition(db, file, &identifier, &lookup_items) else {
+        let generated_file = macro_name_expansion(db, file, position)?;
+        return Some(Location { uri: get_uri(db, generated_file), range: Range::default() });
+    };
+    let node = stable_ptr.lookup(syntax_db);
+    let found_file = stable_ptr.file_id(syntax_db);
+    let span = node.span_without_trivia(syntax_db);
+    let (found_file, span) = get_originating_location(db.upcast(), found_file, span);
+    let start = from_pos(span.start.position_in_file(db.upcast(), found_file).unwrap());
+    let end = from_pos(span.end.position_in_file(db.upcast(), found_file).unwrap());
+    Some(Location { uri: get_uri(db, found_file), range: Range { start, end } })
 }
 
 fn find_definition(
@@ -920,6 +1417,7 @@ fn resolved_concrete_item_def(
 fn resolved_generic_item_def(db: &dyn DefsGroup, item: ResolvedGenericItem) -> SyntaxStablePtrId {
     match item {
         ResolvedGenericItem::Constant(item) => item.untyped_stable_ptr(db),
+        ResolvedGenericItem::TraitConstant(item) => item.untyped_stable_ptr(db),
         ResolvedGenericItem::Module(module_id) => {
             // Check if the module is an inline submodule.
             if let ModuleId::Submodule(submodule_id) = module_id {
@@ -1176,36 +1674,56 @@ fn get_node_and_lookup_items(
     file: FileId,
     position: Position,
 ) -> Option<(SyntaxNode, Vec<LookupItemId>)> {
-    let mut res = Vec::new();
+    let node = get_node(db, file, position)?;
+    let lookup_items = get_lookup_items(db, file, node.clone())?;
+    Some((node, lookup_items))
+}
+
+/// Given a position in a file, return the syntax node for the token at that position.
+fn get_node(db: &dyn SemanticGroup, file: FileId, position: Position) -> Option<SyntaxNode> {
     let syntax_db = db.upcast();
     let filename = file.file_name(db.upcast());
 
     // Get syntax for file.
     let syntax = db.file_syntax(file).to_option().on_none(|| {
-        eprintln!("`get_node_and_lookup_items` failed. File '{filename}' does not exist.");
+        eprintln!("`get_node` failed. File '{filename}' does not exist.");
     })?;
 
     // Get file summary and content.
     let file_summary = db.file_summary(file).on_none(|| {
-        eprintln!("`get_node_and_lookup_items` failed. File '{filename}' does not exist.");
+        eprintln!("`get_node` failed. File '{filename}' does not exist.");
     })?;
     let content = db.file_content(file).on_none(|| {
-        eprintln!("`get_node_and_lookup_items` failed. File '{filename}' does not exist.");
+        eprintln!("`get_node` failed. File '{filename}' does not exist.");
     })?;
 
     // Find offset for position.
     let offset = position_to_offset(file_summary, position, &content)?;
-    let node = syntax.lookup_offset(syntax_db, offset);
+    Some(syntax.lookup_offset(syntax_db, offset))
+}
+
+/// Returns all the lookup items above a node in a file.
+fn get_lookup_items(
+    db: &dyn SemanticGroup,
+    file: FileId,
+    node: SyntaxNode,
+) -> Option<Vec<LookupItemId>> {
+    let mut res = Vec::new();
 
     // Find module.
     let module_id = find_node_module(db, file, node.clone()).on_none(|| {
-        eprintln!("`get_node_and_lookup_items` failed. Failed to find module.");
+        eprintln!("`get_lookup_items` failed. Failed to find module.");
     })?;
-    let file_index = FileIndex(0);
-    let module_file_id = ModuleFileId(module_id, file_index);
+    // Plugin generated files come after the main file of the module.
+    let file_index = db
+        .module_files(module_id)
+        .ok()
+        .and_then(|files| files.iter().position(|module_file| *module_file == file))
+        .unwrap_or(0);
+    let module_file_id = ModuleFileId(module_id, FileIndex(file_index));
 
     // Find containing function.
-    let mut item_node = node.clone();
+    let mut item_node = node;
     loop {
         for item in lookup_item_from_ast(db, module_file_id, item_node.clone()) {
             res.push(item);
@@ -1214,7 +1732,7 @@ fn get_node_and_lookup_items(
             Some(next_node) => {
                 item_node = next_node;
             }
-            None => return Some((node, res)),
+            None => return Some(res),
         }
     }
 }
@@ -1444,6 +1962,50 @@ fn get_range(db: &dyn FilesGroup, location: &DiagnosticLocation) -> Range {
     Range { start, end }
 }
 
+/// Returns the paths under which files may be edited: the workspace folders and the open files.
+fn editable_roots(state: &State) -> Vec<PathBuf> {
+    state
+        .workspace_roots
+        .iter()
+        .cloned()
+        .chain(state.open_files.iter().filter_map(|uri| uri.to_file_path().ok()))
+        .collect()
+}
+
+/// Computes the diagnostics of a file. Returns them, along with their LSP format, if they changed
+/// since they were last published.
+fn updated_file_diagnostics(
+    db: &RootDatabase,
+    uri: &Url,
+    old_file_diagnostics: Option<&FileDiagnostics>,
+    level: DiagnosticsLevel,
+) -> Option<(FileDiagnostics, Vec<Diagnostic>)> {
+    let file_id = file(db, uri.clone());
+    let mut new_file_diagnostics =
+        FileDiagnostics { parser: db.file_syntax_diagnostics(file_id), ..Default::default() };
+    if level >= DiagnosticsLevel::Semantic {
+        new_file_diagnostics.semantic = db.file_semantic_diagnostics(file_id).unwrap_or_default();
+    }
+    if level >= DiagnosticsLevel::Lowering {
+        new_file_diagnostics.lowering = db.file_lowering_diagnostics(file_id).unwrap_or_default();
+    }
+    // Since we are using Arcs, this comparison should be efficient.
+    if old_file_diagnostics == Some(&new_file_diagnostics) {
+        return None;
+    }
+    let diags = lsp_diagnostics(db, &new_file_diagnostics);
+    Some((new_file_diagnostics, diags))
+}
+
+/// Converts the diagnostics of a file to LSP format.
+fn lsp_diagnostics(db: &RootDatabase, file_diagnostics: &FileDiagnostics) -> Vec<Diagnostic> {
+    let mut diags = Vec::new();
+    get_diagnostics(db.upcast(), &mut diags, &file_diagnostics.parser);
+    get_diagnostics(db.upcast(), &mut diags, &file_diagnostics.semantic);
+    get_diagnostics(db.upcast(), &mut diags, &file_diagnostics.lowering);
+    diags
+}
+
 /// Converts internal diagnostics to LSP format.
 fn get_diagnostics<T: DiagnosticEntry>(
     db: &T::DbType,
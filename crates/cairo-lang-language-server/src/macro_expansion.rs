@@ -0,0 +1,172 @@
+//! Expansion of the code generated by macro plugins and inline macros.
+//!
+//! The plugins are rerun on the item or inline macro under the cursor. Generating the same code as
+//! during the analysis yields the same virtual files, so the expansions can be opened, and
+//! navigated from, like any other file.
+
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::plugin::MacroPluginMetadata;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::{FileId, FileKind, FileLongId, VirtualFile};
+use cairo_lang_formatter::{get_formatted_file, FormatterConfig};
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+use tower_lsp::lsp_types::Position;
+
+use crate::{find_node_module, get_node};
+
+#[cfg(test)]
+#[path = "macro_expansion_test.rs"]
+mod test;
+
+/// Returns the code generated for the innermost inline macro or item containing the position that
+/// plugins act on, along with the code generated from it in turn.
+pub fn expand_macro(db: &RootDatabase, file: FileId, position: Position) -> Option<String> {
+    let files = expansion_files(db, file, position)?;
+    Some(files.into_iter().map(|file| file_expansion(db, file)).collect::<Vec<_>>().join("\n"))
+}
+
+/// Returns the virtual files generated for the innermost inline macro or item containing the
+/// position that plugins act on, along with the files generated from them in turn.
+pub fn expansion_files(db: &RootDatabase, file: FileId, position: Position) -> Option<Vec<FileId>> {
+    let syntax_db = db.upcast();
+    let node = get_node(db, file, position)?;
+    let module_id = find_node_module(db, file, node.clone())?;
+    let cfg_set = db
+        .crate_config(module_id.owning_crate(db))
+        .and_then(|config| config.settings.cfg_set)
+        .unwrap_or_else(|| (*db.cfg_set()).clone());
+    let metadata = MacroPluginMetadata { cfg_set: &cfg_set };
+
+    let mut node = Some(node);
+    while let Some(current) = node {
+        let mut files = vec![];
+        if current.kind(syntax_db) == SyntaxKind::ExprInlineMacro {
+            let inline_macro = ast::ExprInlineMacro::from_syntax_node(syntax_db, current.clone());
+            inline_macro_files(db, &inline_macro, &mut files);
+        } else if current.parent().map(|parent| parent.kind(syntax_db))
+            == Some(SyntaxKind::ModuleItemList)
+        {
+            let item = ast::ModuleItem::from_syntax_node(syntax_db, current.clone());
+            item_files(db, &metadata, file, item.clone(), &mut files);
+            // Plugins such as the contract plugin act on the items of a module rather than on the
+            // module itself.
+            if let ast::ModuleItem::Module(module) = item {
+                if let ast::MaybeModuleBody::Some(body) = module.body(syntax_db) {
+                    for item in body.items(syntax_db).elements(syntax_db) {
+                        item_files(db, &metadata, file, item, &mut files);
+                    }
+                }
+            }
+        }
+        if !files.is_empty() {
+            return Some(files);
+        }
+        node = current.parent();
+    }
+    None
+}
+
+/// Returns the first file generated for the macro named at the position, either by an attribute or
+/// as an inline macro.
+pub fn macro_name_expansion(db: &RootDatabase, file: FileId, position: Position) -> Option<FileId> {
+    let syntax_db = db.upcast();
+    let mut node = get_node(db, file, position)?;
+    loop {
+        let parent = node.parent()?;
+        match parent.kind(syntax_db) {
+            SyntaxKind::Attribute => break,
+            SyntaxKind::ExprInlineMacro if node.kind(syntax_db) == SyntaxKind::ExprPath => {
+                break;
+            }
+            kind if ast::ModuleItem::is_variant(kind) || ast::Statement::is_variant(kind) => {
+                return None;
+            }
+            _ => node = parent,
+        }
+    }
+    expansion_files(db, file, position)?.into_iter().next()
+}
+
+/// Collects the files generated by the macro plugins for a module item in `module_file`, and
+/// recursively for the items of these files, the same way the module data is computed.
+fn item_files(
+    db: &RootDatabase,
+    metadata: &MacroPluginMetadata<'_>,
+    module_file: FileId,
+    item: ast::ModuleItem,
+    files: &mut Vec<FileId>,
+) {
+    let syntax_db = db.upcast();
+    for plugin in db.macro_plugins() {
+        let result = plugin.generate_code(syntax_db, item.clone(), metadata);
+        if let Some(generated) = result.code {
+            let new_file = db.intern_file(FileLongId::Virtual(VirtualFile {
+                parent: Some(module_file),
+                name: generated.name,
+                content: Arc::new(generated.content),
+                code_mappings: Arc::new(generated.code_mappings),
+                kind: FileKind::Module,
+            }));
+            files.push(new_file);
+            if let Ok(file_syntax) = db.file_module_syntax(new_file) {
+                for item in file_syntax.items(syntax_db).elements(syntax_db) {
+                    item_files(db, metadata, new_file, item, files);
+                }
+            }
+        }
+        if result.remove_original_item {
+            break;
+        }
+    }
+}
+
+/// Collects the file generated for an inline macro, and recursively for the inline macros in it,
+/// the same way the expression semantics are computed.
+fn inline_macro_files(
+    db: &RootDatabase,
+    inline_macro: &ast::ExprInlineMacro,
+    files: &mut Vec<FileId>,
+) {
+    let syntax_db = db.upcast();
+    let name = inline_macro.path(syntax_db).as_syntax_node().get_text_without_trivia(syntax_db);
+    let Some(plugin) = db.inline_macro_plugins().get(&name).cloned() else {
+        return;
+    };
+    let Some(generated) = plugin.generate_code(syntax_db, inline_macro).code else {
+        return;
+    };
+    let new_file = db.intern_file(FileLongId::Virtual(VirtualFile {
+        parent: Some(inline_macro.stable_ptr().untyped().file_id(syntax_db)),
+        name: generated.name,
+        content: Arc::new(generated.content),
+        code_mappings: Arc::new(generated.code_mappings),
+        kind: FileKind::Expr,
+    }));
+    files.push(new_file);
+    let Ok(expr) = db.file_expr_syntax(new_file) else {
+        return;
+    };
+    for node in expr.as_syntax_node().descendants(syntax_db) {
+        if node.kind(syntax_db) == SyntaxKind::ExprInlineMacro {
+            inline_macro_files(db, &ast::ExprInlineMacro::from_syntax_node(syntax_db, node), files);
+        }
+    }
+}
+
+/// Returns the content of a generated file, formatted when it parses, under a header naming it.
+fn file_expansion(db: &RootDatabase, file: FileId) -> String {
+    let name = file.file_name(db);
+    let content = match db.file_syntax(file) {
+        Ok(node) if db.file_syntax_diagnostics(file).check_error_free().is_ok() => {
+            get_formatted_file(db.upcast(), &node, FormatterConfig::default())
+        }
+        _ => db.file_content(file).map(|content| (*content).clone()).unwrap_or_default(),
+    };
+    format!("// {name}\n{}\n", content.trim_end())
+}
@@ -0,0 +1,128 @@
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextSpan;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use tower_lsp::lsp_types::{Location, Position};
+
+use super::{expand_macro, expansion_files};
+use crate::test_utils::{extract_cursor, mark_ranges, setup_test_file};
+use crate::{find_definition_location, get_uri, position_to_offset};
+
+const CONTRACT_CODE: &str = indoc! {"
+    #[starknet::contract]
+    mod counter {
+        #[storage]
+        struct Storage {
+            value: u32,
+        }
+        fn doubled(value: u32) -> u32 {
+            value * 2
+        }
+        #[external(v0)]
+        fn get(self: @ContractState) -> u32 {
+            doubled(self.value.read())
+        }
+    }
+"};
+
+/// Returns the text at a location, in the file of the given ones it lies in.
+fn location_text(db: &dyn FilesGroup, files: &[FileId], location: &Location) -> String {
+    let file = *files.iter().find(|file| get_uri(db, **file) == location.uri).unwrap();
+    let summary = db.file_summary(file).unwrap();
+    let content = db.file_content(file).unwrap();
+    let start = position_to_offset(summary.clone(), location.range.start, &content).unwrap();
+    let end = position_to_offset(summary, location.range.end, &content).unwrap();
+    TextSpan { start, end }.take(&content).to_string()
+}
+
+#[test]
+fn test_expand_derive() {
+    let (code, position) = extract_cursor(indoc! {"
+        #[derive(<caret>Drop, PartialEq)]
+        struct Point {
+            x: felt252,
+        }
+    "});
+    let (db, file) = setup_test_file(&code);
+    assert_eq!(
+        expand_macro(&db, file, position).unwrap(),
+        indoc! {"
+            // impls
+            impl PointDrop of core::traits::Drop<Point>;
+            impl PointPartialEq of core::traits::PartialEq<Point> {
+                fn eq(lhs: @Point, rhs: @Point) -> bool {
+                    lhs.x == rhs.x
+                }
+                #[inline(always)]
+                fn ne(lhs: @Point, rhs: @Point) -> bool {
+                    !(lhs == rhs)
+                }
+            }
+        "}
+    );
+}
+
+#[test]
+fn test_expand_contract() {
+    let (db, file) = setup_test_file(CONTRACT_CODE);
+    // The name of the contract module.
+    let position = Position { line: 1, character: 4 };
+    let expansion = expand_macro(&db, file, position).unwrap();
+    let headers: Vec<_> = expansion.lines().filter(|line| line.starts_with("// ")).collect();
+    assert_eq!(headers, ["// contract", "// impls", "// starknet_derive"]);
+}
+
+#[test]
+fn test_definition_in_generated_code() {
+    let (code, position) =
+        extract_cursor(&CONTRACT_CODE.replace("@ContractState", "@<caret>ContractState"));
+    let (db, file) = setup_test_file(&code);
+    let location = find_definition_location(&db, file, position).unwrap();
+    assert_ne!(location.uri, get_uri(&db, file));
+    let generated_files = expansion_files(&db, file, position).unwrap();
+    let text = location_text(&db, &generated_files, &location);
+    assert_eq!(text.lines().next().unwrap(), "pub struct ContractState {");
+}
+
+#[test]
+fn test_definition_mapped_to_user_code() {
+    let (code, position) =
+        extract_cursor(&CONTRACT_CODE.replace("doubled(self", "<caret>doubled(self"));
+    let (db, file) = setup_test_file(&code);
+    let location = find_definition_location(&db, file, position).unwrap();
+    assert_eq!(location.uri, get_uri(&db, file));
+    assert_eq!(
+        mark_ranges(&code, [location.range]),
+        indoc! {"
+            #[starknet::contract]
+            mod counter {
+                #[storage]
+                struct Storage {
+                    value: u32,
+                }
+                <sel>fn doubled(value: u32) -> u32 {
+                    value * 2
+                }</sel>
+                #[external(v0)]
+                fn get(self: @ContractState) -> u32 {
+                    doubled(self.value.read())
+                }
+            }
+        "}
+    );
+}
+
+#[test]
+fn test_definition_of_macro_name() {
+    let (code, position) = extract_cursor(indoc! {"
+        #[derive(<caret>Drop)]
+        struct Point {
+            x: felt252,
+        }
+    "});
+    let (db, file) = setup_test_file(&code);
+    let location = find_definition_location(&db, file, position).unwrap();
+    let generated_files = expansion_files(&db, file, position).unwrap();
+    assert_eq!(location.uri, get_uri(&db, generated_files[0]));
+}
@@ -0,0 +1,552 @@
+//! Go-to-implementation, go-to-type-definition and call hierarchy support.
+
+use std::collections::HashSet;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{
+    FunctionTitleId, FunctionWithBodyId, ImplItemId, LanguageElementId, LookupItemId, ModuleItemId,
+    TopLevelLanguageElementId, TraitFunctionId, TraitId, TraitItemId,
+};
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::db::{get_originating_location, FilesGroup};
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_lowering::db::LoweringGroup;
+use cairo_lang_lowering::ids::{FunctionLongId, FunctionWithBodyLongId};
+use cairo_lang_semantic::corelib::core_crate;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::items::functions::{GenericFunctionId, ImplGenericFunctionId};
+use cairo_lang_semantic::items::imp::ImplId;
+use cairo_lang_semantic::lookup_item::LookupItemEx;
+use cairo_lang_semantic::resolve::{ResolvedConcreteItem, ResolvedGenericItem};
+use cairo_lang_semantic::{Expr, ExprFunctionCall, FunctionId, TypeId, TypeLongId};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use cairo_lang_utils::Upcast;
+use tower_lsp::lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, Location, Position,
+    SymbolKind,
+};
+
+use crate::references::{
+    definition_location, is_generated_file, lsp_location, lsp_range, SymbolLocation,
+};
+use crate::{
+    file, get_node_and_lookup_items, get_uri, nearest_semantic_expr, nearest_semantic_pat,
+};
+
+#[cfg(test)]
+#[path = "navigation_test.rs"]
+mod test;
+
+/// An item that is provided by impls.
+enum ImplementedItem {
+    Trait(TraitId),
+    TraitFunction(TraitFunctionId),
+}
+
+/// Returns the locations of the impls of the trait, or of the impl functions of the trait
+/// function, named by the identifier at the given position.
+pub fn implementations(
+    db: &RootDatabase,
+    file: FileId,
+    position: Position,
+) -> Option<Vec<Location>> {
+    let (identifier, lookup_items) = identifier_at(db, file, position)?;
+    let (trait_id, trait_function_id) = match implemented_item(db, &identifier, &lookup_items)? {
+        ImplementedItem::Trait(trait_id) => (trait_id, None),
+        ImplementedItem::TraitFunction(trait_function_id) => {
+            (trait_function_id.trait_id(db), Some(trait_function_id))
+        }
+    };
+
+    let mut locations = OrderedHashSet::<SymbolLocation>::default();
+    for crate_id in db.crates() {
+        for module_id in db.crate_modules(crate_id).iter() {
+            for impl_def_id in db.module_impls_ids(*module_id).unwrap_or_default().iter().copied() {
+                if db.impl_def_trait(impl_def_id).ok() != Some(trait_id) {
+                    continue;
+                }
+                let ptr = match trait_function_id {
+                    Some(trait_function_id) => {
+                        let Ok(Some(impl_function_id)) =
+                            db.impl_function_by_trait_function(impl_def_id, trait_function_id)
+                        else {
+                            continue;
+                        };
+                        impl_function_id.untyped_stable_ptr(db)
+                    }
+                    None => impl_def_id.untyped_stable_ptr(db),
+                };
+                if let Some(location) = definition_location(db, ptr) {
+                    locations.insert(location);
+                }
+            }
+        }
+    }
+    Some(locations.into_iter().filter_map(|location| lsp_location(db, location)).collect())
+}
+
+/// Returns the location of the declaration of the type of the variable or expression at the given
+/// position, or of the type named there.
+pub fn type_definition(db: &RootDatabase, file: FileId, position: Position) -> Option<Location> {
+    let (node, lookup_items) = get_node_and_lookup_items(db, file, position)?;
+    let ty = lookup_items
+        .first()
+        .and_then(|lookup_item_id| lookup_item_id.function_with_body())
+        .and_then(|function_id| match nearest_semantic_pat(db, node.clone(), function_id) {
+            Some(pattern) => Some(pattern.ty()),
+            None => Some(nearest_semantic_expr(db, node.clone(), function_id)?.ty()),
+        });
+    let ptr = match ty {
+        Some(ty) => type_declaration(db, ty)?,
+        None => {
+            // Outside of function bodies, the identifier may name a type, e.g. in a signature.
+            let (identifier, lookup_items) = identifier_at(db, file, position)?;
+            lookup_items.iter().find_map(|lookup_item_id| {
+                match db.lookup_resolved_concrete_item_by_ptr(
+                    *lookup_item_id,
+                    identifier.stable_ptr(),
+                )? {
+                    ResolvedConcreteItem::Type(ty) => type_declaration(db, ty),
+                    _ => None,
+                }
+            })?
+        }
+    };
+    lsp_location(db, definition_location(db, ptr)?)
+}
+
+/// Returns the function named by the identifier at the given position, as a call hierarchy item.
+pub fn prepare_call_hierarchy(
+    db: &RootDatabase,
+    file: FileId,
+    position: Position,
+) -> Option<Vec<CallHierarchyItem>> {
+    let (identifier, lookup_items) = identifier_at(db, file, position)?;
+    let function = function_at(db, &identifier, &lookup_items)?;
+    Some(vec![call_hierarchy_item(db, function)?])
+}
+
+/// Returns the functions calling the function of a call hierarchy item, along with the locations of
+/// the calls.
+pub fn incoming_calls(
+    db: &RootDatabase,
+    item: &CallHierarchyItem,
+) -> Option<Vec<CallHierarchyIncomingCall>> {
+    let callee = item_function(db, item)?;
+    let callee_file = callee.untyped_stable_ptr(db).file_id(db.upcast());
+    // Core is only searched for callers of its own functions.
+    let include_core = db.file_modules(callee_file).map_or(false, |modules| {
+        modules.iter().any(|module_id| module_id.owning_crate(db) == core_crate(db))
+    });
+
+    let mut incoming = vec![];
+    for function_id in workspace_functions(db, include_core) {
+        if !lowered_callees(db, function_id).contains(&callee) {
+            continue;
+        }
+        let caller = match function_id {
+            FunctionWithBodyId::Free(free_function_id) => FunctionTitleId::Free(free_function_id),
+            FunctionWithBodyId::Impl(impl_function_id) => FunctionTitleId::Impl(impl_function_id),
+        };
+        let Some(from) = call_hierarchy_item(db, caller) else {
+            continue;
+        };
+        let from_ranges = call_sites(db, function_id)
+            .get(&callee)
+            .into_iter()
+            .flatten()
+            .filter(|location| get_uri(db, location.file) == from.uri)
+            .filter_map(|location| lsp_range(db, *location))
+            .collect();
+        incoming.push(CallHierarchyIncomingCall { from, from_ranges });
+    }
+    Some(incoming)
+}
+
+/// Returns the functions called by the function of a call hierarchy item, along with the locations
+/// of the calls.
+pub fn outgoing_calls(
+    db: &RootDatabase,
+    item: &CallHierarchyItem,
+) -> Option<Vec<CallHierarchyOutgoingCall>> {
+    let function_id = match item_function(db, item)? {
+        FunctionTitleId::Free(free_function_id) => FunctionWithBodyId::Free(free_function_id),
+        FunctionTitleId::Impl(impl_function_id) => FunctionWithBodyId::Impl(impl_function_id),
+        // Functions without a body call nothing.
+        FunctionTitleId::Extern(_) | FunctionTitleId::Trait(_) => return Some(vec![]),
+    };
+    let call_sites = call_sites(db, function_id);
+    Some(
+        lowered_callees(db, function_id)
+            .into_iter()
+            .filter_map(|callee| {
+                let from_ranges = call_sites
+                    .get(&callee)
+                    .into_iter()
+                    .flatten()
+                    .filter(|location| get_uri(db, location.file) == item.uri)
+                    .filter_map(|location| lsp_range(db, *location))
+                    .collect();
+                Some(CallHierarchyOutgoingCall {
+                    to: call_hierarchy_item(db, callee)?,
+                    from_ranges,
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Returns the identifier at the given position, along with the lookup items containing it.
+fn identifier_at(
+    db: &RootDatabase,
+    file: FileId,
+    position: Position,
+) -> Option<(ast::TerminalIdentifier, Vec<LookupItemId>)> {
+    let syntax_db = db.upcast();
+    let (node, lookup_items) = get_node_and_lookup_items(db, file, position)?;
+    if node.kind(syntax_db) != SyntaxKind::TokenIdentifier {
+        return None;
+    }
+    let identifier = ast::TerminalIdentifier::from_syntax_node(syntax_db, node.parent()?);
+    Some((identifier, lookup_items))
+}
+
+/// Returns whether the identifier is the name of the innermost lookup item containing it.
+fn is_declaration_name(db: &RootDatabase, identifier: &ast::TerminalIdentifier) -> bool {
+    identifier.as_syntax_node().parent().map_or(false, |parent| {
+        matches!(
+            parent.kind(db.upcast()),
+            SyntaxKind::ItemTrait | SyntaxKind::ItemImpl | SyntaxKind::FunctionDeclaration
+        )
+    })
+}
+
+/// Returns the trait or trait function named by an identifier, either at its declaration, at the
+/// declaration of an impl (function) providing it, or where it is used.
+fn implemented_item(
+    db: &RootDatabase,
+    identifier: &ast::TerminalIdentifier,
+    lookup_items: &[LookupItemId],
+) -> Option<ImplementedItem> {
+    if is_declaration_name(db, identifier) {
+        return match *lookup_items.first()? {
+            LookupItemId::ModuleItem(ModuleItemId::Trait(trait_id)) => {
+                Some(ImplementedItem::Trait(trait_id))
+            }
+            LookupItemId::ModuleItem(ModuleItemId::Impl(impl_def_id)) => {
+                Some(ImplementedItem::Trait(db.impl_def_trait(impl_def_id).ok()?))
+            }
+            LookupItemId::TraitItem(TraitItemId::Function(trait_function_id)) => {
+                Some(ImplementedItem::TraitFunction(trait_function_id))
+            }
+            LookupItemId::ImplItem(ImplItemId::Function(impl_function_id)) => {
+                Some(ImplementedItem::TraitFunction(
+                    db.impl_function_trait_function(impl_function_id).ok()?,
+                ))
+            }
+            _ => None,
+        };
+    }
+    if let Some(function) = called_function(db, identifier, lookup_items) {
+        return match function.get_concrete(db).generic_function {
+            GenericFunctionId::Impl(ImplGenericFunctionId { function, .. }) => {
+                Some(ImplementedItem::TraitFunction(function))
+            }
+            _ => None,
+        };
+    }
+    lookup_items.iter().find_map(|lookup_item_id| {
+        match db.lookup_resolved_generic_item_by_ptr(*lookup_item_id, identifier.stable_ptr())? {
+            ResolvedGenericItem::Trait(trait_id) => Some(ImplementedItem::Trait(trait_id)),
+            ResolvedGenericItem::TraitFunction(trait_function_id)
+            | ResolvedGenericItem::GenericFunction(GenericFunctionId::Impl(
+                ImplGenericFunctionId { function: trait_function_id, .. },
+            )) => Some(ImplementedItem::TraitFunction(trait_function_id)),
+            _ => None,
+        }
+    })
+}
+
+/// Returns the function named by an identifier, either at its declaration or where it is called.
+fn function_at(
+    db: &RootDatabase,
+    identifier: &ast::TerminalIdentifier,
+    lookup_items: &[LookupItemId],
+) -> Option<FunctionTitleId> {
+    if is_declaration_name(db, identifier) {
+        return match *lookup_items.first()? {
+            LookupItemId::ModuleItem(ModuleItemId::FreeFunction(free_function_id)) => {
+                Some(FunctionTitleId::Free(free_function_id))
+            }
+            LookupItemId::ModuleItem(ModuleItemId::ExternFunction(extern_function_id)) => {
+                Some(FunctionTitleId::Extern(extern_function_id))
+            }
+            LookupItemId::TraitItem(TraitItemId::Function(trait_function_id)) => {
+                Some(FunctionTitleId::Trait(trait_function_id))
+            }
+            LookupItemId::ImplItem(ImplItemId::Function(impl_function_id)) => {
+                Some(FunctionTitleId::Impl(impl_function_id))
+            }
+            _ => None,
+        };
+    }
+    if let Some(function) = called_function(db, identifier, lookup_items) {
+        return Some(function_title(db, function));
+    }
+    lookup_items.iter().find_map(|lookup_item_id| {
+        match db.lookup_resolved_generic_item_by_ptr(*lookup_item_id, identifier.stable_ptr())? {
+            ResolvedGenericItem::GenericFunction(generic_function) => {
+                Some(generic_function_title(db, generic_function))
+            }
+            ResolvedGenericItem::TraitFunction(trait_function_id) => {
+                Some(FunctionTitleId::Trait(trait_function_id))
+            }
+            _ => None,
+        }
+    })
+}
+
+/// Returns the function of a call hierarchy item, found at the name of its declaration.
+fn item_function(db: &RootDatabase, item: &CallHierarchyItem) -> Option<FunctionTitleId> {
+    let file = file(db, item.uri.clone());
+    let (identifier, lookup_items) = identifier_at(db, file, item.selection_range.start)?;
+    function_at(db, &identifier, &lookup_items)
+}
+
+/// Returns the function called by the call whose function name is the identifier, as resolved by
+/// the semantic model.
+fn called_function(
+    db: &RootDatabase,
+    identifier: &ast::TerminalIdentifier,
+    lookup_items: &[LookupItemId],
+) -> Option<FunctionId> {
+    let syntax_db = db.upcast();
+    // The name is the last segment of the path of the call.
+    let path = identifier.as_syntax_node().parent()?.parent()?;
+    let call_node = path.parent()?;
+    if path.kind(syntax_db) != SyntaxKind::ExprPath
+        || call_node.kind(syntax_db) != SyntaxKind::ExprFunctionCall
+    {
+        return None;
+    }
+    let call = ast::ExprFunctionCall::from_syntax_node(syntax_db, call_node.clone());
+    let segments = call.path(syntax_db).elements(syntax_db);
+    if segments.last()?.as_syntax_node() != identifier.as_syntax_node().parent()? {
+        return None;
+    }
+    // Method calls (`a.f(x)`) are resolved as the whole binary expression.
+    let expr_ptr = match call_node.parent() {
+        Some(parent) if parent.kind(syntax_db) == SyntaxKind::ExprBinary => {
+            let binary = ast::ExprBinary::from_syntax_node(syntax_db, parent);
+            match binary.op(syntax_db) {
+                ast::BinaryOperator::Dot(_) => ast::Expr::Binary(binary).stable_ptr(),
+                _ => ast::Expr::FunctionCall(call).stable_ptr(),
+            }
+        }
+        _ => ast::Expr::FunctionCall(call).stable_ptr(),
+    };
+    let function_id = lookup_items.first()?.function_with_body()?;
+    let expr_id = db.lookup_expr_by_ptr(function_id, expr_ptr).to_option()?;
+    match db.expr_semantic(function_id, expr_id) {
+        Expr::FunctionCall(ExprFunctionCall { function, .. }) => Some(function),
+        _ => None,
+    }
+}
+
+/// Returns the declaration of the function a semantic function refers to.
+fn function_title(db: &RootDatabase, function: FunctionId) -> FunctionTitleId {
+    generic_function_title(db, function.get_concrete(db).generic_function)
+}
+
+/// Returns the declaration of a generic function. Functions of concrete impls are declared in the
+/// impl, and others in their trait.
+fn generic_function_title(
+    db: &RootDatabase,
+    generic_function: GenericFunctionId,
+) -> FunctionTitleId {
+    match generic_function {
+        GenericFunctionId::Free(free_function_id) => FunctionTitleId::Free(free_function_id),
+        GenericFunctionId::Extern(extern_function_id) => {
+            FunctionTitleId::Extern(extern_function_id)
+        }
+        GenericFunctionId::Impl(ImplGenericFunctionId { impl_id, function }) => {
+            let impl_function_id = match impl_id {
+                ImplId::Concrete(concrete_impl_id) => {
+                    concrete_impl_id.get_impl_function(db, function).ok().flatten()
+                }
+                _ => None,
+            };
+            impl_function_id.map_or(FunctionTitleId::Trait(function), FunctionTitleId::Impl)
+        }
+    }
+}
+
+/// Returns the call hierarchy item of a function, if it is declared in user code.
+fn call_hierarchy_item(db: &RootDatabase, function: FunctionTitleId) -> Option<CallHierarchyItem> {
+    let syntax_db = db.upcast();
+    let ptr = function.untyped_stable_ptr(db);
+    let name_location = definition_location(db, ptr)?;
+    if is_generated_file(db, name_location.file) {
+        return None;
+    }
+    let selection_range = lsp_range(db, name_location)?;
+    let (file, span) = get_originating_location(
+        db.upcast(),
+        ptr.file_id(syntax_db),
+        ptr.lookup(syntax_db).span_without_trivia(syntax_db),
+    );
+    // The whole declaration may not map to user code when only parts of it were copied.
+    let range = if file == name_location.file {
+        lsp_range(db, SymbolLocation { file, span })?
+    } else {
+        selection_range
+    };
+    Some(CallHierarchyItem {
+        name: function.name(db).into(),
+        kind: match function {
+            FunctionTitleId::Free(_) | FunctionTitleId::Extern(_) => SymbolKind::FUNCTION,
+            FunctionTitleId::Trait(_) | FunctionTitleId::Impl(_) => SymbolKind::METHOD,
+        },
+        tags: None,
+        detail: Some(function.format(db)),
+        uri: get_uri(db, name_location.file),
+        range,
+        selection_range,
+        data: None,
+    })
+}
+
+/// Returns the functions a function calls, according to its lowering. Calls made by the functions
+/// generated for its loops are included.
+fn lowered_callees(
+    db: &RootDatabase,
+    function_id: FunctionWithBodyId,
+) -> OrderedHashSet<FunctionTitleId> {
+    let mut callees = OrderedHashSet::default();
+    let mut visited = HashSet::new();
+    let mut pending =
+        vec![db.intern_lowering_function_with_body(FunctionWithBodyLongId::Semantic(function_id))];
+    while let Some(lowered_function_id) = pending.pop() {
+        if !visited.insert(lowered_function_id) {
+            continue;
+        }
+        let Ok(direct_callees) = db.function_with_body_direct_callees(lowered_function_id) else {
+            continue;
+        };
+        for callee in direct_callees.iter() {
+            match db.lookup_intern_lowering_function(*callee) {
+                FunctionLongId::Semantic(function) => {
+                    callees.insert(function_title(db, function));
+                }
+                FunctionLongId::Generated(_) => {
+                    if let Ok(Some(body)) = callee.body(db) {
+                        pending.push(body.function_with_body_id(db));
+                    }
+                }
+            }
+        }
+    }
+    callees
+}
+
+/// Returns the locations of the names of the functions called in the body of a function, mapped
+/// back to user code.
+fn call_sites(
+    db: &RootDatabase,
+    function_id: FunctionWithBodyId,
+) -> OrderedHashMap<FunctionTitleId, Vec<SymbolLocation>> {
+    let syntax_db = db.upcast();
+    let mut call_sites = OrderedHashMap::<FunctionTitleId, Vec<SymbolLocation>>::default();
+    let Ok(body) = db.function_body(function_id) else {
+        return call_sites;
+    };
+    for (_, expr) in body.exprs.iter() {
+        let Expr::FunctionCall(call) = expr else {
+            continue;
+        };
+        let node = call_name_node(db, call.stable_ptr.lookup(syntax_db).as_syntax_node());
+        let (file, span) = get_originating_location(
+            db.upcast(),
+            call.stable_ptr.untyped().file_id(syntax_db),
+            node.span_without_trivia(syntax_db),
+        );
+        call_sites
+            .entry(function_title(db, call.function))
+            .or_default()
+            .push(SymbolLocation { file, span });
+    }
+    call_sites
+}
+
+/// Returns the node naming the called function in a call expression: the last segment of its path,
+/// or its operator for calls made by operators.
+fn call_name_node(db: &RootDatabase, node: SyntaxNode) -> SyntaxNode {
+    let syntax_db = db.upcast();
+    let call = match node.kind(syntax_db) {
+        SyntaxKind::ExprFunctionCall => {
+            ast::ExprFunctionCall::from_syntax_node(syntax_db, node.clone())
+        }
+        SyntaxKind::ExprBinary => {
+            let binary = ast::ExprBinary::from_syntax_node(syntax_db, node.clone());
+            match (binary.op(syntax_db), binary.rhs(syntax_db)) {
+                (ast::BinaryOperator::Dot(_), ast::Expr::FunctionCall(call)) => call,
+                (op, _) => return op.as_syntax_node(),
+            }
+        }
+        SyntaxKind::ExprUnary => {
+            return ast::ExprUnary::from_syntax_node(syntax_db, node)
+                .op(syntax_db)
+                .as_syntax_node();
+        }
+        _ => return node,
+    };
+    match call.path(syntax_db).elements(syntax_db).last() {
+        Some(segment) => segment.as_syntax_node(),
+        None => node,
+    }
+}
+
+/// Returns the functions with a body in all the crates, optionally excluding core.
+fn workspace_functions(db: &RootDatabase, include_core: bool) -> Vec<FunctionWithBodyId> {
+    let core = core_crate(db);
+    let mut functions = vec![];
+    for crate_id in db.crates() {
+        if crate_id == core && !include_core {
+            continue;
+        }
+        for module_id in db.crate_modules(crate_id).iter() {
+            for free_function_id in
+                db.module_free_functions_ids(*module_id).unwrap_or_default().iter()
+            {
+                functions.push(FunctionWithBodyId::Free(*free_function_id));
+            }
+            for impl_def_id in db.module_impls_ids(*module_id).unwrap_or_default().iter() {
+                for impl_function_id in db.impl_functions(*impl_def_id).unwrap_or_default().values()
+                {
+                    functions.push(FunctionWithBodyId::Impl(*impl_function_id));
+                }
+            }
+        }
+    }
+    functions
+}
+
+/// Returns the declaration of a type, looking through snapshots.
+fn type_declaration(db: &RootDatabase, ty: TypeId) -> Option<SyntaxStablePtrId> {
+    match db.lookup_intern_type(ty) {
+        TypeLongId::Concrete(concrete_type_id) => {
+            Some(concrete_type_id.generic_type(db).untyped_stable_ptr(db))
+        }
+        TypeLongId::Snapshot(inner_ty) => type_declaration(db, inner_ty),
+        TypeLongId::GenericParameter(generic_param_id) => {
+            Some(generic_param_id.untyped_stable_ptr(db))
+        }
+        _ => None,
+    }
+}
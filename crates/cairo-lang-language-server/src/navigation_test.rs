@@ -0,0 +1,238 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+use tower_lsp::lsp_types::{CallHierarchyItem, Position, Range};
+
+use super::{
+    implementations, incoming_calls, outgoing_calls, prepare_call_hierarchy, type_definition,
+};
+use crate::test_utils::{extract_cursor, mark_ranges, setup_test_file};
+
+const CALLS_CODE: &str = indoc! {"
+    trait Area<T> {
+        fn area(self: @T) -> u32;
+    }
+    #[derive(Drop)]
+    struct Square {
+        side: u32,
+    }
+    impl SquareArea of Area<Square> {
+        fn area(self: @Square) -> u32 {
+            square(*self.side)
+        }
+    }
+    fn square(x: u32) -> u32 {
+        x * x
+    }
+    fn total(a: @Square, b: @Square) -> u32 {
+        a.area() + b.area() + square(2)
+    }
+"};
+
+/// Returns the code with the implementations of the item at its cursor marked.
+fn code_with_implementations(code: &str) -> String {
+    let (code, position) = extract_cursor(code);
+    let (db, file) = setup_test_file(&code);
+    let locations = implementations(&db, file, position).expect("No trait at the cursor.");
+    mark_ranges(&code, locations.into_iter().map(|location| location.range))
+}
+
+/// Returns the code with the type definition of the expression at its cursor marked.
+fn code_with_type_definition(code: &str) -> String {
+    let (code, position) = extract_cursor(code);
+    let (db, file) = setup_test_file(&code);
+    let location = type_definition(&db, file, position).expect("No type at the cursor.");
+    mark_ranges(&code, [location.range])
+}
+
+/// Returns a description of a call hierarchy item and the call sites of a call, as `name` and
+/// `line:character` pairs.
+fn describe_call(item: &CallHierarchyItem, ranges: &[Range]) -> String {
+    let sites = ranges
+        .iter()
+        .map(|range| format!("{}:{}", range.start.line, range.start.character))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{} at {}", item.name, sites)
+}
+
+#[test_case(
+    indoc! {"
+        trait <caret>Shape<T> {
+            fn sides(self: @T) -> u32;
+        }
+        impl TriangleShape of Shape<u8> {
+            fn sides(self: @u8) -> u32 {
+                3
+            }
+        }
+        impl SquareShape of Shape<u16> {
+            fn sides(self: @u16) -> u32 {
+                4
+            }
+        }
+    "},
+    indoc! {"
+        trait Shape<T> {
+            fn sides(self: @T) -> u32;
+        }
+        impl <sel>TriangleShape</sel> of Shape<u8> {
+            fn sides(self: @u8) -> u32 {
+                3
+            }
+        }
+        impl <sel>SquareShape</sel> of Shape<u16> {
+            fn sides(self: @u16) -> u32 {
+                4
+            }
+        }
+    "};
+    "trait item"
+)]
+#[test_case(
+    indoc! {"
+        trait Shape<T> {
+            fn sides(self: @T) -> u32;
+        }
+        impl TriangleShape of Shape<u8> {
+            fn sides(self: @u8) -> u32 {
+                3
+            }
+        }
+        impl SquareShape of Shape<u16> {
+            fn sides(self: @u16) -> u32 {
+                4
+            }
+        }
+        fn foo(x: @u8) -> u32 {
+            Shape::<caret>sides(x)
+        }
+    "},
+    indoc! {"
+        trait Shape<T> {
+            fn sides(self: @T) -> u32;
+        }
+        impl TriangleShape of Shape<u8> {
+            fn <sel>sides</sel>(self: @u8) -> u32 {
+                3
+            }
+        }
+        impl SquareShape of Shape<u16> {
+            fn <sel>sides</sel>(self: @u16) -> u32 {
+                4
+            }
+        }
+        fn foo(x: @u8) -> u32 {
+            Shape::sides(x)
+        }
+    "};
+    "trait function"
+)]
+fn test_implementations(code: &str, expected: &str) {
+    assert_eq!(code_with_implementations(code), expected);
+}
+
+#[test_case(
+    indoc! {"
+        #[derive(Drop)]
+        struct Square {
+            side: u32,
+        }
+        fn foo() -> u32 {
+            let <caret>s = Square { side: 2 };
+            s.side
+        }
+    "},
+    indoc! {"
+        #[derive(Drop)]
+        struct <sel>Square</sel> {
+            side: u32,
+        }
+        fn foo() -> u32 {
+            let s = Square { side: 2 };
+            s.side
+        }
+    "};
+    "variable"
+)]
+#[test_case(
+    indoc! {"
+        #[derive(Drop)]
+        struct Square {
+            side: u32,
+        }
+        fn make() -> Square {
+            Square { side: 2 }
+        }
+        fn foo() -> u32 {
+            <caret>make().side
+        }
+    "},
+    indoc! {"
+        #[derive(Drop)]
+        struct <sel>Square</sel> {
+            side: u32,
+        }
+        fn make() -> Square {
+            Square { side: 2 }
+        }
+        fn foo() -> u32 {
+            make().side
+        }
+    "};
+    "call expression"
+)]
+#[test_case(
+    indoc! {"
+        enum Shape {
+            Square: u32,
+        }
+        fn foo(s: <caret>Shape) {}
+    "},
+    indoc! {"
+        enum <sel>Shape</sel> {
+            Square: u32,
+        }
+        fn foo(s: Shape) {}
+    "};
+    "type in signature"
+)]
+fn test_type_definition(code: &str, expected: &str) {
+    assert_eq!(code_with_type_definition(code), expected);
+}
+
+#[test_case(12, 3, &["total at 16:26", "area at 9:8"]; "free function")]
+#[test_case(8, 7, &["total at 16:6, 16:17"]; "impl function")]
+fn test_incoming_calls(line: u32, character: u32, expected: &[&str]) {
+    let (db, file) = setup_test_file(CALLS_CODE);
+    let position = Position { line, character };
+    let [item] = &prepare_call_hierarchy(&db, file, position).unwrap()[..] else {
+        panic!("Expected a single item.");
+    };
+    let calls = incoming_calls(&db, item).unwrap();
+    assert_eq!(
+        calls.iter().map(|call| describe_call(&call.from, &call.from_ranges)).collect::<Vec<_>>(),
+        expected
+    );
+}
+
+#[test_case(
+    15,
+    3,
+    &["area at 16:6, 16:17", "add at 16:13, 16:24", "square at 16:26"];
+    "free function"
+)]
+#[test_case(8, 7, &["square at 9:8"]; "impl function")]
+#[test_case(12, 3, &["mul at 13:6"]; "operator")]
+fn test_outgoing_calls(line: u32, character: u32, expected: &[&str]) {
+    let (db, file) = setup_test_file(CALLS_CODE);
+    let position = Position { line, character };
+    let [item] = &prepare_call_hierarchy(&db, file, position).unwrap()[..] else {
+        panic!("Expected a single item.");
+    };
+    let calls = outgoing_calls(&db, item).unwrap();
+    assert_eq!(
+        calls.iter().map(|call| describe_call(&call.to, &call.from_ranges)).collect::<Vec<_>>(),
+        expected
+    );
+}
@@ -0,0 +1,595 @@
+//! Find-all-references and rename support.
+//!
+//! A symbol is identified by the location of its name at its definition site, mapped back through
+//! plugin generated files to the user's code. This lets code copied by plugins (e.g. the body of a
+//! Starknet contract) be matched with the original code the user edits.
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{
+    FunctionWithBodyId, ImplItemId, LanguageElementId, LookupItemId, ModuleId, ModuleItemId,
+};
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::db::{get_originating_location, FilesGroup};
+use cairo_lang_filesystem::ids::{FileId, FileLongId, VirtualFile};
+use cairo_lang_filesystem::span::TextSpan;
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_parser::lexer::Lexer;
+use cairo_lang_semantic::corelib::core_crate;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::resolve::ResolvedGenericItem;
+use cairo_lang_semantic::{ConcreteStructId, Expr, ExprDesnap, ExprSnapshot, Pattern};
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use cairo_lang_utils::Upcast;
+use tower_lsp::lsp_types::{DocumentHighlight, DocumentHighlightKind, Location, Position, Range};
+
+use crate::{
+    from_pos, get_lookup_items, get_node, get_uri, resolved_concrete_item_def,
+    resolved_generic_item_def,
+};
+
+#[cfg(test)]
+#[path = "references_test.rs"]
+mod test;
+
+/// A span in a file, mapped back to the user's code where possible.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SymbolLocation {
+    pub file: FileId,
+    pub span: TextSpan,
+}
+
+/// A struct constructor argument or struct pattern field written in shorthand form (`S { x }`),
+/// where a single identifier names both a member and a variable.
+struct Shorthand {
+    /// The member the identifier refers to.
+    member: SymbolLocation,
+    /// The location of the whole argument or field, including binding modifiers.
+    location: SymbolLocation,
+    /// The text of the whole argument or field.
+    text: String,
+}
+
+/// An identifier in the workspace.
+struct Reference {
+    location: SymbolLocation,
+    /// The symbols the identifier refers to. An identifier refers to more than one symbol only in
+    /// the shorthand case.
+    symbols: Vec<SymbolLocation>,
+    shorthand: Option<Shorthand>,
+}
+
+/// A symbol along with all of its references in the workspace.
+pub struct SymbolReferences {
+    /// The location of the name of the symbol at its definition.
+    symbol: SymbolLocation,
+    name: String,
+    /// The location of the identifier the symbol was looked up from, in the requested file.
+    cursor: SymbolLocation,
+    references: Vec<Reference>,
+}
+
+impl SymbolReferences {
+    /// Returns the location of the identifier the symbol was looked up from.
+    pub fn cursor_location(&self) -> SymbolLocation {
+        self.cursor
+    }
+
+    /// Returns the locations of all the references, optionally including the declaration.
+    pub fn locations(&self, include_declaration: bool) -> Vec<SymbolLocation> {
+        self.references
+            .iter()
+            .map(|reference| reference.location)
+            .filter(|location| include_declaration || *location != self.symbol)
+            .collect()
+    }
+
+    /// Returns whether the symbol is defined in user code, and thus may be renamed.
+    pub fn is_renameable(&self, db: &dyn SemanticGroup) -> bool {
+        !is_generated_file(db.upcast(), self.symbol.file) && !is_in_core(db, self.symbol.file)
+    }
+
+    /// Returns the edits renaming the symbol to `new_name`.
+    ///
+    /// Shorthand sites are expanded, so that the other symbol named by them keeps its name.
+    pub fn rename_edits(&self, new_name: &str) -> Vec<(SymbolLocation, String)> {
+        let old_name = &self.name;
+        let mut edited = OrderedHashSet::<SymbolLocation>::default();
+        let mut edits = vec![];
+        for reference in &self.references {
+            let (location, text) = match &reference.shorthand {
+                Some(Shorthand { member, location, text }) if *member == self.symbol => {
+                    (*location, format!("{new_name}: {text}"))
+                }
+                Some(Shorthand { location, text, .. }) => {
+                    let modifiers = &text[..text.len() - old_name.len()];
+                    (*location, format!("{old_name}: {modifiers}{new_name}"))
+                }
+                None => (reference.location, new_name.to_string()),
+            };
+            if edited.insert(location) {
+                edits.push((location, text));
+            }
+        }
+        edits
+    }
+}
+
+/// Finds the symbol named by the identifier at the given position, along with all its references
+/// in the workspace.
+pub fn find_references(
+    db: &RootDatabase,
+    file: FileId,
+    position: Position,
+) -> Option<SymbolReferences> {
+    search_references(db, file, position, false)
+}
+
+/// Returns the occurrences, in the requested file, of the symbol named by the identifier at the
+/// given position. Declarations and assignment targets are marked as writes.
+pub fn document_highlights(
+    db: &RootDatabase,
+    file: FileId,
+    position: Position,
+) -> Option<Vec<DocumentHighlight>> {
+    let references = search_references(db, file, position, true)?;
+    let syntax = db.file_syntax(file).ok()?;
+    Some(
+        references
+            .locations(true)
+            .into_iter()
+            .filter(|location| location.file == file)
+            .filter_map(|location| {
+                let node = syntax.lookup_offset(db.upcast(), location.span.start);
+                let kind = if location == references.symbol || is_assigned(db, node) {
+                    DocumentHighlightKind::WRITE
+                } else {
+                    DocumentHighlightKind::READ
+                };
+                Some(DocumentHighlight { range: lsp_range(db, location)?, kind: Some(kind) })
+            })
+            .collect(),
+    )
+}
+
+/// Finds the symbol named by the identifier at the given position, along with its references in
+/// the workspace, or only in the requested file (and the code generated from it) if
+/// `in_file_only`.
+fn search_references(
+    db: &RootDatabase,
+    file: FileId,
+    position: Position,
+    in_file_only: bool,
+) -> Option<SymbolReferences> {
+    let syntax_db = db.upcast();
+    let node = get_node(db, file, position)?;
+    if node.kind(syntax_db) != SyntaxKind::TokenIdentifier {
+        return None;
+    }
+    let name = node.text(syntax_db)?.to_string();
+    let cursor = SymbolLocation { file, span: node.span_without_trivia(syntax_db) };
+    let cursor_origin = originating_location(db, file, &node, &name)?;
+
+    // Core is only searched when the requested symbol is used from it, as it is never renamed.
+    let include_core = is_in_core(db, cursor_origin.file);
+    let files = if in_file_only {
+        file_and_generated_files(db, file)
+    } else {
+        workspace_files(db, include_core)
+    };
+    let mut references = vec![];
+    for file in files {
+        let Ok(syntax) = db.file_syntax(file) else {
+            continue;
+        };
+        for node in syntax.descendants(syntax_db) {
+            if node.kind(syntax_db) != SyntaxKind::TokenIdentifier
+                || node.text(syntax_db).as_deref() != Some(name.as_str())
+            {
+                continue;
+            }
+            let identifier = ast::TerminalIdentifier::from_syntax_node(
+                syntax_db,
+                node.parent().expect("Token nodes always have a terminal parent."),
+            );
+            if let Some(reference) = identifier_reference(db, file, &identifier, &name) {
+                references.push(reference);
+            }
+        }
+    }
+
+    // The cursor may be in code that is also copied by a plugin, in which case only the copy is
+    // analyzed.
+    let symbol = references
+        .iter()
+        .filter(|reference| reference.location == cursor_origin)
+        .find_map(|reference| reference.symbols.first().copied())?;
+
+    let mut seen = OrderedHashSet::<SymbolLocation>::default();
+    references
+        .retain(|reference| reference.symbols.contains(&symbol) && seen.insert(reference.location));
+    Some(SymbolReferences { symbol, name, cursor, references })
+}
+
+/// Returns whether `name` can be used as an identifier.
+pub fn is_valid_identifier(db: &dyn SemanticGroup, file: FileId, name: &str) -> bool {
+    let mut terminals = Lexer::from_text(db.upcast(), file, name);
+    let Some(terminal) = terminals.next() else {
+        return false;
+    };
+    terminal.kind == SyntaxKind::TerminalIdentifier
+        && terminal.text == name
+        && terminal.leading_trivia.is_empty()
+        && terminal.trailing_trivia.is_empty()
+        && terminals.next().map(|terminal| terminal.kind) == Some(SyntaxKind::TerminalEndOfFile)
+}
+
+/// Converts a symbol location to an LSP location.
+pub fn lsp_location(db: &dyn FilesGroup, location: SymbolLocation) -> Option<Location> {
+    Some(Location { uri: get_uri(db, location.file), range: lsp_range(db, location)? })
+}
+
+/// Converts a symbol location to an LSP range.
+pub fn lsp_range(db: &dyn FilesGroup, location: SymbolLocation) -> Option<Range> {
+    let SymbolLocation { file, span } = location;
+    Some(Range {
+        start: from_pos(span.start.position_in_file(db, file)?),
+        end: from_pos(span.end.position_in_file(db, file)?),
+    })
+}
+
+/// Returns all the files of all the crates in the workspace, including plugin generated files.
+fn workspace_files(db: &RootDatabase, include_core: bool) -> OrderedHashSet<FileId> {
+    let core = core_crate(db);
+    let mut files = OrderedHashSet::default();
+    for crate_id in db.crates() {
+        if crate_id == core && !include_core {
+            continue;
+        }
+        for module_id in db.crate_modules(crate_id).iter() {
+            for file in db.module_files(*module_id).unwrap_or_default().iter() {
+                files.insert(*file);
+            }
+        }
+    }
+    files
+}
+
+/// Returns a file along with the files generated by plugins from the modules it defines.
+fn file_and_generated_files(db: &RootDatabase, file: FileId) -> OrderedHashSet<FileId> {
+    let mut files = OrderedHashSet::default();
+    files.insert(file);
+    for module_id in db.file_modules(file).unwrap_or_default().iter() {
+        for file in db.module_files(*module_id).unwrap_or_default().iter() {
+            files.insert(*file);
+        }
+    }
+    files
+}
+
+/// Returns whether the identifier token is the target of an assignment, possibly as the member
+/// accessed.
+fn is_assigned(db: &RootDatabase, token: SyntaxNode) -> bool {
+    let syntax_db = db.upcast();
+    let Some(mut target) = token.parent().and_then(|terminal| terminal.parent()?.parent()) else {
+        return false;
+    };
+    if target.kind(syntax_db) != SyntaxKind::ExprPath {
+        return false;
+    }
+    while let Some(parent) = target.parent() {
+        if parent.kind(syntax_db) != SyntaxKind::ExprBinary {
+            return false;
+        }
+        let binary = ast::ExprBinary::from_syntax_node(syntax_db, parent.clone());
+        match binary.op(syntax_db) {
+            ast::BinaryOperator::Dot(_)
+                if binary.rhs(syntax_db).as_syntax_node().stable_ptr() == target.stable_ptr() =>
+            {
+                target = parent;
+            }
+            ast::BinaryOperator::Eq(_)
+            | ast::BinaryOperator::PlusEq(_)
+            | ast::BinaryOperator::MinusEq(_)
+            | ast::BinaryOperator::MulEq(_)
+            | ast::BinaryOperator::DivEq(_)
+            | ast::BinaryOperator::ModEq(_) => {
+                return binary.lhs(syntax_db).as_syntax_node().stable_ptr() == target.stable_ptr();
+            }
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// Returns the reference made by an identifier, if it lies in user code.
+fn identifier_reference(
+    db: &RootDatabase,
+    file: FileId,
+    identifier: &ast::TerminalIdentifier,
+    name: &str,
+) -> Option<Reference> {
+    let syntax_db = db.upcast();
+    let node = identifier.as_syntax_node();
+    let location = originating_location(db, file, &node, name)?;
+    let lookup_items = get_lookup_items(db, file, node.clone()).unwrap_or_default();
+    let function_id = lookup_items.iter().find_map(|item| match item {
+        LookupItemId::ModuleItem(ModuleItemId::FreeFunction(id)) => {
+            Some(FunctionWithBodyId::Free(*id))
+        }
+        LookupItemId::ImplItem(ImplItemId::Function(id)) => Some(FunctionWithBodyId::Impl(*id)),
+        _ => None,
+    });
+
+    let mut definitions = identifier_definitions(db, identifier, &lookup_items, function_id);
+    let member = function_id.and_then(|function_id| {
+        let concrete_struct_id = member_struct(db, function_id, &node)?;
+        let members = db.struct_members(concrete_struct_id.struct_id(db)).ok()?;
+        Some(members.get(name)?.id.stable_ptr(db).untyped())
+    });
+    definitions.extend(member);
+    let symbols = definitions.into_iter().filter_map(|ptr| definition_location(db, ptr)).collect();
+
+    let shorthand = member.and_then(|member| {
+        let parent = node.parent()?;
+        let is_shorthand = match parent.kind(syntax_db) {
+            SyntaxKind::StructArgSingle => matches!(
+                ast::StructArgSingle::from_syntax_node(syntax_db, parent.clone())
+                    .arg_expr(syntax_db),
+                ast::OptionStructArgExpr::Empty(_)
+            ),
+            SyntaxKind::PatternIdentifier => {
+                parent.parent()?.kind(syntax_db) == SyntaxKind::PatternStructParamList
+            }
+            _ => false,
+        };
+        if !is_shorthand {
+            return None;
+        }
+        let text = parent.clone().get_text_without_trivia(syntax_db);
+        Some(Shorthand {
+            member: definition_location(db, member)?,
+            location: originating_location(db, file, &parent, &text)?,
+            text,
+        })
+    });
+
+    Some(Reference { location, symbols, shorthand })
+}
+
+/// Returns the definitions an identifier refers to, either as the name of a declaration or as a
+/// path resolved by the semantic model.
+fn identifier_definitions(
+    db: &RootDatabase,
+    identifier: &ast::TerminalIdentifier,
+    lookup_items: &[LookupItemId],
+    function_id: Option<FunctionWithBodyId>,
+) -> Vec<SyntaxStablePtrId> {
+    let syntax_db = db.upcast();
+    let node = identifier.as_syntax_node();
+    let Some(parent) = node.parent() else {
+        return vec![];
+    };
+    match parent.kind(syntax_db) {
+        SyntaxKind::FunctionDeclaration => {
+            let Some(function) = parent.parent() else {
+                return vec![];
+            };
+            // Functions of impls are renamed along with the trait function they implement.
+            let trait_function = lookup_items.iter().find_map(|item| match item {
+                LookupItemId::ImplItem(ImplItemId::Function(id))
+                    if id.untyped_stable_ptr(db) == function.stable_ptr() =>
+                {
+                    db.impl_function_trait_function(*id).ok()
+                }
+                _ => None,
+            });
+            return vec![match trait_function {
+                Some(trait_function) => trait_function.untyped_stable_ptr(db),
+                None => function.stable_ptr(),
+            }];
+        }
+        SyntaxKind::ItemModule
+        | SyntaxKind::ItemConstant
+        | SyntaxKind::ItemStruct
+        | SyntaxKind::ItemEnum
+        | SyntaxKind::ItemTrait
+        | SyntaxKind::ItemImpl
+        | SyntaxKind::ItemTypeAlias
+        | SyntaxKind::ItemImplAlias
+        | SyntaxKind::ItemExternType
+        | SyntaxKind::TraitItemType
+        | SyntaxKind::TraitItemConstant
+        | SyntaxKind::TraitItemImpl
+        | SyntaxKind::Member
+        | SyntaxKind::Variant
+        | SyntaxKind::Param
+        | SyntaxKind::GenericParamType
+        | SyntaxKind::GenericParamConst
+        | SyntaxKind::GenericParamImplNamed => return vec![parent.stable_ptr()],
+        _ => {}
+    }
+
+    let mut definitions = vec![];
+    if let Some(function_id) = function_id {
+        definitions.extend(declared_variable(db, function_id, &node));
+    }
+    for lookup_item_id in lookup_items.iter().copied() {
+        if let Some(item) =
+            db.lookup_resolved_generic_item_by_ptr(lookup_item_id, identifier.stable_ptr())
+        {
+            definitions.extend(match item {
+                ResolvedGenericItem::Module(ModuleId::Submodule(submodule_id)) => {
+                    Some(submodule_id.untyped_stable_ptr(db))
+                }
+                ResolvedGenericItem::Module(ModuleId::CrateRoot(_)) => None,
+                item => Some(resolved_generic_item_def(db, item)),
+            });
+            break;
+        } else if let Some(item) =
+            db.lookup_resolved_concrete_item_by_ptr(lookup_item_id, identifier.stable_ptr())
+        {
+            definitions.extend(resolved_concrete_item_def(db, item));
+            break;
+        }
+    }
+    definitions
+}
+
+/// If the identifier declares a local variable, returns the variable.
+fn declared_variable(
+    db: &RootDatabase,
+    function_id: FunctionWithBodyId,
+    node: &SyntaxNode,
+) -> Option<SyntaxStablePtrId> {
+    let syntax_db = db.upcast();
+    let parent = node.parent()?;
+    // A variable is bound either by a plain path pattern or by a pattern with modifiers.
+    let pattern = match parent.kind(syntax_db) {
+        SyntaxKind::PatternIdentifier => parent,
+        SyntaxKind::PathSegmentSimple => parent.parent()?,
+        _ => return None,
+    };
+    if !ast::Pattern::is_variant(pattern.kind(syntax_db)) {
+        return None;
+    }
+    let pattern = ast::Pattern::from_syntax_node(syntax_db, pattern);
+    let pattern_id = db.lookup_pattern_by_ptr(function_id, pattern.stable_ptr()).to_option()?;
+    match db.pattern_semantic(function_id, pattern_id) {
+        Pattern::Variable(variable) => Some(variable.var.id.untyped_stable_ptr(db)),
+        _ => None,
+    }
+}
+
+/// If the identifier names a struct member in a member access, a struct constructor or a struct
+/// pattern, returns the struct.
+fn member_struct(
+    db: &RootDatabase,
+    function_id: FunctionWithBodyId,
+    node: &SyntaxNode,
+) -> Option<ConcreteStructId> {
+    let syntax_db = db.upcast();
+    let parent = node.parent()?;
+    match parent.kind(syntax_db) {
+        SyntaxKind::PathSegmentSimple => {
+            let path = parent.parent()?;
+            let binary = path.parent()?;
+            if binary.kind(syntax_db) != SyntaxKind::ExprBinary {
+                return None;
+            }
+            let binary = ast::ExprBinary::from_syntax_node(syntax_db, binary);
+            if binary.rhs(syntax_db).as_syntax_node().stable_ptr() != path.stable_ptr() {
+                return None;
+            }
+            let mut expr_id =
+                db.lookup_expr_by_ptr(function_id, binary.stable_ptr().into()).ok()?;
+            // Method receivers may be implicitly snapshotted or desnapped.
+            loop {
+                match db.expr_semantic(function_id, expr_id) {
+                    Expr::MemberAccess(member_access) => {
+                        return Some(member_access.concrete_struct_id);
+                    }
+                    Expr::Snapshot(ExprSnapshot { inner, .. })
+                    | Expr::Desnap(ExprDesnap { inner, .. }) => expr_id = inner,
+                    _ => return None,
+                }
+            }
+        }
+        SyntaxKind::StructArgSingle => {
+            let ctor = ancestor_of_kind(syntax_db, parent, SyntaxKind::ExprStructCtorCall)?;
+            let ctor = ast::ExprStructCtorCall::from_syntax_node(syntax_db, ctor);
+            let expr_id = db.lookup_expr_by_ptr(function_id, ctor.stable_ptr().into()).ok()?;
+            match db.expr_semantic(function_id, expr_id) {
+                Expr::StructCtor(struct_ctor) => Some(struct_ctor.concrete_struct_id),
+                _ => None,
+            }
+        }
+        SyntaxKind::PatternIdentifier | SyntaxKind::PatternStructParamWithExpr => {
+            if parent.parent()?.kind(syntax_db) != SyntaxKind::PatternStructParamList {
+                return None;
+            }
+            let pattern = ancestor_of_kind(syntax_db, parent, SyntaxKind::PatternStruct)?;
+            let pattern = ast::PatternStruct::from_syntax_node(syntax_db, pattern);
+            let pattern_id =
+                db.lookup_pattern_by_ptr(function_id, pattern.stable_ptr().into()).to_option()?;
+            match db.pattern_semantic(function_id, pattern_id) {
+                Pattern::Struct(pattern_struct) => Some(pattern_struct.concrete_struct_id),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the location of the name of the definition at `ptr`, or None if it has no name.
+pub fn definition_location(db: &RootDatabase, ptr: SyntaxStablePtrId) -> Option<SymbolLocation> {
+    let syntax_db = db.upcast();
+    let node = ptr.lookup(syntax_db);
+    let node = match node.kind(syntax_db) {
+        SyntaxKind::TerminalIdentifier => node,
+        SyntaxKind::FunctionWithBody
+        | SyntaxKind::TraitItemFunction
+        | SyntaxKind::ItemExternFunction => {
+            let declaration = child_of_kind(syntax_db, node, SyntaxKind::FunctionDeclaration)?;
+            child_of_kind(syntax_db, declaration, SyntaxKind::TerminalIdentifier)?
+        }
+        _ => child_of_kind(syntax_db, node, SyntaxKind::TerminalIdentifier)?,
+    };
+    let file = ptr.file_id(syntax_db);
+    let name = node.clone().get_text_without_trivia(syntax_db);
+    // Names generated by plugins without a mapping to user code identify the symbol in place.
+    originating_location(db, file, &node, &name)
+        .or_else(|| Some(SymbolLocation { file, span: node.span_without_trivia(syntax_db) }))
+}
+
+/// Maps the span of `node` back to user code, as long as the text found there is `text`.
+fn originating_location(
+    db: &RootDatabase,
+    file: FileId,
+    node: &SyntaxNode,
+    text: &str,
+) -> Option<SymbolLocation> {
+    let (file, span) =
+        get_originating_location(db.upcast(), file, node.span_without_trivia(db.upcast()));
+    let content = db.file_content(file)?;
+    if is_generated_file(db.upcast(), file) || span.take(&content) != text {
+        return None;
+    }
+    Some(SymbolLocation { file, span })
+}
+
+/// Returns whether the file was generated by a plugin.
+pub fn is_generated_file(db: &dyn FilesGroup, file: FileId) -> bool {
+    matches!(db.lookup_intern_file(file), FileLongId::Virtual(VirtualFile { parent: Some(_), .. }))
+}
+
+/// Returns whether the file belongs to the core library.
+fn is_in_core(db: &dyn SemanticGroup, file: FileId) -> bool {
+    let Ok(modules) = db.file_modules(file) else {
+        return false;
+    };
+    modules.iter().any(|module_id| module_id.owning_crate(db.upcast()) == core_crate(db))
+}
+
+fn child_of_kind(
+    db: &dyn cairo_lang_syntax::node::db::SyntaxGroup,
+    node: SyntaxNode,
+    kind: SyntaxKind,
+) -> Option<SyntaxNode> {
+    db.get_children(node).iter().find(|child| child.kind(db) == kind).cloned()
+}
+
+fn ancestor_of_kind(
+    db: &dyn cairo_lang_syntax::node::db::SyntaxGroup,
+    mut node: SyntaxNode,
+    kind: SyntaxKind,
+) -> Option<SyntaxNode> {
+    while node.kind(db) != kind {
+        node = node.parent()?;
+    }
+    Some(node)
+}
@@ -0,0 +1,571 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+use tower_lsp::lsp_types::TextEdit;
+
+use super::{find_references, is_valid_identifier, lsp_range};
+use crate::test_utils::{
+    apply_text_edits, extract_cursor, mark_ranges, setup_test_file, setup_test_files,
+};
+
+/// Returns the code with the references to the symbol at its cursor marked, optionally including
+/// the declaration.
+fn references(code: &str, include_declaration: bool) -> String {
+    let (code, position) = extract_cursor(code);
+    let (db, file) = setup_test_file(&code);
+    let references = find_references(&db, file, position).expect("No symbol at the cursor.");
+    let ranges = references.locations(include_declaration).into_iter().map(|location| {
+        assert_eq!(location.file, file);
+        lsp_range(&db, location).unwrap()
+    });
+    mark_ranges(&code, ranges)
+}
+
+/// Renames the symbol at the cursor of the given code to `new_name`, and returns the edited code.
+fn rename(code: &str, new_name: &str) -> String {
+    let (code, position) = extract_cursor(code);
+    let (db, file) = setup_test_file(&code);
+    let references = find_references(&db, file, position).expect("No symbol at the cursor.");
+    let edits = references
+        .rename_edits(new_name)
+        .into_iter()
+        .map(|(location, new_text)| {
+            assert_eq!(location.file, file);
+            TextEdit { range: lsp_range(&db, location).unwrap(), new_text }
+        })
+        .collect();
+    apply_text_edits(&code, edits)
+}
+
+#[test_case(
+    indoc! {"
+        #[derive(Drop)]
+        struct S {
+            <caret>x: felt252,
+        }
+        fn foo(s: S) -> felt252 {
+            let x = s.x;
+            let S { x } = S { x };
+            x
+        }
+    "},
+    indoc! {"
+        #[derive(Drop)]
+        struct S {
+            y: felt252,
+        }
+        fn foo(s: S) -> felt252 {
+            let x = s.y;
+            let S { y: x } = S { y: x };
+            x
+        }
+    "};
+    "member"
+)]
+#[test_case(
+    indoc! {"
+        #[derive(Drop)]
+        struct S {
+            x: felt252,
+        }
+        fn foo() -> felt252 {
+            let <caret>x = 1;
+            let S { x } = S { x };
+            x
+        }
+    "},
+    indoc! {"
+        #[derive(Drop)]
+        struct S {
+            x: felt252,
+        }
+        fn foo() -> felt252 {
+            let y = 1;
+            let S { x } = S { x: y };
+            x
+        }
+    "};
+    "local in constructor"
+)]
+#[test_case(
+    indoc! {"
+        #[derive(Drop)]
+        struct S {
+            x: felt252,
+        }
+        fn foo(s: S) -> felt252 {
+            let S { mut x } = s;
+            <caret>x += 1;
+            x
+        }
+    "},
+    indoc! {"
+        #[derive(Drop)]
+        struct S {
+            x: felt252,
+        }
+        fn foo(s: S) -> felt252 {
+            let S { x: mut y } = s;
+            y += 1;
+            y
+        }
+    "};
+    "mut local in pattern"
+)]
+#[test_case(
+    indoc! {"
+        #[derive(Drop)]
+        struct S {
+            x: felt252,
+        }
+        fn foo(s: S) -> felt252 {
+            let S { ref x } = s;
+            <caret>x
+        }
+    "},
+    indoc! {"
+        #[derive(Drop)]
+        struct S {
+            x: felt252,
+        }
+        fn foo(s: S) -> felt252 {
+            let S { x: ref y } = s;
+            y
+        }
+    "};
+    "ref local in pattern"
+)]
+fn test_rename_shorthand(code: &str, expected: &str) {
+    assert_eq!(rename(code, "y"), expected);
+}
+
+#[test_case(
+    indoc! {"
+        fn <caret>foo() -> felt252 {
+            1
+        }
+        fn bar() -> felt252 {
+            foo() + foo()
+        }
+    "},
+    indoc! {"
+        fn <sel>foo</sel>() -> felt252 {
+            1
+        }
+        fn bar() -> felt252 {
+            <sel>foo</sel>() + <sel>foo</sel>()
+        }
+    "};
+    "function"
+)]
+#[test_case(
+    indoc! {"
+        #[derive(Drop)]
+        struct S {
+            x: felt252,
+        }
+        fn foo(s: <caret>S) -> S {
+            S { x: s.x }
+        }
+    "},
+    indoc! {"
+        #[derive(Drop)]
+        struct <sel>S</sel> {
+            x: felt252,
+        }
+        fn foo(s: <sel>S</sel>) -> <sel>S</sel> {
+            <sel>S</sel> { x: s.x }
+        }
+    "};
+    "struct item"
+)]
+#[test_case(
+    indoc! {"
+        enum E {
+            A: felt252,
+            B,
+        }
+        fn foo(e: E) -> felt252 {
+            match e {
+                E::<caret>A(x) => x,
+                E::B => 0,
+            }
+        }
+        fn bar() -> E {
+            E::A(1)
+        }
+    "},
+    indoc! {"
+        enum E {
+            <sel>A</sel>: felt252,
+            B,
+        }
+        fn foo(e: E) -> felt252 {
+            match e {
+                E::<sel>A</sel>(x) => x,
+                E::B => 0,
+            }
+        }
+        fn bar() -> E {
+            E::<sel>A</sel>(1)
+        }
+    "};
+    "enum variant"
+)]
+#[test_case(
+    indoc! {"
+        trait Tr {
+            fn <caret>f(self: felt252) -> felt252;
+        }
+        impl TrImpl of Tr {
+            fn f(self: felt252) -> felt252 {
+                self
+            }
+        }
+        fn foo() -> felt252 {
+            1.f() + Tr::f(2)
+        }
+    "},
+    indoc! {"
+        trait Tr {
+            fn <sel>f</sel>(self: felt252) -> felt252;
+        }
+        impl TrImpl of Tr {
+            fn <sel>f</sel>(self: felt252) -> felt252 {
+                self
+            }
+        }
+        fn foo() -> felt252 {
+            1.<sel>f</sel>() + Tr::<sel>f</sel>(2)
+        }
+    "};
+    "trait method"
+)]
+#[test_case(
+    indoc! {"
+        #[starknet::contract]
+        mod contract {
+            #[storage]
+            struct Storage {
+                value: felt252,
+            }
+            #[external(v0)]
+            fn get(self: @ContractState) -> felt252 {
+                self.<caret>value.read()
+            }
+            #[external(v0)]
+            fn set(ref self: ContractState, value: felt252) {
+                self.value.write(value);
+            }
+        }
+    "},
+    indoc! {"
+        #[starknet::contract]
+        mod contract {
+            #[storage]
+            struct Storage {
+                <sel>value</sel>: felt252,
+            }
+            #[external(v0)]
+            fn get(self: @ContractState) -> felt252 {
+                self.<sel>value</sel>.read()
+            }
+            #[external(v0)]
+            fn set(ref self: ContractState, value: felt252) {
+                self.<sel>value</sel>.write(value);
+            }
+        }
+    "};
+    "storage variable"
+)]
+#[test_case(
+    indoc! {"
+        fn foo(x: felt252) -> felt252 {
+            let <caret>y = x + 1;
+            let x = y * y;
+            x + y
+        }
+    "},
+    indoc! {"
+        fn foo(x: felt252) -> felt252 {
+            let <sel>y</sel> = x + 1;
+            let x = <sel>y</sel> * <sel>y</sel>;
+            x + <sel>y</sel>
+        }
+    "};
+    "local"
+)]
+fn test_references(code: &str, expected: &str) {
+    assert_eq!(references(code, true), expected);
+}
+
+#[test]
+fn test_references_without_declaration() {
+    let code = indoc! {"
+        fn foo() -> felt252 {
+            let x = 1;
+            <caret>x + x
+        }
+    "};
+    let expected = indoc! {"
+        fn foo() -> felt252 {
+            let x = 1;
+            <sel>x</sel> + <sel>x</sel>
+        }
+    "};
+    assert_eq!(references(code, false), expected);
+}
+
+#[test_case(
+    indoc! {"
+        fn <caret>foo() -> felt252 {
+            1
+        }
+        fn bar() -> felt252 {
+            foo() + foo()
+        }
+    "},
+    indoc! {"
+        fn baz() -> felt252 {
+            1
+        }
+        fn bar() -> felt252 {
+            baz() + baz()
+        }
+    "};
+    "function"
+)]
+#[test_case(
+    indoc! {"
+        #[derive(Drop)]
+        struct <caret>S {
+            x: felt252,
+        }
+        fn foo(s: S) -> S {
+            S { x: s.x }
+        }
+    "},
+    indoc! {"
+        #[derive(Drop)]
+        struct baz {
+            x: felt252,
+        }
+        fn foo(s: baz) -> baz {
+            baz { x: s.x }
+        }
+    "};
+    "struct item"
+)]
+#[test_case(
+    indoc! {"
+        enum E {
+            <caret>A: felt252,
+            B,
+        }
+        fn foo(e: E) -> felt252 {
+            match e {
+                E::A(x) => x,
+                E::B => 0,
+            }
+        }
+    "},
+    indoc! {"
+        enum E {
+            baz: felt252,
+            B,
+        }
+        fn foo(e: E) -> felt252 {
+            match e {
+                E::baz(x) => x,
+                E::B => 0,
+            }
+        }
+    "};
+    "enum variant"
+)]
+#[test_case(
+    indoc! {"
+        trait Tr {
+            fn f(self: felt252) -> felt252;
+        }
+        impl TrImpl of Tr {
+            fn <caret>f(self: felt252) -> felt252 {
+                self
+            }
+        }
+        fn foo() -> felt252 {
+            1.f() + Tr::f(2)
+        }
+    "},
+    indoc! {"
+        trait Tr {
+            fn baz(self: felt252) -> felt252;
+        }
+        impl TrImpl of Tr {
+            fn baz(self: felt252) -> felt252 {
+                self
+            }
+        }
+        fn foo() -> felt252 {
+            1.baz() + Tr::baz(2)
+        }
+    "};
+    "trait method from impl"
+)]
+#[test_case(
+    indoc! {"
+        #[starknet::contract]
+        mod contract {
+            #[storage]
+            struct Storage {
+                <caret>value: felt252,
+            }
+            #[external(v0)]
+            fn set(ref self: ContractState, value: felt252) {
+                self.value.write(value);
+            }
+        }
+    "},
+    indoc! {"
+        #[starknet::contract]
+        mod contract {
+            #[storage]
+            struct Storage {
+                baz: felt252,
+            }
+            #[external(v0)]
+            fn set(ref self: ContractState, value: felt252) {
+                self.baz.write(value);
+            }
+        }
+    "};
+    "storage variable"
+)]
+#[test_case(
+    indoc! {"
+        fn foo(<caret>x: felt252) -> felt252 {
+            let y = x + 1;
+            let x = y * x;
+            x
+        }
+    "},
+    indoc! {"
+        fn foo(baz: felt252) -> felt252 {
+            let y = baz + 1;
+            let x = y * baz;
+            x
+        }
+    "};
+    "parameter shadowed by local"
+)]
+fn test_rename(code: &str, expected: &str) {
+    assert_eq!(rename(code, "baz"), expected);
+}
+
+#[test_case(
+    indoc! {"
+        fn foo() -> felt252 {
+            core::<caret>integer::u8_to_felt252(1)
+        }
+    "};
+    "core module"
+)]
+#[test_case(
+    indoc! {"
+        fn foo(x: Array<felt252>) -> usize {
+            x.<caret>len()
+        }
+    "};
+    "core function"
+)]
+#[test_case(
+    indoc! {"
+        #[starknet::contract]
+        mod contract {
+            #[storage]
+            struct Storage {}
+            #[external(v0)]
+            fn foo(self: @<caret>ContractState) {}
+        }
+    "};
+    "plugin generated struct"
+)]
+fn test_not_renameable(code: &str) {
+    let (code, position) = extract_cursor(code);
+    let (db, file) = setup_test_file(&code);
+    let references = find_references(&db, file, position).expect("No symbol at the cursor.");
+    assert!(!references.is_renameable(&db));
+}
+
+#[test_case("bar", true; "identifier")]
+#[test_case("_bar1", true; "identifier with underscore and digit")]
+#[test_case("1bar", false; "starts with digit")]
+#[test_case("let", false; "keyword")]
+#[test_case("bar baz", false; "two identifiers")]
+#[test_case("", false; "empty")]
+fn test_valid_identifier(name: &str, expected: bool) {
+    let (db, file) = setup_test_file("");
+    assert_eq!(is_valid_identifier(&db, file, name), expected);
+}
+
+#[test]
+fn test_rename_across_files() {
+    let lib_code = indoc! {"
+        mod a;
+        use a::<caret>Point;
+        fn origin() -> Point {
+            a::make(0, 0)
+        }
+    "};
+    let a_code = indoc! {"
+        #[derive(Drop)]
+        pub struct Point {
+            pub x: felt252,
+            pub y: felt252,
+        }
+        pub fn make(x: felt252, y: felt252) -> Point {
+            Point { x, y }
+        }
+    "};
+    let (lib_code, position) = extract_cursor(lib_code);
+    let (db, files) = setup_test_files(&[("lib.cairo", &lib_code), ("a.cairo", a_code)]);
+    let references = find_references(&db, files[0], position).expect("No symbol at the cursor.");
+    let edits = references.rename_edits("Vec2");
+    let edited: Vec<_> = files
+        .iter()
+        .zip([lib_code.as_str(), a_code])
+        .map(|(file, code)| {
+            let file_edits = edits
+                .iter()
+                .filter(|(location, _)| location.file == *file)
+                .map(|(location, new_text)| TextEdit {
+                    range: lsp_range(&db, *location).unwrap(),
+                    new_text: new_text.clone(),
+                })
+                .collect();
+            apply_text_edits(code, file_edits)
+        })
+        .collect();
+    assert_eq!(
+        edited,
+        [
+            indoc! {"
+                mod a;
+                use a::Vec2;
+                fn origin() -> Vec2 {
+                    a::make(0, 0)
+                }
+            "},
+            indoc! {"
+                #[derive(Drop)]
+                pub struct Vec2 {
+                    pub x: felt252,
+                    pub y: felt252,
+                }
+                pub fn make(x: felt252, y: felt252) -> Vec2 {
+                    Vec2 { x, y }
+                }
+            "},
+        ]
+    );
+}
@@ -105,6 +105,7 @@ impl ScarbService {
                             edition,
                             cfg_set: Default::default(),
                             experimental_features: Default::default(),
+                            lints: Default::default(),
                         },
                     ))
                 } else {
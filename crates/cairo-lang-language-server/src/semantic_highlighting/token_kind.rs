@@ -81,7 +81,9 @@ impl SemanticTokenKind {
             | SyntaxKind::TokenMinus
             | SyntaxKind::TokenMul
             | SyntaxKind::TokenDiv
-            | SyntaxKind::TokenMod => return Some(SemanticTokenKind::Operator),
+            | SyntaxKind::TokenMod
+            | SyntaxKind::TokenDotDot
+            | SyntaxKind::TokenDotDotEq => return Some(SemanticTokenKind::Operator),
             SyntaxKind::TokenSingleLineComment => return Some(SemanticTokenKind::Comment),
             SyntaxKind::TokenShortString | SyntaxKind::TokenString => {
                 return Some(SemanticTokenKind::String);
@@ -152,7 +154,8 @@ impl SemanticTokenKind {
                     db.lookup_resolved_generic_item_by_ptr(lookup_item_id, identifier.stable_ptr())
                 {
                     return Some(match item {
-                        ResolvedGenericItem::Constant(_) => SemanticTokenKind::EnumMember,
+                        ResolvedGenericItem::Constant(_)
+                        | ResolvedGenericItem::TraitConstant(_) => SemanticTokenKind::EnumMember,
                         ResolvedGenericItem::Module(_) => SemanticTokenKind::Namespace,
                         ResolvedGenericItem::GenericFunction(_)
                         | ResolvedGenericItem::TraitFunction(_) => SemanticTokenKind::Function,
@@ -170,7 +173,8 @@ impl SemanticTokenKind {
                     db.lookup_resolved_concrete_item_by_ptr(lookup_item_id, identifier.stable_ptr())
                 {
                     return Some(match item {
-                        ResolvedConcreteItem::Constant(_) => SemanticTokenKind::EnumMember,
+                        ResolvedConcreteItem::Constant(_)
+                        | ResolvedConcreteItem::ImplConstant(_) => SemanticTokenKind::EnumMember,
                         ResolvedConcreteItem::Module(_) => SemanticTokenKind::Namespace,
                         ResolvedConcreteItem::Function(_)
                         | ResolvedConcreteItem::TraitFunction(_) => SemanticTokenKind::Function,
@@ -0,0 +1,327 @@
+//! Signature help for the arguments of function calls and inline macros.
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::TextOffset;
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::expr::inference::InferenceId;
+use cairo_lang_semantic::items::function_with_body::SemanticExprLookup;
+use cairo_lang_semantic::items::functions::{GenericFunctionId, ImplGenericFunctionId};
+use cairo_lang_semantic::items::imp::ImplId;
+use cairo_lang_semantic::lookup_item::{HasResolverData, LookupItemEx};
+use cairo_lang_semantic::resolve::{ResolvedGenericItem, Resolver};
+use cairo_lang_semantic::Expr;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::PathSegmentEx;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+use tower_lsp::lsp_types::{
+    ParameterInformation, ParameterLabel, Position, SignatureHelp, SignatureInformation,
+};
+
+use crate::completions::find_methods_for_type;
+use crate::{get_lookup_items, position_to_offset};
+
+#[cfg(test)]
+#[path = "signature_help_test.rs"]
+mod test;
+
+/// Returns the signature of the innermost call or inline macro whose arguments contain the
+/// position, with the argument at the position as the active parameter.
+pub fn signature_help(
+    db: &RootDatabase,
+    file: FileId,
+    position: Position,
+) -> Option<SignatureHelp> {
+    let syntax_db = db.upcast();
+    let offset = position_to_offset(db.file_summary(file)?, position, &db.file_content(file)?)?;
+    let mut node = Some(db.file_syntax(file).to_option()?.lookup_offset(syntax_db, offset));
+    while let Some(current) = node {
+        if matches!(
+            current.kind(syntax_db),
+            SyntaxKind::ArgListParenthesized
+                | SyntaxKind::ArgListBracketed
+                | SyntaxKind::ArgListBraced
+        ) {
+            // The argument lists consist of an opening token, the arguments and a closing token,
+            // which may be missing while the arguments are typed.
+            let children = db.get_children(current.clone());
+            let [open, arguments, close] = &children[..] else {
+                return None;
+            };
+            let is_inside = open.span_end_without_trivia(syntax_db) <= offset
+                && (close.width(syntax_db).as_u32() == 0
+                    || offset <= close.span_start_without_trivia(syntax_db));
+            if is_inside {
+                let (argument_index, argument_name) = active_argument(db, arguments, offset);
+                let parent = current.parent()?;
+                let signature = match parent.kind(syntax_db) {
+                    SyntaxKind::ExprFunctionCall => {
+                        function_signature(db, file, parent, argument_index, argument_name)
+                    }
+                    SyntaxKind::ExprInlineMacro => macro_signature(db, parent, argument_index),
+                    _ => None,
+                }?;
+                let active_parameter = signature.active_parameter;
+                return Some(SignatureHelp {
+                    signatures: vec![signature],
+                    active_signature: Some(0),
+                    active_parameter,
+                });
+            }
+        }
+        node = current.parent();
+    }
+    None
+}
+
+/// Returns the index of the argument at an offset in an argument list, and its name if it is a
+/// named argument.
+fn active_argument(
+    db: &RootDatabase,
+    arguments: &SyntaxNode,
+    offset: TextOffset,
+) -> (usize, Option<String>) {
+    let syntax_db = db.upcast();
+    // Arguments and the commas separating them alternate.
+    let children = db.get_children(arguments.clone());
+    let index = children
+        .iter()
+        .filter(|child| {
+            child.kind(syntax_db) == SyntaxKind::TerminalComma
+                && child.span_end_without_trivia(syntax_db) <= offset
+        })
+        .count();
+    let name =
+        children.iter().step_by(2).nth(index).and_then(
+            |argument| match ast::Arg::from_syntax_node(syntax_db, argument.clone())
+                .arg_clause(syntax_db)
+            {
+                ast::ArgClause::Named(clause) => {
+                    Some(clause.name(syntax_db).text(syntax_db).into())
+                }
+                ast::ArgClause::Unnamed(_) | ast::ArgClause::FieldInitShorthand(_) => None,
+            },
+        );
+    (index, name)
+}
+
+/// Returns the signature of the function called by a function call expression.
+fn function_signature(
+    db: &RootDatabase,
+    file: FileId,
+    call_node: SyntaxNode,
+    argument_index: usize,
+    argument_name: Option<String>,
+) -> Option<SignatureInformation> {
+    let syntax_db = db.upcast();
+    let call = ast::ExprFunctionCall::from_syntax_node(syntax_db, call_node.clone());
+    // Method calls (`a.f(x)`) pass the receiver as the first argument.
+    let method_call = call_node.parent().and_then(|parent| {
+        if parent.kind(syntax_db) != SyntaxKind::ExprBinary {
+            return None;
+        }
+        let binary = ast::ExprBinary::from_syntax_node(syntax_db, parent);
+        let is_method_call = matches!(binary.op(syntax_db), ast::BinaryOperator::Dot(_))
+            && binary.rhs(syntax_db).as_syntax_node() == call_node;
+        is_method_call.then_some(binary)
+    });
+    let declaration = callee_declaration(db, file, &call, method_call.as_ref())?;
+
+    let params = declaration.signature(syntax_db).parameters(syntax_db).elements(syntax_db);
+    let active_parameter = match argument_name {
+        Some(name) => params.iter().position(|param| param.name(syntax_db).text(syntax_db) == name),
+        None => Some(argument_index + usize::from(method_call.is_some())),
+    };
+    Some(declaration_signature(db, &declaration, active_parameter))
+}
+
+/// Resolves the declaration of the function called by a function call expression.
+fn callee_declaration(
+    db: &RootDatabase,
+    file: FileId,
+    call: &ast::ExprFunctionCall,
+    method_call: Option<&ast::ExprBinary>,
+) -> Option<ast::FunctionDeclaration> {
+    let syntax_db = db.upcast();
+    let lookup_items = get_lookup_items(db, file, call.as_syntax_node())?;
+    let lookup_item_id = lookup_items.first().copied()?;
+    let function_id = lookup_item_id.function_with_body()?;
+
+    // The call expression was resolved by the semantic model.
+    let expr_ptr = match method_call {
+        Some(method_call) => ast::Expr::Binary(method_call.clone()).stable_ptr(),
+        None => ast::Expr::FunctionCall(call.clone()).stable_ptr(),
+    };
+    if let Some(expr_id) = db.lookup_expr_by_ptr(function_id, expr_ptr).to_option() {
+        if let Expr::FunctionCall(call_expr) = db.expr_semantic(function_id, expr_id) {
+            let generic_function = call_expr.function.get_concrete(db).generic_function;
+            return generic_function_declaration(db, generic_function);
+        }
+    }
+
+    // The arguments may not be valid yet while they are typed, so resolve the callee by itself.
+    let segments = call.path(syntax_db).elements(syntax_db);
+    let identifier = segments.last()?.identifier_ast(syntax_db);
+    let Some(method_call) = method_call else {
+        return match db
+            .lookup_resolved_generic_item_by_ptr(lookup_item_id, identifier.stable_ptr())?
+        {
+            ResolvedGenericItem::GenericFunction(generic_function) => {
+                generic_function_declaration(db, generic_function)
+            }
+            ResolvedGenericItem::TraitFunction(trait_function_id) => {
+                Some(trait_function_id.stable_ptr(db).lookup(syntax_db).declaration(syntax_db))
+            }
+            _ => None,
+        };
+    };
+    let lhs = method_call.lhs(syntax_db);
+    let lhs_id = db.lookup_expr_by_ptr(function_id, lhs.stable_ptr()).to_option()?;
+    let ty = db.expr_semantic(function_id, lhs_id).ty();
+    if ty.is_missing(db) {
+        return None;
+    }
+    let resolver_data = lookup_item_id.resolver_data(db).ok()?;
+    let resolver = Resolver::with_data(
+        db,
+        resolver_data.as_ref().clone_with_inference_id(db, InferenceId::NoContext),
+    );
+    let name = identifier.text(syntax_db);
+    find_methods_for_type(db, resolver, ty, lhs.stable_ptr().untyped())
+        .into_iter()
+        .find(|trait_function_id| trait_function_id.name(db) == name)
+        .map(|trait_function_id| {
+            trait_function_id.stable_ptr(db).lookup(syntax_db).declaration(syntax_db)
+        })
+}
+
+/// Returns the declaration of a generic function. For functions of concrete impls, the declaration
+/// in the impl is preferred over the one in the trait, as it names the concrete types.
+fn generic_function_declaration(
+    db: &RootDatabase,
+    generic_function: GenericFunctionId,
+) -> Option<ast::FunctionDeclaration> {
+    let syntax_db = db.upcast();
+    Some(match generic_function {
+        GenericFunctionId::Free(free_function_id) => {
+            free_function_id.stable_ptr(db).lookup(syntax_db).declaration(syntax_db)
+        }
+        GenericFunctionId::Extern(extern_function_id) => {
+            extern_function_id.stable_ptr(db).lookup(syntax_db).declaration(syntax_db)
+        }
+        GenericFunctionId::Impl(ImplGenericFunctionId { impl_id, function }) => {
+            let impl_function_id = match impl_id {
+                ImplId::Concrete(concrete_impl_id) => {
+                    concrete_impl_id.get_impl_function(db, function).ok().flatten()
+                }
+                _ => None,
+            };
+            match impl_function_id {
+                Some(impl_function_id) => {
+                    impl_function_id.stable_ptr(db).lookup(syntax_db).declaration(syntax_db)
+                }
+                None => function.stable_ptr(db).lookup(syntax_db).declaration(syntax_db),
+            }
+        }
+    })
+}
+
+/// Returns the signature of a function declaration, including its generic parameters, implicits
+/// and `nopanic`, on a single line.
+fn declaration_signature(
+    db: &RootDatabase,
+    declaration: &ast::FunctionDeclaration,
+    active_parameter: Option<usize>,
+) -> SignatureInformation {
+    let syntax_db = db.upcast();
+    let signature = declaration.signature(syntax_db);
+    let mut label = format!(
+        "fn {}{}(",
+        declaration.name(syntax_db).text(syntax_db),
+        single_line_text(db, declaration.generic_params(syntax_db).as_syntax_node())
+    );
+    let mut parameters = vec![];
+    for (i, param) in signature.parameters(syntax_db).elements(syntax_db).into_iter().enumerate() {
+        if i > 0 {
+            label.push_str(", ");
+        }
+        let param_text = single_line_text(db, param.as_syntax_node());
+        parameters.push(parameter_information(&mut label, &param_text));
+    }
+    label.push(')');
+    for suffix in [
+        signature.ret_ty(syntax_db).as_syntax_node(),
+        signature.implicits_clause(syntax_db).as_syntax_node(),
+        signature.optional_no_panic(syntax_db).as_syntax_node(),
+    ] {
+        let suffix_text = single_line_text(db, suffix);
+        if !suffix_text.is_empty() {
+            label.push(' ');
+            label.push_str(&suffix_text);
+        }
+    }
+    let active_parameter =
+        active_parameter.filter(|index| *index < parameters.len()).map(|index| index as u32);
+    SignatureInformation {
+        label,
+        documentation: None,
+        parameters: Some(parameters),
+        active_parameter,
+    }
+}
+
+/// Returns the signature of an inline macro, if it has one.
+fn macro_signature(
+    db: &RootDatabase,
+    macro_node: SyntaxNode,
+    argument_index: usize,
+) -> Option<SignatureInformation> {
+    let syntax_db = db.upcast();
+    let macro_ast = ast::ExprInlineMacro::from_syntax_node(syntax_db, macro_node);
+    let name = macro_ast.path(syntax_db).as_syntax_node().get_text_without_trivia(syntax_db);
+    let signature = db.inline_macro_plugins().get(&name)?.signature()?;
+
+    let mut label = format!("{name}!(");
+    let mut parameters = vec![];
+    for (i, param) in signature.params.iter().enumerate() {
+        if i > 0 {
+            label.push_str(", ");
+        }
+        let is_variadic = signature.variadic && i + 1 == signature.params.len();
+        let param_text = if is_variadic { format!("{param}...") } else { param.to_string() };
+        parameters.push(parameter_information(&mut label, &param_text));
+    }
+    label.push(')');
+    // The arguments after the last parameter of a variadic macro all belong to it.
+    let active_parameter = if signature.variadic {
+        Some(argument_index.min(parameters.len().saturating_sub(1)))
+    } else {
+        (argument_index < parameters.len()).then_some(argument_index)
+    };
+    Some(SignatureInformation {
+        label,
+        documentation: None,
+        parameters: Some(parameters),
+        active_parameter: active_parameter.map(|index| index as u32),
+    })
+}
+
+/// Appends a parameter to a signature label, returning the parameter's information.
+fn parameter_information(label: &mut String, param_text: &str) -> ParameterInformation {
+    // Offsets in labels are counted in UTF-16 code units.
+    let start = label.encode_utf16().count() as u32;
+    label.push_str(param_text);
+    let end = label.encode_utf16().count() as u32;
+    ParameterInformation { label: ParameterLabel::LabelOffsets([start, end]), documentation: None }
+}
+
+/// Returns the text of a node, with all whitespace sequences replaced by a single space.
+fn single_line_text(db: &RootDatabase, node: SyntaxNode) -> String {
+    node.get_text_without_trivia(db.upcast()).split_whitespace().collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,148 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+use tower_lsp::lsp_types::ParameterLabel;
+
+use super::signature_help;
+use crate::test_utils::{extract_cursor, setup_test_file};
+
+/// Returns the label of the signature shown at the cursor of the code, with the active parameter
+/// enclosed in `<sel>` and `</sel>` markers.
+fn signature_at_cursor(code: &str) -> String {
+    let (code, position) = extract_cursor(code);
+    let (db, file) = setup_test_file(&code);
+    let help = signature_help(&db, file, position).expect("No signature at the cursor.");
+    let [signature] = &help.signatures[..] else {
+        panic!("Expected a single signature.");
+    };
+    let mut label = signature.label.clone();
+    if let Some(active_parameter) = help.active_parameter {
+        let parameters = signature.parameters.as_ref().unwrap();
+        let ParameterLabel::LabelOffsets([start, end]) =
+            parameters[active_parameter as usize].label
+        else {
+            panic!("Expected parameter label offsets.");
+        };
+        label.insert_str(end as usize, "</sel>");
+        label.insert_str(start as usize, "<sel>");
+    }
+    label
+}
+
+#[test_case(
+    indoc! {"
+        fn add(a: felt252, b: felt252) -> felt252 {
+            a + b
+        }
+        fn foo() -> felt252 {
+            add(1, <caret>2)
+        }
+    "},
+    "fn add(a: felt252, <sel>b: felt252</sel>) -> felt252";
+    "free function"
+)]
+#[test_case(
+    indoc! {"
+        #[derive(Copy, Drop)]
+        struct Point {
+            x: felt252,
+        }
+        trait Shift<T> {
+            fn shift(self: T, dx: felt252, dy: felt252) -> T;
+        }
+        impl PointShift of Shift<Point> {
+            fn shift(self: Point, dx: felt252, dy: felt252) -> Point {
+                self
+            }
+        }
+        fn foo(p: Point) -> Point {
+            p.shift(<caret>
+        }
+    "},
+    "fn shift(self: T, <sel>dx: felt252</sel>, dy: felt252) -> T";
+    "trait method of an incomplete call"
+)]
+#[test_case(
+    indoc! {"
+        #[derive(Copy, Drop)]
+        struct Point {
+            x: felt252,
+        }
+        trait Shift<T> {
+            fn shift(self: T, dx: felt252, dy: felt252) -> T;
+        }
+        impl PointShift of Shift<Point> {
+            fn shift(self: Point, dx: felt252, dy: felt252) -> Point {
+                self
+            }
+        }
+        fn foo(p: Point) -> Point {
+            p.shift(1, <caret>2)
+        }
+    "},
+    "fn shift(self: Point, dx: felt252, <sel>dy: felt252</sel>) -> Point";
+    "impl method of a resolved call"
+)]
+#[test_case(
+    indoc! {"
+        fn first<T, +Drop<T>>(a: T, b: T) -> T {
+            a
+        }
+        fn foo() -> u8 {
+            first(<caret>1_u8, 2)
+        }
+    "},
+    "fn first<T, +Drop<T>>(<sel>a: T</sel>, b: T) -> T";
+    "generic function"
+)]
+#[test_case(
+    indoc! {"
+        fn bump(ref x: felt252,
+            y: felt252) implicits(core::RangeCheck) nopanic {
+            x = x + y;
+        }
+        fn foo() {
+            let mut x = 1;
+            bump(ref <caret>x, 2);
+        }
+    "},
+    "fn bump(<sel>ref x: felt252</sel>, y: felt252) implicits(core::RangeCheck) nopanic";
+    "ref and implicits"
+)]
+#[test_case(
+    indoc! {"
+        fn foo() {
+            let x = array![1, 2, <caret>3];
+        }
+    "},
+    "array!(<sel>elements...</sel>)";
+    "variadic inline macro"
+)]
+fn test_signature_help(code: &str, expected: &str) {
+    assert_eq!(signature_at_cursor(code), expected);
+}
+
+#[test_case("add(<caret>1, 2, 3)", "fn add(<sel>a: u8</sel>, b: u8, c: u8) -> u8"; "first")]
+#[test_case("add(1,<caret> 2, 3)", "fn add(a: u8, <sel>b: u8</sel>, c: u8) -> u8"; "after comma")]
+#[test_case("add(1, 2<caret>, 3)", "fn add(a: u8, <sel>b: u8</sel>, c: u8) -> u8"; "before comma")]
+#[test_case("add(1, 2, <caret>)", "fn add(a: u8, b: u8, <sel>c: u8</sel>) -> u8"; "missing last")]
+#[test_case(
+    "add(c: 1, a: <caret>2, b: 3)",
+    "fn add(<sel>a: u8</sel>, b: u8, c: u8) -> u8";
+    "named"
+)]
+#[test_case("add(1, 2, 3, <caret>4)", "fn add(a: u8, b: u8, c: u8) -> u8"; "extra")]
+fn test_active_argument(call: &str, expected: &str) {
+    let code = format!(
+        indoc! {"
+            fn add(a: u8, b: u8, c: u8) -> u8 {{
+                a + b + c
+            }}
+            fn foo() -> u8 {{
+                {}
+            }}
+        "},
+        call
+    );
+    assert_eq!(signature_at_cursor(&code), expected);
+}
@@ -0,0 +1,152 @@
+//! Folding and selection ranges, computed from the syntax tree of a file alone.
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_filesystem::db::FilesGroup;
+use cairo_lang_filesystem::ids::FileId;
+use cairo_lang_filesystem::span::{TextOffset, TextSpan};
+use cairo_lang_parser::db::ParserGroup;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::SyntaxNode;
+use cairo_lang_utils::Upcast;
+use tower_lsp::lsp_types::{FoldingRange, FoldingRangeKind, Position, Range, SelectionRange};
+
+use crate::references::{lsp_range, SymbolLocation};
+use crate::{get_node, position_to_offset};
+
+#[cfg(test)]
+#[path = "structure_test.rs"]
+mod test;
+
+/// Returns the foldable regions of a file: the contents of brackets spanning several lines, runs of
+/// comments and runs of `use` items.
+pub fn folding_ranges(db: &RootDatabase, file: FileId) -> Option<Vec<FoldingRange>> {
+    let syntax_db = db.upcast();
+    let syntax = db.file_syntax(file).ok()?;
+    let content = db.file_content(file)?;
+    let summary = db.file_summary(file)?;
+    let line = |offset: TextOffset| offset.get_line_number(db, file);
+
+    let mut ranges = vec![];
+    let mut comment_lines = vec![];
+    for node in syntax.descendants(syntax_db) {
+        match node.kind(syntax_db) {
+            SyntaxKind::TokenSingleLineComment => {
+                // Only comments on lines of their own are folded, so that no code gets hidden.
+                let start = node.span(syntax_db).start;
+                let comment_line = line(start)?;
+                let line_start = summary.line_offsets[comment_line];
+                if (TextSpan { start: line_start, end: start }).take(&content).trim().is_empty() {
+                    comment_lines.push(comment_line);
+                }
+            }
+            SyntaxKind::ModuleItemList => {
+                let items = syntax_db.get_children(node);
+                for run in items
+                    .split(|item| item.kind(syntax_db) != SyntaxKind::ItemUse)
+                    .filter(|run| !run.is_empty())
+                {
+                    let start = line(run[0].span_without_trivia(syntax_db).start)?;
+                    let end = line(run[run.len() - 1].span_without_trivia(syntax_db).end)?;
+                    ranges.extend(folding_range(start, end, Some(FoldingRangeKind::Imports)));
+                }
+            }
+            _ => {
+                if let Some((open, close)) = brackets(syntax_db, node) {
+                    // The closing bracket stays visible.
+                    let start = line(open.span_without_trivia(syntax_db).start)?;
+                    let end = line(close.span_without_trivia(syntax_db).start)?.saturating_sub(1);
+                    ranges.extend(folding_range(start, end, None));
+                }
+            }
+        }
+    }
+
+    let mut comment_lines = comment_lines.into_iter().peekable();
+    while let Some(start) = comment_lines.next() {
+        let mut end = start;
+        while let Some(line) = comment_lines.next_if(|line| *line <= end + 1) {
+            end = line;
+        }
+        ranges.extend(folding_range(start, end, Some(FoldingRangeKind::Comment)));
+    }
+    Some(ranges)
+}
+
+/// Returns, for each position, the chain of the syntax nodes containing it, from the innermost
+/// outwards.
+pub fn selection_ranges(
+    db: &RootDatabase,
+    file: FileId,
+    positions: &[Position],
+) -> Option<Vec<SelectionRange>> {
+    positions.iter().map(|position| selection_range(db, file, *position)).collect()
+}
+
+fn selection_range(db: &RootDatabase, file: FileId, position: Position) -> Option<SelectionRange> {
+    let syntax_db = db.upcast();
+    let offset = position_to_offset(db.file_summary(file)?, position, &db.file_content(file)?)?;
+    let mut spans: Vec<TextSpan> = vec![];
+    let mut node = get_node(db, file, position);
+    while let Some(current) = node {
+        // Comments are selected, but not the whitespace around them, nor the nodes they precede or
+        // follow.
+        let span = current.span_without_trivia(syntax_db);
+        if span.start < span.end
+            && span.start <= offset
+            && offset <= span.end
+            && !matches!(
+                current.kind(syntax_db),
+                SyntaxKind::Trivia | SyntaxKind::TokenWhitespace | SyntaxKind::TokenNewline
+            )
+            && spans.last() != Some(&span)
+        {
+            spans.push(span);
+        }
+        node = current.parent();
+    }
+
+    let mut selection_range = None;
+    for span in spans.into_iter().rev() {
+        selection_range = Some(SelectionRange {
+            range: lsp_range(db, SymbolLocation { file, span })?,
+            parent: selection_range.map(Box::new),
+        });
+    }
+    // Positions outside of any node, such as in trailing whitespace, select nothing.
+    Some(selection_range.unwrap_or(SelectionRange {
+        range: Range { start: position, end: position },
+        parent: None,
+    }))
+}
+
+/// Returns the opening and closing bracket tokens among the children of a node.
+fn brackets(db: &dyn SyntaxGroup, node: SyntaxNode) -> Option<(SyntaxNode, SyntaxNode)> {
+    let children = db.get_children(node);
+    let open = children.iter().find(|child| {
+        matches!(
+            child.kind(db),
+            SyntaxKind::TerminalLBrace | SyntaxKind::TerminalLParen | SyntaxKind::TerminalLBrack
+        )
+    })?;
+    let close = children.iter().rev().find(|child| {
+        matches!(
+            child.kind(db),
+            SyntaxKind::TerminalRBrace | SyntaxKind::TerminalRParen | SyntaxKind::TerminalRBrack
+        )
+    })?;
+    Some((open.clone(), close.clone()))
+}
+
+fn folding_range(
+    start_line: usize,
+    end_line: usize,
+    kind: Option<FoldingRangeKind>,
+) -> Option<FoldingRange> {
+    (start_line < end_line).then(|| FoldingRange {
+        start_line: start_line as u32,
+        end_line: end_line as u32,
+        kind,
+        ..FoldingRange::default()
+    })
+}
@@ -0,0 +1,127 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+use tower_lsp::lsp_types::{FoldingRangeKind, SelectionRange};
+
+use super::{folding_ranges, selection_ranges};
+use crate::test_utils::{extract_cursor, setup_test_file};
+
+#[test]
+fn test_folding_ranges() {
+    let code = indoc! {"
+        // A comment
+        // spanning lines.
+        use core::array::ArrayTrait;
+        use core::array::SpanTrait;
+        use core::option::{
+            Option,
+            OptionTrait,
+        };
+        fn foo(
+            x: felt252,
+        ) -> felt252 { // Not folded, as it follows code.
+            let y = (
+                x,
+                1,
+            );
+            if x == 0 {
+                // A nested
+                // comment.
+                y.0
+            } else {
+                x
+            }
+        }
+        fn bar() {}
+        use core::box::BoxTrait;
+    "};
+    let (db, file) = setup_test_file(code);
+    let mut ranges = folding_ranges(&db, file).unwrap();
+    ranges.sort_by_key(|range| (range.start_line, range.end_line));
+    let ranges: Vec<_> = ranges
+        .into_iter()
+        .map(|range| {
+            let kind = match range.kind {
+                Some(FoldingRangeKind::Comment) => "comment",
+                Some(FoldingRangeKind::Imports) => "imports",
+                Some(FoldingRangeKind::Region) => "region",
+                None => "code",
+            };
+            format!("{}-{} {kind}", range.start_line, range.end_line)
+        })
+        .collect();
+    assert_eq!(
+        ranges,
+        [
+            "0-1 comment",
+            "2-7 imports",
+            "4-6 code",
+            "8-9 code",
+            "10-21 code",
+            "11-13 code",
+            "15-18 code",
+            "16-17 comment",
+            "19-20 code",
+        ]
+    );
+}
+
+#[test_case(
+    indoc! {"
+        fn foo(x: felt252) -> felt252 {
+            if x == 0 {
+                let y = x + <caret>1;
+                y
+            } else {
+                x
+            }
+        }
+    "},
+    &["1", "x + 1", "let y = x + 1;", "2:8-3:9", "1:14-4:5", "1:4-6:5", "0:30-7:1", "0:0-7:1"];
+    "nested blocks"
+)]
+#[test_case(
+    indoc! {"
+        use core::option::{
+            Option,
+            <caret>OptionTrait,
+        };
+    "},
+    &["OptionTrait", "1:4-2:16", "0:18-3:1", "0:10-3:1", "0:4-3:1", "0:0-3:2"];
+    "multi-line use list"
+)]
+#[test_case(
+    indoc! {"
+        fn foo() {
+            // A <caret>comment.
+            let x = 1;
+        }
+    "},
+    &["// A comment.", "0:9-3:1", "0:0-3:1"];
+    "comment"
+)]
+fn test_selection_ranges(code: &str, expected: &[&str]) {
+    let (code, position) = extract_cursor(code);
+    let (db, file) = setup_test_file(&code);
+    let [selection_range] = &selection_ranges(&db, file, &[position]).unwrap()[..] else {
+        panic!("Expected a single selection range.");
+    };
+    let lines: Vec<_> = code.lines().collect();
+    let mut selections = vec![];
+    let mut current = Some(selection_range);
+    while let Some(SelectionRange { range, parent }) = current {
+        // Multi-line selections are described by their positions.
+        selections.push(if range.start.line == range.end.line {
+            lines[range.start.line as usize]
+                [range.start.character as usize..range.end.character as usize]
+                .to_string()
+        } else {
+            format!(
+                "{}:{}-{}:{}",
+                range.start.line, range.start.character, range.end.line, range.end.character
+            )
+        });
+        current = parent.as_deref();
+    }
+    assert_eq!(selections, expected);
+}
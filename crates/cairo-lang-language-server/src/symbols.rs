@@ -0,0 +1,350 @@
+//! Document outline and workspace symbol search.
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_defs::db::DefsGroup;
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId, ModuleItemId, TopLevelLanguageElementId};
+use cairo_lang_filesystem::ids::{CrateId, FileId};
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_starknet::plugin::consts::{
+    COMPONENT_ATTR, CONTRACT_ATTR, EVENT_ATTR, STORAGE_ATTR,
+};
+use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use cairo_lang_utils::Upcast;
+use tower_lsp::lsp_types::{DocumentSymbol, Location, SymbolInformation, SymbolKind};
+
+use crate::references::{is_generated_file, lsp_location, lsp_range, SymbolLocation};
+
+#[cfg(test)]
+#[path = "symbols_test.rs"]
+mod test;
+
+/// An item defined in a module, or in another item.
+struct Symbol {
+    name: String,
+    kind: SymbolKind,
+    detail: Option<String>,
+    /// The whole definition.
+    ptr: SyntaxStablePtrId,
+    /// The name of the definition.
+    name_ptr: SyntaxStablePtrId,
+    /// An inline submodule, whose items are only collected on demand.
+    submodule: Option<ModuleId>,
+    children: Vec<Symbol>,
+}
+
+/// Returns the outline of the items defined in a file.
+pub fn document_symbols(db: &RootDatabase, file: FileId) -> Option<Vec<DocumentSymbol>> {
+    let module_id = *db.file_modules(file).ok()?.first()?;
+    Some(
+        module_symbols(db, module_id)
+            .into_iter()
+            .filter_map(|symbol| document_symbol(db, file, symbol))
+            .collect(),
+    )
+}
+
+/// Returns the items of a crate, and the members of its items, as found by the workspace symbol
+/// search.
+pub fn crate_symbols(db: &RootDatabase, crate_id: CrateId) -> Vec<SymbolInformation> {
+    let mut symbols = vec![];
+    let mut add_symbol = |symbol: &Symbol, container_name: String| {
+        if let Some(location) = symbol_location(db, symbol.name_ptr) {
+            #[allow(deprecated)]
+            symbols.push(SymbolInformation {
+                name: symbol.name.clone(),
+                kind: symbol.kind,
+                tags: None,
+                deprecated: None,
+                location,
+                container_name: Some(container_name),
+            });
+        }
+    };
+    // Submodules are visited on their own, so their items are not collected recursively.
+    for module_id in db.crate_modules(crate_id).iter() {
+        let module_path = module_id.full_path(db);
+        for symbol in module_symbols(db, *module_id) {
+            add_symbol(&symbol, module_path.clone());
+            for child in &symbol.children {
+                add_symbol(child, format!("{module_path}::{}", symbol.name));
+            }
+        }
+    }
+    symbols
+}
+
+/// Returns the symbols whose name matches `query`, best matches first.
+pub fn matching_symbols(
+    symbols: impl IntoIterator<Item = SymbolInformation>,
+    query: &str,
+) -> Vec<SymbolInformation> {
+    let query = query.to_lowercase();
+    let mut matches: Vec<_> = symbols
+        .into_iter()
+        .filter_map(|symbol| Some((fuzzy_score(&query, &symbol.name)?, symbol)))
+        .collect();
+    matches.sort_by(|(score, lhs), (other_score, rhs)| {
+        (score, lhs.name.len(), &lhs.name).cmp(&(other_score, rhs.name.len(), &rhs.name))
+    });
+    matches.into_iter().map(|(_, symbol_information)| symbol_information).collect()
+}
+
+/// Returns how well `name` matches `query`, lower being better, or None if it does not match.
+///
+/// A name matches if it contains all the characters of the query in order, ignoring case.
+fn fuzzy_score(query: &str, name: &str) -> Option<usize> {
+    let name = name.to_lowercase();
+    if name.starts_with(query) {
+        return Some(0);
+    }
+    if name.contains(query) {
+        return Some(1);
+    }
+    let mut name_chars = name.chars();
+    query.chars().all(|c| name_chars.any(|name_char| name_char == c)).then_some(2)
+}
+
+/// Converts a symbol to an LSP document symbol, if it is defined in the given file.
+fn document_symbol(db: &RootDatabase, file: FileId, symbol: Symbol) -> Option<DocumentSymbol> {
+    if symbol.ptr.file_id(db) != file {
+        return None;
+    }
+    let selection_range = lsp_range(db, syntax_location(db, symbol.name_ptr))?;
+    let range = lsp_range(db, syntax_location(db, symbol.ptr))?;
+    let mut children = symbol.children;
+    if let Some(module_id) = symbol.submodule {
+        children.extend(module_symbols(db, module_id));
+    }
+    let mut children: Vec<_> =
+        children.into_iter().filter_map(|child| document_symbol(db, file, child)).collect();
+    children.sort_by_key(|child| (child.range.start.line, child.range.start.character));
+    #[allow(deprecated)]
+    Some(DocumentSymbol {
+        name: symbol.name,
+        detail: symbol.detail,
+        kind: symbol.kind,
+        tags: None,
+        deprecated: None,
+        range,
+        selection_range,
+        children: Some(children),
+    })
+}
+
+/// Returns the symbols of the items of a module.
+fn module_symbols(db: &RootDatabase, module_id: ModuleId) -> Vec<Symbol> {
+    let Ok(items) = db.module_items(module_id) else {
+        return vec![];
+    };
+    items.iter().filter_map(|item| module_item_symbol(db, module_id, *item)).collect()
+}
+
+/// Returns the symbol of a module item, along with the symbols of its members.
+fn module_item_symbol(
+    db: &RootDatabase,
+    module_id: ModuleId,
+    item: ModuleItemId,
+) -> Option<Symbol> {
+    let syntax_db = db.upcast();
+    let mut detail = None;
+    let mut submodule = None;
+    let mut children = vec![];
+    let kind = match item {
+        ModuleItemId::Use(_) => return None,
+        ModuleItemId::Constant(_) => SymbolKind::CONSTANT,
+        ModuleItemId::Submodule(id) => {
+            let module_ast = id.stable_ptr(db).lookup(syntax_db);
+            if module_ast.has_attr(syntax_db, CONTRACT_ATTR) {
+                detail = Some("contract".into());
+                children.extend(storage_symbol(db, &module_ast));
+            } else if module_ast.has_attr(syntax_db, COMPONENT_ATTR) {
+                detail = Some("component".into());
+                children.extend(storage_symbol(db, &module_ast));
+            }
+            if let ast::MaybeModuleBody::Some(_) = module_ast.body(syntax_db) {
+                submodule = Some(ModuleId::Submodule(id));
+            }
+            SymbolKind::MODULE
+        }
+        ModuleItemId::FreeFunction(_) | ModuleItemId::ExternFunction(_) => SymbolKind::FUNCTION,
+        ModuleItemId::Struct(id) => {
+            for member in db.struct_members(id).unwrap_or_default().values() {
+                let member_ast = member.id.stable_ptr(db).lookup(syntax_db);
+                let name_ptr = match &member_ast {
+                    ast::StructMember::Named(member) => {
+                        member.name(syntax_db).stable_ptr().untyped()
+                    }
+                    ast::StructMember::Tuple(member) => member.stable_ptr().untyped(),
+                };
+                children.push(Symbol::leaf(
+                    member.id.name(db).into(),
+                    SymbolKind::FIELD,
+                    member_ast.stable_ptr().untyped(),
+                    name_ptr,
+                ));
+            }
+            SymbolKind::STRUCT
+        }
+        ModuleItemId::Enum(id) => {
+            if id.stable_ptr(db).lookup(syntax_db).has_attr(syntax_db, EVENT_ATTR) {
+                detail = Some("event".into());
+            }
+            for (name, variant) in db.enum_variants(id).unwrap_or_default() {
+                let variant_ast = variant.stable_ptr(db).lookup(syntax_db);
+                children.push(Symbol::leaf(
+                    name.into(),
+                    SymbolKind::ENUM_MEMBER,
+                    variant_ast.stable_ptr().untyped(),
+                    variant_ast.name(syntax_db).stable_ptr().untyped(),
+                ));
+            }
+            SymbolKind::ENUM
+        }
+        ModuleItemId::Trait(id) => {
+            for (name, trait_type) in db.trait_types(id).unwrap_or_default() {
+                let trait_type_ast = trait_type.stable_ptr(db).lookup(syntax_db);
+                children.push(Symbol::leaf(
+                    name.into(),
+                    SymbolKind::TYPE_PARAMETER,
+                    trait_type_ast.stable_ptr().untyped(),
+                    trait_type_ast.name(syntax_db).stable_ptr().untyped(),
+                ));
+            }
+            for (name, trait_constant) in db.trait_constants(id).unwrap_or_default() {
+                let trait_constant_ast = trait_constant.stable_ptr(db).lookup(syntax_db);
+                children.push(Symbol::leaf(
+                    name.into(),
+                    SymbolKind::CONSTANT,
+                    trait_constant_ast.stable_ptr().untyped(),
+                    trait_constant_ast.name(syntax_db).stable_ptr().untyped(),
+                ));
+            }
+            for (name, trait_function) in db.trait_functions(id).unwrap_or_default() {
+                let trait_function_ast = trait_function.stable_ptr(db).lookup(syntax_db);
+                children.push(Symbol::leaf(
+                    name.into(),
+                    SymbolKind::METHOD,
+                    trait_function_ast.stable_ptr().untyped(),
+                    trait_function_ast
+                        .declaration(syntax_db)
+                        .name(syntax_db)
+                        .stable_ptr()
+                        .untyped(),
+                ));
+            }
+            SymbolKind::INTERFACE
+        }
+        ModuleItemId::Impl(id) => {
+            for (impl_type, impl_type_ast) in db.impl_types(id).unwrap_or_default().iter() {
+                children.push(Symbol::leaf(
+                    impl_type.name(db).into(),
+                    SymbolKind::TYPE_PARAMETER,
+                    impl_type_ast.stable_ptr().untyped(),
+                    impl_type_ast.name(syntax_db).stable_ptr().untyped(),
+                ));
+            }
+            for (impl_constant, impl_constant_ast) in
+                db.impl_constants(id).unwrap_or_default().iter()
+            {
+                children.push(Symbol::leaf(
+                    impl_constant.name(db).into(),
+                    SymbolKind::CONSTANT,
+                    impl_constant_ast.stable_ptr().untyped(),
+                    impl_constant_ast.name(syntax_db).stable_ptr().untyped(),
+                ));
+            }
+            for (name, impl_function) in db.impl_functions(id).unwrap_or_default() {
+                let impl_function_ast = impl_function.stable_ptr(db).lookup(syntax_db);
+                children.push(Symbol::leaf(
+                    name.into(),
+                    SymbolKind::METHOD,
+                    impl_function_ast.stable_ptr().untyped(),
+                    impl_function_ast.declaration(syntax_db).name(syntax_db).stable_ptr().untyped(),
+                ));
+            }
+            SymbolKind::OBJECT
+        }
+        ModuleItemId::ImplAlias(_) => SymbolKind::OBJECT,
+        ModuleItemId::TypeAlias(_) => SymbolKind::TYPE_PARAMETER,
+        ModuleItemId::ExternType(_) => SymbolKind::STRUCT,
+    };
+    Some(Symbol {
+        name: item.name(db).into(),
+        kind,
+        detail,
+        ptr: item.untyped_stable_ptr(db),
+        name_ptr: db.module_item_name_stable_ptr(module_id, item).ok()?,
+        submodule,
+        children,
+    })
+}
+
+/// Returns the symbol of the storage struct of a Starknet contract or component.
+///
+/// The storage struct is replaced by plugin generated code, so it is taken from the syntax of the
+/// module.
+fn storage_symbol(db: &RootDatabase, module_ast: &ast::ItemModule) -> Option<Symbol> {
+    let syntax_db = db.upcast();
+    let ast::MaybeModuleBody::Some(body) = module_ast.body(syntax_db) else {
+        return None;
+    };
+    let storage_ast =
+        body.items(syntax_db).elements(syntax_db).into_iter().find_map(|item| match item {
+            ast::ModuleItem::Struct(item) if item.has_attr(syntax_db, STORAGE_ATTR) => Some(item),
+            _ => None,
+        })?;
+    let children = storage_ast
+        .members(syntax_db)
+        .into_iter()
+        .filter_map(|member| match member {
+            ast::StructMember::Named(member) => Some(Symbol::leaf(
+                member.name(syntax_db).text(syntax_db).into(),
+                SymbolKind::FIELD,
+                member.stable_ptr().untyped(),
+                member.name(syntax_db).stable_ptr().untyped(),
+            )),
+            ast::StructMember::Tuple(_) => None,
+        })
+        .collect();
+    Some(Symbol {
+        name: storage_ast.name(syntax_db).text(syntax_db).into(),
+        kind: SymbolKind::STRUCT,
+        detail: Some("storage".into()),
+        ptr: storage_ast.stable_ptr().untyped(),
+        name_ptr: storage_ast.name(syntax_db).stable_ptr().untyped(),
+        submodule: None,
+        children,
+    })
+}
+
+impl Symbol {
+    /// Creates a symbol without children.
+    fn leaf(
+        name: String,
+        kind: SymbolKind,
+        ptr: SyntaxStablePtrId,
+        name_ptr: SyntaxStablePtrId,
+    ) -> Self {
+        Self { name, kind, detail: None, ptr, name_ptr, submodule: None, children: vec![] }
+    }
+}
+
+/// Returns the location of a syntax node in its own file.
+fn syntax_location(db: &RootDatabase, ptr: SyntaxStablePtrId) -> SymbolLocation {
+    let syntax_db = db.upcast();
+    SymbolLocation {
+        file: ptr.file_id(syntax_db),
+        span: ptr.lookup(syntax_db).span_without_trivia(syntax_db),
+    }
+}
+
+/// Returns the LSP location of a syntax node, if it lies in user code.
+fn symbol_location(db: &RootDatabase, ptr: SyntaxStablePtrId) -> Option<Location> {
+    let location = syntax_location(db, ptr);
+    if is_generated_file(db, location.file) {
+        return None;
+    }
+    lsp_location(db, location)
+}
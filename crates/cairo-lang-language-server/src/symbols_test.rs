@@ -0,0 +1,155 @@
+use cairo_lang_defs::db::DefsGroup;
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+use tower_lsp::lsp_types::DocumentSymbol;
+
+use super::{crate_symbols, document_symbols, matching_symbols};
+use crate::test_utils::setup_test_file;
+
+/// Appends the outline of the symbols to `outline`, a line per symbol, indented by its nesting.
+fn format_outline(outline: &mut String, symbols: &[DocumentSymbol], indent: usize) {
+    for symbol in symbols {
+        let detail =
+            symbol.detail.as_ref().map(|detail| format!(" ({detail})")).unwrap_or_default();
+        let range = symbol.range;
+        outline.push_str(&format!(
+            "{:indent$}{:?} {}{detail}: {}:{}-{}:{}\n",
+            "",
+            symbol.kind,
+            symbol.name,
+            range.start.line,
+            range.start.character,
+            range.end.line,
+            range.end.character,
+        ));
+        format_outline(outline, symbol.children.as_deref().unwrap_or_default(), indent + 4);
+    }
+}
+
+#[test]
+fn test_document_symbols() {
+    let code = indoc! {"
+        use core::array::ArrayTrait;
+        mod inner {
+            const C: felt252 = 1;
+            fn f() {}
+        }
+        #[derive(Drop)]
+        struct S {
+            a: felt252,
+            b: u8,
+        }
+        struct T(felt252, u8);
+        enum E {
+            A,
+            B: felt252,
+        }
+        trait Tr<X> {
+            fn m(self: @X);
+        }
+        impl TrImpl of Tr<S> {
+            fn m(self: @S) {}
+        }
+        #[starknet::contract]
+        mod contract {
+            #[storage]
+            struct Storage {
+                value: felt252,
+            }
+            #[event]
+            #[derive(Drop, starknet::Event)]
+            enum Event {
+                Changed: Changed,
+            }
+            #[derive(Drop, starknet::Event)]
+            struct Changed {
+                value: felt252,
+            }
+            #[external(v0)]
+            fn get(self: @ContractState) -> felt252 {
+                self.value.read()
+            }
+        }
+    "};
+    let (db, file) = setup_test_file(code);
+    let mut outline = String::new();
+    format_outline(&mut outline, &document_symbols(&db, file).unwrap(), 0);
+    assert_eq!(
+        outline,
+        indoc! {"
+            Module inner: 1:0-4:1
+                Constant C: 2:4-2:25
+                Function f: 3:4-3:13
+            Struct S: 5:0-9:1
+                Field a: 7:4-7:14
+                Field b: 8:4-8:9
+            Struct T: 10:0-10:22
+                Field 0: 10:9-10:16
+                Field 1: 10:18-10:20
+            Enum E: 11:0-14:1
+                EnumMember A: 12:4-12:5
+                EnumMember B: 13:4-13:14
+            Interface Tr: 15:0-17:1
+                Method m: 16:4-16:19
+            Object TrImpl: 18:0-20:1
+                Method m: 19:4-19:21
+            Module contract (contract): 21:0-40:1
+                Struct Storage (storage): 23:4-26:5
+                    Field value: 25:8-25:22
+                Enum Event (event): 27:4-31:5
+                    EnumMember Changed: 30:8-30:24
+                Struct Changed: 32:4-35:5
+                    Field value: 34:8-34:22
+                Function get: 36:4-39:5
+        "}
+    );
+}
+
+#[test_case(
+    "tot",
+    &["total_supply", "set_total", "TokenOwnerTable"];
+    "prefix then substring then subsequence"
+)]
+#[test_case("TOKEN", &["TokenOwnerTable"]; "case insensitive")]
+#[test_case("owner", &["owner", "TokenOwnerTable"]; "shorter names first")]
+#[test_case("xyz", &[]; "no match")]
+fn test_workspace_symbols(query: &str, expected: &[&str]) {
+    let code = indoc! {"
+        struct TokenOwnerTable {
+            owner: felt252,
+        }
+        fn total_supply() -> u256 {
+            0
+        }
+        mod admin {
+            fn set_total(total: u256) {}
+        }
+    "};
+    let (db, file) = setup_test_file(code);
+    let crate_id = db.file_modules(file).unwrap()[0].owning_crate(&db);
+    let symbols = matching_symbols(crate_symbols(&db, crate_id), query);
+    assert_eq!(symbols.iter().map(|symbol| symbol.name.as_str()).collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn test_workspace_symbol_containers() {
+    let code = indoc! {"
+        struct TokenOwnerTable {
+            owner: felt252,
+        }
+        mod admin {
+            fn set_owner(owner: felt252) {}
+        }
+    "};
+    let (db, file) = setup_test_file(code);
+    let crate_id = db.file_modules(file).unwrap()[0].owning_crate(&db);
+    let symbols = matching_symbols(crate_symbols(&db, crate_id), "owner");
+    assert_eq!(
+        symbols
+            .iter()
+            .map(|symbol| format!("{}::{}", symbol.container_name.as_ref().unwrap(), symbol.name))
+            .collect::<Vec<_>>(),
+        ["test::TokenOwnerTable::owner", "test::admin::set_owner", "test::TokenOwnerTable"]
+    );
+}
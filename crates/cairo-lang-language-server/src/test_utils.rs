@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use cairo_lang_compiler::db::RootDatabase;
+use cairo_lang_filesystem::db::{
+    init_dev_corelib, CrateConfiguration, CrateSettings, ExperimentalFeaturesConfig, FilesGroup,
+    FilesGroupEx,
+};
+use cairo_lang_filesystem::detect::detect_corelib;
+use cairo_lang_filesystem::ids::{
+    CrateLongId, Directory, FileId, FileKind, FileLongId, VirtualFile,
+};
+use tower_lsp::lsp_types::{Position, Range, TextDocumentContentChangeEvent, TextEdit};
+
+use crate::config::Config;
+use crate::configured_db;
+use crate::vfs::apply_content_change;
+
+/// The marker of the cursor position in test code.
+const CURSOR: &str = "<caret>";
+
+/// Sets up a database configured like the one of the server, with the corelib and a crate with a
+/// single file of the given content. Returns the database and the file.
+pub fn setup_test_file(content: &str) -> (RootDatabase, FileId) {
+    let (db, files) = setup_test_files(&[("lib.cairo", content)]);
+    (db, files[0])
+}
+
+/// Sets up a database configured like the one of the server, with the corelib and a crate with
+/// files of the given names and contents, in its root directory. The crate root is the `lib.cairo`
+/// file. Returns the database and the files, in the given order.
+pub fn setup_test_files(files: &[(&str, &str)]) -> (RootDatabase, Vec<FileId>) {
+    let mut db = configured_db(&Config::default());
+    init_dev_corelib(&mut db, detect_corelib().expect("The corelib was not found."));
+    let file_ids: Vec<_> = files
+        .iter()
+        .map(|(name, content)| {
+            db.intern_file(FileLongId::Virtual(VirtualFile {
+                parent: None,
+                name: (*name).into(),
+                content: Arc::new((*content).into()),
+                code_mappings: Default::default(),
+                kind: FileKind::Module,
+            }))
+        })
+        .collect();
+    let config = CrateConfiguration {
+        root: Directory::Virtual {
+            files: files.iter().map(|(name, _)| (*name).into()).zip(file_ids.clone()).collect(),
+            dirs: Default::default(),
+        },
+        settings: CrateSettings {
+            experimental_features: ExperimentalFeaturesConfig { negative_impls: true },
+            ..CrateSettings::default()
+        },
+    };
+    let crate_id =
+        db.intern_crate(CrateLongId::Virtual { name: "test".into(), config: config.clone() });
+    // The language server searches the crates of the workspace, which are the configured ones.
+    db.set_crate_config(crate_id, Some(config));
+    (db, file_ids)
+}
+
+/// Removes the cursor marker from the given content. Returns the content and the position of the
+/// marker.
+pub fn extract_cursor(content: &str) -> (String, Position) {
+    let offset = content.find(CURSOR).expect("The content has no cursor marker.");
+    let before = &content[..offset];
+    let line = before.matches('\n').count() as u32;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let character = before[line_start..].encode_utf16().count() as u32;
+    (content.replacen(CURSOR, "", 1), Position { line, character })
+}
+
+/// Returns the given content with the (non-overlapping) edits applied.
+pub fn apply_text_edits(content: &str, mut edits: Vec<TextEdit>) -> String {
+    // Applying the edits from the end keeps the positions of the remaining ones valid.
+    edits.sort_by_key(|edit| (edit.range.start, edit.range.end));
+    let mut content = content.to_string();
+    for edit in edits.into_iter().rev() {
+        apply_content_change(
+            &mut content,
+            &TextDocumentContentChangeEvent {
+                range: Some(edit.range),
+                range_length: None,
+                text: edit.new_text,
+            },
+        );
+    }
+    content
+}
+
+/// Returns the given content with each of the (non-overlapping) ranges enclosed in `<sel>` and
+/// `</sel>` markers.
+pub fn mark_ranges(content: &str, ranges: impl IntoIterator<Item = Range>) -> String {
+    let edits = ranges
+        .into_iter()
+        .flat_map(|Range { start, end }| {
+            [
+                TextEdit { range: Range { start: end, end }, new_text: "</sel>".into() },
+                TextEdit { range: Range { start, end: start }, new_text: "<sel>".into() },
+            ]
+        })
+        .collect();
+    apply_text_edits(content, edits)
+}
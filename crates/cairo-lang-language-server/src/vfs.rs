@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 use tower_lsp::lsp_types::notification::Notification;
-use tower_lsp::lsp_types::Url;
+use tower_lsp::lsp_types::{Position, TextDocumentContentChangeEvent, Url};
+
+#[cfg(test)]
+#[path = "vfs_test.rs"]
+mod test;
 
 #[derive(Debug)]
 pub enum UpdateVirtualFile {}
@@ -24,3 +28,47 @@ pub struct ProvideVirtualFileRequest {
 pub struct ProvideVirtualFileResponse {
     pub content: Option<String>,
 }
+
+#[derive(Debug, Eq, PartialEq, Clone, Deserialize, Serialize)]
+pub struct ExpandMacroResponse {
+    /// The code generated for the macro at the requested position, if any.
+    pub content: Option<String>,
+}
+
+/// Applies a document change sent by the client to the current content of the document.
+///
+/// Changes without a range replace the whole document. Positions are in UTF-16 code units, as
+/// defined by the LSP, and are clamped to the document bounds.
+pub fn apply_content_change(content: &mut String, change: &TextDocumentContentChangeEvent) {
+    let Some(range) = change.range else {
+        *content = change.text.clone();
+        return;
+    };
+    let start = position_to_byte_offset(content, range.start);
+    let end = position_to_byte_offset(content, range.end).max(start);
+    content.replace_range(start..end, &change.text);
+}
+
+/// Converts an LSP position to a byte offset in the given content.
+fn position_to_byte_offset(content: &str, position: Position) -> usize {
+    let mut line_start = 0;
+    for _ in 0..position.line {
+        match content[line_start..].find('\n') {
+            Some(line_end) => line_start += line_end + 1,
+            None => return content.len(),
+        }
+    }
+    let line = content[line_start..].split('\n').next().unwrap_or_default();
+    // Positions past the end of the line are placed before its line break, including a `\r\n` one.
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    let mut offset = line_start;
+    let mut character = 0;
+    for c in line.chars() {
+        if character >= position.character {
+            break;
+        }
+        character += c.len_utf16() as u32;
+        offset += c.len_utf8();
+    }
+    offset
+}
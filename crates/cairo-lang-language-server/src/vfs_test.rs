@@ -0,0 +1,97 @@
+use pretty_assertions::assert_eq;
+use test_case::test_case;
+use tower_lsp::lsp_types::{Position, Range, TextDocumentContentChangeEvent};
+
+use super::{apply_content_change, position_to_byte_offset};
+
+/// Returns a change replacing the range between the given (line, character) positions.
+fn change(start: (u32, u32), end: (u32, u32), text: &str) -> TextDocumentContentChangeEvent {
+    TextDocumentContentChangeEvent {
+        range: Some(Range {
+            start: Position { line: start.0, character: start.1 },
+            end: Position { line: end.0, character: end.1 },
+        }),
+        range_length: None,
+        text: text.into(),
+    }
+}
+
+#[test_case("abc\ndef", (0, 0), 0; "start")]
+#[test_case("abc\ndef", (0, 2), 2; "middle of first line")]
+#[test_case("abc\ndef", (1, 1), 5; "middle of second line")]
+#[test_case("abc\ndef", (0, 10), 3; "past end of line")]
+#[test_case("abc\ndef", (1, 10), 7; "past end of last line")]
+#[test_case("abc\ndef", (5, 0), 7; "past end of document")]
+#[test_case("abc\r\ndef", (0, 10), 3; "past end of crlf line")]
+#[test_case("abc\r\ndef", (1, 1), 6; "after crlf line")]
+#[test_case("aé😀b\nc", (0, 2), 3; "after two byte char")]
+#[test_case("aé😀b\nc", (0, 4), 7; "after surrogate pair")]
+#[test_case("aé😀b\nc", (0, 5), 8; "after non ascii chars")]
+#[test_case("aé😀b\nc", (1, 1), 10; "after non ascii line")]
+fn test_position_to_byte_offset(content: &str, (line, character): (u32, u32), expected: usize) {
+    assert_eq!(position_to_byte_offset(content, Position { line, character }), expected);
+}
+
+#[test]
+fn test_full_replace() {
+    let mut content = "fn foo() {}\n".to_string();
+    let change = TextDocumentContentChangeEvent {
+        range: None,
+        range_length: None,
+        text: "fn bar() {}".into(),
+    };
+    apply_content_change(&mut content, &change);
+    assert_eq!(content, "fn bar() {}");
+}
+
+#[test_case("fn foo() {}", change((0, 3), (0, 6), "bar"), "fn bar() {}"; "replace")]
+#[test_case("fn foo() {}", change((0, 10), (0, 10), " 1 "), "fn foo() { 1 }"; "insert")]
+#[test_case("fn foo() {}", change((0, 2), (0, 6), ""), "fn() {}"; "delete")]
+#[test_case(
+    "fn foo() {\n    let x = 1;\n    x\n}\n",
+    change((0, 9), (3, 1), "-> felt252 {\n    1\n}"),
+    "fn foo() -> felt252 {\n    1\n}\n";
+    "multiline replace"
+)]
+#[test_case(
+    "fn foo() {}\n",
+    change((0, 10), (0, 10), "\n    let x = 1;\n"),
+    "fn foo() {\n    let x = 1;\n}\n";
+    "multiline insert"
+)]
+#[test_case(
+    "let s = 'é😀';\nlet t = s;\n",
+    change((0, 9), (0, 12), "ab"),
+    "let s = 'ab';\nlet t = s;\n";
+    "non ascii replace"
+)]
+#[test_case(
+    "let s = '😀';\nlet t = s;\n",
+    change((1, 4), (1, 5), "u"),
+    "let s = '😀';\nlet u = s;\n";
+    "after non ascii line"
+)]
+#[test_case(
+    "fn foo() {\r\n    1\r\n}\r\n",
+    change((1, 4), (1, 5), "2"),
+    "fn foo() {\r\n    2\r\n}\r\n";
+    "crlf"
+)]
+#[test_case(
+    "fn foo() {\r\n    1\r\n}\r\n",
+    change((0, 20), (1, 20), " 2"),
+    "fn foo() { 2\r\n}\r\n";
+    "crlf past end of lines"
+)]
+#[test_case("fn foo() {}", change((0, 3), (5, 0), "bar"), "fn bar"; "end past end of document")]
+#[test_case("fn foo() {}", change((3, 0), (5, 0), "\n"), "fn foo() {}\n"; "past end of document")]
+#[test_case("fn foo() {}", change((0, 6), (0, 3), "bar"), "fn foobar() {}"; "reversed range")]
+fn test_apply_content_change(
+    content: &str,
+    change: TextDocumentContentChangeEvent,
+    expected: &str,
+) {
+    let mut content = content.to_string();
+    apply_content_change(&mut content, &change);
+    assert_eq!(content, expected);
+}
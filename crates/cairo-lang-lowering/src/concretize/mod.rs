@@ -1,11 +1,19 @@
 use cairo_lang_diagnostics::Maybe;
+use cairo_lang_semantic as semantic;
+use cairo_lang_semantic::corelib::{core_module, get_core_ty_by_name};
+use cairo_lang_semantic::items::imp::ImplLookupContext;
 use cairo_lang_semantic::substitution::{
     GenericSubstitution, SemanticRewriter, SubstitutionRewriter,
 };
+use id_arena::Arena;
+use num_bigint::BigInt;
 
 use crate::db::LoweringGroup;
-use crate::ids::{FunctionId, FunctionLongId, GeneratedFunction};
-use crate::{FlatBlockEnd, FlatLowered, MatchArm, Statement};
+use crate::ids::{FunctionId, FunctionLongId, GeneratedFunction, SemanticFunctionIdEx};
+use crate::{
+    FlatBlockEnd, FlatLowered, MatchArm, Statement, StatementImplConstant, StatementLiteral,
+    StatementStructConstruct, VarUsage, Variable,
+};
 
 /// Rewrites a [FunctionId] with a [SubstitutionRewriter].
 fn concretize_function(
@@ -14,17 +22,49 @@ fn concretize_function(
     function: FunctionId,
 ) -> Maybe<FunctionId> {
     let long_id = match db.lookup_intern_lowering_function(function) {
-        FunctionLongId::Semantic(id) => FunctionLongId::Semantic(rewriter.rewrite(id)?),
-        FunctionLongId::Generated(GeneratedFunction { parent, element }) => {
-            FunctionLongId::Generated(GeneratedFunction {
-                parent: rewriter.rewrite(parent)?,
-                element,
-            })
+        // Calls to closures are only resolved to their generated functions once concrete.
+        FunctionLongId::Semantic(id) => return Ok(rewriter.rewrite(id)?.lowered(db)),
+        FunctionLongId::Generated(GeneratedFunction { parent, key }) => {
+            FunctionLongId::Generated(GeneratedFunction { parent: rewriter.rewrite(parent)?, key })
         }
     };
     Ok(db.intern_lowering_function(long_id))
 }
 
+/// Replaces a constant of an impl that became concrete with the statements binding its value.
+fn concretize_impl_constant(
+    db: &dyn LoweringGroup,
+    variables: &mut Arena<Variable>,
+    stmt: StatementImplConstant,
+) -> Maybe<Vec<Statement>> {
+    let semantic_db = db.upcast();
+    let Some(constant) = stmt.impl_constant_id.value(semantic_db)? else {
+        return Ok(vec![Statement::ImplConstant(stmt)]);
+    };
+    let semantic::Expr::Literal(literal) = constant.value else {
+        panic!("Only literal constants are supported.");
+    };
+    let output = stmt.output;
+    if variables[output].ty != get_core_ty_by_name(semantic_db, "u256".into(), vec![]) {
+        return Ok(vec![Statement::Literal(StatementLiteral { value: literal.value, output })]);
+    }
+
+    // A u256 is constructed from its low and high u128 parts.
+    let u128_ty = get_core_ty_by_name(semantic_db, "u128".into(), vec![]);
+    let lookup_context = ImplLookupContext::new(core_module(semantic_db), vec![]);
+    let location = variables[output].location;
+    let mask128 = BigInt::from(u128::MAX);
+    let mut statements = vec![];
+    let mut inputs = vec![];
+    for value in [&literal.value & mask128, &literal.value >> 128] {
+        let var_id = variables.alloc(Variable::new(db, lookup_context.clone(), u128_ty, location));
+        statements.push(Statement::Literal(StatementLiteral { value, output: var_id }));
+        inputs.push(VarUsage { var_id, location });
+    }
+    statements.push(Statement::StructConstruct(StatementStructConstruct { inputs, output }));
+    Ok(statements)
+}
+
 /// Concretizes a lowered generic function by applying a generic parameter substitution on its
 /// variable types, variants and called functions.
 pub fn concretize_lowered(
@@ -42,21 +82,32 @@ pub fn concretize_lowered(
     }
     // Substitute all statements.
     for block in lowered.blocks.iter_mut() {
-        for stmt in block.statements.iter_mut() {
-            match stmt {
+        let mut statements = Vec::with_capacity(block.statements.len());
+        for mut stmt in std::mem::take(&mut block.statements) {
+            match &mut stmt {
                 Statement::Call(stmt) => {
                     stmt.function = concretize_function(db, &mut rewriter, stmt.function)?;
                 }
                 Statement::EnumConstruct(stmt) => {
                     stmt.variant = rewriter.rewrite(stmt.variant.clone())?;
                 }
+                Statement::ImplConstant(stmt) => {
+                    let stmt = StatementImplConstant {
+                        impl_constant_id: rewriter.rewrite(stmt.impl_constant_id)?,
+                        output: stmt.output,
+                    };
+                    statements.extend(concretize_impl_constant(db, &mut lowered.variables, stmt)?);
+                    continue;
+                }
                 Statement::Snapshot(_)
                 | Statement::Desnap(_)
                 | Statement::Literal(_)
                 | Statement::StructConstruct(_)
                 | Statement::StructDestructure(_) => {}
             }
+            statements.push(stmt);
         }
+        block.statements = statements;
         if let FlatBlockEnd::Match { info } = &mut block.end {
             for MatchArm { arm_selector: selector, .. } in match info {
                 crate::MatchInfo::Enum(s) => s.arms.iter_mut(),
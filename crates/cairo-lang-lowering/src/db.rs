@@ -5,7 +5,8 @@ use cairo_lang_defs::ids::{LanguageElementId, ModuleId, ModuleItemId};
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_semantic::db::SemanticGroup;
-use cairo_lang_semantic::TypeId;
+use cairo_lang_semantic::lints::apply_lint_levels;
+use cairo_lang_semantic::{corelib, TypeId};
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::unordered_hash_set::UnorderedHashSet;
 use cairo_lang_utils::Upcast;
@@ -323,8 +324,8 @@ fn priv_function_with_body_lowering(
     let multi_lowering = db.priv_function_with_body_multi_lowering(semantic_function_id)?;
     let lowered = match &db.lookup_intern_lowering_function_with_body(function_id) {
         ids::FunctionWithBodyLongId::Semantic(_) => multi_lowering.main_lowering.clone(),
-        ids::FunctionWithBodyLongId::Generated { element, .. } => {
-            multi_lowering.generated_lowerings[element].clone()
+        ids::FunctionWithBodyLongId::Generated { key, .. } => {
+            multi_lowering.generated_lowerings[key].clone()
         }
     };
     Ok(Arc::new(lowered))
@@ -497,6 +498,7 @@ fn function_with_body_lowering_diagnostics(
             diagnostics.add(LoweringDiagnostic {
                 location,
                 kind: LoweringDiagnosticKind::NoPanicFunctionCycle,
+                severity_override: None,
             });
         }
     }
@@ -522,11 +524,18 @@ fn semantic_function_with_body_lowering_diagnostics(
         );
         diagnostics
             .extend(db.function_with_body_lowering_diagnostics(function_id).unwrap_or_default());
-        for (element, _) in multi_lowering.generated_lowerings.iter() {
+        for (key, _) in multi_lowering.generated_lowerings.iter() {
+            // The body of a copyable closure is lowered for both `FnOnce::call` and `Fn::call`,
+            // with the same diagnostics - report them only for the former.
+            if let ids::GeneratedFunctionKey::TraitFunc(trait_function, _) = key {
+                if trait_function.trait_id(db.upcast()) == corelib::fn_trait(db.upcast()) {
+                    continue;
+                }
+            }
             let function_id =
                 db.intern_lowering_function_with_body(ids::FunctionWithBodyLongId::Generated {
                     parent: semantic_function_id,
-                    element: *element,
+                    key: *key,
                 });
             diagnostics.extend(
                 db.function_with_body_lowering_diagnostics(function_id).unwrap_or_default(),
@@ -568,7 +577,7 @@ fn module_lowering_diagnostics(
             ModuleItemId::ExternFunction(_) => {}
         }
     }
-    Ok(diagnostics.build())
+    Ok(apply_lint_levels(db.upcast(), module_id, diagnostics.build()))
 }
 
 fn file_lowering_diagnostics(
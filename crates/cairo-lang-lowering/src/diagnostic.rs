@@ -1,12 +1,13 @@
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_diagnostics::{
-    DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, DiagnosticNote, Diagnostics,
-    DiagnosticsBuilder,
+    error_code, DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, DiagnosticNote, Diagnostics,
+    DiagnosticsBuilder, ErrorCode, Severity,
 };
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_semantic::corelib::LiteralError;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::expr::inference::InferenceError;
+use cairo_lang_semantic::lints::{Lint, LintDiagnostic};
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 
 use crate::Location;
@@ -34,7 +35,7 @@ impl LoweringDiagnostics {
         location: Location,
         kind: LoweringDiagnosticKind,
     ) -> DiagnosticAdded {
-        self.diagnostics.add(LoweringDiagnostic { location, kind })
+        self.diagnostics.add(LoweringDiagnostic { location, kind, severity_override: None })
     }
 }
 
@@ -42,6 +43,8 @@ impl LoweringDiagnostics {
 pub struct LoweringDiagnostic {
     pub location: Location,
     pub kind: LoweringDiagnosticKind,
+    /// The severity set by the lint levels in effect at the location of the diagnostic, if any.
+    pub severity_override: Option<Severity>,
 }
 
 impl DiagnosticEntry for LoweringDiagnostic {
@@ -86,6 +89,30 @@ impl DiagnosticEntry for LoweringDiagnostic {
         &self.location.notes
     }
 
+    fn severity(&self) -> Severity {
+        self.severity_override.unwrap_or(Severity::Error)
+    }
+
+    /// Lowering diagnostics use the codes `E0400`-`E0499`. New kinds must be given new codes.
+    fn error_code(&self) -> Option<ErrorCode> {
+        Some(match &self.kind {
+            LoweringDiagnosticKind::Unreachable { .. } => error_code!("E0400"),
+            LoweringDiagnosticKind::VariableMoved { .. } => error_code!("E0401"),
+            LoweringDiagnosticKind::VariableNotDropped { .. } => error_code!("E0402"),
+            LoweringDiagnosticKind::MatchError(match_err) => match_err.error_code(),
+            LoweringDiagnosticKind::DesnappingANonCopyableType { .. } => error_code!("E0403"),
+            LoweringDiagnosticKind::UnexpectedError => error_code!("E0404"),
+            LoweringDiagnosticKind::CannotInlineFunctionThatMightCallItself => {
+                error_code!("E0405")
+            }
+            LoweringDiagnosticKind::MemberPathLoop => error_code!("E0406"),
+            LoweringDiagnosticKind::NoPanicFunctionCycle => error_code!("E0407"),
+            LoweringDiagnosticKind::LiteralError(_) => error_code!("E0408"),
+            LoweringDiagnosticKind::UnsupportedPattern => error_code!("E0409"),
+            LoweringDiagnosticKind::Unsupported => error_code!("E0410"),
+        })
+    }
+
     #[allow(unreachable_patterns, clippy::single_match)]
     fn location(&self, db: &Self::DbType) -> DiagnosticLocation {
         match &self.kind {
@@ -101,7 +128,40 @@ impl DiagnosticEntry for LoweringDiagnostic {
     }
 }
 
+impl LintDiagnostic for LoweringDiagnostic {
+    fn lint(&self) -> Option<Lint> {
+        match &self.kind {
+            LoweringDiagnosticKind::Unreachable { .. } => Some(Lint::UnreachableCode),
+            _ => None,
+        }
+    }
+
+    fn lint_stable_ptr(&self) -> SyntaxStablePtrId {
+        self.location.stable_location.stable_ptr()
+    }
+
+    fn set_severity(&mut self, severity: Severity) {
+        self.severity_override = Some(severity);
+    }
+}
+
 impl MatchError {
+    /// Match errors use the codes `E0450`-`E0499`, regardless of the match-like construct.
+    fn error_code(&self) -> ErrorCode {
+        match &self.error {
+            MatchDiagnostic::UnsupportedMatchedType(_) => error_code!("E0450"),
+            MatchDiagnostic::UnsupportedMatchedValueTuple => error_code!("E0451"),
+            MatchDiagnostic::UnsupportedMatchArmNotAVariant => error_code!("E0452"),
+            MatchDiagnostic::UnsupportedMatchArmNotATuple => error_code!("E0453"),
+            MatchDiagnostic::UnreachableMatchArm => error_code!("E0454"),
+            MatchDiagnostic::MissingMatchArm(_) => error_code!("E0455"),
+            MatchDiagnostic::UnsupportedMatchArmNotALiteral => error_code!("E0456"),
+            MatchDiagnostic::UnsupportedMatchArmNonSequential => error_code!("E0457"),
+            MatchDiagnostic::NonExhaustiveMatchFelt252 => error_code!("E0458"),
+            MatchDiagnostic::UnsupportedNumericInLetCondition => error_code!("E0459"),
+        }
+    }
+
     fn format(&self) -> String {
         match (&self.error, &self.kind) {
             (MatchDiagnostic::UnsupportedMatchedType(matched_type), MatchKind::Match) => {
@@ -110,6 +170,9 @@ impl MatchError {
             (MatchDiagnostic::UnsupportedMatchedType(matched_type), MatchKind::IfLet) => {
                 format!("Unsupported type in if-let. Type: `{}`.", matched_type)
             }
+            (MatchDiagnostic::UnsupportedMatchedType(matched_type), MatchKind::LetElse) => {
+                format!("Unsupported type in let-else. Type: `{}`.", matched_type)
+            }
             (MatchDiagnostic::UnsupportedMatchedValueTuple, MatchKind::Match) => {
                 "Unsupported matched value. Currently, match on tuples only supports enums as \
                  tuple members."
@@ -120,6 +183,11 @@ impl MatchError {
                  tuple members."
                     .into()
             }
+            (MatchDiagnostic::UnsupportedMatchedValueTuple, MatchKind::LetElse) => {
+                "Unsupported value in let-else. Currently, let-else on tuples only supports enums \
+                 as tuple members."
+                    .into()
+            }
             (MatchDiagnostic::UnsupportedMatchArmNotAVariant, _) => {
                 "Unsupported pattern - not a variant.".into()
             }
@@ -143,20 +211,20 @@ impl MatchError {
                 MatchDiagnostic::UnsupportedMatchArmNotALiteral
                 | MatchDiagnostic::UnsupportedMatchArmNonSequential
                 | MatchDiagnostic::NonExhaustiveMatchFelt252,
-                MatchKind::IfLet,
-            ) => unreachable!("Numeric values are not supported in if-let conditions."),
+                MatchKind::IfLet | MatchKind::LetElse,
+            ) => unreachable!("Numeric values are not supported in let conditions."),
 
             (MatchDiagnostic::MissingMatchArm(variant), MatchKind::Match) => {
                 format!("Missing match arm: `{}` not covered.", variant)
             }
-            (MatchDiagnostic::MissingMatchArm(_), MatchKind::IfLet) => {
-                unreachable!("If-let is not required to be exhaustive.")
+            (MatchDiagnostic::MissingMatchArm(_), MatchKind::IfLet | MatchKind::LetElse) => {
+                unreachable!("If-let and let-else are not required to be exhaustive.")
             }
 
             (MatchDiagnostic::UnreachableMatchArm, MatchKind::Match) => {
                 "Unreachable pattern arm.".into()
             }
-            (MatchDiagnostic::UnreachableMatchArm, MatchKind::IfLet) => {
+            (MatchDiagnostic::UnreachableMatchArm, MatchKind::IfLet | MatchKind::LetElse) => {
                 "Unreachable else clause.".into()
             }
             (MatchDiagnostic::UnsupportedNumericInLetCondition, MatchKind::Match) => {
@@ -165,6 +233,9 @@ impl MatchError {
             (MatchDiagnostic::UnsupportedNumericInLetCondition, MatchKind::IfLet) => {
                 "Numeric values are not supported in if-let conditions.".into()
             }
+            (MatchDiagnostic::UnsupportedNumericInLetCondition, MatchKind::LetElse) => {
+                "Numeric values are not supported in let-else statements.".into()
+            }
         }
     }
 }
@@ -197,6 +268,7 @@ pub struct MatchError {
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum MatchKind {
     IfLet,
+    LetElse,
     Match,
 }
 
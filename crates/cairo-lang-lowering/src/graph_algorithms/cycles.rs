@@ -21,6 +21,7 @@ pub fn function_with_body_direct_callees(
                     direct_callees.insert(stmt.function);
                 }
                 crate::Statement::Literal(_)
+                | crate::Statement::ImplConstant(_)
                 | crate::Statement::StructConstruct(_)
                 | crate::Statement::StructDestructure(_)
                 | crate::Statement::EnumConstruct(_)
@@ -1,13 +1,16 @@
 use cairo_lang_debug::DebugWithDb;
-use cairo_lang_defs::ids::UnstableSalsaId;
+use cairo_lang_defs::ids::{TopLevelLanguageElementId, UnstableSalsaId};
 use cairo_lang_diagnostics::{DiagnosticAdded, DiagnosticNote, Maybe};
 use cairo_lang_proc_macros::{DebugWithDb, SemanticObject};
 use cairo_lang_syntax::node::ast;
 use cairo_lang_utils::define_short_id;
 use defs::diagnostic_utils::StableLocation;
 use defs::ids::FreeFunctionId;
+use semantic::items::function_with_body::SemanticExprLookup;
+use semantic::items::functions::{GenericFunctionId, ImplGenericFunctionId};
+use semantic::items::imp::ImplId;
 use semantic::substitution::{GenericSubstitution, SubstitutionRewriter};
-use semantic::{ExprVar, Mutability};
+use semantic::{ExprVar, GenericArgumentId, Mutability, TypeLongId};
 use smol_str::SmolStr;
 use {cairo_lang_defs as defs, cairo_lang_semantic as semantic};
 
@@ -18,7 +21,7 @@ use crate::Location;
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub enum FunctionWithBodyLongId {
     Semantic(defs::ids::FunctionWithBodyId),
-    Generated { parent: defs::ids::FunctionWithBodyId, element: semantic::ExprId },
+    Generated { parent: defs::ids::FunctionWithBodyId, key: GeneratedFunctionKey },
 }
 define_short_id!(
     FunctionWithBodyId,
@@ -41,13 +44,13 @@ impl FunctionWithBodyLongId {
             FunctionWithBodyLongId::Semantic(semantic) => ConcreteFunctionWithBodyLongId::Semantic(
                 semantic::ConcreteFunctionWithBodyId::from_generic(db.upcast(), semantic)?,
             ),
-            FunctionWithBodyLongId::Generated { parent, element } => {
+            FunctionWithBodyLongId::Generated { parent, key } => {
                 ConcreteFunctionWithBodyLongId::Generated(GeneratedFunction {
                     parent: semantic::ConcreteFunctionWithBodyId::from_generic(
                         db.upcast(),
                         parent,
                     )?,
-                    element,
+                    key,
                 })
             }
         })
@@ -102,10 +105,10 @@ impl ConcreteFunctionWithBodyLongId {
             ConcreteFunctionWithBodyLongId::Semantic(id) => {
                 FunctionWithBodyLongId::Semantic(id.function_with_body_id(semantic_db))
             }
-            ConcreteFunctionWithBodyLongId::Generated(GeneratedFunction { parent, element }) => {
+            ConcreteFunctionWithBodyLongId::Generated(GeneratedFunction { parent, key }) => {
                 FunctionWithBodyLongId::Generated {
                     parent: parent.function_with_body_id(semantic_db),
-                    element,
+                    key,
                 }
             }
         };
@@ -203,7 +206,9 @@ impl ConcreteFunctionWithBodyId {
             ConcreteFunctionWithBodyLongId::Generated(generated) => {
                 let parent_id = generated.parent.function_with_body_id(semantic_db);
                 StableLocation::new(
-                    db.function_body(parent_id)?.exprs[generated.element].stable_ptr().untyped(),
+                    db.function_body(parent_id)?.exprs[generated.key.expr_id()]
+                        .stable_ptr()
+                        .untyped(),
                 )
             }
         })
@@ -279,9 +284,42 @@ pub trait SemanticFunctionIdEx {
 }
 impl SemanticFunctionIdEx for semantic::FunctionId {
     fn lowered(&self, db: &dyn LoweringGroup) -> FunctionId {
-        db.intern_lowering_function(FunctionLongId::Semantic(*self))
+        let long_id = match closure_call_function(db, *self) {
+            Some(generated) => FunctionLongId::Generated(generated),
+            None => FunctionLongId::Semantic(*self),
+        };
+        db.intern_lowering_function(long_id)
     }
 }
+
+/// If the given function is the `call` function of a trait the compiler implements for a closure
+/// type (e.g. `Fn::call`), returns the function generated for the closure.
+fn closure_call_function(
+    db: &dyn LoweringGroup,
+    function_id: semantic::FunctionId,
+) -> Option<GeneratedFunction> {
+    let semantic_db = db.upcast();
+    let GenericFunctionId::Impl(ImplGenericFunctionId {
+        impl_id: ImplId::GeneratedImpl(concrete_trait_id),
+        function,
+    }) = function_id.get_concrete(semantic_db).generic_function
+    else {
+        return None;
+    };
+    let Some(GenericArgumentId::Type(ty)) =
+        concrete_trait_id.generic_args(semantic_db).first().copied()
+    else {
+        return None;
+    };
+    let TypeLongId::Closure(closure) = db.lookup_intern_type(ty) else {
+        return None;
+    };
+    let parent = closure.parent_function.get_concrete(semantic_db).body(semantic_db).ok()??;
+    let expr_id = db
+        .lookup_expr_by_ptr(parent.function_with_body_id(semantic_db), closure.stable_ptr.into())
+        .ok()?;
+    Some(GeneratedFunction { parent, key: GeneratedFunctionKey::TraitFunc(function, expr_id) })
+}
 impl<'a> DebugWithDb<dyn LoweringGroup + 'a> for FunctionLongId {
     fn fmt(
         &self,
@@ -297,21 +335,48 @@ impl<'a> DebugWithDb<dyn LoweringGroup + 'a> for FunctionLongId {
     }
 }
 
+/// A key for a function generated by the compiler, unique within its parent function.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+pub enum GeneratedFunctionKey {
+    /// The body of a loop expression.
+    Loop(semantic::ExprId),
+    /// The implementation of a trait function for a compiler generated type, e.g. `Fn::call` of a
+    /// closure.
+    TraitFunc(defs::ids::TraitFunctionId, semantic::ExprId),
+}
+impl GeneratedFunctionKey {
+    /// Returns the expression the function was generated for.
+    pub fn expr_id(&self) -> semantic::ExprId {
+        match *self {
+            GeneratedFunctionKey::Loop(expr_id) | GeneratedFunctionKey::TraitFunc(_, expr_id) => {
+                expr_id
+            }
+        }
+    }
+}
+
 /// Generated function.
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 pub struct GeneratedFunction {
     pub parent: semantic::ConcreteFunctionWithBodyId,
-    pub element: semantic::ExprId,
+    pub key: GeneratedFunctionKey,
 }
 impl GeneratedFunction {
     pub fn body(&self, db: &dyn LoweringGroup) -> ConcreteFunctionWithBodyId {
-        let GeneratedFunction { parent, element } = *self;
-        let long_id =
-            ConcreteFunctionWithBodyLongId::Generated(GeneratedFunction { parent, element });
+        let long_id = ConcreteFunctionWithBodyLongId::Generated(*self);
         db.intern_lowering_concrete_function_with_body(long_id)
     }
     pub fn name(&self, db: &dyn LoweringGroup) -> SmolStr {
-        format!("{}[expr{}]", self.parent.full_path(db.upcast()), self.element.index()).into()
+        let parent = self.parent.full_path(db.upcast());
+        match self.key {
+            GeneratedFunctionKey::Loop(expr_id) => format!("{parent}[expr{}]", expr_id.index()),
+            GeneratedFunctionKey::TraitFunc(trait_function, expr_id) => format!(
+                "{parent}[expr{}][{}]",
+                expr_id.index(),
+                trait_function.full_path(db.upcast())
+            ),
+        }
+        .into()
     }
 }
 
@@ -13,7 +13,6 @@ use defs::diagnostic_utils::StableLocation;
 use id_arena::Arena;
 use itertools::{zip_eq, Itertools};
 use semantic::corelib::{core_module, get_ty_by_name};
-use semantic::expr::inference::InferenceError;
 use semantic::types::wrap_in_snapshots;
 use semantic::{ExprVarMemberPath, MatchArmSelector, TypeLongId};
 use {cairo_lang_defs as defs, cairo_lang_semantic as semantic};
@@ -25,7 +24,8 @@ use crate::blocks::FlatBlocksBuilder;
 use crate::db::LoweringGroup;
 use crate::diagnostic::LoweringDiagnostics;
 use crate::ids::{
-    ConcreteFunctionWithBodyId, FunctionWithBodyId, LocationId, SemanticFunctionIdEx, Signature,
+    ConcreteFunctionWithBodyId, FunctionWithBodyId, GeneratedFunctionKey, LocationId,
+    SemanticFunctionIdEx, Signature,
 };
 use crate::lower::external::{extern_facade_expr, extern_facade_return_tys};
 use crate::objects::Variable;
@@ -61,26 +61,12 @@ impl<'db> VariableAllocator<'db> {
 
     /// Allocates a new variable in the context's variable arena according to the context.
     pub fn new_var(&mut self, req: VarRequest) -> VariableId {
-        let ty_info = self.db.type_info(self.lookup_context.clone(), req.ty);
-        self.variables.alloc(Variable {
-            duplicatable: ty_info
-                .clone()
-                .map_err(InferenceError::Failed)
-                .and_then(|info| info.duplicatable),
-            droppable: ty_info
-                .clone()
-                .map_err(InferenceError::Failed)
-                .and_then(|info| info.droppable),
-            destruct_impl: ty_info
-                .clone()
-                .map_err(InferenceError::Failed)
-                .and_then(|info| info.destruct_impl),
-            panic_destruct_impl: ty_info
-                .map_err(InferenceError::Failed)
-                .and_then(|info| info.panic_destruct_impl),
-            ty: req.ty,
-            location: req.location,
-        })
+        self.variables.alloc(Variable::new(
+            self.db,
+            self.lookup_context.clone(),
+            req.ty,
+            req.location,
+        ))
     }
 
     /// Retrieves the LocationId of a stable syntax pointer in the current function file.
@@ -113,7 +99,7 @@ pub struct EncapsulatingLoweringContext<'db> {
     /// Block usages for the entire encapsulating function.
     pub block_usages: BlockUsages,
     /// Lowerings of generated functions.
-    pub lowerings: OrderedHashMap<semantic::ExprId, FlatLowered>,
+    pub lowerings: OrderedHashMap<GeneratedFunctionKey, FlatLowered>,
 }
 impl<'db> EncapsulatingLoweringContext<'db> {
     pub fn new(
@@ -15,8 +15,10 @@ cairo_lang_test_utils::test_file_test!(
     generated,
     "src/lower/test_data",
     {
+        closure :"closure",
         loop_ :"loop",
         while_ :"while",
+        for_ :"for",
     },
     test_generated_function
 );
@@ -60,11 +62,11 @@ fn test_generated_function(
         )
         .unwrap();
 
-        for (expr_id, lowering) in multi_lowering.generated_lowerings.iter() {
+        for (key, lowering) in multi_lowering.generated_lowerings.iter() {
             let generated_id = db.intern_lowering_concrete_function_with_body(
                 ConcreteFunctionWithBodyLongId::Generated(GeneratedFunction {
                     parent: test_function.concrete_function_id,
-                    element: *expr_id,
+                    key: *key,
                 }),
             );
 
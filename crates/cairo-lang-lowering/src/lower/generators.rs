@@ -2,6 +2,7 @@
 //! ownership of OwnedVariable.
 
 use cairo_lang_semantic as semantic;
+use cairo_lang_semantic::items::imp::ImplConstantId;
 use cairo_lang_semantic::ConcreteVariant;
 use cairo_lang_utils::extract_matches;
 use itertools::chain;
@@ -12,7 +13,7 @@ use super::VariableId;
 use crate::ids::LocationId;
 use crate::lower::context::LoweringContext;
 use crate::objects::{
-    Statement, StatementCall, StatementLiteral, StatementStructConstruct,
+    Statement, StatementCall, StatementImplConstant, StatementLiteral, StatementStructConstruct,
     StatementStructDestructure, VarUsage,
 };
 use crate::{StatementDesnap, StatementEnumConstruct, StatementSnapshot};
@@ -46,6 +47,27 @@ impl Literal {
     }
 }
 
+/// Generator for [StatementImplConstant].
+pub struct ImplConstant {
+    pub impl_constant_id: ImplConstantId,
+    pub location: LocationId,
+    pub ty: semantic::TypeId,
+}
+impl ImplConstant {
+    pub fn add(
+        self,
+        ctx: &mut LoweringContext<'_, '_>,
+        builder: &mut StatementsBuilder,
+    ) -> VarUsage {
+        let output = ctx.new_var(VarRequest { ty: self.ty, location: self.location });
+        builder.push_statement(Statement::ImplConstant(StatementImplConstant {
+            impl_constant_id: self.impl_constant_id,
+            output,
+        }));
+        VarUsage { var_id: output, location: self.location }
+    }
+}
+
 /// Generator for [StatementCall].
 /// Note that builder.finalize_statement() must be called manually after ref bindings.
 pub struct Call {
@@ -15,7 +15,7 @@ use crate::diagnostic::{MatchDiagnostic, MatchError, MatchKind};
 use crate::ids::{LocationId, SemanticFunctionIdEx};
 use crate::lower::context::VarRequest;
 use crate::lower::lower_match::{
-    lower_concrete_enum_match, lower_expr_match_tuple, lower_optimized_extern_match,
+    lower_concrete_enum_match, lower_expr_match_tuple, lower_optimized_extern_match, MatchArmExpr,
     MatchArmWrapper, TupleInfo,
 };
 use crate::lower::{
@@ -247,8 +247,11 @@ pub fn lower_expr_if_let(
     let (n_snapshots, long_type_id) = peel_snapshots(ctx.db.upcast(), ty);
 
     let arms = vec![
-        MatchArmWrapper { patterns: patterns.into(), expr: Some(expr.if_block) },
-        MatchArmWrapper { patterns: vec![], expr: expr.else_block },
+        MatchArmWrapper { patterns: patterns.into(), expr: MatchArmExpr::Expr(expr.if_block) },
+        MatchArmWrapper {
+            patterns: vec![],
+            expr: expr.else_block.map_or(MatchArmExpr::Unit, MatchArmExpr::Expr),
+        },
     ];
 
     if let Some(types) = try_extract_matches!(long_type_id, TypeLongId::Tuple) {
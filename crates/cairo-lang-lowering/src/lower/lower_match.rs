@@ -1,10 +1,12 @@
 use std::vec;
 
 use cairo_lang_debug::DebugWithDb;
+use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::flag::Flag;
 use cairo_lang_filesystem::ids::FlagId;
 use cairo_lang_semantic as semantic;
 use cairo_lang_semantic::corelib;
+use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_utils::try_extract_matches;
 use cairo_lang_utils::unordered_hash_map::{Entry, UnorderedHashMap};
@@ -23,6 +25,7 @@ use super::context::{
     lowering_flow_error_to_sealed_block, LoweredExpr, LoweredExprExternEnum, LoweringContext,
     LoweringFlowError, LoweringResult,
 };
+use super::usage::MemberPath;
 use super::{
     alloc_empty_block, generators, lower_expr_literal, lower_tail_expr,
     lowered_expr_to_block_scope_end,
@@ -48,16 +51,60 @@ struct ExtractedEnumDetails {
     n_snapshots: usize,
 }
 
-/// MatchArm wrapper that allow for optional expression clause.
-/// Used in the case of if-let with missing else clause.
+/// MatchArm wrapper that allow for non-expression arm clauses.
+/// Used in the case of if-let with missing else clause, and of let-else.
 pub struct MatchArmWrapper {
     pub patterns: Vec<PatternId>,
-    pub expr: Option<semantic::ExprId>,
+    pub expr: MatchArmExpr,
 }
 
 impl From<&semantic::MatchArm> for MatchArmWrapper {
     fn from(arm: &semantic::MatchArm) -> Self {
-        Self { patterns: arm.patterns.clone(), expr: Some(arm.expression) }
+        Self { patterns: arm.patterns.clone(), expr: MatchArmExpr::Expr(arm.expression) }
+    }
+}
+
+/// The clause of a [MatchArmWrapper], lowered once its patterns are bound.
+pub enum MatchArmExpr {
+    /// A semantic expression.
+    Expr(semantic::ExprId),
+    /// A unit value. Used in the case of if-let with missing else clause.
+    Unit,
+    /// A tuple of the variables bound by the arm patterns. Used in the case of let-else.
+    BoundVars(Vec<semantic::PatternVariable>),
+}
+
+/// Lowers the clause of a match arm into the end of `builder`.
+fn lower_match_arm_expr(
+    ctx: &mut LoweringContext<'_, '_>,
+    mut builder: BlockBuilder,
+    arm_expr: &MatchArmExpr,
+    location: LocationId,
+) -> Maybe<SealedBlockBuilder> {
+    match arm_expr {
+        MatchArmExpr::Expr(expr) => lower_tail_expr(ctx, builder, *expr),
+        MatchArmExpr::Unit => lowered_expr_to_block_scope_end(
+            ctx,
+            builder,
+            Ok(LoweredExpr::Tuple { exprs: vec![], location }),
+        ),
+        MatchArmExpr::BoundVars(vars) => {
+            let exprs = vars
+                .iter()
+                .map(|var| {
+                    let location = ctx.get_location(var.stable_ptr.untyped());
+                    let member_path = MemberPath::Var(semantic::VarId::Local(var.var.id));
+                    LoweredExpr::AtVariable(
+                        builder.get_ref_raw(ctx, &member_path, location).unwrap(),
+                    )
+                })
+                .collect();
+            lowered_expr_to_block_scope_end(
+                ctx,
+                builder,
+                Ok(LoweredExpr::Tuple { exprs, location }),
+            )
+        }
     }
 }
 
@@ -146,8 +193,8 @@ fn get_underscore_pattern_path(
         .find(|option| option.is_some())??;
 
     for arm in arms.iter().skip(otherwise_variant.arm_index + 1) {
-        if arm.patterns.is_empty() && arm.expr.is_some() {
-            let expr = ctx.function_body.exprs[arm.expr.unwrap()].clone();
+        if let (true, MatchArmExpr::Expr(expr)) = (arm.patterns.is_empty(), &arm.expr) {
+            let expr = ctx.function_body.exprs[*expr].clone();
             ctx.diagnostics.report(
                 expr.stable_ptr().untyped(),
                 MatchError(MatchError {
@@ -727,6 +774,99 @@ pub(crate) fn lower_expr_match(
     )
 }
 
+/// Lowers a let-else statement, binding the variables of its pattern in `builder`.
+/// The pattern is lowered as a match arm returning its variables, and the else clause as an arm
+/// matching any other value.
+pub(crate) fn lower_let_else(
+    ctx: &mut LoweringContext<'_, '_>,
+    builder: &mut BlockBuilder,
+    pattern: PatternId,
+    expr: semantic::ExprId,
+    else_clause: semantic::ExprId,
+    stable_ptr: ast::StatementPtr,
+) -> LoweringResult<()> {
+    log::trace!("Lowering a let-else statement.");
+    let location = ctx.get_location(stable_ptr.untyped());
+    let lowered_expr = lower_expr(ctx, builder, expr)?;
+
+    let matched_expr = ctx.function_body.exprs[expr].clone();
+    let ty = matched_expr.ty();
+
+    if ty == ctx.db.core_felt252_ty()
+        || corelib::get_convert_to_felt252_libfunc_name_by_type(ctx.db.upcast(), ty).is_some()
+    {
+        return Err(LoweringFlowError::Failed(ctx.diagnostics.report(
+            stable_ptr.untyped(),
+            MatchError(MatchError {
+                kind: MatchKind::LetElse,
+                error: MatchDiagnostic::UnsupportedNumericInLetCondition,
+            }),
+        )));
+    }
+
+    let (n_snapshots, long_type_id) = peel_snapshots(ctx.db.upcast(), ty);
+
+    let vars = ctx.function_body.patterns[pattern].variables(&ctx.function_body.patterns);
+    let arms = vec![
+        MatchArmWrapper { patterns: vec![pattern], expr: MatchArmExpr::BoundVars(vars.clone()) },
+        MatchArmWrapper { patterns: vec![], expr: MatchArmExpr::Expr(else_clause) },
+    ];
+
+    let bound_vars = if let Some(types) = try_extract_matches!(long_type_id, TypeLongId::Tuple) {
+        lower_expr_match_tuple(
+            ctx,
+            builder,
+            lowered_expr,
+            &matched_expr,
+            &TupleInfo { types, n_snapshots },
+            &arms,
+            MatchKind::LetElse,
+        )
+    } else if let LoweredExpr::ExternEnum(extern_enum) = lowered_expr {
+        lower_optimized_extern_match(ctx, builder, extern_enum, &arms, MatchKind::LetElse)
+    } else {
+        lower_concrete_enum_match(
+            ctx,
+            builder,
+            &matched_expr,
+            lowered_expr,
+            &arms,
+            location,
+            MatchKind::LetElse,
+        )
+    }?;
+
+    // Rebind the pattern variables, returned as a tuple from the matching arm.
+    let outputs = if let LoweredExpr::Tuple { exprs, .. } = bound_vars {
+        exprs
+    } else {
+        generators::StructDestructure {
+            input: bound_vars.as_var_usage(ctx, builder)?.var_id,
+            var_reqs: vars
+                .iter()
+                .map(|var| VarRequest {
+                    ty: var.var.ty,
+                    location: ctx.get_location(var.stable_ptr.untyped()),
+                })
+                .collect(),
+        }
+        .add(ctx, &mut builder.statements)
+        .into_iter()
+        .map(|var_id| {
+            LoweredExpr::AtVariable(VarUsage { var_id, location: ctx.variables[var_id].location })
+        })
+        .collect()
+    };
+    for (var, output) in zip_eq(vars, outputs) {
+        let var_usage = output.as_var_usage(ctx, builder)?;
+        let sem_var = semantic::Variable::Local(var.var);
+        builder.put_semantic(sem_var.id(), var_usage.var_id);
+        // TODO(spapini): Build semantic_defs in semantic model.
+        ctx.semantic_defs.insert(sem_var.id(), sem_var);
+    }
+    Ok(())
+}
+
 pub(crate) fn lower_concrete_enum_match(
     ctx: &mut LoweringContext<'_, '_>,
     builder: &mut BlockBuilder,
@@ -1024,14 +1164,7 @@ fn group_match_arms(
                 return match lowering_inner_pattern_result {
                     Ok(_) => {
                         // Lower the arm expression.
-                        match arm.expr {
-                            Some(expr) => lower_tail_expr(ctx, subscope, expr),
-                            None => lowered_expr_to_block_scope_end(
-                                ctx,
-                                subscope,
-                                Ok(LoweredExpr::Tuple { exprs: vec![], location }),
-                            ),
-                        }
+                        lower_match_arm_expr(ctx, subscope, &arm.expr, location)
                     }
                     Err(err) => lowering_flow_error_to_sealed_block(ctx, subscope, err),
                 }
@@ -1076,15 +1209,8 @@ fn group_match_arms(
                 sealed_blocks,
                 location,
             )?;
-            match arm.expr {
-                Some(expr) => lower_tail_expr(ctx, outer_subscope, expr),
-                None => lowered_expr_to_block_scope_end(
-                    ctx,
-                    outer_subscope,
-                    Ok(LoweredExpr::Tuple { exprs: vec![], location }),
-                ),
-            }
-            .map_err(LoweringFlowError::Failed)
+            lower_match_arm_expr(ctx, outer_subscope, &arm.expr, location)
+                .map_err(LoweringFlowError::Failed)
         })
         .collect()
 }
@@ -2,7 +2,7 @@ use std::vec;
 
 use block_builder::BlockBuilder;
 use cairo_lang_debug::DebugWithDb;
-use cairo_lang_diagnostics::Maybe;
+use cairo_lang_diagnostics::{Maybe, ToMaybe};
 use cairo_lang_semantic::corelib;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
@@ -14,6 +14,7 @@ use semantic::corelib::{
     core_felt252_ty, core_submodule, get_core_function_id, get_core_ty_by_name, get_function_id,
     never_ty, unit_ty, validate_literal,
 };
+use semantic::db::SemanticGroup;
 use semantic::items::structure::SemanticStructEx;
 use semantic::literals::try_extract_minus_literal;
 use semantic::types::{peel_snapshots, wrap_in_snapshots};
@@ -31,13 +32,13 @@ use self::context::{
 use self::external::{extern_facade_expr, extern_facade_return_tys};
 use self::logical_op::lower_logical_op;
 use self::lower_if::lower_expr_if;
-use self::lower_match::lower_expr_match;
+use self::lower_match::{lower_expr_match, lower_let_else};
 use crate::blocks::FlatBlocks;
 use crate::db::LoweringGroup;
 use crate::diagnostic::LoweringDiagnosticKind::{self, *};
 use crate::ids::{
-    FunctionLongId, FunctionWithBodyId, FunctionWithBodyLongId, GeneratedFunction, LocationId,
-    SemanticFunctionIdEx, Signature,
+    FunctionLongId, FunctionWithBodyId, FunctionWithBodyLongId, GeneratedFunction,
+    GeneratedFunctionKey, LocationId, SemanticFunctionIdEx, Signature,
 };
 use crate::lower::context::{LoweringResult, VarRequest};
 use crate::lower::generators::StructDestructure;
@@ -62,7 +63,7 @@ mod generated_test;
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct MultiLowering {
     pub main_lowering: FlatLowered,
-    pub generated_lowerings: OrderedHashMap<semantic::ExprId, FlatLowered>,
+    pub generated_lowerings: OrderedHashMap<GeneratedFunctionKey, FlatLowered>,
 }
 
 /// Lowers a semantic free function.
@@ -231,6 +232,117 @@ pub fn lower_while_loop(
     builder.merge_and_end_with_match(ctx, match_info, vec![block_main, block_else], while_location)
 }
 
+/// Lowers an expression of type [semantic::ExprFor].
+pub fn lower_for_loop(
+    ctx: &mut LoweringContext<'_, '_>,
+    builder: &mut BlockBuilder,
+    loop_expr: semantic::ExprFor,
+    loop_expr_id: semantic::ExprId,
+) -> LoweringResult<LoweredExpr> {
+    let semantic_db: &dyn SemanticGroup = ctx.db.upcast();
+    let for_location = ctx.get_location(loop_expr.stable_ptr.untyped());
+    let iterator_member_path = loop_expr.into_iter_member_path;
+
+    // Call `Iterator::next` on the iterator.
+    let next_ty = semantic_db
+        .concrete_function_signature(loop_expr.next_function_id)
+        .map_err(LoweringFlowError::Failed)?
+        .return_type;
+    let iterator = builder.get_ref(ctx, &iterator_member_path).ok_or_else(|| {
+        LoweringFlowError::Failed(
+            ctx.diagnostics.report(loop_expr.stable_ptr.untyped(), MemberPathLoop),
+        )
+    })?;
+    let (ref_outputs, next_value) = perform_function_call(
+        ctx,
+        builder,
+        loop_expr.next_function_id,
+        vec![iterator],
+        vec![iterator_member_path.ty()],
+        next_ty,
+        for_location,
+    )?;
+    for output_var in ref_outputs {
+        builder.update_ref(ctx, &iterator_member_path, output_var.var_id);
+    }
+    let next_value = next_value.as_var_usage(ctx, builder)?;
+
+    let semantic::TypeLongId::Concrete(semantic::ConcreteTypeId::Enum(concrete_enum_id)) =
+        ctx.db.lookup_intern_type(next_ty)
+    else {
+        unreachable!("`Iterator::next` must return an `Option`.");
+    };
+    let item_ty = extract_matches!(
+        semantic_db.lookup_intern_concrete_enum(concrete_enum_id).generic_args[0],
+        GenericArgumentId::Type
+    );
+    let some_variant = corelib::option_some_variant(semantic_db, GenericArgumentId::Type(item_ty));
+    let none_variant = corelib::option_none_variant(semantic_db, GenericArgumentId::Type(item_ty));
+
+    // `Some` block - bind the item to the pattern, run the body and continue to the next
+    // iteration.
+    let mut subscope_some = create_subscope_with_bound_refs(ctx, builder);
+    let block_some_id = subscope_some.block_id;
+    let pattern = ctx.function_body.patterns[loop_expr.pattern].clone();
+    let some_block_var_id = ctx.new_var(VarRequest {
+        ty: item_ty,
+        location: ctx.get_location(pattern.stable_ptr().untyped()),
+    });
+    let body =
+        extract_matches!(&ctx.function_body.exprs[loop_expr.body], semantic::Expr::Block).clone();
+    let block_expr = (|| {
+        lower_single_pattern(
+            ctx,
+            &mut subscope_some,
+            pattern,
+            LoweredExpr::AtVariable(VarUsage { var_id: some_block_var_id, location: for_location }),
+        )?;
+        lower_expr_block(ctx, &mut subscope_some, &body)?;
+        // Add recursive call.
+        let signature = ctx.signature.clone();
+        call_loop_func(
+            ctx,
+            signature,
+            &mut subscope_some,
+            loop_expr_id,
+            loop_expr.stable_ptr.untyped(),
+        )
+    })();
+    let block_some = lowered_expr_to_block_scope_end(ctx, subscope_some, block_expr)
+        .map_err(LoweringFlowError::Failed)?;
+
+    // Empty `None` block - the iteration is finished.
+    let subscope_none = create_subscope_with_bound_refs(ctx, builder);
+    let block_none_id = subscope_none.block_id;
+    let none_block_var_id =
+        ctx.new_var(VarRequest { ty: corelib::unit_ty(semantic_db), location: for_location });
+    let block_none = lowered_expr_to_block_scope_end(
+        ctx,
+        subscope_none,
+        Ok(LoweredExpr::Tuple { exprs: vec![], location: for_location }),
+    )
+    .map_err(LoweringFlowError::Failed)?;
+
+    let match_info = MatchInfo::Enum(MatchEnumInfo {
+        concrete_enum_id,
+        input: next_value,
+        arms: vec![
+            MatchArm {
+                arm_selector: MatchArmSelector::VariantId(some_variant),
+                block_id: block_some_id,
+                var_ids: vec![some_block_var_id],
+            },
+            MatchArm {
+                arm_selector: MatchArmSelector::VariantId(none_variant),
+                block_id: block_none_id,
+                var_ids: vec![none_block_var_id],
+            },
+        ],
+        location: for_location,
+    });
+    builder.merge_and_end_with_match(ctx, match_info, vec![block_some, block_none], for_location)
+}
+
 /// Lowers a loop inner function into [FlatLowered].
 /// Similar to `lower_function`, but adds a recursive call.
 // TODO(spapini): Unite with `lower_function`.
@@ -287,7 +399,12 @@ pub fn lower_loop_function(
                 let block_expr = lower_while_loop(&mut ctx, &mut builder, while_expr, loop_expr_id);
                 (block_expr, stable_ptr)
             }
-            _ => unreachable!("Loop expression must be either loop or while."),
+            semantic::Expr::For(for_expr) => {
+                let stable_ptr = for_expr.stable_ptr;
+                let block_expr = lower_for_loop(&mut ctx, &mut builder, for_expr, loop_expr_id);
+                (block_expr, stable_ptr)
+            }
+            _ => unreachable!("Loop expression must be either loop, while or for."),
         };
 
         let block_sealed = lowered_expr_to_block_scope_end(&mut ctx, builder, block_expr)?;
@@ -414,7 +531,18 @@ pub fn lower_statement(
                 x.as_var_usage(ctx, builder)?;
             }
         }
-        semantic::Statement::Let(semantic::StatementLet { pattern, expr, stable_ptr: _ }) => {
+        semantic::Statement::Let(semantic::StatementLet {
+            pattern,
+            expr,
+            else_clause: Some(else_clause),
+            stable_ptr,
+        }) => lower_let_else(ctx, builder, *pattern, *expr, *else_clause, *stable_ptr)?,
+        semantic::Statement::Let(semantic::StatementLet {
+            pattern,
+            expr,
+            else_clause: None,
+            stable_ptr: _,
+        }) => {
             log::trace!("Lowering a let statement.");
             let lowered_expr = lower_expr(ctx, builder, *expr)?;
             let pattern = ctx.function_body.patterns[*pattern].clone();
@@ -595,6 +723,7 @@ fn lower_expr(
     let expr = ctx.function_body.exprs[expr_id].clone();
     match &expr {
         semantic::Expr::Constant(expr) => lower_expr_constant(ctx, expr, builder),
+        semantic::Expr::ImplConstant(expr) => lower_expr_impl_constant(ctx, expr, builder),
         semantic::Expr::Tuple(expr) => lower_expr_tuple(ctx, expr, builder),
         semantic::Expr::Snapshot(expr) => lower_expr_snapshot(ctx, expr, builder),
         semantic::Expr::Desnap(expr) => lower_expr_desnap(ctx, expr, builder),
@@ -604,7 +733,7 @@ fn lower_expr(
         semantic::Expr::FunctionCall(expr) => lower_expr_function_call(ctx, expr, builder),
         semantic::Expr::Match(expr) => lower_expr_match(ctx, expr, builder),
         semantic::Expr::If(expr) => lower_expr_if(ctx, builder, expr),
-        semantic::Expr::Loop(_) | semantic::Expr::While(_) => {
+        semantic::Expr::Loop(_) | semantic::Expr::While(_) | semantic::Expr::For(_) => {
             lower_expr_loop(ctx, builder, expr_id)
         }
         semantic::Expr::Var(expr) => {
@@ -618,6 +747,7 @@ fn lower_expr(
         semantic::Expr::StructCtor(expr) => lower_expr_struct_ctor(ctx, expr, builder),
         semantic::Expr::EnumVariantCtor(expr) => lower_expr_enum_ctor(ctx, expr, builder),
         semantic::Expr::PropagateError(expr) => lower_expr_error_propagate(ctx, expr, builder),
+        semantic::Expr::Closure(expr) => lower_expr_closure(ctx, expr, expr_id, builder),
         semantic::Expr::Missing(semantic::ExprMissing { diag_added, .. }) => {
             Err(LoweringFlowError::Failed(*diag_added))
         }
@@ -831,6 +961,38 @@ fn lower_expr_constant(
     lower_expr_literal(ctx, const_expr_literal, builder)
 }
 
+/// Lowers an expression of type [semantic::ExprImplConstant].
+fn lower_expr_impl_constant(
+    ctx: &mut LoweringContext<'_, '_>,
+    expr: &semantic::ExprImplConstant,
+    builder: &mut BlockBuilder,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering an impl constant: {:?}", expr.debug(&ctx.expr_formatter));
+    let value = expr.impl_constant_id.value(ctx.db.upcast()).map_err(LoweringFlowError::Failed)?;
+    let Some(constant) = value else {
+        // The impl is generic, the value is only known on concretization.
+        let location = ctx.get_location(expr.stable_ptr.untyped());
+        return Ok(LoweredExpr::AtVariable(
+            generators::ImplConstant {
+                impl_constant_id: expr.impl_constant_id,
+                ty: expr.ty,
+                location,
+            }
+            .add(ctx, &mut builder.statements),
+        ));
+    };
+    let semantic::Expr::Literal(const_expr_literal) = &constant.value else {
+        panic!("Only literal constants are supported.");
+    };
+    lower_expr_literal_helper(
+        ctx,
+        expr.stable_ptr.untyped(),
+        expr.ty,
+        &const_expr_literal.value,
+        builder,
+    )
+}
+
 /// Lowers an expression of type [semantic::ExprTuple].
 fn lower_expr_tuple(
     ctx: &mut LoweringContext<'_, '_>,
@@ -1018,10 +1180,45 @@ fn lower_expr_loop(
     builder: &mut BlockBuilder,
     loop_expr_id: ExprId,
 ) -> LoweringResult<LoweredExpr> {
-    let (stable_ptr, return_type) = match ctx.function_body.exprs[loop_expr_id] {
+    let (stable_ptr, return_type) = match ctx.function_body.exprs[loop_expr_id].clone() {
         semantic::Expr::Loop(semantic::ExprLoop { stable_ptr, ty, .. }) => (stable_ptr, ty),
         semantic::Expr::While(semantic::ExprWhile { stable_ptr, ty, .. }) => (stable_ptr, ty),
-        _ => unreachable!("Loop expression must be either loop or while."),
+        semantic::Expr::For(semantic::ExprFor {
+            into_iter,
+            into_iter_member_path,
+            expr_id,
+            stable_ptr,
+            ty,
+            ..
+        }) => {
+            // Compute the iterator before the loop, and bind it to its hidden variable.
+            let input = lower_expr_to_var_usage(ctx, builder, expr_id)?;
+            let location = ctx.get_location(into_iter_member_path.stable_ptr().untyped());
+            let into_iter_ty = into_iter_member_path.ty();
+            let (_, iterator) = perform_function_call(
+                ctx,
+                builder,
+                into_iter,
+                vec![input],
+                vec![],
+                into_iter_ty,
+                location,
+            )?;
+            let iterator = iterator.as_var_usage(ctx, builder)?;
+            let semantic_var_id = into_iter_member_path.base_var();
+            builder.put_semantic(semantic_var_id, iterator.var_id);
+            // TODO(spapini): Build semantic_defs in semantic model.
+            ctx.semantic_defs.insert(
+                semantic_var_id,
+                semantic::Variable::Local(semantic::LocalVariable {
+                    id: extract_matches!(semantic_var_id, semantic::VarId::Local),
+                    ty: into_iter_ty,
+                    is_mut: true,
+                }),
+            );
+            (stable_ptr, ty)
+        }
+        _ => unreachable!("Loop expression must be either loop, while or for."),
     };
 
     let usage = &ctx.block_usages.block_usages[&loop_expr_id];
@@ -1042,7 +1239,7 @@ fn lower_expr_loop(
     // Get the function id.
     let function = ctx.db.intern_lowering_function_with_body(FunctionWithBodyLongId::Generated {
         parent: ctx.semantic_function_id,
-        element: loop_expr_id,
+        key: GeneratedFunctionKey::Loop(loop_expr_id),
     });
 
     // Generate the function.
@@ -1051,7 +1248,7 @@ fn lower_expr_loop(
         lower_loop_function(encapsulating_ctx, function, loop_signature.clone(), loop_expr_id)
             .map_err(LoweringFlowError::Failed)?;
     // TODO(spapini): Recursive call.
-    encapsulating_ctx.lowerings.insert(loop_expr_id, lowered);
+    encapsulating_ctx.lowerings.insert(GeneratedFunctionKey::Loop(loop_expr_id), lowered);
 
     ctx.encapsulating_ctx = Some(encapsulating_ctx);
     ctx.current_loop_expr_id = Some(loop_expr_id);
@@ -1072,7 +1269,7 @@ fn call_loop_func(
     // Call it.
     let function = ctx.db.intern_lowering_function(FunctionLongId::Generated(GeneratedFunction {
         parent: ctx.concrete_function_id.base_semantic_function(ctx.db),
-        element: loop_expr_id,
+        key: GeneratedFunctionKey::Loop(loop_expr_id),
     }));
     let inputs = loop_signature
         .params
@@ -1101,6 +1298,199 @@ fn call_loop_func(
     Ok(LoweredExpr::AtVariable(call_result.returns.into_iter().next().unwrap()))
 }
 
+/// Lowers an expression of type [semantic::ExprClosure].
+///
+/// The closure value is a struct of its captured variables. Its body is lowered into a generated
+/// function for `FnOnce::call`, and for `Fn::call` if the closure is copyable.
+fn lower_expr_closure(
+    ctx: &mut LoweringContext<'_, '_>,
+    expr: &semantic::ExprClosure,
+    closure_expr_id: ExprId,
+    builder: &mut BlockBuilder,
+) -> LoweringResult<LoweredExpr> {
+    log::trace!("Lowering a closure expression: {:?}", expr.debug(&ctx.expr_formatter));
+    let location = ctx.get_location(expr.stable_ptr.untyped());
+    let inputs = expr
+        .captured_vars
+        .iter()
+        .map(|var| {
+            LoweredExpr::Member(ExprVarMemberPath::Var(var.clone()), location)
+                .as_var_usage(ctx, builder)
+        })
+        .collect::<LoweringResult<Vec<_>>>()?;
+    let closure_var = generators::StructConstruct { inputs, ty: expr.ty, location }
+        .add(ctx, &mut builder.statements);
+
+    let semantic_db = ctx.db.upcast();
+    let mut call_traits = vec![corelib::fn_once_trait(semantic_db)];
+    if ctx.variables[closure_var.var_id].duplicatable.is_ok() {
+        call_traits.push(corelib::fn_trait(semantic_db));
+    }
+    for trait_id in call_traits {
+        let (trait_function, call_function) =
+            closure_call_function(ctx.db, expr.ty, trait_id).map_err(LoweringFlowError::Failed)?;
+        let signature =
+            ctx.db.concrete_function_signature(call_function).map_err(LoweringFlowError::Failed)?;
+        let signature = Signature { location, ..Signature::from_semantic(ctx.db, signature) };
+        let key = GeneratedFunctionKey::TraitFunc(trait_function, closure_expr_id);
+        let function =
+            ctx.db.intern_lowering_function_with_body(FunctionWithBodyLongId::Generated {
+                parent: ctx.semantic_function_id,
+                key,
+            });
+
+        // Generate the function.
+        let encapsulating_ctx = std::mem::take(&mut ctx.encapsulating_ctx).unwrap();
+        let lowered = lower_closure_function(encapsulating_ctx, function, signature, expr)
+            .map(|lowered| encapsulating_ctx.lowerings.insert(key, lowered));
+        ctx.encapsulating_ctx = Some(encapsulating_ctx);
+        lowered.map_err(LoweringFlowError::Failed)?;
+    }
+
+    Ok(LoweredExpr::AtVariable(closure_var))
+}
+
+/// Returns the `call` function of a `Fn`-like trait, implemented by the compiler for the given
+/// closure type.
+fn closure_call_function(
+    db: &dyn LoweringGroup,
+    closure_ty: semantic::TypeId,
+    trait_id: defs::ids::TraitId,
+) -> Maybe<(defs::ids::TraitFunctionId, semantic::FunctionId)> {
+    let closure = extract_matches!(db.lookup_intern_type(closure_ty), TypeLongId::Closure);
+    let args_ty = db.intern_type(TypeLongId::Tuple(closure.param_tys));
+    let concrete_trait = db.intern_concrete_trait(semantic::ConcreteTraitLongId {
+        trait_id,
+        generic_args: vec![
+            GenericArgumentId::Type(closure_ty),
+            GenericArgumentId::Type(args_ty),
+            GenericArgumentId::Type(closure.ret_ty),
+        ],
+    });
+    let trait_function = db.trait_function_by_name(trait_id, "call".into())?.to_maybe()?;
+    let function = db.intern_function(semantic::FunctionLongId {
+        function: semantic::ConcreteFunction {
+            generic_function: semantic::items::functions::GenericFunctionId::Impl(
+                semantic::items::functions::ImplGenericFunctionId {
+                    impl_id: semantic::items::imp::ImplId::GeneratedImpl(concrete_trait),
+                    function: trait_function,
+                },
+            ),
+            generic_args: vec![],
+        },
+    });
+    Ok((trait_function, function))
+}
+
+/// Lowers the body of a closure into a generated function implementing `call` of a `Fn`-like
+/// trait. The function takes the closure (or a snapshot of it) and a tuple of the arguments.
+fn lower_closure_function(
+    encapsulating_ctx: &mut EncapsulatingLoweringContext<'_>,
+    function_id: FunctionWithBodyId,
+    signature: Signature,
+    closure_expr: &semantic::ExprClosure,
+) -> Maybe<FlatLowered> {
+    let mut ctx = LoweringContext::new(encapsulating_ctx, function_id, signature)?;
+    let location = ctx.get_location(closure_expr.stable_ptr.untyped());
+
+    // Initialize builder.
+    let root_block_id = alloc_empty_block(&mut ctx);
+    let mut builder = BlockBuilder::root(&mut ctx, root_block_id);
+
+    let parameters = ctx
+        .signature
+        .params
+        .clone()
+        .into_iter()
+        .map(|param| ctx.new_var(VarRequest { ty: param.ty(), location }))
+        .collect_vec();
+
+    let root_ok = (|| {
+        let [closure_var, args_var] = parameters[..] else {
+            unreachable!("A closure call function must have exactly two parameters.");
+        };
+        let block_expr = (|| {
+            // Bind the captured variables, taking them out of the snapshot of the closure in `Fn`.
+            let mut closure_var = VarUsage { var_id: closure_var, location };
+            if ctx.variables[closure_var.var_id].ty != closure_expr.ty {
+                closure_var = generators::Desnap { input: closure_var, location }
+                    .add(&mut ctx, &mut builder.statements);
+            }
+            let var_reqs = closure_expr
+                .captured_vars
+                .iter()
+                .map(|var| VarRequest {
+                    ty: var.ty,
+                    location: ctx.get_location(var.stable_ptr.untyped()),
+                })
+                .collect();
+            let captured_vars =
+                generators::StructDestructure { input: closure_var.var_id, var_reqs }
+                    .add(&mut ctx, &mut builder.statements);
+            for (var, var_id) in zip_eq(&closure_expr.captured_vars, captured_vars) {
+                builder.put_semantic(var.var, var_id);
+            }
+
+            // Bind the parameters.
+            let var_reqs = closure_expr
+                .params
+                .iter()
+                .map(|pattern| {
+                    let pattern = &ctx.function_body.patterns[*pattern];
+                    VarRequest {
+                        ty: pattern.ty(),
+                        location: ctx.get_location(pattern.stable_ptr().untyped()),
+                    }
+                })
+                .collect();
+            let args = generators::StructDestructure { input: args_var, var_reqs }
+                .add(&mut ctx, &mut builder.statements);
+            for (pattern, var_id) in zip_eq(&closure_expr.params, args) {
+                let pattern = ctx.function_body.patterns[*pattern].clone();
+                let location = ctx.variables[var_id].location;
+                lower_single_pattern(
+                    &mut ctx,
+                    &mut builder,
+                    pattern,
+                    LoweredExpr::AtVariable(VarUsage { var_id, location }),
+                )?;
+            }
+
+            lower_expr(&mut ctx, &mut builder, closure_expr.body)
+        })();
+
+        let block_sealed = lowered_expr_to_block_scope_end(&mut ctx, builder, block_expr)?;
+        match block_sealed {
+            SealedBlockBuilder::GotoCallsite { mut builder, expr } => {
+                // Convert to a return.
+                let var_usage = expr.unwrap_or_else(|| {
+                    generators::StructConstruct {
+                        inputs: vec![],
+                        ty: unit_ty(ctx.db.upcast()),
+                        location,
+                    }
+                    .add(&mut ctx, &mut builder.statements)
+                });
+                builder.ret(&mut ctx, var_usage, location)?;
+            }
+            SealedBlockBuilder::Ends(_) => {}
+        }
+
+        Ok(root_block_id)
+    })();
+
+    let blocks = root_ok
+        .map(|_| ctx.blocks.build().expect("Root block must exist."))
+        .unwrap_or_else(FlatBlocks::new_errored);
+    Ok(FlatLowered {
+        diagnostics: ctx.diagnostics.build(),
+        variables: ctx.variables.variables,
+        blocks,
+        signature: ctx.signature.clone(),
+        parameters,
+    })
+}
+
 /// Lowers a sequence of expressions and return them all. If the flow ended in the middle,
 /// propagates that flow error without returning any variable.
 fn lower_exprs_to_var_usages(
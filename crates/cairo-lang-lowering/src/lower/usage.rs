@@ -149,6 +149,9 @@ impl BlockUsages {
                     match &function_body.statements[*stmt] {
                         Statement::Let(stmt) => {
                             self.handle_expr(function_body, stmt.expr, &mut usage);
+                            if let Some(else_clause) = stmt.else_clause {
+                                self.handle_expr(function_body, else_clause, &mut usage);
+                            }
                             Self::handle_pattern(&function_body.patterns, stmt.pattern, &mut usage);
                         }
                         Statement::Expr(stmt) => {
@@ -188,6 +191,27 @@ impl BlockUsages {
 
                 self.block_usages.insert(expr_id, usage);
             }
+            Expr::For(expr) => {
+                self.handle_expr(function_body, expr.expr_id, current);
+                // The iterator is introduced by the loop, and is used and changed by each
+                // iteration.
+                current.introductions.insert(expr.into_iter_member_path.base_var());
+                let mut usage = Usage::default();
+                usage.usage.insert(
+                    (&expr.into_iter_member_path).into(),
+                    expr.into_iter_member_path.clone(),
+                );
+                usage.changes.insert(
+                    (&expr.into_iter_member_path).into(),
+                    expr.into_iter_member_path.clone(),
+                );
+                Self::handle_pattern(&function_body.patterns, expr.pattern, &mut usage);
+                self.handle_expr(function_body, expr.body, &mut usage);
+                usage.finalize_as_scope();
+                current.add_usage_and_changes(&usage);
+
+                self.block_usages.insert(expr_id, usage);
+            }
             Expr::FunctionCall(expr) => {
                 for arg in &expr.args {
                     match arg {
@@ -250,7 +274,21 @@ impl BlockUsages {
                 self.handle_expr(function_body, expr.value_expr, current)
             }
             Expr::PropagateError(expr) => self.handle_expr(function_body, expr.inner, current),
-            Expr::Constant(_) => {}
+            Expr::Closure(expr) => {
+                // The body is lowered as a separate function, so its usage only matters for the
+                // blocks and loops it contains.
+                let mut usage = Usage::default();
+                for pattern in &expr.params {
+                    Self::handle_pattern(&function_body.patterns, *pattern, &mut usage);
+                }
+                self.handle_expr(function_body, expr.body, &mut usage);
+                for var in &expr.captured_vars {
+                    current
+                        .usage
+                        .insert(MemberPath::Var(var.var), ExprVarMemberPath::Var(var.clone()));
+                }
+            }
+            Expr::Constant(_) | Expr::ImplConstant(_) => {}
             Expr::Missing(_) => {}
         }
     }
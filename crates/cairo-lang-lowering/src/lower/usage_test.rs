@@ -53,6 +53,7 @@ fn test_function_usage(
             semantic::Expr::Block(_) => write!(usages_str, "Block").unwrap(),
             semantic::Expr::Loop(_) => write!(usages_str, "Loop").unwrap(),
             semantic::Expr::While(_) => write!(usages_str, "While").unwrap(),
+            semantic::Expr::For(_) => write!(usages_str, "For").unwrap(),
             _ => unreachable!(),
         }
         writeln!(usages_str, " {}:{}:", position.line, position.col).unwrap();
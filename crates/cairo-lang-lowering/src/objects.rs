@@ -15,8 +15,8 @@ use id_arena::{Arena, Id};
 use num_bigint::BigInt;
 pub mod blocks;
 pub use blocks::BlockId;
-use semantic::expr::inference::InferenceResult;
-use semantic::items::imp::ImplId;
+use semantic::expr::inference::{InferenceError, InferenceResult};
+use semantic::items::imp::{ImplConstantId, ImplId, ImplLookupContext};
 use semantic::MatchArmSelector;
 
 use self::blocks::FlatBlocks;
@@ -200,12 +200,45 @@ pub struct Variable {
     pub location: LocationId,
 }
 
+impl Variable {
+    pub fn new(
+        db: &dyn LoweringGroup,
+        lookup_context: ImplLookupContext,
+        ty: semantic::TypeId,
+        location: LocationId,
+    ) -> Self {
+        let ty_info = db.type_info(lookup_context, ty);
+        Self {
+            duplicatable: ty_info
+                .clone()
+                .map_err(InferenceError::Failed)
+                .and_then(|info| info.duplicatable),
+            droppable: ty_info
+                .clone()
+                .map_err(InferenceError::Failed)
+                .and_then(|info| info.droppable),
+            destruct_impl: ty_info
+                .clone()
+                .map_err(InferenceError::Failed)
+                .and_then(|info| info.destruct_impl),
+            panic_destruct_impl: ty_info
+                .map_err(InferenceError::Failed)
+                .and_then(|info| info.panic_destruct_impl),
+            ty,
+            location,
+        }
+    }
+}
+
 /// Lowered statement.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Statement {
     // Values.
     // TODO(spapini): Consts.
     Literal(StatementLiteral),
+    /// An associated constant of an impl that is not yet concrete. Replaced by the value of the
+    /// constant on concretization.
+    ImplConstant(StatementImplConstant),
 
     // Flow control.
     Call(StatementCall),
@@ -224,6 +257,7 @@ impl Statement {
     pub fn inputs(&self) -> Vec<VarUsage> {
         match &self {
             Statement::Literal(_stmt) => vec![],
+            Statement::ImplConstant(_stmt) => vec![],
             Statement::Call(stmt) => stmt.inputs.clone(),
             Statement::StructConstruct(stmt) => stmt.inputs.clone(),
             Statement::StructDestructure(stmt) => vec![stmt.input],
@@ -235,6 +269,7 @@ impl Statement {
     pub fn outputs(&self) -> Vec<VariableId> {
         match &self {
             Statement::Literal(stmt) => vec![stmt.output],
+            Statement::ImplConstant(stmt) => vec![stmt.output],
             Statement::Call(stmt) => stmt.outputs.clone(),
             Statement::StructConstruct(stmt) => vec![stmt.output],
             Statement::StructDestructure(stmt) => stmt.outputs.clone(),
@@ -247,6 +282,7 @@ impl Statement {
         // TODO(Gil): Add location to all statements.
         match &self {
             Statement::Literal(_) => None,
+            Statement::ImplConstant(_) => None,
             Statement::Call(stmt) => Some(stmt.location),
             Statement::StructConstruct(_) => None,
             Statement::StructDestructure(stmt) => Some(stmt.input.location),
@@ -266,6 +302,15 @@ pub struct StatementLiteral {
     pub output: VariableId,
 }
 
+/// A statement that binds the value of an associated constant of an impl to a variable.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StatementImplConstant {
+    /// The impl constant.
+    pub impl_constant_id: ImplConstantId,
+    /// The variable to bind the value to.
+    pub output: VariableId,
+}
+
 /// A statement that calls a user function.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StatementCall {
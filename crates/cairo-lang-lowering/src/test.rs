@@ -49,6 +49,7 @@ cairo_lang_test_utils::test_file_test!(
         extern_ :"extern",
         arm_pattern_destructure :"arm_pattern_destructure",
         if_ :"if",
+        let_else :"let_else",
         implicits :"implicits",
         logical_operator :"logical_operator",
         loop_ :"loop",
@@ -62,6 +63,7 @@ cairo_lang_test_utils::test_file_test!(
         tuple :"tuple",
         strings :"strings",
         while_ :"while",
+        for_ :"for",
     },
     test_function_lowering
 );
@@ -231,6 +233,7 @@ a = a * 3
     builder.add(LoweringDiagnostic {
         location,
         kind: LoweringDiagnosticKind::CannotInlineFunctionThatMightCallItself,
+        severity_override: None,
     });
 
     assert_eq!(
@@ -2,9 +2,9 @@ use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 
 use crate::{
     BlockId, FlatBlock, FlatBlockEnd, MatchArm, MatchEnumInfo, MatchEnumValue, MatchExternInfo,
-    MatchInfo, Statement, StatementCall, StatementDesnap, StatementEnumConstruct, StatementLiteral,
-    StatementSnapshot, StatementStructConstruct, StatementStructDestructure, VarRemapping,
-    VarUsage, VariableId,
+    MatchInfo, Statement, StatementCall, StatementDesnap, StatementEnumConstruct,
+    StatementImplConstant, StatementLiteral, StatementSnapshot, StatementStructConstruct,
+    StatementStructDestructure, VarRemapping, VarUsage, VariableId,
 };
 
 /// A rebuilder trait for rebuilding lowered representation.
@@ -28,6 +28,10 @@ pub trait RebuilderEx: Rebuilder {
                 value: stmt.value.clone(),
                 output: self.map_var_id(stmt.output),
             }),
+            Statement::ImplConstant(stmt) => Statement::ImplConstant(StatementImplConstant {
+                impl_constant_id: stmt.impl_constant_id,
+                output: self.map_var_id(stmt.output),
+            }),
             Statement::Call(stmt) => Statement::Call(StatementCall {
                 function: stmt.function,
                 inputs: stmt.inputs.iter().map(|v| self.map_var_usage(*v)).collect(),
@@ -1,4 +1,4 @@
-use cairo_lang_diagnostics::DiagnosticEntry;
+use cairo_lang_diagnostics::{error_code, DiagnosticEntry, ErrorCode};
 use cairo_lang_filesystem::db::FilesGroup;
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_filesystem::span::TextSpan;
@@ -38,6 +38,7 @@ pub enum ParserDiagnosticKind {
     AttributesWithoutImplItem,
     AttributesWithoutStatement,
     DisallowedTrailingSeparatorOr,
+    MissingForLoopIn,
 }
 impl DiagnosticEntry for ParserDiagnostic {
     type DbType = dyn FilesGroup;
@@ -120,10 +121,44 @@ Did you mean to write `{identifier}!{left}...{right}'?",
             ParserDiagnosticKind::DisallowedTrailingSeparatorOr => {
                 "A trailing `|` is not allowed in an or-pattern.".to_string()
             }
+            ParserDiagnosticKind::MissingForLoopIn => {
+                "Missing tokens. Expected 'in' after the pattern of a 'for' loop.".to_string()
+            }
         }
     }
 
     fn location(&self, _db: &dyn FilesGroup) -> cairo_lang_diagnostics::DiagnosticLocation {
         cairo_lang_diagnostics::DiagnosticLocation { file_id: self.file_id, span: self.span }
     }
+
+    /// Parser diagnostics use the codes `E0001`-`E0099`. New kinds must be given new codes.
+    fn error_code(&self) -> Option<ErrorCode> {
+        Some(match &self.kind {
+            ParserDiagnosticKind::SkippedElement { .. } => error_code!("E0001"),
+            ParserDiagnosticKind::MissingToken(_) => error_code!("E0002"),
+            ParserDiagnosticKind::MissingExpression => error_code!("E0003"),
+            ParserDiagnosticKind::MissingPathSegment => error_code!("E0004"),
+            ParserDiagnosticKind::MissingTypeClause => error_code!("E0005"),
+            ParserDiagnosticKind::MissingTypeExpression => error_code!("E0006"),
+            ParserDiagnosticKind::MissingWrappedArgList => error_code!("E0007"),
+            ParserDiagnosticKind::MissingPatteren => error_code!("E0008"),
+            ParserDiagnosticKind::ItemInlineMacroWithoutBang { .. } => error_code!("E0009"),
+            ParserDiagnosticKind::ReservedIdentifier { .. } => error_code!("E0010"),
+            ParserDiagnosticKind::UnderscoreNotAllowedAsIdentifier => error_code!("E0011"),
+            ParserDiagnosticKind::MissingLiteralSuffix => error_code!("E0012"),
+            ParserDiagnosticKind::InvalidNumericLiteralValue => error_code!("E0013"),
+            ParserDiagnosticKind::IllegalStringEscaping => error_code!("E0014"),
+            ParserDiagnosticKind::ShortStringMustBeAscii => error_code!("E0015"),
+            ParserDiagnosticKind::StringMustBeAscii => error_code!("E0016"),
+            ParserDiagnosticKind::UnterminatedShortString => error_code!("E0017"),
+            ParserDiagnosticKind::UnterminatedString => error_code!("E0018"),
+            ParserDiagnosticKind::VisibilityWithoutItem => error_code!("E0019"),
+            ParserDiagnosticKind::AttributesWithoutItem => error_code!("E0020"),
+            ParserDiagnosticKind::AttributesWithoutTraitItem => error_code!("E0021"),
+            ParserDiagnosticKind::AttributesWithoutImplItem => error_code!("E0022"),
+            ParserDiagnosticKind::AttributesWithoutStatement => error_code!("E0023"),
+            ParserDiagnosticKind::DisallowedTrailingSeparatorOr => error_code!("E0024"),
+            ParserDiagnosticKind::MissingForLoopIn => error_code!("E0025"),
+        })
+    }
 }
@@ -199,6 +199,7 @@ impl<'a> Lexer<'a> {
             "match" => TokenKind::Match,
             "if" => TokenKind::If,
             "loop" => TokenKind::Loop,
+            "for" => TokenKind::For,
             "continue" => TokenKind::Continue,
             "break" => TokenKind::Break,
             "else" => TokenKind::Else,
@@ -253,7 +254,13 @@ impl<'a> Lexer<'a> {
                 ']' => self.take_token_of_kind(TokenKind::RBrack),
                 '(' => self.take_token_of_kind(TokenKind::LParen),
                 ')' => self.take_token_of_kind(TokenKind::RParen),
-                '.' => self.pick_kind('.', TokenKind::DotDot, TokenKind::Dot),
+                '.' => {
+                    self.take();
+                    match self.peek() {
+                        Some('.') => self.pick_kind('=', TokenKind::DotDotEq, TokenKind::DotDot),
+                        _ => TokenKind::Dot,
+                    }
+                }
                 '*' => self.pick_kind('=', TokenKind::MulEq, TokenKind::Mul),
                 '/' => self.pick_kind('=', TokenKind::DivEq, TokenKind::Div),
                 '%' => self.pick_kind('=', TokenKind::ModEq, TokenKind::Mod),
@@ -363,6 +370,7 @@ enum TokenKind {
     If,
     While,
     Loop,
+    For,
     Continue,
     Break,
     Else,
@@ -406,6 +414,7 @@ enum TokenKind {
     Comma,
     Dot,
     DotDot,
+    DotDotEq,
     Eq,
     Hash,
     Semicolon,
@@ -450,6 +459,7 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::If => SyntaxKind::TerminalIf,
         TokenKind::While => SyntaxKind::TerminalWhile,
         TokenKind::Loop => SyntaxKind::TerminalLoop,
+        TokenKind::For => SyntaxKind::TerminalFor,
         TokenKind::Continue => SyntaxKind::TerminalContinue,
         TokenKind::Break => SyntaxKind::TerminalBreak,
         TokenKind::Else => SyntaxKind::TerminalElse,
@@ -486,6 +496,7 @@ fn token_kind_to_terminal_syntax_kind(kind: TokenKind) -> SyntaxKind {
         TokenKind::Comma => SyntaxKind::TerminalComma,
         TokenKind::Dot => SyntaxKind::TerminalDot,
         TokenKind::DotDot => SyntaxKind::TerminalDotDot,
+        TokenKind::DotDotEq => SyntaxKind::TerminalDotDotEq,
         TokenKind::Eq => SyntaxKind::TerminalEq,
         TokenKind::Hash => SyntaxKind::TerminalHash,
         TokenKind::Semicolon => SyntaxKind::TerminalSemicolon,
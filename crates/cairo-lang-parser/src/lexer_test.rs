@@ -58,6 +58,7 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
         SyntaxKind::TerminalMatch => vec!["match"],
         SyntaxKind::TerminalIf => vec!["if"],
         SyntaxKind::TerminalLoop => vec!["loop"],
+        SyntaxKind::TerminalFor => vec!["for"],
         SyntaxKind::TerminalBreak => vec!["break"],
         SyntaxKind::TerminalElse => vec!["else"],
         SyntaxKind::TerminalUse => vec!["use"],
@@ -73,6 +74,7 @@ fn terminal_kind_to_text(kind: SyntaxKind) -> Vec<&'static str> {
         SyntaxKind::TerminalModEq => vec!["%="],
         SyntaxKind::TerminalDot => vec!["."],
         SyntaxKind::TerminalDotDot => vec![".."],
+        SyntaxKind::TerminalDotDotEq => vec!["..="],
         SyntaxKind::TerminalEq => vec!["="],
         SyntaxKind::TerminalEqEq => vec!["=="],
         SyntaxKind::TerminalGE => vec![">="],
@@ -133,6 +135,7 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
         SyntaxKind::TerminalMatch,
         SyntaxKind::TerminalIf,
         SyntaxKind::TerminalLoop,
+        SyntaxKind::TerminalFor,
         SyntaxKind::TerminalBreak,
         SyntaxKind::TerminalElse,
         SyntaxKind::TerminalUse,
@@ -164,6 +167,7 @@ fn terminal_kinds() -> Vec<SyntaxKind> {
         SyntaxKind::TerminalComma,
         SyntaxKind::TerminalDot,
         SyntaxKind::TerminalDotDot,
+        SyntaxKind::TerminalDotDotEq,
         SyntaxKind::TerminalEq,
         SyntaxKind::TerminalSemicolon,
         SyntaxKind::TerminalQuestionMark,
@@ -218,6 +222,7 @@ fn need_separator(
         || ((text0 == "<" || text0 == ">") && text1.starts_with('='))
         || (text0 == ":" && text1.starts_with(':'))
         || (text0 == "." && text1.starts_with('.'))
+        || (text0 == ".." && text1.starts_with('='))
         || (text0 == "-" && (text1.starts_with('>') || text1.starts_with('=')))
         || ((text0 == "+" || text0 == "*" || text0 == "/" || text0 == "%")
             && text1.starts_with('='))
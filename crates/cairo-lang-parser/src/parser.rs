@@ -365,7 +365,8 @@ impl<'a> Parser<'a> {
     }
 
     /// Assumes the current token is Struct.
-    /// Expected pattern: `struct<Identifier>{<ParamList>}`
+    /// Expected pattern: `struct<Identifier>{<ParamList>}`, `struct<Identifier>(<TypeList>);` or
+    /// `struct<Identifier>;`
     fn expect_item_struct(
         &mut self,
         attributes: AttributeListGreen,
@@ -374,9 +375,22 @@ impl<'a> Parser<'a> {
         let struct_kw = self.take::<TerminalStruct>();
         let name = self.parse_identifier();
         let generic_params = self.parse_optional_generic_params();
-        let lbrace = self.parse_token::<TerminalLBrace>();
-        let members = self.parse_member_list();
-        let rbrace = self.parse_token::<TerminalRBrace>();
+        let body = match self.peek().kind {
+            SyntaxKind::TerminalLParen => {
+                let lparen = self.take::<TerminalLParen>();
+                let members = self.parse_tuple_member_list();
+                let rparen = self.parse_token::<TerminalRParen>();
+                let semicolon = self.parse_token::<TerminalSemicolon>();
+                StructBodyTuple::new_green(self.db, lparen, members, rparen, semicolon).into()
+            }
+            SyntaxKind::TerminalSemicolon => self.take::<TerminalSemicolon>().into(),
+            _ => {
+                let lbrace = self.parse_token::<TerminalLBrace>();
+                let members = self.parse_member_list();
+                let rbrace = self.parse_token::<TerminalRBrace>();
+                StructBodyNamed::new_green(self.db, lbrace, members, rbrace).into()
+            }
+        };
         ItemStruct::new_green(
             self.db,
             attributes,
@@ -384,9 +398,7 @@ impl<'a> Parser<'a> {
             struct_kw,
             name,
             generic_params,
-            lbrace,
-            members,
-            rbrace,
+            body,
         )
     }
 
@@ -566,8 +578,10 @@ impl<'a> Parser<'a> {
 
     /// Returns a GreenId of a node with a UsePath kind or TryParseFailure if can't parse a UsePath.
     fn try_parse_use_path(&mut self) -> TryParseResult<UsePathGreen> {
-        if !matches!(self.peek().kind, SyntaxKind::TerminalLBrace | SyntaxKind::TerminalIdentifier)
-        {
+        if !matches!(
+            self.peek().kind,
+            SyntaxKind::TerminalLBrace | SyntaxKind::TerminalIdentifier | SyntaxKind::TerminalMul
+        ) {
             return Err(TryParseFailure::SkipToken);
         }
         Ok(self.parse_use_path())
@@ -587,6 +601,9 @@ impl<'a> Parser<'a> {
                     ));
             let rbrace = self.parse_token::<TerminalRBrace>();
             UsePathMulti::new_green(self.db, lbrace, items, rbrace).into()
+        } else if self.peek().kind == SyntaxKind::TerminalMul {
+            let star = self.take::<TerminalMul>();
+            UsePathStar::new_green(self.db, star).into()
         } else if let Ok(ident) = self.try_parse_identifier() {
             let ident = PathSegmentSimple::new_green(self.db, ident).into();
             match self.peek().kind {
@@ -675,13 +692,25 @@ impl<'a> Parser<'a> {
             OptionVisibilityPubArgumentClauseEmpty::new_green(self.db).into()
         } else {
             let lparen = self.parse_token::<TerminalLParen>();
-            let argument = self.parse_token::<TerminalIdentifier>();
+            let argument = self.parse_visibility_pub_argument();
             let rparen = self.parse_token::<TerminalRParen>();
             VisibilityPubArgumentClause::new_green(self.db, lparen, argument, rparen).into()
         };
         Some(VisibilityPub::new_green(self.db, pub_kw, argument_clause))
     }
 
+    /// Returns a GreenId of the argument of a `pub(...)` visibility, which is either an identifier
+    /// (e.g. `crate` or `super`) or `in` followed by a module path.
+    fn parse_visibility_pub_argument(&mut self) -> VisibilityPubArgumentGreen {
+        if self.peek().kind == SyntaxKind::TerminalIdentifier && self.peek().text == "in" {
+            let in_kw = self.take::<TerminalIdentifier>();
+            let path = self.parse_path();
+            VisibilityPubArgumentIn::new_green(self.db, in_kw, path).into()
+        } else {
+            self.parse_token::<TerminalIdentifier>().into()
+        }
+    }
+
     /// Returns a GreenId of a node with an attribute list kind or TryParseFailure if an attribute
     /// list can't be parsed.
     /// `expected_elements_str` are the expected elements that these attributes are parsed for.
@@ -1066,6 +1095,8 @@ impl<'a> Parser<'a> {
             SyntaxKind::TerminalOrOr => self.take::<TerminalOrOr>().into(),
             SyntaxKind::TerminalOr => self.take::<TerminalOr>().into(),
             SyntaxKind::TerminalXor => self.take::<TerminalXor>().into(),
+            SyntaxKind::TerminalDotDot => self.take::<TerminalDotDot>().into(),
+            SyntaxKind::TerminalDotDotEq => self.take::<TerminalDotDotEq>().into(),
             _ => unreachable!(),
         }
     }
@@ -1195,6 +1226,12 @@ impl<'a> Parser<'a> {
             SyntaxKind::TerminalWhile if lbrace_allowed == LbraceAllowed::Allow => {
                 Ok(self.expect_while_expr().into())
             }
+            SyntaxKind::TerminalFor if lbrace_allowed == LbraceAllowed::Allow => {
+                Ok(self.expect_for_expr().into())
+            }
+            SyntaxKind::TerminalOr | SyntaxKind::TerminalOrOr => {
+                Ok(self.expect_closure_expr(lbrace_allowed).into())
+            }
 
             _ => {
                 // TODO(yuval): report to diagnostics.
@@ -1662,6 +1699,25 @@ impl<'a> Parser<'a> {
         ExprWhile::new_green(self.db, while_kw, condition, body)
     }
 
+    /// Assumes the current token is `For`.
+    /// Expected pattern: `for <pattern> in <expression> <block>`.
+    fn expect_for_expr(&mut self) -> ExprForGreen {
+        let for_kw = self.take::<TerminalFor>();
+        let pattern = self.parse_pattern();
+        let in_identifier =
+            if self.peek().kind == SyntaxKind::TerminalIdentifier && self.peek().text == "in" {
+                self.take::<TerminalIdentifier>()
+            } else {
+                self.create_and_report_missing::<TerminalIdentifier>(
+                    ParserDiagnosticKind::MissingForLoopIn,
+                )
+            };
+        let expr = self.parse_expr_limited(MAX_PRECEDENCE, LbraceAllowed::Forbid);
+        let body = self.parse_block();
+
+        ExprFor::new_green(self.db, for_kw, pattern, in_identifier, expr, body)
+    }
+
     /// Returns a GreenId of a node with a MatchArm kind or TryParseFailure if a match arm can't be
     /// parsed.
     pub fn try_parse_match_arm(&mut self) -> TryParseResult<MatchArmGreen> {
@@ -1683,6 +1739,43 @@ impl<'a> Parser<'a> {
         Ok(MatchArm::new_green(self.db, pattern_list_green, arrow, expr))
     }
 
+    /// Assumes the current token is `|` or `||`.
+    /// Expected pattern: `|<ClosureParamList>| <OptionReturnTypeClause> <Expr>`, or
+    /// `|| <OptionReturnTypeClause> <Expr>` for a closure without parameters.
+    fn expect_closure_expr(&mut self, lbrace_allowed: LbraceAllowed) -> ExprClosureGreen {
+        let wrapper = if self.peek().kind == SyntaxKind::TerminalOrOr {
+            self.take::<TerminalOrOr>().into()
+        } else {
+            let leftor = self.take::<TerminalOr>();
+            let params = self.parse_closure_param_list();
+            let rightor = self.parse_token::<TerminalOr>();
+            ClosureParamWrapperNAry::new_green(self.db, leftor, params, rightor).into()
+        };
+        let ret_ty = self.parse_option_return_type_clause();
+        let expr = self.parse_expr_limited(MAX_PRECEDENCE, lbrace_allowed);
+        ExprClosure::new_green(self.db, wrapper, ret_ty, expr)
+    }
+
+    /// Returns a GreenId of a node with kind ClosureParamList.
+    fn parse_closure_param_list(&mut self) -> ClosureParamListGreen {
+        ClosureParamList::new_green(
+            self.db,
+            self.parse_separated_list::<ClosureParam, TerminalComma, ClosureParamListElementOrSeparatorGreen>(
+                Self::try_parse_closure_param,
+                is_of_kind!(or, semicolon, rparen, block, lbrace, rbrace, module_item_kw),
+                "closure parameter",
+            ),
+        )
+    }
+
+    /// Returns a GreenId of a node with kind ClosureParam or TryParseFailure if a closure
+    /// parameter can't be parsed.
+    fn try_parse_closure_param(&mut self) -> TryParseResult<ClosureParamGreen> {
+        let pattern = self.try_parse_pattern()?;
+        let type_clause = self.parse_option_type_clause();
+        Ok(ClosureParam::new_green(self.db, pattern, type_clause))
+    }
+
     /// Returns a GreenId of a node with some Pattern kind (see
     /// [syntax::node::ast::Pattern]) or TryParseFailure if a pattern can't be parsed.
     fn try_parse_pattern(&mut self) -> TryParseResult<PatternGreen> {
@@ -1723,13 +1816,44 @@ impl<'a> Parser<'a> {
                         PatternStruct::new_green(self.db, path, lbrace, params, rbrace).into()
                     }
                     SyntaxKind::TerminalLParen => {
-                        // Enum pattern.
                         let lparen = self.take::<TerminalLParen>();
+                        if self.peek().kind == SyntaxKind::TerminalRParen {
+                            // Tuple struct pattern without members.
+                            let patterns = PatternList::new_green(self.db, vec![]);
+                            let rparen = self.take::<TerminalRParen>();
+                            return Ok(PatternTupleStruct::new_green(
+                                self.db, path, lparen, patterns, rparen,
+                            )
+                            .into());
+                        }
                         let pattern = self.parse_pattern();
+                        if self.peek().kind != SyntaxKind::TerminalComma {
+                            // Enum pattern, or a tuple struct pattern with a single member.
+                            let rparen = self.parse_token::<TerminalRParen>();
+                            let inner_pattern = PatternEnumInnerPattern::new_green(
+                                self.db, lparen, pattern, rparen,
+                            );
+                            return Ok(
+                                PatternEnum::new_green(self.db, path, inner_pattern.into()).into()
+                            );
+                        }
+                        // Tuple struct pattern.
+                        let comma = self.take::<TerminalComma>();
+                        let mut elements: Vec<PatternListElementOrSeparatorGreen> =
+                            vec![pattern.into(), comma.into()];
+                        elements.extend(self.parse_separated_list::<
+                            Pattern,
+                            TerminalComma,
+                            PatternListElementOrSeparatorGreen,
+                        >(
+                            Self::try_parse_pattern,
+                            is_of_kind!(rparen, block, rbrace, module_item_kw),
+                            "pattern",
+                        ));
+                        let patterns = PatternList::new_green(self.db, elements);
                         let rparen = self.parse_token::<TerminalRParen>();
-                        let inner_pattern =
-                            PatternEnumInnerPattern::new_green(self.db, lparen, pattern, rparen);
-                        PatternEnum::new_green(self.db, path, inner_pattern.into()).into()
+                        PatternTupleStruct::new_green(self.db, path, lparen, patterns, rparen)
+                            .into()
                     }
                     _ => {
                         let green_node = self.db.lookup_intern_green(path.0);
@@ -1823,6 +1947,7 @@ impl<'a> Parser<'a> {
                 let type_clause = self.parse_option_type_clause();
                 let eq = self.parse_token::<TerminalEq>();
                 let rhs = self.parse_expr();
+                let let_else_clause = self.parse_option_let_else_clause();
                 let semicolon = self.parse_token::<TerminalSemicolon>();
                 Ok(StatementLet::new_green(
                     self.db,
@@ -1832,6 +1957,7 @@ impl<'a> Parser<'a> {
                     type_clause,
                     eq,
                     rhs,
+                    let_else_clause,
                     semicolon,
                 )
                 .into())
@@ -1873,6 +1999,17 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Returns a GreenId of a node with kind LetElseClause or OptionLetElseClauseEmpty if the let
+    /// statement has no else clause.
+    fn parse_option_let_else_clause(&mut self) -> OptionLetElseClauseGreen {
+        if self.peek().kind != SyntaxKind::TerminalElse {
+            return OptionLetElseClauseEmpty::new_green(self.db).into();
+        }
+        let else_kw = self.take::<TerminalElse>();
+        let else_block = self.parse_block();
+        LetElseClause::new_green(self.db, else_kw, else_block).into()
+    }
+
     /// Returns a GreenId of a node with kind TypeClause or OptionTypeClauseEmpty if a type clause
     /// can't be parsed.
     fn parse_option_type_clause(&mut self) -> OptionTypeClauseGreen {
@@ -2020,6 +2157,34 @@ impl<'a> Parser<'a> {
         Ok(Member::new_green(self.db, attributes, visibility, name, type_clause))
     }
 
+    /// Returns a GreenId of a node with kind TupleMemberList.
+    fn parse_tuple_member_list(&mut self) -> TupleMemberListGreen {
+        TupleMemberList::new_green(
+            self.db,
+            self.parse_separated_list::<
+                TupleMember,
+                TerminalComma,
+                TupleMemberListElementOrSeparatorGreen,
+            >(
+                Self::try_parse_tuple_member,
+                is_of_kind!(rparen, block, lbrace, rbrace, module_item_kw),
+                "tuple struct member",
+            ),
+        )
+    }
+
+    /// Returns a GreenId of a node with kind TupleMember or TryParseFailure if a tuple struct
+    /// member can't be parsed.
+    fn try_parse_tuple_member(&mut self) -> TryParseResult<TupleMemberGreen> {
+        let attributes = self.try_parse_attribute_list("Tuple struct member");
+        let visibility = self.parse_visibility();
+        let (ty, attributes) = match attributes {
+            Ok(attributes) => (self.parse_type_expr(), attributes),
+            Err(_) => (self.try_parse_type_expr()?, AttributeList::new_green(self.db, vec![])),
+        };
+        Ok(TupleMember::new_green(self.db, attributes, visibility, ty))
+    }
+
     /// Returns a GreenId of a node with kind VariantList.
     fn parse_variant_list(&mut self) -> VariantListGreen {
         VariantList::new_green(
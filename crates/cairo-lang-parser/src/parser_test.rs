@@ -163,6 +163,7 @@ cairo_lang_test_utils::test_file_test!(
     {
         constant: "constant",
         enum_: "enum",
+        struct_: "struct",
         expr: "expr",
         loop_: "loop",
         item_free_function: "item_free_function",
@@ -186,6 +187,10 @@ cairo_lang_test_utils::test_file_test!(
         generics: "generics",
         generic_params: "generic_params",
         while_: "while",
+        for_: "for",
+        range: "range",
+        closure: "closure",
+        use_: "use",
 },
     test_partial_parser_tree
 );
@@ -62,6 +62,13 @@ macro_rules! comma {
 }
 pub(crate) use comma;
 
+macro_rules! or {
+    () => {
+        SyntaxKind::TerminalOr
+    };
+}
+pub(crate) use or;
+
 macro_rules! semicolon {
     () => {
         SyntaxKind::TerminalSemicolon
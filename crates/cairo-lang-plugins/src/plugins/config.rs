@@ -4,6 +4,7 @@ use cairo_lang_defs::patcher::PatchBuilder;
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
 use cairo_lang_syntax::attribute::structured::{
     Attribute, AttributeArg, AttributeArgVariant, AttributeStructurize,
@@ -199,10 +200,13 @@ fn parse_predicate_item(
 ) -> Option<Cfg> {
     match arg.variant {
         AttributeArgVariant::FieldInitShorthand { .. } => {
-            diagnostics.push(PluginDiagnostic::error(
-                arg.arg_stable_ptr.untyped(),
-                "This attribute does not support field initialization shorthands.".into(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    arg.arg_stable_ptr.untyped(),
+                    "This attribute does not support field initialization shorthands.".into(),
+                )
+                .with_error_code(error_code!("E0520")),
+            );
             None
         }
         AttributeArgVariant::Named { name, value, value_stable_ptr, .. } => {
@@ -210,10 +214,13 @@ fn parse_predicate_item(
                 ast::Expr::ShortString(terminal) => terminal.string_value(db).unwrap_or_default(),
                 ast::Expr::String(terminal) => terminal.string_value(db).unwrap_or_default(),
                 _ => {
-                    diagnostics.push(PluginDiagnostic::error(
-                        value_stable_ptr.untyped(),
-                        "Expected a string/short-string literal.".into(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            value_stable_ptr.untyped(),
+                            "Expected a string/short-string literal.".into(),
+                        )
+                        .with_error_code(error_code!("E0521")),
+                    );
                     return None;
                 }
             };
@@ -222,17 +229,23 @@ fn parse_predicate_item(
         }
         AttributeArgVariant::Unnamed { value, value_stable_ptr, .. } => {
             let ast::Expr::Path(path) = value else {
-                diagnostics.push(PluginDiagnostic::error(
-                    value_stable_ptr.untyped(),
-                    "Expected identifier.".into(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        value_stable_ptr.untyped(),
+                        "Expected identifier.".into(),
+                    )
+                    .with_error_code(error_code!("E0522")),
+                );
                 return None;
             };
             let [ast::PathSegment::Simple(segment)] = &path.elements(db)[..] else {
-                diagnostics.push(PluginDiagnostic::error(
-                    value_stable_ptr.untyped(),
-                    "Expected simple path.".into(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        value_stable_ptr.untyped(),
+                        "Expected simple path.".into(),
+                    )
+                    .with_error_code(error_code!("E0523")),
+                );
                 return None;
             };
             let key = segment.ident(db).text(db);
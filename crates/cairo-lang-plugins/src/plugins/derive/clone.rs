@@ -27,19 +27,9 @@ pub fn handle_clone(info: &DeriveInfo, stable_ptr: SyntaxStablePtrId, result: &m
                         variant=variant.name,
                     )).join("\n    ")}
             }
-            TypeVariantInfo::Struct(members) => {
-                formatdoc! {"
-                {name} {{
-                    {}
-                }}",
-                    indent_by(4, members.iter().map(|member| {
-                        format!(
-                            "{member}: core::clone::Clone::clone(self.{member}),",
-                            member=member.name,
-                        )
-                    }).join("\n"))
-                }
-            }
+            TypeVariantInfo::Struct(kind, members) => kind.format_ctor(name, members, |member| {
+                format!("core::clone::Clone::clone(self.{})", member.name)
+            }),
             TypeVariantInfo::Extern => {
                 result.diagnostics.push(unsupported_for_extern_diagnostic(stable_ptr));
                 return;
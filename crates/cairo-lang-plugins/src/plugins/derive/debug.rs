@@ -4,7 +4,7 @@ use indoc::formatdoc;
 use itertools::Itertools;
 
 use super::{unsupported_for_extern_diagnostic, DeriveInfo, DeriveResult};
-use crate::plugins::derive::TypeVariantInfo;
+use crate::plugins::derive::{StructKind, TypeVariantInfo};
 
 /// Adds derive result for the `Debug` trait.
 pub fn handle_debug(info: &DeriveInfo, stable_ptr: SyntaxStablePtrId, result: &mut DeriveResult) {
@@ -33,7 +33,7 @@ pub fn handle_debug(info: &DeriveInfo, stable_ptr: SyntaxStablePtrId, result: &m
                         variant=variant.name,
                     )).join("\n"))}
             }
-            TypeVariantInfo::Struct(members) => {
+            TypeVariantInfo::Struct(StructKind::Named, members) => {
                 formatdoc!(
                     "
                         write!(f, \"{name} {{{{\")?;{}
@@ -50,6 +50,23 @@ pub fn handle_debug(info: &DeriveInfo, stable_ptr: SyntaxStablePtrId, result: &m
                         .join("\nwrite!(f, \",\")?;"),
                 )
             }
+            TypeVariantInfo::Struct(StructKind::Tuple, members) => {
+                formatdoc!(
+                    "
+                        write!(f, \"{name}(\")?;{}
+                        write!(f, \")\")",
+                    members
+                        .iter()
+                        .map(|member| formatdoc!(
+                            "
+
+                                core::fmt::Debug::fmt(self.{member}, ref f)?;",
+                            member = member.name
+                        ))
+                        .join("\nwrite!(f, \", \")?;"),
+                )
+            }
+            TypeVariantInfo::Struct(StructKind::Unit, _) => format!("write!(f, \"{name}\")"),
             TypeVariantInfo::Extern => {
                 result.diagnostics.push(unsupported_for_extern_diagnostic(stable_ptr));
                 return;
@@ -1,11 +1,11 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::TypedSyntaxNode;
 use indent::indent_by;
 use indoc::formatdoc;
-use itertools::Itertools;
 
 use super::{unsupported_for_extern_diagnostic, DeriveInfo, DeriveResult};
 use crate::plugins::derive::TypeVariantInfo;
@@ -34,33 +34,30 @@ pub fn handle_default(
                     Some((variant, variant.attributes.find_attr(db, DEFAULT_ATTR)?))
                 });
                 let Some((default_variant, _)) = default_variants.next() else {
-                    result.diagnostics.push(PluginDiagnostic::error(
-                        stable_ptr,
-                        "derive `Default` for enum only supported with a default variant.".into(),
-                    ));
+                    result.diagnostics.push(
+                        PluginDiagnostic::error(
+                            stable_ptr,
+                            "derive `Default` for enum only supported with a default variant."
+                                .into(),
+                        )
+                        .with_error_code(error_code!("E0524")),
+                    );
                     return;
                 };
                 for (_, extra_default_attr) in default_variants {
-                    result.diagnostics.push(PluginDiagnostic::error(
-                        extra_default_attr.as_syntax_node().stable_ptr(),
-                        "Multiple variants annotated with `#[default]`".into(),
-                    ));
+                    result.diagnostics.push(
+                        PluginDiagnostic::error(
+                            extra_default_attr.as_syntax_node().stable_ptr(),
+                            "Multiple variants annotated with `#[default]`".into(),
+                        )
+                        .with_error_code(error_code!("E0525")),
+                    );
                 }
                 let default_variant = &default_variant.name;
                 formatdoc!("{ty}::{default_variant}(core::traits::Default::default())")
             }
-            TypeVariantInfo::Struct(members) => {
-                formatdoc! {"
-                    {ty} {{
-                        {}
-                    }}",
-                    indent_by(4, members.iter().map(|member| {
-                        format!(
-                            "{member}: core::traits::Default::default(),",
-                            member=member.name,
-                        )
-                    }).join("\n"))
-                }
+            TypeVariantInfo::Struct(kind, members) => {
+                kind.format_ctor(ty, members, |_| "core::traits::Default::default()".to_string())
             }
             TypeVariantInfo::Extern => {
                 result.diagnostics.push(unsupported_for_extern_diagnostic(stable_ptr));
@@ -31,15 +31,15 @@ pub fn handle_destruct(
                     }).join("\n    ")
                 }
             }
-            TypeVariantInfo::Struct(members) => {
+            TypeVariantInfo::Struct(kind, members) => {
                 format!(
-                    "let {ty} {{ {} }} = self;{}",
-                    members.iter().map(|member| &member.name).join(", "),
+                    "let {} = self;{}",
+                    kind.format_pattern(ty, members),
                     members
                         .iter()
                         .map(|member| format!(
                             "\ncore::traits::Destruct::destruct({});",
-                            member.name
+                            kind.member_binding(member)
                         ))
                         .join(""),
                 )
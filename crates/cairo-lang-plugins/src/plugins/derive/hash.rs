@@ -29,7 +29,7 @@ pub fn handle_hash(info: &DeriveInfo, stable_ptr: SyntaxStablePtrId, result: &mu
                     ).join("\n"))
                 }
             }
-            TypeVariantInfo::Struct(members) => format!(
+            TypeVariantInfo::Struct(_, members) => format!(
                 "{}\nstate",
                 members
                     .iter()
@@ -1,16 +1,16 @@
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{
     AttributeArg, AttributeArgVariant, AttributeStructurize,
 };
-use cairo_lang_syntax::node::ast::{
-    AttributeList, MemberList, OptionWrappedGenericParamList, VariantList,
-};
+use cairo_lang_syntax::node::ast::{AttributeList, OptionWrappedGenericParamList, VariantList};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::{GenericParamEx, QueryAttrs};
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
+use indoc::formatdoc;
 use itertools::{chain, Itertools};
 use smol_str::SmolStr;
 
@@ -44,7 +44,10 @@ impl MacroPlugin for DerivePlugin {
                     struct_ast.name(db),
                     struct_ast.attributes(db),
                     struct_ast.generic_params(db),
-                    TypeVariantInfo::Struct(extract_members(db, struct_ast.members(db))),
+                    TypeVariantInfo::Struct(
+                        StructKind::from_ast(&struct_ast.body(db)),
+                        extract_members(db, struct_ast.members(db)),
+                    ),
                 ),
                 ast::ModuleItem::Enum(enum_ast) => DeriveInfo::new(
                     db,
@@ -80,10 +83,72 @@ struct MemberInfo {
 /// Information on the type being derived.
 enum TypeVariantInfo {
     Enum(Vec<MemberInfo>),
-    Struct(Vec<MemberInfo>),
+    Struct(StructKind, Vec<MemberInfo>),
     Extern,
 }
 
+/// The way the members of a struct are declared.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StructKind {
+    /// Named members, e.g. `struct A { a: felt252 }`.
+    Named,
+    /// Positional members, e.g. `struct A(felt252);`.
+    Tuple,
+    /// No members, e.g. `struct A;`.
+    Unit,
+}
+impl StructKind {
+    fn from_ast(body: &ast::StructBody) -> Self {
+        match body {
+            ast::StructBody::Named(_) => Self::Named,
+            ast::StructBody::Tuple(_) => Self::Tuple,
+            ast::StructBody::Unit(_) => Self::Unit,
+        }
+    }
+
+    /// Returns the name of the variable holding the value of a member in [Self::format_pattern].
+    fn member_binding(&self, member: &MemberInfo) -> String {
+        match self {
+            Self::Named | Self::Unit => member.name.to_string(),
+            Self::Tuple => format!("__{}", member.name),
+        }
+    }
+
+    /// Formats a constructor of the struct `ty`, with the members values given by `value`.
+    fn format_ctor(
+        &self,
+        ty: &str,
+        members: &[MemberInfo],
+        value: impl Fn(&MemberInfo) -> String,
+    ) -> String {
+        match self {
+            Self::Named | Self::Unit => formatdoc! {"
+                {ty} {{
+                    {}
+                }}",
+                members.iter().map(|member| format!("{}: {},", member.name, value(member)))
+                    .join("\n    ")
+            },
+            Self::Tuple => formatdoc! {"
+                {ty}(
+                    {}
+                )",
+                members.iter().map(|member| format!("{},", value(member))).join("\n    ")
+            },
+        }
+    }
+
+    /// Formats a pattern destructuring the struct `ty`, binding each member to the variable named
+    /// by [Self::member_binding].
+    fn format_pattern(&self, ty: &str, members: &[MemberInfo]) -> String {
+        let bindings = members.iter().map(|member| self.member_binding(member)).join(", ");
+        match self {
+            Self::Named | Self::Unit => format!("{ty} {{ {bindings} }}"),
+            Self::Tuple => format!("{ty}({bindings})"),
+        }
+    }
+}
+
 /// Information on generic params.
 struct GenericParamsInfo {
     /// All the generic params name, at the original order.
@@ -200,13 +265,12 @@ impl DeriveInfo {
 }
 
 /// Extracts the information on the members of the struct.
-fn extract_members(db: &dyn SyntaxGroup, members: MemberList) -> Vec<MemberInfo> {
+fn extract_members(db: &dyn SyntaxGroup, members: Vec<ast::StructMember>) -> Vec<MemberInfo> {
     members
-        .elements(db)
         .into_iter()
         .map(|member| MemberInfo {
-            name: member.name(db).text(db),
-            _ty: member.type_clause(db).ty(db).as_syntax_node().get_text_without_trivia(db),
+            name: member.name(db),
+            _ty: member.type_expr(db).as_syntax_node().get_text_without_trivia(db),
             attributes: member.attributes(db),
         })
         .collect()
@@ -243,10 +307,10 @@ fn generate_derive_code_for_type(db: &dyn SyntaxGroup, info: DeriveInfo) -> Plug
         let attr = attr.structurize(db);
 
         if attr.args.is_empty() {
-            result.diagnostics.push(PluginDiagnostic::error(
-                attr.args_stable_ptr.untyped(),
-                "Expected args.".into(),
-            ));
+            result.diagnostics.push(
+                PluginDiagnostic::error(attr.args_stable_ptr.untyped(), "Expected args.".into())
+                    .with_error_code(error_code!("E0526")),
+            );
             continue;
         }
 
@@ -259,10 +323,10 @@ fn generate_derive_code_for_type(db: &dyn SyntaxGroup, info: DeriveInfo) -> Plug
                 ..
             } = arg
             else {
-                result.diagnostics.push(PluginDiagnostic::error(
-                    arg.arg_stable_ptr.untyped(),
-                    "Expected path.".into(),
-                ));
+                result.diagnostics.push(
+                    PluginDiagnostic::error(arg.arg_stable_ptr.untyped(), "Expected path.".into())
+                        .with_error_code(error_code!("E0527")),
+                );
                 continue;
             };
 
@@ -321,4 +385,5 @@ fn get_empty_impl(derived_trait: &str, info: &DeriveInfo) -> String {
 /// Returns a diagnostic for when a derive is not supported for extern types.
 fn unsupported_for_extern_diagnostic(stable_ptr: SyntaxStablePtrId) -> PluginDiagnostic {
     PluginDiagnostic::error(stable_ptr, "Unsupported trait for derive for extern types.".into())
+        .with_error_code(error_code!("E0528"))
 }
@@ -39,7 +39,7 @@ pub fn handle_partial_eq(
                     })
                 }).join("\n    ")}
             }
-            TypeVariantInfo::Struct(members) => {
+            TypeVariantInfo::Struct(_, members) => {
                 if members.is_empty() {
                     "true".to_string()
                 } else {
@@ -31,7 +31,7 @@ pub fn handle_serde(info: &DeriveInfo, stable_ptr: SyntaxStablePtrId, result: &m
                     )
                 }).join("\n    ")}
             }
-            TypeVariantInfo::Struct(members) => members
+            TypeVariantInfo::Struct(_, members) => members
                 .iter()
                 .map(|member| {
                     format!(
@@ -67,17 +67,12 @@ pub fn handle_serde(info: &DeriveInfo, stable_ptr: SyntaxStablePtrId, result: &m
                     }).join("\n        ")
                 }
             }
-            TypeVariantInfo::Struct(members) => {
-                formatdoc! {"
-                    core::option::Option::Some({ty} {{
-                        {}
-                    }})",
-                    members.iter().map(|member|format!(
-                        "{member}: core::serde::Serde::deserialize(ref serialized)?,",
-                        member=member.name
-                    )).join("\n    "),
-                }
-            }
+            TypeVariantInfo::Struct(kind, members) => format!(
+                "core::option::Option::Some({})",
+                kind.format_ctor(ty, members, |_| {
+                    "core::serde::Serde::deserialize(ref serialized)?".to_string()
+                })
+            ),
             TypeVariantInfo::Extern => {
                 result.diagnostics.push(unsupported_for_extern_diagnostic(stable_ptr));
                 return;
@@ -1,4 +1,4 @@
-use cairo_lang_filesystem::db::{CrateSettings, Edition, ExperimentalFeaturesConfig};
+use cairo_lang_filesystem::db::{CrateSettings, Edition, ExperimentalFeaturesConfig, LintLevel};
 use indoc::indoc;
 use pretty_assertions::assert_eq;
 
@@ -19,6 +19,7 @@ fn test_serde() {
                 edition: Default::default(),
                 experimental_features: ExperimentalFeaturesConfig::default(),
                 cfg_set: Default::default(),
+                lints: Default::default(),
             },
             override_map: [
                 (
@@ -27,6 +28,7 @@ fn test_serde() {
                         edition: Edition::V2023_10,
                         experimental_features: ExperimentalFeaturesConfig::default(),
                         cfg_set: Default::default(),
+                        lints: Default::default(),
                     },
                 ),
                 (
@@ -35,6 +37,12 @@ fn test_serde() {
                         edition: Default::default(),
                         experimental_features: ExperimentalFeaturesConfig { negative_impls: true },
                         cfg_set: Default::default(),
+                        lints: [
+                            ("unused_variables".into(), LintLevel::Allow),
+                            ("warnings".into(), LintLevel::Deny),
+                        ]
+                        .into_iter()
+                        .collect(),
                     },
                 ),
             ]
@@ -68,6 +76,10 @@ fn test_serde() {
 
             [config.override.crate3.experimental_features]
             negative_impls = true
+
+            [config.override.crate3.lints]
+            unused_variables = "allow"
+            warnings = "deny"
         "# }
     );
     assert_eq!(config, toml::from_str(&serialized).unwrap());
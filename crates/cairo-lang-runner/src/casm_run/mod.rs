@@ -960,7 +960,7 @@ impl<'a> CairoHintProcessor<'a> {
 
         // Prepare runner for running the constructor.
         let runner = self.runner.expect("Runner is needed for starknet.");
-        let Some(contract_info) = runner.starknet_contracts_info.get(&class_hash) else {
+        let Some(contract_info) = runner.contract_info(&class_hash) else {
             fail_syscall!(b"CLASS_HASH_NOT_FOUND");
         };
 
@@ -1018,10 +1018,8 @@ impl<'a> CairoHintProcessor<'a> {
 
         // Prepare runner for running the ctor.
         let runner = self.runner.expect("Runner is needed for starknet.");
-        let contract_info = runner
-            .starknet_contracts_info
-            .get(class_hash)
-            .expect("Deployed contract not found in registry.");
+        let contract_info =
+            runner.contract_info(class_hash).expect("Deployed contract not found in registry.");
 
         // Call the function.
         let Some(entry_point) = contract_info.externals.get(&selector) else {
@@ -1057,7 +1055,7 @@ impl<'a> CairoHintProcessor<'a> {
         deduct_gas!(gas_counter, LIBRARY_CALL);
         // Prepare runner for running the call.
         let runner = self.runner.expect("Runner is needed for starknet.");
-        let Some(contract_info) = runner.starknet_contracts_info.get(&class_hash) else {
+        let Some(contract_info) = runner.contract_info(&class_hash) else {
             fail_syscall!(b"CLASS_HASH_NOT_DECLARED")
         };
 
@@ -1083,11 +1081,7 @@ impl<'a> CairoHintProcessor<'a> {
     ) -> Result<SyscallResult, HintError> {
         deduct_gas!(gas_counter, REPLACE_CLASS);
         // Validating the class hash was declared as one of the starknet contracts.
-        if !self
-            .runner
-            .expect("Runner is needed for starknet.")
-            .starknet_contracts_info
-            .contains_key(&new_class)
+        if self.runner.expect("Runner is needed for starknet.").contract_info(&new_class).is_none()
         {
             fail_syscall!(b"CLASS_HASH_NOT_FOUND");
         };
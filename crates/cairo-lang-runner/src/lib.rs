@@ -167,8 +167,7 @@ pub struct SierraCasmRunner {
     type_sizes: TypeSizeMap,
     /// The casm program matching the Sierra code.
     casm_program: CairoProgram,
-    #[allow(dead_code)]
-    /// Mapping from class_hash to contract info.
+    /// Mapping from the test class hash to contract info.
     starknet_contracts_info: OrderedHashMap<Felt252, ContractInfo>,
     /// Whether to run the profiler when running using this runner.
     run_profiler: bool,
@@ -203,6 +202,16 @@ impl SierraCasmRunner {
         })
     }
 
+    /// Returns the info of the contract with the given class hash, which is either its test class
+    /// hash or its actual class hash.
+    pub fn contract_info(&self, class_hash: &Felt252) -> Option<&ContractInfo> {
+        self.starknet_contracts_info.get(class_hash).or_else(|| {
+            self.starknet_contracts_info
+                .values()
+                .find(|contract_info| contract_info.class_hash.as_ref() == Some(class_hash))
+        })
+    }
+
     /// Runs the vm starting from a function in the context of a given starknet state.
     pub fn run_function_with_starknet_context(
         &self,
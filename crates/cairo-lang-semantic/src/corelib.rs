@@ -348,6 +348,8 @@ pub fn unwrap_error_propagation_type(
         | TypeLongId::Tuple(_)
         | TypeLongId::Snapshot(_)
         | TypeLongId::Var(_)
+        | TypeLongId::Closure(_)
+        | TypeLongId::ImplType(_)
         | TypeLongId::Missing(_) => None,
     }
 }
@@ -410,6 +412,8 @@ pub fn core_binary_operator(
         BinaryOperator::And(_) => ("BitAnd", "bitand", false),
         BinaryOperator::Or(_) => ("BitOr", "bitor", false),
         BinaryOperator::Xor(_) => ("BitXor", "bitxor", false),
+        BinaryOperator::DotDot(_) => ("RangeOp", "range", false),
+        BinaryOperator::DotDotEq(_) => ("RangeInclusiveOp", "range_inclusive", false),
         _ => return Ok(Err(SemanticDiagnosticKind::UnknownBinaryOperator)),
     };
     Ok(Ok((
@@ -570,6 +574,14 @@ pub fn destruct_trait(db: &dyn SemanticGroup) -> TraitId {
     get_core_trait(db, "Destruct".into())
 }
 
+pub fn fn_trait(db: &dyn SemanticGroup) -> TraitId {
+    get_core_trait(db, "Fn".into())
+}
+
+pub fn fn_once_trait(db: &dyn SemanticGroup) -> TraitId {
+    get_core_trait(db, "FnOnce".into())
+}
+
 /// Given a core library trait name and its generic arguments, returns [ConcreteTraitId].
 fn get_core_concrete_trait(
     db: &dyn SemanticGroup,
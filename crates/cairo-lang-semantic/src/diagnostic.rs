@@ -3,12 +3,13 @@ use std::fmt::Display;
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{
-    EnumId, FunctionTitleId, ImplDefId, ImplFunctionId, ModuleItemId, StructId,
+    EnumId, FunctionTitleId, ImplConstantDefId, ImplDefId, ImplFunctionId, ModuleItemId, StructId,
     TopLevelLanguageElementId, TraitFunctionId, TraitId,
 };
 use cairo_lang_defs::plugin::PluginDiagnostic;
 use cairo_lang_diagnostics::{
-    DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, Diagnostics, DiagnosticsBuilder, Severity,
+    error_code, DiagnosticAdded, DiagnosticEntry, DiagnosticLocation, Diagnostics,
+    DiagnosticsBuilder, ErrorCode, Severity,
 };
 use cairo_lang_filesystem::ids::FileId;
 use cairo_lang_syntax as syntax;
@@ -70,15 +71,18 @@ pub struct SemanticDiagnostic {
     /// true if the diagnostic should be reported *after* the given location. Normally false, in
     /// which case the diagnostic points to the given location (as-is).
     pub after: bool,
+    /// The severity set by the lint levels in effect at the location of the diagnostic, if any.
+    /// See [crate::lints].
+    pub severity_override: Option<Severity>,
 }
 impl SemanticDiagnostic {
     /// Create a diagnostic in the given location.
     pub fn new(stable_location: StableLocation, kind: SemanticDiagnosticKind) -> Self {
-        SemanticDiagnostic { stable_location, kind, after: false }
+        SemanticDiagnostic { stable_location, kind, after: false, severity_override: None }
     }
     /// Create a diagnostic in the location after the given location (with width 0).
     pub fn new_after(stable_location: StableLocation, kind: SemanticDiagnosticKind) -> Self {
-        SemanticDiagnostic { stable_location, kind, after: true }
+        SemanticDiagnostic { stable_location, kind, after: true, severity_override: None }
     }
 }
 impl DiagnosticEntry for SemanticDiagnostic {
@@ -505,6 +509,8 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::PanicableExternFunction => {
                 "An extern function must be marked as nopanic.".into()
             }
+            // Plugins assign codes to their own diagnostics, and `E0192` is used for plugins that
+            // assign none.
             SemanticDiagnosticKind::PluginDiagnostic(diagnostic) => {
                 format!("Plugin diagnostic: {}", diagnostic.message)
             }
@@ -525,6 +531,7 @@ impl DiagnosticEntry for SemanticDiagnostic {
                     UnsupportedOutsideOfFunctionFeatureName::FunctionCall => "Function call",
                     UnsupportedOutsideOfFunctionFeatureName::ReturnStatement => "Return statement",
                     UnsupportedOutsideOfFunctionFeatureName::ErrorPropagate => "The '?' operator",
+                    UnsupportedOutsideOfFunctionFeatureName::Closure => "Closure",
                 };
                 format!("{feature_name_str} is not supported outside of functions.")
             }
@@ -615,6 +622,39 @@ impl DiagnosticEntry for SemanticDiagnostic {
             SemanticDiagnosticKind::ErrorPropagateNotAllowedInsideALoop => {
                 "`?` not allowed inside a `loop`.".into()
             }
+            SemanticDiagnosticKind::ReturnNotAllowedInsideAClosure => {
+                "`return` not allowed inside a closure.".into()
+            }
+            SemanticDiagnosticKind::ErrorPropagateNotAllowedInsideAClosure => {
+                "`?` not allowed inside a closure.".into()
+            }
+            SemanticDiagnosticKind::CapturedVariableModified => {
+                "Captured variables cannot be modified inside a closure.".into()
+            }
+            SemanticDiagnosticKind::NonNeverLetElseType => {
+                "`else` clause of `let...else` must exit the scope. Consider using `return`, \
+                 `continue`, `break` or `panic`."
+                    .into()
+            }
+            SemanticDiagnosticKind::WrongImplConstantType {
+                impl_constant_id,
+                trait_id,
+                expected_ty,
+                actual_ty,
+            } => {
+                let defs_db = db.upcast();
+                let constant_name = impl_constant_id.name(defs_db);
+                format!(
+                    "Type of impl constant `{}::{}` is incompatible with `{}::{}`. Expected: \
+                     `{}`, actual: `{}`.",
+                    impl_constant_id.impl_def_id(defs_db).name(defs_db),
+                    constant_name,
+                    trait_id.name(defs_db),
+                    constant_name,
+                    expected_ty.format(db),
+                    actual_ty.format(db)
+                )
+            }
             SemanticDiagnosticKind::ConstGenericParamNotSupported => {
                 "Const generic args are not allowed in this context.".into()
             }
@@ -637,6 +677,20 @@ impl DiagnosticEntry for SemanticDiagnostic {
                 "`feature` attribute argument should be a single string.".into()
             }
             SemanticDiagnosticKind::UnsupportedPubArgument => "Unsupported `pub` argument.".into(),
+            SemanticDiagnosticKind::PubInPathNotAncestor { module_path } => {
+                format!("`pub(in {module_path})` must refer to an ancestor module.")
+            }
+            SemanticDiagnosticKind::GlobUseNotModule => {
+                "Only modules can be imported with a glob (`*`) import.".into()
+            }
+            SemanticDiagnosticKind::AmbiguousGlobImport { name } => {
+                format!("`{name}` is ambiguous, as it is imported by multiple glob imports.")
+            }
+            SemanticDiagnosticKind::NotATupleStruct => "Not a tuple struct.".into(),
+            SemanticDiagnosticKind::NotAUnitStruct => "Not a unit struct.".into(),
+            SemanticDiagnosticKind::TupleStructCtorNotImmutable => {
+                "Tuple struct constructor arguments must be immutable.".into()
+            }
             SemanticDiagnosticKind::UnknownStatementAttribute => {
                 "Unknown statement attribute.".into()
             }
@@ -678,6 +732,40 @@ impl DiagnosticEntry for SemanticDiagnostic {
                  specified."
                     .into()
             }
+            SemanticDiagnosticKind::UnknownLint { name } => format!("Unknown lint `{name}`."),
+            SemanticDiagnosticKind::NoImplementationOfIntoIterator { ty, inference_errors } => {
+                if inference_errors.is_empty() {
+                    format!("Type `{}` does not implement the `IntoIterator` trait.", ty.format(db))
+                } else {
+                    format!(
+                        "Type `{}` could not be iterated over.\n{}",
+                        ty.format(db),
+                        inference_errors.format(db)
+                    )
+                }
+            }
+            SemanticDiagnosticKind::NoImplementationOfIterator { ty, inference_errors } => {
+                if inference_errors.is_empty() {
+                    format!("Type `{}` does not implement the `Iterator` trait.", ty.format(db))
+                } else {
+                    format!(
+                        "Type `{}` could not be used as an iterator.\n{}",
+                        ty.format(db),
+                        inference_errors.format(db)
+                    )
+                }
+            }
+            SemanticDiagnosticKind::CallOfNonCallable { ty, inference_errors } => {
+                if inference_errors.is_empty() {
+                    format!("Type `{}` is not callable.", ty.format(db))
+                } else {
+                    format!(
+                        "Type `{}` could not be called.\n{}",
+                        ty.format(db),
+                        inference_errors.format(db)
+                    )
+                }
+            }
         }
     }
 
@@ -690,14 +778,185 @@ impl DiagnosticEntry for SemanticDiagnostic {
     }
 
     fn severity(&self) -> Severity {
+        if let Some(severity) = self.severity_override {
+            return severity;
+        }
         match &self.kind {
             SemanticDiagnosticKind::UnusedVariable
             | SemanticDiagnosticKind::UnhandledMustUseType { .. }
-            | SemanticDiagnosticKind::UnhandledMustUseFunction => Severity::Warning,
+            | SemanticDiagnosticKind::UnhandledMustUseFunction
+            | SemanticDiagnosticKind::UnknownLint { .. } => Severity::Warning,
             SemanticDiagnosticKind::PluginDiagnostic(diag) => diag.severity,
             _ => Severity::Error,
         }
     }
+
+    /// Semantic diagnostics use the codes `E0100`-`E0399`. New kinds must be given new codes.
+    fn error_code(&self) -> Option<ErrorCode> {
+        Some(match &self.kind {
+            SemanticDiagnosticKind::ModuleFileNotFound { .. } => error_code!("E0100"),
+            SemanticDiagnosticKind::Unsupported => error_code!("E0101"),
+            SemanticDiagnosticKind::UnknownLiteral => error_code!("E0102"),
+            SemanticDiagnosticKind::UnknownBinaryOperator => error_code!("E0103"),
+            SemanticDiagnosticKind::UnknownTrait => error_code!("E0104"),
+            SemanticDiagnosticKind::UnknownImpl => error_code!("E0105"),
+            SemanticDiagnosticKind::UnexpectedElement { .. } => error_code!("E0106"),
+            SemanticDiagnosticKind::UnknownType => error_code!("E0107"),
+            SemanticDiagnosticKind::UnknownEnum => error_code!("E0108"),
+            SemanticDiagnosticKind::LiteralError(_) => error_code!("E0109"),
+            SemanticDiagnosticKind::NotAVariant => error_code!("E0110"),
+            SemanticDiagnosticKind::NotAStruct => error_code!("E0111"),
+            SemanticDiagnosticKind::NotAType => error_code!("E0112"),
+            SemanticDiagnosticKind::NotATrait => error_code!("E0113"),
+            SemanticDiagnosticKind::NotAnImpl => error_code!("E0114"),
+            SemanticDiagnosticKind::ImplItemNotInTrait { .. } => error_code!("E0115"),
+            SemanticDiagnosticKind::GenericsNotSupportedInItem { .. } => error_code!("E0116"),
+            SemanticDiagnosticKind::UnexpectedGenericArgs => error_code!("E0117"),
+            SemanticDiagnosticKind::UnknownMember => error_code!("E0118"),
+            SemanticDiagnosticKind::MemberSpecifiedMoreThanOnce => error_code!("E0119"),
+            SemanticDiagnosticKind::StructBaseStructExpressionNotLast => error_code!("E0120"),
+            SemanticDiagnosticKind::StructBaseStructExpressionNoEffect => error_code!("E0121"),
+            SemanticDiagnosticKind::UseCycle => error_code!("E0122"),
+            SemanticDiagnosticKind::TypeAliasCycle => error_code!("E0123"),
+            SemanticDiagnosticKind::ImplAliasCycle => error_code!("E0124"),
+            SemanticDiagnosticKind::ImplRequirementCycle => error_code!("E0125"),
+            SemanticDiagnosticKind::MissingMember { .. } => error_code!("E0126"),
+            SemanticDiagnosticKind::WrongNumberOfParameters { .. } => error_code!("E0127"),
+            SemanticDiagnosticKind::WrongNumberOfArguments { .. } => error_code!("E0128"),
+            SemanticDiagnosticKind::WrongParameterType { .. } => error_code!("E0129"),
+            SemanticDiagnosticKind::VariantCtorNotImmutable => error_code!("E0130"),
+            SemanticDiagnosticKind::TraitParamMutable { .. } => error_code!("E0131"),
+            SemanticDiagnosticKind::TraitFunctionWithBody { .. } => error_code!("E0132"),
+            SemanticDiagnosticKind::ParameterShouldBeReference { .. } => error_code!("E0133"),
+            SemanticDiagnosticKind::ParameterShouldNotBeReference { .. } => error_code!("E0134"),
+            SemanticDiagnosticKind::WrongParameterName { .. } => error_code!("E0135"),
+            SemanticDiagnosticKind::WrongType { .. } => error_code!("E0136"),
+            SemanticDiagnosticKind::InconsistentBinding => error_code!("E0137"),
+            SemanticDiagnosticKind::WrongArgumentType { .. } => error_code!("E0138"),
+            SemanticDiagnosticKind::WrongReturnType { .. } => error_code!("E0139"),
+            SemanticDiagnosticKind::WrongNumberOfGenericParamsForImplFunction { .. } => {
+                error_code!("E0140")
+            }
+            SemanticDiagnosticKind::WrongReturnTypeForImpl { .. } => error_code!("E0141"),
+            SemanticDiagnosticKind::AmbiguousTrait { .. } => error_code!("E0142"),
+            SemanticDiagnosticKind::VariableNotFound { .. } => error_code!("E0143"),
+            SemanticDiagnosticKind::MissingVariableInPattern => error_code!("E0144"),
+            SemanticDiagnosticKind::StructMemberRedefinition { .. } => error_code!("E0145"),
+            SemanticDiagnosticKind::EnumVariantRedefinition { .. } => error_code!("E0146"),
+            SemanticDiagnosticKind::ParamNameRedefinition { .. } => error_code!("E0147"),
+            SemanticDiagnosticKind::ConditionNotBool { .. } => error_code!("E0148"),
+            SemanticDiagnosticKind::IncompatibleMatchArms { .. } => error_code!("E0149"),
+            SemanticDiagnosticKind::IncompatibleIfBlockTypes { .. } => error_code!("E0150"),
+            SemanticDiagnosticKind::LogicalOperatorNotAllowedInIfLet => error_code!("E0151"),
+            SemanticDiagnosticKind::IncompatibleLoopBreakTypes { .. } => error_code!("E0152"),
+            SemanticDiagnosticKind::TypeHasNoMembers { .. } => error_code!("E0153"),
+            SemanticDiagnosticKind::CannotCallMethod { .. } => error_code!("E0154"),
+            SemanticDiagnosticKind::NoSuchMember { .. } => error_code!("E0155"),
+            SemanticDiagnosticKind::MemberNotVisible { .. } => error_code!("E0156"),
+            SemanticDiagnosticKind::NoSuchVariant { .. } => error_code!("E0157"),
+            SemanticDiagnosticKind::IncompatibleErrorPropagateType { .. } => error_code!("E0158"),
+            SemanticDiagnosticKind::ErrorPropagateOnNonErrorType { .. } => error_code!("E0159"),
+            SemanticDiagnosticKind::UnhandledMustUseType { .. } => error_code!("E0160"),
+            SemanticDiagnosticKind::UnstableFeature { .. } => error_code!("E0161"),
+            SemanticDiagnosticKind::UnhandledMustUseFunction => error_code!("E0162"),
+            SemanticDiagnosticKind::UnusedVariable => error_code!("E0163"),
+            SemanticDiagnosticKind::ConstGenericParamNotSupported => error_code!("E0164"),
+            SemanticDiagnosticKind::NegativeImplsNotEnabled => error_code!("E0165"),
+            SemanticDiagnosticKind::NegativeImplsOnlyOnImpls => error_code!("E0166"),
+            SemanticDiagnosticKind::RefArgNotAVariable => error_code!("E0167"),
+            SemanticDiagnosticKind::RefArgNotMutable => error_code!("E0168"),
+            SemanticDiagnosticKind::RefArgNotExplicit => error_code!("E0169"),
+            SemanticDiagnosticKind::ImmutableArgWithModifiers => error_code!("E0170"),
+            SemanticDiagnosticKind::AssignmentToImmutableVar => error_code!("E0171"),
+            SemanticDiagnosticKind::InvalidLhsForAssignment => error_code!("E0172"),
+            SemanticDiagnosticKind::InvalidMemberExpression => error_code!("E0173"),
+            SemanticDiagnosticKind::InvalidPath => error_code!("E0174"),
+            SemanticDiagnosticKind::PathNotFound(_) => error_code!("E0175"),
+            SemanticDiagnosticKind::SuperUsedInRootModule => error_code!("E0176"),
+            SemanticDiagnosticKind::ItemNotVisible { .. } => error_code!("E0177"),
+            SemanticDiagnosticKind::RedundantModifier { .. } => error_code!("E0178"),
+            SemanticDiagnosticKind::ReferenceLocalVariable => error_code!("E0179"),
+            SemanticDiagnosticKind::UnexpectedEnumPattern { .. } => error_code!("E0180"),
+            SemanticDiagnosticKind::UnexpectedStructPattern { .. } => error_code!("E0181"),
+            SemanticDiagnosticKind::UnexpectedTuplePattern { .. } => error_code!("E0182"),
+            SemanticDiagnosticKind::WrongNumberOfTupleElements { .. } => error_code!("E0183"),
+            SemanticDiagnosticKind::WrongEnum { .. } => error_code!("E0184"),
+            SemanticDiagnosticKind::InvalidCopyTraitImpl { .. } => error_code!("E0185"),
+            SemanticDiagnosticKind::InvalidDropTraitImpl { .. } => error_code!("E0186"),
+            SemanticDiagnosticKind::InvalidImplItem { .. } => error_code!("E0187"),
+            SemanticDiagnosticKind::MissingItemsInImpl { .. } => error_code!("E0188"),
+            SemanticDiagnosticKind::PassPanicAsNopanic { .. } => error_code!("E0189"),
+            SemanticDiagnosticKind::PanicableFromNonPanicable => error_code!("E0190"),
+            SemanticDiagnosticKind::PanicableExternFunction => error_code!("E0191"),
+            // Plugins assign codes to their own diagnostics, and `E0192` is used for plugins that
+            // assign none.
+            SemanticDiagnosticKind::PluginDiagnostic(diagnostic) => {
+                diagnostic.error_code.unwrap_or(error_code!("E0192"))
+            }
+            SemanticDiagnosticKind::NameDefinedMultipleTimes { .. } => error_code!("E0193"),
+            SemanticDiagnosticKind::NamedArgumentsAreNotSupported => error_code!("E0194"),
+            SemanticDiagnosticKind::ArgPassedToNegativeImpl => error_code!("E0195"),
+            SemanticDiagnosticKind::UnnamedArgumentFollowsNamed => error_code!("E0196"),
+            SemanticDiagnosticKind::NamedArgumentMismatch { .. } => error_code!("E0197"),
+            SemanticDiagnosticKind::UnsupportedOutsideOfFunction { .. } => error_code!("E0198"),
+            SemanticDiagnosticKind::OnlyLiteralConstants => error_code!("E0199"),
+            SemanticDiagnosticKind::ExternItemWithImplGenericsNotSupported => error_code!("E0200"),
+            SemanticDiagnosticKind::MissingSemicolon => error_code!("E0201"),
+            SemanticDiagnosticKind::TraitMismatch { .. } => error_code!("E0202"),
+            SemanticDiagnosticKind::DesnapNonSnapshot => error_code!("E0203"),
+            SemanticDiagnosticKind::InternalInferenceError(_) => error_code!("E0204"),
+            SemanticDiagnosticKind::NoImplementationOfIndexOperator { .. } => error_code!("E0205"),
+            SemanticDiagnosticKind::MultipleImplementationOfIndexOperator(_) => {
+                error_code!("E0206")
+            }
+            SemanticDiagnosticKind::UnsupportedInlineArguments => error_code!("E0207"),
+            SemanticDiagnosticKind::RedundantInlineAttribute => error_code!("E0208"),
+            SemanticDiagnosticKind::InlineAttrForExternFunctionNotAllowed => error_code!("E0209"),
+            SemanticDiagnosticKind::InlineAlwaysWithImplGenericArgNotAllowed => {
+                error_code!("E0210")
+            }
+            SemanticDiagnosticKind::TailExpressionNotAllowedInLoop => error_code!("E0211"),
+            SemanticDiagnosticKind::ContinueOnlyAllowedInsideALoop => error_code!("E0212"),
+            SemanticDiagnosticKind::BreakOnlyAllowedInsideALoop => error_code!("E0213"),
+            SemanticDiagnosticKind::BreakWithValueOnlyAllowedInsideALoop => error_code!("E0214"),
+            SemanticDiagnosticKind::ReturnNotAllowedInsideALoop => error_code!("E0215"),
+            SemanticDiagnosticKind::ErrorPropagateNotAllowedInsideALoop => error_code!("E0216"),
+            SemanticDiagnosticKind::ImplicitPrecedenceAttrForExternFunctionNotAllowed => {
+                error_code!("E0217")
+            }
+            SemanticDiagnosticKind::RedundantImplicitPrecedenceAttribute => error_code!("E0218"),
+            SemanticDiagnosticKind::UnsupportedImplicitPrecedenceArguments => error_code!("E0219"),
+            SemanticDiagnosticKind::UnsupportedFeatureAttrArguments => error_code!("E0220"),
+            SemanticDiagnosticKind::UnsupportedPubArgument => error_code!("E0221"),
+            SemanticDiagnosticKind::UnknownStatementAttribute => error_code!("E0222"),
+            SemanticDiagnosticKind::InlineMacroNotFound { .. } => error_code!("E0223"),
+            SemanticDiagnosticKind::InlineMacroFailed { .. } => error_code!("E0224"),
+            SemanticDiagnosticKind::UnknownGenericParam { .. } => error_code!("E0225"),
+            SemanticDiagnosticKind::PositionalGenericAfterNamed => error_code!("E0226"),
+            SemanticDiagnosticKind::GenericArgDuplicate { .. } => error_code!("E0227"),
+            SemanticDiagnosticKind::TooManyGenericArguments { .. } => error_code!("E0228"),
+            SemanticDiagnosticKind::GenericArgOutOfOrder { .. } => error_code!("E0229"),
+            SemanticDiagnosticKind::UnsupportedTraitItem { .. } => error_code!("E0230"),
+            SemanticDiagnosticKind::UnsupportedImplItem { .. } => error_code!("E0231"),
+            SemanticDiagnosticKind::UnknownLint { .. } => error_code!("E0232"),
+            SemanticDiagnosticKind::NoImplementationOfIntoIterator { .. } => error_code!("E0233"),
+            SemanticDiagnosticKind::NoImplementationOfIterator { .. } => error_code!("E0234"),
+            SemanticDiagnosticKind::ReturnNotAllowedInsideAClosure => error_code!("E0235"),
+            SemanticDiagnosticKind::ErrorPropagateNotAllowedInsideAClosure => {
+                error_code!("E0236")
+            }
+            SemanticDiagnosticKind::CapturedVariableModified => error_code!("E0237"),
+            SemanticDiagnosticKind::CallOfNonCallable { .. } => error_code!("E0238"),
+            SemanticDiagnosticKind::NonNeverLetElseType => error_code!("E0239"),
+            SemanticDiagnosticKind::WrongImplConstantType { .. } => error_code!("E0240"),
+            SemanticDiagnosticKind::PubInPathNotAncestor { .. } => error_code!("E0241"),
+            SemanticDiagnosticKind::GlobUseNotModule => error_code!("E0242"),
+            SemanticDiagnosticKind::AmbiguousGlobImport { .. } => error_code!("E0243"),
+            SemanticDiagnosticKind::NotATupleStruct => error_code!("E0244"),
+            SemanticDiagnosticKind::NotAUnitStruct => error_code!("E0245"),
+            SemanticDiagnosticKind::TupleStructCtorNotImmutable => error_code!("E0246"),
+        })
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -981,6 +1240,16 @@ pub enum SemanticDiagnosticKind {
     UnsupportedImplicitPrecedenceArguments,
     UnsupportedFeatureAttrArguments,
     UnsupportedPubArgument,
+    PubInPathNotAncestor {
+        module_path: String,
+    },
+    GlobUseNotModule,
+    AmbiguousGlobImport {
+        name: SmolStr,
+    },
+    NotATupleStruct,
+    NotAUnitStruct,
+    TupleStructCtorNotImmutable,
     UnknownStatementAttribute,
     InlineMacroNotFound {
         macro_name: SmolStr,
@@ -1008,6 +1277,31 @@ pub enum SemanticDiagnosticKind {
     UnsupportedImplItem {
         kind: String,
     },
+    UnknownLint {
+        name: String,
+    },
+    NoImplementationOfIntoIterator {
+        ty: semantic::TypeId,
+        inference_errors: TraitInferenceErrors,
+    },
+    NoImplementationOfIterator {
+        ty: semantic::TypeId,
+        inference_errors: TraitInferenceErrors,
+    },
+    ReturnNotAllowedInsideAClosure,
+    ErrorPropagateNotAllowedInsideAClosure,
+    CapturedVariableModified,
+    CallOfNonCallable {
+        ty: semantic::TypeId,
+        inference_errors: TraitInferenceErrors,
+    },
+    NonNeverLetElseType,
+    WrongImplConstantType {
+        impl_constant_id: ImplConstantDefId,
+        trait_id: TraitId,
+        expected_ty: semantic::TypeId,
+        actual_ty: semantic::TypeId,
+    },
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -1024,6 +1318,7 @@ pub enum UnsupportedOutsideOfFunctionFeatureName {
     FunctionCall,
     ReturnStatement,
     ErrorPropagate,
+    Closure,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
@@ -1041,7 +1336,9 @@ pub enum ElementKind {
 impl From<&ResolvedConcreteItem> for ElementKind {
     fn from(val: &ResolvedConcreteItem) -> Self {
         match val {
-            ResolvedConcreteItem::Constant(_) => ElementKind::Constant,
+            ResolvedConcreteItem::Constant(_) | ResolvedConcreteItem::ImplConstant(_) => {
+                ElementKind::Constant
+            }
             ResolvedConcreteItem::Module(_) => ElementKind::Module,
             ResolvedConcreteItem::Function(_) => ElementKind::Function,
             ResolvedConcreteItem::TraitFunction(_) => ElementKind::TraitFunction,
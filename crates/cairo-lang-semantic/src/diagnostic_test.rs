@@ -6,6 +6,7 @@ use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
     MacroPlugin, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile, PluginResult,
 };
+use cairo_lang_diagnostics::{error_code, DiagnosticEntry};
 use cairo_lang_syntax::node::ast;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
@@ -14,6 +15,7 @@ use pretty_assertions::assert_eq;
 use test_log::test;
 
 use crate::db::SemanticGroup;
+use crate::diagnostic::SemanticDiagnosticKind;
 use crate::items::us::SemanticUseEx;
 use crate::plugin::AnalyzerPlugin;
 use crate::resolve::ResolvedGenericItem;
@@ -32,6 +34,7 @@ cairo_lang_test_utils::test_file_test!(
         neg_impl: "neg_impl",
         plus_eq: "plus_eq",
         inline: "inline",
+        lints: "lints",
     },
     test_expr_diagnostics
 );
@@ -292,3 +295,32 @@ fn test_analyzer_diagnostics() {
     "#},
     );
 }
+
+#[test]
+fn test_plugin_diagnostics_error_codes() {
+    let mut db_val = SemanticDatabaseForTesting::new_empty();
+    let db = &mut db_val;
+    db.set_analyzer_plugins(vec![Arc::new(NoU128RenameAnalyzerPlugin)]);
+    let crate_id = setup_test_crate(
+        db,
+        indoc! {"
+            use u128 as short_u128_rename;
+            const X: felt252 = consteval_int!(foo());
+       "},
+    );
+
+    // Built-in plugins assign their own codes, and diagnostics of plugins that assign none fall
+    // back to the generic plugin diagnostic code.
+    assert_eq!(
+        get_crate_semantic_diagnostics(db, crate_id)
+            .get_all()
+            .iter()
+            .filter(|diagnostic| matches!(
+                diagnostic.kind,
+                SemanticDiagnosticKind::PluginDiagnostic(_)
+            ))
+            .map(|diagnostic| diagnostic.error_code())
+            .collect::<Vec<_>>(),
+        vec![Some(error_code!("E0192")), Some(error_code!("E0564"))]
+    );
+}
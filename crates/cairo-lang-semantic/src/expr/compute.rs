@@ -8,10 +8,10 @@ use std::sync::Arc;
 use ast::PathSegment;
 use cairo_lang_defs::db::validate_attributes_flat;
 use cairo_lang_defs::ids::{
-    EnumId, FunctionTitleId, FunctionWithBodyId, GenericKind, LanguageElementId, LocalVarLongId,
-    LookupItemId, MemberId, ModuleId, TraitFunctionId, TraitId,
+    EnumId, FunctionTitleId, FunctionWithBodyId, GenericKind, GenericTypeId, LanguageElementId,
+    LocalVarLongId, LookupItemId, MemberId, ModuleId, TraitFunctionId, TraitId,
 };
-use cairo_lang_diagnostics::{Maybe, ToOption};
+use cairo_lang_diagnostics::{Maybe, ToMaybe, ToOption};
 use cairo_lang_filesystem::ids::{FileKind, FileLongId, VirtualFile};
 use cairo_lang_syntax::attribute::consts::FEATURE_ATTR;
 use cairo_lang_syntax::attribute::structured::{
@@ -45,9 +45,9 @@ use super::pattern::{
     PatternVariable,
 };
 use crate::corelib::{
-    core_binary_operator, core_bool_ty, core_unary_operator, false_literal_expr, get_core_trait,
-    never_ty, true_literal_expr, try_get_core_ty_by_name, unit_expr, unit_ty,
-    unwrap_error_propagation_type,
+    core_binary_operator, core_bool_ty, core_unary_operator, false_literal_expr, fn_once_trait,
+    fn_trait, get_core_trait, get_core_ty_by_name, never_ty, true_literal_expr,
+    try_get_core_ty_by_name, unit_expr, unit_ty, unwrap_error_propagation_type,
 };
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::{self, *};
@@ -58,16 +58,18 @@ use crate::diagnostic::{
 use crate::items::enm::SemanticEnumEx;
 use crate::items::imp::{filter_candidate_traits, infer_impl_by_self};
 use crate::items::modifiers::compute_mutability;
-use crate::items::structure::SemanticStructEx;
+use crate::items::structure::{SemanticStructEx, StructKind};
 use crate::items::visibility;
 use crate::literals::try_extract_minus_literal;
 use crate::resolve::{ResolvedConcreteItem, ResolvedGenericItem, Resolver};
 use crate::semantic::{self, FunctionId, LocalVariable, TypeId, TypeLongId, Variable};
 use crate::substitution::SemanticRewriter;
-use crate::types::{peel_snapshots, resolve_type, wrap_in_snapshots, ConcreteTypeId};
+use crate::types::{
+    peel_snapshots, resolve_type, wrap_in_snapshots, ClosureTypeLongId, ConcreteTypeId,
+};
 use crate::{
-    ConcreteEnumId, GenericArgumentId, Member, Mutability, Parameter, PatternStringLiteral,
-    PatternStruct, Signature,
+    ConcreteEnumId, ConcreteFunctionWithBodyId, ConcreteStructId, GenericArgumentId, Member,
+    Mutability, Parameter, PatternStringLiteral, PatternStruct, Signature,
 };
 
 /// Expression with its id.
@@ -108,6 +110,18 @@ enum LoopContext {
     Loop(FlowMergeTypeHelper),
     /// Context inside a `while` loop
     While,
+    /// Context inside a `for` loop
+    For,
+}
+
+/// Context inside the body of a closure.
+#[derive(Debug, Clone)]
+struct ClosureContext {
+    /// The depth of the environment holding the closure parameters. Variables found in shallower
+    /// environments are captured by the closure.
+    env_depth: usize,
+    /// The variables captured by the closure so far, in capture order.
+    captured_vars: OrderedHashMap<semantic::VarId, ExprVar>,
 }
 
 /// Context for computing the semantic model of expression trees.
@@ -124,6 +138,8 @@ pub struct ComputationContext<'ctx> {
     /// Definitions of semantic variables.
     pub semantic_defs: UnorderedHashMap<semantic::VarId, semantic::Variable>,
     loop_ctx: Option<LoopContext>,
+    /// The contexts of the closures whose bodies are being computed, innermost last.
+    closure_ctxs: Vec<ClosureContext>,
 }
 impl<'ctx> ComputationContext<'ctx> {
     pub fn new(
@@ -148,6 +164,7 @@ impl<'ctx> ComputationContext<'ctx> {
             statements: Arena::default(),
             semantic_defs,
             loop_ctx: None,
+            closure_ctxs: vec![],
         }
     }
 
@@ -197,6 +214,13 @@ impl<'ctx> ComputationContext<'ctx> {
             .report_by_ptr(stable_ptr, UnsupportedOutsideOfFunction { feature_name }))
     }
 
+    /// Returns true if the given variable is captured by the closure whose body is being computed.
+    fn is_captured(&self, var_id: semantic::VarId) -> bool {
+        self.closure_ctxs
+            .last()
+            .is_some_and(|closure_ctx| closure_ctx.captured_vars.contains_key(&var_id))
+    }
+
     fn reduce_ty(&mut self, ty: TypeId) -> TypeId {
         // TODO(spapini): Propagate error to diagnostics.
         self.resolver.inference().rewrite(ty).unwrap()
@@ -238,6 +262,17 @@ impl Environment {
         }
     }
 
+    /// Returns true if a variable with the given name is visible from this environment.
+    fn has_variable(&self, name: &SmolStr) -> bool {
+        self.variables.contains_key(name)
+            || self.parent.as_ref().is_some_and(|parent| parent.has_variable(name))
+    }
+
+    /// Returns the number of ancestors of this environment.
+    fn depth(&self) -> usize {
+        self.parent.as_ref().map_or(0, |parent| parent.depth() + 1)
+    }
+
     fn empty() -> Self {
         Self {
             parent: None,
@@ -375,6 +410,8 @@ pub fn maybe_compute_expr_semantic(
         ast::Expr::If(expr_if) => compute_expr_if_semantic(ctx, expr_if),
         ast::Expr::Loop(expr_loop) => compute_expr_loop_semantic(ctx, expr_loop),
         ast::Expr::While(expr_while) => compute_expr_while_semantic(ctx, expr_while),
+        ast::Expr::For(expr_for) => compute_expr_for_semantic(ctx, expr_for),
+        ast::Expr::Closure(expr_closure) => compute_expr_closure_semantic(ctx, expr_closure),
         ast::Expr::ErrorPropagate(expr) => compute_expr_error_propagate_semantic(ctx, expr),
         ast::Expr::InlineMacro(expr) => compute_expr_inline_macro_semantic(ctx, expr),
         ast::Expr::Missing(_) | ast::Expr::FieldInitShorthand(_) => {
@@ -538,6 +575,8 @@ fn compute_expr_binary_semantic(
             // Verify the variable argument is mutable.
             if !ctx.semantic_defs[&member_path.base_var()].is_mut() {
                 ctx.diagnostics.report(syntax, AssignmentToImmutableVar);
+            } else if ctx.is_captured(member_path.base_var()) {
+                ctx.diagnostics.report(syntax, CapturedVariableModified);
             }
             return Ok(Expr::Assignment(ExprAssignment {
                 ref_arg: member_path,
@@ -669,6 +708,13 @@ fn compute_expr_function_call_semantic(
     let syntax_db = db.upcast();
 
     let path = syntax.path(syntax_db);
+    // A call of a variable, e.g. a closure.
+    if let [PathSegment::Simple(ident_segment)] = &path.elements(syntax_db)[..] {
+        if ctx.environment.has_variable(&ident_segment.ident(syntax_db).text(syntax_db)) {
+            let callee = resolve_expr_path(ctx, &path)?;
+            return compute_expr_closure_call_semantic(ctx, syntax, callee);
+        }
+    }
     let item =
         ctx.resolver.resolve_concrete_path(ctx.diagnostics, &path, NotFoundItemType::Function)?;
     let args_syntax = syntax.arguments(syntax_db);
@@ -713,6 +759,9 @@ fn compute_expr_function_call_semantic(
         ResolvedConcreteItem::Function(function) => {
             expr_function_call(ctx, function, named_args, syntax.stable_ptr().into())
         }
+        ResolvedConcreteItem::Type(ty) if try_extract_concrete_struct(db, ty).is_some() => {
+            tuple_struct_ctor_expr(ctx, ty, named_args, syntax)
+        }
         _ => Err(ctx.diagnostics.report(
             &path,
             UnexpectedElement { expected: vec![ElementKind::Function], actual: (&item).into() },
@@ -720,6 +769,273 @@ fn compute_expr_function_call_semantic(
     }
 }
 
+/// Returns the concrete struct of the given type, if it is a struct.
+fn try_extract_concrete_struct(db: &dyn SemanticGroup, ty: TypeId) -> Option<ConcreteStructId> {
+    try_extract_matches!(db.lookup_intern_type(ty), TypeLongId::Concrete)
+        .and_then(|c| try_extract_matches!(c, ConcreteTypeId::Struct))
+}
+
+/// Computes the semantic model of a tuple struct constructor call, e.g. `A(x, y)`.
+fn tuple_struct_ctor_expr(
+    ctx: &mut ComputationContext<'_>,
+    ty: TypeId,
+    named_args: Vec<NamedArg>,
+    syntax: &ast::ExprFunctionCall,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let args_syntax = syntax.arguments(syntax_db);
+    let concrete_struct_id = try_extract_concrete_struct(db, ty).to_maybe()?;
+    if db.struct_kind(concrete_struct_id.struct_id(db))? != StructKind::Tuple {
+        return Err(ctx.diagnostics.report(&syntax.path(syntax_db), NotATupleStruct));
+    }
+    let members = db.concrete_struct_members(concrete_struct_id)?;
+    if named_args.len() != members.len() {
+        return Err(ctx.diagnostics.report(
+            &args_syntax,
+            WrongNumberOfArguments { expected: members.len(), actual: named_args.len() },
+        ));
+    }
+    let mut member_exprs = vec![];
+    for (NamedArg(arg, name_terminal, mutability), (member_name, member)) in
+        zip_eq(named_args, members.iter())
+    {
+        if let Some(name_terminal) = name_terminal {
+            ctx.diagnostics.report(&name_terminal, NamedArgumentsAreNotSupported);
+        }
+        if mutability != Mutability::Immutable {
+            return Err(ctx.diagnostics.report(&args_syntax, TupleStructCtorNotImmutable));
+        }
+        let arg_stable_ptr = arg.stable_ptr().untyped();
+        check_struct_member_is_visible(ctx, member, arg_stable_ptr, member_name);
+        let expected_ty = ctx.reduce_ty(member.ty);
+        let actual_ty = ctx.reduce_ty(arg.ty());
+        if ctx.resolver.inference().conform_ty(actual_ty, expected_ty).is_err() {
+            return Err(ctx
+                .diagnostics
+                .report_by_ptr(arg_stable_ptr, WrongArgumentType { expected_ty, actual_ty }));
+        }
+        member_exprs.push((member.id, arg.id));
+    }
+    Ok(Expr::StructCtor(ExprStructCtor {
+        concrete_struct_id,
+        members: member_exprs,
+        base_struct: None,
+        ty,
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
+/// Computes the semantic model of a call of a value, e.g. a closure, `f(args)`.
+/// The call is computed as `Fn::call(@f, (args,))` if `f` implements `Fn`, and as
+/// `FnOnce::call(f, (args,))` otherwise.
+fn compute_expr_closure_call_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprFunctionCall,
+    callee: Expr,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let stable_ptr: ast::ExprPtr = syntax.stable_ptr().into();
+
+    let named_args: Vec<_> = syntax
+        .arguments(syntax_db)
+        .arguments(syntax_db)
+        .elements(syntax_db)
+        .into_iter()
+        .map(|arg_syntax| compute_named_argument_clause(ctx, arg_syntax))
+        .collect();
+    let callee_ty = ctx.reduce_ty(callee.ty());
+    // When the closure type is known, check the arguments directly against its parameters.
+    let param_tys = try_extract_matches!(db.lookup_intern_type(callee_ty), TypeLongId::Closure)
+        .map(|closure| closure.param_tys);
+    if let Some(param_tys) = &param_tys {
+        if param_tys.len() != named_args.len() {
+            return Err(ctx.diagnostics.report(
+                &syntax.arguments(syntax_db),
+                WrongNumberOfArguments { expected: param_tys.len(), actual: named_args.len() },
+            ));
+        }
+    }
+    let mut items = vec![];
+    let mut item_tys = vec![];
+    for (i, NamedArg(arg, name_terminal, mutability)) in named_args.into_iter().enumerate() {
+        if let Some(name_terminal) = name_terminal {
+            ctx.diagnostics.report(&name_terminal, NamedArgumentsAreNotSupported);
+        }
+        if mutability != Mutability::Immutable {
+            ctx.diagnostics.report_by_ptr(arg.stable_ptr().untyped(), ImmutableArgWithModifiers);
+        }
+        if let Some(param_tys) = &param_tys {
+            let (expected_ty, actual_ty) = (param_tys[i], arg.ty());
+            if ctx.resolver.inference().conform_ty(actual_ty, expected_ty).is_err() {
+                return Err(ctx.diagnostics.report_by_ptr(
+                    arg.stable_ptr().untyped(),
+                    WrongArgumentType { expected_ty, actual_ty },
+                ));
+            }
+        }
+        items.push(arg.id);
+        item_tys.push(arg.ty());
+    }
+    let args_expr = Expr::Tuple(ExprTuple {
+        items,
+        ty: db.intern_type(TypeLongId::Tuple(item_tys)),
+        stable_ptr,
+    });
+    let args_expr = ExprAndId { expr: args_expr.clone(), id: ctx.exprs.alloc(args_expr) };
+
+    let callee_ptr = callee.stable_ptr().untyped();
+    let callee = ExprAndId { expr: callee.clone(), id: ctx.exprs.alloc(callee) };
+    let fn_trait = fn_trait(db);
+    let candidate_trait = if filter_candidate_traits(
+        ctx,
+        &mut vec![],
+        callee_ty,
+        &[fn_trait],
+        "call".into(),
+        callee_ptr,
+    )
+    .is_empty()
+    {
+        fn_once_trait(db)
+    } else {
+        fn_trait
+    };
+    let (function_id, fixed_callee, mutability) = compute_method_function_call_data(
+        ctx,
+        &[candidate_trait],
+        "call".into(),
+        callee,
+        callee_ptr,
+        None,
+        |ty, _, inference_errors| CallOfNonCallable { ty, inference_errors },
+        |_, _, _| unreachable!("There is a single candidate trait."),
+    )?;
+    expr_function_call(
+        ctx,
+        function_id,
+        vec![
+            NamedArg(fixed_callee, None, mutability),
+            NamedArg(args_expr, None, Mutability::Immutable),
+        ],
+        stable_ptr,
+    )
+}
+
+/// Computes the semantic model of an expression of type [ast::ExprClosure].
+fn compute_expr_closure_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprClosure,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let stable_ptr = syntax.stable_ptr();
+    let Some(function) = ctx.function else {
+        return Err(ctx.diagnostics.report(
+            syntax,
+            UnsupportedOutsideOfFunction {
+                feature_name: UnsupportedOutsideOfFunctionFeatureName::Closure,
+            },
+        ));
+    };
+    let parent_function =
+        ConcreteFunctionWithBodyId::from_generic(db, function)?.function_id(db)?;
+
+    let (params, param_tys, ret_ty, body, captured_vars) = ctx.run_in_subscope(|new_ctx| {
+        let params_syntax = match syntax.wrapper(syntax_db) {
+            ast::ClosureParamWrapper::Nullary(_) => vec![],
+            ast::ClosureParamWrapper::NAry(wrapper) => {
+                wrapper.params(syntax_db).elements(syntax_db)
+            }
+        };
+        let mut params = vec![];
+        let mut param_tys = vec![];
+        let mut variables = UnorderedHashMap::default();
+        for param_syntax in params_syntax {
+            let ty = match param_syntax.type_clause(syntax_db) {
+                ast::OptionTypeClause::Empty(_) => new_ctx
+                    .resolver
+                    .inference()
+                    .new_type_var(Some(param_syntax.stable_ptr().untyped())),
+                ast::OptionTypeClause::TypeClause(type_clause) => resolve_type(
+                    db,
+                    new_ctx.diagnostics,
+                    &mut new_ctx.resolver,
+                    &type_clause.ty(syntax_db),
+                ),
+            };
+            let pattern = compute_pattern_semantic(
+                new_ctx,
+                &param_syntax.pattern(syntax_db),
+                ty,
+                &mut variables,
+            );
+            for v in pattern.variables(&new_ctx.patterns) {
+                let var_def = Variable::Local(v.var.clone());
+                new_ctx.environment.variables.insert(v.name.clone(), var_def.clone());
+                new_ctx.semantic_defs.insert(var_def.id(), var_def);
+            }
+            params.push(pattern.id);
+            param_tys.push(ty);
+        }
+        let explicit_ret_ty = match syntax.ret_ty(syntax_db) {
+            ast::OptionReturnTypeClause::Empty(_) => None,
+            ast::OptionReturnTypeClause::ReturnTypeClause(ret_ty_clause) => Some(resolve_type(
+                db,
+                new_ctx.diagnostics,
+                &mut new_ctx.resolver,
+                &ret_ty_clause.ty(syntax_db),
+            )),
+        };
+
+        // Loops enclosing the closure can't be controlled from its body.
+        let old_loop_ctx = new_ctx.loop_ctx.take();
+        new_ctx.closure_ctxs.push(ClosureContext {
+            env_depth: new_ctx.environment.depth(),
+            captured_vars: Default::default(),
+        });
+        let body_syntax = syntax.expr(syntax_db);
+        let body = compute_expr_semantic(new_ctx, &body_syntax);
+        let closure_ctx = new_ctx.closure_ctxs.pop().unwrap();
+        new_ctx.loop_ctx = old_loop_ctx;
+
+        let ret_ty = match explicit_ret_ty {
+            Some(expected_ty) => {
+                let actual_ty = body.ty();
+                if !expected_ty.is_missing(db)
+                    && !actual_ty.is_missing(db)
+                    && new_ctx.resolver.inference().conform_ty(actual_ty, expected_ty).is_err()
+                {
+                    new_ctx
+                        .diagnostics
+                        .report(&body_syntax, WrongReturnType { expected_ty, actual_ty });
+                }
+                expected_ty
+            }
+            None => body.ty(),
+        };
+        let captured_vars: Vec<_> =
+            closure_ctx.captured_vars.into_iter().map(|(_, var)| var).collect();
+        (params, param_tys, ret_ty, body.id, captured_vars)
+    });
+
+    let ty = db.intern_type(TypeLongId::Closure(ClosureTypeLongId {
+        param_tys,
+        ret_ty,
+        captured_types: captured_vars.iter().map(|var| var.ty).collect(),
+        parent_function,
+        stable_ptr,
+    }));
+    Ok(Expr::Closure(ExprClosure {
+        params,
+        body,
+        captured_vars,
+        ty,
+        stable_ptr: stable_ptr.into(),
+    }))
+}
+
 /// Computes the semantic model of an expression of type [ast::Arg].
 ///
 /// Returns the value and the optional argument name.
@@ -826,7 +1142,9 @@ pub fn compute_expr_block_semantic(
         let ty = if let Some(t) = &tail_semantic_expr {
             t.ty()
         } else if let Some(statement) = statements_semantic.last() {
-            if let Statement::Return(_) | Statement::Break(_) = &new_ctx.statements[*statement] {
+            if let Statement::Return(_) | Statement::Break(_) | Statement::Continue(_) =
+                &new_ctx.statements[*statement]
+            {
                 never_ty(new_ctx.db)
             } else {
                 unit_ty(db)
@@ -1107,6 +1425,102 @@ fn compute_expr_while_semantic(
     }))
 }
 
+/// Computes the semantic model of an expression of type [ast::ExprFor].
+fn compute_expr_for_semantic(
+    ctx: &mut ComputationContext<'_>,
+    syntax: &ast::ExprFor,
+) -> Maybe<Expr> {
+    let db = ctx.db;
+    let syntax_db = db.upcast();
+    let expr_syntax = syntax.expr(syntax_db);
+    let expr_ptr = expr_syntax.stable_ptr();
+
+    // Compute the iterator, `IntoIterator::into_iter(expr)`.
+    let expr = compute_expr_semantic(ctx, &expr_syntax);
+    let into_iterator_trait = get_core_trait(db, "IntoIterator".into());
+    let (into_iter, fixed_expr, mutability) = compute_method_function_call_data(
+        ctx,
+        &[into_iterator_trait],
+        "into_iter".into(),
+        expr,
+        expr_ptr.untyped(),
+        None,
+        |ty, _, inference_errors| NoImplementationOfIntoIterator { ty, inference_errors },
+        |_, _, _| unreachable!("There is a single candidate trait."),
+    )?;
+    let expr_id = fixed_expr.id;
+    let into_iter_call =
+        expr_function_call(ctx, into_iter, vec![NamedArg(fixed_expr, None, mutability)], expr_ptr)?;
+    ctx.resolver.inference().solve().ok();
+    let into_iter_ty = ctx.reduce_ty(into_iter_call.ty());
+
+    // The iterator is held by a hidden mutable variable, identified by the `in` keyword.
+    let into_iter_var = LocalVariable {
+        id: db.intern_local_var(LocalVarLongId(
+            ctx.resolver.module_file_id,
+            syntax.identifier(syntax_db).stable_ptr(),
+        )),
+        ty: into_iter_ty,
+        is_mut: true,
+    };
+    let into_iter_expr_var = ExprVar {
+        var: semantic::VarId::Local(into_iter_var.id),
+        ty: into_iter_var.ty,
+        stable_ptr: expr_ptr,
+    };
+    ctx.semantic_defs
+        .insert(semantic::VarId::Local(into_iter_var.id), Variable::Local(into_iter_var));
+    let into_iter_expr = Expr::Var(into_iter_expr_var.clone());
+    let into_iter_expr =
+        ExprAndId { expr: into_iter_expr.clone(), id: ctx.exprs.alloc(into_iter_expr) };
+
+    // Compute `Iterator::next` of the iterator, and the type of the items it yields.
+    let iterator_trait = get_core_trait(db, "Iterator".into());
+    let (next_function_id, _, _) = compute_method_function_call_data(
+        ctx,
+        &[iterator_trait],
+        "next".into(),
+        into_iter_expr,
+        expr_ptr.untyped(),
+        None,
+        |ty, _, inference_errors| NoImplementationOfIterator { ty, inference_errors },
+        |_, _, _| unreachable!("There is a single candidate trait."),
+    )?;
+    ctx.resolver.inference().solve().ok();
+    let next_ty = ctx.reduce_ty(db.concrete_function_signature(next_function_id)?.return_type);
+    let item_ty = ctx.resolver.inference().new_type_var(Some(expr_ptr.untyped()));
+    let option_ty =
+        get_core_ty_by_name(db, "Option".into(), vec![GenericArgumentId::Type(item_ty)]);
+    ctx.resolver.inference().conform_ty(next_ty, option_ty).ok();
+
+    let (pattern, body) = ctx.run_in_subscope(|new_ctx| {
+        let pattern = compute_pattern_semantic(
+            new_ctx,
+            &syntax.pattern(syntax_db),
+            item_ty,
+            &mut UnorderedHashMap::default(),
+        );
+        for v in pattern.variables(&new_ctx.patterns) {
+            let var_def = Variable::Local(v.var.clone());
+            new_ctx.environment.variables.insert(v.name.clone(), var_def.clone());
+            new_ctx.semantic_defs.insert(var_def.id(), var_def);
+        }
+        let (body, _loop_ctx) =
+            compute_loop_body_semantic(new_ctx, syntax.body(syntax_db), LoopContext::For);
+        (pattern.id, body)
+    });
+    Ok(Expr::For(ExprFor {
+        into_iter,
+        into_iter_member_path: ExprVarMemberPath::Var(into_iter_expr_var),
+        next_function_id,
+        expr_id,
+        pattern,
+        body,
+        ty: unit_ty(db),
+        stable_ptr: syntax.stable_ptr().into(),
+    }))
+}
+
 /// Computes the semantic model for a body of a loop.
 fn compute_loop_body_semantic(
     ctx: &mut ComputationContext<'_>,
@@ -1171,8 +1585,10 @@ fn compute_expr_error_propagate_semantic(
         syntax.stable_ptr().untyped(),
         UnsupportedOutsideOfFunctionFeatureName::ErrorPropagate,
     )?;
-    // Disallow error propagation inside a loop.
-    if ctx.loop_ctx.is_some() {
+    // Disallow error propagation inside a loop or a closure.
+    if !ctx.closure_ctxs.is_empty() {
+        ctx.diagnostics.report(syntax, ErrorPropagateNotAllowedInsideAClosure);
+    } else if ctx.loop_ctx.is_some() {
         ctx.diagnostics.report(syntax, SemanticDiagnosticKind::ErrorPropagateNotAllowedInsideALoop);
     }
     let (_, func_err_variant) = unwrap_error_propagation_type(ctx.db, func_signature.return_type)
@@ -1380,8 +1796,27 @@ fn maybe_compute_pattern_semantic(
                 &path,
                 NotFoundItemType::Identifier,
             )?;
-            let generic_variant = try_extract_matches!(item, ResolvedGenericItem::Variant)
-                .ok_or_else(|| ctx.diagnostics.report(&path, NotAVariant))?;
+            let generic_variant = match item {
+                ResolvedGenericItem::Variant(generic_variant) => generic_variant,
+                // A tuple struct pattern with a single member, or a unit struct pattern.
+                ResolvedGenericItem::GenericType(GenericTypeId::Struct(_)) => {
+                    let patterns = match enum_pattern.pattern(syntax_db) {
+                        ast::OptionPatternEnumInnerPattern::Empty(_) => None,
+                        ast::OptionPatternEnumInnerPattern::PatternEnumInnerPattern(p) => {
+                            Some(vec![p.pattern(syntax_db)])
+                        }
+                    };
+                    return compute_positional_struct_pattern(
+                        ctx,
+                        pattern_syntax,
+                        &path,
+                        patterns,
+                        ty,
+                        or_pattern_variables_map,
+                    );
+                }
+                _ => return Err(ctx.diagnostics.report(&path, NotAVariant)),
+            };
 
             let (concrete_enum, n_snapshots) = extract_concrete_enum_from_pattern_and_validate(
                 ctx,
@@ -1445,33 +1880,12 @@ fn maybe_compute_pattern_semantic(
             or_pattern_variables_map,
         ),
         ast::Pattern::Struct(pattern_struct) => {
-            let pattern_ty = try_extract_matches!(
-                ctx.resolver.resolve_concrete_path(
-                    ctx.diagnostics,
-                    &pattern_struct.path(syntax_db),
-                    NotFoundItemType::Type
-                )?,
-                ResolvedConcreteItem::Type
-            )
-            .ok_or_else(|| ctx.diagnostics.report(&pattern_struct.path(syntax_db), NotAType))?;
-            ctx.resolver
-                .inference()
-                .conform_ty(pattern_ty, ctx.db.intern_type(peel_snapshots(ctx.db, ty).1))
-                .map_err(|err| err.report(ctx.diagnostics, stable_ptr))?;
-            let ty = ctx.reduce_ty(ty);
-            // Peel all snapshot wrappers.
-            let (n_snapshots, long_ty) = peel_snapshots(ctx.db, ty);
-
-            // Check that type is an struct, and get the concrete struct from it.
-            let concrete_struct_id = try_extract_matches!(long_ty, TypeLongId::Concrete)
-                .and_then(|c| try_extract_matches!(c, ConcreteTypeId::Struct))
-                .ok_or(())
-                .or_else(|_| {
-                    // Don't add a diagnostic if the type is missing.
-                    // A diagnostic should've already been added.
-                    ty.check_not_missing(ctx.db)?;
-                    Err(ctx.diagnostics.report(pattern_struct, UnexpectedStructPattern { ty }))
-                })?;
+            let (concrete_struct_id, n_snapshots, ty) = resolve_struct_pattern_type(
+                ctx,
+                pattern_syntax,
+                &pattern_struct.path(syntax_db),
+                ty,
+            )?;
             let pattern_param_asts = pattern_struct.params(syntax_db).elements(syntax_db);
             let struct_id = concrete_struct_id.struct_id(ctx.db);
             let mut members = ctx.db.concrete_struct_members(concrete_struct_id)?;
@@ -1546,9 +1960,19 @@ fn maybe_compute_pattern_semantic(
                 field_patterns,
                 ty,
                 n_snapshots,
-                stable_ptr: pattern_struct.stable_ptr(),
+                stable_ptr: pattern_struct.stable_ptr().into(),
             })
         }
+        ast::Pattern::TupleStruct(pattern_tuple_struct) => {
+            return compute_positional_struct_pattern(
+                ctx,
+                pattern_syntax,
+                &pattern_tuple_struct.path(syntax_db),
+                Some(pattern_tuple_struct.patterns(syntax_db).elements(syntax_db)),
+                ty,
+                or_pattern_variables_map,
+            );
+        }
         ast::Pattern::Tuple(pattern_tuple) => {
             // Peel all snapshot wrappers.
             let (n_snapshots, long_ty) = peel_snapshots(ctx.db, ty);
@@ -1627,6 +2051,89 @@ fn maybe_compute_pattern_semantic(
     Ok(pattern)
 }
 
+/// Resolves the struct type of a struct pattern from its path, and validates that it matches the
+/// type of the matched value. Returns the concrete struct, the number of snapshots wrapping it in
+/// the matched value, and the reduced type of the matched value.
+fn resolve_struct_pattern_type(
+    ctx: &mut ComputationContext<'_>,
+    pattern_syntax: &ast::Pattern,
+    path: &ast::ExprPath,
+    ty: TypeId,
+) -> Maybe<(ConcreteStructId, usize, TypeId)> {
+    let stable_ptr = pattern_syntax.stable_ptr().untyped();
+    let pattern_ty = try_extract_matches!(
+        ctx.resolver.resolve_concrete_path(ctx.diagnostics, path, NotFoundItemType::Type)?,
+        ResolvedConcreteItem::Type
+    )
+    .ok_or_else(|| ctx.diagnostics.report(path, NotAType))?;
+    ctx.resolver
+        .inference()
+        .conform_ty(pattern_ty, ctx.db.intern_type(peel_snapshots(ctx.db, ty).1))
+        .map_err(|err| err.report(ctx.diagnostics, stable_ptr))?;
+    let ty = ctx.reduce_ty(ty);
+    // Peel all snapshot wrappers.
+    let (n_snapshots, long_ty) = peel_snapshots(ctx.db, ty);
+
+    // Check that type is an struct, and get the concrete struct from it.
+    let concrete_struct_id = try_extract_matches!(long_ty, TypeLongId::Concrete)
+        .and_then(|c| try_extract_matches!(c, ConcreteTypeId::Struct))
+        .ok_or(())
+        .or_else(|_| {
+            // Don't add a diagnostic if the type is missing.
+            // A diagnostic should've already been added.
+            ty.check_not_missing(ctx.db)?;
+            Err(ctx.diagnostics.report(pattern_syntax, UnexpectedStructPattern { ty }))
+        })?;
+    Ok((concrete_struct_id, n_snapshots, ty))
+}
+
+/// Computes the semantic model of a tuple struct pattern with the given member patterns (e.g.
+/// `A(x, y)`), or of a unit struct pattern if `patterns` is None (e.g. `m::A`).
+fn compute_positional_struct_pattern(
+    ctx: &mut ComputationContext<'_>,
+    pattern_syntax: &ast::Pattern,
+    path: &ast::ExprPath,
+    patterns: Option<Vec<ast::Pattern>>,
+    ty: TypeId,
+    or_pattern_variables_map: &mut UnorderedHashMap<SmolStr, LocalVariable>,
+) -> Maybe<Pattern> {
+    let (concrete_struct_id, n_snapshots, ty) =
+        resolve_struct_pattern_type(ctx, pattern_syntax, path, ty)?;
+    let struct_kind = ctx.db.struct_kind(concrete_struct_id.struct_id(ctx.db))?;
+    let patterns = match patterns {
+        Some(patterns) if struct_kind == StructKind::Tuple => patterns,
+        None if struct_kind == StructKind::Unit => vec![],
+        Some(_) => return Err(ctx.diagnostics.report(path, NotATupleStruct)),
+        None => return Err(ctx.diagnostics.report(path, NotAUnitStruct)),
+    };
+    let members = ctx.db.concrete_struct_members(concrete_struct_id)?;
+    if members.len() != patterns.len() {
+        return Err(ctx.diagnostics.report(
+            pattern_syntax,
+            WrongNumberOfTupleElements { expected: members.len(), actual: patterns.len() },
+        ));
+    }
+    let mut field_patterns = vec![];
+    for ((member_name, member), pattern_ast) in zip_eq(members.iter(), patterns) {
+        check_struct_member_is_visible(
+            ctx,
+            member,
+            pattern_ast.stable_ptr().untyped(),
+            member_name,
+        );
+        let ty = wrap_in_snapshots(ctx.db, member.ty, n_snapshots);
+        let pattern = compute_pattern_semantic(ctx, &pattern_ast, ty, or_pattern_variables_map);
+        field_patterns.push((member.clone(), pattern.id));
+    }
+    Ok(Pattern::Struct(PatternStruct {
+        concrete_struct_id,
+        field_patterns,
+        ty,
+        n_snapshots,
+        stable_ptr: pattern_syntax.stable_ptr(),
+    }))
+}
+
 /// Validates that the semantic type of an enum pattern is an enum, and returns the concrete enum.
 fn extract_concrete_enum_from_pattern_and_validate(
     ctx: &mut ComputationContext<'_>,
@@ -1966,7 +2473,15 @@ fn dot_expr(
 ) -> Maybe<Expr> {
     // Find MemberId.
     match rhs_syntax {
-        ast::Expr::Path(expr) => member_access_expr(ctx, lexpr, expr, stable_ptr),
+        ast::Expr::Path(expr) => {
+            let member_name = expr_as_identifier(ctx, &expr, ctx.db.upcast())?;
+            member_access_expr(ctx, lexpr, member_name, expr.stable_ptr().untyped(), stable_ptr)
+        }
+        // A positional member of a tuple struct, e.g. `x.0`.
+        ast::Expr::Literal(literal) => {
+            let member_name = literal.text(ctx.db.upcast());
+            member_access_expr(ctx, lexpr, member_name, literal.stable_ptr().untyped(), stable_ptr)
+        }
         ast::Expr::FunctionCall(expr) => method_call_expr(ctx, lexpr, expr, stable_ptr),
         _ => Err(ctx.diagnostics.report(&rhs_syntax, InvalidMemberExpression)),
     }
@@ -2049,13 +2564,11 @@ fn method_call_expr(
 fn member_access_expr(
     ctx: &mut ComputationContext<'_>,
     lexpr: ExprAndId,
-    rhs_syntax: ast::ExprPath,
+    member_name: SmolStr,
+    rhs_stable_ptr: SyntaxStablePtrId,
     stable_ptr: ast::ExprPtr,
 ) -> Maybe<Expr> {
-    let syntax_db = ctx.db.upcast();
-
     // Find MemberId.
-    let member_name = expr_as_identifier(ctx, &rhs_syntax, syntax_db)?;
     let ty = ctx.reduce_ty(lexpr.ty());
     let (n_snapshots, long_ty) = peel_snapshots(ctx.db, ty);
     match long_ty {
@@ -2064,20 +2577,15 @@ fn member_access_expr(
                 // TODO(lior): Add a diagnostic test when accessing a member of a missing type.
                 let members = ctx.db.concrete_struct_members(concrete_struct_id)?;
                 let Some(member) = members.get(&member_name) else {
-                    return Err(ctx.diagnostics.report(
-                        &rhs_syntax,
+                    return Err(ctx.diagnostics.report_by_ptr(
+                        rhs_stable_ptr,
                         NoSuchMember {
                             struct_id: concrete_struct_id.struct_id(ctx.db),
                             member_name,
                         },
                     ));
                 };
-                check_struct_member_is_visible(
-                    ctx,
-                    member,
-                    rhs_syntax.stable_ptr().untyped(),
-                    &member_name,
-                );
+                check_struct_member_is_visible(ctx, member, rhs_stable_ptr, &member_name);
                 let member_path = if n_snapshots == 0 {
                     lexpr.as_member_path().map(|parent| ExprVarMemberPath::Member {
                         parent: Box::new(parent),
@@ -2102,22 +2610,25 @@ fn member_access_expr(
                     stable_ptr,
                 }))
             }
-            _ => Err(ctx.diagnostics.report(&rhs_syntax, TypeHasNoMembers { ty, member_name })),
+            _ => Err(ctx
+                .diagnostics
+                .report_by_ptr(rhs_stable_ptr, TypeHasNoMembers { ty, member_name })),
         },
         TypeLongId::Tuple(_) => {
             // TODO(spapini): Handle .0, .1, etc. .
-            Err(ctx.diagnostics.report(&rhs_syntax, Unsupported))
+            Err(ctx.diagnostics.report_by_ptr(rhs_stable_ptr, Unsupported))
         }
         TypeLongId::Snapshot(_) => {
             // TODO(spapini): Handle snapshot members.
-            Err(ctx.diagnostics.report(&rhs_syntax, Unsupported))
+            Err(ctx.diagnostics.report_by_ptr(rhs_stable_ptr, Unsupported))
         }
-        TypeLongId::GenericParameter(_) => {
-            Err(ctx.diagnostics.report(&rhs_syntax, TypeHasNoMembers { ty, member_name }))
+        TypeLongId::GenericParameter(_) | TypeLongId::Closure(_) | TypeLongId::ImplType(_) => {
+            Err(ctx.diagnostics.report_by_ptr(rhs_stable_ptr, TypeHasNoMembers { ty, member_name }))
         }
-        TypeLongId::Var(_) => Err(ctx
-            .diagnostics
-            .report(&rhs_syntax, InternalInferenceError(InferenceError::TypeNotInferred { ty }))),
+        TypeLongId::Var(_) => Err(ctx.diagnostics.report_by_ptr(
+            rhs_stable_ptr,
+            InternalInferenceError(InferenceError::TypeNotInferred { ty }),
+        )),
         TypeLongId::Missing(diag_added) => Err(diag_added),
     }
 }
@@ -2154,6 +2665,13 @@ fn resolve_expr_path(ctx: &mut ComputationContext<'_>, path: &ast::ExprPath) ->
             ty: db.constant_semantic_data(constant_id)?.value.ty(),
             stable_ptr: path.stable_ptr().into(),
         })),
+        ResolvedConcreteItem::ImplConstant(impl_constant_id) => {
+            Ok(Expr::ImplConstant(ExprImplConstant {
+                impl_constant_id,
+                ty: impl_constant_id.ty(db)?,
+                stable_ptr: path.stable_ptr().into(),
+            }))
+        }
         ResolvedConcreteItem::Variant(variant) if variant.ty == unit_ty(db) => {
             let stable_ptr = path.stable_ptr().into();
             let concrete_enum_id = variant.concrete_enum_id;
@@ -2164,6 +2682,15 @@ fn resolve_expr_path(ctx: &mut ComputationContext<'_>, path: &ast::ExprPath) ->
                 stable_ptr,
             }))
         }
+        ResolvedConcreteItem::Type(ty) if is_unit_struct(db, ty) => {
+            Ok(Expr::StructCtor(ExprStructCtor {
+                concrete_struct_id: try_extract_concrete_struct(db, ty).to_maybe()?,
+                members: vec![],
+                base_struct: None,
+                ty,
+                stable_ptr: path.stable_ptr().into(),
+            }))
+        }
         resolved_item => Err(ctx.diagnostics.report(
             path,
             UnexpectedElement {
@@ -2174,6 +2701,13 @@ fn resolve_expr_path(ctx: &mut ComputationContext<'_>, path: &ast::ExprPath) ->
     }
 }
 
+/// Returns whether the given type is a unit struct, e.g. `struct A;`.
+fn is_unit_struct(db: &dyn SemanticGroup, ty: TypeId) -> bool {
+    try_extract_concrete_struct(db, ty).is_some_and(|concrete_struct_id| {
+        db.struct_kind(concrete_struct_id.struct_id(db)) == Ok(StructKind::Unit)
+    })
+}
+
 /// Resolves a variable given a context and a simple name.
 ///
 /// Reports a diagnostic if the variable was not found.
@@ -2201,13 +2735,22 @@ pub fn get_variable_by_name(
     variable_name: &SmolStr,
     stable_ptr: ast::ExprPtr,
 ) -> Option<Expr> {
+    let mut depth = ctx.environment.depth();
     let mut maybe_env = Some(&mut *ctx.environment);
     while let Some(env) = maybe_env {
         if let Some(var) = env.variables.get(variable_name) {
             env.used_variables.insert(var.id());
-            return Some(Expr::Var(ExprVar { var: var.id(), ty: var.ty(), stable_ptr }));
+            let expr_var = ExprVar { var: var.id(), ty: var.ty(), stable_ptr };
+            // Variables defined outside of a closure are captured by it.
+            for closure_ctx in &mut ctx.closure_ctxs {
+                if depth < closure_ctx.env_depth {
+                    closure_ctx.captured_vars.entry(var.id()).or_insert_with(|| expr_var.clone());
+                }
+            }
+            return Some(Expr::Var(expr_var));
         }
         maybe_env = env.parent.as_deref_mut();
+        depth = depth.saturating_sub(1);
     }
     None
 }
@@ -2272,6 +2815,8 @@ fn expr_function_call(
             // Verify the variable argument is mutable.
             if !ctx.semantic_defs[&ref_arg.base_var()].is_mut() {
                 ctx.diagnostics.report_by_ptr(arg.stable_ptr().untyped(), RefArgNotMutable);
+            } else if ctx.is_captured(ref_arg.base_var()) {
+                ctx.diagnostics.report_by_ptr(arg.stable_ptr().untyped(), CapturedVariableModified);
             }
             // Verify that it is passed explicitly as 'ref'.
             if mutability != Mutability::Reference {
@@ -2309,6 +2854,10 @@ fn has_panic_incompatibility(
     if try_extract_minus_literal(ctx.db, &ctx.exprs, expr_function_call).is_some() {
         return Ok(false);
     }
+    // Closure bodies are allowed to panic, regardless of the enclosing function.
+    if !ctx.closure_ctxs.is_empty() {
+        return Ok(false);
+    }
     // If this is not from within a context of a function - e.g. a const item, we will exit with an
     // error here, as this is a call with bad context.
     let caller_signature = ctx.get_signature(
@@ -2405,6 +2954,21 @@ pub fn compute_statement_semantic(
                 }
             };
 
+            // The `else` block of a `let...else` is computed before the pattern variables are
+            // introduced, as they are not accessible in it.
+            let else_clause = match let_syntax.let_else_clause(syntax_db) {
+                ast::OptionLetElseClause::Empty(_) => None,
+                ast::OptionLetElseClause::LetElseClause(else_clause) => {
+                    let else_block_syntax = ast::Expr::Block(else_clause.else_block(syntax_db));
+                    let else_expr = compute_expr_semantic(ctx, &else_block_syntax);
+                    let else_ty = ctx.reduce_ty(else_expr.ty());
+                    if !else_ty.is_missing(db) && else_ty != never_ty(db) {
+                        ctx.diagnostics.report(&else_block_syntax, NonNeverLetElseType);
+                    }
+                    Some(else_expr.id)
+                }
+            };
+
             let pattern = compute_pattern_semantic(
                 ctx,
                 &let_syntax.pattern(syntax_db),
@@ -2426,6 +2990,7 @@ pub fn compute_statement_semantic(
             semantic::Statement::Let(semantic::StatementLet {
                 pattern: pattern.id,
                 expr: rhs_expr_id,
+                else_clause,
                 stable_ptr: syntax.stable_ptr(),
             })
         }
@@ -2473,6 +3038,9 @@ pub fn compute_statement_semantic(
             })
         }
         ast::Statement::Return(return_syntax) => {
+            if !ctx.closure_ctxs.is_empty() {
+                return Err(ctx.diagnostics.report(return_syntax, ReturnNotAllowedInsideAClosure));
+            }
             if ctx.loop_ctx.is_some() {
                 return Err(ctx.diagnostics.report(return_syntax, ReturnNotAllowedInsideALoop));
             }
@@ -2530,7 +3098,7 @@ pub fn compute_statement_semantic(
                         );
                     };
                 }
-                Some(LoopContext::While) => {
+                Some(LoopContext::While | LoopContext::For) => {
                     if expr_option.is_some() {
                         ctx.diagnostics.report(break_syntax, BreakWithValueOnlyAllowedInsideALoop);
                     };
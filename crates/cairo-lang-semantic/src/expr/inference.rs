@@ -8,7 +8,7 @@ use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{
     ConstantId, EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, GenericParamId,
     ImplAliasId, ImplDefId, ImplFunctionId, LanguageElementId, LocalVarId, LookupItemId, MemberId,
-    ParamId, StructId, TraitFunctionId, TraitId, VarId, VariantId,
+    ParamId, StructId, TraitConstantId, TraitFunctionId, TraitId, TraitTypeId, VarId, VariantId,
 };
 use cairo_lang_diagnostics::DiagnosticAdded;
 use cairo_lang_proc_macros::{DebugWithDb, SemanticObject};
@@ -31,11 +31,15 @@ use crate::items::functions::{
     GenericFunctionWithBodyId, ImplGenericFunctionId, ImplGenericFunctionWithBodyId,
 };
 use crate::items::generics::{GenericParamConst, GenericParamImpl, GenericParamType};
-use crate::items::imp::{ImplId, ImplLookupContext, UninferredImpl};
+use crate::items::imp::{
+    reduce_impl_type, ImplConstantId, ImplId, ImplLookupContext, ImplTypeId, UninferredImpl,
+};
 use crate::items::trt::{ConcreteTraitGenericFunctionId, ConcreteTraitGenericFunctionLongId};
 use crate::literals::LiteralId;
 use crate::substitution::{HasDb, SemanticRewriter, SubstitutionRewriter};
-use crate::types::{ConcreteEnumLongId, ConcreteExternTypeLongId, ConcreteStructLongId};
+use crate::types::{
+    ClosureTypeLongId, ConcreteEnumLongId, ConcreteExternTypeLongId, ConcreteStructLongId,
+};
 use crate::{
     add_basic_rewrites, add_expr_rewrites, add_rewrite, semantic_object_for_id, ConcreteEnumId,
     ConcreteExternTypeId, ConcreteFunction, ConcreteImplId, ConcreteImplLongId, ConcreteStructId,
@@ -600,11 +604,22 @@ impl<'db> Inference<'db> {
         let generic_args = concrete_trait_id.generic_args(self.db);
         match generic_args.first() {
             Some(GenericArgumentId::Type(ty)) => {
-                if let TypeLongId::Var(_) = self.db.lookup_intern_type(*ty) {
-                    // Don't try to infer such impls.
-                    return Ok(SolutionSet::Ambiguous(Ambiguity::WillNotInfer {
-                        concrete_trait_id,
-                    }));
+                match self.db.lookup_intern_type(*ty) {
+                    TypeLongId::Var(_) => {
+                        // Don't try to infer such impls.
+                        return Ok(SolutionSet::Ambiguous(Ambiguity::WillNotInfer {
+                            concrete_trait_id,
+                        }));
+                    }
+                    TypeLongId::ImplType(impl_type_id)
+                        if matches!(impl_type_id.impl_id(), ImplId::ImplVar(_)) =>
+                    {
+                        // The type is not known until the impl is inferred.
+                        return Ok(SolutionSet::Ambiguous(Ambiguity::WillNotInfer {
+                            concrete_trait_id,
+                        }));
+                    }
+                    _ => {}
                 }
             }
             Some(GenericArgumentId::Impl(ImplId::ImplVar(_))) => {
@@ -701,7 +716,17 @@ impl<'a> SemanticRewriter<TypeLongId, NoError> for Inference<'a> {
                 return self.rewrite(self.db.lookup_intern_type(*type_id));
             }
         }
-        value.default_rewrite(self)
+        let value = value.default_rewrite(self)?;
+        // An impl type of an inferred concrete impl is reduced to the type defined by the impl.
+        if let TypeLongId::ImplType(impl_type_id) = value {
+            if let ImplId::Concrete(_) = impl_type_id.impl_id() {
+                return Ok(match reduce_impl_type(self.db, impl_type_id) {
+                    Ok(ty) => self.db.lookup_intern_type(ty),
+                    Err(diag_added) => TypeLongId::Missing(diag_added),
+                });
+            }
+        }
+        Ok(value)
     }
 }
 impl<'a> SemanticRewriter<ImplId, NoError> for Inference<'a> {
@@ -1,11 +1,11 @@
-use itertools::zip_eq;
+use itertools::{chain, zip_eq};
 
 use super::canonic::ResultNoErrEx;
 use super::{Inference, InferenceError, InferenceResult, InferenceVar};
 use crate::corelib::never_ty;
-use crate::items::imp::ImplId;
+use crate::items::imp::{ImplId, ImplTypeId};
 use crate::substitution::SemanticRewriter;
-use crate::types::peel_snapshots;
+use crate::types::{peel_snapshots, ClosureTypeLongId};
 use crate::{
     ConcreteImplLongId, ConcreteTraitId, ConcreteTraitLongId, ConcreteTypeId, GenericArgumentId,
     TypeId, TypeLongId,
@@ -44,6 +44,7 @@ pub trait InferenceConform {
         var: InferenceVar,
     ) -> bool;
     fn impl_contains_var(&mut self, impl_id: &ImplId, var: InferenceVar) -> bool;
+    fn is_impl_type_of_var(&self, ty: TypeId) -> bool;
 }
 
 impl<'db> InferenceConform for Inference<'db> {
@@ -62,8 +63,14 @@ impl<'db> InferenceConform for Inference<'db> {
         ty1: TypeId,
         ty0_is_self: bool,
     ) -> Result<(TypeId, usize), InferenceError> {
-        let ty0 = self.rewrite(ty0).no_err();
-        let ty1 = self.rewrite(ty1).no_err();
+        let mut ty0 = self.rewrite(ty0).no_err();
+        let mut ty1 = self.rewrite(ty1).no_err();
+        // An impl type of an uninferred impl may only be reduced once the impl is inferred.
+        if self.is_impl_type_of_var(ty0) || self.is_impl_type_of_var(ty1) {
+            self.solve()?;
+            ty0 = self.rewrite(ty0).no_err();
+            ty1 = self.rewrite(ty1).no_err();
+        }
         if ty0 == never_ty(self.db) || ty0.is_missing(self.db) {
             return Ok((ty1, 0));
         }
@@ -131,6 +138,43 @@ impl<'db> InferenceConform for Inference<'db> {
                 Ok((self.db.intern_type(TypeLongId::Snapshot(ty)), n_snapshots))
             }
             TypeLongId::GenericParameter(_) => Err(InferenceError::TypeKindMismatch { ty0, ty1 }),
+            TypeLongId::Closure(closure0) => {
+                let (n_snapshots, long_ty1) = self.maybe_peel_snapshots(ty0_is_self, ty1);
+                let TypeLongId::Closure(closure1) = long_ty1 else {
+                    return Err(InferenceError::TypeKindMismatch { ty0, ty1 });
+                };
+                if closure0.stable_ptr != closure1.stable_ptr
+                    || closure0.param_tys.len() != closure1.param_tys.len()
+                    || closure0.captured_types.len() != closure1.captured_types.len()
+                {
+                    return Err(InferenceError::TypeKindMismatch { ty0, ty1 });
+                }
+                let param_tys = zip_eq(closure0.param_tys, closure1.param_tys)
+                    .map(|(subty0, subty1)| self.conform_ty(subty0, subty1))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let ret_ty = self.conform_ty(closure0.ret_ty, closure1.ret_ty)?;
+                let captured_types = zip_eq(closure0.captured_types, closure1.captured_types)
+                    .map(|(subty0, subty1)| self.conform_ty(subty0, subty1))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let long_ty = TypeLongId::Closure(ClosureTypeLongId {
+                    param_tys,
+                    ret_ty,
+                    captured_types,
+                    ..closure0
+                });
+                Ok((self.db.intern_type(long_ty), n_snapshots))
+            }
+            TypeLongId::ImplType(impl_type0) => {
+                let TypeLongId::ImplType(impl_type1) = long_ty1 else {
+                    return Err(InferenceError::TypeKindMismatch { ty0, ty1 });
+                };
+                if impl_type0.ty() != impl_type1.ty() {
+                    return Err(InferenceError::TypeKindMismatch { ty0, ty1 });
+                }
+                let impl_id = self.conform_impl(impl_type0.impl_id(), impl_type1.impl_id())?;
+                let long_ty = TypeLongId::ImplType(ImplTypeId::new(impl_id, impl_type0.ty()));
+                Ok((self.db.intern_type(long_ty), n_snapshots))
+            }
             TypeLongId::Var(var) => Ok((self.assign_ty(var, ty1)?, n_snapshots)),
             TypeLongId::Missing(_) => Ok((ty0, n_snapshots)),
         }
@@ -234,7 +278,15 @@ impl<'db> InferenceConform for Inference<'db> {
                     generic_args,
                 })))
             }
-            ImplId::GenericParameter(_) => Err(InferenceError::ImplKindMismatch { impl0, impl1 }),
+            ImplId::GeneratedImpl(concrete_trait0) => {
+                let ImplId::GeneratedImpl(concrete_trait1) = impl1 else {
+                    return Err(InferenceError::ImplKindMismatch { impl0, impl1 });
+                };
+                Ok(ImplId::GeneratedImpl(self.conform_traits(concrete_trait0, concrete_trait1)?))
+            }
+            ImplId::GenericParameter(_) | ImplId::SelfImpl(_) => {
+                Err(InferenceError::ImplKindMismatch { impl0, impl1 })
+            }
         }
     }
 
@@ -267,6 +319,13 @@ impl<'db> InferenceConform for Inference<'db> {
                 tys.into_iter().map(|ty| self.ty_contains_var(ty, var)).any(|x| x)
             }
             TypeLongId::Snapshot(ty) => self.ty_contains_var(ty, var),
+            TypeLongId::ImplType(impl_type_id) => {
+                self.impl_contains_var(&impl_type_id.impl_id(), var)
+            }
+            TypeLongId::Closure(closure) => {
+                chain!(closure.param_tys, [closure.ret_ty], closure.captured_types)
+                    .any(|ty| self.ty_contains_var(ty, var))
+            }
             TypeLongId::Var(new_var) => {
                 if InferenceVar::Type(new_var.id) == var {
                     return true;
@@ -309,6 +368,9 @@ impl<'db> InferenceConform for Inference<'db> {
                 var,
             ),
             ImplId::GenericParameter(_) => false,
+            ImplId::GeneratedImpl(concrete_trait) | ImplId::SelfImpl(concrete_trait) => {
+                self.generic_args_contain_var(&concrete_trait.generic_args(self.db), var)
+            }
             ImplId::ImplVar(new_var) => {
                 if InferenceVar::Impl(new_var.get(self.db).id) == var {
                     return true;
@@ -320,4 +382,12 @@ impl<'db> InferenceConform for Inference<'db> {
             }
         }
     }
+
+    /// Checks if a type is an impl type of an impl variable, which is not yet inferred.
+    fn is_impl_type_of_var(&self, ty: TypeId) -> bool {
+        matches!(
+            self.db.lookup_intern_type(ty),
+            TypeLongId::ImplType(impl_type_id) if matches!(impl_type_id.impl_id(), ImplId::ImplVar(_))
+        )
+    }
 }
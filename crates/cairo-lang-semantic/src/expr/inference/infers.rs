@@ -1,18 +1,19 @@
-use cairo_lang_defs::ids::{ImplAliasId, ImplDefId, TraitFunctionId};
+use cairo_lang_defs::ids::{ImplAliasId, ImplDefId, TraitFunctionId, TraitId};
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
-use cairo_lang_utils::extract_matches;
+use cairo_lang_utils::{extract_matches, try_extract_matches};
 use itertools::Itertools;
 
 use super::canonic::ResultNoErrEx;
 use super::conform::InferenceConform;
 use super::{Inference, InferenceError, InferenceResult};
+use crate::corelib::{copy_trait, drop_trait, fn_once_trait, fn_trait};
 use crate::items::functions::{GenericFunctionId, ImplGenericFunctionId};
 use crate::items::imp::{ImplId, ImplLookupContext, UninferredImpl};
 use crate::items::trt::ConcreteTraitGenericFunctionId;
 use crate::substitution::{GenericSubstitution, SemanticRewriter, SubstitutionRewriter};
 use crate::{
     ConcreteFunction, ConcreteImplLongId, ConcreteTraitId, ConcreteTraitLongId, FunctionId,
-    FunctionLongId, GenericArgumentId, GenericParam, TypeId,
+    FunctionLongId, GenericArgumentId, GenericParam, TypeId, TypeLongId,
 };
 
 /// Functions for embedding generic semantic objects in an existing [Inference] object, by
@@ -39,6 +40,13 @@ pub trait InferenceEmbeddings {
         lookup_context: &ImplLookupContext,
         stable_ptr: Option<SyntaxStablePtrId>,
     ) -> InferenceResult<ImplId>;
+    fn infer_generated_impl(
+        &mut self,
+        trait_id: TraitId,
+        concrete_trait_id: ConcreteTraitId,
+        lookup_context: &ImplLookupContext,
+        stable_ptr: Option<SyntaxStablePtrId>,
+    ) -> InferenceResult<ImplId>;
     fn infer_generic_assignment(
         &mut self,
         generic_params: &[GenericParam],
@@ -111,6 +119,9 @@ impl<'db> InferenceEmbeddings for Inference<'db> {
                 self.conform_traits(concrete_trait_id, imp_concrete_trait_id)?;
                 ImplId::GenericParameter(param_id)
             }
+            UninferredImpl::GeneratedImpl(trait_id) => {
+                self.infer_generated_impl(trait_id, concrete_trait_id, lookup_context, stable_ptr)?
+            }
         };
         Ok(impl_id)
     }
@@ -147,6 +158,65 @@ impl<'db> InferenceEmbeddings for Inference<'db> {
         ))
     }
 
+    /// Infers all the variables required to make a compiler generated impl provide a concrete
+    /// trait. Currently, such impls exist only for closure types: `Fn`, `FnOnce`, `Copy` and
+    /// `Drop`.
+    fn infer_generated_impl(
+        &mut self,
+        trait_id: TraitId,
+        concrete_trait_id: ConcreteTraitId,
+        lookup_context: &ImplLookupContext,
+        stable_ptr: Option<SyntaxStablePtrId>,
+    ) -> InferenceResult<ImplId> {
+        let db = self.db;
+        let concrete_trait_id = self.rewrite(concrete_trait_id).no_err();
+        if trait_id != concrete_trait_id.trait_id(db) {
+            return Err(InferenceError::TraitMismatch {
+                trt0: trait_id,
+                trt1: concrete_trait_id.trait_id(db),
+            });
+        }
+        let generic_args = concrete_trait_id.generic_args(db);
+        let Some(TypeLongId::Closure(closure)) = generic_args
+            .first()
+            .and_then(|arg| try_extract_matches!(arg, GenericArgumentId::Type))
+            .map(|ty| db.lookup_intern_type(*ty))
+        else {
+            return Err(InferenceError::NoImplsFound { concrete_trait_id });
+        };
+
+        let is_fn = trait_id == fn_trait(db);
+        let captured_types_trait = if is_fn || trait_id == copy_trait(db) {
+            Some(copy_trait(db))
+        } else if trait_id == drop_trait(db) {
+            Some(drop_trait(db))
+        } else if trait_id == fn_once_trait(db) {
+            None
+        } else {
+            return Err(InferenceError::NoImplsFound { concrete_trait_id });
+        };
+        if is_fn || trait_id == fn_once_trait(db) {
+            let [_, GenericArgumentId::Type(args_ty), GenericArgumentId::Type(ret_ty)] =
+                generic_args[..]
+            else {
+                return Err(InferenceError::NoImplsFound { concrete_trait_id });
+            };
+            let params_ty = db.intern_type(TypeLongId::Tuple(closure.param_tys));
+            self.conform_ty(params_ty, args_ty)?;
+            self.conform_ty(closure.ret_ty, ret_ty)?;
+        }
+        if let Some(captured_types_trait) = captured_types_trait {
+            for ty in closure.captured_types {
+                let concrete_trait_id = db.intern_concrete_trait(ConcreteTraitLongId {
+                    trait_id: captured_types_trait,
+                    generic_args: vec![GenericArgumentId::Type(ty)],
+                });
+                self.new_impl_var(concrete_trait_id, stable_ptr, lookup_context.clone())?;
+            }
+        }
+        Ok(ImplId::GeneratedImpl(self.rewrite(concrete_trait_id).no_err()))
+    }
+
     /// Infers all the variables required to make an impl alias (possibly with free generic params)
     /// provide a concrete trait.
     fn infer_impl_alias(
@@ -219,7 +289,7 @@ impl<'db> InferenceEmbeddings for Inference<'db> {
             let generic_arg =
                 self.infer_generic_arg(&generic_param, lookup_context.clone(), stable_ptr)?;
             generic_args.push(generic_arg);
-            substitution.0.insert(generic_param.id(), generic_arg);
+            substitution.insert(generic_param.id(), generic_arg);
         }
         Ok(generic_args)
     }
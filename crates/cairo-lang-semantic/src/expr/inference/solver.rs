@@ -124,8 +124,14 @@ impl Solver {
         lookup_context: ImplLookupContext,
     ) -> Self {
         let filter = canonical_trait.0.filter(db);
-        let candidates =
+        let mut candidates =
             find_candidates_at_context(db, &lookup_context, filter).unwrap_or_default();
+        // Closure types get compiler generated impls for their traits.
+        if let Some(GenericArgumentId::Type(ty)) = canonical_trait.0.generic_args(db).first() {
+            if matches!(db.lookup_intern_type(*ty), TypeLongId::Closure(_)) {
+                candidates.insert(UninferredImpl::GeneratedImpl(canonical_trait.0.trait_id(db)));
+            }
+        }
         let candidate_solvers = candidates
             .into_iter()
             .filter_map(|candidate| {
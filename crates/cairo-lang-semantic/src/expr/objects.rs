@@ -8,6 +8,7 @@ use num_bigint::BigInt;
 
 use super::fmt::ExprFormatter;
 use super::pattern::Pattern;
+use crate::items::imp::ImplConstantId;
 use crate::{semantic, ConcreteStructId, FunctionId, TypeId};
 
 pub type PatternId = Id<Pattern>;
@@ -80,6 +81,7 @@ pub struct StatementExpr {
 pub struct StatementLet {
     pub pattern: PatternId,
     pub expr: ExprId,
+    pub else_clause: Option<ExprId>,
     #[hide_field_debug_with_db]
     #[dont_rewrite]
     pub stable_ptr: ast::StatementPtr,
@@ -123,6 +125,8 @@ pub enum Expr {
     Block(ExprBlock),
     Loop(ExprLoop),
     While(ExprWhile),
+    For(ExprFor),
+    Closure(ExprClosure),
     FunctionCall(ExprFunctionCall),
     Match(ExprMatch),
     If(ExprIf),
@@ -134,6 +138,7 @@ pub enum Expr {
     EnumVariantCtor(ExprEnumVariantCtor),
     PropagateError(ExprPropagateError),
     Constant(ExprConstant),
+    ImplConstant(ExprImplConstant),
     Missing(ExprMissing),
 }
 impl Expr {
@@ -147,6 +152,8 @@ impl Expr {
             Expr::Block(expr) => expr.ty,
             Expr::Loop(expr) => expr.ty,
             Expr::While(expr) => expr.ty,
+            Expr::For(expr) => expr.ty,
+            Expr::Closure(expr) => expr.ty,
             Expr::FunctionCall(expr) => expr.ty,
             Expr::Match(expr) => expr.ty,
             Expr::If(expr) => expr.ty,
@@ -158,6 +165,7 @@ impl Expr {
             Expr::EnumVariantCtor(expr) => expr.ty,
             Expr::PropagateError(expr) => expr.ok_variant.ty,
             Expr::Constant(expr) => expr.ty,
+            Expr::ImplConstant(expr) => expr.ty,
             Expr::Missing(expr) => expr.ty,
         }
     }
@@ -171,6 +179,8 @@ impl Expr {
             Expr::Block(expr) => expr.stable_ptr,
             Expr::Loop(expr) => expr.stable_ptr,
             Expr::While(expr) => expr.stable_ptr,
+            Expr::For(expr) => expr.stable_ptr,
+            Expr::Closure(expr) => expr.stable_ptr,
             Expr::FunctionCall(expr) => expr.stable_ptr,
             Expr::Match(expr) => expr.stable_ptr,
             Expr::If(expr) => expr.stable_ptr,
@@ -182,6 +192,7 @@ impl Expr {
             Expr::EnumVariantCtor(expr) => expr.stable_ptr,
             Expr::PropagateError(expr) => expr.stable_ptr,
             Expr::Constant(expr) => expr.stable_ptr,
+            Expr::ImplConstant(expr) => expr.stable_ptr,
             Expr::Missing(expr) => expr.stable_ptr,
         }
     }
@@ -261,6 +272,44 @@ pub struct ExprWhile {
     pub stable_ptr: ast::ExprPtr,
 }
 
+/// A `for` loop. `for pattern in expr { body }` is computed as a loop over the iterator
+/// `IntoIterator::into_iter(expr)`, calling `Iterator::next` on it until `None` is returned.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprFor {
+    /// The `IntoIterator::into_iter` function applied to the iterated expression.
+    pub into_iter: FunctionId,
+    /// The member path of the hidden variable holding the iterator.
+    pub into_iter_member_path: ExprVarMemberPath,
+    /// The `Iterator::next` function called on the iterator in each iteration.
+    pub next_function_id: FunctionId,
+    /// The iterated expression, with snapshots added if required by `into_iter`.
+    pub expr_id: ExprId,
+    /// The pattern bound to each item yielded by the iterator.
+    pub pattern: PatternId,
+    pub body: ExprId,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::ExprPtr,
+}
+
+/// A closure expression, `|params| body`. Its value holds the variables captured from the
+/// enclosing scopes, and its type is a unique closure type.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprClosure {
+    pub params: Vec<PatternId>,
+    pub body: ExprId,
+    /// The variables captured by the closure. Captured variables are moved (or copied) into the
+    /// closure value.
+    pub captured_vars: Vec<ExprVar>,
+    pub ty: semantic::TypeId,
+    #[hide_field_debug_with_db]
+    #[dont_rewrite]
+    pub stable_ptr: ast::ExprPtr,
+}
+
 /// A sequence of member accesses of a variable. For example: a, a.b, a.b.c, ...
 #[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
 pub enum ExprVarMemberPath {
@@ -493,6 +542,17 @@ pub struct ExprConstant {
     pub stable_ptr: ast::ExprPtr,
 }
 
+/// A usage of an associated constant of an impl, e.g. `T::SIZE`.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
+#[debug_db(ExprFormatter<'a>)]
+pub struct ExprImplConstant {
+    pub impl_constant_id: ImplConstantId,
+    pub ty: semantic::TypeId,
+    #[dont_rewrite]
+    #[hide_field_debug_with_db]
+    pub stable_ptr: ast::ExprPtr,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq, DebugWithDb, SemanticObject)]
 #[debug_db(ExprFormatter<'a>)]
 pub struct ExprMissing {
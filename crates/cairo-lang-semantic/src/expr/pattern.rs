@@ -72,7 +72,7 @@ impl Pattern {
             Pattern::Literal(pattern) => pattern.stable_ptr,
             Pattern::StringLiteral(pattern) => pattern.stable_ptr,
             Pattern::Variable(pattern) => pattern.stable_ptr,
-            Pattern::Struct(pattern) => pattern.stable_ptr.into(),
+            Pattern::Struct(pattern) => pattern.stable_ptr,
             Pattern::Tuple(pattern) => pattern.stable_ptr.into(),
             Pattern::EnumVariant(pattern) => pattern.stable_ptr,
             Pattern::Otherwise(pattern) => pattern.stable_ptr.into(),
@@ -126,7 +126,7 @@ pub struct PatternStruct {
     pub n_snapshots: usize,
     #[hide_field_debug_with_db]
     #[dont_rewrite]
-    pub stable_ptr: ast::PatternStructPtr,
+    pub stable_ptr: ast::PatternPtr,
 }
 
 /// A pattern that destructures a tuple to its fields.
@@ -28,10 +28,12 @@ cairo_lang_test_utils::test_file_test!(
     "src/expr/test_data",
     {
         assignment: "assignment",
+        closure: "closure",
         constant: "constant",
         constructor: "constructor",
         enum_: "enum",
         error_propagate: "error_propagate",
+        for_: "for",
         function_call: "function_call",
         generics: "generics",
         if_: "if",
@@ -62,6 +64,7 @@ cairo_lang_test_utils::test_file_test!(
         assignment: "assignment",
         block: "block",
         call: "call",
+        closure: "closure",
         inline_macros: "inline_macros",
         let_statement: "let_statement",
         literals: "literals",
@@ -1,6 +1,7 @@
 use cairo_lang_defs::patcher::PatchBuilder;
 use cairo_lang_defs::plugin::{
-    InlineMacroExprPlugin, InlinePluginResult, NamedPlugin, PluginGeneratedFile,
+    InlineMacroExprPlugin, InlineMacroSignature, InlinePluginResult, NamedPlugin,
+    PluginGeneratedFile,
 };
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
@@ -47,4 +48,8 @@ impl InlineMacroExprPlugin for ArrayMacro {
             diagnostics: vec![],
         }
     }
+
+    fn signature(&self) -> Option<InlineMacroSignature> {
+        Some(InlineMacroSignature { params: vec!["elements"], variadic: true })
+    }
 }
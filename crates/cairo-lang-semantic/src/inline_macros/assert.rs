@@ -1,7 +1,9 @@
 use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
-    InlineMacroExprPlugin, InlinePluginResult, NamedPlugin, PluginDiagnostic, PluginGeneratedFile,
+    InlineMacroExprPlugin, InlineMacroSignature, InlinePluginResult, NamedPlugin, PluginDiagnostic,
+    PluginGeneratedFile,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::ast::WrappedArgList;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
@@ -28,19 +30,28 @@ impl InlineMacroExprPlugin for AssertMacro {
         let Some((value, format_args)) = arguments.split_first() else {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    arguments_syntax.lparen(db).stable_ptr().untyped(),
-                    format!("Macro `{}` requires at least 1 argument.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        arguments_syntax.lparen(db).stable_ptr().untyped(),
+                        format!("Macro `{}` requires at least 1 argument.", Self::NAME),
+                    )
+                    .with_error_code(error_code!("E0560")),
+                ],
             };
         };
         let Some(value) = try_extract_unnamed_arg(db, value) else {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    value.stable_ptr().untyped(),
-                    format!("Macro `{}` requires the first argument to be unnamed.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        value.stable_ptr().untyped(),
+                        format!(
+                            "Macro `{}` requires the first argument to be unnamed.",
+                            Self::NAME
+                        ),
+                    )
+                    .with_error_code(error_code!("E0561")),
+                ],
             };
         };
         let f = "__formatter_for_assert_macro__";
@@ -107,4 +118,8 @@ impl InlineMacroExprPlugin for AssertMacro {
             diagnostics: vec![],
         }
     }
+
+    fn signature(&self) -> Option<InlineMacroSignature> {
+        Some(InlineMacroSignature { params: vec!["cond: bool", "fmt", "args"], variadic: true })
+    }
 }
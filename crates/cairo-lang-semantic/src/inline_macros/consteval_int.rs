@@ -1,6 +1,8 @@
 use cairo_lang_defs::plugin::{
-    InlineMacroExprPlugin, InlinePluginResult, NamedPlugin, PluginDiagnostic, PluginGeneratedFile,
+    InlineMacroExprPlugin, InlineMacroSignature, InlinePluginResult, NamedPlugin, PluginDiagnostic,
+    PluginGeneratedFile,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::ids::{CodeMapping, CodeOrigin};
 use cairo_lang_filesystem::span::{TextOffset, TextSpan, TextWidth};
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -48,6 +50,10 @@ impl InlineMacroExprPlugin for ConstevalIntMacro {
             diagnostics,
         }
     }
+
+    fn signature(&self) -> Option<InlineMacroSignature> {
+        Some(InlineMacroSignature { params: vec!["value"], variadic: false })
+    }
 }
 
 /// Compute the actual value of an integer expression, or fail with diagnostics.
@@ -93,10 +99,13 @@ pub fn compute_constant_expr(
                     ^ compute_constant_expr(db, &bin_expr.rhs(db), diagnostics)?,
             ),
             _ => {
-                diagnostics.push(PluginDiagnostic::error(
-                    bin_expr.stable_ptr().untyped(),
-                    "Unsupported binary operator in consteval_int macro".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        bin_expr.stable_ptr().untyped(),
+                        "Unsupported binary operator in consteval_int macro".to_string(),
+                    )
+                    .with_error_code(error_code!("E0562")),
+                );
                 None
             }
         },
@@ -105,10 +114,13 @@ pub fn compute_constant_expr(
                 Some(-compute_constant_expr(db, &un_expr.expr(db), diagnostics)?)
             }
             _ => {
-                diagnostics.push(PluginDiagnostic::error(
-                    un_expr.stable_ptr().untyped(),
-                    "Unsupported unary operator in consteval_int macro".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        un_expr.stable_ptr().untyped(),
+                        "Unsupported unary operator in consteval_int macro".to_string(),
+                    )
+                    .with_error_code(error_code!("E0563")),
+                );
                 None
             }
         },
@@ -116,10 +128,13 @@ pub fn compute_constant_expr(
             compute_constant_expr(db, &paren_expr.expr(db), diagnostics)
         }
         _ => {
-            diagnostics.push(PluginDiagnostic::error(
-                value.stable_ptr().untyped(),
-                "Unsupported expression in consteval_int macro".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    value.stable_ptr().untyped(),
+                    "Unsupported expression in consteval_int macro".to_string(),
+                )
+                .with_error_code(error_code!("E0564")),
+            );
             None
         }
     }
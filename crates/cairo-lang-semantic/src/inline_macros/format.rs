@@ -1,6 +1,7 @@
 use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
-    InlineMacroExprPlugin, InlinePluginResult, NamedPlugin, PluginGeneratedFile,
+    InlineMacroExprPlugin, InlineMacroSignature, InlinePluginResult, NamedPlugin,
+    PluginGeneratedFile,
 };
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::WrappedArgListHelper;
@@ -60,4 +61,8 @@ impl InlineMacroExprPlugin for FormatMacro {
             diagnostics: vec![],
         }
     }
+
+    fn signature(&self) -> Option<InlineMacroSignature> {
+        Some(InlineMacroSignature { params: vec!["fmt", "args"], variadic: true })
+    }
 }
@@ -7,6 +7,7 @@ mod print;
 mod write;
 
 use cairo_lang_defs::plugin::{InlinePluginResult, PluginDiagnostic};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_plugins::get_base_plugins;
 use cairo_lang_syntax::node::ast::{self};
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -49,13 +50,16 @@ pub fn unsupported_bracket_diagnostic(
 ) -> InlinePluginResult {
     InlinePluginResult {
         code: None,
-        diagnostics: vec![PluginDiagnostic::error(
-            macro_ast.arguments(db).left_bracket_stable_ptr(db),
-            format!(
-                "Macro `{}` does not support this bracket type.",
-                macro_ast.path(db).as_syntax_node().get_text_without_trivia(db)
-            ),
-        )],
+        diagnostics: vec![
+            PluginDiagnostic::error(
+                macro_ast.arguments(db).left_bracket_stable_ptr(db),
+                format!(
+                    "Macro `{}` does not support this bracket type.",
+                    macro_ast.path(db).as_syntax_node().get_text_without_trivia(db)
+                ),
+            )
+            .with_error_code(error_code!("E0565")),
+        ],
     }
 }
 
@@ -125,14 +129,17 @@ macro_rules! extract_macro_unnamed_args {
 
         let args = $crate::inline_macros::extract_unnamed_args($db, &macro_arg_list, $n);
         let Some(args) = args else {
-            let diagnostics = vec![PluginDiagnostic::error(
-                $syntax.stable_ptr().untyped(),
-                format!(
-                    "Macro `{}` must have exactly {} unnamed arguments.",
-                    $syntax.path($db).as_syntax_node().get_text_without_trivia($db),
-                    $n
-                ),
-            )];
+            let diagnostics = vec![
+                PluginDiagnostic::error(
+                    $syntax.stable_ptr().untyped(),
+                    format!(
+                        "Macro `{}` must have exactly {} unnamed arguments.",
+                        $syntax.path($db).as_syntax_node().get_text_without_trivia($db),
+                        $n
+                    ),
+                )
+                .with_error_code(error_code!("E0566")),
+            ];
             return InlinePluginResult { code: None, diagnostics };
         };
         let args: [ast::Expr; $n] = args.try_into().unwrap();
@@ -1,6 +1,7 @@
 use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
-    InlineMacroExprPlugin, InlinePluginResult, NamedPlugin, PluginGeneratedFile,
+    InlineMacroExprPlugin, InlineMacroSignature, InlinePluginResult, NamedPlugin,
+    PluginGeneratedFile,
 };
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::WrappedArgListHelper;
@@ -23,6 +24,10 @@ impl InlineMacroExprPlugin for PrintMacro {
     ) -> InlinePluginResult {
         generate_code_inner(syntax, db, false)
     }
+
+    fn signature(&self) -> Option<InlineMacroSignature> {
+        Some(InlineMacroSignature { params: vec!["fmt", "args"], variadic: true })
+    }
 }
 
 /// Macro for printing with a new line.
@@ -39,6 +44,10 @@ impl InlineMacroExprPlugin for PrintlnMacro {
     ) -> InlinePluginResult {
         generate_code_inner(syntax, db, true)
     }
+
+    fn signature(&self) -> Option<InlineMacroSignature> {
+        Some(InlineMacroSignature { params: vec!["fmt", "args"], variadic: true })
+    }
 }
 
 fn generate_code_inner(
@@ -2,8 +2,10 @@ use std::fmt;
 
 use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
-    InlineMacroExprPlugin, InlinePluginResult, NamedPlugin, PluginDiagnostic, PluginGeneratedFile,
+    InlineMacroExprPlugin, InlineMacroSignature, InlinePluginResult, NamedPlugin, PluginDiagnostic,
+    PluginGeneratedFile,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_filesystem::span::{TextSpan, TextWidth};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
@@ -26,6 +28,10 @@ impl InlineMacroExprPlugin for WriteMacro {
     ) -> InlinePluginResult {
         generate_code_inner(syntax, db, false)
     }
+
+    fn signature(&self) -> Option<InlineMacroSignature> {
+        Some(InlineMacroSignature { params: vec!["f: Formatter", "fmt", "args"], variadic: true })
+    }
 }
 
 /// Macro for writing into a formatter with an additional new line.
@@ -42,6 +48,10 @@ impl InlineMacroExprPlugin for WritelnMacro {
     ) -> InlinePluginResult {
         generate_code_inner(syntax, db, true)
     }
+
+    fn signature(&self) -> Option<InlineMacroSignature> {
+        Some(InlineMacroSignature { params: vec!["f: Formatter", "fmt", "args"], variadic: true })
+    }
 }
 
 fn generate_code_inner(
@@ -98,51 +108,72 @@ impl FormattingInfo {
         let argument_list_elements = arguments.arguments(db).elements(db);
         let mut args_iter = argument_list_elements.iter();
         let Some(formatter_arg) = args_iter.next() else {
-            return Err(vec![PluginDiagnostic::error(
-                arguments.lparen(db).stable_ptr().untyped(),
-                "Macro expected formatter argument.".to_string(),
-            )]);
+            return Err(vec![
+                PluginDiagnostic::error(
+                    arguments.lparen(db).stable_ptr().untyped(),
+                    "Macro expected formatter argument.".to_string(),
+                )
+                .with_error_code(error_code!("E0567")),
+            ]);
         };
         let Some(formatter_expr) = try_extract_unnamed_arg(db, formatter_arg) else {
-            return Err(vec![PluginDiagnostic::error(
-                formatter_arg.stable_ptr().untyped(),
-                "Formatter argument must unnamed.".to_string(),
-            )]);
+            return Err(vec![
+                PluginDiagnostic::error(
+                    formatter_arg.stable_ptr().untyped(),
+                    "Formatter argument must unnamed.".to_string(),
+                )
+                .with_error_code(error_code!("E0568")),
+            ]);
         };
         if matches!(formatter_expr, ast::Expr::String(_)) {
-            return Err(vec![PluginDiagnostic::error(
-                formatter_arg.stable_ptr().untyped(),
-                "Formatter argument must not be a string literal.".to_string(),
-            )]);
+            return Err(vec![
+                PluginDiagnostic::error(
+                    formatter_arg.stable_ptr().untyped(),
+                    "Formatter argument must not be a string literal.".to_string(),
+                )
+                .with_error_code(error_code!("E0569")),
+            ]);
         }
         let Some(format_string_arg) = args_iter.next() else {
-            return Err(vec![PluginDiagnostic::error(
-                arguments.lparen(db).stable_ptr().untyped(),
-                "Macro expected format string argument.".to_string(),
-            )]);
+            return Err(vec![
+                PluginDiagnostic::error(
+                    arguments.lparen(db).stable_ptr().untyped(),
+                    "Macro expected format string argument.".to_string(),
+                )
+                .with_error_code(error_code!("E0570")),
+            ]);
         };
         let Some(format_string_expr) = try_extract_unnamed_arg(db, format_string_arg) else {
-            return Err(vec![PluginDiagnostic::error(
-                format_string_arg.stable_ptr().untyped(),
-                "Format string argument must be unnamed.".to_string(),
-            )]);
+            return Err(vec![
+                PluginDiagnostic::error(
+                    format_string_arg.stable_ptr().untyped(),
+                    "Format string argument must be unnamed.".to_string(),
+                )
+                .with_error_code(error_code!("E0571")),
+            ]);
         };
         let Some(format_string) = try_extract_matches!(format_string_expr, ast::Expr::String)
             .and_then(|arg| arg.string_value(db))
         else {
-            return Err(vec![PluginDiagnostic::error(
-                format_string_arg.stable_ptr().untyped(),
-                "Format string argument must be a string literal.".to_string(),
-            )]);
+            return Err(vec![
+                PluginDiagnostic::error(
+                    format_string_arg.stable_ptr().untyped(),
+                    "Format string argument must be a string literal.".to_string(),
+                )
+                .with_error_code(error_code!("E0572")),
+            ]);
         };
         let mut diagnostics = vec![];
         let args: Vec<_> = args_iter
             .filter_map(|arg| {
                 try_extract_unnamed_arg(db, arg).on_none(|| {
-                    diagnostics.push(PluginDiagnostic::error(
-                        arg.stable_ptr().untyped(),
-                        "Expected unnamed argument.".to_string(),
-                    ))
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            arg.stable_ptr().untyped(),
+                            "Expected unnamed argument.".to_string(),
+                        )
+                        .with_error_code(error_code!("E0573")),
+                    )
                 })
             })
             .collect();
@@ -194,24 +225,30 @@ impl FormattingInfo {
                 let argument_info = match extract_placeholder_argument(&mut format_iter) {
                     Ok(argument_info) => argument_info,
                     Err(error_message) => {
-                        diagnostics.push(PluginDiagnostic::error(
-                            self.format_string_arg.as_syntax_node().stable_ptr(),
-                            format!("Invalid format string: {error_message}."),
-                        ));
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                self.format_string_arg.as_syntax_node().stable_ptr(),
+                                format!("Invalid format string: {error_message}."),
+                            )
+                            .with_error_code(error_code!("E0574")),
+                        );
                         return;
                     }
                 };
                 match argument_info.source {
                     PlaceholderArgumentSource::Positional(positional) => {
                         let Some(arg) = self.args.get(positional) else {
-                            diagnostics.push(PluginDiagnostic::error(
-                                self.format_string_arg.as_syntax_node().stable_ptr(),
-                                format!(
-                                    "Invalid reference to positional argument {positional} (there \
-                                     are {} arguments).",
-                                    self.args.len()
-                                ),
-                            ));
+                            diagnostics.push(
+                                PluginDiagnostic::error(
+                                    self.format_string_arg.as_syntax_node().stable_ptr(),
+                                    format!(
+                                        "Invalid reference to positional argument {positional} \
+                                         (there are {} arguments).",
+                                        self.args.len()
+                                    ),
+                                )
+                                .with_error_code(error_code!("E0575")),
+                            );
                             return;
                         };
                         arg_used[positional] = true;
@@ -264,24 +301,30 @@ impl FormattingInfo {
                     pending_chars.push('}');
                     format_iter.next();
                 } else {
-                    diagnostics.push(PluginDiagnostic::error(
-                        self.format_string_arg.as_syntax_node().stable_ptr(),
-                        "Closing `}` without a matching `{`.".to_string(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            self.format_string_arg.as_syntax_node().stable_ptr(),
+                            "Closing `}` without a matching `{`.".to_string(),
+                        )
+                        .with_error_code(error_code!("E0576")),
+                    );
                 }
             } else {
                 pending_chars.push(c);
             }
         }
         if missing_args > 0 {
-            diagnostics.push(PluginDiagnostic::error(
-                self.format_string_arg.as_syntax_node().stable_ptr(),
-                format!(
-                    "{} positional arguments in format string, but only {} arguments.",
-                    self.args.len() + missing_args,
-                    self.args.len()
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    self.format_string_arg.as_syntax_node().stable_ptr(),
+                    format!(
+                        "{} positional arguments in format string, but only {} arguments.",
+                        self.args.len() + missing_args,
+                        self.args.len()
+                    ),
+                )
+                .with_error_code(error_code!("E0577")),
+            );
             return;
         }
         if with_newline {
@@ -304,10 +347,13 @@ impl FormattingInfo {
         builder.add_str("}\n");
         for (position, used) in arg_used.into_iter().enumerate() {
             if !used {
-                diagnostics.push(PluginDiagnostic::error(
-                    self.args[position].as_syntax_node().stable_ptr(),
-                    "Unused argument.".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        self.args[position].as_syntax_node().stable_ptr(),
+                        "Unused argument.".to_string(),
+                    )
+                    .with_error_code(error_code!("E0578")),
+                );
             }
         }
     }
@@ -1,9 +1,12 @@
 use cairo_lang_defs::ids::{
-    EnumId, FreeFunctionId, FunctionWithBodyId, ImplAliasId, ImplDefId, ImplFunctionId, ImplTypeId,
-    ModuleId, StructId, SubmoduleId, TraitFunctionId, TraitId, TraitTypeId,
+    EnumId, FreeFunctionId, FunctionWithBodyId, ImplAliasId, ImplDefId, ImplFunctionId,
+    ImplTypeDefId, ModuleId, StructId, SubmoduleId, TraitFunctionId, TraitId, TraitTypeId,
 };
 use cairo_lang_diagnostics::Maybe;
+use cairo_lang_filesystem::db::LintLevel;
+use cairo_lang_syntax::attribute::consts::{ALLOW_ATTR, DENY_ATTR, WARN_ATTR};
 use cairo_lang_syntax::attribute::structured::Attribute;
+use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::TypedSyntaxNode;
 
 use crate::db::SemanticGroup;
@@ -83,6 +86,30 @@ fn is_single_arg_attr(db: &dyn SemanticGroup, attr: &Attribute, arg_name: &str)
     }
 }
 
+/// Returns the lint level set by the given attribute, if it is an `#[allow]`, `#[warn]` or
+/// `#[deny]` attribute.
+pub fn lint_level_attr(attr: &Attribute) -> Option<LintLevel> {
+    match attr.id.as_str() {
+        ALLOW_ATTR => Some(LintLevel::Allow),
+        WARN_ATTR => Some(LintLevel::Warn),
+        DENY_ATTR => Some(LintLevel::Deny),
+        _ => None,
+    }
+}
+
+/// Returns the level set for the lint (or lint group) named `lint_name` by the given attributes, if
+/// any. Later attributes take precedence over earlier ones.
+pub fn lint_level_from_attributes(
+    db: &dyn SyntaxGroup,
+    attributes: &[Attribute],
+    lint_name: &str,
+) -> Option<LintLevel> {
+    attributes.iter().rev().find_map(|attr| {
+        let level = lint_level_attr(attr)?;
+        attr.args.iter().any(|arg| arg.text(db) == lint_name).then_some(level)
+    })
+}
+
 impl SemanticQueryAttrs for ModuleId {
     fn attributes_elements(&self, db: &dyn SemanticGroup) -> Maybe<Vec<Attribute>> {
         db.module_attributes(*self)
@@ -150,7 +177,7 @@ impl SemanticQueryAttrs for TraitFunctionId {
     }
 }
 
-impl SemanticQueryAttrs for ImplTypeId {
+impl SemanticQueryAttrs for ImplTypeDefId {
     fn attributes_elements(&self, db: &dyn SemanticGroup) -> Maybe<Vec<Attribute>> {
         db.impl_type_attributes(*self)
     }
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use cairo_lang_defs::ids::{ConstantId, LanguageElementId, LookupItemId, ModuleItemId};
 use cairo_lang_diagnostics::{Diagnostics, Maybe, ToMaybe};
 use cairo_lang_proc_macros::{DebugWithDb, SemanticObject};
-use cairo_lang_syntax::node::TypedSyntaxNode;
+use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
 
 use crate::corelib::validate_literal;
 use crate::db::SemanticGroup;
@@ -30,9 +30,9 @@ pub struct Constant {
 #[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
 #[debug_db(dyn SemanticGroup + 'static)]
 pub struct ConstantData {
-    diagnostics: Diagnostics<SemanticDiagnostic>,
-    constant: Maybe<Constant>,
-    resolver_data: Arc<ResolverData>,
+    pub diagnostics: Diagnostics<SemanticDiagnostic>,
+    pub constant: Maybe<Constant>,
+    pub resolver_data: Arc<ResolverData>,
 }
 
 /// Query implementation of [SemanticGroup::priv_constant_semantic_data].
@@ -40,17 +40,33 @@ pub fn priv_constant_semantic_data(
     db: &dyn SemanticGroup,
     const_id: ConstantId,
 ) -> Maybe<ConstantData> {
-    let module_file_id = const_id.module_file_id(db.upcast());
-    let mut diagnostics = SemanticDiagnostics::new(module_file_id.file_id(db.upcast())?);
     // TODO(spapini): when code changes in a file, all the AST items change (as they contain a path
     // to the green root that changes. Once ASTs are rooted on items, use a selector that picks only
     // the item instead of all the module data.
     let const_ast = db.module_constant_by_id(const_id)?.to_maybe()?;
+    let lookup_item_id = LookupItemId::ModuleItem(ModuleItemId::Constant(const_id));
+    constant_semantic_data_helper(db, &const_ast, lookup_item_id, None)
+}
+
+/// Helper for computing the semantic data of a constant, either a module constant or an impl
+/// constant. The resolver of an impl constant is based on the resolver data of its impl.
+pub fn constant_semantic_data_helper(
+    db: &dyn SemanticGroup,
+    const_ast: &ast::ItemConstant,
+    lookup_item_id: LookupItemId,
+    parent_resolver_data: Option<Arc<ResolverData>>,
+) -> Maybe<ConstantData> {
+    let module_file_id = lookup_item_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id.file_id(db.upcast())?);
     let syntax_db = db.upcast();
 
-    let lookup_item_id = LookupItemId::ModuleItem(ModuleItemId::Constant(const_id));
     let inference_id = InferenceId::LookupItemDeclaration(lookup_item_id);
-    let mut resolver = Resolver::new(db, module_file_id, inference_id);
+    let mut resolver = match parent_resolver_data {
+        Some(parent_resolver_data) => {
+            Resolver::with_data(db, parent_resolver_data.clone_with_inference_id(db, inference_id))
+        }
+        None => Resolver::new(db, module_file_id, inference_id),
+    };
 
     let const_type = resolve_type(
         db,
@@ -47,7 +47,10 @@ impl ImplGenericFunctionId {
             ImplId::Concrete(concrete_impl_id) => {
                 concrete_impl_id.get_impl_function(db, self.function)
             }
-            ImplId::GenericParameter(_) | ImplId::ImplVar(_) => Ok(None),
+            ImplId::GenericParameter(_)
+            | ImplId::ImplVar(_)
+            | ImplId::GeneratedImpl(_)
+            | ImplId::SelfImpl(_) => Ok(None),
         }
     }
     /// Converts to ImplGenericFunctionWithBodyId if this is a function of a concrete impl.
@@ -119,9 +122,10 @@ impl GenericFunctionId {
             GenericFunctionId::Extern(id) => db.extern_function_signature(id),
             GenericFunctionId::Impl(id) => {
                 let concrete_trait_id = db.impl_concrete_trait(id.impl_id)?;
+                let substitution = GenericSubstitution::from_self_impl(id.impl_id);
                 let id = ConcreteTraitGenericFunctionId::new(db, concrete_trait_id, id.function);
-
-                db.concrete_trait_function_signature(id)
+                let signature = db.concrete_trait_function_signature(id)?;
+                SubstitutionRewriter { db, substitution: &substitution }.rewrite(signature)
             }
         }
     }
@@ -131,8 +135,10 @@ impl GenericFunctionId {
             GenericFunctionId::Extern(id) => db.extern_function_declaration_generic_params(id),
             GenericFunctionId::Impl(id) => {
                 let concrete_trait_id = db.impl_concrete_trait(id.impl_id)?;
+                let substitution = GenericSubstitution::from_self_impl(id.impl_id);
                 let id = ConcreteTraitGenericFunctionId::new(db, concrete_trait_id, id.function);
-                db.concrete_trait_function_generic_params(id)
+                let generic_params = db.concrete_trait_function_generic_params(id)?;
+                SubstitutionRewriter { db, substitution: &substitution }.rewrite(generic_params)
             }
         }
     }
@@ -384,7 +390,7 @@ impl ConcreteFunctionWithBody {
 }
 
 /// Converts each generic param to a generic argument that passes the same generic param.
-fn generic_params_to_args(
+pub fn generic_params_to_args(
     params: Vec<GenericParam>,
     db: &dyn SemanticGroup,
 ) -> Maybe<Vec<GenericArgumentId>> {
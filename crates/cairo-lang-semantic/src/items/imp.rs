@@ -4,9 +4,10 @@ use std::vec;
 
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{
-    FunctionTitleId, FunctionWithBodyId, GenericKind, GenericParamId, ImplAliasId, ImplDefId,
-    ImplFunctionId, ImplFunctionLongId, ImplItemId, ImplTypeId, ImplTypeLongId, LanguageElementId,
-    LookupItemId, ModuleId, ModuleItemId, TopLevelLanguageElementId, TraitFunctionId, TraitId,
+    FunctionTitleId, FunctionWithBodyId, GenericKind, GenericParamId, ImplAliasId,
+    ImplConstantDefId, ImplConstantDefLongId, ImplDefId, ImplFunctionId, ImplFunctionLongId,
+    ImplItemId, ImplTypeDefId, ImplTypeDefLongId, LanguageElementId, LookupItemId, ModuleId,
+    ModuleItemId, TopLevelLanguageElementId, TraitConstantId, TraitFunctionId, TraitId,
     TraitTypeId,
 };
 use cairo_lang_diagnostics::{
@@ -31,9 +32,11 @@ use syntax::node::{Terminal, TypedSyntaxNode};
 use super::enm::SemanticEnumEx;
 use super::function_with_body::{get_inline_config, FunctionBody, FunctionBodyData};
 use super::functions::{
-    forbid_inline_always_with_impl_generic_param, FunctionDeclarationData, InlineConfiguration,
+    forbid_inline_always_with_impl_generic_param, generic_params_to_args, FunctionDeclarationData,
+    InlineConfiguration,
 };
 use super::generics::{semantic_generic_params, GenericArgumentHead, GenericParamsData};
+use super::module::module_glob_imported_items;
 use super::structure::SemanticStructEx;
 use super::trt::{ConcreteTraitGenericFunctionId, ConcreteTraitGenericFunctionLongId};
 use super::type_aliases::{
@@ -49,10 +52,13 @@ use crate::expr::inference::canonic::ResultNoErrEx;
 use crate::expr::inference::infers::InferenceEmbeddings;
 use crate::expr::inference::solver::SolutionSet;
 use crate::expr::inference::{ImplVarId, InferenceError, InferenceId};
+use crate::items::constant::{constant_semantic_data_helper, Constant, ConstantData};
 use crate::items::function_with_body::get_implicit_precedence;
 use crate::items::functions::ImplicitPrecedence;
 use crate::items::us::SemanticUseEx;
-use crate::resolve::{ResolvedConcreteItem, ResolvedGenericItem, Resolver, ResolverData};
+use crate::resolve::{
+    ResolvedConcreteItem, ResolvedGenericItem, Resolver, ResolverData, TraitOrImplContext,
+};
 use crate::substitution::{GenericSubstitution, SemanticRewriter, SubstitutionRewriter};
 use crate::{
     semantic, semantic_object_for_id, ConcreteFunction, ConcreteTraitId, ConcreteTraitLongId,
@@ -132,13 +138,22 @@ pub enum ImplId {
     Concrete(ConcreteImplId),
     GenericParameter(GenericParamId),
     ImplVar(ImplVarId),
+    /// An impl generated by the compiler, e.g. the `Fn` and `Copy` impls of a closure type.
+    GeneratedImpl(ConcreteTraitId),
+    /// The `Self` impl inside a trait, i.e. the yet unknown impl of the trait the item is in.
+    SelfImpl(ConcreteTraitId),
 }
 impl ImplId {
     /// Returns the [ImplHead] of an impl if available.
     pub fn head(&self, db: &dyn SemanticGroup) -> Option<ImplHead> {
         Some(match self {
             ImplId::Concrete(concrete) => ImplHead::Concrete(concrete.impl_def_id(db)),
-            ImplId::GenericParameter(_) | ImplId::ImplVar(_) => return None,
+            ImplId::GenericParameter(_)
+            | ImplId::ImplVar(_)
+            | ImplId::GeneratedImpl(_)
+            | ImplId::SelfImpl(_) => {
+                return None;
+            }
         })
     }
     pub fn name(&self, db: &dyn SemanticGroup) -> SmolStr {
@@ -148,6 +163,10 @@ impl ImplId {
                 generic_param_impl.name(db.upcast()).unwrap_or_else(|| "_".into())
             }
             ImplId::ImplVar(var) => format!("{var:?}").into(),
+            ImplId::GeneratedImpl(concrete_trait) => {
+                format!("{}(generated)", concrete_trait.name(db)).into()
+            }
+            ImplId::SelfImpl(_) => "Self".into(),
         }
     }
     pub fn format(&self, db: &dyn SemanticGroup) -> String {
@@ -157,6 +176,10 @@ impl ImplId {
             }
             ImplId::GenericParameter(generic_param_impl) => generic_param_impl.format(db.upcast()),
             ImplId::ImplVar(var) => format!("{var:?}"),
+            ImplId::GeneratedImpl(concrete_trait) => {
+                format!("{:?}(generated)", concrete_trait.debug(db.elongate()))
+            }
+            ImplId::SelfImpl(_) => "Self".into(),
         }
     }
     pub fn concrete_trait(&self, db: &dyn SemanticGroup) -> Maybe<ConcreteTraitId> {
@@ -168,6 +191,11 @@ impl ImplId {
             ImplId::Concrete(concrete_impl_id) => concrete_impl_id.is_fully_concrete(db),
             ImplId::GenericParameter(_) => false,
             ImplId::ImplVar(_) => false,
+            ImplId::SelfImpl(_) => false,
+            ImplId::GeneratedImpl(concrete_trait) => concrete_trait
+                .generic_args(db)
+                .iter()
+                .all(|generic_argument_id| generic_argument_id.is_fully_concrete(db)),
         }
     }
 }
@@ -181,10 +209,121 @@ impl DebugWithDb<dyn SemanticGroup> for ImplId {
             ImplId::Concrete(concrete_impl_id) => write!(f, "{:?}", concrete_impl_id.debug(db)),
             ImplId::GenericParameter(param) => write!(f, "{:?}", param.debug(db)),
             ImplId::ImplVar(var) => write!(f, "?{}", var.get(db).id.0),
+            ImplId::GeneratedImpl(concrete_trait) => {
+                write!(f, "{:?}(generated)", concrete_trait.debug(db))
+            }
+            ImplId::SelfImpl(_) => write!(f, "Self"),
         }
     }
 }
 
+/// An associated type of an impl, i.e. `impl_id::ty`. When the impl is concrete, this is reduced
+/// to the type defined by the impl (see [reduce_impl_type]).
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
+pub struct ImplTypeId {
+    /// The impl the type is taken from.
+    impl_id: ImplId,
+    /// The trait type the impl type implements.
+    ty: TraitTypeId,
+}
+impl ImplTypeId {
+    pub fn new(impl_id: ImplId, ty: TraitTypeId) -> Self {
+        Self { impl_id, ty }
+    }
+    pub fn impl_id(&self) -> ImplId {
+        self.impl_id
+    }
+    pub fn ty(&self) -> TraitTypeId {
+        self.ty
+    }
+    pub fn format(&self, db: &dyn SemanticGroup) -> String {
+        format!("{}::{}", self.impl_id.name(db), self.ty.name(db.upcast()))
+    }
+}
+impl DebugWithDb<dyn SemanticGroup> for ImplTypeId {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        db: &(dyn SemanticGroup + 'static),
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.format(db))
+    }
+}
+
+/// Returns the type an impl type refers to. If the impl is concrete, this is the type defined in
+/// the impl, otherwise the impl type itself.
+pub fn reduce_impl_type(db: &dyn SemanticGroup, impl_type_id: ImplTypeId) -> Maybe<TypeId> {
+    let ImplId::Concrete(concrete_impl_id) = impl_type_id.impl_id else {
+        return Ok(db.intern_type(TypeLongId::ImplType(impl_type_id)));
+    };
+    let Some(impl_type_def_id) =
+        db.impl_type_by_trait_type(concrete_impl_id.impl_def_id(db), impl_type_id.ty)?
+    else {
+        // A diagnostic about the missing item is reported on the impl.
+        return Err(skip_diagnostic());
+    };
+    let ty = db.impl_type_resolved_type(impl_type_def_id)?;
+    SubstitutionRewriter { db, substitution: &concrete_impl_id.substitution(db)? }.rewrite(ty)
+}
+
+/// An associated constant of an impl, i.e. `impl_id::constant`.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
+pub struct ImplConstantId {
+    /// The impl the constant is taken from.
+    impl_id: ImplId,
+    /// The trait constant the impl constant implements.
+    trait_constant_id: TraitConstantId,
+}
+impl ImplConstantId {
+    pub fn new(impl_id: ImplId, trait_constant_id: TraitConstantId) -> Self {
+        Self { impl_id, trait_constant_id }
+    }
+    pub fn impl_id(&self) -> ImplId {
+        self.impl_id
+    }
+    pub fn trait_constant_id(&self) -> TraitConstantId {
+        self.trait_constant_id
+    }
+    /// Returns the type of the constant, as seen from the impl.
+    pub fn ty(&self, db: &dyn SemanticGroup) -> Maybe<TypeId> {
+        let concrete_trait_id = self.impl_id.concrete_trait(db)?;
+        let substitution = GenericSubstitution::new(
+            &db.trait_generic_params(concrete_trait_id.trait_id(db))?,
+            &concrete_trait_id.generic_args(db),
+        )
+        .with_self_impl(self.impl_id);
+        SubstitutionRewriter { db, substitution: &substitution }
+            .rewrite(db.trait_constant_type(self.trait_constant_id)?)
+    }
+    /// Returns the value of the constant, if the impl is concrete.
+    pub fn value(&self, db: &dyn SemanticGroup) -> Maybe<Option<Constant>> {
+        let ImplId::Concrete(concrete_impl_id) = self.impl_id else {
+            return Ok(None);
+        };
+        let Some(impl_constant_def_id) = db.impl_constant_by_trait_constant(
+            concrete_impl_id.impl_def_id(db),
+            self.trait_constant_id,
+        )?
+        else {
+            // A diagnostic about the missing item is reported on the impl.
+            return Err(skip_diagnostic());
+        };
+        Ok(Some(db.impl_constant_def_value(impl_constant_def_id)?))
+    }
+    pub fn format(&self, db: &dyn SemanticGroup) -> String {
+        format!("{}::{}", self.impl_id.name(db), self.trait_constant_id.name(db.upcast()))
+    }
+}
+impl DebugWithDb<dyn SemanticGroup> for ImplConstantId {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        db: &(dyn SemanticGroup + 'static),
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.format(db))
+    }
+}
+
 /// Head of an impl. A non-param non-variable impl has a head, which represents the kind of the root
 /// node in its tree representation. This is used for caching queries for fast lookups when the impl
 /// is not completely inferred yet.
@@ -339,6 +478,9 @@ pub fn impl_concrete_trait(db: &dyn SemanticGroup, impl_id: ImplId) -> Maybe<Con
             param_impl.concrete_trait
         }
         ImplId::ImplVar(var) => Ok(var.get(db).concrete_trait_id),
+        ImplId::GeneratedImpl(concrete_trait) | ImplId::SelfImpl(concrete_trait) => {
+            Ok(concrete_trait)
+        }
     }
 }
 
@@ -413,6 +555,8 @@ pub fn priv_impl_declaration_data_inner(
     let generic_params = resolver.inference().rewrite(generic_params).no_err();
 
     let attributes = impl_ast.attributes(syntax_db).structurize(syntax_db);
+    // Items of the impl may refer to the impl itself as `Self`.
+    resolver.trait_or_impl_ctx = TraitOrImplContext::Impl(impl_def_id);
     let resolver_data = Arc::new(resolver.data);
     Ok(ImplDeclarationData {
         diagnostics: diagnostics.build(),
@@ -430,7 +574,8 @@ pub fn priv_impl_declaration_data_inner(
 pub struct ImplDefinitionData {
     diagnostics: Diagnostics<SemanticDiagnostic>,
     function_asts: OrderedHashMap<ImplFunctionId, ast::FunctionWithBody>,
-    item_type_asts: Arc<OrderedHashMap<ImplTypeId, ast::ItemTypeAlias>>,
+    item_type_asts: Arc<OrderedHashMap<ImplTypeDefId, ast::ItemTypeAlias>>,
+    item_constant_asts: Arc<OrderedHashMap<ImplConstantDefId, ast::ItemConstant>>,
 }
 
 // --- Selectors ---
@@ -455,6 +600,9 @@ pub fn impl_semantic_definition_diagnostics(
     for impl_item_type_id in data.item_type_asts.keys() {
         diagnostics.extend(db.impl_type_semantic_diagnostics(*impl_item_type_id));
     }
+    for impl_item_constant_id in data.item_constant_asts.keys() {
+        diagnostics.extend(db.impl_constant_def_semantic_diagnostics(*impl_item_constant_id));
+    }
 
     diagnostics.build()
 }
@@ -495,7 +643,7 @@ pub fn impl_function_by_trait_function(
 pub fn impl_types(
     db: &dyn SemanticGroup,
     impl_def_id: ImplDefId,
-) -> Maybe<Arc<OrderedHashMap<ImplTypeId, ast::ItemTypeAlias>>> {
+) -> Maybe<Arc<OrderedHashMap<ImplTypeDefId, ast::ItemTypeAlias>>> {
     Ok(db.priv_impl_definition_data(impl_def_id)?.item_type_asts)
 }
 
@@ -503,14 +651,14 @@ pub fn impl_types(
 pub fn impl_type_ids(
     db: &dyn SemanticGroup,
     impl_def_id: ImplDefId,
-) -> Maybe<Arc<Vec<ImplTypeId>>> {
+) -> Maybe<Arc<Vec<ImplTypeDefId>>> {
     Ok(Arc::new(db.impl_types(impl_def_id)?.keys().copied().collect()))
 }
 
 /// Query implementation of [crate::db::SemanticGroup::impl_type_by_id].
 pub fn impl_type_by_id(
     db: &dyn SemanticGroup,
-    impl_type_id: ImplTypeId,
+    impl_type_id: ImplTypeDefId,
 ) -> Maybe<Option<ast::ItemTypeAlias>> {
     let impl_types = db.impl_types(impl_type_id.impl_def_id(db.upcast()))?;
     Ok(impl_types.get(&impl_type_id).cloned())
@@ -521,17 +669,41 @@ pub fn impl_type_by_trait_type(
     db: &dyn SemanticGroup,
     impl_def_id: ImplDefId,
     trait_type_id: TraitTypeId,
-) -> Maybe<Option<ImplTypeId>> {
+) -> Maybe<Option<ImplTypeDefId>> {
     let defs_db = db.upcast();
     let name = trait_type_id.name(defs_db);
     for impl_type_id in db.priv_impl_definition_data(impl_def_id)?.item_type_asts.keys() {
-        if db.lookup_intern_impl_type(*impl_type_id).name(defs_db) == name {
+        if db.lookup_intern_impl_type_def(*impl_type_id).name(defs_db) == name {
             return Ok(Some(*impl_type_id));
         }
     }
     Ok(None)
 }
 
+/// Query implementation of [crate::db::SemanticGroup::impl_constants].
+pub fn impl_constants(
+    db: &dyn SemanticGroup,
+    impl_def_id: ImplDefId,
+) -> Maybe<Arc<OrderedHashMap<ImplConstantDefId, ast::ItemConstant>>> {
+    Ok(db.priv_impl_definition_data(impl_def_id)?.item_constant_asts)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_constant_by_trait_constant].
+pub fn impl_constant_by_trait_constant(
+    db: &dyn SemanticGroup,
+    impl_def_id: ImplDefId,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<Option<ImplConstantDefId>> {
+    let defs_db = db.upcast();
+    let name = trait_constant_id.name(defs_db);
+    for impl_constant_id in db.impl_constants(impl_def_id)?.keys() {
+        if impl_constant_id.name(defs_db) == name {
+            return Ok(Some(*impl_constant_id));
+        }
+    }
+    Ok(None)
+}
+
 // --- Computation ---
 
 /// Query implementation of [crate::db::SemanticGroup::priv_impl_definition_data].
@@ -566,6 +738,7 @@ pub fn priv_impl_definition_data(
 
     let mut function_asts = OrderedHashMap::default();
     let mut item_type_asts = OrderedHashMap::default();
+    let mut item_constant_asts = OrderedHashMap::default();
     let mut impl_item_names = OrderedHashSet::<_>::default();
 
     if let MaybeImplBody::Some(body) = impl_ast.body(syntax_db) {
@@ -620,7 +793,7 @@ pub fn priv_impl_definition_data(
                 }
                 ImplItem::Type(ty) => {
                     let impl_type_id =
-                        db.intern_impl_type(ImplTypeLongId(module_file_id, ty.stable_ptr()));
+                        db.intern_impl_type_def(ImplTypeDefLongId(module_file_id, ty.stable_ptr()));
                     let name_node = ty.name(syntax_db);
                     let name = name_node.text(syntax_db);
                     if !impl_item_names.insert(name.clone()) {
@@ -631,11 +804,21 @@ pub fn priv_impl_definition_data(
                     }
                     item_type_asts.insert(impl_type_id, ty);
                 }
-                ImplItem::Constant(constant) => report_unsupported_impl_item(
-                    &mut diagnostics,
-                    constant.const_kw(syntax_db),
-                    "Constant",
-                ),
+                ImplItem::Constant(constant) => {
+                    let impl_constant_id = db.intern_impl_constant_def(ImplConstantDefLongId(
+                        module_file_id,
+                        constant.stable_ptr(),
+                    ));
+                    let name_node = constant.name(syntax_db);
+                    let name = name_node.text(syntax_db);
+                    if !impl_item_names.insert(name.clone()) {
+                        diagnostics.report_by_ptr(
+                            name_node.stable_ptr().untyped(),
+                            SemanticDiagnosticKind::NameDefinedMultipleTimes { name },
+                        );
+                    }
+                    item_constant_asts.insert(impl_constant_id, constant);
+                }
                 ImplItem::Impl(imp) => {
                     report_unsupported_impl_item(&mut diagnostics, imp.impl_kw(syntax_db), "Impl")
                 }
@@ -667,6 +850,7 @@ pub fn priv_impl_definition_data(
         diagnostics: diagnostics.build(),
         function_asts,
         item_type_asts: item_type_asts.into(),
+        item_constant_asts: item_constant_asts.into(),
     })
 }
 
@@ -749,9 +933,10 @@ fn get_inner_types(db: &dyn SemanticGroup, ty: TypeId) -> Maybe<Vec<TypeId>> {
         }
         TypeLongId::Tuple(tys) => tys,
         TypeLongId::Snapshot(_) => vec![],
-        TypeLongId::GenericParameter(_) => {
+        TypeLongId::GenericParameter(_) | TypeLongId::ImplType(_) => {
             return Err(skip_diagnostic());
         }
+        TypeLongId::Closure(closure) => closure.captured_types,
         TypeLongId::Var(_) => panic!("Types should be fully resolved at this point."),
         TypeLongId::Missing(diag_added) => {
             return Err(diag_added);
@@ -808,6 +993,17 @@ pub fn module_impl_ids_for_trait_filter(
             _ => {}
         }
     }
+    for item_id in module_glob_imported_items(db, module_id).unwrap_or_default() {
+        match ResolvedGenericItem::from_module_item(db, item_id) {
+            Ok(ResolvedGenericItem::Impl(impl_def_id)) => {
+                uninferred_impls.push(UninferredImpl::Def(impl_def_id));
+            }
+            Ok(ResolvedGenericItem::GenericImplAlias(impl_alias_id)) => {
+                uninferred_impls.push(UninferredImpl::ImplAlias(impl_alias_id));
+            }
+            _ => {}
+        }
+    }
     let mut res = Vec::new();
     for uninferred_impl in uninferred_impls {
         let Ok(trait_id) = uninferred_impl.trait_id(db) else { continue };
@@ -914,6 +1110,8 @@ pub enum UninferredImpl {
     Def(ImplDefId),
     ImplAlias(ImplAliasId),
     GenericParam(GenericParamId),
+    /// A compiler generated impl of a trait, see [ImplId::GeneratedImpl].
+    GeneratedImpl(TraitId),
 }
 impl UninferredImpl {
     pub fn concrete_trait(&self, db: &dyn SemanticGroup) -> Maybe<ConcreteTraitId> {
@@ -928,6 +1126,7 @@ impl UninferredImpl {
                     extract_matches!(db.generic_param_semantic(*param)?, GenericParam::Impl);
                 param.concrete_trait
             }
+            UninferredImpl::GeneratedImpl(_) => Err(skip_diagnostic()),
         }
     }
 
@@ -943,6 +1142,7 @@ impl UninferredImpl {
                     extract_matches!(db.generic_param_semantic(*param)?, GenericParam::Impl);
                 param.concrete_trait.map(|concrete_trait| concrete_trait.trait_id(db))
             }
+            UninferredImpl::GeneratedImpl(trait_id) => Ok(*trait_id),
         }
     }
 
@@ -952,6 +1152,7 @@ impl UninferredImpl {
             UninferredImpl::Def(impl_def_id) => impl_def_id.module_file_id(defs_db).0,
             UninferredImpl::ImplAlias(impl_alias_id) => impl_alias_id.module_file_id(defs_db).0,
             UninferredImpl::GenericParam(param) => param.module_file_id(defs_db).0,
+            UninferredImpl::GeneratedImpl(trait_id) => trait_id.module_file_id(defs_db).0,
         }
     }
 }
@@ -965,6 +1166,9 @@ impl DebugWithDb<dyn SemanticGroup> for UninferredImpl {
             UninferredImpl::GenericParam(param) => {
                 write!(f, "generic param {}", param.name(db.upcast()).unwrap_or_else(|| "_".into()))
             }
+            UninferredImpl::GeneratedImpl(trait_id) => {
+                write!(f, "generated impl of {:?}", trait_id.full_path(db.upcast()))
+            }
         }
     }
 }
@@ -1152,20 +1356,23 @@ pub struct ImplItemTypeData {
 /// Query implementation of [crate::db::SemanticGroup::impl_type_semantic_diagnostics].
 pub fn impl_type_semantic_diagnostics(
     db: &dyn SemanticGroup,
-    impl_type_id: ImplTypeId,
+    impl_type_id: ImplTypeDefId,
 ) -> Diagnostics<SemanticDiagnostic> {
     db.priv_impl_type_semantic_data(impl_type_id).map(|data| data.diagnostics).unwrap_or_default()
 }
 
 /// Query implementation of [crate::db::SemanticGroup::impl_type_resolved_type].
-pub fn impl_type_resolved_type(db: &dyn SemanticGroup, impl_type_id: ImplTypeId) -> Maybe<TypeId> {
+pub fn impl_type_resolved_type(
+    db: &dyn SemanticGroup,
+    impl_type_id: ImplTypeDefId,
+) -> Maybe<TypeId> {
     db.priv_impl_type_semantic_data(impl_type_id)?.type_alias_data.resolved_type
 }
 
 /// Query implementation of [crate::db::SemanticGroup::impl_type_generic_params].
 pub fn impl_type_generic_params(
     db: &dyn SemanticGroup,
-    impl_type_id: ImplTypeId,
+    impl_type_id: ImplTypeDefId,
 ) -> Maybe<Vec<GenericParam>> {
     Ok(db.priv_impl_type_generic_params_data(impl_type_id)?.generic_params)
 }
@@ -1173,7 +1380,7 @@ pub fn impl_type_generic_params(
 /// Query implementation of [crate::db::SemanticGroup::impl_type_attributes].
 pub fn impl_type_attributes(
     db: &dyn SemanticGroup,
-    impl_type_id: ImplTypeId,
+    impl_type_id: ImplTypeDefId,
 ) -> Maybe<Vec<Attribute>> {
     Ok(db.priv_impl_type_semantic_data(impl_type_id)?.type_alias_data.attributes)
 }
@@ -1181,7 +1388,7 @@ pub fn impl_type_attributes(
 /// Query implementation of [crate::db::SemanticGroup::impl_type_resolver_data].
 pub fn impl_type_resolver_data(
     db: &dyn SemanticGroup,
-    impl_type_id: ImplTypeId,
+    impl_type_id: ImplTypeDefId,
 ) -> Maybe<Arc<ResolverData>> {
     Ok(db.priv_impl_type_semantic_data(impl_type_id)?.type_alias_data.resolver_data)
 }
@@ -1189,7 +1396,7 @@ pub fn impl_type_resolver_data(
 /// Query implementation of [crate::db::SemanticGroup::impl_type_trait_type].
 pub fn impl_type_trait_type(
     db: &dyn SemanticGroup,
-    impl_type_id: ImplTypeId,
+    impl_type_id: ImplTypeDefId,
 ) -> Maybe<TraitTypeId> {
     db.priv_impl_type_semantic_data(impl_type_id)?.trait_type_id
 }
@@ -1199,7 +1406,7 @@ pub fn impl_type_trait_type(
 /// Query implementation of [crate::db::SemanticGroup::priv_impl_type_semantic_data].
 pub fn priv_impl_type_semantic_data(
     db: &dyn SemanticGroup,
-    impl_type_id: ImplTypeId,
+    impl_type_id: ImplTypeDefId,
 ) -> Maybe<ImplItemTypeData> {
     let module_file_id = impl_type_id.module_file_id(db.upcast());
     let mut diagnostics = SemanticDiagnostics::new(module_file_id.file_id(db.upcast())?);
@@ -1227,7 +1434,7 @@ pub fn priv_impl_type_semantic_data(
 pub fn priv_impl_type_semantic_data_cycle(
     db: &dyn SemanticGroup,
     _cycle: &[String],
-    impl_type_id: &ImplTypeId,
+    impl_type_id: &ImplTypeDefId,
 ) -> Maybe<ImplItemTypeData> {
     let module_file_id = impl_type_id.module_file_id(db.upcast());
     let mut diagnostics = SemanticDiagnostics::new(module_file_id.file_id(db.upcast())?);
@@ -1254,7 +1461,7 @@ pub fn priv_impl_type_semantic_data_cycle(
 /// Query implementation of [crate::db::SemanticGroup::priv_impl_type_generic_params_data].
 pub fn priv_impl_type_generic_params_data(
     db: &dyn SemanticGroup,
-    impl_type_id: ImplTypeId,
+    impl_type_id: ImplTypeDefId,
 ) -> Maybe<GenericParamsData> {
     let defs_db = db.upcast();
     let module_file_id = impl_type_id.module_file_id(defs_db);
@@ -1275,7 +1482,7 @@ pub fn priv_impl_type_generic_params_data(
 fn validate_impl_item_type(
     db: &dyn SemanticGroup,
     diagnostics: &mut SemanticDiagnostics,
-    impl_type_id: ImplTypeId,
+    impl_type_id: ImplTypeDefId,
     impl_type_ast: &ast::ItemTypeAlias,
 ) -> Maybe<TraitTypeId> {
     let defs_db = db.upcast();
@@ -1309,6 +1516,141 @@ fn validate_impl_item_type(
     Ok(trait_type_id)
 }
 
+// === Impl Item Constant ===
+
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct ImplItemConstantData {
+    constant_data: ConstantData,
+    trait_constant_id: Maybe<TraitConstantId>,
+    /// The diagnostics of the impl constant, including the ones for the constant itself.
+    diagnostics: Diagnostics<SemanticDiagnostic>,
+}
+
+// --- Selectors ---
+
+/// Query implementation of [crate::db::SemanticGroup::impl_constant_def_semantic_diagnostics].
+pub fn impl_constant_def_semantic_diagnostics(
+    db: &dyn SemanticGroup,
+    impl_constant_id: ImplConstantDefId,
+) -> Diagnostics<SemanticDiagnostic> {
+    db.priv_impl_constant_semantic_data(impl_constant_id)
+        .map(|data| data.diagnostics)
+        .unwrap_or_default()
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_constant_def_value].
+pub fn impl_constant_def_value(
+    db: &dyn SemanticGroup,
+    impl_constant_id: ImplConstantDefId,
+) -> Maybe<Constant> {
+    db.priv_impl_constant_semantic_data(impl_constant_id)?.constant_data.constant
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_constant_def_resolver_data].
+pub fn impl_constant_def_resolver_data(
+    db: &dyn SemanticGroup,
+    impl_constant_id: ImplConstantDefId,
+) -> Maybe<Arc<ResolverData>> {
+    Ok(db.priv_impl_constant_semantic_data(impl_constant_id)?.constant_data.resolver_data)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::impl_constant_def_trait_constant].
+pub fn impl_constant_def_trait_constant(
+    db: &dyn SemanticGroup,
+    impl_constant_id: ImplConstantDefId,
+) -> Maybe<TraitConstantId> {
+    db.priv_impl_constant_semantic_data(impl_constant_id)?.trait_constant_id
+}
+
+// --- Computation ---
+
+/// Query implementation of [crate::db::SemanticGroup::priv_impl_constant_semantic_data].
+pub fn priv_impl_constant_semantic_data(
+    db: &dyn SemanticGroup,
+    impl_constant_id: ImplConstantDefId,
+) -> Maybe<ImplItemConstantData> {
+    let defs_db = db.upcast();
+    let module_file_id = impl_constant_id.module_file_id(defs_db);
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id.file_id(defs_db)?);
+    let impl_def_id = impl_constant_id.impl_def_id(defs_db);
+    let impl_constants = db.impl_constants(impl_def_id)?;
+    let impl_constant_ast = impl_constants.get(&impl_constant_id).to_maybe()?;
+    let lookup_item_id = LookupItemId::ImplItem(ImplItemId::Constant(impl_constant_id));
+
+    let constant_data = constant_semantic_data_helper(
+        db,
+        impl_constant_ast,
+        lookup_item_id,
+        Some(db.impl_def_resolver_data(impl_def_id)?),
+    )?;
+    diagnostics.diagnostics.extend(constant_data.diagnostics.clone());
+
+    let trait_constant_id = validate_impl_item_constant(
+        db,
+        &mut diagnostics,
+        impl_constant_id,
+        impl_constant_ast,
+        &constant_data,
+    );
+
+    Ok(ImplItemConstantData { constant_data, trait_constant_id, diagnostics: diagnostics.build() })
+}
+
+/// Returns the impl that an impl definition refers to from within itself, i.e. the impl with its
+/// own generic params as arguments.
+pub fn self_impl_of_impl_def(db: &dyn SemanticGroup, impl_def_id: ImplDefId) -> Maybe<ImplId> {
+    let generic_args = generic_params_to_args(db.impl_def_generic_params(impl_def_id)?, db)?;
+    Ok(ImplId::Concrete(db.intern_concrete_impl(ConcreteImplLongId { impl_def_id, generic_args })))
+}
+
+/// Validates the impl item constant, and returns the matching trait constant id.
+fn validate_impl_item_constant(
+    db: &dyn SemanticGroup,
+    diagnostics: &mut SemanticDiagnostics,
+    impl_constant_id: ImplConstantDefId,
+    impl_constant_ast: &ast::ItemConstant,
+    constant_data: &ConstantData,
+) -> Maybe<TraitConstantId> {
+    let defs_db = db.upcast();
+    let impl_def_id = impl_constant_id.impl_def_id(defs_db);
+    let concrete_trait_id = db.impl_def_concrete_trait(impl_def_id)?;
+    let trait_id = concrete_trait_id.trait_id(db);
+    let constant_name = impl_constant_id.name(defs_db);
+    let trait_constant_id =
+        db.trait_constant_by_name(trait_id, constant_name.clone())?.ok_or_else(|| {
+            diagnostics.report(
+                impl_constant_ast,
+                ImplItemNotInTrait {
+                    impl_def_id,
+                    impl_item_name: constant_name,
+                    trait_id,
+                    item_kind: "constant".into(),
+                },
+            )
+        })?;
+
+    // Check the type of the constant matches the type declared in the trait.
+    let actual_ty = constant_data.constant.as_ref().map_err(|err| *err)?.value.ty();
+    actual_ty.check_not_missing(db)?;
+    let self_impl = self_impl_of_impl_def(db, impl_def_id)?;
+    let substitution = GenericSubstitution::new(
+        &db.trait_generic_params(trait_id)?,
+        &concrete_trait_id.generic_args(db),
+    )
+    .with_self_impl(self_impl);
+    let expected_ty = SubstitutionRewriter { db, substitution: &substitution }
+        .rewrite(db.trait_constant_type(trait_constant_id)?)?;
+    if !expected_ty.is_missing(db) && expected_ty != actual_ty {
+        diagnostics.report(
+            &impl_constant_ast.type_clause(db.upcast()),
+            WrongImplConstantType { impl_constant_id, trait_id, expected_ty, actual_ty },
+        );
+    }
+
+    Ok(trait_constant_id)
+}
+
 // === Impl Function Declaration ===
 
 #[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
@@ -1579,7 +1921,8 @@ fn validate_impl_function_signature(
                 GenericArgumentId::Type(db.intern_type(TypeLongId::GenericParameter(param.id())))
             })
             .collect_vec(),
-    );
+    )
+    .with_self_impl(self_impl_of_impl_def(db, impl_def_id)?);
     let concrete_trait_signature = SubstitutionRewriter { db, substitution: &substitution }
         .rewrite(concrete_trait_signature)?;
 
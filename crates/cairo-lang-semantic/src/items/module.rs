@@ -4,23 +4,25 @@ use std::sync::Arc;
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{
-    ConstantId, EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, ImplAliasId, ImplDefId,
-    LanguageElementId, ModuleId, ModuleItemId, ModuleTypeAliasId, StructId, SubmoduleId, TraitId,
-    UseId,
+    ConstantId, EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, GlobalUseId, ImplAliasId,
+    ImplDefId, LanguageElementId, ModuleId, ModuleItemId, ModuleTypeAliasId, StructId, SubmoduleId,
+    TraitId, UseId,
 };
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe};
+use cairo_lang_filesystem::db::Edition;
 use cairo_lang_syntax::attribute::structured::{Attribute, AttributeListStructurize};
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
+use itertools::Itertools;
 use smol_str::SmolStr;
 
 use super::us::SemanticUseEx;
-use super::visibility::Visibility;
+use super::visibility::{peek_visible_in, Visibility};
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind;
-use crate::resolve::ResolvedGenericItem;
+use crate::resolve::{extract_edition, ignore_visibility_checks, ResolvedGenericItem};
 use crate::SemanticDiagnostic;
 
 /// Information per item in a module.
@@ -87,7 +89,7 @@ pub fn priv_module_semantic_data(
         };
         // Defaulting to pub as if diagnostics are added privacy diagnostics are less interesting.
         let visibility = visibility
-            .map(|v| Visibility::from_ast(db.upcast(), &mut diagnostics, &v))
+            .map(|v| Visibility::from_ast(def_db, &mut diagnostics, &v, module_id))
             .unwrap_or(Visibility::Public);
         if items.insert(name.clone(), ModuleItemInfo { item_id, visibility }).is_some() {
             // `item` is extracted from `module_items` and thus `module_item_name_stable_ptr` is
@@ -235,5 +237,141 @@ pub fn module_usable_trait_ids(
             _ => {}
         };
     }
+    // Add traits from glob imports in the module.
+    for item_id in module_glob_imported_items(db, module_id)? {
+        let Ok(resolved_item) = ResolvedGenericItem::from_module_item(db, item_id) else {
+            continue;
+        };
+        let trait_id = match resolved_item {
+            ResolvedGenericItem::Trait(trait_id) => trait_id,
+            ResolvedGenericItem::Impl(impl_def_id) => {
+                let Ok(trait_id) = db.impl_def_trait(impl_def_id) else {
+                    continue;
+                };
+                trait_id
+            }
+            _ => continue,
+        };
+        module_traits.insert(trait_id);
+    }
     Ok(module_traits.into())
 }
+
+/// Returns the ids of the items imported into the given module by its glob imports, i.e. the items
+/// that are found by name lookup in the module through its glob imports. Items defined or
+/// explicitly imported in the module shadow glob-imported items of the same name, and names
+/// glob-imported as more than one item are ambiguous, and import none of them.
+pub fn module_glob_imported_items(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+) -> Maybe<Vec<ModuleItemId>> {
+    let module_items = &db.priv_module_semantic_data(module_id)?.items;
+    let user_edition = extract_edition(db, module_id.owning_crate(db.upcast()));
+    let mut visited = OrderedHashSet::default();
+    let mut items_by_name = OrderedHashMap::<SmolStr, Vec<ModuleItemId>>::default();
+    for (name, item_info, _) in
+        collect_glob_imported_items(db, module_id, None, user_edition, None, &mut visited)
+    {
+        if !module_items.contains_key(&name) {
+            items_by_name.entry(name).or_default().push(item_info.item_id);
+        }
+    }
+    Ok(items_by_name
+        .into_iter()
+        .filter_map(|(_, items)| if let [item_id] = items[..] { Some(item_id) } else { None })
+        .collect())
+}
+
+/// Collects the distinct items imported by the glob imports of the given module, with their names,
+/// or only the items named `name` if it is given. Only items visible from the module are imported,
+/// and they get the visibility of the glob import. Names glob-imported into the imported modules
+/// are imported as well, unless shadowed by items defined in them.
+///
+/// The visibility checks are done for a user crate of `user_edition`, and `skipped_global_use` -
+/// a glob import whose path is being resolved - is not used. Already visited modules are skipped
+/// to avoid cycles of glob imports.
+pub fn collect_glob_imported_items(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    name: Option<&SmolStr>,
+    user_edition: Edition,
+    skipped_global_use: Option<GlobalUseId>,
+    visited: &mut OrderedHashSet<ModuleId>,
+) -> Vec<(SmolStr, ModuleItemInfo, Maybe<ResolvedGenericItem>)> {
+    let mut items: Vec<(SmolStr, ModuleItemInfo, Maybe<ResolvedGenericItem>)> = vec![];
+    if !visited.insert(module_id) {
+        return items;
+    }
+    let Ok(global_uses) = db.module_global_uses(module_id) else {
+        return items;
+    };
+    for global_use_id in global_uses.keys().copied() {
+        if skipped_global_use == Some(global_use_id) {
+            continue;
+        }
+        let Ok((imported_module_id, visibility)) = db.global_use_imported_module(global_use_id)
+        else {
+            continue;
+        };
+        let Ok(imported_module_data) = db.priv_module_semantic_data(imported_module_id) else {
+            continue;
+        };
+        let imported_items = &imported_module_data.items;
+        // Items defined in the imported module shadow the ones glob-imported into it.
+        let defined_candidates = match name {
+            Some(name) => imported_items
+                .get(name)
+                .map(|item_info| (name, item_info))
+                .into_iter()
+                .collect_vec(),
+            None => imported_items.iter().collect_vec(),
+        };
+        let glob_candidates = if name.is_some() && !defined_candidates.is_empty() {
+            vec![]
+        } else {
+            collect_glob_imported_items(
+                db,
+                imported_module_id,
+                name,
+                user_edition,
+                skipped_global_use,
+                visited,
+            )
+            .into_iter()
+            .filter(|(item_name, ..)| !imported_items.contains_key(item_name))
+            .map(|(item_name, item_info, _)| (item_name, item_info))
+            .collect()
+        };
+        let candidates = defined_candidates
+            .into_iter()
+            .map(|(item_name, item_info)| (item_name.clone(), item_info.clone()))
+            .chain(glob_candidates);
+        for (item_name, item_info) in candidates {
+            if !ignore_visibility_checks(db, user_edition, imported_module_id)
+                && !peek_visible_in(
+                    db.upcast(),
+                    item_info.visibility,
+                    imported_module_id,
+                    module_id,
+                )
+            {
+                continue;
+            }
+            // The same item may be imported by several globs, e.g. through a re-export.
+            let resolved_item = ResolvedGenericItem::from_module_item(db, item_info.item_id);
+            if items.iter().any(|(other_name, other_info, other_resolved_item)| {
+                *other_name == item_name
+                    && (other_info.item_id == item_info.item_id
+                        || (resolved_item.is_ok() && *other_resolved_item == resolved_item))
+            }) {
+                continue;
+            }
+            items.push((
+                item_name,
+                ModuleItemInfo { item_id: item_info.item_id, visibility },
+                resolved_item,
+            ));
+        }
+    }
+    items
+}
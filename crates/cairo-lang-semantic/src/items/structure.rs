@@ -6,7 +6,7 @@ use cairo_lang_defs::ids::{
 use cairo_lang_diagnostics::{Diagnostics, Maybe, ToMaybe};
 use cairo_lang_proc_macros::{DebugWithDb, SemanticObject};
 use cairo_lang_syntax::attribute::structured::{Attribute, AttributeListStructurize};
-use cairo_lang_syntax::node::{Terminal, TypedSyntaxNode};
+use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use cairo_lang_utils::Upcast;
 use smol_str::SmolStr;
@@ -36,9 +36,21 @@ pub struct StructDeclarationData {
     diagnostics: Diagnostics<SemanticDiagnostic>,
     generic_params: Vec<semantic::GenericParam>,
     attributes: Vec<Attribute>,
+    kind: StructKind,
     resolver_data: Arc<ResolverData>,
 }
 
+/// The way the members of a struct are declared.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum StructKind {
+    /// Named members, e.g. `struct A { a: felt252 }`.
+    Named,
+    /// Positional members, e.g. `struct A(felt252);`. Their names are their positions.
+    Tuple,
+    /// No members, e.g. `struct A;`.
+    Unit,
+}
+
 /// Query implementation of [crate::db::SemanticGroup::priv_struct_declaration_data].
 pub fn priv_struct_declaration_data(
     db: &dyn SemanticGroup,
@@ -66,6 +78,11 @@ pub fn priv_struct_declaration_data(
     diagnostics.diagnostics.extend(generic_params_data.diagnostics);
 
     let attributes = struct_ast.attributes(syntax_db).structurize(syntax_db);
+    let kind = match struct_ast.body(syntax_db) {
+        ast::StructBody::Named(_) => StructKind::Named,
+        ast::StructBody::Tuple(_) => StructKind::Tuple,
+        ast::StructBody::Unit(_) => StructKind::Unit,
+    };
 
     // Check fully resolved.
     if let Some((stable_ptr, inference_err)) = resolver.inference().finalize() {
@@ -78,6 +95,7 @@ pub fn priv_struct_declaration_data(
         diagnostics: diagnostics.build(),
         generic_params,
         attributes,
+        kind,
         resolver_data,
     })
 }
@@ -134,6 +152,11 @@ pub fn struct_attributes(db: &dyn SemanticGroup, struct_id: StructId) -> Maybe<V
     Ok(db.priv_struct_declaration_data(struct_id)?.attributes)
 }
 
+/// Query implementation of [crate::db::SemanticGroup::struct_kind].
+pub fn struct_kind(db: &dyn SemanticGroup, struct_id: StructId) -> Maybe<StructKind> {
+    Ok(db.priv_struct_declaration_data(struct_id)?.kind)
+}
+
 /// Query implementation of [crate::db::SemanticGroup::struct_declaration_resolver_data].
 pub fn struct_declaration_resolver_data(
     db: &dyn SemanticGroup,
@@ -186,20 +209,16 @@ pub fn priv_struct_definition_data(
 
     // Members.
     let mut members = OrderedHashMap::default();
-    for member in struct_ast.members(syntax_db).elements(syntax_db) {
+    for member in struct_ast.members(syntax_db) {
         let id = db.intern_member(MemberLongId(module_file_id, member.stable_ptr()));
-        let ty = resolve_type(
-            db,
-            &mut diagnostics,
-            &mut resolver,
-            &member.type_clause(syntax_db).ty(syntax_db),
-        );
+        let ty = resolve_type(db, &mut diagnostics, &mut resolver, &member.type_expr(syntax_db));
         let visibility = Visibility::from_ast(
-            syntax_db,
+            db.upcast(),
             &mut diagnostics.diagnostics,
             &member.visibility(syntax_db),
+            module_file_id.0,
         );
-        let member_name = member.name(syntax_db).text(syntax_db);
+        let member_name = member.name(syntax_db);
         if let Some(_other_member) =
             members.insert(member_name.clone(), Member { id, ty, visibility })
         {
@@ -241,7 +260,7 @@ pub fn struct_definition_resolver_data(
     db: &dyn SemanticGroup,
     struct_id: StructId,
 ) -> Maybe<Arc<ResolverData>> {
-    Ok(db.priv_struct_declaration_data(struct_id)?.resolver_data)
+    Ok(db.priv_struct_definition_data(struct_id)?.resolver_data)
 }
 
 pub trait SemanticStructEx<'a>: Upcast<dyn SemanticGroup + 'a> {
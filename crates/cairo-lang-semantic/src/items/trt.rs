@@ -3,7 +3,8 @@ use std::sync::Arc;
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{
     FunctionTitleId, LanguageElementId, LookupItemId, ModuleItemId, TopLevelLanguageElementId,
-    TraitFunctionId, TraitFunctionLongId, TraitId, TraitItemId, TraitTypeId, TraitTypeLongId,
+    TraitConstantId, TraitConstantLongId, TraitFunctionId, TraitFunctionLongId, TraitId,
+    TraitItemId, TraitTypeId, TraitTypeLongId,
 };
 use cairo_lang_diagnostics::{Diagnostics, DiagnosticsBuilder, Maybe, ToMaybe};
 use cairo_lang_proc_macros::{DebugWithDb, SemanticObject};
@@ -28,8 +29,9 @@ use crate::diagnostic::{report_unsupported_trait_item, SemanticDiagnostics};
 use crate::expr::compute::{compute_root_expr, ComputationContext, Environment};
 use crate::expr::inference::canonic::ResultNoErrEx;
 use crate::expr::inference::InferenceId;
-use crate::resolve::{Resolver, ResolverData};
+use crate::resolve::{Resolver, ResolverData, TraitOrImplContext};
 use crate::substitution::{GenericSubstitution, SemanticRewriter, SubstitutionRewriter};
+use crate::types::resolve_type;
 use crate::{
     semantic, semantic_object_for_id, FunctionBody, GenericArgumentId, GenericParam, Mutability,
     SemanticDiagnostic, TypeId,
@@ -262,6 +264,8 @@ pub fn priv_trait_semantic_declaration_data(
     }
     let generic_params = resolver.inference().rewrite(generic_params).no_err();
 
+    // Items of the trait may refer to the trait's impl as `Self`.
+    resolver.trait_or_impl_ctx = TraitOrImplContext::Trait(trait_id);
     let resolver_data = Arc::new(resolver.data);
     Ok(TraitDeclarationData {
         diagnostics: diagnostics.build(),
@@ -279,6 +283,7 @@ pub struct TraitDefinitionData {
     diagnostics: Diagnostics<SemanticDiagnostic>,
     function_asts: OrderedHashMap<TraitFunctionId, ast::TraitItemFunction>,
     item_type_asts: OrderedHashMap<TraitTypeId, ast::TraitItemType>,
+    item_constant_asts: OrderedHashMap<TraitConstantId, ast::TraitItemConstant>,
 }
 
 // --- Selectors ---
@@ -302,6 +307,9 @@ pub fn trait_semantic_definition_diagnostics(
     for trait_type_id in data.item_type_asts.keys() {
         diagnostics.extend(db.trait_type_diagnostics(*trait_type_id));
     }
+    for trait_constant_id in data.item_constant_asts.keys() {
+        diagnostics.extend(db.trait_constant_diagnostics(*trait_constant_id));
+    }
 
     diagnostics.build()
 }
@@ -313,7 +321,10 @@ pub fn trait_item_names(
 ) -> Maybe<OrderedHashSet<SmolStr>> {
     let trait_functions = db.trait_functions(trait_id)?;
     let trait_types = db.trait_types(trait_id)?;
-    Ok(chain!(trait_functions.keys(), trait_types.keys()).cloned().collect())
+    let trait_constants = db.trait_constants(trait_id)?;
+    Ok(chain!(trait_functions.keys(), trait_types.keys(), trait_constants.keys())
+        .cloned()
+        .collect())
 }
 
 /// Query implementation of [crate::db::SemanticGroup::trait_functions].
@@ -366,6 +377,31 @@ pub fn trait_type_by_name(
     Ok(db.trait_types(trait_id)?.get(&name).copied())
 }
 
+/// Query implementation of [crate::db::SemanticGroup::trait_constants].
+pub fn trait_constants(
+    db: &dyn SemanticGroup,
+    trait_id: TraitId,
+) -> Maybe<OrderedHashMap<SmolStr, TraitConstantId>> {
+    Ok(db
+        .priv_trait_semantic_definition_data(trait_id)?
+        .item_constant_asts
+        .keys()
+        .map(|constant_id| {
+            let constant_long_id = db.lookup_intern_trait_constant(*constant_id);
+            (constant_long_id.name(db.upcast()), *constant_id)
+        })
+        .collect())
+}
+
+/// Query implementation of [crate::db::SemanticGroup::trait_constant_by_name].
+pub fn trait_constant_by_name(
+    db: &dyn SemanticGroup,
+    trait_id: TraitId,
+    name: SmolStr,
+) -> Maybe<Option<TraitConstantId>> {
+    Ok(db.trait_constants(trait_id)?.get(&name).copied())
+}
+
 // --- Computation ---
 
 /// Query implementation of [crate::db::SemanticGroup::priv_trait_semantic_definition_data].
@@ -383,6 +419,7 @@ pub fn priv_trait_semantic_definition_data(
 
     let mut function_asts = OrderedHashMap::default();
     let mut item_type_asts = OrderedHashMap::default();
+    let mut item_constant_asts = OrderedHashMap::default();
     let mut trait_item_names = OrderedHashSet::<_>::default();
     if let ast::MaybeTraitBody::Some(body) = trait_ast.body(syntax_db) {
         for item in body.items(syntax_db).elements(syntax_db) {
@@ -415,11 +452,21 @@ pub fn priv_trait_semantic_definition_data(
                     }
                     item_type_asts.insert(trait_type_id, ty);
                 }
-                ast::TraitItem::Constant(constant) => report_unsupported_trait_item(
-                    &mut diagnostics,
-                    constant.const_kw(syntax_db),
-                    "Constant",
-                ),
+                ast::TraitItem::Constant(constant) => {
+                    let trait_constant_id = db.intern_trait_constant(TraitConstantLongId(
+                        module_file_id,
+                        constant.stable_ptr(),
+                    ));
+                    let name_node = constant.name(syntax_db);
+                    let name = name_node.text(syntax_db);
+                    if !trait_item_names.insert(name.clone()) {
+                        diagnostics.report_by_ptr(
+                            name_node.stable_ptr().untyped(),
+                            SemanticDiagnosticKind::NameDefinedMultipleTimes { name },
+                        );
+                    }
+                    item_constant_asts.insert(trait_constant_id, constant);
+                }
                 ast::TraitItem::Impl(imp) => {
                     report_unsupported_trait_item(&mut diagnostics, imp.impl_kw(syntax_db), "Impl")
                 }
@@ -428,7 +475,12 @@ pub fn priv_trait_semantic_definition_data(
         }
     }
 
-    Ok(TraitDefinitionData { diagnostics: diagnostics.build(), function_asts, item_type_asts })
+    Ok(TraitDefinitionData {
+        diagnostics: diagnostics.build(),
+        function_asts,
+        item_type_asts,
+        item_constant_asts,
+    })
 }
 
 // === Trait item type ===
@@ -558,6 +610,86 @@ pub fn priv_trait_type_data(
     })
 }
 
+// === Trait item constant ===
+
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct TraitItemConstantData {
+    pub diagnostics: Diagnostics<SemanticDiagnostic>,
+    pub ty: TypeId,
+    pub attributes: Vec<Attribute>,
+    pub resolver_data: Arc<ResolverData>,
+}
+
+// --- Selectors ---
+
+/// Query implementation of [crate::db::SemanticGroup::trait_constant_diagnostics].
+pub fn trait_constant_diagnostics(
+    db: &dyn SemanticGroup,
+    trait_constant_id: TraitConstantId,
+) -> Diagnostics<SemanticDiagnostic> {
+    db.priv_trait_constant_data(trait_constant_id).map(|data| data.diagnostics).unwrap_or_default()
+}
+
+/// Query implementation of [crate::db::SemanticGroup::trait_constant_type].
+pub fn trait_constant_type(
+    db: &dyn SemanticGroup,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<TypeId> {
+    Ok(db.priv_trait_constant_data(trait_constant_id)?.ty)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::trait_constant_attributes].
+pub fn trait_constant_attributes(
+    db: &dyn SemanticGroup,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<Vec<Attribute>> {
+    Ok(db.priv_trait_constant_data(trait_constant_id)?.attributes)
+}
+
+/// Query implementation of [crate::db::SemanticGroup::trait_constant_resolver_data].
+pub fn trait_constant_resolver_data(
+    db: &dyn SemanticGroup,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<Arc<ResolverData>> {
+    Ok(db.priv_trait_constant_data(trait_constant_id)?.resolver_data)
+}
+
+// --- Computation ---
+
+/// Query implementation of [crate::db::SemanticGroup::priv_trait_constant_data].
+pub fn priv_trait_constant_data(
+    db: &dyn SemanticGroup,
+    trait_constant_id: TraitConstantId,
+) -> Maybe<TraitItemConstantData> {
+    let syntax_db = db.upcast();
+    let module_file_id = trait_constant_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id.file_id(db.upcast())?);
+    let trait_id = trait_constant_id.trait_id(db.upcast());
+    let data = db.priv_trait_semantic_definition_data(trait_id)?;
+    let constant_syntax = &data.item_constant_asts[&trait_constant_id];
+    let inference_id = InferenceId::LookupItemDeclaration(LookupItemId::TraitItem(
+        TraitItemId::Constant(trait_constant_id),
+    ));
+    let parent_resolver_data = db.trait_resolver_data(trait_id)?;
+    let mut resolver =
+        Resolver::with_data(db, parent_resolver_data.clone_with_inference_id(db, inference_id));
+    for trait_generic_param in db.trait_generic_params(trait_id)? {
+        resolver.add_generic_param(trait_generic_param.id());
+    }
+
+    let ty = resolve_type(
+        db,
+        &mut diagnostics,
+        &mut resolver,
+        &constant_syntax.type_clause(syntax_db).ty(syntax_db),
+    );
+    let attributes = constant_syntax.attributes(syntax_db).structurize(syntax_db);
+    let resolver_data = Arc::new(resolver.data);
+
+    Ok(TraitItemConstantData { diagnostics: diagnostics.build(), ty, attributes, resolver_data })
+}
+
 // === Trait function Declaration ===
 
 // --- Selectors ---
@@ -1,13 +1,16 @@
 use std::sync::Arc;
 
-use cairo_lang_defs::ids::{LanguageElementId, LookupItemId, ModuleItemId, UseId};
+use cairo_lang_defs::ids::{
+    GlobalUseId, LanguageElementId, LookupItemId, ModuleId, ModuleItemId, UseId,
+};
 use cairo_lang_diagnostics::{Diagnostics, Maybe, ToMaybe};
 use cairo_lang_proc_macros::DebugWithDb;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::kind::SyntaxKind;
-use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
 use cairo_lang_utils::Upcast;
 
+use super::visibility::Visibility;
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
@@ -62,7 +65,7 @@ pub fn get_use_segments(
         ast::UsePath::Single(use_ast) => {
             segments.push(use_ast.ident(db));
         }
-        ast::UsePath::Multi(_) => {}
+        ast::UsePath::Multi(_) | ast::UsePath::Star(_) => {}
     };
     Ok(())
 }
@@ -83,8 +86,8 @@ fn get_parent_use_path(db: &dyn SyntaxGroup, use_path: &ast::UsePath) -> Option<
             SyntaxKind::UsePathList => {
                 continue;
             }
-            SyntaxKind::UsePathLeaf => {
-                unreachable!("UsePathLeaf can't be a parent of another UsePath.");
+            SyntaxKind::UsePathLeaf | SyntaxKind::UsePathStar => {
+                unreachable!("UsePathLeaf and UsePathStar can't be a parent of another UsePath.");
             }
             _ => {
                 unreachable!();
@@ -133,6 +136,83 @@ pub fn use_resolver_data(db: &dyn SemanticGroup, use_id: UseId) -> Maybe<Arc<Res
     Ok(db.priv_use_semantic_data(use_id)?.resolver_data)
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, DebugWithDb)]
+#[debug_db(dyn SemanticGroup + 'static)]
+pub struct UseGlobalData {
+    diagnostics: Diagnostics<SemanticDiagnostic>,
+    imported_module: Maybe<ModuleId>,
+    visibility: Visibility,
+}
+
+/// Query implementation of [crate::db::SemanticGroup::priv_global_use_semantic_data].
+pub fn priv_global_use_semantic_data(
+    db: &dyn SemanticGroup,
+    global_use_id: GlobalUseId,
+) -> Maybe<UseGlobalData> {
+    let module_file_id = global_use_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id.file_id(db.upcast())?);
+    let mut resolver = Resolver::new(db, module_file_id, InferenceId::NoContext);
+    resolver.resolved_global_use = Some(global_use_id);
+    let star_ast = db.module_global_use_by_id(global_use_id)?.to_maybe()?;
+    let syntax_db = db.upcast();
+    let visibility = Visibility::from_ast(
+        db.upcast(),
+        &mut diagnostics.diagnostics,
+        &get_item_use(syntax_db, star_ast.as_syntax_node()).visibility(syntax_db),
+        module_file_id.0,
+    );
+    let mut segments = vec![];
+    get_use_segments(syntax_db, &ast::UsePath::Star(star_ast.clone()), &mut segments)?;
+    let imported_module = if segments.is_empty() {
+        Err(diagnostics.report(&star_ast, GlobUseNotModule))
+    } else {
+        match resolver.resolve_generic_path(
+            &mut diagnostics,
+            segments,
+            NotFoundItemType::Identifier,
+        ) {
+            Ok(ResolvedGenericItem::Module(module_id)) => Ok(module_id),
+            Ok(_) => Err(diagnostics.report(&star_ast, GlobUseNotModule)),
+            Err(diag_added) => Err(diag_added),
+        }
+    };
+
+    Ok(UseGlobalData { diagnostics: diagnostics.build(), imported_module, visibility })
+}
+
+/// Returns the `use` item a use path node is part of.
+fn get_item_use(db: &dyn SyntaxGroup, mut node: SyntaxNode) -> ast::ItemUse {
+    while node.kind(db) != SyntaxKind::ItemUse {
+        node = node.parent().expect("UsePath is not under an ItemUse.");
+    }
+    ast::ItemUse::from_syntax_node(db, node)
+}
+
+/// Cycle handling for [crate::db::SemanticGroup::priv_global_use_semantic_data].
+pub fn priv_global_use_semantic_data_cycle(
+    db: &dyn SemanticGroup,
+    _cycle: &[String],
+    global_use_id: &GlobalUseId,
+) -> Maybe<UseGlobalData> {
+    let module_file_id = global_use_id.module_file_id(db.upcast());
+    let mut diagnostics = SemanticDiagnostics::new(module_file_id.file_id(db.upcast())?);
+    let star_ast = db.module_global_use_by_id(*global_use_id)?.to_maybe()?;
+    let imported_module = Err(diagnostics.report(&star_ast, UseCycle));
+    Ok(UseGlobalData {
+        diagnostics: diagnostics.build(),
+        imported_module,
+        visibility: Visibility::Public,
+    })
+}
+
+/// Query implementation of [crate::db::SemanticGroup::global_use_semantic_diagnostics].
+pub fn global_use_semantic_diagnostics(
+    db: &dyn SemanticGroup,
+    global_use_id: GlobalUseId,
+) -> Diagnostics<SemanticDiagnostic> {
+    db.priv_global_use_semantic_data(global_use_id).map(|data| data.diagnostics).unwrap_or_default()
+}
+
 pub trait SemanticUseEx<'a>: Upcast<dyn SemanticGroup + 'a> {
     /// Returns the resolved item or an error if it can't be resolved.
     ///
@@ -141,6 +221,18 @@ pub trait SemanticUseEx<'a>: Upcast<dyn SemanticGroup + 'a> {
         let db = self.upcast();
         db.priv_use_semantic_data(use_id)?.resolved_item
     }
+
+    /// Returns the module imported by a global use, and the visibility of the items it imports.
+    ///
+    /// This is not a query as the cycle handling is done in priv_global_use_semantic_data.
+    fn global_use_imported_module(
+        &self,
+        global_use_id: GlobalUseId,
+    ) -> Maybe<(ModuleId, Visibility)> {
+        let db = self.upcast();
+        let data = db.priv_global_use_semantic_data(global_use_id)?;
+        Ok((data.imported_module?, data.visibility))
+    }
 }
 
 impl<'a, T: Upcast<dyn SemanticGroup + 'a> + ?Sized> SemanticUseEx<'a> for T {}
@@ -1,11 +1,10 @@
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_defs::diagnostic_utils::StableLocation;
-use cairo_lang_defs::ids::ModuleId;
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId};
 use cairo_lang_diagnostics::DiagnosticsBuilder;
-use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::{ast, Terminal};
+use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 
-use crate::diagnostic::SemanticDiagnosticKind;
+use crate::diagnostic::{NotFoundItemType, SemanticDiagnosticKind};
 use crate::SemanticDiagnostic;
 
 /// Visibility of an item.
@@ -13,34 +12,138 @@ use crate::SemanticDiagnostic;
 pub enum Visibility {
     Public,
     PublicInCrate,
+    /// Visible in the given module and its descendants (`pub(super)` or `pub(in path)`).
+    PublicInModule(ModuleId),
     Private,
 }
 impl Visibility {
+    /// Computes the visibility of an item defined in `module_id` from its visibility syntax.
     pub fn from_ast(
-        db: &dyn SyntaxGroup,
+        db: &dyn DefsGroup,
         diagnostics: &mut DiagnosticsBuilder<SemanticDiagnostic>,
         visibility: &ast::Visibility,
+        module_id: ModuleId,
     ) -> Self {
-        match visibility {
-            ast::Visibility::Pub(visibility_pub) => match visibility_pub.argument_clause(db) {
-                ast::OptionVisibilityPubArgumentClause::Empty(_) => Self::Public,
-                ast::OptionVisibilityPubArgumentClause::VisibilityPubArgumentClause(argument) => {
-                    if argument.argument(db).text(db) == "crate" {
-                        Self::PublicInCrate
-                    } else {
+        let syntax_db = db.upcast();
+        let argument_clause = match visibility {
+            ast::Visibility::Pub(visibility_pub) => match visibility_pub.argument_clause(syntax_db)
+            {
+                ast::OptionVisibilityPubArgumentClause::Empty(_) => return Self::Public,
+                ast::OptionVisibilityPubArgumentClause::VisibilityPubArgumentClause(clause) => {
+                    clause
+                }
+            },
+            ast::Visibility::Default(_) => return Self::Private,
+        };
+        let visible_module_id = match argument_clause.argument(syntax_db) {
+            ast::VisibilityPubArgument::Simple(identifier) => {
+                match identifier.text(syntax_db).as_str() {
+                    "crate" => return Self::PublicInCrate,
+                    "self" => return Self::Private,
+                    "super" => match module_id {
+                        ModuleId::CrateRoot(_) => {
+                            diagnostics.add(SemanticDiagnostic::new(
+                                StableLocation::from_ast(&identifier),
+                                SemanticDiagnosticKind::SuperUsedInRootModule,
+                            ));
+                            return Self::Public;
+                        }
+                        ModuleId::Submodule(submodule_id) => submodule_id.parent_module(db),
+                    },
+                    _ => {
                         diagnostics.add(SemanticDiagnostic::new(
-                            StableLocation::from_ast(&argument),
+                            StableLocation::from_ast(&argument_clause),
                             SemanticDiagnosticKind::UnsupportedPubArgument,
                         ));
-                        Self::Public
+                        return Self::Public;
                     }
                 }
-            },
-            ast::Visibility::Default(_) => Self::Private,
+            }
+            ast::VisibilityPubArgument::In(argument_in) => {
+                let path = argument_in.path(syntax_db);
+                let Some(visible_module_id) =
+                    resolve_visibility_path(db, diagnostics, &path, module_id)
+                else {
+                    return Self::Public;
+                };
+                if visible_module_id != module_id
+                    && !db.module_ancestors(module_id).contains(&visible_module_id)
+                {
+                    diagnostics.add(SemanticDiagnostic::new(
+                        StableLocation::from_ast(&path),
+                        SemanticDiagnosticKind::PubInPathNotAncestor {
+                            module_path: path.as_syntax_node().get_text_without_trivia(syntax_db),
+                        },
+                    ));
+                    return Self::Public;
+                }
+                visible_module_id
+            }
+        };
+        if visible_module_id == module_id {
+            Self::Private
+        } else if matches!(visible_module_id, ModuleId::CrateRoot(_)) {
+            Self::PublicInCrate
+        } else {
+            Self::PublicInModule(visible_module_id)
         }
     }
 }
 
+/// Resolves the module path of a `pub(in path)` visibility, relative to the module the item is
+/// defined in. Only `crate`, `self`, `super` and submodule names are allowed as segments.
+/// Returns None and adds a diagnostic if the path does not lead to a module.
+fn resolve_visibility_path(
+    db: &dyn DefsGroup,
+    diagnostics: &mut DiagnosticsBuilder<SemanticDiagnostic>,
+    path: &ast::ExprPath,
+    module_id: ModuleId,
+) -> Option<ModuleId> {
+    let syntax_db = db.upcast();
+    let mut current = module_id;
+    for (i, segment) in path.elements(syntax_db).into_iter().enumerate() {
+        let ast::PathSegment::Simple(simple) = &segment else {
+            diagnostics.add(SemanticDiagnostic::new(
+                StableLocation::new(segment.stable_ptr().untyped()),
+                SemanticDiagnosticKind::InvalidPath,
+            ));
+            return None;
+        };
+        let identifier = simple.ident(syntax_db);
+        current = match identifier.text(syntax_db).as_str() {
+            "crate" if i == 0 => ModuleId::CrateRoot(module_id.owning_crate(db)),
+            "self" if i == 0 => module_id,
+            "super" => match current {
+                ModuleId::CrateRoot(_) => {
+                    diagnostics.add(SemanticDiagnostic::new(
+                        StableLocation::from_ast(&identifier),
+                        SemanticDiagnosticKind::SuperUsedInRootModule,
+                    ));
+                    return None;
+                }
+                ModuleId::Submodule(submodule_id) => submodule_id.parent_module(db),
+            },
+            name => {
+                let submodule_id = db
+                    .module_submodules_ids(current)
+                    .ok()?
+                    .iter()
+                    .copied()
+                    .find(|submodule_id| submodule_id.name(db) == name);
+                let Some(submodule_id) = submodule_id else {
+                    diagnostics.add(SemanticDiagnostic::new(
+                        StableLocation::from_ast(&identifier),
+                        SemanticDiagnosticKind::PathNotFound(NotFoundItemType::Identifier),
+                    ));
+                    return None;
+                };
+                ModuleId::Submodule(submodule_id)
+            }
+        };
+    }
+    Some(current)
+}
+
 /// Determine whether a module member is visible to user module given the visibility within it,
 /// ignoring or forgetting the visibility of the ancestors of the containing module for a moment.
 pub fn peek_visible_in(
@@ -57,6 +160,10 @@ pub fn peek_visible_in(
         Visibility::PublicInCrate => {
             user_module_id.owning_crate(db) == containing_module_id.owning_crate(db)
         }
+        Visibility::PublicInModule(visible_module_id) => {
+            user_module_id == visible_module_id
+                || db.module_ancestors(user_module_id).contains(&visible_module_id)
+        }
         Visibility::Private => db.module_ancestors(user_module_id).contains(&containing_module_id),
     }
 }
@@ -0,0 +1,247 @@
+//! Lints: named kinds of diagnostics whose reporting level may be configured.
+//!
+//! The level of a lint at a given location is determined by the nearest `#[allow(..)]`,
+//! `#[warn(..)]` or `#[deny(..)]` attribute naming it, looking at the syntax ancestors of the
+//! location and then at the declarations of the enclosing modules. If no attribute names the
+//! lint, the crate settings are used, and finally the default level of the lint.
+
+use cairo_lang_defs::diagnostic_utils::StableLocation;
+use cairo_lang_defs::ids::{LanguageElementId, ModuleId};
+use cairo_lang_diagnostics::{DiagnosticEntry, Diagnostics, DiagnosticsBuilder, Severity};
+use cairo_lang_filesystem::db::LintLevel;
+use cairo_lang_syntax::attribute::structured::AttributeListStructurize;
+use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
+use cairo_lang_syntax::node::kind::SyntaxKind;
+use cairo_lang_syntax::node::{ast, SyntaxNode, TypedSyntaxNode};
+
+use crate::db::SemanticGroup;
+use crate::diagnostic::SemanticDiagnosticKind;
+use crate::items::attribute::{lint_level_attr, lint_level_from_attributes};
+use crate::SemanticDiagnostic;
+
+/// The name of the lint group containing all the lints that warn by default, as well as all the
+/// warnings not reported by a specific lint (e.g. plugin warnings).
+pub const WARNINGS_LINT_GROUP: &str = "warnings";
+
+/// A lint - a named kind of diagnostics whose reporting level may be configured.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Lint {
+    /// Variables that are never used.
+    UnusedVariables,
+    /// Values of `#[must_use]` types or functions that are not used.
+    UnusedMustUse,
+    /// Code that can never be executed.
+    UnreachableCode,
+    /// Lint attributes naming unknown lints.
+    UnknownLints,
+}
+impl Lint {
+    /// All the lints.
+    pub const ALL: [Lint; 4] =
+        [Lint::UnusedVariables, Lint::UnusedMustUse, Lint::UnreachableCode, Lint::UnknownLints];
+
+    /// The name of the lint, as used in attributes, crate settings and command line flags.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::UnusedVariables => "unused_variables",
+            Lint::UnusedMustUse => "unused_must_use",
+            Lint::UnreachableCode => "unreachable_code",
+            Lint::UnknownLints => "unknown_lints",
+        }
+    }
+
+    /// Returns the lint with the given name, if any.
+    pub fn from_name(name: &str) -> Option<Lint> {
+        Lint::ALL.into_iter().find(|lint| lint.name() == name)
+    }
+
+    /// The level of the lint when not configured otherwise.
+    pub fn default_level(&self) -> LintLevel {
+        match self {
+            Lint::UnusedVariables | Lint::UnusedMustUse | Lint::UnknownLints => LintLevel::Warn,
+            Lint::UnreachableCode => LintLevel::Deny,
+        }
+    }
+}
+
+/// Returns true if the given name is the name of a lint or of a lint group.
+pub fn is_known_lint_name(name: &str) -> bool {
+    name == WARNINGS_LINT_GROUP || Lint::from_name(name).is_some()
+}
+
+/// A diagnostic whose reporting may be controlled by lint levels.
+pub trait LintDiagnostic: DiagnosticEntry<DbType = dyn SemanticGroup> {
+    /// The lint reporting this diagnostic, if any.
+    fn lint(&self) -> Option<Lint>;
+    /// The pointer to the syntax whose ancestors' attributes control the level of the diagnostic.
+    fn lint_stable_ptr(&self) -> SyntaxStablePtrId;
+    /// Overrides the severity of the diagnostic.
+    fn set_severity(&mut self, severity: Severity);
+}
+
+impl LintDiagnostic for SemanticDiagnostic {
+    fn lint(&self) -> Option<Lint> {
+        match &self.kind {
+            SemanticDiagnosticKind::UnusedVariable => Some(Lint::UnusedVariables),
+            SemanticDiagnosticKind::UnhandledMustUseType { .. }
+            | SemanticDiagnosticKind::UnhandledMustUseFunction => Some(Lint::UnusedMustUse),
+            SemanticDiagnosticKind::UnknownLint { .. } => Some(Lint::UnknownLints),
+            _ => None,
+        }
+    }
+
+    fn lint_stable_ptr(&self) -> SyntaxStablePtrId {
+        self.stable_location.stable_ptr()
+    }
+
+    fn set_severity(&mut self, severity: Severity) {
+        self.severity_override = Some(severity);
+    }
+}
+
+/// Applies the lint levels in effect at the locations of the given diagnostics of a module:
+/// allowed diagnostics are dropped, and the severity of the rest is set according to their level.
+///
+/// Only diagnostics reported by a lint and warnings are affected. Warnings not reported by a
+/// specific lint are controlled by the [WARNINGS_LINT_GROUP] group alone.
+pub fn apply_lint_levels<T: LintDiagnostic>(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    diagnostics: Diagnostics<T>,
+) -> Diagnostics<T> {
+    let all = diagnostics.get_all();
+    if !all.iter().any(|diagnostic| is_lint_controlled(diagnostic)) {
+        return diagnostics;
+    }
+    let mut builder = DiagnosticsBuilder::default();
+    for mut diagnostic in all {
+        if !is_lint_controlled(&diagnostic) {
+            builder.add(diagnostic);
+            continue;
+        }
+        let ptr = diagnostic.lint_stable_ptr();
+        let mut level = match diagnostic.lint() {
+            Some(lint) => {
+                lint_level(db, module_id, ptr, lint.name()).unwrap_or_else(|| lint.default_level())
+            }
+            None => LintLevel::Warn,
+        };
+        if level == LintLevel::Warn {
+            level = lint_level(db, module_id, ptr, WARNINGS_LINT_GROUP).unwrap_or(LintLevel::Warn);
+        }
+        match level {
+            LintLevel::Allow => continue,
+            LintLevel::Warn => diagnostic.set_severity(Severity::Warning),
+            LintLevel::Deny => diagnostic.set_severity(Severity::Error),
+        }
+        builder.add(diagnostic);
+    }
+    builder.build()
+}
+
+/// Returns true if the level of the given diagnostic may be controlled by lint levels.
+fn is_lint_controlled<T: LintDiagnostic>(diagnostic: &T) -> bool {
+    diagnostic.lint().is_some() || diagnostic.severity() == Severity::Warning
+}
+
+/// Returns the level configured for the lint (or lint group) named `lint_name` at the given
+/// location in the given module, if any.
+fn lint_level(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    ptr: SyntaxStablePtrId,
+    lint_name: &str,
+) -> Option<LintLevel> {
+    let syntax_db = db.upcast();
+    let mut node = Some(ptr.lookup(syntax_db));
+    while let Some(current) = node {
+        if let Some(level) = syntax_lint_level(syntax_db, &current, lint_name) {
+            return Some(level);
+        }
+        node = current.parent();
+    }
+    module_lint_level(db, module_id, lint_name)
+}
+
+/// Returns the level configured for the lint (or lint group) named `lint_name` by the declaration
+/// of the given module or of its ancestors, or by the settings of its crate.
+fn module_lint_level(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    lint_name: &str,
+) -> Option<LintLevel> {
+    match module_id {
+        ModuleId::CrateRoot(crate_id) => {
+            db.crate_config(crate_id)?.settings.lints.get(lint_name).copied()
+        }
+        ModuleId::Submodule(submodule_id) => db
+            .module_attributes(module_id)
+            .ok()
+            .and_then(|attributes| lint_level_from_attributes(db.upcast(), &attributes, lint_name))
+            .or_else(|| module_lint_level(db, submodule_id.parent_module(db.upcast()), lint_name)),
+    }
+}
+
+/// Returns the level set for the lint (or lint group) named `lint_name` by the attributes of the
+/// given syntax node, if it has any.
+fn syntax_lint_level(
+    db: &dyn SyntaxGroup,
+    node: &SyntaxNode,
+    lint_name: &str,
+) -> Option<LintLevel> {
+    let attribute_list = attribute_list_child(db, node)?;
+    lint_level_from_attributes(db, &attribute_list.structurize(db), lint_name)
+}
+
+/// Returns the attribute list of the given syntax node, if it has one.
+fn attribute_list_child(db: &dyn SyntaxGroup, node: &SyntaxNode) -> Option<ast::AttributeList> {
+    db.get_children(node.clone())
+        .iter()
+        .find(|child| child.kind(db) == SyntaxKind::AttributeList)
+        .map(|child| ast::AttributeList::from_syntax_node(db, child.clone()))
+}
+
+/// Reports the lint attributes naming unknown lints in the files of the given module.
+///
+/// Only the files written by the user are checked, each by the module it is the main file of.
+pub fn report_unknown_lints(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    diagnostics: &mut DiagnosticsBuilder<SemanticDiagnostic>,
+) {
+    let Ok(file_id) = db.module_main_file(module_id) else {
+        return;
+    };
+    if let ModuleId::Submodule(submodule_id) = module_id {
+        // Inline modules are checked as part of the file of their parent.
+        if db.module_main_file(submodule_id.parent_module(db.upcast())) == Ok(file_id) {
+            return;
+        }
+    }
+    let Ok(syntax) = db.file_syntax(file_id) else {
+        return;
+    };
+    let syntax_db = db.upcast();
+    for node in syntax.descendants(syntax_db) {
+        if node.kind(syntax_db) != SyntaxKind::AttributeList {
+            continue;
+        }
+        let attributes =
+            ast::AttributeList::from_syntax_node(syntax_db, node).structurize(syntax_db);
+        for attr in attributes {
+            if lint_level_attr(&attr).is_none() {
+                continue;
+            }
+            for arg in attr.args {
+                let name = arg.text(syntax_db);
+                if !is_known_lint_name(&name) {
+                    diagnostics.add(SemanticDiagnostic::new(
+                        StableLocation::new(arg.arg_stable_ptr.untyped()),
+                        SemanticDiagnosticKind::UnknownLint { name },
+                    ));
+                }
+            }
+        }
+    }
+}
@@ -2,9 +2,10 @@ use std::sync::Arc;
 
 use cairo_lang_defs::ids::{
     ConstantId, EnumId, ExternFunctionId, ExternTypeId, FileIndex, FreeFunctionId,
-    FunctionWithBodyId, ImplAliasId, ImplDefId, ImplFunctionId, ImplItemId, ImplTypeId,
-    LanguageElementId, LookupItemId, ModuleFileId, ModuleId, ModuleItemId, ModuleTypeAliasId,
-    StructId, SubmoduleId, TraitFunctionId, TraitId, TraitItemId, TraitTypeId, UseId,
+    FunctionWithBodyId, ImplAliasId, ImplConstantDefId, ImplDefId, ImplFunctionId, ImplItemId,
+    ImplTypeDefId, LanguageElementId, LookupItemId, ModuleFileId, ModuleId, ModuleItemId,
+    ModuleTypeAliasId, StructId, SubmoduleId, TraitConstantId, TraitFunctionId, TraitId,
+    TraitItemId, TraitTypeId, UseId,
 };
 use cairo_lang_diagnostics::Maybe;
 
@@ -176,10 +177,17 @@ impl HasResolverData for TraitItemId {
         match self {
             TraitItemId::Function(item) => item.resolver_data(db),
             TraitItemId::Type(item) => item.resolver_data(db),
+            TraitItemId::Constant(item) => item.resolver_data(db),
         }
     }
 }
 
+impl HasResolverData for TraitConstantId {
+    fn resolver_data(&self, db: &dyn SemanticGroup) -> Maybe<Arc<ResolverData>> {
+        db.trait_constant_resolver_data(*self)
+    }
+}
+
 impl HasResolverData for TraitTypeId {
     fn resolver_data(&self, db: &dyn SemanticGroup) -> Maybe<Arc<ResolverData>> {
         db.trait_type_resolver_data(*self)
@@ -199,11 +207,18 @@ impl HasResolverData for ImplItemId {
         match self {
             ImplItemId::Function(item) => item.resolver_data(db),
             ImplItemId::Type(item) => item.resolver_data(db),
+            ImplItemId::Constant(item) => item.resolver_data(db),
         }
     }
 }
 
-impl HasResolverData for ImplTypeId {
+impl HasResolverData for ImplConstantDefId {
+    fn resolver_data(&self, db: &dyn SemanticGroup) -> Maybe<Arc<ResolverData>> {
+        db.impl_constant_def_resolver_data(*self)
+    }
+}
+
+impl HasResolverData for ImplTypeDefId {
     fn resolver_data(&self, db: &dyn SemanticGroup) -> Maybe<Arc<ResolverData>> {
         db.impl_type_resolver_data(*self)
     }
@@ -1,13 +1,13 @@
 use cairo_lang_defs::ids::{
     ConstantId, FunctionWithBodyId, GenericTypeId, ImplAliasId, ImplDefId, ModuleId, ModuleItemId,
-    ModuleTypeAliasId, TopLevelLanguageElementId, TraitFunctionId, TraitId, VarId,
+    ModuleTypeAliasId, TopLevelLanguageElementId, TraitConstantId, TraitFunctionId, TraitId, VarId,
 };
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_proc_macros::DebugWithDb;
 
 use crate::db::SemanticGroup;
 use crate::items::functions::GenericFunctionId;
-use crate::items::imp::ImplId;
+use crate::items::imp::{ImplConstantId, ImplId};
 use crate::items::trt::ConcreteTraitGenericFunctionId;
 use crate::items::us::SemanticUseEx;
 use crate::{ConcreteTraitId, ConcreteVariant, FunctionId, TypeId, TypeLongId, Variant};
@@ -23,6 +23,7 @@ pub enum ResolvedGenericItem {
     Module(ModuleId),
     GenericFunction(GenericFunctionId),
     TraitFunction(TraitFunctionId),
+    TraitConstant(TraitConstantId),
     GenericType(GenericTypeId),
     GenericTypeAlias(ModuleTypeAliasId),
     GenericImplAlias(ImplAliasId),
@@ -70,6 +71,7 @@ impl ResolvedGenericItem {
             ResolvedGenericItem::Module(id) => id.full_path(defs_db),
             ResolvedGenericItem::GenericFunction(id) => id.format(db),
             ResolvedGenericItem::TraitFunction(id) => id.full_path(defs_db),
+            ResolvedGenericItem::TraitConstant(id) => id.full_path(defs_db),
             ResolvedGenericItem::GenericType(id) => id.full_path(defs_db),
             ResolvedGenericItem::GenericTypeAlias(id) => id.full_path(defs_db),
             ResolvedGenericItem::GenericImplAlias(id) => id.full_path(defs_db),
@@ -92,6 +94,7 @@ pub enum ResolvedConcreteItem {
     Variant(ConcreteVariant),
     Trait(ConcreteTraitId),
     Impl(ImplId),
+    ImplConstant(ImplConstantId),
 }
 impl ResolvedConcreteItem {
     pub fn generic(&self, db: &dyn SemanticGroup) -> Option<ResolvedGenericItem> {
@@ -119,6 +122,9 @@ impl ResolvedConcreteItem {
                     idx: *idx,
                 })
             }
+            ResolvedConcreteItem::ImplConstant(impl_constant_id) => {
+                ResolvedGenericItem::TraitConstant(impl_constant_id.trait_constant_id())
+            }
             ResolvedConcreteItem::Trait(concrete_trait) => ResolvedGenericItem::Trait(
                 db.lookup_intern_concrete_trait(*concrete_trait).trait_id,
             ),
@@ -126,7 +132,12 @@ impl ResolvedConcreteItem {
                 ImplId::Concrete(concrete_impl_id) => ResolvedGenericItem::Impl(
                     db.lookup_intern_concrete_impl(*concrete_impl_id).impl_def_id,
                 ),
-                ImplId::GenericParameter(_) | ImplId::ImplVar(_) => return None,
+                ImplId::GenericParameter(_)
+                | ImplId::ImplVar(_)
+                | ImplId::GeneratedImpl(_)
+                | ImplId::SelfImpl(_) => {
+                    return None;
+                }
             },
         })
     }
@@ -3,8 +3,8 @@ use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut, Neg};
 
 use cairo_lang_defs::ids::{
-    GenericKind, GenericParamId, GenericTypeId, ImplDefId, LanguageElementId, ModuleFileId,
-    ModuleId, TraitId,
+    GenericKind, GenericParamId, GenericTypeId, GlobalUseId, ImplDefId, LanguageElementId,
+    ModuleFileId, ModuleId, TraitId,
 };
 use cairo_lang_diagnostics::Maybe;
 use cairo_lang_filesystem::db::Edition;
@@ -16,6 +16,7 @@ use cairo_lang_syntax::node::helpers::PathSegmentEx;
 use cairo_lang_syntax::node::ids::SyntaxStablePtrId;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use cairo_lang_utils::ordered_hash_set::OrderedHashSet;
 use cairo_lang_utils::try_extract_matches;
 use cairo_lang_utils::unordered_hash_map::UnorderedHashMap;
 pub use item::{ResolvedConcreteItem, ResolvedGenericItem};
@@ -27,13 +28,17 @@ use crate::corelib::{core_submodule, get_submodule};
 use crate::db::SemanticGroup;
 use crate::diagnostic::SemanticDiagnosticKind::*;
 use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
+use crate::expr::inference::canonic::ResultNoErrEx;
 use crate::expr::inference::conform::InferenceConform;
 use crate::expr::inference::infers::InferenceEmbeddings;
 use crate::expr::inference::{Inference, InferenceData, InferenceId};
 use crate::items::enm::SemanticEnumEx;
-use crate::items::functions::{GenericFunctionId, ImplGenericFunctionId};
-use crate::items::imp::{ConcreteImplId, ConcreteImplLongId, ImplId, ImplLookupContext};
-use crate::items::module::ModuleItemInfo;
+use crate::items::functions::{generic_params_to_args, GenericFunctionId, ImplGenericFunctionId};
+use crate::items::imp::{
+    reduce_impl_type, self_impl_of_impl_def, ConcreteImplId, ConcreteImplLongId, ImplConstantId,
+    ImplId, ImplLookupContext, ImplTypeId,
+};
+use crate::items::module::{collect_glob_imported_items, ModuleItemInfo};
 use crate::items::trt::{ConcreteTraitGenericFunctionLongId, ConcreteTraitId, ConcreteTraitLongId};
 use crate::items::visibility;
 use crate::literals::LiteralLongId;
@@ -49,6 +54,9 @@ mod test;
 
 mod item;
 
+/// The name by which the impl of the current trait or impl is referred to.
+pub const SELF_IMPL_NAME: &str = "Self";
+
 /// Lookback maps for item resolving. Can be used to quickly check what is the semantic resolution
 /// of any path segment.
 #[derive(Clone, Default, Debug, PartialEq, Eq, DebugWithDb)]
@@ -88,6 +96,15 @@ impl ResolvedItems {
     }
 }
 
+/// The trait or impl a resolver resolves paths inside of, if any. Used for resolving `Self`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TraitOrImplContext {
+    #[default]
+    None,
+    Trait(TraitId),
+    Impl(ImplDefId),
+}
+
 #[derive(Debug, PartialEq, Eq, DebugWithDb)]
 #[debug_db(dyn SemanticGroup + 'static)]
 pub struct ResolverData {
@@ -101,6 +118,9 @@ pub struct ResolverData {
     pub resolved_items: ResolvedItems,
     /// Inference data for the resolver.
     pub inference_data: InferenceData,
+    /// The trait or impl in which the resolver resolves paths.
+    #[hide_field_debug_with_db]
+    pub trait_or_impl_ctx: TraitOrImplContext,
 }
 impl ResolverData {
     pub fn new(module_file_id: ModuleFileId, inference_id: InferenceId) -> Self {
@@ -110,6 +130,7 @@ impl ResolverData {
             generic_params: Default::default(),
             resolved_items: Default::default(),
             inference_data: InferenceData::new(inference_id),
+            trait_or_impl_ctx: TraitOrImplContext::None,
         }
     }
     pub fn clone_with_inference_id(
@@ -123,6 +144,7 @@ impl ResolverData {
             generic_params: self.generic_params.clone(),
             resolved_items: self.resolved_items.clone(),
             inference_data: self.inference_data.clone_with_inference_id(db, inference_id),
+            trait_or_impl_ctx: self.trait_or_impl_ctx,
         }
     }
 }
@@ -132,6 +154,8 @@ pub struct Resolver<'db> {
     db: &'db dyn SemanticGroup,
     pub data: ResolverData,
     pub edition: Edition,
+    /// A glob import whose path is being resolved, and thus must not be used for lookups.
+    pub resolved_global_use: Option<GlobalUseId>,
 }
 impl Deref for Resolver<'_> {
     type Target = ResolverData;
@@ -167,16 +191,7 @@ impl<'db> Resolver<'db> {
         module_file_id: ModuleFileId,
         inference_id: InferenceId,
     ) -> Self {
-        Self::with_data(
-            db,
-            ResolverData {
-                module_file_id,
-                generic_param_by_name: Default::default(),
-                generic_params: Default::default(),
-                resolved_items: Default::default(),
-                inference_data: InferenceData::new(inference_id),
-            },
-        )
+        Self::with_data(db, ResolverData::new(module_file_id, inference_id))
     }
 
     pub fn with_data(db: &'db dyn SemanticGroup, data: ResolverData) -> Self {
@@ -184,6 +199,7 @@ impl<'db> Resolver<'db> {
             edition: extract_edition(db, data.module_file_id.0.owning_crate(db.upcast())),
             db,
             data,
+            resolved_global_use: None,
         }
     }
 
@@ -511,10 +527,9 @@ impl<'db> Resolver<'db> {
                 if ident == "super" {
                     return Err(diagnostics.report(identifier, InvalidPath));
                 }
-                let item_info = self
-                    .db
-                    .module_item_info_by_name(*module_id, ident)?
-                    .ok_or_else(|| diagnostics.report(identifier, PathNotFound(item_type)))?;
+                let item_info =
+                    self.resolve_item_in_module(diagnostics, *module_id, identifier)?
+                        .ok_or_else(|| diagnostics.report(identifier, PathNotFound(item_type)))?;
                 self.validate_item_visibility(diagnostics, *module_id, identifier, &item_info);
                 let generic_item =
                     ResolvedGenericItem::from_module_item(self.db, item_info.item_id)?;
@@ -550,9 +565,31 @@ impl<'db> Resolver<'db> {
                 // Find the relevant function in the trait.
                 let long_trait_id = self.db.lookup_intern_concrete_trait(*concrete_trait_id);
                 let trait_id = long_trait_id.trait_id;
-                let Some(trait_function_id) = self.db.trait_function_by_name(trait_id, ident)?
+                let Some(trait_function_id) =
+                    self.db.trait_function_by_name(trait_id, ident.clone())?
                 else {
-                    return Err(diagnostics.report(identifier, InvalidPath));
+                    // An associated item of the trait. Infer the impl it is taken from.
+                    let impl_lookup_context = self.impl_lookup_context();
+                    let mut inference = self.inference();
+                    let impl_id = inference
+                        .new_impl_var(
+                            *concrete_trait_id,
+                            Some(identifier.stable_ptr().untyped()),
+                            impl_lookup_context,
+                        )
+                        .and_then(|impl_id| {
+                            inference.solve()?;
+                            Ok(inference.rewrite(impl_id).no_err())
+                        })
+                        .map_err(|err| {
+                            err.report(diagnostics, identifier.stable_ptr().untyped())
+                        })?;
+                    return self.resolve_impl_associated_item(
+                        diagnostics,
+                        impl_id,
+                        identifier,
+                        generic_args_syntax,
+                    );
                 };
 
                 let concrete_trait_function = self.db.intern_concrete_trait_function(
@@ -584,9 +621,15 @@ impl<'db> Resolver<'db> {
             ResolvedConcreteItem::Impl(impl_id) => {
                 let concrete_trait_id = self.db.impl_concrete_trait(*impl_id)?;
                 let trait_id = concrete_trait_id.trait_id(self.db);
-                let Some(trait_function_id) = self.db.trait_function_by_name(trait_id, ident)?
+                let Some(trait_function_id) =
+                    self.db.trait_function_by_name(trait_id, ident.clone())?
                 else {
-                    return Err(diagnostics.report(identifier, InvalidPath));
+                    return self.resolve_impl_associated_item(
+                        diagnostics,
+                        *impl_id,
+                        identifier,
+                        generic_args_syntax,
+                    );
                 };
                 let generic_function_id = GenericFunctionId::Impl(ImplGenericFunctionId {
                     impl_id: *impl_id,
@@ -604,6 +647,32 @@ impl<'db> Resolver<'db> {
         }
     }
 
+    /// Resolves an associated type or constant of an impl, i.e. the `Item` in `ImplPath::Item`.
+    fn resolve_impl_associated_item(
+        &mut self,
+        diagnostics: &mut SemanticDiagnostics,
+        impl_id: ImplId,
+        identifier: &ast::TerminalIdentifier,
+        generic_args_syntax: Option<Vec<ast::GenericArg>>,
+    ) -> Maybe<ResolvedConcreteItem> {
+        let ident = identifier.text(self.db.upcast());
+        let trait_id = self.db.impl_concrete_trait(impl_id)?.trait_id(self.db);
+        if let Some(generic_args_syntax) = generic_args_syntax {
+            return Err(diagnostics.report(&generic_args_syntax[0], UnexpectedGenericArgs));
+        }
+        if let Some(trait_type_id) = self.db.trait_type_by_name(trait_id, ident.clone())? {
+            let ty = reduce_impl_type(self.db, ImplTypeId::new(impl_id, trait_type_id))?;
+            return Ok(ResolvedConcreteItem::Type(ty));
+        }
+        if let Some(trait_constant_id) = self.db.trait_constant_by_name(trait_id, ident)? {
+            return Ok(ResolvedConcreteItem::ImplConstant(ImplConstantId::new(
+                impl_id,
+                trait_constant_id,
+            )));
+        }
+        Err(diagnostics.report(identifier, InvalidPath))
+    }
+
     /// Specializes a ResolvedGenericItem that came from a ModuleItem.
     fn specialize_generic_module_item(
         &mut self,
@@ -694,6 +763,7 @@ impl<'db> Resolver<'db> {
             }
             ResolvedGenericItem::Variant(_) => panic!("Variant is not a module item."),
             ResolvedGenericItem::TraitFunction(_) => panic!("TraitFunction is not a module item."),
+            ResolvedGenericItem::TraitConstant(_) => panic!("TraitConstant is not a module item."),
             ResolvedGenericItem::Variable(_, _) => panic!("Variable is not a module item."),
         })
     }
@@ -710,10 +780,9 @@ impl<'db> Resolver<'db> {
         let ident = identifier.text(syntax_db);
         match item {
             ResolvedGenericItem::Module(module_id) => {
-                let item_info = self
-                    .db
-                    .module_item_info_by_name(*module_id, ident)?
-                    .ok_or_else(|| diagnostics.report(identifier, PathNotFound(item_type)))?;
+                let item_info =
+                    self.resolve_item_in_module(diagnostics, *module_id, identifier)?
+                        .ok_or_else(|| diagnostics.report(identifier, PathNotFound(item_type)))?;
                 self.validate_item_visibility(diagnostics, *module_id, identifier, &item_info);
                 ResolvedGenericItem::from_module_item(self.db, item_info.item_id)
             }
@@ -740,6 +809,13 @@ impl<'db> Resolver<'db> {
         let syntax_db = self.db.upcast();
         let ident = identifier.text(syntax_db);
 
+        // `Self` inside a trait or an impl refers to the impl of the trait.
+        if ident == SELF_IMPL_NAME {
+            if let Some(impl_id) = self.self_impl() {
+                return Some(ResolvedConcreteItem::Impl(impl_id));
+            }
+        }
+
         // If a generic param with this name is found, use it.
         if let Some(generic_param_id) = self.data.generic_param_by_name.get(&ident) {
             let item = match generic_param_id.kind(self.db.upcast()) {
@@ -759,6 +835,23 @@ impl<'db> Resolver<'db> {
         None
     }
 
+    /// Returns the impl `Self` refers to in the current trait or impl context, if any.
+    fn self_impl(&self) -> Option<ImplId> {
+        match self.trait_or_impl_ctx {
+            TraitOrImplContext::None => None,
+            TraitOrImplContext::Trait(trait_id) => {
+                let generic_params = self.db.trait_generic_params(trait_id).ok()?;
+                let generic_args = generic_params_to_args(generic_params, self.db).ok()?;
+                Some(ImplId::SelfImpl(
+                    self.db.intern_concrete_trait(ConcreteTraitLongId { trait_id, generic_args }),
+                ))
+            }
+            TraitOrImplContext::Impl(impl_def_id) => {
+                self_impl_of_impl_def(self.db, impl_def_id).ok()
+            }
+        }
+    }
+
     /// Determines the base module for the path resolving. Looks only in non-local scope (i.e.
     /// current module, or crates).
     /// Returns Some(module) if the identifier is an item in a module. Otherwise, the path is fully
@@ -774,10 +867,15 @@ impl<'db> Resolver<'db> {
 
         // If the first segment is a name of a crate, use the crate's root module as the base
         // module.
-        let crate_id = self.db.intern_crate(CrateLongId::Real(ident));
+        let crate_id = self.db.intern_crate(CrateLongId::Real(ident.clone()));
         if self.db.crate_config(crate_id).is_some() {
             return None;
         }
+        // If an item with this name is imported by a glob import of the current module, use the
+        // current module.
+        if !self.glob_imported_items(self.module_file_id.0, &ident).is_empty() {
+            return Some(self.module_file_id.0);
+        }
         // Last resort, use the `prelude` module as the base module.
         Some(self.prelude_submodule())
     }
@@ -969,7 +1067,7 @@ impl<'db> Resolver<'db> {
                 diagnostics,
             )?;
             resolved_args.push(generic_arg);
-            substitution.0.insert(generic_param.id(), generic_arg);
+            substitution.insert(generic_param.id(), generic_arg);
         }
 
         Ok(resolved_args)
@@ -1084,9 +1182,46 @@ impl<'db> Resolver<'db> {
     /// Should visibility checks not actually happen for lookups in this module.
     // TODO(orizi): Remove this check when performing a major Cairo update.
     pub fn ignore_visibility_checks(&self, module_id: ModuleId) -> bool {
-        let owning_crate = module_id.owning_crate(self.db.upcast());
-        extract_edition(self.db, owning_crate).ignore_visibility()
-            || self.edition.ignore_visibility() && owning_crate == self.db.core_crate()
+        ignore_visibility_checks(self.db, self.edition, module_id)
+    }
+
+    /// Resolves the item named `identifier` in the given module. Items defined or explicitly
+    /// imported in the module take priority over items imported by its glob imports.
+    /// Returns None if no such item exists, and adds a diagnostic if the name is ambiguous.
+    fn resolve_item_in_module(
+        &self,
+        diagnostics: &mut SemanticDiagnostics,
+        module_id: ModuleId,
+        identifier: &ast::TerminalIdentifier,
+    ) -> Maybe<Option<ModuleItemInfo>> {
+        let ident = identifier.text(self.db.upcast());
+        if let Some(item_info) = self.db.module_item_info_by_name(module_id, ident.clone())? {
+            return Ok(Some(item_info));
+        }
+        let mut glob_imported_items = self.glob_imported_items(module_id, &ident).into_iter();
+        let item_info = glob_imported_items.next();
+        if glob_imported_items.next().is_some() {
+            return Err(diagnostics.report(identifier, AmbiguousGlobImport { name: ident }));
+        }
+        Ok(item_info)
+    }
+
+    /// Returns the distinct items named `ident` imported by the glob imports of the given module.
+    /// Only items visible from the module are imported, and they get the visibility of the glob
+    /// import. Names glob-imported into the imported modules are imported as well.
+    fn glob_imported_items(&self, module_id: ModuleId, ident: &SmolStr) -> Vec<ModuleItemInfo> {
+        let mut visited = OrderedHashSet::default();
+        collect_glob_imported_items(
+            self.db,
+            module_id,
+            Some(ident),
+            self.edition,
+            self.resolved_global_use,
+            &mut visited,
+        )
+        .into_iter()
+        .map(|(_, item_info, _)| item_info)
+        .collect()
     }
 
     /// Validates that an item is visible from the current module or adds a diagnostic.
@@ -1109,8 +1244,20 @@ impl<'db> Resolver<'db> {
     }
 }
 
+/// Should visibility checks not actually happen for lookups in `module_id` from a crate of the
+/// given edition.
+pub fn ignore_visibility_checks(
+    db: &dyn SemanticGroup,
+    user_edition: Edition,
+    module_id: ModuleId,
+) -> bool {
+    let owning_crate = module_id.owning_crate(db.upcast());
+    extract_edition(db, owning_crate).ignore_visibility()
+        || user_edition.ignore_visibility() && owning_crate == db.core_crate()
+}
+
 /// Extracts the edition of a crate.
-fn extract_edition(db: &dyn SemanticGroup, crate_id: CrateId) -> Edition {
+pub fn extract_edition(db: &dyn SemanticGroup, crate_id: CrateId) -> Edition {
     db.crate_config(crate_id).map(|config| config.settings.edition).unwrap_or_default()
 }
 
@@ -3,7 +3,9 @@ use std::sync::Arc;
 use cairo_lang_debug::DebugWithDb;
 use cairo_lang_defs::ids::{FunctionWithBodyId, ModuleId, ModuleItemId};
 use cairo_lang_diagnostics::ToOption;
-use cairo_lang_filesystem::db::{AsFilesGroupMut, CrateConfiguration, FilesGroup, FilesGroupEx};
+use cairo_lang_filesystem::db::{
+    AsFilesGroupMut, CrateConfiguration, Edition, FilesGroup, FilesGroupEx,
+};
 use cairo_lang_filesystem::ids::{CrateLongId, Directory, FileLongId};
 use cairo_lang_utils::extract_matches;
 use indoc::indoc;
@@ -12,7 +14,9 @@ use test_log::test;
 
 use crate::db::SemanticGroup;
 use crate::expr::fmt::ExprFormatter;
-use crate::test_utils::{setup_test_module, SemanticDatabaseForTesting};
+use crate::test_utils::{
+    get_crate_semantic_diagnostics, setup_test_module, SemanticDatabaseForTesting,
+};
 
 #[test]
 fn test_resolve_path() {
@@ -45,7 +49,7 @@ fn test_resolve_path() {
         "Some(Block(ExprBlock { statements: [Expr(StatementExpr { expr: \
          FunctionCall(ExprFunctionCall { function: test::bar::<(core::felt252, Q)>, args: \
          [Value(Var(ParamId(test::value)))], ty: test::S::<()> }) }), Let(StatementLet { pattern: \
-         Variable(_c), expr: Var(ParamId(test::b)) })], tail: None, ty: () }))"
+         Variable(_c), expr: Var(ParamId(test::b)), else_clause: None })], tail: None, ty: () }))"
     );
 }
 
@@ -105,6 +109,144 @@ fn test_resolve_path_super() {
     );
 }
 
+#[test]
+fn test_restricted_visibility() {
+    let mut db_val = SemanticDatabaseForTesting::new_empty();
+    let db = &mut db_val;
+
+    let crate_id = db.intern_crate(CrateLongId::Real("test".into()));
+    let root = Directory::Real("src".into());
+    let mut config = CrateConfiguration::default_for_root(root);
+    config.settings.edition = Edition::V2023_11;
+    db.set_crate_config(crate_id, Some(config));
+
+    set_file_content(
+        db,
+        "src/lib.cairo",
+        indoc! {"
+            mod a;
+            mod c {}
+            fn use_from_root() {
+                a::crate_visible();
+                a::b::super_visible();
+                a::b::in_a_visible();
+                a::b::in_root_visible();
+            }
+        "},
+    );
+    set_file_content(
+        db,
+        "src/a.cairo",
+        indoc! {"
+            pub mod b;
+            pub(crate) fn crate_visible() {}
+            fn use_from_a() {
+                b::super_visible();
+                b::in_a_visible();
+                b::self_visible();
+            }
+        "},
+    );
+    set_file_content(
+        db,
+        "src/a/b.cairo",
+        indoc! {"
+            pub(super) fn super_visible() {}
+            pub(in crate::a) fn in_a_visible() {}
+            pub(in super::super) fn in_root_visible() {}
+            pub(self) fn self_visible() {}
+            pub(in crate::c) fn not_ancestor() {}
+            pub(in crate::d) fn missing_module() {}
+            pub(other) fn unsupported() {}
+        "},
+    );
+    assert_eq!(
+        get_crate_semantic_diagnostics(db, crate_id).format(db),
+        indoc! {"
+            error: Item `test::a::b::super_visible` is not visible in this context.
+             --> src/lib.cairo:5:11
+                a::b::super_visible();
+                      ^***********^
+
+            error: Item `test::a::b::in_a_visible` is not visible in this context.
+             --> src/lib.cairo:6:11
+                a::b::in_a_visible();
+                      ^**********^
+
+            error: Item `test::a::b::self_visible` is not visible in this context.
+             --> src/a.cairo:6:8
+                b::self_visible();
+                   ^**********^
+
+            error: `pub(in crate::c)` must refer to an ancestor module.
+             --> src/a/b.cairo:5:8
+            pub(in crate::c) fn not_ancestor() {}
+                   ^******^
+
+            error: Identifier not found.
+             --> src/a/b.cairo:6:15
+            pub(in crate::d) fn missing_module() {}
+                          ^
+
+            error: Unsupported `pub` argument.
+             --> src/a/b.cairo:7:4
+            pub(other) fn unsupported() {}
+               ^*****^
+
+        "}
+    );
+}
+
+#[test]
+fn test_glob_import_visibility() {
+    let mut db_val = SemanticDatabaseForTesting::new_empty();
+    let db = &mut db_val;
+
+    let crate_id = db.intern_crate(CrateLongId::Real("test".into()));
+    let root = Directory::Real("src".into());
+    let mut config = CrateConfiguration::default_for_root(root);
+    config.settings.edition = Edition::V2023_11;
+    db.set_crate_config(crate_id, Some(config));
+
+    set_file_content(
+        db,
+        "src/lib.cairo",
+        indoc! {"
+            mod a;
+            use a::*;
+            fn use_from_root() {
+                public_fn();
+                private_fn();
+            }
+            mod inner {
+                use super::*;
+                fn use_from_inner() {
+                    use_from_root();
+                    public_fn();
+                }
+            }
+        "},
+    );
+    set_file_content(
+        db,
+        "src/a.cairo",
+        indoc! {"
+            pub fn public_fn() {}
+            fn private_fn() {}
+        "},
+    );
+    assert_eq!(
+        get_crate_semantic_diagnostics(db, crate_id).format(db),
+        indoc! {"
+            error: Function not found.
+             --> src/lib.cairo:5:5
+                private_fn();
+                ^********^
+
+        "}
+    );
+}
+
 #[test]
 fn test_resolve_path_trait_impl() {
     let db_val = SemanticDatabaseForTesting::default();
@@ -1,9 +1,10 @@
 use std::collections::{HashMap, VecDeque};
-use std::ops::Deref;
+use std::ops::{Deref, DerefMut};
 
 use cairo_lang_defs::ids::{
     EnumId, ExternFunctionId, ExternTypeId, FreeFunctionId, GenericParamId, ImplAliasId, ImplDefId,
-    ImplFunctionId, LocalVarId, MemberId, ParamId, StructId, TraitFunctionId, TraitId, VariantId,
+    ImplFunctionId, LocalVarId, MemberId, ParamId, StructId, TraitConstantId, TraitFunctionId,
+    TraitId, TraitTypeId, VariantId,
 };
 use cairo_lang_diagnostics::{DiagnosticAdded, Maybe};
 use cairo_lang_utils::extract_matches;
@@ -19,10 +20,12 @@ use crate::items::functions::{
     GenericFunctionWithBodyId, ImplGenericFunctionId, ImplGenericFunctionWithBodyId,
 };
 use crate::items::generics::{GenericParamConst, GenericParamImpl, GenericParamType};
-use crate::items::imp::{ImplId, UninferredImpl};
+use crate::items::imp::{reduce_impl_type, ImplConstantId, ImplId, ImplTypeId, UninferredImpl};
 use crate::items::trt::{ConcreteTraitGenericFunctionId, ConcreteTraitGenericFunctionLongId};
 use crate::literals::LiteralId;
-use crate::types::{ConcreteEnumLongId, ConcreteExternTypeLongId, ConcreteStructLongId};
+use crate::types::{
+    ClosureTypeLongId, ConcreteEnumLongId, ConcreteExternTypeLongId, ConcreteStructLongId,
+};
 use crate::{
     ConcreteEnumId, ConcreteExternTypeId, ConcreteFunction, ConcreteImplId, ConcreteImplLongId,
     ConcreteStructId, ConcreteTraitId, ConcreteTraitLongId, ConcreteTypeId, ConcreteVariant,
@@ -30,19 +33,40 @@ use crate::{
     MatchArmSelector, Parameter, Signature, TypeId, TypeLongId, ValueSelectorArm, VarId,
 };
 
-/// A substitution of generic arguments in generic parameters. Used for concretization.
+/// A substitution of generic arguments in generic parameters, and optionally of the `Self` impl of
+/// a trait. Used for concretization.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
-pub struct GenericSubstitution(pub OrderedHashMap<GenericParamId, GenericArgumentId>);
+pub struct GenericSubstitution {
+    param_to_arg: OrderedHashMap<GenericParamId, GenericArgumentId>,
+    /// The impl to substitute [ImplId::SelfImpl] with, if any.
+    pub self_impl: Option<ImplId>,
+}
 impl GenericSubstitution {
     pub fn new(generic_params: &[GenericParam], generic_args: &[GenericArgumentId]) -> Self {
-        GenericSubstitution(
-            zip_eq(generic_params.iter().map(|param| param.id()), generic_args.iter().copied())
-                .collect(),
-        )
+        GenericSubstitution {
+            param_to_arg: zip_eq(
+                generic_params.iter().map(|param| param.id()),
+                generic_args.iter().copied(),
+            )
+            .collect(),
+            self_impl: None,
+        }
+    }
+    /// Returns a substitution that substitutes only the `Self` impl of a trait.
+    pub fn from_self_impl(self_impl: ImplId) -> Self {
+        GenericSubstitution { param_to_arg: Default::default(), self_impl: Some(self_impl) }
+    }
+    /// Sets the impl to substitute the `Self` impl of a trait with.
+    pub fn with_self_impl(mut self, self_impl: ImplId) -> Self {
+        self.self_impl = Some(self_impl);
+        self
     }
     pub fn concat(mut self, other: GenericSubstitution) -> Self {
-        for (key, value) in other.0.into_iter() {
-            self.0.insert(key, value);
+        for (key, value) in other.param_to_arg.into_iter() {
+            self.param_to_arg.insert(key, value);
+        }
+        if other.self_impl.is_some() {
+            self.self_impl = other.self_impl;
         }
         self
     }
@@ -51,13 +75,19 @@ impl Deref for GenericSubstitution {
     type Target = OrderedHashMap<GenericParamId, GenericArgumentId>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.param_to_arg
+    }
+}
+impl DerefMut for GenericSubstitution {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.param_to_arg
     }
 }
 #[allow(clippy::derived_hash_with_manual_eq)]
 impl std::hash::Hash for GenericSubstitution {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        self.0.iter().collect_vec().hash(state);
+        self.param_to_arg.iter().collect_vec().hash(state);
+        self.self_impl.hash(state);
     }
 }
 
@@ -192,6 +222,8 @@ macro_rules! add_basic_rewrites {
         $crate::prune_single!(__identity_helper, ImplAliasId, $($exclude)*);
         $crate::prune_single!(__identity_helper, TraitId, $($exclude)*);
         $crate::prune_single!(__identity_helper, TraitFunctionId, $($exclude)*);
+        $crate::prune_single!(__identity_helper, TraitTypeId, $($exclude)*);
+        $crate::prune_single!(__identity_helper, TraitConstantId, $($exclude)*);
         $crate::prune_single!(__identity_helper, VariantId, $($exclude)*);
         $crate::prune_single!(__identity_helper, ImplFunctionId, $($exclude)*);
         $crate::prune_single!(__identity_helper, EnumId, $($exclude)*);
@@ -225,6 +257,7 @@ macro_rules! add_basic_rewrites {
         $crate::prune_single!(__regular_helper, FunctionLongId, $($exclude)*);
         $crate::prune_single!(__regular_helper, TypeId, $($exclude)*);
         $crate::prune_single!(__regular_helper, TypeLongId, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ClosureTypeLongId, $($exclude)*);
         $crate::prune_single!(__regular_helper, ConcreteVariant, $($exclude)*);
         $crate::prune_single!(__regular_helper, ValueSelectorArm, $($exclude)*);
         $crate::prune_single!(__regular_helper, MatchArmSelector, $($exclude)*);
@@ -242,6 +275,8 @@ macro_rules! add_basic_rewrites {
         $crate::prune_single!(__regular_helper, ConcreteTraitGenericFunctionLongId, $($exclude)*);
         $crate::prune_single!(__regular_helper, ConcreteTraitGenericFunctionId, $($exclude)*);
         $crate::prune_single!(__regular_helper, ImplId, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ImplTypeId, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ImplConstantId, $($exclude)*);
         $crate::prune_single!(__regular_helper, UninferredImpl, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprVarMemberPath, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprVar, $($exclude)*);
@@ -276,6 +311,8 @@ macro_rules! add_expr_rewrites {
         $crate::prune_single!(__regular_helper, Condition, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprLoop, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprWhile, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ExprFor, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ExprClosure, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprLiteral, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprStringLiteral, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprMemberAccess, $($exclude)*);
@@ -283,6 +320,7 @@ macro_rules! add_expr_rewrites {
         $crate::prune_single!(__regular_helper, ExprEnumVariantCtor, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprPropagateError, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprConstant, $($exclude)*);
+        $crate::prune_single!(__regular_helper, ExprImplConstant, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprMissing, $($exclude)*);
         $crate::prune_single!(__regular_helper, ExprFunctionCallArg, $($exclude)*);
         $crate::prune_single!(__regular_helper, MatchArm, $($exclude)*);
@@ -326,7 +364,17 @@ impl<'a> SemanticRewriter<TypeLongId, DiagnosticAdded> for SubstitutionRewriter<
                 return Ok(self.db.lookup_intern_type(type_id));
             }
         }
-        value.default_rewrite(self)
+        let value = value.default_rewrite(self)?;
+        // An impl type of a concrete impl is reduced to the type defined by the impl.
+        if let TypeLongId::ImplType(impl_type_id) = value {
+            if let ImplId::Concrete(_) = impl_type_id.impl_id() {
+                return Ok(match reduce_impl_type(self.db, impl_type_id) {
+                    Ok(ty) => self.db.lookup_intern_type(ty),
+                    Err(diag_added) => TypeLongId::Missing(diag_added),
+                });
+            }
+        }
+        Ok(value)
     }
 }
 impl<'a> SemanticRewriter<ImplId, DiagnosticAdded> for SubstitutionRewriter<'a> {
@@ -339,6 +387,11 @@ impl<'a> SemanticRewriter<ImplId, DiagnosticAdded> for SubstitutionRewriter<'a>
                 return Ok(impl_id);
             }
         }
+        if let ImplId::SelfImpl(_) = value {
+            if let Some(self_impl) = self.substitution.self_impl {
+                return Ok(self_impl);
+            }
+        }
         value.default_rewrite(self)
     }
 }
@@ -135,6 +135,7 @@ pub fn setup_test_crate(db: &dyn SemanticGroup, content: &str) -> CrateId {
                 edition: Edition::default(),
                 experimental_features: ExperimentalFeaturesConfig { negative_impls: true },
                 cfg_set: Default::default(),
+                lints: Default::default(),
             },
         },
     })
@@ -1,4 +1,5 @@
 use cairo_lang_debug::DebugWithDb;
+use cairo_lang_defs::diagnostic_utils::StableLocation;
 use cairo_lang_defs::ids::{EnumId, ExternTypeId, GenericParamId, GenericTypeId, StructId};
 use cairo_lang_diagnostics::{DiagnosticAdded, Maybe};
 use cairo_lang_proc_macros::SemanticObject;
@@ -19,10 +20,10 @@ use crate::diagnostic::{NotFoundItemType, SemanticDiagnostics};
 use crate::expr::inference::canonic::ResultNoErrEx;
 use crate::expr::inference::{InferenceData, InferenceId, InferenceResult, TypeVar};
 use crate::items::attribute::SemanticQueryAttrs;
-use crate::items::imp::{ImplId, ImplLookupContext};
+use crate::items::imp::{ImplId, ImplLookupContext, ImplTypeId};
 use crate::resolve::{ResolvedConcreteItem, Resolver};
 use crate::substitution::SemanticRewriter;
-use crate::{semantic, semantic_object_for_id, ConcreteTraitId};
+use crate::{semantic, semantic_object_for_id, ConcreteTraitId, FunctionId};
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
 pub enum TypeLongId {
@@ -33,6 +34,10 @@ pub enum TypeLongId {
     Snapshot(TypeId),
     GenericParameter(GenericParamId),
     Var(TypeVar),
+    Closure(ClosureTypeLongId),
+    /// An associated type of an impl that is not yet known to be concrete, e.g. `T::State` for a
+    /// generic impl `T`.
+    ImplType(ImplTypeId),
     Missing(#[dont_rewrite] DiagnosticAdded),
 }
 impl OptionFrom<TypeLongId> for ConcreteTypeId {
@@ -84,6 +89,12 @@ impl TypeId {
             TypeLongId::Snapshot(ty) => ty.is_fully_concrete(db),
             TypeLongId::GenericParameter(_) => false,
             TypeLongId::Var(_) => false,
+            TypeLongId::Closure(closure) => {
+                closure.param_tys.iter().all(|ty| ty.is_fully_concrete(db))
+                    && closure.ret_ty.is_fully_concrete(db)
+                    && closure.captured_types.iter().all(|ty| ty.is_fully_concrete(db))
+            }
+            TypeLongId::ImplType(_) => false,
             TypeLongId::Missing(_) => false,
         }
     }
@@ -104,6 +115,8 @@ impl TypeLongId {
                 format!("{}", generic_param.name(db.upcast()).unwrap_or_else(|| "_".into()))
             }
             TypeLongId::Var(var) => format!("?{}", var.id.0),
+            TypeLongId::Closure(closure) => closure.format(db),
+            TypeLongId::ImplType(impl_type_id) => impl_type_id.format(db),
             TypeLongId::Missing(_) => "<missing>".to_string(),
         }
     }
@@ -114,7 +127,11 @@ impl TypeLongId {
             TypeLongId::Concrete(concrete) => TypeHead::Concrete(concrete.generic_type(db)),
             TypeLongId::Tuple(_) => TypeHead::Tuple,
             TypeLongId::Snapshot(inner) => TypeHead::Snapshot(Box::new(inner.head(db)?)),
-            TypeLongId::GenericParameter(_) | TypeLongId::Var(_) | TypeLongId::Missing(_) => {
+            TypeLongId::GenericParameter(_)
+            | TypeLongId::Var(_)
+            | TypeLongId::Closure(_)
+            | TypeLongId::ImplType(_)
+            | TypeLongId::Missing(_) => {
                 return None;
             }
         })
@@ -130,6 +147,40 @@ impl DebugWithDb<dyn SemanticGroup> for TypeLongId {
     }
 }
 
+/// The type of a closure expression. Each closure expression has its own unique type, identified by
+/// the closure's location and the function it is defined in.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, SemanticObject)]
+pub struct ClosureTypeLongId {
+    pub param_tys: Vec<TypeId>,
+    pub ret_ty: TypeId,
+    /// The types of the variables captured by the closure, in capture order.
+    pub captured_types: Vec<TypeId>,
+    /// The function in which the closure is defined.
+    pub parent_function: FunctionId,
+    #[dont_rewrite]
+    pub stable_ptr: ast::ExprClosurePtr,
+}
+impl ClosureTypeLongId {
+    pub fn format(&self, db: &dyn SemanticGroup) -> String {
+        let location = StableLocation::new(self.stable_ptr.untyped());
+        let file_id = location.file_id(db.upcast());
+        let position = location
+            .diagnostic_location(db.upcast())
+            .span
+            .start
+            .position_in_file(db.upcast(), file_id);
+        match position {
+            Some(position) => format!(
+                "{{closure@{}:{}:{}}}",
+                file_id.file_name(db.upcast()),
+                position.line + 1,
+                position.col + 1
+            ),
+            None => "{closure}".into(),
+        }
+    }
+}
+
 /// Head of a type. A non-param non-variable type has a head, which represents the kind of the root
 /// node in its type tree. This is used for caching queries for fast lookups when the type is not
 /// completely inferred yet.
@@ -451,6 +502,8 @@ pub fn single_value_type(db: &dyn SemanticGroup, ty: TypeId) -> Maybe<bool> {
         semantic::TypeLongId::Snapshot(ty) => db.single_value_type(ty)?,
         semantic::TypeLongId::GenericParameter(_) => false,
         semantic::TypeLongId::Var(_) => false,
+        semantic::TypeLongId::Closure(_) => false,
+        semantic::TypeLongId::ImplType(_) => false,
         semantic::TypeLongId::Missing(_) => false,
     })
 }
@@ -286,6 +286,9 @@ pub fn generate_statement_code(
         lowering::Statement::Literal(statement_literal) => {
             generate_statement_literal_code(context, statement_literal)
         }
+        lowering::Statement::ImplConstant(_) => {
+            panic!("Impl constants should be concretized at this point.")
+        }
         lowering::Statement::Call(statement_call) => {
             generate_statement_call_code(context, statement_call, statement_location)
         }
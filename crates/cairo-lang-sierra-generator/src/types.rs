@@ -116,6 +116,9 @@ pub fn get_concrete_long_type_id(
             }
         }
         semantic::TypeLongId::Tuple(_) => user_type_long_id("Struct", "Tuple".into())?.into(),
+        semantic::TypeLongId::Closure(closure) => {
+            user_type_long_id("Struct", closure.format(db.upcast()).into())?.into()
+        }
         semantic::TypeLongId::Snapshot(ty) => {
             let inner_ty = db.get_concrete_type_id(ty).unwrap();
             let ty =
@@ -132,6 +135,7 @@ pub fn get_concrete_long_type_id(
         }
         semantic::TypeLongId::GenericParameter(_)
         | semantic::TypeLongId::Var(_)
+        | semantic::TypeLongId::ImplType(_)
         | semantic::TypeLongId::Missing(_) => {
             panic!(
                 "Types should be fully resolved at this point. Got: `{}`.",
@@ -181,8 +185,10 @@ pub fn type_dependencies(
         },
         semantic::TypeLongId::Tuple(inner_types) => inner_types,
         semantic::TypeLongId::Snapshot(ty) => vec![ty],
+        semantic::TypeLongId::Closure(closure) => closure.captured_types,
         semantic::TypeLongId::GenericParameter(_)
         | semantic::TypeLongId::Var(_)
+        | semantic::TypeLongId::ImplType(_)
         | semantic::TypeLongId::Missing(_) => {
             panic!(
                 "Types should be fully resolved at this point. Got: `{}`.",
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct Contract {
-    items: OrderedHashSet<Item>,
+    pub(crate) items: OrderedHashSet<Item>,
 }
 impl Contract {
     pub fn from_items(items: OrderedHashSet<Item>) -> Self {
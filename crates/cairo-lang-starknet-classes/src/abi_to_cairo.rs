@@ -0,0 +1,307 @@
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use convert_case::{Case, Casing};
+use itertools::Itertools;
+use thiserror::Error;
+
+use crate::abi::{
+    Contract, EnumVariant, EventField, EventFieldKind, EventKind, Function, Item, StateMutability,
+    StructMember,
+};
+
+#[cfg(test)]
+#[path = "abi_to_cairo_test.rs"]
+mod test;
+
+/// The prefix of the paths of corelib types, which are used by their full path in the generated
+/// code.
+const CORELIB_PREFIX: &str = "core::";
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum AbiToCairoError {
+    #[error("Generic type `{0}` is not supported.")]
+    GenericType(String),
+    #[error("Type `{0}` is not defined in the ABI.")]
+    UndefinedType(String),
+    #[error("Field `{field}` of event `{event}` is of an unsupported kind `{kind:?}`.")]
+    UnsupportedEventField { event: String, field: String, kind: EventFieldKind },
+}
+
+/// Generates Cairo code declaring the interfaces of a contract from its ABI, together with the
+/// types and the events they use, so that the contract can be called through the dispatchers
+/// generated for the interfaces.
+///
+/// The interfaces of the ABI are declared as `#[starknet::interface]` traits, and the external
+/// functions that are not part of an interface are declared in a trait named
+/// `contract_interface_name`. Constructors and L1 handlers are not declared, as they are not called
+/// through dispatchers.
+/// The declared types and interfaces are named by the last segment of their path, prefixed by the
+/// segments before it when required to make their names unique. Corelib types are used by their
+/// full path.
+pub fn abi_to_cairo(
+    abi: &Contract,
+    contract_interface_name: &str,
+) -> Result<String, AbiToCairoError> {
+    let mut types = OrderedHashMap::<&str, TypeDeclaration<'_>>::default();
+    let mut interfaces = vec![];
+    let mut contract_functions = vec![];
+    for item in &abi.items {
+        match item {
+            Item::Struct(item) if !item.name.starts_with(CORELIB_PREFIX) => {
+                types.entry(&item.name).or_default().body = Some(TypeBody::Struct(&item.members));
+            }
+            Item::Enum(item) if !item.name.starts_with(CORELIB_PREFIX) => {
+                types.entry(&item.name).or_default().body = Some(TypeBody::Enum(&item.variants));
+            }
+            Item::Event(item) => types.entry(&item.name).or_default().event = Some(&item.kind),
+            Item::Interface(item) => interfaces.push(item),
+            Item::Function(item) => contract_functions.push(item),
+            Item::Struct(_)
+            | Item::Enum(_)
+            | Item::Constructor(_)
+            | Item::L1Handler(_)
+            | Item::Impl(_) => {}
+        }
+    }
+    if let Some(generic_type) = types.keys().find(|path| path.contains('<')) {
+        return Err(AbiToCairoError::GenericType(generic_type.to_string()));
+    }
+
+    let names = declaration_names(
+        types.keys().copied().chain(interfaces.iter().map(|interface| interface.name.as_str())),
+    );
+    let generator = Generator { names: &names };
+    let mut declarations = vec![];
+    for (path, declaration) in types.iter() {
+        declarations.push(generator.type_declaration(path, declaration)?);
+    }
+    for interface in interfaces {
+        declarations.push(generator.interface_declaration(
+            &names[interface.name.as_str()],
+            interface.items.iter().filter_map(|item| match item {
+                Item::Function(function) => Some(function),
+                _ => None,
+            }),
+        )?);
+    }
+    if !contract_functions.is_empty() {
+        declarations
+            .push(generator.interface_declaration(contract_interface_name, contract_functions)?);
+    }
+    Ok(declarations.into_iter().map(|declaration| declaration + "\n").join("\n"))
+}
+
+/// A type declared by the ABI - as a struct or an enum used by the functions, as an event, or as
+/// both.
+#[derive(Default)]
+struct TypeDeclaration<'a> {
+    body: Option<TypeBody<'a>>,
+    event: Option<&'a EventKind>,
+}
+
+/// The body of a struct or an enum declared by the ABI.
+enum TypeBody<'a> {
+    Struct(&'a [StructMember]),
+    Enum(&'a [EnumVariant]),
+}
+
+/// Returns the names of the declarations of the given paths - the last segment of each path,
+/// prefixed by the minimal number of preceding segments (in `PascalCase`) that makes it unique.
+fn declaration_names<'a>(paths: impl Iterator<Item = &'a str>) -> OrderedHashMap<&'a str, String> {
+    let paths = paths.collect_vec();
+    let segments = paths.iter().map(|path| path.split("::").collect_vec()).collect_vec();
+    let name = |segments: &[&str], suffix_len: usize| {
+        let (last, prefix) = segments.split_last().unwrap();
+        let prefix = &prefix[prefix.len() + 1 - suffix_len..];
+        prefix.iter().map(|segment| segment.to_case(Case::Pascal)).join("") + last
+    };
+    let mut suffix_lens = vec![1; paths.len()];
+    loop {
+        let names = segments
+            .iter()
+            .zip(&suffix_lens)
+            .map(|(segments, suffix_len)| name(segments, *suffix_len))
+            .collect_vec();
+        let mut extended = false;
+        for (i, suffix_len) in suffix_lens.iter_mut().enumerate() {
+            let is_duplicate = names.iter().filter(|other| **other == names[i]).count() > 1;
+            if is_duplicate && *suffix_len < segments[i].len() {
+                *suffix_len += 1;
+                extended = true;
+            }
+        }
+        if !extended {
+            return paths.into_iter().zip(names).collect();
+        }
+    }
+}
+
+/// Generates the Cairo declarations of the items of an ABI.
+struct Generator<'a> {
+    /// The names of the declared types and interfaces, by their paths.
+    names: &'a OrderedHashMap<&'a str, String>,
+}
+impl Generator<'_> {
+    /// Returns the declaration of a struct or an enum, deriving `Serde` if it is used by the
+    /// functions and `starknet::Event` if it is an event.
+    fn type_declaration(
+        &self,
+        path: &str,
+        declaration: &TypeDeclaration<'_>,
+    ) -> Result<String, AbiToCairoError> {
+        let mut derives = vec!["Drop"];
+        if declaration.body.is_some() {
+            derives.push("Serde");
+        }
+        let fields = match (declaration.event, &declaration.body) {
+            (Some(event), _) => {
+                derives.push("starknet::Event");
+                match event {
+                    EventKind::Struct { members } => {
+                        TypeFields::Struct(self.event_fields(path, members, true)?)
+                    }
+                    EventKind::Enum { variants } => {
+                        TypeFields::Enum(self.event_fields(path, variants, false)?)
+                    }
+                }
+            }
+            (None, Some(TypeBody::Struct(members))) => TypeFields::Struct(
+                members
+                    .iter()
+                    .map(|member| Ok(("", member.name.as_str(), self.cairo_type(&member.ty)?)))
+                    .collect::<Result<_, _>>()?,
+            ),
+            (None, Some(TypeBody::Enum(variants))) => TypeFields::Enum(
+                variants
+                    .iter()
+                    .map(|variant| Ok(("", variant.name.as_str(), self.cairo_type(&variant.ty)?)))
+                    .collect::<Result<_, _>>()?,
+            ),
+            (None, None) => unreachable!("Declarations are created for a body or an event."),
+        };
+
+        let name = &self.names[path];
+        let derives = derives.join(", ");
+        let (keyword, fields) = match fields {
+            TypeFields::Struct(members) => (
+                "struct",
+                members
+                    .into_iter()
+                    .map(|(attr, name, ty)| format!("\n    {attr}pub {name}: {ty},"))
+                    .join(""),
+            ),
+            TypeFields::Enum(variants) => (
+                "enum",
+                variants
+                    .into_iter()
+                    .map(|(attr, name, ty)| match ty.as_str() {
+                        "()" => format!("\n    {attr}{name},"),
+                        _ => format!("\n    {attr}{name}: {ty},"),
+                    })
+                    .join(""),
+            ),
+        };
+        let body = if fields.is_empty() { "{}".into() } else { format!("{{{fields}\n}}") };
+        Ok(format!("#[derive({derives})]\npub {keyword} {name} {body}"))
+    }
+
+    /// Returns the attribute, the name and the type of each of the fields of an event - the members
+    /// of a struct event if `is_struct`, and the variants of an enum event otherwise.
+    fn event_fields<'a>(
+        &self,
+        event: &str,
+        fields: &'a [EventField],
+        is_struct: bool,
+    ) -> Result<Vec<(&'static str, &'a str, String)>, AbiToCairoError> {
+        fields
+            .iter()
+            .map(|field| {
+                let attr = match (field.kind, is_struct) {
+                    (EventFieldKind::KeySerde, _) => "#[key]\n    ",
+                    (EventFieldKind::DataSerde, true) | (EventFieldKind::Nested, false) => "",
+                    (EventFieldKind::Flat, false) => "#[flat]\n    ",
+                    (kind, _) => {
+                        return Err(AbiToCairoError::UnsupportedEventField {
+                            event: event.into(),
+                            field: field.name.clone(),
+                            kind,
+                        });
+                    }
+                };
+                Ok((attr, field.name.as_str(), self.cairo_type(&field.ty)?))
+            })
+            .collect()
+    }
+
+    /// Returns the declaration of an interface trait with the given functions.
+    fn interface_declaration<'a>(
+        &self,
+        name: &str,
+        functions: impl IntoIterator<Item = &'a Function>,
+    ) -> Result<String, AbiToCairoError> {
+        let mut signatures = vec![];
+        for function in functions {
+            let self_param = match function.state_mutability {
+                StateMutability::External => "ref self: TContractState",
+                StateMutability::View => "self: @TContractState",
+            };
+            let params = function
+                .inputs
+                .iter()
+                .map(|input| Ok(format!(", {}: {}", input.name, self.cairo_type(&input.ty)?)))
+                .collect::<Result<String, _>>()?;
+            let outputs = function
+                .outputs
+                .iter()
+                .map(|output| self.cairo_type(&output.ty))
+                .collect::<Result<Vec<_>, _>>()?;
+            let return_type = match &outputs[..] {
+                [] => "".into(),
+                [output] => format!(" -> {output}"),
+                _ => format!(" -> ({})", outputs.join(", ")),
+            };
+            signatures
+                .push(format!("\n    fn {}({self_param}{params}){return_type};", function.name));
+        }
+        Ok(format!(
+            "#[starknet::interface]\npub trait {name}<TContractState> {{{}\n}}",
+            signatures.join("")
+        ))
+    }
+
+    /// Returns the Cairo type of an ABI type - with the paths of the declared types replaced by
+    /// their names.
+    fn cairo_type(&self, ty: &str) -> Result<String, AbiToCairoError> {
+        let mut cairo_type = String::new();
+        let mut path = String::new();
+        // A trailing character that is not part of a path flushes the last path.
+        for c in ty.chars().chain(std::iter::once(' ')) {
+            if c.is_ascii_alphanumeric() || c == '_' || c == ':' {
+                path.push(c);
+                continue;
+            }
+            // The path of a generic type is followed by `::<`.
+            let generic_separator = if path.ends_with("::") { "::" } else { "" };
+            let type_path = path.trim_end_matches("::");
+            if type_path.is_empty() || type_path.starts_with(CORELIB_PREFIX) {
+                cairo_type.push_str(type_path);
+            } else {
+                let name = self
+                    .names
+                    .get(type_path)
+                    .ok_or_else(|| AbiToCairoError::UndefinedType(type_path.into()))?;
+                cairo_type.push_str(name);
+            }
+            cairo_type.push_str(generic_separator);
+            cairo_type.push(c);
+            path.clear();
+        }
+        cairo_type.pop();
+        Ok(cairo_type)
+    }
+}
+
+/// The attribute, the name and the type of each of the fields of a type declaration.
+enum TypeFields<'a> {
+    Struct(Vec<(&'static str, &'a str, String)>),
+    Enum(Vec<(&'static str, &'a str, String)>),
+}
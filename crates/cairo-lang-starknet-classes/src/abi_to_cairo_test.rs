@@ -0,0 +1,249 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+use crate::abi::{Contract, EventFieldKind};
+use crate::abi_to_cairo::{abi_to_cairo, AbiToCairoError};
+
+/// Parses an ABI from its JSON representation.
+fn parse_abi(json: &str) -> Contract {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn test_abi_to_cairo() {
+    let abi = parse_abi(indoc! {r#"
+        [
+          {
+            "type": "impl",
+            "name": "BankImpl",
+            "interface_name": "bank::IBank"
+          },
+          {
+            "type": "struct",
+            "name": "core::integer::u256",
+            "members": [
+              {"name": "low", "type": "core::integer::u128"},
+              {"name": "high", "type": "core::integer::u128"}
+            ]
+          },
+          {
+            "type": "struct",
+            "name": "bank::Account",
+            "members": [
+              {"name": "owner", "type": "core::starknet::contract_address::ContractAddress"},
+              {"name": "balance", "type": "core::integer::u256"}
+            ]
+          },
+          {
+            "type": "enum",
+            "name": "bank::Status",
+            "variants": [
+              {"name": "Open", "type": "()"},
+              {"name": "Frozen", "type": "core::felt252"}
+            ]
+          },
+          {
+            "type": "interface",
+            "name": "bank::IBank",
+            "items": [
+              {
+                "type": "function",
+                "name": "get_account",
+                "inputs": [{"name": "id", "type": "core::felt252"}],
+                "outputs": [{"type": "bank::Account"}],
+                "state_mutability": "view"
+              },
+              {
+                "type": "function",
+                "name": "set_accounts",
+                "inputs": [
+                  {"name": "accounts", "type": "core::array::Span::<bank::Account>"},
+                  {"name": "status", "type": "bank::Status"}
+                ],
+                "outputs": [],
+                "state_mutability": "external"
+              }
+            ]
+          },
+          {
+            "type": "function",
+            "name": "stats",
+            "inputs": [],
+            "outputs": [{"type": "(core::integer::u32, @bank::Status)"}],
+            "state_mutability": "view"
+          },
+          {
+            "type": "constructor",
+            "name": "constructor",
+            "inputs": []
+          },
+          {
+            "type": "event",
+            "name": "bank::Opened",
+            "kind": "struct",
+            "members": [
+              {"name": "account", "type": "bank::Account", "kind": "key"},
+              {"name": "at", "type": "core::integer::u64", "kind": "data"}
+            ]
+          },
+          {
+            "type": "event",
+            "name": "bank::Status",
+            "kind": "enum",
+            "variants": [
+              {"name": "Open", "type": "()", "kind": "key"},
+              {"name": "Frozen", "type": "core::felt252", "kind": "key"}
+            ]
+          },
+          {
+            "type": "event",
+            "name": "bank::Event",
+            "kind": "enum",
+            "variants": [
+              {"name": "Opened", "type": "bank::Opened", "kind": "nested"},
+              {"name": "Status", "type": "bank::Status", "kind": "flat"}
+            ]
+          }
+        ]
+    "#});
+    assert_eq!(
+        abi_to_cairo(&abi, "IBankContract").unwrap(),
+        indoc! {"
+            #[derive(Drop, Serde)]
+            pub struct Account {
+                pub owner: core::starknet::contract_address::ContractAddress,
+                pub balance: core::integer::u256,
+            }
+
+            #[derive(Drop, Serde, starknet::Event)]
+            pub enum Status {
+                #[key]
+                Open,
+                #[key]
+                Frozen: core::felt252,
+            }
+
+            #[derive(Drop, starknet::Event)]
+            pub struct Opened {
+                #[key]
+                pub account: Account,
+                pub at: core::integer::u64,
+            }
+
+            #[derive(Drop, starknet::Event)]
+            pub enum Event {
+                Opened: Opened,
+                #[flat]
+                Status: Status,
+            }
+
+            #[starknet::interface]
+            pub trait IBank<TContractState> {
+                fn get_account(self: @TContractState, id: core::felt252) -> Account;
+                fn set_accounts(ref self: TContractState, accounts: core::array::Span::<Account>, status: Status);
+            }
+
+            #[starknet::interface]
+            pub trait IBankContract<TContractState> {
+                fn stats(self: @TContractState) -> (core::integer::u32, @Status);
+            }
+        "}
+    );
+}
+
+#[test]
+fn test_conflicting_names() {
+    let abi = parse_abi(indoc! {r#"
+        [
+          {
+            "type": "event",
+            "name": "token::erc_20::Event",
+            "kind": "enum",
+            "variants": []
+          },
+          {
+            "type": "event",
+            "name": "token::ownable::Event",
+            "kind": "enum",
+            "variants": []
+          },
+          {
+            "type": "event",
+            "name": "token::Event",
+            "kind": "enum",
+            "variants": [
+              {"name": "Erc20", "type": "token::erc_20::Event", "kind": "nested"},
+              {"name": "Ownable", "type": "token::ownable::Event", "kind": "nested"}
+            ]
+          }
+        ]
+    "#});
+    assert_eq!(
+        abi_to_cairo(&abi, "IToken").unwrap(),
+        indoc! {"
+            #[derive(Drop, starknet::Event)]
+            pub enum Erc20Event {}
+
+            #[derive(Drop, starknet::Event)]
+            pub enum OwnableEvent {}
+
+            #[derive(Drop, starknet::Event)]
+            pub enum TokenEvent {
+                Erc20: Erc20Event,
+                Ownable: OwnableEvent,
+            }
+        "}
+    );
+}
+
+#[test]
+fn test_unsupported_abis() {
+    let undefined_type = parse_abi(indoc! {r#"
+        [
+          {
+            "type": "function",
+            "name": "get",
+            "inputs": [],
+            "outputs": [{"type": "test::Missing"}],
+            "state_mutability": "view"
+          }
+        ]
+    "#});
+    assert_eq!(
+        abi_to_cairo(&undefined_type, "IContract"),
+        Err(AbiToCairoError::UndefinedType("test::Missing".into()))
+    );
+
+    let generic_type = parse_abi(indoc! {r#"
+        [
+          {
+            "type": "struct",
+            "name": "test::Wrapper::<core::felt252>",
+            "members": [{"name": "value", "type": "core::felt252"}]
+          }
+        ]
+    "#});
+    assert_eq!(
+        abi_to_cairo(&generic_type, "IContract"),
+        Err(AbiToCairoError::GenericType("test::Wrapper::<core::felt252>".into()))
+    );
+
+    let data_variant = parse_abi(indoc! {r#"
+        [
+          {
+            "type": "event",
+            "name": "test::Event",
+            "kind": "enum",
+            "variants": [{"name": "Value", "type": "core::felt252", "kind": "data"}]
+          }
+        ]
+    "#});
+    assert_eq!(
+        abi_to_cairo(&data_variant, "IContract"),
+        Err(AbiToCairoError::UnsupportedEventField {
+            event: "test::Event".into(),
+            field: "Value".into(),
+            kind: EventFieldKind::DataSerde,
+        })
+    );
+}
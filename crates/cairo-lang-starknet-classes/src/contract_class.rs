@@ -1,13 +1,17 @@
+use cairo_felt::Felt252;
 use cairo_lang_sierra as sierra;
 use cairo_lang_utils::bigint::{deserialize_big_uint, serialize_big_uint, BigUintAsHex};
+use itertools::Itertools;
 use num_bigint::BigUint;
 use serde::{Deserialize, Serialize};
+use starknet_crypto::{poseidon_hash_many, FieldElement};
 use thiserror::Error;
 
 use crate::abi::Contract;
 use crate::allowed_libfuncs::{lookup_allowed_libfuncs_list, AllowedLibfuncsError, ListSelector};
 use crate::compiler_version::{current_compiler_version_id, current_sierra_version_id};
 use crate::felt252_serde::{sierra_from_felt252s, sierra_to_felt252s, Felt252SerdeError};
+use crate::keccak::starknet_keccak;
 
 #[cfg(test)]
 #[path = "contract_class_test.rs"]
@@ -60,6 +64,41 @@ impl ContractClass {
         Ok(sierra_program)
     }
 
+    /// Returns the hash value of the contract class, which identifies the class when declared on
+    /// the network.
+    ///
+    /// The ABI is hashed as the string it is declared as, which declare tooling produces with
+    /// Python's `json.dumps` defaults.
+    pub fn class_hash(&self) -> Felt252 {
+        let abi = match &self.abi {
+            Some(abi) => to_python_json_string(abi),
+            None => String::new(),
+        };
+        let sierra_program_hash = poseidon_hash_many(
+            &self
+                .sierra_program
+                .iter()
+                .map(|felt| big_uint_to_field_element(&felt.value))
+                .collect_vec(),
+        );
+
+        // Compute total hash by hashing each component on top of the previous one.
+        Felt252::from_bytes_be(
+            &poseidon_hash_many(&[
+                FieldElement::from_byte_slice_be(
+                    format!("CONTRACT_CLASS_V{}", self.contract_class_version).as_bytes(),
+                )
+                .unwrap(),
+                entry_points_hash(&self.entry_points_by_type.external),
+                entry_points_hash(&self.entry_points_by_type.l1_handler),
+                entry_points_hash(&self.entry_points_by_type.constructor),
+                big_uint_to_field_element(&starknet_keccak(abi.as_bytes())),
+                sierra_program_hash,
+            ])
+            .to_bytes_be(),
+        )
+    }
+
     /// Sanity checks the contract class.
     /// Currently only checks that if ABI exists, its counts match the entry points counts.
     pub fn sanity_check(&self) {
@@ -96,6 +135,80 @@ impl ContractClass {
 
 const DEFAULT_CONTRACT_CLASS_VERSION: &str = "0.1.0";
 
+/// Returns the hash for a set of entry points.
+fn entry_points_hash(entry_points: &[ContractEntryPoint]) -> FieldElement {
+    poseidon_hash_many(
+        &entry_points
+            .iter()
+            .flat_map(|entry_point| {
+                [
+                    big_uint_to_field_element(&entry_point.selector),
+                    FieldElement::from(entry_point.function_idx),
+                ]
+            })
+            .collect_vec(),
+    )
+}
+
+/// Serializes a value to JSON like Python's `json.dumps` does by default: with `", "` and `": "`
+/// separators, and with the characters outside of printable ASCII escaped.
+fn to_python_json_string<T: Serialize>(value: &T) -> String {
+    let mut writer = vec![];
+    value
+        .serialize(&mut serde_json::Serializer::with_formatter(&mut writer, PythonJsonFormatter))
+        .expect("Failed to serialize to JSON.");
+    String::from_utf8(writer).expect("JSON serialization produced invalid UTF-8.")
+}
+
+/// A JSON formatter matching the output of Python's `json.dumps` with its default arguments.
+struct PythonJsonFormatter;
+impl serde_json::ser::Formatter for PythonJsonFormatter {
+    fn begin_array_value<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        if first { Ok(()) } else { writer.write_all(b", ") }
+    }
+
+    fn begin_object_key<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        if first { Ok(()) } else { writer.write_all(b", ") }
+    }
+
+    fn begin_object_value<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        writer.write_all(b": ")
+    }
+
+    fn write_string_fragment<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        fragment: &str,
+    ) -> std::io::Result<()> {
+        for c in fragment.chars() {
+            if (' '..='~').contains(&c) {
+                writer.write_all(&[c as u8])?;
+            } else {
+                let mut code_units = [0; 2];
+                for code_unit in c.encode_utf16(&mut code_units) {
+                    write!(writer, "\\u{code_unit:04x}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn big_uint_to_field_element(value: &BigUint) -> FieldElement {
+    FieldElement::from_byte_slice_be(&value.to_bytes_be()).unwrap()
+}
+
 #[derive(Clone, Default, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ContractEntryPoints {
     #[serde(rename = "EXTERNAL")]
@@ -6,7 +6,8 @@ use pretty_assertions::assert_eq;
 use test_case::test_case;
 
 use crate::contract_class::{
-    ContractClass, ContractEntryPoint, ContractEntryPoints, DEFAULT_CONTRACT_CLASS_VERSION,
+    to_python_json_string, ContractClass, ContractEntryPoint, ContractEntryPoints,
+    DEFAULT_CONTRACT_CLASS_VERSION,
 };
 use crate::test_utils::get_example_file_path;
 
@@ -72,3 +73,39 @@ fn test_full_contract_deserialization_from_contracts_crate(name: &str) {
     let serialized = serde_json::to_value(&contract).unwrap();
     assert_eq!(serialized, deserialized);
 }
+
+// Tests the class hash computation of a contract.
+// The expected hashes were computed independently of this crate, from the ABI string produced by
+// Python's `json.dumps` and reference Keccak and Poseidon implementations.
+#[test_case(
+    "minimal_contract__minimal_contract",
+    "0x55fedf977713c5eb0f0d17dd41c23547219fad4625947b9a03d56b127c186ae"
+)]
+#[test_case(
+    "test_contract__test_contract",
+    "0x5913665199bf544cccaf55a0bc926eb9b335540ba62ca4f292e41a192a672f3"
+)]
+#[test_case(
+    "hello_starknet__hello_starknet",
+    "0x372cf99d731363dcca9909ca3ccd84ab79cd98dc7e8b4aa9e37566ec6d4976a"
+)]
+fn test_class_hash(name: &str, expected_class_hash: &str) {
+    let contract_path = get_example_file_path(&format!("{name}.contract_class.json"));
+    let contract: ContractClass =
+        serde_json::from_reader(BufReader::new(std::fs::File::open(contract_path).unwrap()))
+            .unwrap();
+    assert_eq!(format!("{:#x}", contract.class_hash().to_biguint()), expected_class_hash);
+}
+
+#[test]
+fn test_python_json_string() {
+    let value = serde_json::json!({
+        "name": "caf\u{e9} \u{1f600}",
+        "items": [1, {"quote": "\"\\\n\u{7f}"}, []],
+        "empty": {},
+    });
+    assert_eq!(
+        to_python_json_string(&value),
+        r#"{"empty": {}, "items": [1, {"quote": "\"\\\n\u007f"}, []], "name": "caf\u00e9 \ud83d\ude00"}"#
+    );
+}
@@ -6,6 +6,7 @@
 //! Learn more at [starkware.io](http://starknet.io/).
 
 pub mod abi;
+pub mod abi_to_cairo;
 pub mod allowed_libfuncs;
 pub mod casm_contract_class;
 pub mod compiler_version;
@@ -13,6 +14,7 @@ pub mod contract_class;
 mod felt252_serde;
 mod felt252_vec_compression;
 pub mod keccak;
+pub mod storage_layout;
 
 #[cfg(test)]
 mod test_utils;
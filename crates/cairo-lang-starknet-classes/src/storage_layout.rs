@@ -0,0 +1,172 @@
+use std::collections::HashSet;
+
+use cairo_lang_utils::bigint::BigUintAsHex;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[cfg(test)]
+#[path = "storage_layout_test.rs"]
+mod test;
+
+/// The storage layout of a contract or a component.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageLayout {
+    pub members: Vec<StorageMemberLayout>,
+}
+
+/// The layout of a single member of a storage struct.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageMemberLayout {
+    pub name: String,
+    /// The base address of the member - the `sn_keccak` of its name. Unused for substorage
+    /// members, whose members are laid out by their own names.
+    pub address: BigUintAsHex,
+    pub kind: StorageMemberKind,
+    /// The types of the keys of a mapping member, empty otherwise.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_types: Vec<String>,
+    /// The type of the stored value or of the elements of a vector, or the path of the component
+    /// of a substorage member.
+    pub value_type: String,
+    /// The number of storage slots used by a single value, as given by `Store::size`, if it is
+    /// known.
+    pub size: Option<u8>,
+    /// The members of the component of a substorage member.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub members: Vec<StorageMemberLayout>,
+}
+impl StorageMemberLayout {
+    /// Returns a description of the type of the member, as declared in the storage struct.
+    fn type_description(&self) -> String {
+        match self.kind {
+            StorageMemberKind::LegacyMap => {
+                format!("LegacyMap<{}, {}>", self.key_types.join(", "), self.value_type)
+            }
+            StorageMemberKind::Map => {
+                format!("Map<{}, {}>", self.key_types.join(", "), self.value_type)
+            }
+            StorageMemberKind::Vec => format!("Vec<{}>", self.value_type),
+            StorageMemberKind::Value | StorageMemberKind::Substorage => self.value_type.clone(),
+        }
+    }
+}
+
+/// The kind of a storage member.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageMemberKind {
+    /// A value stored at the base address of the member.
+    Value,
+    /// A `LegacyMap`, whose values are stored at the pedersen hash of the address and the keys.
+    LegacyMap,
+    /// A `Map`, whose values are stored at the poseidon hash of the address and the keys. The keys
+    /// of nested maps are combined into a tuple key.
+    Map,
+    /// A `Vec`, whose length is stored at the address, and whose elements are stored as the values
+    /// of a `Map` from their index.
+    Vec,
+    /// The storage of a component, embedded with `#[substorage(v0)]`.
+    Substorage,
+}
+
+/// An incompatibility between the storage layouts of a class and of the class it is upgraded to.
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+pub enum StorageLayoutIssue {
+    #[error("Storage member `{name}` was removed.")]
+    Removed { name: String },
+    #[error("Storage member `{old_name}` was renamed to `{new_name}`, which changes its address.")]
+    Renamed { old_name: String, new_name: String },
+    #[error("Storage member `{name}` changed its type from `{old_type}` to `{new_type}`.")]
+    Retyped { name: String, old_type: String, new_type: String },
+    #[error("Storage member `{name}` changed its size from {old_size} to {new_size} slots.")]
+    Resized { name: String, old_size: u8, new_size: u8 },
+    #[error("Storage member `{name}` has the same address as `{other_name}`.")]
+    Collision { name: String, other_name: String },
+}
+
+/// Compares the storage layout of a class with the layout of the class replacing it, and returns
+/// the storage slots the new class would not read as the old class wrote them, along with the
+/// members of the new class sharing an address.
+pub fn check_upgrade_compatibility(
+    old: &StorageLayout,
+    new: &StorageLayout,
+) -> Vec<StorageLayoutIssue> {
+    // The old class is already deployed, so only the collisions of the new one are reported.
+    let (old_slots, _) = storage_slots(old);
+    let (new_slots, mut issues) = storage_slots(new);
+    // The new slots that are not at an address of an old one, and may be renamed old slots.
+    let mut added_slots: HashSet<_> =
+        new_slots.keys().filter(|address| !old_slots.contains_key(*address)).collect();
+
+    for (address, (name, old_member)) in old_slots.iter() {
+        let Some((_, new_member)) = new_slots.get(address) else {
+            let old_type = old_member.type_description();
+            let renamed = new_slots.iter().find(|(address, (_, new_member))| {
+                added_slots.contains(address) && new_member.type_description() == old_type
+            });
+            issues.push(match renamed {
+                Some((address, (new_name, _))) => {
+                    added_slots.remove(address);
+                    StorageLayoutIssue::Renamed {
+                        old_name: name.clone(),
+                        new_name: new_name.clone(),
+                    }
+                }
+                None => StorageLayoutIssue::Removed { name: name.clone() },
+            });
+            continue;
+        };
+        let (old_type, new_type) = (old_member.type_description(), new_member.type_description());
+        if old_type != new_type {
+            issues.push(StorageLayoutIssue::Retyped { name: name.clone(), old_type, new_type });
+        } else if let (Some(old_size), Some(new_size)) = (old_member.size, new_member.size) {
+            if old_size != new_size {
+                issues.push(StorageLayoutIssue::Resized { name: name.clone(), old_size, new_size });
+            }
+        }
+    }
+    issues
+}
+
+/// Storage slots by their address, along with the name of their member.
+type StorageSlots<'a> = OrderedHashMap<&'a BigUint, (String, &'a StorageMemberLayout)>;
+
+/// Returns the storage slots of a layout by their address, with the members of substorage members
+/// flattened into it, and named by their path from the layout. Also returns the collisions between
+/// members at the same address, of which only the first is kept in the slots.
+fn storage_slots(layout: &StorageLayout) -> (StorageSlots<'_>, Vec<StorageLayoutIssue>) {
+    let mut slots = OrderedHashMap::default();
+    let mut collisions = vec![];
+    add_storage_slots(&layout.members, "", &mut slots, &mut collisions);
+    (slots, collisions)
+}
+
+/// Adds the storage slots of the given members to `slots`, with their names prefixed by `prefix`.
+fn add_storage_slots<'a>(
+    members: &'a [StorageMemberLayout],
+    prefix: &str,
+    slots: &mut StorageSlots<'a>,
+    collisions: &mut Vec<StorageLayoutIssue>,
+) {
+    for member in members {
+        let name = format!("{prefix}{}", member.name);
+        match member.kind {
+            StorageMemberKind::Substorage => {
+                add_storage_slots(&member.members, &format!("{name}."), slots, collisions)
+            }
+            StorageMemberKind::Value
+            | StorageMemberKind::LegacyMap
+            | StorageMemberKind::Map
+            | StorageMemberKind::Vec => match slots.get(&member.address.value) {
+                // Members of different components may have the same name, and thus address.
+                Some((other_name, _)) => collisions
+                    .push(StorageLayoutIssue::Collision { name, other_name: other_name.clone() }),
+                None => {
+                    slots.insert(&member.address.value, (name, member));
+                }
+            },
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use cairo_lang_utils::bigint::BigUintAsHex;
+use pretty_assertions::assert_eq;
+
+use crate::keccak::starknet_keccak;
+use crate::storage_layout::{
+    check_upgrade_compatibility, StorageLayout, StorageLayoutIssue, StorageMemberKind,
+    StorageMemberLayout,
+};
+
+/// Returns the layout of a storage member of the given kind.
+fn member(
+    name: &str,
+    kind: StorageMemberKind,
+    key_types: &[&str],
+    value_type: &str,
+    size: Option<u8>,
+) -> StorageMemberLayout {
+    StorageMemberLayout {
+        name: name.into(),
+        address: BigUintAsHex { value: starknet_keccak(name.as_bytes()) },
+        kind,
+        key_types: key_types.iter().map(|ty| ty.to_string()).collect(),
+        value_type: value_type.into(),
+        size,
+        members: vec![],
+    }
+}
+
+/// Returns the layout of a value storage member.
+fn value(name: &str, ty: &str, size: u8) -> StorageMemberLayout {
+    member(name, StorageMemberKind::Value, &[], ty, Some(size))
+}
+
+/// Returns the layout of a substorage storage member.
+fn substorage(name: &str, members: Vec<StorageMemberLayout>) -> StorageMemberLayout {
+    StorageMemberLayout {
+        members,
+        ..member(name, StorageMemberKind::Substorage, &[], "test::component", None)
+    }
+}
+
+fn old_layout() -> StorageLayout {
+    StorageLayout {
+        members: vec![
+            value("counter", "core::integer::u128", 1),
+            member(
+                "balances",
+                StorageMemberKind::LegacyMap,
+                &["core::starknet::contract_address::ContractAddress"],
+                "core::integer::u256",
+                Some(2),
+            ),
+            substorage("ownable", vec![value("owner", "core::felt252", 1)]),
+        ],
+    }
+}
+
+#[test]
+fn test_compatible_upgrade() {
+    let mut new_layout = old_layout();
+    // Adding members and moving them between the contract and its components keeps their slots.
+    new_layout.members.push(value("total", "core::integer::u256", 2));
+    new_layout.members.push(value("owner", "core::felt252", 1));
+    new_layout.members[2].members.clear();
+    assert_eq!(check_upgrade_compatibility(&old_layout(), &new_layout), vec![]);
+}
+
+#[test]
+fn test_incompatible_upgrade() {
+    let new_layout = StorageLayout {
+        members: vec![
+            value("count", "core::integer::u128", 1),
+            member(
+                "balances",
+                StorageMemberKind::LegacyMap,
+                &["core::felt252"],
+                "core::integer::u256",
+                Some(2),
+            ),
+            substorage("ownable", vec![]),
+        ],
+    };
+    assert_eq!(
+        check_upgrade_compatibility(&old_layout(), &new_layout),
+        vec![
+            StorageLayoutIssue::Renamed { old_name: "counter".into(), new_name: "count".into() },
+            StorageLayoutIssue::Retyped {
+                name: "balances".into(),
+                old_type: "LegacyMap<core::starknet::contract_address::ContractAddress, \
+                           core::integer::u256>"
+                    .into(),
+                new_type: "LegacyMap<core::felt252, core::integer::u256>".into(),
+            },
+            StorageLayoutIssue::Removed { name: "ownable.owner".into() },
+        ]
+    );
+}
+
+#[test]
+fn test_resized_member() {
+    let mut new_layout = old_layout();
+    new_layout.members[0].size = Some(2);
+    assert_eq!(
+        check_upgrade_compatibility(&old_layout(), &new_layout),
+        vec![StorageLayoutIssue::Resized { name: "counter".into(), old_size: 1, new_size: 2 }]
+    );
+}
+
+#[test]
+fn test_retyped_collection() {
+    let old_layout = StorageLayout {
+        members: vec![member(
+            "items",
+            StorageMemberKind::Map,
+            &["core::integer::u64"],
+            "core::felt252",
+            Some(1),
+        )],
+    };
+    let new_layout = StorageLayout {
+        members: vec![member("items", StorageMemberKind::Vec, &[], "core::felt252", Some(1))],
+    };
+    assert_eq!(
+        check_upgrade_compatibility(&old_layout, &new_layout),
+        vec![StorageLayoutIssue::Retyped {
+            name: "items".into(),
+            old_type: "Map<core::integer::u64, core::felt252>".into(),
+            new_type: "Vec<core::felt252>".into(),
+        }]
+    );
+}
+
+#[test]
+fn test_colliding_members() {
+    let mut new_layout = old_layout();
+    // Members of different components with the same name share their address.
+    new_layout.members.push(substorage("access", vec![value("owner", "core::felt252", 1)]));
+    assert_eq!(
+        check_upgrade_compatibility(&old_layout(), &new_layout),
+        vec![StorageLayoutIssue::Collision {
+            name: "access.owner".into(),
+            other_name: "ownable.owner".into(),
+        }]
+    );
+}
@@ -0,0 +1,55 @@
+use starknet::ContractAddress;
+
+#[derive(Copy, Drop, PartialEq, starknet::Store)]
+#[storage_node]
+pub struct Config {
+    pub owner: ContractAddress,
+    pub fee: u256,
+    pub enabled: bool,
+}
+
+pub mod pairs {
+    #[derive(Copy, Drop, PartialEq, starknet::Store)]
+    #[storage_node]
+    pub struct Pair<T> {
+        pub first: T,
+        pub second: T,
+    }
+}
+
+#[starknet::contract]
+pub mod storage_node_contract {
+    use super::Config;
+
+    #[storage]
+    struct Storage {
+        #[storage_node]
+        config: Config,
+        copied_config: super::Config,
+        #[storage_node]
+        pair: super::pairs::Pair<u128>,
+    }
+
+    #[external(v0)]
+    pub fn get_fee(self: @ContractState) -> u256 {
+        self.config.fee.read()
+    }
+
+    #[external(v0)]
+    pub fn set_fee(ref self: ContractState, fee: u256) {
+        self.config.fee.write(fee);
+    }
+
+    #[external(v0)]
+    pub fn get_pair_sum(self: @ContractState) -> u128 {
+        self.pair.first.read() + self.pair.second.read()
+    }
+
+    pub fn get_copied_config(self: @ContractState) -> super::Config {
+        self.copied_config.read()
+    }
+
+    pub fn set_copied_config(ref self: ContractState, config: super::Config) {
+        self.copied_config.write(config);
+    }
+}
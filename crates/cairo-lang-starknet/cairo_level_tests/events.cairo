@@ -157,3 +157,30 @@ fn test_pop_log() {
     assert_eq(keys.at(0), @1234, 'unexpected key');
     assert_eq(data.at(0), @2345, 'unexpected data');
 }
+
+#[derive(Copy, Drop, Debug, PartialEq, starknet::Event)]
+struct Transferred(#[key]
+felt252, u128);
+
+#[derive(Copy, Drop, Debug, PartialEq, starknet::Event)]
+struct Paused;
+
+#[test]
+fn test_tuple_and_unit_struct_events() {
+    let mut keys = array![];
+    let mut data = array![];
+    starknet::Event::append_keys_and_data(@Transferred(12, 34), ref keys, ref data);
+    assert_eq!(keys, array![12]);
+    assert_eq!(data, array![34]);
+    let mut keys = keys.span();
+    let mut data = data.span();
+    assert_eq!(starknet::Event::deserialize(ref keys, ref data), Option::Some(Transferred(12, 34)));
+
+    let mut keys = array![];
+    let mut data = array![];
+    starknet::Event::append_keys_and_data(@Paused, ref keys, ref data);
+    assert!(keys.is_empty() && data.is_empty());
+    let mut keys = keys.span();
+    let mut data = data.span();
+    assert_eq!(starknet::Event::<Paused>::deserialize(ref keys, ref data), Option::Some(Paused));
+}
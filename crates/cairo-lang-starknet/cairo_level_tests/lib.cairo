@@ -21,5 +21,9 @@ mod replace_class_test;
 #[cfg(test)]
 mod storage_access;
 #[cfg(test)]
+mod storage_collections;
+#[cfg(test)]
+mod storage_nodes;
+#[cfg(test)]
 mod contract_address_test;
 mod utils;
@@ -80,15 +80,23 @@ struct NonZeros {
     value_felt252: NonZero<felt252>,
 }
 
+#[derive(Copy, Drop, Debug, Serde, PartialEq, starknet::Store)]
+struct Wei(u256, u8);
+
+#[derive(Copy, Drop, Debug, Serde, PartialEq, starknet::Store)]
+struct Marker;
+
 #[starknet::contract]
 mod test_contract {
-    use super::{AbcEtc, ByteArrays, NonZeros};
+    use super::{AbcEtc, ByteArrays, Marker, NonZeros, Wei};
 
     #[storage]
     struct Storage {
         data: AbcEtc,
         byte_arrays: ByteArrays,
         non_zeros: NonZeros,
+        wei: Wei,
+        marker: Marker,
     }
 
     #[external(v0)]
@@ -120,6 +128,18 @@ mod test_contract {
     pub fn get_non_zeros(self: @ContractState) -> NonZeros {
         self.non_zeros.read()
     }
+
+    #[external(v0)]
+    pub fn set_wei(ref self: ContractState, value: (Wei, Marker)) {
+        let (wei, marker) = value;
+        self.wei.write(wei);
+        self.marker.write(marker);
+    }
+
+    #[external(v0)]
+    pub fn get_wei(self: @ContractState) -> (Wei, Marker) {
+        (self.wei.read(), self.marker.read())
+    }
 }
 
 #[test]
@@ -206,3 +226,14 @@ fn test_read_write_non_zero() {
     assert!(test_contract::__external::set_non_zeros(serialized(x.clone())).is_empty());
     assert_eq!(deserialized(test_contract::__external::get_non_zeros(serialized(()))), x);
 }
+
+#[test]
+fn test_read_write_tuple_and_unit_structs() {
+    let x = (Wei(BoundedInt::max(), 7), Marker);
+
+    assert!(test_contract::__external::set_wei(serialized(x)).is_empty());
+    assert_eq!(deserialized(test_contract::__external::get_wei(serialized(()))), x);
+    let base_address = starknet::storage_base_address_from_felt252(selector!("wei"));
+    assert!(starknet::Store::read_at_offset(0, base_address, 2).unwrap() == 7_u8);
+    assert_eq!(starknet::Store::<Marker>::size(), 0);
+}
@@ -0,0 +1,73 @@
+use starknet::ContractAddress;
+use starknet::storage::{StorageMapMemberAccessTrait, StorageVecMemberAccessTrait};
+use core::test::test_utils::assert_eq;
+
+#[starknet::contract]
+mod test_contract {
+    use starknet::ContractAddress;
+
+    #[storage]
+    struct Storage {
+        balances: Map<ContractAddress, u256>,
+        allowances: Map<ContractAddress, Map<ContractAddress, u256>>,
+        items: Vec<u128>,
+    }
+}
+
+fn address(value: felt252) -> ContractAddress {
+    value.try_into().unwrap()
+}
+
+#[test]
+fn test_map() {
+    let mut state = test_contract::contract_state_for_testing();
+    state.balances.write(address(1), 10);
+    state.balances.write(address(2), 20);
+    assert_eq(@state.balances.read(address(1)), @10, 'Wrong balance');
+    assert_eq(@state.balances.read(address(2)), @20, 'Wrong balance');
+    assert_eq(@state.balances.read(address(3)), @0, 'Wrong balance');
+}
+
+#[test]
+fn test_nested_map() {
+    let mut state = test_contract::contract_state_for_testing();
+    state.allowances.write((address(1), address(2)), 12);
+    state.allowances.write((address(2), address(1)), 21);
+    assert_eq(@state.allowances.read((address(1), address(2))), @12, 'Wrong allowance');
+    assert_eq(@state.allowances.read((address(2), address(1))), @21, 'Wrong allowance');
+    // The value of an inner map is at the address of the combined key.
+    let base_address = starknet::storage::storage_map_entry_address(
+        selector!("allowances"), (address(1), address(2))
+    );
+    let value: u256 = starknet::Store::read(0, base_address).unwrap();
+    assert_eq(@value, @12, 'Wrong address');
+}
+
+#[test]
+fn test_vec() {
+    let mut state = test_contract::contract_state_for_testing();
+    assert_eq(@state.items.len(), @0, 'Wrong length');
+    state.items.append(5);
+    state.items.append(6);
+    assert_eq(@state.items.len(), @2, 'Wrong length');
+    assert_eq(@state.items.read(0), @5, 'Wrong element');
+    assert_eq(@state.items.read(1), @6, 'Wrong element');
+    state.items.write(0, 7);
+    assert_eq(@state.items.read(0), @7, 'Wrong element');
+    assert_eq(@state.items.len(), @2, 'Wrong length');
+}
+
+#[test]
+#[should_panic(expected: ('Index out of bounds',))]
+fn test_vec_read_out_of_bounds() {
+    let mut state = test_contract::contract_state_for_testing();
+    state.items.append(5);
+    state.items.read(1);
+}
+
+#[test]
+#[should_panic(expected: ('Index out of bounds',))]
+fn test_vec_write_out_of_bounds() {
+    let mut state = test_contract::contract_state_for_testing();
+    state.items.write(0, 5);
+}
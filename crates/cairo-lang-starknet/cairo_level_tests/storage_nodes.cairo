@@ -0,0 +1,55 @@
+use starknet::ContractAddress;
+use cairo_level_tests::contracts::storage_node::{Config, storage_node_contract};
+use cairo_level_tests::contracts::storage_node::pairs::Pair;
+use starknet::storage::StorageNodeMemberAccessTrait;
+use core::test::test_utils::assert_eq;
+
+fn owner() -> ContractAddress {
+    'owner'.try_into().unwrap()
+}
+
+#[test]
+fn test_storage_node_members() {
+    let mut state = storage_node_contract::contract_state_for_testing();
+    state.config.owner.write(owner());
+    storage_node_contract::set_fee(ref state, 7);
+    state.config.enabled.write(true);
+    assert_eq(@state.config.owner.read(), @owner(), 'Wrong owner');
+    assert_eq(@storage_node_contract::get_fee(@state), @7, 'Wrong fee');
+    assert_eq(@state.config.enabled.read(), @true, 'Wrong enabled');
+}
+
+#[test]
+fn test_storage_node_layout() {
+    let mut state = storage_node_contract::contract_state_for_testing();
+    let address = starknet::storage_access::storage_base_address_from_felt252(selector!("config"));
+    // The members of a storage node are stored as the members of the stored struct.
+    let config = Config { owner: owner(), fee: 10, enabled: true };
+    starknet::Store::write(0, address, config).unwrap();
+    assert_eq(@state.config.owner.read(), @owner(), 'Wrong owner');
+    assert_eq(@state.config.fee.read(), @10, 'Wrong fee');
+    assert_eq(@state.config.enabled.read(), @true, 'Wrong enabled');
+    state.config.fee.write(20);
+    let config: Config = starknet::Store::read(0, address).unwrap();
+    assert(config == Config { owner: owner(), fee: 20, enabled: true }, 'Wrong config');
+}
+
+#[test]
+fn test_storage_node_struct_value() {
+    let mut state = storage_node_contract::contract_state_for_testing();
+    // The struct is still stored as a whole in members not annotated with `#[storage_node]`.
+    let config = Config { owner: owner(), fee: 30, enabled: false };
+    storage_node_contract::set_copied_config(ref state, config);
+    assert(storage_node_contract::get_copied_config(@state) == config, 'Wrong config');
+}
+
+#[test]
+fn test_generic_storage_node() {
+    let mut state = storage_node_contract::contract_state_for_testing();
+    state.pair.second.write(5);
+    state.pair.first.write(3);
+    assert_eq(@storage_node_contract::get_pair_sum(@state), @8, 'Wrong sum');
+    let address = starknet::storage_access::storage_base_address_from_felt252(selector!("pair"));
+    let pair: Pair<u128> = starknet::Store::read(0, address).unwrap();
+    assert(pair == Pair { first: 3, second: 5 }, 'Wrong pair');
+}
@@ -640,9 +640,11 @@ impl<'a> AbiBuilder<'a> {
                 Ok(())
             }
             TypeLongId::Snapshot(ty) => self.add_type(ty),
-            TypeLongId::GenericParameter(_) | TypeLongId::Var(_) | TypeLongId::Missing(_) => {
-                Err(ABIError::UnexpectedType)
-            }
+            TypeLongId::GenericParameter(_)
+            | TypeLongId::Var(_)
+            | TypeLongId::Closure(_)
+            | TypeLongId::ImplType(_)
+            | TypeLongId::Missing(_) => Err(ABIError::UnexpectedType),
         }
     }
 
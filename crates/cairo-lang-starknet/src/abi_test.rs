@@ -2,14 +2,19 @@ use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_defs::db::DefsGroup;
 use cairo_lang_semantic::items::attribute::SemanticQueryAttrs;
 use cairo_lang_semantic::test_utils::setup_test_module;
+use cairo_lang_starknet_classes::abi_to_cairo::abi_to_cairo;
 use cairo_lang_test_utils::parse_test_file::TestRunnerResult;
-use cairo_lang_test_utils::{get_direct_or_file_content, verify_diagnostics_expectation};
+use cairo_lang_test_utils::{
+    compare_contents_or_fix_with_path, get_direct_or_file_content, verify_diagnostics_expectation,
+};
 use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
+use test_case::test_case;
 
 use super::BuilderConfig;
 use crate::abi::AbiBuilder;
 use crate::plugin::consts::CONTRACT_ATTR;
 use crate::starknet_plugin_suite;
+use crate::test_utils::{get_example_file_path, get_test_contract};
 
 /// Helper function for testing ABI failures.
 pub fn test_abi_failure(
@@ -57,3 +62,30 @@ cairo_lang_test_utils::test_file_test!(
   },
   test_abi_failure
 );
+
+/// Tests that the interface generated from the ABI of a contract in the contracts crate is the same
+/// as in <test_case>.interface.cairo, and that it compiles.
+#[test_case("hello_starknet::hello_starknet")]
+#[test_case("erc20::erc_20")]
+#[test_case("with_ownable::ownable_balance")]
+#[test_case("upgradable_counter::counter_contract")]
+#[test_case("multi_component::contract_with_4_components")]
+fn test_abi_to_cairo_from_contracts_crate(example_contract_path: &str) {
+    let contract = get_test_contract(
+        format!("cairo_level_tests::contracts::{example_contract_path}").as_str(),
+    );
+    let interface = abi_to_cairo(&contract.abi.unwrap(), "IContract").unwrap();
+    let example_file_name = example_contract_path.replace("::", "__");
+    compare_contents_or_fix_with_path(
+        &get_example_file_path(format!("{example_file_name}.interface.cairo").as_str()),
+        interface.clone(),
+    );
+
+    let db = &mut RootDatabase::builder()
+        .detect_corelib()
+        .with_plugin_suite(starknet_plugin_suite())
+        .build()
+        .unwrap();
+    let (_, diagnostics) = setup_test_module(db, &interface).split();
+    assert_eq!(diagnostics, "");
+}
@@ -1,5 +1,6 @@
 use cairo_lang_defs::ids::ModuleId;
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_semantic::db::SemanticGroup;
 use cairo_lang_semantic::items::attribute::SemanticQueryAttrs;
 use cairo_lang_semantic::plugin::AnalyzerPlugin;
@@ -39,11 +40,15 @@ fn add_non_starknet_interface_embeddable_diagnostics(
         }
         let Ok(impl_trait) = db.impl_def_trait(*id) else { continue };
         if !impl_trait.has_attr(db.upcast(), STARKNET_INTERFACE_ATTR).unwrap_or(true) {
-            diagnostics.push(PluginDiagnostic::warning(
-                item.stable_ptr().untyped(),
-                "Impls with the embeddable attribute must implement a starknet interface trait."
-                    .to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::warning(
+                    item.stable_ptr().untyped(),
+                    "Impls with the embeddable attribute must implement a starknet interface \
+                     trait."
+                        .to_string(),
+                )
+                .with_error_code(error_code!("E0700")),
+            );
         }
     }
 }
@@ -66,11 +71,14 @@ fn add_abi_diagnostics(
     };
     for err in abi_builder.errors() {
         if !matches!(err, ABIError::SemanticError) {
-            diagnostics.push(PluginDiagnostic::warning(
-                err.location(db)
-                    .unwrap_or_else(|| contract.submodule_id.stable_ptr(db.upcast()).untyped()),
-                format!("Failed to generate ABI: {err}"),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::warning(
+                    err.location(db)
+                        .unwrap_or_else(|| contract.submodule_id.stable_ptr(db.upcast()).untyped()),
+                    format!("Failed to generate ABI: {err}"),
+                )
+                .with_error_code(error_code!("E0701")),
+            );
         }
     }
 }
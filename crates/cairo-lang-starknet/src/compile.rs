@@ -1,8 +1,10 @@
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
+use cairo_felt::Felt252;
 use cairo_lang_compiler::db::RootDatabase;
-use cairo_lang_compiler::project::setup_project;
+use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
+use cairo_lang_compiler::project::{setup_project, update_crate_lint_levels};
 use cairo_lang_compiler::CompilerConfig;
 use cairo_lang_defs::ids::TopLevelLanguageElementId;
 use cairo_lang_diagnostics::ToOption;
@@ -17,7 +19,9 @@ use cairo_lang_starknet_classes::allowed_libfuncs::ListSelector;
 use cairo_lang_starknet_classes::contract_class::{
     ContractClass, ContractEntryPoint, ContractEntryPoints,
 };
+use cairo_lang_starknet_classes::storage_layout::StorageLayout;
 use cairo_lang_utils::arc_unwrap_or_clone;
+use cairo_lang_utils::ordered_hash_map::OrderedHashMap;
 use itertools::{chain, Itertools};
 
 use crate::abi::AbiBuilder;
@@ -28,6 +32,7 @@ use crate::contract::{
 };
 use crate::plugin::consts::{CONSTRUCTOR_MODULE, EXTERNAL_MODULE, L1_HANDLER_MODULE};
 use crate::starknet_plugin_suite;
+use crate::storage_layout::get_storage_layout;
 
 #[cfg(test)]
 #[path = "compile_test.rs"]
@@ -40,14 +45,56 @@ pub fn compile_path(
     contract_path: Option<&str>,
     compiler_config: CompilerConfig<'_>,
 ) -> Result<ContractClass> {
+    let (db, main_crate_ids) = prepare_db(path, &compiler_config)?;
+    compile_contract_in_prepared_db(&db, contract_path, main_crate_ids, compiler_config)
+}
+
+/// Compile the contract given by path, returning it along with its storage layout.
+/// Errors if there is ambiguity.
+pub fn compile_path_with_storage_layout(
+    path: &Path,
+    contract_path: Option<&str>,
+    mut compiler_config: CompilerConfig<'_>,
+) -> Result<(ContractClass, StorageLayout)> {
+    let (db, main_crate_ids) = prepare_db(path, &compiler_config)?;
+    let contract = find_contract(&db, contract_path, main_crate_ids, &mut compiler_config)?;
+    let mut classes = compile_prepared_db(&db, &[&contract], compiler_config)?;
+    assert_eq!(classes.len(), 1);
+    Ok((classes.remove(0), get_storage_layout(&db, &contract)?))
+}
+
+/// Returns the class hashes of the contracts in the crate given by path, by the full path of their
+/// modules, as compiled for deployment (with the default config, and without the `test` cfg).
+/// Returns no class hashes if the crate does not compile so.
+pub fn class_hashes_path(path: &Path) -> Result<OrderedHashMap<String, Felt252>> {
+    let compiler_config = CompilerConfig::default();
+    let (db, main_crate_ids) = prepare_db(path, &compiler_config)?;
+    if DiagnosticsReporter::ignoring().with_crates(&main_crate_ids).allow_warnings().check(&db) {
+        return Ok(OrderedHashMap::default());
+    }
+    find_contracts(&db, &main_crate_ids)
+        .iter()
+        .map(|contract| {
+            let contract_class =
+                compile_contract_with_prepared_and_checked_db(&db, contract, &compiler_config)?;
+            Ok((contract.module_id().full_path(&db), contract_class.class_hash()))
+        })
+        .collect()
+}
+
+/// Returns a database with the crate given by path set up, and the ids of its main crates.
+fn prepare_db(
+    path: &Path,
+    compiler_config: &CompilerConfig<'_>,
+) -> Result<(RootDatabase, Vec<CrateId>)> {
     let mut db = RootDatabase::builder()
         .detect_corelib()
         .with_plugin_suite(starknet_plugin_suite())
         .build()?;
 
     let main_crate_ids = setup_project(&mut db, Path::new(&path))?;
-
-    compile_contract_in_prepared_db(&db, contract_path, main_crate_ids, compiler_config)
+    update_crate_lint_levels(&mut db, &main_crate_ids, &compiler_config.lint_levels)?;
+    Ok((db, main_crate_ids))
 }
 
 /// Runs StarkNet contract compiler on the specified contract.
@@ -59,19 +106,34 @@ pub fn compile_contract_in_prepared_db(
     main_crate_ids: Vec<CrateId>,
     mut compiler_config: CompilerConfig<'_>,
 ) -> Result<ContractClass> {
+    let contract = find_contract(db, contract_path, main_crate_ids, &mut compiler_config)?;
+    let mut classes = compile_prepared_db(db, &[&contract], compiler_config)?;
+    assert_eq!(classes.len(), 1);
+    Ok(classes.remove(0))
+}
+
+/// Finds the contract given by its path in the main crates.
+/// If no contract was specified, verify that there is only one.
+/// Otherwise, return an error.
+fn find_contract(
+    db: &RootDatabase,
+    contract_path: Option<&str>,
+    main_crate_ids: Vec<CrateId>,
+    compiler_config: &mut CompilerConfig<'_>,
+) -> Result<ContractDeclaration> {
     let mut contracts = find_contracts(db, &main_crate_ids);
 
     // TODO(ilya): Add contract names.
     if let Some(contract_path) = contract_path {
         contracts.retain(|contract| contract.submodule_id.full_path(db) == contract_path);
     };
-    let contract = match contracts.len() {
+    match contracts.len() {
         0 => {
             // Report diagnostics as they might reveal the reason why no contract was found.
             compiler_config.diagnostics_reporter.ensure(db)?;
             anyhow::bail!("Contract not found.");
         }
-        1 => &contracts[0],
+        1 => Ok(contracts.remove(0)),
         _ => {
             let contract_names =
                 contracts.iter().map(|contract| contract.submodule_id.full_path(db)).join("\n  ");
@@ -81,12 +143,7 @@ pub fn compile_contract_in_prepared_db(
                 contract_names
             );
         }
-    };
-
-    let contracts = vec![contract];
-    let mut classes = compile_prepared_db(db, &contracts, compiler_config)?;
-    assert_eq!(classes.len(), 1);
-    Ok(classes.remove(0))
+    }
 }
 
 /// Runs Starknet contracts compiler.
@@ -218,6 +275,18 @@ pub fn starknet_compile(
     config: Option<CompilerConfig<'_>>,
     allowed_libfuncs_list: Option<ListSelector>,
 ) -> anyhow::Result<String> {
+    let contract =
+        starknet_compile_contract_class(crate_path, contract_path, config, allowed_libfuncs_list)?;
+    serde_json::to_string_pretty(&contract).with_context(|| "Serialization failed.")
+}
+
+/// Compile Starknet crate (or specific contract in the crate), returning the contract class.
+pub fn starknet_compile_contract_class(
+    crate_path: PathBuf,
+    contract_path: Option<String>,
+    config: Option<CompilerConfig<'_>>,
+    allowed_libfuncs_list: Option<ListSelector>,
+) -> anyhow::Result<ContractClass> {
     let contract = compile_path(
         &crate_path,
         contract_path.as_deref(),
@@ -230,5 +299,28 @@ pub fn starknet_compile(
             ListSelector::default()
         },
     )?;
-    serde_json::to_string_pretty(&contract).with_context(|| "Serialization failed.")
+    Ok(contract)
+}
+
+/// Compile Starknet crate (or specific contract in the crate), returning the contract class along
+/// with the storage layout of the contract.
+pub fn starknet_compile_contract_class_with_storage_layout(
+    crate_path: PathBuf,
+    contract_path: Option<String>,
+    config: Option<CompilerConfig<'_>>,
+    allowed_libfuncs_list: Option<ListSelector>,
+) -> anyhow::Result<(ContractClass, StorageLayout)> {
+    let (contract, storage_layout) = compile_path_with_storage_layout(
+        &crate_path,
+        contract_path.as_deref(),
+        if let Some(config) = config { config } else { CompilerConfig::default() },
+    )?;
+    contract.validate_version_compatible(
+        if let Some(allowed_libfuncs_list) = allowed_libfuncs_list {
+            allowed_libfuncs_list
+        } else {
+            ListSelector::default()
+        },
+    )?;
+    Ok((contract, storage_layout))
 }
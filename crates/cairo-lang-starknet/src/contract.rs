@@ -274,6 +274,11 @@ fn get_submodule_id(
 /// Sierra information of a contract.
 #[derive(Clone, Serialize, Deserialize, PartialEq, Debug)]
 pub struct ContractInfo {
+    /// The class hash of the contract, as compiled for deployment (see [class_hashes_path]). None
+    /// if the contract does not compile so.
+    ///
+    /// [class_hashes_path]: crate::compile::class_hashes_path
+    pub class_hash: Option<Felt252>,
     /// Sierra function of the constructor.
     pub constructor: Option<FunctionId>,
     /// Sierra functions of the external functions.
@@ -291,25 +296,32 @@ pub struct ContractInfo {
 }
 
 /// Returns the list of functions in a given module.
+///
+/// `class_hashes` are the class hashes of the contracts as compiled for deployment, by the full
+/// path of their modules, as returned by [class_hashes_path].
+///
+/// [class_hashes_path]: crate::compile::class_hashes_path
 pub fn get_contracts_info<T: SierraIdReplacer>(
     db: &dyn SierraGenGroup,
     main_crate_ids: Vec<CrateId>,
     replacer: &T,
+    class_hashes: &OrderedHashMap<String, Felt252>,
 ) -> Result<OrderedHashMap<Felt252, ContractInfo>, anyhow::Error> {
     let contracts = find_contracts(db.upcast(), &main_crate_ids);
     let mut contracts_info = OrderedHashMap::default();
     for contract in contracts {
-        let (class_hash, contract_info) = analyze_contract(db, &contract, replacer)?;
+        let (class_hash, contract_info) = analyze_contract(db, &contract, replacer, class_hashes)?;
         contracts_info.insert(class_hash, contract_info);
     }
     Ok(contracts_info)
 }
 
-/// Analyzes a contract and returns its class hash and a list of its functions.
+/// Analyzes a contract and returns its test class hash and a list of its functions.
 fn analyze_contract<T: SierraIdReplacer>(
     db: &dyn SierraGenGroup,
     contract: &ContractDeclaration,
     replacer: &T,
+    class_hashes: &OrderedHashMap<String, Felt252>,
 ) -> anyhow::Result<(cairo_felt::Felt252, ContractInfo)> {
     // Extract class hash.
     let item =
@@ -318,7 +330,7 @@ fn analyze_contract<T: SierraIdReplacer>(
     let value =
         extract_matches!(db.constant_semantic_data(constant_id).unwrap().value, Expr::Literal)
             .value;
-    let class_hash = value.into();
+    let test_class_hash = value.into();
 
     // Extract functions.
     let SemanticEntryPoints { external, l1_handler, constructor } =
@@ -334,12 +346,14 @@ fn analyze_contract<T: SierraIdReplacer>(
         .map(|f| get_selector_and_sierra_function(db, &f, replacer))
         .collect();
 
+    let class_hash = class_hashes.get(&contract.module_id().full_path(db.upcast())).cloned();
     let contract_info = ContractInfo {
+        class_hash,
         externals,
         l1_handlers,
         constructor: constructors.into_iter().next().map(|x| x.1),
     };
-    Ok((class_hash, contract_info))
+    Ok((test_class_hash, contract_info))
 }
 
 /// Converts a function to a Sierra function.
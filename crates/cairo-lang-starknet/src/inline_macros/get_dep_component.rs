@@ -1,7 +1,9 @@
 use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
-    InlineMacroExprPlugin, InlinePluginResult, NamedPlugin, PluginDiagnostic, PluginGeneratedFile,
+    InlineMacroExprPlugin, InlineMacroSignature, InlinePluginResult, NamedPlugin, PluginDiagnostic,
+    PluginGeneratedFile,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_semantic::extract_macro_unnamed_args;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
@@ -21,6 +23,13 @@ impl InlineMacroExprPlugin for GetDepComponentMacro {
     ) -> InlinePluginResult {
         get_dep_component_generate_code_helper(db, syntax, false)
     }
+
+    fn signature(&self) -> Option<InlineMacroSignature> {
+        Some(InlineMacroSignature {
+            params: vec!["self: @ContractState", "component"],
+            variadic: false,
+        })
+    }
 }
 
 /// Macro for getting a mutable component given a mutable contract state that has it.
@@ -37,6 +46,13 @@ impl InlineMacroExprPlugin for GetDepComponentMutMacro {
     ) -> InlinePluginResult {
         get_dep_component_generate_code_helper(db, syntax, true)
     }
+
+    fn signature(&self) -> Option<InlineMacroSignature> {
+        Some(InlineMacroSignature {
+            params: vec!["ref self: ContractState", "component"],
+            variadic: false,
+        })
+    }
 }
 
 /// A helper function for the code generation of both `DepComponentMacro` and
@@ -62,13 +78,16 @@ fn get_dep_component_generate_code_helper(
         if !matches!(&contract_arg_modifiers[..], &[ast::Modifier::Ref(_)]) {
             // TODO(Gil): The generated diagnostics points to the whole inline macro, it should
             // point to the arg.
-            let diagnostics = vec![PluginDiagnostic::error(
-                contract_arg.stable_ptr().untyped(),
-                format!(
-                    "The first argument of `{}` macro must have only a `ref` modifier.",
-                    GetDepComponentMutMacro::NAME
-                ),
-            )];
+            let diagnostics = vec![
+                PluginDiagnostic::error(
+                    contract_arg.stable_ptr().untyped(),
+                    format!(
+                        "The first argument of `{}` macro must have only a `ref` modifier.",
+                        GetDepComponentMutMacro::NAME
+                    ),
+                )
+                .with_error_code(error_code!("E0702")),
+            ];
             return InlinePluginResult { code: None, diagnostics };
         };
     }
@@ -1,6 +1,8 @@
 use cairo_lang_defs::plugin::{
-    InlineMacroExprPlugin, InlinePluginResult, NamedPlugin, PluginDiagnostic, PluginGeneratedFile,
+    InlineMacroExprPlugin, InlineMacroSignature, InlinePluginResult, NamedPlugin, PluginDiagnostic,
+    PluginGeneratedFile,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_semantic::extract_macro_single_unnamed_arg;
 use cairo_lang_starknet_classes::keccak::starknet_keccak;
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -25,10 +27,13 @@ impl InlineMacroExprPlugin for SelectorMacro {
         );
 
         let ast::Expr::String(input_string) = arg else {
-            let diagnostics = vec![PluginDiagnostic::error(
-                syntax.stable_ptr().untyped(),
-                format!("`{}` macro argument must be a string", SelectorMacro::NAME),
-            )];
+            let diagnostics = vec![
+                PluginDiagnostic::error(
+                    syntax.stable_ptr().untyped(),
+                    format!("`{}` macro argument must be a string", SelectorMacro::NAME),
+                )
+                .with_error_code(error_code!("E0703")),
+            ];
             return InlinePluginResult { code: None, diagnostics };
         };
         let selector_string = input_string.string_value(db).unwrap();
@@ -44,4 +49,8 @@ impl InlineMacroExprPlugin for SelectorMacro {
             diagnostics: vec![],
         }
     }
+
+    fn signature(&self) -> Option<InlineMacroSignature> {
+        Some(InlineMacroSignature { params: vec!["name"], variadic: false })
+    }
 }
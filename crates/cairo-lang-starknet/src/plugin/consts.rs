@@ -8,6 +8,7 @@ pub const STORAGE_STRUCT_NAME: &str = "Storage";
 pub const EVENT_TYPE_NAME: &str = "Event";
 pub const LEGACY_STORAGE_MAPPING: &str = "LegacyMap";
 pub const STORAGE_MAPPING: &str = "Map";
+pub const STORAGE_VEC: &str = "Vec";
 pub const CONTRACT_STATE_NAME: &str = "ContractState";
 pub const GENERIC_CONTRACT_STATE_NAME: &str = "TContractState";
 pub const COMPONENT_STATE_NAME: &str = "ComponentState";
@@ -25,7 +26,7 @@ pub const INTERFACE_ATTR: &str = "starknet::interface";
 pub(super) const DEPRECATED_CONTRACT_ATTR: &str = "contract";
 pub const CONTRACT_ATTR: &str = "starknet::contract";
 pub const CONTRACT_ATTR_ACCOUNT_ARG: &str = "account";
-pub(super) const COMPONENT_ATTR: &str = "starknet::component";
+pub const COMPONENT_ATTR: &str = "starknet::component";
 pub const STORAGE_ATTR: &str = "storage";
 pub const EXTERNAL_ATTR: &str = "external";
 pub const EMBEDDABLE_ATTR: &str = "starknet::embeddable";
@@ -37,6 +38,8 @@ pub const EMBEDDABLE_AS_ATTR: &str = "embeddable_as";
 pub const COMPONENT_INLINE_MACRO: &str = "component";
 pub const HAS_COMPONENT_TRAIT: &str = "HasComponent";
 pub const SUBSTORAGE_ATTR: &str = "substorage";
+pub const STORAGE_NODE_ATTR: &str = "storage_node";
+pub const STORAGE_NODE_SUFFIX: &str = "StorageNode";
 pub const NESTED_ATTR: &str = "nested";
 pub const FLAT_ATTR: &str = "flat";
 pub const KEY_ATTR: &str = "key";
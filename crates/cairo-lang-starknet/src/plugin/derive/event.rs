@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::{ModifiedNode, RewriteNode};
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_starknet_classes::abi::EventFieldKind;
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::QueryAttrs;
@@ -35,10 +36,13 @@ fn handle_struct(
     // TODO(spapini): Support generics.
     let generic_params = struct_ast.generic_params(db);
     let ast::OptionWrappedGenericParamList::Empty(_) = generic_params else {
-        diagnostics.push(PluginDiagnostic::error(
-            generic_params.stable_ptr().untyped(),
-            format!("{EVENT_TYPE_NAME} structs with generic arguments are unsupported"),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                generic_params.stable_ptr().untyped(),
+                format!("{EVENT_TYPE_NAME} structs with generic arguments are unsupported"),
+            )
+            .with_error_code(error_code!("E0704")),
+        );
         return None;
     };
 
@@ -47,25 +51,37 @@ fn handle_struct(
     let mut deserialize_members = vec![];
     let mut ctor = vec![];
     let mut members = vec![];
-    for member in struct_ast.members(db).elements(db) {
-        let member_name = RewriteNode::new_trimmed(member.name(db).as_syntax_node());
+    let is_tuple = matches!(struct_ast.body(db), ast::StructBody::Tuple(_));
+    for member in struct_ast.members(db) {
+        // Positional members can't be used as variable names, so they are bound to prefixed ones.
+        let (member_name, member_var) = match &member {
+            ast::StructMember::Named(named) => {
+                let member_name = RewriteNode::new_trimmed(named.name(db).as_syntax_node());
+                (member_name.clone(), member_name)
+            }
+            ast::StructMember::Tuple(_) => {
+                let index = member.name(db);
+                (RewriteNode::text(&index), RewriteNode::Text(format!("__{index}")))
+            }
+        };
         let member_kind =
             get_field_kind_for_member(db, diagnostics, &member, EventFieldKind::DataSerde);
-        members.push((member.name(db).text(db), member_kind));
+        members.push((member.name(db), member_kind));
 
         let member_for_append = RewriteNode::interpolate_patched(
             "self.$member_name$",
-            &[("member_name".to_string(), member_name.clone())].into(),
+            &[("member_name".to_string(), member_name)].into(),
         );
         let append_member = append_field(member_kind, member_for_append);
-        let deserialize_member = deserialize_field(member_kind, member_name.clone());
+        let deserialize_member = deserialize_field(member_kind, member_var.clone());
         append_members.push(append_member);
         deserialize_members.push(deserialize_member);
         ctor.push(RewriteNode::interpolate_patched(
             "$member_name$, ",
-            &[("member_name".to_string(), member_name)].into(),
+            &[("member_name".to_string(), member_var)].into(),
         ));
     }
+    let (ctor_open, ctor_close) = if is_tuple { ("(", ")") } else { (" {", "}") };
     let event_data = EventData::Struct { members };
     let append_members = RewriteNode::Modified(ModifiedNode { children: Some(append_members) });
     let deserialize_members =
@@ -85,7 +101,7 @@ fn handle_struct(
                 fn deserialize(
                     ref keys: Span<felt252>, ref data: Span<felt252>,
                 ) -> Option<$struct_name$> {{$deserialize_members$
-                    Option::Some($struct_name$ {{$ctor$}})
+                    Option::Some($struct_name${ctor_open}$ctor${ctor_close})
                 }}
             }}
             "
@@ -107,7 +123,7 @@ fn handle_struct(
 fn get_field_kind_for_member(
     db: &dyn SyntaxGroup,
     diagnostics: &mut Vec<PluginDiagnostic>,
-    member: &ast::Member,
+    member: &ast::StructMember,
     default: EventFieldKind,
 ) -> EventFieldKind {
     let is_nested = member.has_attr(db, NESTED_ATTR);
@@ -116,17 +132,23 @@ fn get_field_kind_for_member(
 
     // Currently, nested fields are unsupported.
     if is_nested {
-        diagnostics.push(PluginDiagnostic::error(
-            member.stable_ptr().untyped(),
-            "Nested event fields are currently unsupported".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                member.stable_ptr().untyped(),
+                "Nested event fields are currently unsupported".to_string(),
+            )
+            .with_error_code(error_code!("E0705")),
+        );
     }
     // Currently, serde fields are unsupported.
     if is_serde {
-        diagnostics.push(PluginDiagnostic::error(
-            member.stable_ptr().untyped(),
-            "Serde event fields are currently unsupported".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                member.stable_ptr().untyped(),
+                "Serde event fields are currently unsupported".to_string(),
+            )
+            .with_error_code(error_code!("E0706")),
+        );
     }
 
     if is_key {
@@ -151,10 +173,13 @@ fn get_field_kind_for_variant(
 
     // Currently, nested fields are unsupported.
     if is_nested {
-        diagnostics.push(PluginDiagnostic::error(
-            variant.stable_ptr().untyped(),
-            "Nested event fields are currently unsupported".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                variant.stable_ptr().untyped(),
+                "Nested event fields are currently unsupported".to_string(),
+            )
+            .with_error_code(error_code!("E0705")),
+        );
     }
 
     if is_flat {
@@ -163,10 +188,13 @@ fn get_field_kind_for_variant(
 
     // Currently, serde fields are unsupported.
     if is_serde {
-        diagnostics.push(PluginDiagnostic::error(
-            variant.stable_ptr().untyped(),
-            "Serde event fields are currently unsupported".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                variant.stable_ptr().untyped(),
+                "Serde event fields are currently unsupported".to_string(),
+            )
+            .with_error_code(error_code!("E0706")),
+        );
     }
 
     if is_key {
@@ -187,10 +215,13 @@ fn handle_enum(
     // TODO(spapini): Support generics.
     let generic_params = enum_ast.generic_params(db);
     let ast::OptionWrappedGenericParamList::Empty(_) = generic_params else {
-        diagnostics.push(PluginDiagnostic::error(
-            generic_params.stable_ptr().untyped(),
-            format!("{EVENT_TYPE_NAME} enums with generic arguments are unsupported"),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                generic_params.stable_ptr().untyped(),
+                format!("{EVENT_TYPE_NAME} enums with generic arguments are unsupported"),
+            )
+            .with_error_code(error_code!("E0707")),
+        );
         return None;
     };
 
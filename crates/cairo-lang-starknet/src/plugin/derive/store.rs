@@ -1,12 +1,14 @@
 use cairo_lang_defs::patcher::RewriteNode;
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::db::SyntaxGroup;
-use cairo_lang_syntax::node::helpers::QueryAttrs;
+use cairo_lang_syntax::node::helpers::{GenericParamEx, QueryAttrs};
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use indent::indent_by;
 use indoc::formatdoc;
+use itertools::Itertools;
 
-use crate::plugin::consts::STORE_TRAIT;
+use crate::plugin::consts::{STORAGE_NODE_ATTR, STORAGE_NODE_SUFFIX, STORE_TRAIT};
 
 /// Returns the rewrite node for the `#[derive(starknet::Store)]` attribute.
 pub fn handle_store_derive(
@@ -15,41 +17,50 @@ pub fn handle_store_derive(
     diagnostics: &mut Vec<PluginDiagnostic>,
 ) -> Option<RewriteNode> {
     match item_ast {
-        ast::ModuleItem::Struct(struct_ast) => handle_struct(db, struct_ast),
+        ast::ModuleItem::Struct(struct_ast) => handle_struct(db, struct_ast, diagnostics),
         ast::ModuleItem::Enum(enum_ast) => handle_enum(db, enum_ast, diagnostics),
         _ => None,
     }
 }
 
-/// Derive the `Store` trait for structs annotated with `derive(starknet::Store)`.
-fn handle_struct(db: &dyn SyntaxGroup, struct_ast: &ast::ItemStruct) -> Option<RewriteNode> {
+/// Derive the `Store` trait for structs annotated with `derive(starknet::Store)`, and the
+/// `StorageNodeTrait` for the ones also annotated with `#[storage_node]`.
+fn handle_struct(
+    db: &dyn SyntaxGroup,
+    struct_ast: &ast::ItemStruct,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+) -> Option<RewriteNode> {
     let mut reads_values = Vec::new();
     let mut reads_values_at_offset = Vec::new();
     let mut reads_fields = Vec::new();
     let mut writes = Vec::new();
     let mut writes_at_offset = Vec::new();
     let mut sizes = Vec::new();
-    for (i, field) in struct_ast.members(db).elements(db).iter().enumerate() {
-        let field_name = field.name(db).as_syntax_node().get_text_without_trivia(db);
-        let field_type = field.type_clause(db).ty(db).as_syntax_node().get_text_without_trivia(db);
+    let is_tuple = matches!(struct_ast.body(db), ast::StructBody::Tuple(_));
+    let members = struct_ast.members(db);
+    for (i, field) in members.iter().enumerate() {
+        let field_name = field.name(db);
+        // Positional members can't be used as variable names, so they are bound to prefixed ones.
+        let field_var = if is_tuple { format!("__{field_name}") } else { field_name.to_string() };
+        let field_type = field.type_expr(db).as_syntax_node().get_text_without_trivia(db);
 
         if i == 0 {
             reads_values.push(format!(
-                "let {field_name} = {STORE_TRAIT}::<{field_type}>::read(address_domain, base)?;"
+                "let {field_var} = {STORE_TRAIT}::<{field_type}>::read(address_domain, base)?;"
             ));
             reads_values_at_offset.push(format!(
-                "let {field_name} = {STORE_TRAIT}::<{field_type}>::read_at_offset(address_domain, \
+                "let {field_var} = {STORE_TRAIT}::<{field_type}>::read_at_offset(address_domain, \
                  base, offset)?;"
             ));
         } else {
             let subsequent_read = format!(
-                "let {field_name} = {STORE_TRAIT}::<{field_type}>::read_at_offset(address_domain, \
+                "let {field_var} = {STORE_TRAIT}::<{field_type}>::read_at_offset(address_domain, \
                  base, current_offset)?;"
             );
             reads_values.push(subsequent_read.clone());
             reads_values_at_offset.push(subsequent_read);
         }
-        if i < struct_ast.members(db).elements(db).len() - 1 {
+        if i < members.len() - 1 {
             if i == 0 {
                 reads_values.push(format!(
                     "let mut current_offset = {STORE_TRAIT}::<{field_type}>::size();"
@@ -65,7 +76,7 @@ fn handle_struct(db: &dyn SyntaxGroup, struct_ast: &ast::ItemStruct) -> Option<R
             }
         }
 
-        reads_fields.push(format!("{field_name},"));
+        reads_fields.push(format!("{field_var},"));
 
         if i == 0 {
             writes.push(format!(
@@ -84,7 +95,7 @@ fn handle_struct(db: &dyn SyntaxGroup, struct_ast: &ast::ItemStruct) -> Option<R
             writes_at_offset.push(subsequent_write);
         }
 
-        if i < struct_ast.members(db).elements(db).len() - 1 {
+        if i < members.len() - 1 {
             if i == 0 {
                 writes.push(format!(
                     "let mut current_offset = {STORE_TRAIT}::<{field_type}>::size();"
@@ -102,36 +113,41 @@ fn handle_struct(db: &dyn SyntaxGroup, struct_ast: &ast::ItemStruct) -> Option<R
         sizes.push(format!("{STORE_TRAIT}::<{field_type}>::size()"));
     }
 
+    let struct_name = struct_ast.name(db).as_syntax_node().get_text_without_trivia(db);
+    let generics = GenericParams::new(db, struct_ast.generic_params(db));
+    let full_struct_name = format!("{struct_name}{}", generics.format_args());
+    let reads_fields = reads_fields.join("\n                ");
+    let read_value = if is_tuple {
+        format!("{struct_name}(\n                {reads_fields}\n            )")
+    } else {
+        format!("{struct_name} {{\n                {reads_fields}\n            }}")
+    };
     let store_impl = formatdoc!(
         "
-        impl Store{struct_name} of {STORE_TRAIT}::<{struct_name}> {{
+        impl Store{struct_name}{impl_params} of {STORE_TRAIT}::<{full_struct_name}> {{
             fn read(address_domain: u32, base: starknet::storage_access::StorageBaseAddress) -> \
-         starknet::SyscallResult<{struct_name}> {{
+         starknet::SyscallResult<{full_struct_name}> {{
                 {reads_values}
                 starknet::SyscallResult::Ok(
-                    {struct_name} {{
-                        {reads_fields}
-                    }}
+                    {read_value}
                 )
             }}
             fn write(address_domain: u32, base: starknet::storage_access::StorageBaseAddress, \
-         value: {struct_name}) -> starknet::SyscallResult<()> {{
+         value: {full_struct_name}) -> starknet::SyscallResult<()> {{
                 {writes}
                 starknet::SyscallResult::Ok(())
             }}
             fn read_at_offset(address_domain: u32, base: \
          starknet::storage_access::StorageBaseAddress, offset: u8) -> \
-         starknet::SyscallResult<{struct_name}> {{
+         starknet::SyscallResult<{full_struct_name}> {{
                 {reads_values_at_offset}
                 starknet::SyscallResult::Ok(
-                    {struct_name} {{
-                        {reads_fields}
-                    }}
+                    {read_value}
                 )
             }}
             #[inline(always)]
             fn write_at_offset(address_domain: u32, base: \
-         starknet::storage_access::StorageBaseAddress, offset: u8, value: {struct_name}) -> \
+         starknet::storage_access::StorageBaseAddress, offset: u8, value: {full_struct_name}) -> \
          starknet::SyscallResult<()> {{
                 {writes_at_offset}
                 starknet::SyscallResult::Ok(())
@@ -142,16 +158,160 @@ fn handle_struct(db: &dyn SyntaxGroup, struct_ast: &ast::ItemStruct) -> Option<R
             }}
         }}
         ",
-        struct_name = struct_ast.name(db).as_syntax_node().get_text_without_trivia(db),
+        impl_params = generics.format_impl_params(&[STORE_TRAIT, "Drop"]),
         reads_values_at_offset = reads_values_at_offset.join("\n        "),
         reads_values = reads_values.join("\n        "),
-        reads_fields = reads_fields.join("\n                "),
         writes = writes.join("\n        "),
         writes_at_offset = writes_at_offset.join("\n        "),
         sizes = if sizes.is_empty() { "0".to_string() } else { sizes.join(" +\n        ") }
     );
 
-    Some(RewriteNode::Text(store_impl))
+    let Some(storage_node_attr) = struct_ast.find_attr(db, STORAGE_NODE_ATTR) else {
+        return Some(RewriteNode::Text(store_impl));
+    };
+    if is_tuple {
+        diagnostics.push(
+            PluginDiagnostic::error(
+                storage_node_attr.stable_ptr().untyped(),
+                format!("`#[{STORAGE_NODE_ATTR}]` is not supported for tuple structs."),
+            )
+            .with_error_code(error_code!("E0708")),
+        );
+        return Some(RewriteNode::Text(store_impl));
+    }
+    let storage_node_impl = handle_struct_storage_node(db, struct_ast, &generics);
+    Some(RewriteNode::Text(format!("{store_impl}{storage_node_impl}")))
+}
+
+/// Generates the storage node of a struct annotated with `#[storage_node]` - a struct with a
+/// `StorageNodeMember` for each member, which is accessed at the offset of the member in the
+/// `Store` layout of the struct. The node is the `Node` type of the `HasStorageNode` impl of the
+/// struct, so it is reachable wherever the struct is.
+fn handle_struct_storage_node(
+    db: &dyn SyntaxGroup,
+    struct_ast: &ast::ItemStruct,
+    generics: &GenericParams,
+) -> String {
+    let struct_name = struct_ast.name(db).as_syntax_node().get_text_without_trivia(db);
+    let node_name = format!("{struct_name}{STORAGE_NODE_SUFFIX}");
+    // The node has the generic params of the struct, so the members of a generic struct are
+    // accessed through the node of its concrete type.
+    let generic_params = struct_ast.generic_params(db).as_syntax_node().get_text_without_trivia(db);
+    let full_struct_name = format!("{struct_name}{}", generics.format_args());
+    let full_node_name = format!("{node_name}{}", generics.format_args());
+    let impl_params = generics.format_impl_params(&[]);
+    // The node has the visibility of the struct, so it is accessible wherever the struct is.
+    let visibility = match struct_ast.visibility(db) {
+        ast::Visibility::Default(_) => "".to_string(),
+        visibility => format!("{} ", visibility.as_syntax_node().get_text_without_trivia(db)),
+    };
+    let mut node_members = Vec::new();
+    let mut node_member_inits = Vec::new();
+    // The offset of a member is only computed when it is accessed, so creating the node is free.
+    let mut member_offsets = Vec::new();
+    let members = struct_ast.members(db);
+    for (i, field) in members.iter().enumerate() {
+        let field_name = field.name(db);
+        let field_type = field.type_expr(db).as_syntax_node().get_text_without_trivia(db);
+        node_members.push(format!(
+            "pub {field_name}: starknet::storage::StorageNodeMember<{full_node_name}, \
+             {field_type}>,"
+        ));
+        node_member_inits.push(format!(
+            "{field_name}: starknet::storage::StorageNodeMember {{ address, index: {i} }},"
+        ));
+        if i == members.len() - 1 {
+            member_offsets.push(if i == 0 { "0_u8" } else { "offset" }.to_string());
+            break;
+        }
+        if i == 0 {
+            member_offsets.push("if index == 0 {\n    return 0_u8;\n}".to_string());
+            let mutability = if members.len() > 2 { "mut " } else { "" };
+            member_offsets
+                .push(format!("let {mutability}offset = {STORE_TRAIT}::<{field_type}>::size();"));
+        } else {
+            member_offsets.push(format!("if index == {i} {{\n    return offset;\n}}"));
+            member_offsets.push(format!("offset += {STORE_TRAIT}::<{field_type}>::size();"));
+        }
+    }
+    if member_offsets.is_empty() {
+        member_offsets.push("0_u8".to_string());
+    }
+
+    formatdoc!(
+        "
+        {visibility}struct {node_name}{generic_params} {{
+            {node_members}
+        }}
+        impl {node_name}Copy{impl_params} of Copy<{full_node_name}>;
+        impl {node_name}Drop{impl_params} of Drop<{full_node_name}>;
+        impl {struct_name}HasStorageNode{impl_params} of \
+         starknet::storage::HasStorageNode<{full_struct_name}> {{
+            type Node = {full_node_name};
+        }}
+        impl StorageNode{struct_name}{store_impl_params} of \
+         starknet::storage::StorageNodeTrait<{full_node_name}> {{
+            #[inline(always)]
+            fn new(address: starknet::storage_access::StorageBaseAddress) -> {full_node_name} {{
+                {node_name} {{
+                    {node_member_inits}
+                }}
+            }}
+            fn member_offset(index: u8) -> u8 {{
+                {member_offsets}
+            }}
+        }}
+        ",
+        store_impl_params = generics.format_impl_params(&[STORE_TRAIT]),
+        node_members = node_members.join("\n    "),
+        node_member_inits = node_member_inits.join("\n            "),
+        member_offsets = indent_by(8, member_offsets.join("\n")).trim_start(),
+    )
+}
+
+/// The generic params of a struct deriving `starknet::Store`.
+struct GenericParams {
+    /// The names of the params, in their order of declaration.
+    names: Vec<String>,
+    /// The declarations of the params, in their order of declaration.
+    declarations: Vec<String>,
+    /// The names of the type params.
+    type_names: Vec<String>,
+}
+impl GenericParams {
+    fn new(db: &dyn SyntaxGroup, generic_params: ast::OptionWrappedGenericParamList) -> Self {
+        let mut generics = Self { names: vec![], declarations: vec![], type_names: vec![] };
+        if let ast::OptionWrappedGenericParamList::WrappedGenericParamList(params) = generic_params
+        {
+            for param in params.generic_params(db).elements(db) {
+                let name = param.name(db).map_or_else(|| "_".into(), |name| name.text(db));
+                generics.names.push(name.to_string());
+                if let ast::GenericParam::Type(_) = param {
+                    generics.type_names.push(name.to_string());
+                }
+                generics.declarations.push(param.as_syntax_node().get_text_without_trivia(db));
+            }
+        }
+        generics
+    }
+
+    /// Formats the generic args of the struct type, e.g. `<T>`. Empty for a non generic struct.
+    fn format_args(&self) -> String {
+        if self.names.is_empty() { "".to_string() } else { format!("<{}>", self.names.join(", ")) }
+    }
+
+    /// Formats the generic params of an impl for the struct type, requiring an impl of each of
+    /// `traits` for each type param, e.g. `<T, +Drop<T>>`. Empty for a non generic struct.
+    fn format_impl_params(&self, traits: &[&str]) -> String {
+        if self.names.is_empty() {
+            return "".to_string();
+        }
+        let bounds = self
+            .type_names
+            .iter()
+            .flat_map(|name| traits.iter().map(move |tr| format!("+{tr}<{name}>")));
+        format!("<{}>", self.declarations.iter().cloned().chain(bounds).join(", "))
+    }
 }
 
 /// Derive the `starknet::Store` trait for enums annotated with `derive(starknet::Store)`.
@@ -173,10 +333,13 @@ fn handle_enum(
     for (i, variant) in enum_ast.variants(db).elements(db).iter().enumerate() {
         let indicator = if variant.attributes(db).has_attr(db, "default") {
             if default_index.is_some() {
-                diagnostics.push(PluginDiagnostic::error(
-                    variant.stable_ptr().untyped(),
-                    "Multiple variants annotated with `#[default]`".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        variant.stable_ptr().untyped(),
+                        "Multiple variants annotated with `#[default]`".to_string(),
+                    )
+                    .with_error_code(error_code!("E0709")),
+                );
                 return None;
             }
             default_index = Some(i);
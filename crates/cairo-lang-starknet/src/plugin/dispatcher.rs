@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{PluginDiagnostic, PluginGeneratedFile, PluginResult};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::ast::{self, MaybeTraitBody, OptionReturnTypeClause};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::{BodyItems, QueryAttrs};
@@ -19,13 +20,16 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
     if trait_ast.has_attr(db, DEPRECATED_ABI_ATTR) {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic::error(
-                trait_ast.stable_ptr().untyped(),
-                format!(
-                    "The '{DEPRECATED_ABI_ATTR}' attribute for traits was deprecated, please use \
-                     `{INTERFACE_ATTR}` instead.",
-                ),
-            )],
+            diagnostics: vec![
+                PluginDiagnostic::error(
+                    trait_ast.stable_ptr().untyped(),
+                    format!(
+                        "The '{DEPRECATED_ABI_ATTR}' attribute for traits was deprecated, please \
+                         use `{INTERFACE_ATTR}` instead.",
+                    ),
+                )
+                .with_error_code(error_code!("E0710")),
+            ],
             remove_original_item: false,
         };
     }
@@ -37,10 +41,13 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
         MaybeTraitBody::None(empty_body) => {
             return PluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    empty_body.stable_ptr().untyped(),
-                    "Starknet interfaces without body are not supported.".to_string(),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        empty_body.stable_ptr().untyped(),
+                        "Starknet interfaces without body are not supported.".to_string(),
+                    )
+                    .with_error_code(error_code!("E0711")),
+                ],
                 remove_original_item: false,
             };
         }
@@ -60,11 +67,14 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
     }) else {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic::error(
-                generic_params.stable_ptr().untyped(),
-                "Starknet interfaces must have exactly one generic parameter, which is a type."
-                    .to_string(),
-            )],
+            diagnostics: vec![
+                PluginDiagnostic::error(
+                    generic_params.stable_ptr().untyped(),
+                    "Starknet interfaces must have exactly one generic parameter, which is a type."
+                        .to_string(),
+                )
+                .with_error_code(error_code!("E0712")),
+            ],
             remove_original_item: false,
         };
     };
@@ -94,17 +104,24 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                 let mut params = signature.parameters(db).elements(db).into_iter();
                 // The first parameter is the `self` parameter.
                 let Some(self_param) = params.next() else {
-                    diagnostics.push(PluginDiagnostic::error(
-                        declaration.stable_ptr().untyped(),
-                        "`starknet::interface` functions must have a `self` parameter.".to_string(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            declaration.stable_ptr().untyped(),
+                            "`starknet::interface` functions must have a `self` parameter."
+                                .to_string(),
+                        )
+                        .with_error_code(error_code!("E0713")),
+                    );
                     continue;
                 };
                 if self_param.name(db).text(db) != "self" {
-                    diagnostics.push(PluginDiagnostic::error(
-                        self_param.stable_ptr().untyped(),
-                        "The first parameter must be named `self`.".to_string(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            self_param.stable_ptr().untyped(),
+                            "The first parameter must be named `self`.".to_string(),
+                        )
+                        .with_error_code(error_code!("E0714")),
+                    );
                     skip_generation = true;
                 }
                 let self_param_type_ok = if self_param.is_ref_param(db) {
@@ -115,12 +132,15 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                     false
                 };
                 if !self_param_type_ok {
-                    diagnostics.push(PluginDiagnostic::error(
-                        self_param.stable_ptr().untyped(),
-                        "`starknet::interface` function first parameter must be a reference to \
-                         the trait's generic parameter or a snapshot of it."
-                            .to_string(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            self_param.stable_ptr().untyped(),
+                            "`starknet::interface` function first parameter must be a reference \
+                             to the trait's generic parameter or a snapshot of it."
+                                .to_string(),
+                        )
+                        .with_error_code(error_code!("E0715")),
+                    );
                     skip_generation = true;
                 }
 
@@ -128,31 +148,40 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
                     if param.is_ref_param(db) {
                         skip_generation = true;
 
-                        diagnostics.push(PluginDiagnostic::error(
-                            param.modifiers(db).stable_ptr().untyped(),
-                            "`starknet::interface` functions don't support `ref` parameters other \
-                             than the first one."
-                                .to_string(),
-                        ))
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                param.modifiers(db).stable_ptr().untyped(),
+                                "`starknet::interface` functions don't support `ref` parameters \
+                                 other than the first one."
+                                    .to_string(),
+                            )
+                            .with_error_code(error_code!("E0716")),
+                        )
                     }
                     if param.type_clause(db).ty(db).is_dependent_type(db, &single_generic_param) {
                         skip_generation = true;
 
-                        diagnostics.push(PluginDiagnostic::error(
-                            param.type_clause(db).ty(db).stable_ptr().untyped(),
-                            "`starknet::interface` functions don't support parameters that depend \
-                             on the trait's generic param type."
-                                .to_string(),
-                        ))
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                param.type_clause(db).ty(db).stable_ptr().untyped(),
+                                "`starknet::interface` functions don't support parameters that \
+                                 depend on the trait's generic param type."
+                                    .to_string(),
+                            )
+                            .with_error_code(error_code!("E0717")),
+                        )
                     }
 
                     if param.name(db).text(db) == CALLDATA_PARAM_NAME {
                         skip_generation = true;
 
-                        diagnostics.push(PluginDiagnostic::error(
-                            param.name(db).stable_ptr().untyped(),
-                            "Parameter name `__calldata__` cannot be used.".to_string(),
-                        ))
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                param.name(db).stable_ptr().untyped(),
+                                "Parameter name `__calldata__` cannot be used.".to_string(),
+                            )
+                            .with_error_code(error_code!("E0718")),
+                        )
                     }
 
                     let param_type = param.type_clause(db).ty(db);
@@ -245,24 +274,33 @@ pub fn handle_trait(db: &dyn SyntaxGroup, trait_ast: ast::ItemTrait) -> PluginRe
             // Ignore the missing item.
             ast::TraitItem::Missing(_) => {}
             ast::TraitItem::Type(ty) => {
-                diagnostics.push(PluginDiagnostic::error(
-                    ty.type_kw(db).stable_ptr().untyped(),
-                    "`starknet::interface` does not yet support type items.".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        ty.type_kw(db).stable_ptr().untyped(),
+                        "`starknet::interface` does not yet support type items.".to_string(),
+                    )
+                    .with_error_code(error_code!("E0719")),
+                );
                 continue;
             }
             ast::TraitItem::Constant(constant) => {
-                diagnostics.push(PluginDiagnostic::error(
-                    constant.const_kw(db).stable_ptr().untyped(),
-                    "`starknet::interface` does not yet support constant items.".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        constant.const_kw(db).stable_ptr().untyped(),
+                        "`starknet::interface` does not yet support constant items.".to_string(),
+                    )
+                    .with_error_code(error_code!("E0720")),
+                );
                 continue;
             }
             ast::TraitItem::Impl(imp) => {
-                diagnostics.push(PluginDiagnostic::error(
-                    imp.impl_kw(db).stable_ptr().untyped(),
-                    "`starknet::interface` does not yet support impl items.".to_string(),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        imp.impl_kw(db).stable_ptr().untyped(),
+                        "`starknet::interface` does not yet support impl items.".to_string(),
+                    )
+                    .with_error_code(error_code!("E0721")),
+                );
                 continue;
             }
         }
@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::RewriteNode;
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::consts::IMPLICIT_PRECEDENCE_ATTR;
 use cairo_lang_syntax::node::ast::{
     self, FunctionWithBody, OptionReturnTypeClause, OptionWrappedGenericParamList,
@@ -143,19 +144,25 @@ pub fn handle_entry_point(
     let declaration = item_function.declaration(db);
     let name_node = declaration.name(db);
     if entry_point_kind == EntryPointKind::Constructor && name_node.text(db) != CONSTRUCTOR_NAME {
-        diagnostics.push(PluginDiagnostic::error(
-            name_node.stable_ptr().untyped(),
-            format!("The constructor function must be called `{CONSTRUCTOR_NAME}`."),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                name_node.stable_ptr().untyped(),
+                format!("The constructor function must be called `{CONSTRUCTOR_NAME}`."),
+            )
+            .with_error_code(error_code!("E0725")),
+        );
     }
 
     if let OptionWrappedGenericParamList::WrappedGenericParamList(generic_params) =
         declaration.generic_params(db)
     {
-        diagnostics.push(PluginDiagnostic::error(
-            generic_params.stable_ptr().untyped(),
-            "Contract entry points cannot have generic arguments".to_string(),
-        ))
+        diagnostics.push(
+            PluginDiagnostic::error(
+                generic_params.stable_ptr().untyped(),
+                "Contract entry points cannot have generic arguments".to_string(),
+            )
+            .with_error_code(error_code!("E0726")),
+        )
     }
 
     let mut declaration_node = RewriteNode::new_trimmed(declaration.as_syntax_node());
@@ -229,16 +236,22 @@ fn generate_entry_point_wrapper(
     let mut ref_appends = Vec::new();
 
     let Some((0, first_param)) = params.next() else {
-        return Err(vec![PluginDiagnostic::error(
-            sig.stable_ptr().untyped(),
-            "The first parameter of an entry point must be `self`.".into(),
-        )]);
+        return Err(vec![
+            PluginDiagnostic::error(
+                sig.stable_ptr().untyped(),
+                "The first parameter of an entry point must be `self`.".into(),
+            )
+            .with_error_code(error_code!("E0727")),
+        ]);
     };
     if first_param.name(db).text(db) != "self" {
-        return Err(vec![PluginDiagnostic::error(
-            first_param.stable_ptr().untyped(),
-            "The first parameter of an entry point must be `self`.".into(),
-        )]);
+        return Err(vec![
+            PluginDiagnostic::error(
+                first_param.stable_ptr().untyped(),
+                "The first parameter of an entry point must be `self`.".into(),
+            )
+            .with_error_code(error_code!("E0727")),
+        ]);
     };
     let is_snapshot = matches!(first_param.type_clause(db).ty(db), ast::Expr::Unary(_));
     // TODO(spapini): Check modifiers and type.
@@ -251,10 +264,13 @@ fn generate_entry_point_wrapper(
 
         let is_ref = param.is_ref_param(db);
         if raw_output && is_ref {
-            diagnostics.push(PluginDiagnostic::error(
-                param.modifiers(db).stable_ptr().untyped(),
-                format!("`{RAW_OUTPUT_ATTR}` functions cannot have `ref` parameters."),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    param.modifiers(db).stable_ptr().untyped(),
+                    format!("`{RAW_OUTPUT_ATTR}` functions cannot have `ref` parameters."),
+                )
+                .with_error_code(error_code!("E0728")),
+            );
         }
         let ref_modifier = if is_ref { "ref " } else { "" };
         arg_names.push(format!("{ref_modifier}{arg_name}"));
@@ -296,10 +312,13 @@ fn generate_entry_point_wrapper(
     };
 
     if raw_output && !return_ty_is_felt252_span {
-        diagnostics.push(PluginDiagnostic::error(
-            ret_type_ptr,
-            format!("`{RAW_OUTPUT_ATTR}` functions must return `Span::<felt252>`."),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                ret_type_ptr,
+                format!("`{RAW_OUTPUT_ATTR}` functions must return `Span::<felt252>`."),
+            )
+            .with_error_code(error_code!("E0729")),
+        );
     }
 
     if !diagnostics.is_empty() {
@@ -370,25 +389,35 @@ fn validate_l1_handler_first_parameter(
     if let Some(first_param) = params.elements(db).get(1) {
         // Validate type
         if !first_param.type_clause(db).ty(db).is_felt252(db) {
-            diagnostics.push(PluginDiagnostic::error(
-                first_param.stable_ptr().untyped(),
-                "The second parameter of an L1 handler must be of type `felt252`.".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    first_param.stable_ptr().untyped(),
+                    "The second parameter of an L1 handler must be of type `felt252`.".to_string(),
+                )
+                .with_error_code(error_code!("E0730")),
+            );
         }
 
         // Validate name
         if maybe_strip_underscore(first_param.name(db).text(db).as_str())
             != L1_HANDLER_FIRST_PARAM_NAME
         {
-            diagnostics.push(PluginDiagnostic::error(
-                first_param.stable_ptr().untyped(),
-                "The second parameter of an L1 handler must be named 'from_address'.".to_string(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    first_param.stable_ptr().untyped(),
+                    "The second parameter of an L1 handler must be named 'from_address'."
+                        .to_string(),
+                )
+                .with_error_code(error_code!("E0731")),
+            );
         }
     } else {
-        diagnostics.push(PluginDiagnostic::error(
-            params.stable_ptr().untyped(),
-            "An L1 handler must have the 'from_address' as its second parameter.".to_string(),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                params.stable_ptr().untyped(),
+                "An L1 handler must have the 'from_address' as its second parameter.".to_string(),
+            )
+            .with_error_code(error_code!("E0732")),
+        );
     };
 }
@@ -1,5 +1,6 @@
 use cairo_lang_defs::db::get_all_path_leaves;
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_starknet_classes::abi::EventFieldKind;
 use cairo_lang_syntax::attribute::structured::{
     AttributeArg, AttributeArgVariant, AttributeStructurize,
@@ -75,14 +76,17 @@ pub fn get_starknet_event_variants(
                 let stable_ptr = &leaf.stable_ptr();
                 if stable_ptr.identifier(db) == EVENT_TYPE_NAME {
                     if !item.has_attr(db, EVENT_ATTR) {
-                        diagnostics.push(PluginDiagnostic::error(
-                            stable_ptr.untyped(),
-                            format!(
-                                "{} type that is named `{EVENT_TYPE_NAME}` must be marked with \
-                                 #[{EVENT_ATTR}].",
-                                module_kind.to_str_capital()
-                            ),
-                        ));
+                        diagnostics.push(
+                            PluginDiagnostic::error(
+                                stable_ptr.untyped(),
+                                format!(
+                                    "{} type that is named `{EVENT_TYPE_NAME}` must be marked \
+                                     with #[{EVENT_ATTR}].",
+                                    module_kind.to_str_capital()
+                                ),
+                            )
+                            .with_error_code(error_code!("E0733")),
+                        );
                     }
                     return Some(vec![]);
                 }
@@ -95,25 +99,31 @@ pub fn get_starknet_event_variants(
 
     match (has_event_attr, has_event_name) {
         (true, false) => {
-            diagnostics.push(PluginDiagnostic::error(
-                stable_ptr,
-                format!(
-                    "{} type that is marked with #[{EVENT_ATTR}] must be named \
-                     `{EVENT_TYPE_NAME}`.",
-                    module_kind.to_str_capital()
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    stable_ptr,
+                    format!(
+                        "{} type that is marked with #[{EVENT_ATTR}] must be named \
+                         `{EVENT_TYPE_NAME}`.",
+                        module_kind.to_str_capital()
+                    ),
+                )
+                .with_error_code(error_code!("E0734")),
+            );
             None
         }
         (false, true) => {
-            diagnostics.push(PluginDiagnostic::error(
-                stable_ptr,
-                format!(
-                    "{} type that is named `{EVENT_TYPE_NAME}` must be marked with \
-                     #[{EVENT_ATTR}].",
-                    module_kind.to_str_capital()
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    stable_ptr,
+                    format!(
+                        "{} type that is named `{EVENT_TYPE_NAME}` must be marked with \
+                         #[{EVENT_ATTR}].",
+                        module_kind.to_str_capital()
+                    ),
+                )
+                .with_error_code(error_code!("E0733")),
+            );
             // The attribute is missing, but this counts as a event - we can't create another
             // (empty) event.
             Some(variants)
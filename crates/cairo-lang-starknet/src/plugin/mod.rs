@@ -15,7 +15,7 @@ mod dispatcher;
 mod embeddable;
 mod entry_point;
 pub mod events;
-mod starknet_module;
+pub(crate) mod starknet_module;
 mod storage;
 mod utils;
 
@@ -79,6 +79,7 @@ impl MacroPlugin for StarkNetPlugin {
             NESTED_ATTR.to_string(),
             RAW_OUTPUT_ATTR.to_string(),
             STORAGE_ATTR.to_string(),
+            STORAGE_NODE_ATTR.to_string(),
             SUBSTORAGE_ATTR.to_string(),
         ]
     }
@@ -1,5 +1,6 @@
 use cairo_lang_defs::patcher::RewriteNode;
 use cairo_lang_defs::plugin::{MacroPluginMetadata, PluginDiagnostic};
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_plugins::plugins::HasItemsInCfgEx;
 use cairo_lang_syntax::attribute::structured::{AttributeArg, AttributeArgVariant};
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -33,8 +34,9 @@ impl ComponentSpecificGenerationData {
         RewriteNode::interpolate_patched(
             indoc! {"
             use starknet::storage::{
-                StorageMapMemberAddressTrait, StorageMemberAddressTrait,
-                StorageMapMemberAccessTrait, StorageMemberAccessTrait,
+                StorageMapMemberAddressTrait, StorageMemberAddressTrait, StorageVecMemberAddressTrait,
+                StorageMapMemberAccessTrait, StorageMemberAccessTrait, StorageVecMemberAccessTrait,
+                StorageNodeMemberAccessTrait,
             };
             $has_component_trait$
 
@@ -126,6 +128,7 @@ fn get_embeddable_as_impl_generic_params(
                  `TContractState`."
             ),
         )
+        .with_error_code(error_code!("E0735"))
     };
 
     let ast::OptionWrappedGenericParamList::WrappedGenericParamList(params) = generic_params else {
@@ -154,7 +157,8 @@ fn get_embeddable_as_impl_generic_params(
                 "An impl with #[{EMBEDDABLE_AS_ATTR}] should have a generic parameter which is an \
                  impl of `{HAS_COMPONENT_TRAIT}<{GENERIC_CONTRACT_STATE_NAME}>`."
             ),
-        ));
+        )
+        .with_error_code(error_code!("E0736")));
     }
 
     Ok(generic_params_node)
@@ -180,13 +184,16 @@ impl EmbeddableAsImplParams {
         attr: ast::Attribute,
     ) -> Option<EmbeddableAsImplParams> {
         let Some(attr_arg_value) = get_embeddable_as_attr_value(db, &attr) else {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.stable_ptr().untyped(),
-                format!(
-                    "`{EMBEDDABLE_AS_ATTR}` attribute must have a single unnamed argument for the \
-                     generated impl name, e.g.: #[{EMBEDDABLE_AS_ATTR}(MyImpl)]."
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.stable_ptr().untyped(),
+                    format!(
+                        "`{EMBEDDABLE_AS_ATTR}` attribute must have a single unnamed argument for \
+                         the generated impl name, e.g.: #[{EMBEDDABLE_AS_ATTR}(MyImpl)]."
+                    ),
+                )
+                .with_error_code(error_code!("E0737")),
+            );
             return None;
         };
 
@@ -203,10 +210,15 @@ impl EmbeddableAsImplParams {
         let impl_body = match item_impl.body(db) {
             ast::MaybeImplBody::Some(impl_body) => impl_body,
             ast::MaybeImplBody::None(semicolon) => {
-                diagnostics.push(PluginDiagnostic::error(
-                    semicolon.stable_ptr().untyped(),
-                    format!("`{EMBEDDABLE_AS_ATTR}` attribute is not supported for empty impls."),
-                ));
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        semicolon.stable_ptr().untyped(),
+                        format!(
+                            "`{EMBEDDABLE_AS_ATTR}` attribute is not supported for empty impls."
+                        ),
+                    )
+                    .with_error_code(error_code!("E0738")),
+                );
                 return None;
             }
         };
@@ -235,14 +247,17 @@ fn handle_component_impl(
         if param.is_impl_of(db, "Destruct", GENERIC_CONTRACT_STATE_NAME)
             || param.is_impl_of(db, "PanicDestruct", GENERIC_CONTRACT_STATE_NAME)
         {
-            diagnostics.push(PluginDiagnostic::error(
-                param.stable_ptr().untyped(),
-                format!(
-                    "`embeddable_as` impls can't have impl generic parameters of \
-                     `Destruct<{GENERIC_CONTRACT_STATE_NAME}>` or \
-                     `PanicDestruct<{GENERIC_CONTRACT_STATE_NAME}>`."
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    param.stable_ptr().untyped(),
+                    format!(
+                        "`embeddable_as` impls can't have impl generic parameters of \
+                         `Destruct<{GENERIC_CONTRACT_STATE_NAME}>` or \
+                         `PanicDestruct<{GENERIC_CONTRACT_STATE_NAME}>`."
+                    ),
+                )
+                .with_error_code(error_code!("E0739")),
+            );
             return;
         }
     }
@@ -339,27 +354,33 @@ fn handle_component_embeddable_as_impl_item(
     let function_name = RewriteNode::new_trimmed(declaration.name(db).as_syntax_node());
     let parameters_elements = parameters.elements(db);
     let Some((first_param, rest_params)) = parameters_elements.split_first() else {
-        diagnostics.push(PluginDiagnostic::error(
-            parameters.stable_ptr().untyped(),
-            format!(
-                "A function in an #[{EMBEDDABLE_AS_ATTR}] impl in a component must have a first \
-                 `self` parameter."
-            ),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                parameters.stable_ptr().untyped(),
+                format!(
+                    "A function in an #[{EMBEDDABLE_AS_ATTR}] impl in a component must have a \
+                     first `self` parameter."
+                ),
+            )
+            .with_error_code(error_code!("E0740")),
+        );
         return None;
     };
     let Some((self_param, get_component_call, callsite_modifier)) =
         handle_first_param_for_embeddable_as(db, first_param)
     else {
-        diagnostics.push(PluginDiagnostic::error(
-            parameters.stable_ptr().untyped(),
-            format!(
-                "The first parameter of a function in an #[{EMBEDDABLE_AS_ATTR}] impl in a \
-                 component must be either `self: @{GENERIC_COMPONENT_STATE_NAME}` (for view \
-                 functions) or `ref self: {GENERIC_COMPONENT_STATE_NAME}` (for external \
-                 functions)."
-            ),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                parameters.stable_ptr().untyped(),
+                format!(
+                    "The first parameter of a function in an #[{EMBEDDABLE_AS_ATTR}] impl in a \
+                     component must be either `self: @{GENERIC_COMPONENT_STATE_NAME}` (for view \
+                     functions) or `ref self: {GENERIC_COMPONENT_STATE_NAME}` (for external \
+                     functions)."
+                ),
+            )
+            .with_error_code(error_code!("E0741")),
+        );
         return None;
     };
     let rest_params_node = RewriteNode::new_modified(
@@ -6,6 +6,7 @@ use cairo_lang_defs::plugin::{
     DynGeneratedFileAuxData, MacroPluginMetadata, PluginDiagnostic, PluginGeneratedFile,
     PluginResult,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_plugins::plugins::HasItemsInCfgEx;
 use cairo_lang_syntax::node::ast::MaybeModuleBody;
 use cairo_lang_syntax::node::db::SyntaxGroup;
@@ -96,13 +97,16 @@ pub(super) fn handle_module(db: &dyn SyntaxGroup, module_ast: ast::ItemModule) -
     if module_ast.has_attr(db, DEPRECATED_CONTRACT_ATTR) {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic::error(
-                module_ast.stable_ptr().untyped(),
-                format!(
-                    "The '{DEPRECATED_CONTRACT_ATTR}' attribute was deprecated, please use \
-                     `{CONTRACT_ATTR}` instead.",
-                ),
-            )],
+            diagnostics: vec![
+                PluginDiagnostic::error(
+                    module_ast.stable_ptr().untyped(),
+                    format!(
+                        "The '{DEPRECATED_CONTRACT_ATTR}' attribute was deprecated, please use \
+                         `{CONTRACT_ATTR}` instead.",
+                    ),
+                )
+                .with_error_code(error_code!("E0752")),
+            ],
             remove_original_item: false,
         };
     }
@@ -122,10 +126,13 @@ fn validate_module(
     let MaybeModuleBody::Some(body) = module_ast.body(db) else {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic::error(
-                module_ast.stable_ptr().untyped(),
-                format!("{module_kind_str}s without body are not supported."),
-            )],
+            diagnostics: vec![
+                PluginDiagnostic::error(
+                    module_ast.stable_ptr().untyped(),
+                    format!("{module_kind_str}s without body are not supported."),
+                )
+                .with_error_code(error_code!("E0753")),
+            ],
             remove_original_item: false,
         };
     };
@@ -137,7 +144,7 @@ fn validate_module(
             diagnostics: vec![PluginDiagnostic::error(
                  module_ast.stable_ptr().untyped(),
                  format!("{module_kind_str}s must define a '{STORAGE_STRUCT_NAME}' struct."),
-            )],
+            ).with_error_code(error_code!("E0754"))],
             remove_original_item: false,
         };
     };
@@ -145,10 +152,15 @@ fn validate_module(
     if !storage_struct_ast.has_attr(db, STORAGE_ATTR) {
         return PluginResult {
             code: None,
-            diagnostics: vec![PluginDiagnostic::error(
-                storage_struct_ast.stable_ptr().untyped(),
-                format!("'{STORAGE_STRUCT_NAME}' struct must be annotated with #[{STORAGE_ATTR}]."),
-            )],
+            diagnostics: vec![
+                PluginDiagnostic::error(
+                    storage_struct_ast.stable_ptr().untyped(),
+                    format!(
+                        "'{STORAGE_STRUCT_NAME}' struct must be annotated with #[{STORAGE_ATTR}]."
+                    ),
+                )
+                .with_error_code(error_code!("E0755")),
+            ],
             remove_original_item: false,
         };
     }
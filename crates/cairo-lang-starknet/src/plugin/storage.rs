@@ -1,14 +1,16 @@
 use cairo_lang_defs::patcher::RewriteNode;
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_starknet_classes::keccak::starknet_keccak;
 use cairo_lang_syntax::node::db::SyntaxGroup;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::{ast, Terminal, TypedSyntaxNode};
 use cairo_lang_utils::try_extract_matches;
 use indoc::formatdoc;
 
 use super::consts::{
     CONCRETE_COMPONENT_STATE_NAME, CONTRACT_STATE_NAME, LEGACY_STORAGE_MAPPING, STORAGE_MAPPING,
-    STORAGE_STRUCT_NAME, STORE_TRAIT, SUBSTORAGE_ATTR,
+    STORAGE_NODE_ATTR, STORAGE_STRUCT_NAME, STORAGE_VEC, STORE_TRAIT, SUBSTORAGE_ATTR,
 };
 use super::starknet_module::generation_data::StarknetModuleCommonGenerationData;
 use super::starknet_module::StarknetModuleKind;
@@ -31,7 +33,17 @@ pub fn handle_storage_struct(
     let full_generic_arg_str = starknet_module_kind.get_full_generic_arg_str();
     let full_state_struct_name = starknet_module_kind.get_full_state_struct_name();
 
-    for member in struct_ast.members(db).elements(db) {
+    for member in struct_ast.members(db) {
+        let ast::StructMember::Named(member) = member else {
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    member.stable_ptr().untyped(),
+                    format!("`{STORAGE_STRUCT_NAME}` struct members must be named."),
+                )
+                .with_error_code(error_code!("E0756")),
+            );
+            continue;
+        };
         let member_code_pieces = get_storage_member_code(
             db,
             diagnostics,
@@ -111,20 +123,73 @@ fn get_storage_member_code(
                 module_code: None,
             };
         } else {
-            diagnostics.push(PluginDiagnostic::error(
-                member.stable_ptr().untyped(),
-                format!(
-                    "`{SUBSTORAGE_ATTR}` attribute is only allowed for members of type \
-                     [some_path::]{STORAGE_STRUCT_NAME}`"
-                ),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    member.stable_ptr().untyped(),
+                    format!(
+                        "`{SUBSTORAGE_ATTR}` attribute is only allowed for members of type \
+                         [some_path::]{STORAGE_STRUCT_NAME}`"
+                    ),
+                )
+                .with_error_code(error_code!("E0757")),
+            );
             return Default::default();
         }
     }
 
+    if member.has_attr(db, STORAGE_NODE_ATTR) {
+        return get_storage_node_member_code(db, diagnostics, &member);
+    }
+
     get_simple_storage_member_code(db, diagnostics, member, starknet_module_kind, extra_uses_node)
 }
 
+/// Returns the relevant code for a storage member annotated with `#[storage_node]`, whose type is a
+/// struct deriving `starknet::Store` in storage node mode. The member of the State struct is the
+/// storage node of the struct, reached through the `HasStorageNode` impl of the struct, so each of
+/// its members is accessed separately.
+fn get_storage_node_member_code(
+    db: &dyn SyntaxGroup,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    member: &ast::Member,
+) -> StorageMemberCodePieces {
+    let type_ast = member.type_clause(db).ty(db);
+    if !matches!(type_ast, ast::Expr::Path(_)) {
+        diagnostics.push(
+            PluginDiagnostic::error(
+                type_ast.stable_ptr().untyped(),
+                format!(
+                    "`{STORAGE_NODE_ATTR}` attribute is only allowed for members of a struct type \
+                     annotated with `#[{STORAGE_NODE_ATTR}]`."
+                ),
+            )
+            .with_error_code(error_code!("E0758")),
+        );
+        return Default::default();
+    }
+    let name = member.name(db).text(db);
+    let address = format!("0x{:x}", starknet_keccak(name.as_bytes()));
+    let patches = [
+        ("name".to_string(), RewriteNode::new_trimmed(member.name(db).as_syntax_node())),
+        ("type".to_string(), RewriteNode::new_trimmed(type_ast.as_syntax_node())),
+    ];
+    StorageMemberCodePieces {
+        member_code: Some(RewriteNode::interpolate_patched(
+            "\n        pub $name$: starknet::storage::HasStorageNode::<$type$>::Node,",
+            &patches.clone().into(),
+        )),
+        init_code: Some(RewriteNode::interpolate_patched(
+            &format!(
+                "\n            $name$: starknet::storage::StorageNodeTrait::<\
+                 starknet::storage::HasStorageNode::<$type$>::Node>::new(\
+                 starknet::storage_access::storage_base_address_const::<{address}>()),"
+            ),
+            &patches.into(),
+        )),
+        module_code: None,
+    }
+}
+
 /// Returns the relevant code for a simple (`Store` implementing type) storage member.
 fn get_simple_storage_member_code(
     db: &dyn SyntaxGroup,
@@ -159,15 +224,22 @@ fn get_simple_storage_member_code(
     let address = format!("0x{:x}", starknet_keccak(name.as_bytes()));
     let type_ast = member.type_clause(db).ty(db);
 
-    let member_module_code = match try_extract_mapping_types(db, &type_ast) {
-        Some((key_type_ast, value_type_ast, MappingType::Legacy)) => {
-            let Some(key_type_path) = get_mapping_full_path_type(db, diagnostics, &key_type_ast)
-            else {
+    let member_module_code = match try_extract_storage_collection_type(db, &type_ast) {
+        Some(StorageCollectionType::LegacyMap(key_type_ast, value_type_ast)) => {
+            let Some(key_type_path) = get_generic_arg_full_path_type(
+                db,
+                diagnostics,
+                &key_type_ast,
+                LEGACY_STORAGE_MAPPING,
+            ) else {
                 return Default::default();
             };
-            let Some(value_type_path) =
-                get_mapping_full_path_type(db, diagnostics, &value_type_ast)
-            else {
+            let Some(value_type_path) = get_generic_arg_full_path_type(
+                db,
+                diagnostics,
+                &value_type_ast,
+                LEGACY_STORAGE_MAPPING,
+            ) else {
                 return Default::default();
             };
             Some(RewriteNode::interpolate_patched(
@@ -185,12 +257,38 @@ fn get_simple_storage_member_code(
                 .into(),
             ))
         }
-        Some((_, _, MappingType::NonLegacy)) => {
-            diagnostics.push(PluginDiagnostic::error(
-                type_ast.stable_ptr().untyped(),
-                format!("Non `{LEGACY_STORAGE_MAPPING}` mapping is not yet supported."),
-            ));
-            None
+        Some(StorageCollectionType::Map(key_type_ast, value_type_ast)) => {
+            let Some((key_type_path, value_type_path)) =
+                get_map_types(db, diagnostics, key_type_ast, value_type_ast)
+            else {
+                return Default::default();
+            };
+            Some(RewriteNode::interpolate_patched(
+                &handle_map_storage_member(&address, starknet_module_kind),
+                &[
+                    ("member_module_path".to_string(), member_module_path),
+                    ("extra_uses".to_string(), extra_uses_node),
+                    ("key_type".to_string(), key_type_path),
+                    ("value_type".to_string(), value_type_path),
+                ]
+                .into(),
+            ))
+        }
+        Some(StorageCollectionType::Vec(value_type_ast)) => {
+            let Some(value_type_path) =
+                get_generic_arg_full_path_type(db, diagnostics, &value_type_ast, STORAGE_VEC)
+            else {
+                return Default::default();
+            };
+            Some(RewriteNode::interpolate_patched(
+                &handle_vec_storage_member(&address, starknet_module_kind),
+                &[
+                    ("member_module_path".to_string(), member_module_path),
+                    ("extra_uses".to_string(), extra_uses_node),
+                    ("value_type".to_string(), value_type_path),
+                ]
+                .into(),
+            ))
         }
         None => {
             let type_path = get_full_path_type(db, &type_ast);
@@ -216,33 +314,108 @@ fn get_simple_storage_member_code(
     }
 }
 
+/// Returns the key type and the value type of a `Map` storage member, as RewriteNodes of their full
+/// paths for an inner module. The keys of nested maps are combined into a tuple key, so
+/// `Map<K0, Map<K1, V>>` is accessed as a map from `(K0, K1)` to `V`.
+fn get_map_types(
+    db: &dyn SyntaxGroup,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    key_type_ast: ast::GenericArg,
+    value_type_ast: ast::GenericArg,
+) -> Option<(RewriteNode, RewriteNode)> {
+    let mut key_types =
+        vec![get_generic_arg_full_path_type(db, diagnostics, &key_type_ast, STORAGE_MAPPING)?];
+    let mut value_type_ast = value_type_ast;
+    loop {
+        let value_type_expr =
+            get_generic_arg_expr(db, diagnostics, &value_type_ast, STORAGE_MAPPING)?;
+        match try_extract_storage_collection_type(db, &value_type_expr) {
+            Some(StorageCollectionType::Map(inner_key_type_ast, inner_value_type_ast)) => {
+                key_types.push(get_generic_arg_full_path_type(
+                    db,
+                    diagnostics,
+                    &inner_key_type_ast,
+                    STORAGE_MAPPING,
+                )?);
+                value_type_ast = inner_value_type_ast;
+            }
+            Some(StorageCollectionType::LegacyMap(..) | StorageCollectionType::Vec(..)) => {
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        value_type_expr.stable_ptr().untyped(),
+                        format!(
+                            "Only `{STORAGE_MAPPING}` and `Store` implementing types are \
+                             supported as `{STORAGE_MAPPING}` values."
+                        ),
+                    )
+                    .with_error_code(error_code!("E0759")),
+                );
+                return None;
+            }
+            None => {
+                let key_type = if key_types.len() == 1 {
+                    key_types.pop().unwrap()
+                } else {
+                    RewriteNode::interpolate_patched(
+                        "($key_types$)",
+                        &[(
+                            "key_types".to_string(),
+                            RewriteNode::interspersed(key_types, RewriteNode::text(", ")),
+                        )]
+                        .into(),
+                    )
+                };
+                return Some((key_type, get_full_path_type(db, &value_type_expr)));
+            }
+        }
+    }
+}
+
 /// Returns a RewriteNode of the full path of a type for an inner module for a type specified by a
-/// mapping generic argument - adds "super::" if needed.
-fn get_mapping_full_path_type(
+/// generic argument of the storage collection named `collection_name` - adds "super::" if needed.
+fn get_generic_arg_full_path_type(
     db: &dyn SyntaxGroup,
     diagnostics: &mut Vec<PluginDiagnostic>,
     type_ast: &ast::GenericArg,
+    collection_name: &str,
 ) -> Option<RewriteNode> {
-    let key_type_path = match type_ast {
+    let type_expr = get_generic_arg_expr(db, diagnostics, type_ast, collection_name)?;
+    Some(get_full_path_type(db, &type_expr))
+}
+
+/// Returns the type expression of a generic argument of the storage collection named
+/// `collection_name`.
+fn get_generic_arg_expr(
+    db: &dyn SyntaxGroup,
+    diagnostics: &mut Vec<PluginDiagnostic>,
+    type_ast: &ast::GenericArg,
+    collection_name: &str,
+) -> Option<ast::Expr> {
+    match type_ast {
         ast::GenericArg::Unnamed(x) => match x.value(db) {
-            ast::GenericArgValue::Expr(x) => x.expr(db),
+            ast::GenericArgValue::Expr(x) => Some(x.expr(db)),
             ast::GenericArgValue::Underscore(_) => {
-                diagnostics.push(PluginDiagnostic::error(
-                    type_ast.stable_ptr().untyped(),
-                    format!("{LEGACY_STORAGE_MAPPING} generic arguments must be specified"),
-                ));
-                return None;
+                diagnostics.push(
+                    PluginDiagnostic::error(
+                        type_ast.stable_ptr().untyped(),
+                        format!("{collection_name} generic arguments must be specified"),
+                    )
+                    .with_error_code(error_code!("E0760")),
+                );
+                None
             }
         },
         ast::GenericArg::Named(_) => {
-            diagnostics.push(PluginDiagnostic::error(
-                type_ast.stable_ptr().untyped(),
-                format!("{LEGACY_STORAGE_MAPPING} generic arguments are unnamed"),
-            ));
-            return None;
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    type_ast.stable_ptr().untyped(),
+                    format!("{collection_name} generic arguments are unnamed"),
+                )
+                .with_error_code(error_code!("E0761")),
+            );
+            None
         }
-    };
-    Some(get_full_path_type(db, &key_type_path))
+    }
 }
 
 /// Returns a RewriteNode of the full path of a type for an inner module - adds "super::" if needed.
@@ -323,35 +496,38 @@ fn get_substorage_member_code(
     }
 }
 
-/// The type of the mapping storage member.
-enum MappingType {
-    /// Pedersen based.
-    Legacy,
-    /// Poseidon based.
-    NonLegacy,
+/// A storage collection type of a storage member, with its generic arguments.
+enum StorageCollectionType {
+    /// `LegacyMap<K, V>` - Pedersen based.
+    LegacyMap(ast::GenericArg, ast::GenericArg),
+    /// `Map<K, V>` - Poseidon based.
+    Map(ast::GenericArg, ast::GenericArg),
+    /// `Vec<T>` - the length, followed by the elements as a Poseidon based map from their index.
+    Vec(ast::GenericArg),
 }
 
-/// Given a type, if it is of form `{Legacy,}Map::<K, V>`, returns `K` and `V` and the mapping type.
-/// Otherwise, returns None.
-fn try_extract_mapping_types(
+/// Given a type, if it is of form `{Legacy,}Map::<K, V>` or `Vec::<T>`, returns the collection type
+/// with its generic arguments. Otherwise, returns None.
+fn try_extract_storage_collection_type(
     db: &dyn SyntaxGroup,
     type_ast: &ast::Expr,
-) -> Option<(ast::GenericArg, ast::GenericArg, MappingType)> {
+) -> Option<StorageCollectionType> {
     let as_path = try_extract_matches!(type_ast, ast::Expr::Path)?;
     let [ast::PathSegment::WithGenericArgs(segment)] = &as_path.elements(db)[..] else {
         return None;
     };
     let ty = segment.ident(db).text(db);
+    let generic_args = segment.generic_args(db).generic_args(db).elements(db);
     if ty == LEGACY_STORAGE_MAPPING || ty == STORAGE_MAPPING {
-        let [key_ty, value_ty] = <[ast::GenericArg; 2]>::try_from(
-            segment.generic_args(db).generic_args(db).elements(db),
-        )
-        .ok()?;
-        Some((
-            key_ty,
-            value_ty,
-            if ty == LEGACY_STORAGE_MAPPING { MappingType::Legacy } else { MappingType::NonLegacy },
-        ))
+        let [key_ty, value_ty] = <[ast::GenericArg; 2]>::try_from(generic_args).ok()?;
+        Some(if ty == LEGACY_STORAGE_MAPPING {
+            StorageCollectionType::LegacyMap(key_ty, value_ty)
+        } else {
+            StorageCollectionType::Map(key_ty, value_ty)
+        })
+    } else if ty == STORAGE_VEC {
+        let [value_ty] = <[ast::GenericArg; 1]>::try_from(generic_args).ok()?;
+        Some(StorageCollectionType::Vec(value_ty))
     } else {
         None
     }
@@ -498,3 +674,44 @@ fn handle_legacy_mapping_storage_member(
         ),
     }
 }
+
+/// Generate the address skeleton for a `Map` member in the storage struct.
+fn handle_map_storage_member(address: &str, starknet_module_kind: StarknetModuleKind) -> String {
+    let member_state_name = starknet_module_kind.get_member_state_name();
+    format!(
+        "
+    pub mod $member_module_path$ {{$extra_uses$
+        #[derive(Copy, Drop)]
+        pub struct {member_state_name} {{}}
+
+        impl StoragePoseidonMap{member_state_name}Impl of \
+         starknet::storage::StorageMapMemberAddressTrait<{member_state_name}, $key_type$, \
+         $value_type$> {{
+            fn address(self: @{member_state_name}, key: $key_type$) -> \
+         starknet::storage_access::StorageBaseAddress {{
+                starknet::storage::storage_map_entry_address({address}, key)
+            }}
+        }}
+    }}"
+    )
+}
+
+/// Generate the address skeleton for a `Vec` member in the storage struct.
+fn handle_vec_storage_member(address: &str, starknet_module_kind: StarknetModuleKind) -> String {
+    let member_state_name = starknet_module_kind.get_member_state_name();
+    format!(
+        "
+    pub mod $member_module_path$ {{$extra_uses$
+        #[derive(Copy, Drop)]
+        pub struct {member_state_name} {{}}
+
+        impl StorageVec{member_state_name}Impl of \
+         starknet::storage::StorageVecMemberAddressTrait<{member_state_name}, $value_type$> {{
+            fn address(self: @{member_state_name}) -> starknet::storage_access::StorageBaseAddress \
+         nopanic {{
+                starknet::storage_access::storage_base_address_const::<{address}>()
+            }}
+        }}
+    }}"
+    )
+}
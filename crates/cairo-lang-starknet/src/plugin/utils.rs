@@ -1,4 +1,5 @@
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::node::ast::{self, Attribute, Modifier};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::helpers::{is_single_arg_attr, QueryAttrs};
@@ -215,7 +216,10 @@ pub fn has_v0_attribute_ex(
     };
     validate_v0(db, diagnostics, &attr, attr_name);
     if let Some(deprecated) = deprecated() {
-        diagnostics.push(PluginDiagnostic::warning(attr.stable_ptr().untyped(), deprecated));
+        diagnostics.push(
+            PluginDiagnostic::warning(attr.stable_ptr().untyped(), deprecated)
+                .with_error_code(error_code!("E0762")),
+        );
     }
     true
 }
@@ -228,10 +232,13 @@ fn validate_v0(
     name: &str,
 ) {
     if !is_single_arg_attr(db, attr, "v0") {
-        diagnostics.push(PluginDiagnostic::error(
-            attr.stable_ptr().untyped(),
-            format!("Only #[{name}(v0)] is supported."),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                attr.stable_ptr().untyped(),
+                format!("Only #[{name}(v0)] is supported."),
+            )
+            .with_error_code(error_code!("E0763")),
+        );
     }
 }
 
@@ -256,12 +263,15 @@ pub fn forbid_attribute_in_impl(
     embedded_impl_attr: &str,
 ) {
     if let Some(attr) = impl_item.find_attr(db, attr_name) {
-        diagnostics.push(PluginDiagnostic::error(
-            attr.stable_ptr().untyped(),
-            format!(
-                "The `{attr_name}` attribute is not allowed inside an impl marked as \
-                 `{embedded_impl_attr}`."
-            ),
-        ));
+        diagnostics.push(
+            PluginDiagnostic::error(
+                attr.stable_ptr().untyped(),
+                format!(
+                    "The `{attr_name}` attribute is not allowed inside an impl marked as \
+                     `{embedded_impl_attr}`."
+                ),
+            )
+            .with_error_code(error_code!("E0764")),
+        );
     }
 }
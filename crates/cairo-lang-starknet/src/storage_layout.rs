@@ -0,0 +1,310 @@
+use anyhow::Context;
+use cairo_lang_defs::ids::{
+    FileIndex, LanguageElementId, ModuleId, ModuleItemId, StructId, TopLevelLanguageElementId,
+};
+use cairo_lang_diagnostics::ToOption;
+use cairo_lang_semantic::db::SemanticGroup;
+use cairo_lang_semantic::items::enm::SemanticEnumEx;
+use cairo_lang_semantic::items::imp::{ImplId, ImplLookupContext};
+use cairo_lang_semantic::items::structure::SemanticStructEx;
+use cairo_lang_semantic::resolve::ResolvedConcreteItem;
+use cairo_lang_semantic::types::get_impl_at_context;
+use cairo_lang_semantic::{
+    ConcreteTraitLongId, ConcreteTypeId, GenericArgumentId, Member, TypeId, TypeLongId,
+};
+use cairo_lang_starknet_classes::keccak::starknet_keccak;
+use cairo_lang_starknet_classes::storage_layout::{
+    StorageLayout, StorageMemberKind, StorageMemberLayout,
+};
+use cairo_lang_syntax::node::helpers::PathSegmentEx;
+use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
+use cairo_lang_utils::bigint::BigUintAsHex;
+use cairo_lang_utils::try_extract_matches;
+use itertools::Itertools;
+
+use crate::contract::ContractDeclaration;
+use crate::plugin::consts::COMPONENT_STATE_NAME;
+use crate::plugin::starknet_module::StarknetModuleKind;
+
+#[cfg(test)]
+#[path = "storage_layout_test.rs"]
+mod test;
+
+/// Returns the storage layout of the given contract.
+///
+/// The layout is extracted from the storage state structs generated by the Starknet plugin, so the
+/// code of the contract is expected to be free of diagnostics.
+pub fn get_storage_layout(
+    db: &dyn SemanticGroup,
+    contract: &ContractDeclaration,
+) -> anyhow::Result<StorageLayout> {
+    let members = get_storage_members_layout(
+        db,
+        ModuleId::Submodule(contract.submodule_id),
+        StarknetModuleKind::Contract,
+    )
+    .with_context(|| {
+        format!(
+            "Failed to extract the storage layout of `{}`.",
+            contract.submodule_id.full_path(db.upcast())
+        )
+    })?;
+    Ok(StorageLayout { members })
+}
+
+/// Returns the layout of the storage members of the contract or component in `module_id`, in the
+/// order of their declaration.
+fn get_storage_members_layout(
+    db: &dyn SemanticGroup,
+    module_id: ModuleId,
+    module_kind: StarknetModuleKind,
+) -> anyhow::Result<Vec<StorageMemberLayout>> {
+    let state_struct_name = module_kind.get_state_struct_name();
+    let state_struct_id = db
+        .module_item_by_name(module_id, state_struct_name.clone().into())
+        .to_option()
+        .flatten()
+        .and_then(|item| try_extract_matches!(item, ModuleItemId::Struct))
+        .with_context(|| format!("Struct `{state_struct_name}` not found."))?;
+    db.struct_members(state_struct_id)
+        .to_option()
+        .with_context(|| format!("Failed to get the members of `{state_struct_name}`."))?
+        .iter()
+        .map(|(name, member)| {
+            let stored_struct_ty = get_storage_node_stored_type(db, state_struct_id, member);
+            get_storage_member_layout(db, name, member.ty, stored_struct_ty, module_kind)
+        })
+        .collect()
+}
+
+/// Returns the layout of a storage member, given its type in the state struct, and the stored
+/// struct type of storage node members.
+fn get_storage_member_layout(
+    db: &dyn SemanticGroup,
+    name: &str,
+    state_ty: TypeId,
+    stored_struct_ty: Option<TypeId>,
+    module_kind: StarknetModuleKind,
+) -> anyhow::Result<StorageMemberLayout> {
+    let member_struct_id =
+        try_extract_matches!(db.lookup_intern_type(state_ty), TypeLongId::Concrete)
+            .and_then(|concrete| try_extract_matches!(concrete, ConcreteTypeId::Struct))
+            .with_context(|| format!("Unexpected type of the state of storage member `{name}`."))?
+            .struct_id(db);
+    // The state of a substorage member is the state of its component, and the state of any other
+    // member is defined in a module generated for the member.
+    let member_module_id = member_struct_id.parent_module(db.upcast());
+    let address = BigUintAsHex { value: starknet_keccak(name.as_bytes()) };
+    if member_struct_id.name(db.upcast()) == COMPONENT_STATE_NAME {
+        return Ok(StorageMemberLayout {
+            name: name.into(),
+            address,
+            kind: StorageMemberKind::Substorage,
+            key_types: vec![],
+            value_type: member_module_id.full_path(db.upcast()),
+            size: None,
+            members: get_storage_members_layout(
+                db,
+                member_module_id,
+                StarknetModuleKind::Component,
+            )?,
+        });
+    }
+
+    let (kind, key_types, value_type) = match stored_struct_ty {
+        Some(stored_struct_ty) => Some((StorageMemberKind::Value, vec![], stored_struct_ty)),
+        None => get_storage_member_types(db, member_module_id, module_kind),
+    }
+    .with_context(|| format!("Failed to get the types of storage member `{name}`."))?;
+    let lookup_context = ImplLookupContext::new(member_module_id, vec![]);
+    Ok(StorageMemberLayout {
+        name: name.into(),
+        address,
+        kind,
+        key_types: key_types.into_iter().map(|ty| ty.format(db)).collect(),
+        value_type: value_type.format(db),
+        size: get_store_size(db, &lookup_context, value_type),
+        members: vec![],
+    })
+}
+
+/// Returns the kind, the key types and the value type of a storage member, given the module
+/// generated for it.
+fn get_storage_member_types(
+    db: &dyn SemanticGroup,
+    member_module_id: ModuleId,
+    module_kind: StarknetModuleKind,
+) -> Option<(StorageMemberKind, Vec<TypeId>, TypeId)> {
+    let member_state_name = module_kind.get_member_state_name();
+    // The values and the legacy maps of contracts are accessed by a generated trait, whose types
+    // are taken from the signature of its `read` function:
+    // `fn read(self: @ContractMemberState, key: K) -> V`.
+    let internal_trait_name = format!("Internal{member_state_name}Trait");
+    if let Some(ModuleItemId::Trait(trait_id)) =
+        db.module_item_by_name(member_module_id, internal_trait_name.into()).ok()?
+    {
+        let read_function = *db.trait_functions(trait_id).ok()?.get("read")?;
+        let signature = db.trait_function_signature(read_function).ok()?;
+        let key_types = signature.params.iter().skip(1).map(|param| param.ty).collect_vec();
+        let kind = if key_types.is_empty() {
+            StorageMemberKind::Value
+        } else {
+            StorageMemberKind::LegacyMap
+        };
+        return Some((kind, key_types, signature.return_type));
+    }
+
+    // Any other member has a generated impl of an address trait, whose types are taken from its
+    // generic arguments: `impl ... of StorageMapMemberAddressTrait<ComponentMemberState, K, V>`.
+    let (kind, impl_def_id) = [
+        (StorageMemberKind::Value, format!("Storage{member_state_name}Impl")),
+        (StorageMemberKind::LegacyMap, format!("StorageMap{member_state_name}Impl")),
+        (StorageMemberKind::Map, format!("StoragePoseidonMap{member_state_name}Impl")),
+        (StorageMemberKind::Vec, format!("StorageVec{member_state_name}Impl")),
+    ]
+    .into_iter()
+    .find_map(|(kind, impl_name)| {
+        let item = db.module_item_by_name(member_module_id, impl_name.into()).ok()??;
+        Some((kind, try_extract_matches!(item, ModuleItemId::Impl)?))
+    })?;
+    let mut types = db
+        .impl_def_concrete_trait(impl_def_id)
+        .ok()?
+        .generic_args(db)
+        .into_iter()
+        .skip(1)
+        .map(|arg| try_extract_matches!(arg, GenericArgumentId::Type))
+        .collect::<Option<Vec<_>>>()?;
+    let value_type = types.pop()?;
+    Some((kind, types, value_type))
+}
+
+/// Returns the stored struct type of a storage node member, given its member in the state struct.
+///
+/// The type of the member is the storage node of the stored struct,
+/// `starknet::storage::HasStorageNode::<T>::Node`, and the stored struct type `T` is the generic
+/// argument the `HasStorageNode` path segment is resolved with. Returns `None` for other members.
+fn get_storage_node_stored_type(
+    db: &dyn SemanticGroup,
+    state_struct_id: StructId,
+    member: &Member,
+) -> Option<TypeId> {
+    let syntax_db = db.upcast();
+    let ast::Expr::Path(type_path) =
+        member.id.stable_ptr(db.upcast()).lookup(syntax_db).type_expr(syntax_db)
+    else {
+        return None;
+    };
+    let has_storage_node_trait_id = try_extract_matches!(
+        db.module_item_by_name(core_starknet_submodule(db, "storage")?, "HasStorageNode".into())
+            .ok()??,
+        ModuleItemId::Trait
+    )?;
+    let resolver_data = db.struct_definition_resolver_data(state_struct_id).ok()?;
+    type_path.elements(syntax_db).iter().find_map(|segment| {
+        let identifier_ptr = segment.identifier_ast(syntax_db).stable_ptr();
+        let concrete_trait_id = try_extract_matches!(
+            resolver_data.resolved_items.concrete.get(&identifier_ptr)?,
+            ResolvedConcreteItem::Trait
+        )?;
+        if concrete_trait_id.trait_id(db) != has_storage_node_trait_id {
+            return None;
+        }
+        try_extract_matches!(*concrete_trait_id.generic_args(db).first()?, GenericArgumentId::Type)
+    })
+}
+
+/// Returns the number of storage slots used by a value of type `ty`, as given by `Store::size`.
+///
+/// The size is computed for the `Store` implementations of the corelib and for the ones generated
+/// by `#[derive(starknet::Store)]`, and is unknown for other implementations.
+fn get_store_size(
+    db: &dyn SemanticGroup,
+    lookup_context: &ImplLookupContext,
+    ty: TypeId,
+) -> Option<u8> {
+    let store_trait_id =
+        db.module_item_by_name(core_storage_access_module(db)?, "Store".into()).ok()??;
+    let concrete_trait_id = db.intern_concrete_trait(ConcreteTraitLongId {
+        trait_id: try_extract_matches!(store_trait_id, ModuleItemId::Trait)?,
+        generic_args: vec![GenericArgumentId::Type(ty)],
+    });
+    let store_impl =
+        get_impl_at_context(db, lookup_context.clone(), concrete_trait_id, None).ok()?;
+    let concrete_store_impl = try_extract_matches!(store_impl, ImplId::Concrete)?;
+    let impl_def_id = concrete_store_impl.impl_def_id(db);
+    let impl_name = impl_def_id.name(db.upcast());
+    let generic_args = db.lookup_intern_concrete_impl(concrete_store_impl).generic_args;
+    let type_arg = |index: usize| {
+        generic_args.get(index).and_then(|arg| try_extract_matches!(*arg, GenericArgumentId::Type))
+    };
+    let size_of = |ty: TypeId| get_store_size(db, lookup_context, ty);
+
+    if impl_def_id.parent_module(db.upcast()) == core_storage_access_module(db)? {
+        return match impl_name.as_str() {
+            "StoreFelt252" | "ByteArrayStore" => Some(1),
+            "StoreUsingPacking" => size_of(type_arg(1)?),
+            "OptionStore" => size_of(type_arg(0)?)?.checked_add(1),
+            "ResultStore" => size_of(type_arg(0)?)?.max(size_of(type_arg(1)?)?).checked_add(1),
+            _ if impl_name.starts_with("TupleSize") => {
+                let tuple_types =
+                    try_extract_matches!(db.lookup_intern_type(ty), TypeLongId::Tuple)?;
+                sum_sizes(tuple_types.into_iter().map(size_of))
+            }
+            _ => None,
+        };
+    }
+
+    // Implementations derived by `#[derive(starknet::Store)]` are generated into a separate file,
+    // and are named after the type.
+    let derived_impl_name = match db.lookup_intern_type(ty) {
+        TypeLongId::Concrete(concrete) => {
+            format!("Store{}", concrete.generic_type(db).name(db.upcast()))
+        }
+        _ => return None,
+    };
+    if impl_def_id.module_file_id(db.upcast()).1 == FileIndex(0) || impl_name != derived_impl_name {
+        return None;
+    }
+    match db.lookup_intern_type(ty) {
+        // A struct is stored as its members, one after the other.
+        TypeLongId::Concrete(ConcreteTypeId::Struct(concrete_struct_id)) => sum_sizes(
+            db.concrete_struct_members(concrete_struct_id)
+                .ok()?
+                .values()
+                .map(|member| size_of(member.ty)),
+        ),
+        // An enum is stored as the index of its variant, followed by the value of the variant.
+        TypeLongId::Concrete(ConcreteTypeId::Enum(concrete_enum_id)) => db
+            .concrete_enum_variants(concrete_enum_id)
+            .ok()?
+            .into_iter()
+            .map(|variant| size_of(variant.ty))
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .max()
+            .unwrap_or_default()
+            .checked_add(1),
+        _ => None,
+    }
+}
+
+/// Returns the sum of the given sizes, if they are all known and the sum fits in a `u8`.
+fn sum_sizes(mut sizes: impl Iterator<Item = Option<u8>>) -> Option<u8> {
+    sizes.try_fold(0_u8, |total, size| total.checked_add(size?))
+}
+
+/// Returns the `core::starknet::storage_access` module, where the `Store` trait is defined.
+fn core_storage_access_module(db: &dyn SemanticGroup) -> Option<ModuleId> {
+    core_starknet_submodule(db, "storage_access")
+}
+
+/// Returns the submodule of `core::starknet` named `submodule_name`.
+fn core_starknet_submodule(db: &dyn SemanticGroup, submodule_name: &str) -> Option<ModuleId> {
+    let starknet_module = cairo_lang_semantic::corelib::core_submodule(db, "starknet");
+    let submodule = try_extract_matches!(
+        db.module_item_by_name(starknet_module, submodule_name.into()).ok()??,
+        ModuleItemId::Submodule
+    )?;
+    Some(ModuleId::Submodule(submodule))
+}
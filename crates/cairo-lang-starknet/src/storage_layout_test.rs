@@ -0,0 +1,24 @@
+use cairo_lang_test_utils::compare_contents_or_fix_with_path;
+use test_case::test_case;
+
+use crate::test_utils::{get_example_file_path, get_test_contract_storage_layout};
+
+/// Tests that the storage layout of a contract in the contracts crate is the same as in
+/// <test_case>.storage_layout.json.
+#[test_case("test_contract::test_contract")]
+#[test_case("hello_starknet::hello_starknet")]
+#[test_case("erc20::erc_20")]
+#[test_case("with_ownable::ownable_balance")]
+#[test_case("upgradable_counter::counter_contract")]
+#[test_case("multi_component::contract_with_4_components")]
+#[test_case("storage_node::storage_node_contract")]
+fn test_storage_layout_from_contracts_crate(example_contract_path: &str) {
+    let storage_layout = get_test_contract_storage_layout(
+        format!("cairo_level_tests::contracts::{example_contract_path}").as_str(),
+    );
+    let example_file_name = example_contract_path.replace("::", "__");
+    compare_contents_or_fix_with_path(
+        &get_example_file_path(format!("{example_file_name}.storage_layout.json").as_str()),
+        serde_json::to_string_pretty(&storage_layout).unwrap() + "\n",
+    );
+}
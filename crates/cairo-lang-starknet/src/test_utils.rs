@@ -5,16 +5,20 @@ use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
 use cairo_lang_compiler::project::ProjectConfig;
 use cairo_lang_compiler::CompilerConfig;
+use cairo_lang_defs::ids::TopLevelLanguageElementId;
 use cairo_lang_filesystem::db::FilesGroup;
-use cairo_lang_filesystem::ids::Directory;
+use cairo_lang_filesystem::ids::{CrateId, Directory};
 use cairo_lang_starknet_classes::allowed_libfuncs::BUILTIN_ALL_LIBFUNCS_LIST;
 use cairo_lang_starknet_classes::contract_class::ContractClass;
+use cairo_lang_starknet_classes::storage_layout::StorageLayout;
 use cairo_lang_test_utils::test_lock;
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 
 use crate::compile::compile_contract_in_prepared_db;
+use crate::contract::find_contracts;
 use crate::starknet_plugin_suite;
+use crate::storage_layout::get_storage_layout;
 
 /// Returns a path to example contract that matches `name`.
 pub fn get_example_file_path(file_name: &str) -> PathBuf {
@@ -53,11 +57,8 @@ pub static SHARED_DB_WITH_CONTRACTS: Lazy<Mutex<RootDatabase>> = Lazy::new(|| {
     )
 });
 
-/// Returns the compiled test contract from the contracts crate, with replaced ids.
-pub fn get_test_contract(example_file_name: &str) -> ContractClass {
-    let locked_db = test_lock(&SHARED_DB_WITH_CONTRACTS);
-    let db = locked_db.snapshot();
-    drop(locked_db);
+/// Returns the ids of the main crates of the contracts crate database - the contracts crate itself.
+fn contracts_crate_ids(db: &RootDatabase) -> Vec<CrateId> {
     let crate_configs = db.crate_configs();
     let contracts_crate = crate_configs
         .iter()
@@ -72,7 +73,15 @@ pub fn get_test_contract(example_file_name: &str) -> ContractClass {
             CONTRACTS_CRATE_DIR, contracts_crate
         );
     };
-    let main_crate_ids = vec![**contracts_crate_id];
+    vec![**contracts_crate_id]
+}
+
+/// Returns the compiled test contract from the contracts crate, with replaced ids.
+pub fn get_test_contract(example_file_name: &str) -> ContractClass {
+    let locked_db = test_lock(&SHARED_DB_WITH_CONTRACTS);
+    let db = locked_db.snapshot();
+    drop(locked_db);
+    let main_crate_ids = contracts_crate_ids(&db);
     let diagnostics_reporter = DiagnosticsReporter::default().with_crates(&main_crate_ids);
     compile_contract_in_prepared_db(
         &db,
@@ -82,7 +91,21 @@ pub fn get_test_contract(example_file_name: &str) -> ContractClass {
             replace_ids: true,
             allowed_libfuncs_list_name: Some(BUILTIN_ALL_LIBFUNCS_LIST.to_string()),
             diagnostics_reporter,
+            lint_levels: vec![],
         },
     )
     .expect("compile_path failed")
 }
+
+/// Returns the storage layout of the test contract from the contracts crate.
+pub fn get_test_contract_storage_layout(example_file_name: &str) -> StorageLayout {
+    let locked_db = test_lock(&SHARED_DB_WITH_CONTRACTS);
+    let db = locked_db.snapshot();
+    drop(locked_db);
+    let contracts = find_contracts(&db, &contracts_crate_ids(&db));
+    let contract = contracts
+        .iter()
+        .find(|contract| contract.submodule_id.full_path(&db) == example_file_name)
+        .expect("Contract not found.");
+    get_storage_layout(&db, contract).expect("get_storage_layout failed")
+}
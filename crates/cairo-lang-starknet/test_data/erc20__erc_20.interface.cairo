@@ -0,0 +1,34 @@
+#[derive(Drop, starknet::Event)]
+pub struct Transfer {
+    pub from: core::starknet::contract_address::ContractAddress,
+    pub to: core::starknet::contract_address::ContractAddress,
+    pub value: core::integer::u256,
+}
+
+#[derive(Drop, starknet::Event)]
+pub struct Approval {
+    pub owner: core::starknet::contract_address::ContractAddress,
+    pub spender: core::starknet::contract_address::ContractAddress,
+    pub value: core::integer::u256,
+}
+
+#[derive(Drop, starknet::Event)]
+pub enum Event {
+    Transfer: Transfer,
+    Approval: Approval,
+}
+
+#[starknet::interface]
+pub trait IERC20<TContractState> {
+    fn get_name(self: @TContractState) -> core::felt252;
+    fn get_symbol(self: @TContractState) -> core::felt252;
+    fn get_decimals(self: @TContractState) -> core::integer::u8;
+    fn get_total_supply(self: @TContractState) -> core::integer::u256;
+    fn balance_of(self: @TContractState, account: core::starknet::contract_address::ContractAddress) -> core::integer::u256;
+    fn allowance(self: @TContractState, owner: core::starknet::contract_address::ContractAddress, spender: core::starknet::contract_address::ContractAddress) -> core::integer::u256;
+    fn transfer(ref self: TContractState, recipient: core::starknet::contract_address::ContractAddress, amount: core::integer::u256);
+    fn transfer_from(ref self: TContractState, sender: core::starknet::contract_address::ContractAddress, recipient: core::starknet::contract_address::ContractAddress, amount: core::integer::u256);
+    fn approve(ref self: TContractState, spender: core::starknet::contract_address::ContractAddress, amount: core::integer::u256);
+    fn increase_allowance(ref self: TContractState, spender: core::starknet::contract_address::ContractAddress, added_value: core::integer::u256);
+    fn decrease_allowance(ref self: TContractState, spender: core::starknet::contract_address::ContractAddress, subtracted_value: core::integer::u256);
+}
@@ -0,0 +1,8 @@
+#[derive(Drop, starknet::Event)]
+pub enum Event {}
+
+#[starknet::interface]
+pub trait HelloStarknetTrait<TContractState> {
+    fn increase_balance(ref self: TContractState, amount: core::integer::u32);
+    fn get_balance(self: @TContractState) -> core::integer::u32;
+}
@@ -0,0 +1,80 @@
+#[derive(Drop, starknet::Event)]
+pub struct TransferEvent {
+    pub from: core::starknet::contract_address::ContractAddress,
+    pub to: core::starknet::contract_address::ContractAddress,
+    pub value: core::integer::u256,
+}
+
+#[derive(Drop, starknet::Event)]
+pub struct ApprovalEvent {
+    pub owner: core::starknet::contract_address::ContractAddress,
+    pub spender: core::starknet::contract_address::ContractAddress,
+    pub value: core::integer::u256,
+}
+
+#[derive(Drop, starknet::Event)]
+pub enum Erc20Event {
+    Transfer: TransferEvent,
+    Approval: ApprovalEvent,
+}
+
+#[derive(Drop, starknet::Event)]
+pub enum OwnableEvent {}
+
+#[derive(Drop, starknet::Event)]
+pub enum MintableEvent {}
+
+#[derive(Drop, starknet::Event)]
+pub struct ContractUpgraded {
+    pub old_class_hash: core::starknet::class_hash::ClassHash,
+    pub new_class_hash: core::starknet::class_hash::ClassHash,
+}
+
+#[derive(Drop, starknet::Event)]
+pub enum UpgradableEvent {
+    ContractUpgraded: ContractUpgraded,
+}
+
+#[derive(Drop, starknet::Event)]
+pub enum ContractWith4ComponentsEvent {
+    ERC20: Erc20Event,
+    Ownable: OwnableEvent,
+    Mintable: MintableEvent,
+    Upgradable: UpgradableEvent,
+}
+
+#[starknet::interface]
+pub trait GetSupply<TContractState> {
+    fn get_total_supply_plus_1(self: @TContractState) -> core::integer::u256;
+}
+
+#[starknet::interface]
+pub trait ERC20Trait<TContractState> {
+    fn get_name(self: @TContractState) -> core::felt252;
+    fn get_symbol(self: @TContractState) -> core::felt252;
+    fn get_decimals(self: @TContractState) -> core::integer::u8;
+    fn get_total_supply(self: @TContractState) -> core::integer::u256;
+    fn balance_of(self: @TContractState, account: core::starknet::contract_address::ContractAddress) -> core::integer::u256;
+    fn allowance(self: @TContractState, owner: core::starknet::contract_address::ContractAddress, spender: core::starknet::contract_address::ContractAddress) -> core::integer::u256;
+    fn transfer(ref self: TContractState, recipient: core::starknet::contract_address::ContractAddress, amount: core::integer::u256);
+    fn transfer_from(ref self: TContractState, sender: core::starknet::contract_address::ContractAddress, recipient: core::starknet::contract_address::ContractAddress, amount: core::integer::u256);
+    fn approve(ref self: TContractState, spender: core::starknet::contract_address::ContractAddress, amount: core::integer::u256);
+    fn increase_allowance(ref self: TContractState, spender: core::starknet::contract_address::ContractAddress, added_value: core::integer::u256);
+    fn decrease_allowance(ref self: TContractState, spender: core::starknet::contract_address::ContractAddress, subtracted_value: core::integer::u256);
+}
+
+#[starknet::interface]
+pub trait TransferTrait<TContractState> {
+    fn owner(self: @TContractState) -> core::starknet::contract_address::ContractAddress;
+    fn transfer_ownership(ref self: TContractState, new_owner: core::starknet::contract_address::ContractAddress);
+}
+
+#[starknet::interface]
+pub trait MintTrait<TContractState> {
+    fn mint(ref self: TContractState, account: core::starknet::contract_address::ContractAddress, amount: core::integer::u256);
+}
+
+#[starknet::interface]
+pub trait IUpgradable<TContractState> {
+    fn upgrade(ref self: TContractState, new_class_hash: core::starknet::class_hash::ClassHash);
+}
@@ -0,0 +1,43 @@
+#[derive(Drop, starknet::Event)]
+pub struct CounterIncreased {
+    pub amount: core::integer::u128,
+}
+
+#[derive(Drop, starknet::Event)]
+pub struct CounterDecreased {
+    pub amount: core::integer::u128,
+}
+
+#[derive(Drop, starknet::Event)]
+pub struct ContractUpgraded {
+    pub old_class_hash: core::starknet::class_hash::ClassHash,
+    pub new_class_hash: core::starknet::class_hash::ClassHash,
+}
+
+#[derive(Drop, starknet::Event)]
+pub enum UpgradableEvent {
+    ContractUpgraded: ContractUpgraded,
+}
+
+#[derive(Drop, starknet::Event)]
+pub enum OwnableEvent {}
+
+#[derive(Drop, starknet::Event)]
+pub enum CounterContractEvent {
+    CounterIncreased: CounterIncreased,
+    CounterDecreased: CounterDecreased,
+    UpgradableEvent: UpgradableEvent,
+    OwnableEvent: OwnableEvent,
+}
+
+#[starknet::interface]
+pub trait ICounterContract<TContractState> {
+    fn increase_counter(ref self: TContractState, amount: core::integer::u128);
+    fn decrease_counter(ref self: TContractState, amount: core::integer::u128);
+    fn get_counter(self: @TContractState) -> core::integer::u128;
+}
+
+#[starknet::interface]
+pub trait IUpgradable<TContractState> {
+    fn upgrade(ref self: TContractState, new_class_hash: core::starknet::class_hash::ClassHash);
+}
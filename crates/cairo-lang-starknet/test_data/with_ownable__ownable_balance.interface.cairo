@@ -0,0 +1,19 @@
+#[derive(Drop, starknet::Event)]
+pub enum OwnableEvent {}
+
+#[derive(Drop, starknet::Event)]
+pub enum OwnableBalanceEvent {
+    Ownable: OwnableEvent,
+}
+
+#[starknet::interface]
+pub trait TransferTrait<TContractState> {
+    fn owner(self: @TContractState) -> core::starknet::contract_address::ContractAddress;
+    fn transfer_ownership(ref self: TContractState, new_owner: core::starknet::contract_address::ContractAddress);
+}
+
+#[starknet::interface]
+pub trait IContract<TContractState> {
+    fn get_balance(self: @TContractState) -> core::integer::u128;
+    fn set_balance(ref self: TContractState, new_balance: core::integer::u128);
+}
@@ -32,10 +32,12 @@ pub fn get_spec() -> Vec<Node> {
         .node("If")
         .node("Loop")
         .node("While")
+        .node("For")
         .node("ErrorPropagate")
         .node("FieldInitShorthand")
         .node("Indexed")
         .node("InlineMacro")
+        .node("Closure")
     )
     .add_separated_list("ExprList", "Expr", "TerminalComma")
     .add_struct(StructBuilder::new("Arg")
@@ -116,6 +118,8 @@ pub fn get_spec() -> Vec<Node> {
         .node_with_explicit_kind("GE", "TerminalGE")
         .node_with_explicit_kind("LT", "TerminalLT")
         .node_with_explicit_kind("GT", "TerminalGT")
+        .node_with_explicit_kind("DotDot", "TerminalDotDot")
+        .node_with_explicit_kind("DotDotEq", "TerminalDotDotEq")
     )
     .add_struct(StructBuilder::new("ExprListParenthesized")
         .node("lparen", "TerminalLParen")
@@ -192,6 +196,13 @@ pub fn get_spec() -> Vec<Node> {
         .node("condition", "Condition")
         .node("body", "ExprBlock")
     )
+    .add_struct(StructBuilder::new("ExprFor")
+        .node("for_kw", "TerminalFor")
+        .node("pattern", "Pattern")
+        .node("identifier", "TerminalIdentifier")
+        .node("expr", "Expr")
+        .node("body", "ExprBlock")
+    )
     .add_struct(StructBuilder::new("ElseClause")
         .node("else_kw", "TerminalElse")
         .node("else_block_or_if", "BlockOrIf")
@@ -209,6 +220,25 @@ pub fn get_spec() -> Vec<Node> {
         .node("bang", "TerminalNot")
         .node("arguments", "WrappedArgList")
     )
+    .add_struct(StructBuilder::new("ExprClosure")
+        .node("wrapper", "ClosureParamWrapper")
+        .node("ret_ty", "OptionReturnTypeClause")
+        .node("expr", "Expr")
+    )
+    .add_enum(EnumBuilder::new("ClosureParamWrapper")
+        .node_with_explicit_kind("Nullary", "TerminalOrOr")
+        .node("NAry")
+    )
+    .add_struct(StructBuilder::new("ClosureParamWrapperNAry")
+        .node("leftor", "TerminalOr")
+        .node("params", "ClosureParamList")
+        .node("rightor", "TerminalOr")
+    )
+    .add_separated_list("ClosureParamList", "ClosureParam", "TerminalComma")
+    .add_struct(StructBuilder::new("ClosureParam")
+        .node("pattern", "Pattern")
+        .node("type_clause", "OptionTypeClause")
+    )
     // --- Struct ctor ---
     .add_struct(StructBuilder::new("StructArgExpr")
         .node("colon", "TerminalColon")
@@ -257,6 +287,7 @@ pub fn get_spec() -> Vec<Node> {
         .node_with_explicit_kind("String", "TerminalString")
         .node("Identifier")
         .node("Struct")
+        .node("TupleStruct")
         .node("Tuple")
         .node("Enum")
         .node_with_explicit_kind("Path", "ExprPath")
@@ -273,6 +304,12 @@ pub fn get_spec() -> Vec<Node> {
         .node("rbrace", "TerminalRBrace")
     )
     .add_separated_list("PatternStructParamList", "PatternStructParam", "TerminalComma")
+    .add_struct(StructBuilder::new("PatternTupleStruct")
+        .node("path", "ExprPath")
+        .node("lparen", "TerminalLParen")
+        .node("patterns", "PatternList")
+        .node("rparen", "TerminalRParen")
+    )
     .add_struct(StructBuilder::new("PatternTuple")
         .node("lparen", "TerminalLParen")
         .node("patterns", "PatternList")
@@ -328,8 +365,14 @@ pub fn get_spec() -> Vec<Node> {
         .node("type_clause", "OptionTypeClause")
         .node("eq", "TerminalEq")
         .node("rhs", "Expr")
+        .node("let_else_clause", "OptionLetElseClause")
         .node("semicolon", "TerminalSemicolon")
     )
+    .add_struct(StructBuilder::new("LetElseClause")
+        .node("else_kw", "TerminalElse")
+        .node("else_block", "ExprBlock")
+    )
+    .add_option("LetElseClause")
     .add_option("TerminalSemicolon")
     .add_struct(StructBuilder::new("StatementExpr")
         .node("attributes" ,"AttributeList")
@@ -396,6 +439,16 @@ pub fn get_spec() -> Vec<Node> {
         .node("type_clause", "TypeClause")
     )
     .add_separated_list("MemberList", "Member", "TerminalComma")
+    .add_struct(StructBuilder::new("TupleMember")
+        .node("attributes" ,"AttributeList")
+        .node("visibility", "Visibility")
+        .node("ty", "Expr")
+    )
+    .add_separated_list("TupleMemberList", "TupleMember", "TerminalComma")
+    .add_enum(EnumBuilder::new("StructMember")
+        .node_with_explicit_kind("Named", "Member")
+        .node_with_explicit_kind("Tuple", "TupleMember")
+    )
     .add_struct(StructBuilder::new("Variant")
         .node("attributes" ,"AttributeList")
         .key_node("name", "TerminalIdentifier")
@@ -432,9 +485,17 @@ pub fn get_spec() -> Vec<Node> {
     .add_struct(StructBuilder::new("VisibilityDefault"))
     .add_struct(StructBuilder::new("VisibilityPubArgumentClause")
         .node("lparen", "TerminalLParen")
-        .node("argument", "TerminalIdentifier")
+        .node("argument", "VisibilityPubArgument")
         .node("rparen", "TerminalRParen")
     )
+    .add_enum(EnumBuilder::new("VisibilityPubArgument")
+        .node_with_explicit_kind("Simple", "TerminalIdentifier")
+        .node("In")
+    )
+    .add_struct(StructBuilder::new("VisibilityPubArgumentIn")
+        .node("in_kw", "TerminalIdentifier")
+        .node("path", "ExprPath")
+    )
     .add_option("VisibilityPubArgumentClause")
     .add_struct(StructBuilder::new("VisibilityPub")
         .node("pub_kw", "TerminalPub")
@@ -616,10 +677,24 @@ pub fn get_spec() -> Vec<Node> {
         .node("struct_kw", "TerminalStruct")
         .key_node("name", "TerminalIdentifier")
         .node("generic_params", "OptionWrappedGenericParamList")
+        .node("body", "StructBody")
+    )
+    .add_enum(EnumBuilder::new("StructBody")
+        .node_with_explicit_kind("Named", "StructBodyNamed")
+        .node_with_explicit_kind("Tuple", "StructBodyTuple")
+        .node_with_explicit_kind("Unit", "TerminalSemicolon")
+    )
+    .add_struct(StructBuilder::new("StructBodyNamed")
         .node("lbrace", "TerminalLBrace")
         .node("members", "MemberList")
         .node("rbrace", "TerminalRBrace")
     )
+    .add_struct(StructBuilder::new("StructBodyTuple")
+        .node("lparen", "TerminalLParen")
+        .node("members", "TupleMemberList")
+        .node("rparen", "TerminalRParen")
+        .node("semicolon", "TerminalSemicolon")
+    )
     .add_struct(StructBuilder::new("ItemEnum")
         .node("attributes" ,"AttributeList")
         .node("visibility", "Visibility")
@@ -652,6 +727,7 @@ pub fn get_spec() -> Vec<Node> {
         .node("Leaf")
         .node("Single")
         .node("Multi")
+        .node("Star")
     )
     .add_struct(StructBuilder::new("UsePathLeaf")
         .key_node("ident", "PathSegment")
@@ -667,6 +743,9 @@ pub fn get_spec() -> Vec<Node> {
         .node("use_paths", "UsePathList")
         .node("rbrace", "TerminalRBrace")
     )
+    .add_struct(StructBuilder::new("UsePathStar")
+        .node("star", "TerminalMul")
+    )
     .add_separated_list("UsePathList", "UsePath", "TerminalComma")
     .add_struct(StructBuilder::new("AliasClause")
         .node("as_kw", "TerminalAs")
@@ -764,6 +843,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_keyword_token_and_terminal("If")
     .add_keyword_token_and_terminal("While")
     .add_keyword_token_and_terminal("Loop")
+    .add_keyword_token_and_terminal("For")
     .add_keyword_token_and_terminal("Impl")
     .add_keyword_token_and_terminal("Implicits")
     .add_keyword_token_and_terminal("Let")
@@ -794,6 +874,7 @@ pub fn get_spec() -> Vec<Node> {
     .add_token_and_terminal("DivEq")
     .add_token_and_terminal("Dot")
     .add_token_and_terminal("DotDot")
+    .add_token_and_terminal("DotDotEq")
     .add_token_and_terminal("EndOfFile")
     .add_token_and_terminal("Eq")
     .add_token_and_terminal("EqEq")
@@ -19,6 +19,15 @@ pub const UNSTABLE_ATTR: &str = "unstable";
 /// An attribute to allow usage of a feature under a statement.
 pub const FEATURE_ATTR: &str = "feature";
 
+/// An attribute to set the level of lints under an item or a statement to allow.
+pub const ALLOW_ATTR: &str = "allow";
+
+/// An attribute to set the level of lints under an item or a statement to warn.
+pub const WARN_ATTR: &str = "warn";
+
+/// An attribute to set the level of lints under an item or a statement to deny.
+pub const DENY_ATTR: &str = "deny";
+
 /// An attribute to define the order of implicit arguments.
 pub const IMPLICIT_PRECEDENCE_ATTR: &str = "implicit_precedence";
 
@@ -210,10 +210,12 @@ pub enum Expr {
     If(ExprIf),
     Loop(ExprLoop),
     While(ExprWhile),
+    For(ExprFor),
     ErrorPropagate(ExprErrorPropagate),
     FieldInitShorthand(ExprFieldInitShorthand),
     Indexed(ExprIndexed),
     InlineMacro(ExprInlineMacro),
+    Closure(ExprClosure),
     Missing(ExprMissing),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -311,6 +313,11 @@ impl From<ExprWhilePtr> for ExprPtr {
         Self(value.0)
     }
 }
+impl From<ExprForPtr> for ExprPtr {
+    fn from(value: ExprForPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprErrorPropagatePtr> for ExprPtr {
     fn from(value: ExprErrorPropagatePtr) -> Self {
         Self(value.0)
@@ -331,6 +338,11 @@ impl From<ExprInlineMacroPtr> for ExprPtr {
         Self(value.0)
     }
 }
+impl From<ExprClosurePtr> for ExprPtr {
+    fn from(value: ExprClosurePtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprMissingPtr> for ExprPtr {
     fn from(value: ExprMissingPtr) -> Self {
         Self(value.0)
@@ -421,6 +433,11 @@ impl From<ExprWhileGreen> for ExprGreen {
         Self(value.0)
     }
 }
+impl From<ExprForGreen> for ExprGreen {
+    fn from(value: ExprForGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprErrorPropagateGreen> for ExprGreen {
     fn from(value: ExprErrorPropagateGreen) -> Self {
         Self(value.0)
@@ -441,6 +458,11 @@ impl From<ExprInlineMacroGreen> for ExprGreen {
         Self(value.0)
     }
 }
+impl From<ExprClosureGreen> for ExprGreen {
+    fn from(value: ExprClosureGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<ExprMissingGreen> for ExprGreen {
     fn from(value: ExprMissingGreen) -> Self {
         Self(value.0)
@@ -487,6 +509,7 @@ impl TypedSyntaxNode for Expr {
             SyntaxKind::ExprIf => Expr::If(ExprIf::from_syntax_node(db, node)),
             SyntaxKind::ExprLoop => Expr::Loop(ExprLoop::from_syntax_node(db, node)),
             SyntaxKind::ExprWhile => Expr::While(ExprWhile::from_syntax_node(db, node)),
+            SyntaxKind::ExprFor => Expr::For(ExprFor::from_syntax_node(db, node)),
             SyntaxKind::ExprErrorPropagate => {
                 Expr::ErrorPropagate(ExprErrorPropagate::from_syntax_node(db, node))
             }
@@ -497,6 +520,7 @@ impl TypedSyntaxNode for Expr {
             SyntaxKind::ExprInlineMacro => {
                 Expr::InlineMacro(ExprInlineMacro::from_syntax_node(db, node))
             }
+            SyntaxKind::ExprClosure => Expr::Closure(ExprClosure::from_syntax_node(db, node)),
             SyntaxKind::ExprMissing => Expr::Missing(ExprMissing::from_syntax_node(db, node)),
             _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "Expr"),
         }
@@ -520,10 +544,12 @@ impl TypedSyntaxNode for Expr {
             Expr::If(x) => x.as_syntax_node(),
             Expr::Loop(x) => x.as_syntax_node(),
             Expr::While(x) => x.as_syntax_node(),
+            Expr::For(x) => x.as_syntax_node(),
             Expr::ErrorPropagate(x) => x.as_syntax_node(),
             Expr::FieldInitShorthand(x) => x.as_syntax_node(),
             Expr::Indexed(x) => x.as_syntax_node(),
             Expr::InlineMacro(x) => x.as_syntax_node(),
+            Expr::Closure(x) => x.as_syntax_node(),
             Expr::Missing(x) => x.as_syntax_node(),
         }
     }
@@ -552,10 +578,12 @@ impl Expr {
             SyntaxKind::ExprIf => true,
             SyntaxKind::ExprLoop => true,
             SyntaxKind::ExprWhile => true,
+            SyntaxKind::ExprFor => true,
             SyntaxKind::ExprErrorPropagate => true,
             SyntaxKind::ExprFieldInitShorthand => true,
             SyntaxKind::ExprIndexed => true,
             SyntaxKind::ExprInlineMacro => true,
+            SyntaxKind::ExprClosure => true,
             SyntaxKind::ExprMissing => true,
             _ => false,
         }
@@ -2050,6 +2078,8 @@ pub enum BinaryOperator {
     GE(TerminalGE),
     LT(TerminalLT),
     GT(TerminalGT),
+    DotDot(TerminalDotDot),
+    DotDotEq(TerminalDotDotEq),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct BinaryOperatorPtr(pub SyntaxStablePtrId);
@@ -2181,6 +2211,16 @@ impl From<TerminalGTPtr> for BinaryOperatorPtr {
         Self(value.0)
     }
 }
+impl From<TerminalDotDotPtr> for BinaryOperatorPtr {
+    fn from(value: TerminalDotDotPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalDotDotEqPtr> for BinaryOperatorPtr {
+    fn from(value: TerminalDotDotEqPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<TerminalDotGreen> for BinaryOperatorGreen {
     fn from(value: TerminalDotGreen) -> Self {
         Self(value.0)
@@ -2301,6 +2341,16 @@ impl From<TerminalGTGreen> for BinaryOperatorGreen {
         Self(value.0)
     }
 }
+impl From<TerminalDotDotGreen> for BinaryOperatorGreen {
+    fn from(value: TerminalDotDotGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalDotDotEqGreen> for BinaryOperatorGreen {
+    fn from(value: TerminalDotDotEqGreen) -> Self {
+        Self(value.0)
+    }
+}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct BinaryOperatorGreen(pub GreenId);
 impl TypedSyntaxNode for BinaryOperator {
@@ -2357,6 +2407,12 @@ impl TypedSyntaxNode for BinaryOperator {
             SyntaxKind::TerminalGE => BinaryOperator::GE(TerminalGE::from_syntax_node(db, node)),
             SyntaxKind::TerminalLT => BinaryOperator::LT(TerminalLT::from_syntax_node(db, node)),
             SyntaxKind::TerminalGT => BinaryOperator::GT(TerminalGT::from_syntax_node(db, node)),
+            SyntaxKind::TerminalDotDot => {
+                BinaryOperator::DotDot(TerminalDotDot::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalDotDotEq => {
+                BinaryOperator::DotDotEq(TerminalDotDotEq::from_syntax_node(db, node))
+            }
             _ => {
                 panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "BinaryOperator")
             }
@@ -2388,6 +2444,8 @@ impl TypedSyntaxNode for BinaryOperator {
             BinaryOperator::GE(x) => x.as_syntax_node(),
             BinaryOperator::LT(x) => x.as_syntax_node(),
             BinaryOperator::GT(x) => x.as_syntax_node(),
+            BinaryOperator::DotDot(x) => x.as_syntax_node(),
+            BinaryOperator::DotDotEq(x) => x.as_syntax_node(),
         }
     }
     fn stable_ptr(&self) -> Self::StablePtr {
@@ -2422,6 +2480,8 @@ impl BinaryOperator {
             SyntaxKind::TerminalGE => true,
             SyntaxKind::TerminalLT => true,
             SyntaxKind::TerminalGT => true,
+            SyntaxKind::TerminalDotDot => true,
+            SyntaxKind::TerminalDotDotEq => true,
             _ => false,
         }
     }
@@ -3831,6 +3891,100 @@ impl TypedSyntaxNode for ExprWhile {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ExprFor {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl ExprFor {
+    pub const INDEX_FOR_KW: usize = 0;
+    pub const INDEX_PATTERN: usize = 1;
+    pub const INDEX_IDENTIFIER: usize = 2;
+    pub const INDEX_EXPR: usize = 3;
+    pub const INDEX_BODY: usize = 4;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        for_kw: TerminalForGreen,
+        pattern: PatternGreen,
+        identifier: TerminalIdentifierGreen,
+        expr: ExprGreen,
+        body: ExprBlockGreen,
+    ) -> ExprForGreen {
+        let children: Vec<GreenId> = vec![for_kw.0, pattern.0, identifier.0, expr.0, body.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ExprForGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::ExprFor,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+}
+impl ExprFor {
+    pub fn for_kw(&self, db: &dyn SyntaxGroup) -> TerminalFor {
+        TerminalFor::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
+        Pattern::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn identifier(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn body(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[4].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprForPtr(pub SyntaxStablePtrId);
+impl ExprForPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> ExprFor {
+        ExprFor::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ExprForGreen(pub GreenId);
+impl TypedSyntaxNode for ExprFor {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprFor);
+    type StablePtr = ExprForPtr;
+    type Green = ExprForGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ExprForGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::ExprFor,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalFor::missing(db).0,
+                    Pattern::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    Expr::missing(db).0,
+                    ExprBlock::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ExprFor,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ExprFor
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ExprForPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct ElseClause {
     node: SyntaxNode,
     children: Arc<Vec<SyntaxNode>>,
@@ -4283,55 +4437,64 @@ impl TypedSyntaxNode for ExprInlineMacro {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct StructArgExpr {
+pub struct ExprClosure {
     node: SyntaxNode,
     children: Arc<Vec<SyntaxNode>>,
 }
-impl StructArgExpr {
-    pub const INDEX_COLON: usize = 0;
-    pub const INDEX_EXPR: usize = 1;
+impl ExprClosure {
+    pub const INDEX_WRAPPER: usize = 0;
+    pub const INDEX_RET_TY: usize = 1;
+    pub const INDEX_EXPR: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        colon: TerminalColonGreen,
+        wrapper: ClosureParamWrapperGreen,
+        ret_ty: OptionReturnTypeClauseGreen,
         expr: ExprGreen,
-    ) -> StructArgExprGreen {
-        let children: Vec<GreenId> = vec![colon.0, expr.0];
+    ) -> ExprClosureGreen {
+        let children: Vec<GreenId> = vec![wrapper.0, ret_ty.0, expr.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        StructArgExprGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::StructArgExpr,
+        ExprClosureGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::ExprClosure,
             details: GreenNodeDetails::Node { children, width },
         })))
     }
 }
-impl StructArgExpr {
-    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
-        TerminalColon::from_syntax_node(db, self.children[0].clone())
+impl ExprClosure {
+    pub fn wrapper(&self, db: &dyn SyntaxGroup) -> ClosureParamWrapper {
+        ClosureParamWrapper::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn ret_ty(&self, db: &dyn SyntaxGroup) -> OptionReturnTypeClause {
+        OptionReturnTypeClause::from_syntax_node(db, self.children[1].clone())
     }
     pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[1].clone())
+        Expr::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgExprPtr(pub SyntaxStablePtrId);
-impl StructArgExprPtr {
+pub struct ExprClosurePtr(pub SyntaxStablePtrId);
+impl ExprClosurePtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
-    pub fn lookup(&self, db: &dyn SyntaxGroup) -> StructArgExpr {
-        StructArgExpr::from_syntax_node(db, self.0.lookup(db))
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> ExprClosure {
+        ExprClosure::from_syntax_node(db, self.0.lookup(db))
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgExprGreen(pub GreenId);
-impl TypedSyntaxNode for StructArgExpr {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgExpr);
-    type StablePtr = StructArgExprPtr;
-    type Green = StructArgExprGreen;
+pub struct ExprClosureGreen(pub GreenId);
+impl TypedSyntaxNode for ExprClosure {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ExprClosure);
+    type StablePtr = ExprClosurePtr;
+    type Green = ExprClosureGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StructArgExprGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::StructArgExpr,
+        ExprClosureGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::ExprClosure,
             details: GreenNodeDetails::Node {
-                children: vec![TerminalColon::missing(db).0, Expr::missing(db).0],
+                children: vec![
+                    ClosureParamWrapper::missing(db).0,
+                    OptionReturnTypeClause::missing(db).0,
+                    Expr::missing(db).0,
+                ],
                 width: TextWidth::default(),
             },
         })))
@@ -4340,10 +4503,10 @@ impl TypedSyntaxNode for StructArgExpr {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::StructArgExpr,
+            SyntaxKind::ExprClosure,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::StructArgExpr
+            SyntaxKind::ExprClosure
         );
         let children = db.get_children(node.clone());
         Self { node, children }
@@ -4352,134 +4515,159 @@ impl TypedSyntaxNode for StructArgExpr {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        StructArgExprPtr(self.node.0.stable_ptr)
+        ExprClosurePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub enum OptionStructArgExpr {
-    Empty(OptionStructArgExprEmpty),
-    StructArgExpr(StructArgExpr),
+pub enum ClosureParamWrapper {
+    Nullary(TerminalOrOr),
+    NAry(ClosureParamWrapperNAry),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionStructArgExprPtr(pub SyntaxStablePtrId);
-impl OptionStructArgExprPtr {
+pub struct ClosureParamWrapperPtr(pub SyntaxStablePtrId);
+impl ClosureParamWrapperPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
-    pub fn lookup(&self, db: &dyn SyntaxGroup) -> OptionStructArgExpr {
-        OptionStructArgExpr::from_syntax_node(db, self.0.lookup(db))
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> ClosureParamWrapper {
+        ClosureParamWrapper::from_syntax_node(db, self.0.lookup(db))
     }
 }
-impl From<OptionStructArgExprEmptyPtr> for OptionStructArgExprPtr {
-    fn from(value: OptionStructArgExprEmptyPtr) -> Self {
+impl From<TerminalOrOrPtr> for ClosureParamWrapperPtr {
+    fn from(value: TerminalOrOrPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<StructArgExprPtr> for OptionStructArgExprPtr {
-    fn from(value: StructArgExprPtr) -> Self {
+impl From<ClosureParamWrapperNAryPtr> for ClosureParamWrapperPtr {
+    fn from(value: ClosureParamWrapperNAryPtr) -> Self {
         Self(value.0)
     }
 }
-impl From<OptionStructArgExprEmptyGreen> for OptionStructArgExprGreen {
-    fn from(value: OptionStructArgExprEmptyGreen) -> Self {
+impl From<TerminalOrOrGreen> for ClosureParamWrapperGreen {
+    fn from(value: TerminalOrOrGreen) -> Self {
         Self(value.0)
     }
 }
-impl From<StructArgExprGreen> for OptionStructArgExprGreen {
-    fn from(value: StructArgExprGreen) -> Self {
+impl From<ClosureParamWrapperNAryGreen> for ClosureParamWrapperGreen {
+    fn from(value: ClosureParamWrapperNAryGreen) -> Self {
         Self(value.0)
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionStructArgExprGreen(pub GreenId);
-impl TypedSyntaxNode for OptionStructArgExpr {
+pub struct ClosureParamWrapperGreen(pub GreenId);
+impl TypedSyntaxNode for ClosureParamWrapper {
     const OPTIONAL_KIND: Option<SyntaxKind> = None;
-    type StablePtr = OptionStructArgExprPtr;
-    type Green = OptionStructArgExprGreen;
+    type StablePtr = ClosureParamWrapperPtr;
+    type Green = ClosureParamWrapperGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
         panic!("No missing variant.");
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         match kind {
-            SyntaxKind::OptionStructArgExprEmpty => {
-                OptionStructArgExpr::Empty(OptionStructArgExprEmpty::from_syntax_node(db, node))
+            SyntaxKind::TerminalOrOr => {
+                ClosureParamWrapper::Nullary(TerminalOrOr::from_syntax_node(db, node))
             }
-            SyntaxKind::StructArgExpr => {
-                OptionStructArgExpr::StructArgExpr(StructArgExpr::from_syntax_node(db, node))
+            SyntaxKind::ClosureParamWrapperNAry => {
+                ClosureParamWrapper::NAry(ClosureParamWrapperNAry::from_syntax_node(db, node))
             }
             _ => panic!(
                 "Unexpected syntax kind {:?} when constructing {}.",
-                kind, "OptionStructArgExpr"
+                kind, "ClosureParamWrapper"
             ),
         }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         match self {
-            OptionStructArgExpr::Empty(x) => x.as_syntax_node(),
-            OptionStructArgExpr::StructArgExpr(x) => x.as_syntax_node(),
+            ClosureParamWrapper::Nullary(x) => x.as_syntax_node(),
+            ClosureParamWrapper::NAry(x) => x.as_syntax_node(),
         }
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionStructArgExprPtr(self.as_syntax_node().0.stable_ptr)
+        ClosureParamWrapperPtr(self.as_syntax_node().0.stable_ptr)
     }
 }
-impl OptionStructArgExpr {
+impl ClosureParamWrapper {
     #[allow(clippy::match_like_matches_macro)]
     pub fn is_variant(kind: SyntaxKind) -> bool {
         match kind {
-            SyntaxKind::OptionStructArgExprEmpty => true,
-            SyntaxKind::StructArgExpr => true,
+            SyntaxKind::TerminalOrOr => true,
+            SyntaxKind::ClosureParamWrapperNAry => true,
             _ => false,
         }
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct OptionStructArgExprEmpty {
+pub struct ClosureParamWrapperNAry {
     node: SyntaxNode,
     children: Arc<Vec<SyntaxNode>>,
 }
-impl OptionStructArgExprEmpty {
-    pub fn new_green(db: &dyn SyntaxGroup) -> OptionStructArgExprEmptyGreen {
-        let children: Vec<GreenId> = vec![];
+impl ClosureParamWrapperNAry {
+    pub const INDEX_LEFTOR: usize = 0;
+    pub const INDEX_PARAMS: usize = 1;
+    pub const INDEX_RIGHTOR: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        leftor: TerminalOrGreen,
+        params: ClosureParamListGreen,
+        rightor: TerminalOrGreen,
+    ) -> ClosureParamWrapperNAryGreen {
+        let children: Vec<GreenId> = vec![leftor.0, params.0, rightor.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        OptionStructArgExprEmptyGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::OptionStructArgExprEmpty,
+        ClosureParamWrapperNAryGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::ClosureParamWrapperNAry,
             details: GreenNodeDetails::Node { children, width },
         })))
     }
 }
-impl OptionStructArgExprEmpty {}
+impl ClosureParamWrapperNAry {
+    pub fn leftor(&self, db: &dyn SyntaxGroup) -> TerminalOr {
+        TerminalOr::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn params(&self, db: &dyn SyntaxGroup) -> ClosureParamList {
+        ClosureParamList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rightor(&self, db: &dyn SyntaxGroup) -> TerminalOr {
+        TerminalOr::from_syntax_node(db, self.children[2].clone())
+    }
+}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionStructArgExprEmptyPtr(pub SyntaxStablePtrId);
-impl OptionStructArgExprEmptyPtr {
+pub struct ClosureParamWrapperNAryPtr(pub SyntaxStablePtrId);
+impl ClosureParamWrapperNAryPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
-    pub fn lookup(&self, db: &dyn SyntaxGroup) -> OptionStructArgExprEmpty {
-        OptionStructArgExprEmpty::from_syntax_node(db, self.0.lookup(db))
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> ClosureParamWrapperNAry {
+        ClosureParamWrapperNAry::from_syntax_node(db, self.0.lookup(db))
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct OptionStructArgExprEmptyGreen(pub GreenId);
-impl TypedSyntaxNode for OptionStructArgExprEmpty {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionStructArgExprEmpty);
-    type StablePtr = OptionStructArgExprEmptyPtr;
-    type Green = OptionStructArgExprEmptyGreen;
+pub struct ClosureParamWrapperNAryGreen(pub GreenId);
+impl TypedSyntaxNode for ClosureParamWrapperNAry {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ClosureParamWrapperNAry);
+    type StablePtr = ClosureParamWrapperNAryPtr;
+    type Green = ClosureParamWrapperNAryGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        OptionStructArgExprEmptyGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::OptionStructArgExprEmpty,
-            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        ClosureParamWrapperNAryGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::ClosureParamWrapperNAry,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalOr::missing(db).0,
+                    ClosureParamList::missing(db).0,
+                    TerminalOr::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
         })))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::OptionStructArgExprEmpty,
+            SyntaxKind::ClosureParamWrapperNAry,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::OptionStructArgExprEmpty
+            SyntaxKind::ClosureParamWrapperNAry
         );
         let children = db.get_children(node.clone());
         Self { node, children }
@@ -4488,64 +4676,424 @@ impl TypedSyntaxNode for OptionStructArgExprEmpty {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        OptionStructArgExprEmptyPtr(self.node.0.stable_ptr)
+        ClosureParamWrapperNAryPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct StructArgSingle {
-    node: SyntaxNode,
-    children: Arc<Vec<SyntaxNode>>,
+pub struct ClosureParamList(ElementList<ClosureParam, 2>);
+impl Deref for ClosureParamList {
+    type Target = ElementList<ClosureParam, 2>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
 }
-impl StructArgSingle {
-    pub const INDEX_IDENTIFIER: usize = 0;
-    pub const INDEX_ARG_EXPR: usize = 1;
+impl ClosureParamList {
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        identifier: TerminalIdentifierGreen,
-        arg_expr: OptionStructArgExprGreen,
-    ) -> StructArgSingleGreen {
-        let children: Vec<GreenId> = vec![identifier.0, arg_expr.0];
-        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        StructArgSingleGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::StructArgSingle,
-            details: GreenNodeDetails::Node { children, width },
+        children: Vec<ClosureParamListElementOrSeparatorGreen>,
+    ) -> ClosureParamListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        ClosureParamListGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::ClosureParamList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
         })))
     }
 }
-impl StructArgSingle {
-    pub fn identifier(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ClosureParamListPtr(pub SyntaxStablePtrId);
+impl ClosureParamListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
-    pub fn arg_expr(&self, db: &dyn SyntaxGroup) -> OptionStructArgExpr {
-        OptionStructArgExpr::from_syntax_node(db, self.children[1].clone())
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> ClosureParamList {
+        ClosureParamList::from_syntax_node(db, self.0.lookup(db))
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgSinglePtr(pub SyntaxStablePtrId);
-impl StructArgSinglePtr {
-    pub fn identifier_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
+pub enum ClosureParamListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(ClosureParamGreen),
+}
+impl From<TerminalCommaGreen> for ClosureParamListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        ClosureParamListElementOrSeparatorGreen::Separator(value)
     }
-    pub fn untyped(&self) -> SyntaxStablePtrId {
-        self.0
+}
+impl From<ClosureParamGreen> for ClosureParamListElementOrSeparatorGreen {
+    fn from(value: ClosureParamGreen) -> Self {
+        ClosureParamListElementOrSeparatorGreen::Element(value)
     }
-    pub fn lookup(&self, db: &dyn SyntaxGroup) -> StructArgSingle {
-        StructArgSingle::from_syntax_node(db, self.0.lookup(db))
+}
+impl ClosureParamListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            ClosureParamListElementOrSeparatorGreen::Separator(green) => green.0,
+            ClosureParamListElementOrSeparatorGreen::Element(green) => green.0,
+        }
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StructArgSingleGreen(pub GreenId);
-impl TypedSyntaxNode for StructArgSingle {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgSingle);
-    type StablePtr = StructArgSinglePtr;
-    type Green = StructArgSingleGreen;
+pub struct ClosureParamListGreen(pub GreenId);
+impl TypedSyntaxNode for ClosureParamList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ClosureParamList);
+    type StablePtr = ClosureParamListPtr;
+    type Green = ClosureParamListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StructArgSingleGreen(db.intern_green(Arc::new(GreenNode {
+        ClosureParamListGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::ClosureParamList,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ClosureParamListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ClosureParam {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl ClosureParam {
+    pub const INDEX_PATTERN: usize = 0;
+    pub const INDEX_TYPE_CLAUSE: usize = 1;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        pattern: PatternGreen,
+        type_clause: OptionTypeClauseGreen,
+    ) -> ClosureParamGreen {
+        let children: Vec<GreenId> = vec![pattern.0, type_clause.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        ClosureParamGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::ClosureParam,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+}
+impl ClosureParam {
+    pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
+        Pattern::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> OptionTypeClause {
+        OptionTypeClause::from_syntax_node(db, self.children[1].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ClosureParamPtr(pub SyntaxStablePtrId);
+impl ClosureParamPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> ClosureParam {
+        ClosureParam::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ClosureParamGreen(pub GreenId);
+impl TypedSyntaxNode for ClosureParam {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::ClosureParam);
+    type StablePtr = ClosureParamPtr;
+    type Green = ClosureParamGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        ClosureParamGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::ClosureParam,
+            details: GreenNodeDetails::Node {
+                children: vec![Pattern::missing(db).0, OptionTypeClause::missing(db).0],
+                width: TextWidth::default(),
+            },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ClosureParam,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ClosureParam
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ClosureParamPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StructArgExpr {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl StructArgExpr {
+    pub const INDEX_COLON: usize = 0;
+    pub const INDEX_EXPR: usize = 1;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        colon: TerminalColonGreen,
+        expr: ExprGreen,
+    ) -> StructArgExprGreen {
+        let children: Vec<GreenId> = vec![colon.0, expr.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StructArgExprGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::StructArgExpr,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+}
+impl StructArgExpr {
+    pub fn colon(&self, db: &dyn SyntaxGroup) -> TerminalColon {
+        TerminalColon::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn expr(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[1].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructArgExprPtr(pub SyntaxStablePtrId);
+impl StructArgExprPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> StructArgExpr {
+        StructArgExpr::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructArgExprGreen(pub GreenId);
+impl TypedSyntaxNode for StructArgExpr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgExpr);
+    type StablePtr = StructArgExprPtr;
+    type Green = StructArgExprGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StructArgExprGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::StructArgExpr,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalColon::missing(db).0, Expr::missing(db).0],
+                width: TextWidth::default(),
+            },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::StructArgExpr,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::StructArgExpr
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StructArgExprPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionStructArgExpr {
+    Empty(OptionStructArgExprEmpty),
+    StructArgExpr(StructArgExpr),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionStructArgExprPtr(pub SyntaxStablePtrId);
+impl OptionStructArgExprPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> OptionStructArgExpr {
+        OptionStructArgExpr::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+impl From<OptionStructArgExprEmptyPtr> for OptionStructArgExprPtr {
+    fn from(value: OptionStructArgExprEmptyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<StructArgExprPtr> for OptionStructArgExprPtr {
+    fn from(value: StructArgExprPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<OptionStructArgExprEmptyGreen> for OptionStructArgExprGreen {
+    fn from(value: OptionStructArgExprEmptyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<StructArgExprGreen> for OptionStructArgExprGreen {
+    fn from(value: StructArgExprGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionStructArgExprGreen(pub GreenId);
+impl TypedSyntaxNode for OptionStructArgExpr {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = OptionStructArgExprPtr;
+    type Green = OptionStructArgExprGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::OptionStructArgExprEmpty => {
+                OptionStructArgExpr::Empty(OptionStructArgExprEmpty::from_syntax_node(db, node))
+            }
+            SyntaxKind::StructArgExpr => {
+                OptionStructArgExpr::StructArgExpr(StructArgExpr::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionStructArgExpr"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            OptionStructArgExpr::Empty(x) => x.as_syntax_node(),
+            OptionStructArgExpr::StructArgExpr(x) => x.as_syntax_node(),
+        }
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionStructArgExprPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl OptionStructArgExpr {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::OptionStructArgExprEmpty => true,
+            SyntaxKind::StructArgExpr => true,
+            _ => false,
+        }
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OptionStructArgExprEmpty {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl OptionStructArgExprEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionStructArgExprEmptyGreen {
+        let children: Vec<GreenId> = vec![];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        OptionStructArgExprEmptyGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::OptionStructArgExprEmpty,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+}
+impl OptionStructArgExprEmpty {}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionStructArgExprEmptyPtr(pub SyntaxStablePtrId);
+impl OptionStructArgExprEmptyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> OptionStructArgExprEmpty {
+        OptionStructArgExprEmpty::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionStructArgExprEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionStructArgExprEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionStructArgExprEmpty);
+    type StablePtr = OptionStructArgExprEmptyPtr;
+    type Green = OptionStructArgExprEmptyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        OptionStructArgExprEmptyGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::OptionStructArgExprEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::OptionStructArgExprEmpty,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::OptionStructArgExprEmpty
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionStructArgExprEmptyPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StructArgSingle {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl StructArgSingle {
+    pub const INDEX_IDENTIFIER: usize = 0;
+    pub const INDEX_ARG_EXPR: usize = 1;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        identifier: TerminalIdentifierGreen,
+        arg_expr: OptionStructArgExprGreen,
+    ) -> StructArgSingleGreen {
+        let children: Vec<GreenId> = vec![identifier.0, arg_expr.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StructArgSingleGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::StructArgSingle,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+}
+impl StructArgSingle {
+    pub fn identifier(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn arg_expr(&self, db: &dyn SyntaxGroup) -> OptionStructArgExpr {
+        OptionStructArgExpr::from_syntax_node(db, self.children[1].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructArgSinglePtr(pub SyntaxStablePtrId);
+impl StructArgSinglePtr {
+    pub fn identifier_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> StructArgSingle {
+        StructArgSingle::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructArgSingleGreen(pub GreenId);
+impl TypedSyntaxNode for StructArgSingle {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructArgSingle);
+    type StablePtr = StructArgSinglePtr;
+    type Green = StructArgSingleGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StructArgSingleGreen(db.intern_green(Arc::new(GreenNode {
             kind: SyntaxKind::StructArgSingle,
             details: GreenNodeDetails::Node {
                 children: vec![
@@ -5143,6 +5691,7 @@ pub enum Pattern {
     String(TerminalString),
     Identifier(PatternIdentifier),
     Struct(PatternStruct),
+    TupleStruct(PatternTupleStruct),
     Tuple(PatternTuple),
     Enum(PatternEnum),
     Path(ExprPath),
@@ -5197,6 +5746,11 @@ impl From<PatternStructPtr> for PatternPtr {
         Self(value.0)
     }
 }
+impl From<PatternTupleStructPtr> for PatternPtr {
+    fn from(value: PatternTupleStructPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<PatternTuplePtr> for PatternPtr {
     fn from(value: PatternTuplePtr) -> Self {
         Self(value.0)
@@ -5252,6 +5806,11 @@ impl From<PatternStructGreen> for PatternGreen {
         Self(value.0)
     }
 }
+impl From<PatternTupleStructGreen> for PatternGreen {
+    fn from(value: PatternTupleStructGreen) -> Self {
+        Self(value.0)
+    }
+}
 impl From<PatternTupleGreen> for PatternGreen {
     fn from(value: PatternTupleGreen) -> Self {
         Self(value.0)
@@ -5297,6 +5856,9 @@ impl TypedSyntaxNode for Pattern {
                 Pattern::Identifier(PatternIdentifier::from_syntax_node(db, node))
             }
             SyntaxKind::PatternStruct => Pattern::Struct(PatternStruct::from_syntax_node(db, node)),
+            SyntaxKind::PatternTupleStruct => {
+                Pattern::TupleStruct(PatternTupleStruct::from_syntax_node(db, node))
+            }
             SyntaxKind::PatternTuple => Pattern::Tuple(PatternTuple::from_syntax_node(db, node)),
             SyntaxKind::PatternEnum => Pattern::Enum(PatternEnum::from_syntax_node(db, node)),
             SyntaxKind::ExprPath => Pattern::Path(ExprPath::from_syntax_node(db, node)),
@@ -5313,6 +5875,7 @@ impl TypedSyntaxNode for Pattern {
             Pattern::String(x) => x.as_syntax_node(),
             Pattern::Identifier(x) => x.as_syntax_node(),
             Pattern::Struct(x) => x.as_syntax_node(),
+            Pattern::TupleStruct(x) => x.as_syntax_node(),
             Pattern::Tuple(x) => x.as_syntax_node(),
             Pattern::Enum(x) => x.as_syntax_node(),
             Pattern::Path(x) => x.as_syntax_node(),
@@ -5334,6 +5897,7 @@ impl Pattern {
             SyntaxKind::TerminalString => true,
             SyntaxKind::PatternIdentifier => true,
             SyntaxKind::PatternStruct => true,
+            SyntaxKind::PatternTupleStruct => true,
             SyntaxKind::PatternTuple => true,
             SyntaxKind::PatternEnum => true,
             SyntaxKind::ExprPath => true,
@@ -5553,39 +6117,127 @@ impl From<TerminalCommaGreen> for PatternStructParamListElementOrSeparatorGreen
         PatternStructParamListElementOrSeparatorGreen::Separator(value)
     }
 }
-impl From<PatternStructParamGreen> for PatternStructParamListElementOrSeparatorGreen {
-    fn from(value: PatternStructParamGreen) -> Self {
-        PatternStructParamListElementOrSeparatorGreen::Element(value)
+impl From<PatternStructParamGreen> for PatternStructParamListElementOrSeparatorGreen {
+    fn from(value: PatternStructParamGreen) -> Self {
+        PatternStructParamListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl PatternStructParamListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            PatternStructParamListElementOrSeparatorGreen::Separator(green) => green.0,
+            PatternStructParamListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PatternStructParamListGreen(pub GreenId);
+impl TypedSyntaxNode for PatternStructParamList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternStructParamList);
+    type StablePtr = PatternStructParamListPtr;
+    type Green = PatternStructParamListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        PatternStructParamListGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::PatternStructParamList,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        PatternStructParamListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PatternTupleStruct {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl PatternTupleStruct {
+    pub const INDEX_PATH: usize = 0;
+    pub const INDEX_LPAREN: usize = 1;
+    pub const INDEX_PATTERNS: usize = 2;
+    pub const INDEX_RPAREN: usize = 3;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        path: ExprPathGreen,
+        lparen: TerminalLParenGreen,
+        patterns: PatternListGreen,
+        rparen: TerminalRParenGreen,
+    ) -> PatternTupleStructGreen {
+        let children: Vec<GreenId> = vec![path.0, lparen.0, patterns.0, rparen.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        PatternTupleStructGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::PatternTupleStruct,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+}
+impl PatternTupleStruct {
+    pub fn path(&self, db: &dyn SyntaxGroup) -> ExprPath {
+        ExprPath::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
+        TerminalLParen::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn patterns(&self, db: &dyn SyntaxGroup) -> PatternList {
+        PatternList::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn rparen(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
+        TerminalRParen::from_syntax_node(db, self.children[3].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PatternTupleStructPtr(pub SyntaxStablePtrId);
+impl PatternTupleStructPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
     }
-}
-impl PatternStructParamListElementOrSeparatorGreen {
-    fn id(&self) -> GreenId {
-        match self {
-            PatternStructParamListElementOrSeparatorGreen::Separator(green) => green.0,
-            PatternStructParamListElementOrSeparatorGreen::Element(green) => green.0,
-        }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> PatternTupleStruct {
+        PatternTupleStruct::from_syntax_node(db, self.0.lookup(db))
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct PatternStructParamListGreen(pub GreenId);
-impl TypedSyntaxNode for PatternStructParamList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternStructParamList);
-    type StablePtr = PatternStructParamListPtr;
-    type Green = PatternStructParamListGreen;
+pub struct PatternTupleStructGreen(pub GreenId);
+impl TypedSyntaxNode for PatternTupleStruct {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::PatternTupleStruct);
+    type StablePtr = PatternTupleStructPtr;
+    type Green = PatternTupleStructGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        PatternStructParamListGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::PatternStructParamList,
-            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        PatternTupleStructGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::PatternTupleStruct,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    ExprPath::missing(db).0,
+                    TerminalLParen::missing(db).0,
+                    PatternList::missing(db).0,
+                    TerminalRParen::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
         })))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        Self(ElementList::new(node))
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::PatternTupleStruct,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::PatternTupleStruct
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        PatternStructParamListPtr(self.node.0.stable_ptr)
+        PatternTupleStructPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -6969,109 +7621,332 @@ impl TypedSyntaxNode for StatementMissing {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct StatementLet {
+pub struct StatementLet {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl StatementLet {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_LET_KW: usize = 1;
+    pub const INDEX_PATTERN: usize = 2;
+    pub const INDEX_TYPE_CLAUSE: usize = 3;
+    pub const INDEX_EQ: usize = 4;
+    pub const INDEX_RHS: usize = 5;
+    pub const INDEX_LET_ELSE_CLAUSE: usize = 6;
+    pub const INDEX_SEMICOLON: usize = 7;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        let_kw: TerminalLetGreen,
+        pattern: PatternGreen,
+        type_clause: OptionTypeClauseGreen,
+        eq: TerminalEqGreen,
+        rhs: ExprGreen,
+        let_else_clause: OptionLetElseClauseGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> StatementLetGreen {
+        let children: Vec<GreenId> = vec![
+            attributes.0,
+            let_kw.0,
+            pattern.0,
+            type_clause.0,
+            eq.0,
+            rhs.0,
+            let_else_clause.0,
+            semicolon.0,
+        ];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StatementLetGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::StatementLet,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+}
+impl StatementLet {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn let_kw(&self, db: &dyn SyntaxGroup) -> TerminalLet {
+        TerminalLet::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
+        Pattern::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> OptionTypeClause {
+        OptionTypeClause::from_syntax_node(db, self.children[3].clone())
+    }
+    pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
+        TerminalEq::from_syntax_node(db, self.children[4].clone())
+    }
+    pub fn rhs(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[5].clone())
+    }
+    pub fn let_else_clause(&self, db: &dyn SyntaxGroup) -> OptionLetElseClause {
+        OptionLetElseClause::from_syntax_node(db, self.children[6].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[7].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementLetPtr(pub SyntaxStablePtrId);
+impl StatementLetPtr {
+    pub fn pattern_green(self, db: &dyn SyntaxGroup) -> PatternGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            PatternGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> StatementLet {
+        StatementLet::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StatementLetGreen(pub GreenId);
+impl TypedSyntaxNode for StatementLet {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementLet);
+    type StablePtr = StatementLetPtr;
+    type Green = StatementLetGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StatementLetGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::StatementLet,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    TerminalLet::missing(db).0,
+                    Pattern::missing(db).0,
+                    OptionTypeClause::missing(db).0,
+                    TerminalEq::missing(db).0,
+                    Expr::missing(db).0,
+                    OptionLetElseClause::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::StatementLet,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::StatementLet
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StatementLetPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LetElseClause {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl LetElseClause {
+    pub const INDEX_ELSE_KW: usize = 0;
+    pub const INDEX_ELSE_BLOCK: usize = 1;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        else_kw: TerminalElseGreen,
+        else_block: ExprBlockGreen,
+    ) -> LetElseClauseGreen {
+        let children: Vec<GreenId> = vec![else_kw.0, else_block.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        LetElseClauseGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::LetElseClause,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+}
+impl LetElseClause {
+    pub fn else_kw(&self, db: &dyn SyntaxGroup) -> TerminalElse {
+        TerminalElse::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn else_block(&self, db: &dyn SyntaxGroup) -> ExprBlock {
+        ExprBlock::from_syntax_node(db, self.children[1].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LetElseClausePtr(pub SyntaxStablePtrId);
+impl LetElseClausePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> LetElseClause {
+        LetElseClause::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct LetElseClauseGreen(pub GreenId);
+impl TypedSyntaxNode for LetElseClause {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::LetElseClause);
+    type StablePtr = LetElseClausePtr;
+    type Green = LetElseClauseGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        LetElseClauseGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::LetElseClause,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalElse::missing(db).0, ExprBlock::missing(db).0],
+                width: TextWidth::default(),
+            },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::LetElseClause,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::LetElseClause
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        LetElseClausePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum OptionLetElseClause {
+    Empty(OptionLetElseClauseEmpty),
+    LetElseClause(LetElseClause),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionLetElseClausePtr(pub SyntaxStablePtrId);
+impl OptionLetElseClausePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> OptionLetElseClause {
+        OptionLetElseClause::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+impl From<OptionLetElseClauseEmptyPtr> for OptionLetElseClausePtr {
+    fn from(value: OptionLetElseClauseEmptyPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<LetElseClausePtr> for OptionLetElseClausePtr {
+    fn from(value: LetElseClausePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<OptionLetElseClauseEmptyGreen> for OptionLetElseClauseGreen {
+    fn from(value: OptionLetElseClauseEmptyGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<LetElseClauseGreen> for OptionLetElseClauseGreen {
+    fn from(value: LetElseClauseGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct OptionLetElseClauseGreen(pub GreenId);
+impl TypedSyntaxNode for OptionLetElseClause {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = OptionLetElseClausePtr;
+    type Green = OptionLetElseClauseGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::OptionLetElseClauseEmpty => {
+                OptionLetElseClause::Empty(OptionLetElseClauseEmpty::from_syntax_node(db, node))
+            }
+            SyntaxKind::LetElseClause => {
+                OptionLetElseClause::LetElseClause(LetElseClause::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "OptionLetElseClause"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            OptionLetElseClause::Empty(x) => x.as_syntax_node(),
+            OptionLetElseClause::LetElseClause(x) => x.as_syntax_node(),
+        }
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        OptionLetElseClausePtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl OptionLetElseClause {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::OptionLetElseClauseEmpty => true,
+            SyntaxKind::LetElseClause => true,
+            _ => false,
+        }
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OptionLetElseClauseEmpty {
     node: SyntaxNode,
     children: Arc<Vec<SyntaxNode>>,
 }
-impl StatementLet {
-    pub const INDEX_ATTRIBUTES: usize = 0;
-    pub const INDEX_LET_KW: usize = 1;
-    pub const INDEX_PATTERN: usize = 2;
-    pub const INDEX_TYPE_CLAUSE: usize = 3;
-    pub const INDEX_EQ: usize = 4;
-    pub const INDEX_RHS: usize = 5;
-    pub const INDEX_SEMICOLON: usize = 6;
-    pub fn new_green(
-        db: &dyn SyntaxGroup,
-        attributes: AttributeListGreen,
-        let_kw: TerminalLetGreen,
-        pattern: PatternGreen,
-        type_clause: OptionTypeClauseGreen,
-        eq: TerminalEqGreen,
-        rhs: ExprGreen,
-        semicolon: TerminalSemicolonGreen,
-    ) -> StatementLetGreen {
-        let children: Vec<GreenId> =
-            vec![attributes.0, let_kw.0, pattern.0, type_clause.0, eq.0, rhs.0, semicolon.0];
+impl OptionLetElseClauseEmpty {
+    pub fn new_green(db: &dyn SyntaxGroup) -> OptionLetElseClauseEmptyGreen {
+        let children: Vec<GreenId> = vec![];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        StatementLetGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::StatementLet,
+        OptionLetElseClauseEmptyGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::OptionLetElseClauseEmpty,
             details: GreenNodeDetails::Node { children, width },
         })))
     }
 }
-impl StatementLet {
-    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
-        AttributeList::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn let_kw(&self, db: &dyn SyntaxGroup) -> TerminalLet {
-        TerminalLet::from_syntax_node(db, self.children[1].clone())
-    }
-    pub fn pattern(&self, db: &dyn SyntaxGroup) -> Pattern {
-        Pattern::from_syntax_node(db, self.children[2].clone())
-    }
-    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> OptionTypeClause {
-        OptionTypeClause::from_syntax_node(db, self.children[3].clone())
-    }
-    pub fn eq(&self, db: &dyn SyntaxGroup) -> TerminalEq {
-        TerminalEq::from_syntax_node(db, self.children[4].clone())
-    }
-    pub fn rhs(&self, db: &dyn SyntaxGroup) -> Expr {
-        Expr::from_syntax_node(db, self.children[5].clone())
-    }
-    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
-        TerminalSemicolon::from_syntax_node(db, self.children[6].clone())
-    }
-}
+impl OptionLetElseClauseEmpty {}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementLetPtr(pub SyntaxStablePtrId);
-impl StatementLetPtr {
-    pub fn pattern_green(self, db: &dyn SyntaxGroup) -> PatternGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            PatternGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct OptionLetElseClauseEmptyPtr(pub SyntaxStablePtrId);
+impl OptionLetElseClauseEmptyPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
-    pub fn lookup(&self, db: &dyn SyntaxGroup) -> StatementLet {
-        StatementLet::from_syntax_node(db, self.0.lookup(db))
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> OptionLetElseClauseEmpty {
+        OptionLetElseClauseEmpty::from_syntax_node(db, self.0.lookup(db))
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct StatementLetGreen(pub GreenId);
-impl TypedSyntaxNode for StatementLet {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StatementLet);
-    type StablePtr = StatementLetPtr;
-    type Green = StatementLetGreen;
+pub struct OptionLetElseClauseEmptyGreen(pub GreenId);
+impl TypedSyntaxNode for OptionLetElseClauseEmpty {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::OptionLetElseClauseEmpty);
+    type StablePtr = OptionLetElseClauseEmptyPtr;
+    type Green = OptionLetElseClauseEmptyGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        StatementLetGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::StatementLet,
-            details: GreenNodeDetails::Node {
-                children: vec![
-                    AttributeList::missing(db).0,
-                    TerminalLet::missing(db).0,
-                    Pattern::missing(db).0,
-                    OptionTypeClause::missing(db).0,
-                    TerminalEq::missing(db).0,
-                    Expr::missing(db).0,
-                    TerminalSemicolon::missing(db).0,
-                ],
-                width: TextWidth::default(),
-            },
+        OptionLetElseClauseEmptyGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::OptionLetElseClauseEmpty,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
         })))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::StatementLet,
+            SyntaxKind::OptionLetElseClauseEmpty,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::StatementLet
+            SyntaxKind::OptionLetElseClauseEmpty
         );
         let children = db.get_children(node.clone());
         Self { node, children }
@@ -7080,7 +7955,7 @@ impl TypedSyntaxNode for StatementLet {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        StatementLetPtr(self.node.0.stable_ptr)
+        OptionLetElseClauseEmptyPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -8579,96 +9454,256 @@ impl TypedSyntaxNode for FunctionSignature {
         })))
     }
     fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
-        let kind = node.kind(db);
-        assert_eq!(
-            kind,
-            SyntaxKind::FunctionSignature,
-            "Unexpected SyntaxKind {:?}. Expected {:?}.",
-            kind,
-            SyntaxKind::FunctionSignature
-        );
-        let children = db.get_children(node.clone());
-        Self { node, children }
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::FunctionSignature,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::FunctionSignature
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        FunctionSignaturePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Member {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl Member {
+    pub const INDEX_ATTRIBUTES: usize = 0;
+    pub const INDEX_VISIBILITY: usize = 1;
+    pub const INDEX_NAME: usize = 2;
+    pub const INDEX_TYPE_CLAUSE: usize = 3;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        attributes: AttributeListGreen,
+        visibility: VisibilityGreen,
+        name: TerminalIdentifierGreen,
+        type_clause: TypeClauseGreen,
+    ) -> MemberGreen {
+        let children: Vec<GreenId> = vec![attributes.0, visibility.0, name.0, type_clause.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        MemberGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::Member,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+}
+impl Member {
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        AttributeList::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
+        Visibility::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
+        TypeClause::from_syntax_node(db, self.children[3].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MemberPtr(pub SyntaxStablePtrId);
+impl MemberPtr {
+    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
+        let ptr = db.lookup_intern_stable_ptr(self.0);
+        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
+            TerminalIdentifierGreen(key_fields[0])
+        } else {
+            panic!("Unexpected key field query on root.");
+        }
+    }
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> Member {
+        Member::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MemberGreen(pub GreenId);
+impl TypedSyntaxNode for Member {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::Member);
+    type StablePtr = MemberPtr;
+    type Green = MemberGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        MemberGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::Member,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    AttributeList::missing(db).0,
+                    Visibility::missing(db).0,
+                    TerminalIdentifier::missing(db).0,
+                    TypeClause::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::Member,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::Member
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        MemberPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MemberList(ElementList<Member, 2>);
+impl Deref for MemberList {
+    type Target = ElementList<Member, 2>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl MemberList {
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        children: Vec<MemberListElementOrSeparatorGreen>,
+    ) -> MemberListGreen {
+        let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
+        MemberListGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::MemberList,
+            details: GreenNodeDetails::Node {
+                children: children.iter().map(|x| x.id()).collect(),
+                width,
+            },
+        })))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MemberListPtr(pub SyntaxStablePtrId);
+impl MemberListPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> MemberList {
+        MemberList::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum MemberListElementOrSeparatorGreen {
+    Separator(TerminalCommaGreen),
+    Element(MemberGreen),
+}
+impl From<TerminalCommaGreen> for MemberListElementOrSeparatorGreen {
+    fn from(value: TerminalCommaGreen) -> Self {
+        MemberListElementOrSeparatorGreen::Separator(value)
+    }
+}
+impl From<MemberGreen> for MemberListElementOrSeparatorGreen {
+    fn from(value: MemberGreen) -> Self {
+        MemberListElementOrSeparatorGreen::Element(value)
+    }
+}
+impl MemberListElementOrSeparatorGreen {
+    fn id(&self) -> GreenId {
+        match self {
+            MemberListElementOrSeparatorGreen::Separator(green) => green.0,
+            MemberListElementOrSeparatorGreen::Element(green) => green.0,
+        }
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MemberListGreen(pub GreenId);
+impl TypedSyntaxNode for MemberList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::MemberList);
+    type StablePtr = MemberListPtr;
+    type Green = MemberListGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        MemberListGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::MemberList,
+            details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        Self(ElementList::new(node))
     }
     fn as_syntax_node(&self) -> SyntaxNode {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        FunctionSignaturePtr(self.node.0.stable_ptr)
+        MemberListPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct Member {
+pub struct TupleMember {
     node: SyntaxNode,
     children: Arc<Vec<SyntaxNode>>,
 }
-impl Member {
+impl TupleMember {
     pub const INDEX_ATTRIBUTES: usize = 0;
     pub const INDEX_VISIBILITY: usize = 1;
-    pub const INDEX_NAME: usize = 2;
-    pub const INDEX_TYPE_CLAUSE: usize = 3;
+    pub const INDEX_TY: usize = 2;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
         visibility: VisibilityGreen,
-        name: TerminalIdentifierGreen,
-        type_clause: TypeClauseGreen,
-    ) -> MemberGreen {
-        let children: Vec<GreenId> = vec![attributes.0, visibility.0, name.0, type_clause.0];
+        ty: ExprGreen,
+    ) -> TupleMemberGreen {
+        let children: Vec<GreenId> = vec![attributes.0, visibility.0, ty.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        MemberGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::Member,
+        TupleMemberGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::TupleMember,
             details: GreenNodeDetails::Node { children, width },
         })))
     }
 }
-impl Member {
+impl TupleMember {
     pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
         AttributeList::from_syntax_node(db, self.children[0].clone())
     }
     pub fn visibility(&self, db: &dyn SyntaxGroup) -> Visibility {
         Visibility::from_syntax_node(db, self.children[1].clone())
     }
-    pub fn name(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[2].clone())
-    }
-    pub fn type_clause(&self, db: &dyn SyntaxGroup) -> TypeClause {
-        TypeClause::from_syntax_node(db, self.children[3].clone())
+    pub fn ty(&self, db: &dyn SyntaxGroup) -> Expr {
+        Expr::from_syntax_node(db, self.children[2].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MemberPtr(pub SyntaxStablePtrId);
-impl MemberPtr {
-    pub fn name_green(self, db: &dyn SyntaxGroup) -> TerminalIdentifierGreen {
-        let ptr = db.lookup_intern_stable_ptr(self.0);
-        if let SyntaxStablePtr::Child { key_fields, .. } = ptr {
-            TerminalIdentifierGreen(key_fields[0])
-        } else {
-            panic!("Unexpected key field query on root.");
-        }
-    }
+pub struct TupleMemberPtr(pub SyntaxStablePtrId);
+impl TupleMemberPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
-    pub fn lookup(&self, db: &dyn SyntaxGroup) -> Member {
-        Member::from_syntax_node(db, self.0.lookup(db))
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> TupleMember {
+        TupleMember::from_syntax_node(db, self.0.lookup(db))
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MemberGreen(pub GreenId);
-impl TypedSyntaxNode for Member {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::Member);
-    type StablePtr = MemberPtr;
-    type Green = MemberGreen;
+pub struct TupleMemberGreen(pub GreenId);
+impl TypedSyntaxNode for TupleMember {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TupleMember);
+    type StablePtr = TupleMemberPtr;
+    type Green = TupleMemberGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        MemberGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::Member,
+        TupleMemberGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::TupleMember,
             details: GreenNodeDetails::Node {
                 children: vec![
                     AttributeList::missing(db).0,
                     Visibility::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    TypeClause::missing(db).0,
+                    Expr::missing(db).0,
                 ],
                 width: TextWidth::default(),
             },
@@ -8678,10 +9713,10 @@ impl TypedSyntaxNode for Member {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::Member,
+            SyntaxKind::TupleMember,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::Member
+            SyntaxKind::TupleMember
         );
         let children = db.get_children(node.clone());
         Self { node, children }
@@ -8690,25 +9725,25 @@ impl TypedSyntaxNode for Member {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        MemberPtr(self.node.0.stable_ptr)
+        TupleMemberPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct MemberList(ElementList<Member, 2>);
-impl Deref for MemberList {
-    type Target = ElementList<Member, 2>;
+pub struct TupleMemberList(ElementList<TupleMember, 2>);
+impl Deref for TupleMemberList {
+    type Target = ElementList<TupleMember, 2>;
     fn deref(&self) -> &Self::Target {
         &self.0
     }
 }
-impl MemberList {
+impl TupleMemberList {
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        children: Vec<MemberListElementOrSeparatorGreen>,
-    ) -> MemberListGreen {
+        children: Vec<TupleMemberListElementOrSeparatorGreen>,
+    ) -> TupleMemberListGreen {
         let width = children.iter().map(|id| db.lookup_intern_green(id.id()).width()).sum();
-        MemberListGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::MemberList,
+        TupleMemberListGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::TupleMemberList,
             details: GreenNodeDetails::Node {
                 children: children.iter().map(|x| x.id()).collect(),
                 width,
@@ -8717,47 +9752,47 @@ impl MemberList {
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MemberListPtr(pub SyntaxStablePtrId);
-impl MemberListPtr {
+pub struct TupleMemberListPtr(pub SyntaxStablePtrId);
+impl TupleMemberListPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
-    pub fn lookup(&self, db: &dyn SyntaxGroup) -> MemberList {
-        MemberList::from_syntax_node(db, self.0.lookup(db))
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> TupleMemberList {
+        TupleMemberList::from_syntax_node(db, self.0.lookup(db))
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub enum MemberListElementOrSeparatorGreen {
+pub enum TupleMemberListElementOrSeparatorGreen {
     Separator(TerminalCommaGreen),
-    Element(MemberGreen),
+    Element(TupleMemberGreen),
 }
-impl From<TerminalCommaGreen> for MemberListElementOrSeparatorGreen {
+impl From<TerminalCommaGreen> for TupleMemberListElementOrSeparatorGreen {
     fn from(value: TerminalCommaGreen) -> Self {
-        MemberListElementOrSeparatorGreen::Separator(value)
+        TupleMemberListElementOrSeparatorGreen::Separator(value)
     }
 }
-impl From<MemberGreen> for MemberListElementOrSeparatorGreen {
-    fn from(value: MemberGreen) -> Self {
-        MemberListElementOrSeparatorGreen::Element(value)
+impl From<TupleMemberGreen> for TupleMemberListElementOrSeparatorGreen {
+    fn from(value: TupleMemberGreen) -> Self {
+        TupleMemberListElementOrSeparatorGreen::Element(value)
     }
 }
-impl MemberListElementOrSeparatorGreen {
+impl TupleMemberListElementOrSeparatorGreen {
     fn id(&self) -> GreenId {
         match self {
-            MemberListElementOrSeparatorGreen::Separator(green) => green.0,
-            MemberListElementOrSeparatorGreen::Element(green) => green.0,
+            TupleMemberListElementOrSeparatorGreen::Separator(green) => green.0,
+            TupleMemberListElementOrSeparatorGreen::Element(green) => green.0,
         }
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct MemberListGreen(pub GreenId);
-impl TypedSyntaxNode for MemberList {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::MemberList);
-    type StablePtr = MemberListPtr;
-    type Green = MemberListGreen;
+pub struct TupleMemberListGreen(pub GreenId);
+impl TypedSyntaxNode for TupleMemberList {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TupleMemberList);
+    type StablePtr = TupleMemberListPtr;
+    type Green = TupleMemberListGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        MemberListGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::MemberList,
+        TupleMemberListGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::TupleMemberList,
             details: GreenNodeDetails::Node { children: vec![], width: TextWidth::default() },
         })))
     }
@@ -8768,7 +9803,79 @@ impl TypedSyntaxNode for MemberList {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        MemberListPtr(self.node.0.stable_ptr)
+        TupleMemberListPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum StructMember {
+    Named(Member),
+    Tuple(TupleMember),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructMemberPtr(pub SyntaxStablePtrId);
+impl StructMemberPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> StructMember {
+        StructMember::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+impl From<MemberPtr> for StructMemberPtr {
+    fn from(value: MemberPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TupleMemberPtr> for StructMemberPtr {
+    fn from(value: TupleMemberPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<MemberGreen> for StructMemberGreen {
+    fn from(value: MemberGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TupleMemberGreen> for StructMemberGreen {
+    fn from(value: TupleMemberGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructMemberGreen(pub GreenId);
+impl TypedSyntaxNode for StructMember {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = StructMemberPtr;
+    type Green = StructMemberGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::Member => StructMember::Named(Member::from_syntax_node(db, node)),
+            SyntaxKind::TupleMember => StructMember::Tuple(TupleMember::from_syntax_node(db, node)),
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "StructMember"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            StructMember::Named(x) => x.as_syntax_node(),
+            StructMember::Tuple(x) => x.as_syntax_node(),
+        }
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StructMemberPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl StructMember {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::Member => true,
+            SyntaxKind::TupleMember => true,
+            _ => false,
+        }
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -9501,71 +10608,223 @@ impl TypedSyntaxNode for VisibilityDefault {
         Self { node, children }
     }
     fn as_syntax_node(&self) -> SyntaxNode {
-        self.node.clone()
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        VisibilityDefaultPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct VisibilityPubArgumentClause {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl VisibilityPubArgumentClause {
+    pub const INDEX_LPAREN: usize = 0;
+    pub const INDEX_ARGUMENT: usize = 1;
+    pub const INDEX_RPAREN: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        lparen: TerminalLParenGreen,
+        argument: VisibilityPubArgumentGreen,
+        rparen: TerminalRParenGreen,
+    ) -> VisibilityPubArgumentClauseGreen {
+        let children: Vec<GreenId> = vec![lparen.0, argument.0, rparen.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        VisibilityPubArgumentClauseGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::VisibilityPubArgumentClause,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+}
+impl VisibilityPubArgumentClause {
+    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
+        TerminalLParen::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn argument(&self, db: &dyn SyntaxGroup) -> VisibilityPubArgument {
+        VisibilityPubArgument::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rparen(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
+        TerminalRParen::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityPubArgumentClausePtr(pub SyntaxStablePtrId);
+impl VisibilityPubArgumentClausePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> VisibilityPubArgumentClause {
+        VisibilityPubArgumentClause::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityPubArgumentClauseGreen(pub GreenId);
+impl TypedSyntaxNode for VisibilityPubArgumentClause {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::VisibilityPubArgumentClause);
+    type StablePtr = VisibilityPubArgumentClausePtr;
+    type Green = VisibilityPubArgumentClauseGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        VisibilityPubArgumentClauseGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::VisibilityPubArgumentClause,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLParen::missing(db).0,
+                    VisibilityPubArgument::missing(db).0,
+                    TerminalRParen::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::VisibilityPubArgumentClause,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::VisibilityPubArgumentClause
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        VisibilityPubArgumentClausePtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum VisibilityPubArgument {
+    Simple(TerminalIdentifier),
+    In(VisibilityPubArgumentIn),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityPubArgumentPtr(pub SyntaxStablePtrId);
+impl VisibilityPubArgumentPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> VisibilityPubArgument {
+        VisibilityPubArgument::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+impl From<TerminalIdentifierPtr> for VisibilityPubArgumentPtr {
+    fn from(value: TerminalIdentifierPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<VisibilityPubArgumentInPtr> for VisibilityPubArgumentPtr {
+    fn from(value: VisibilityPubArgumentInPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalIdentifierGreen> for VisibilityPubArgumentGreen {
+    fn from(value: TerminalIdentifierGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<VisibilityPubArgumentInGreen> for VisibilityPubArgumentGreen {
+    fn from(value: VisibilityPubArgumentInGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct VisibilityPubArgumentGreen(pub GreenId);
+impl TypedSyntaxNode for VisibilityPubArgument {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = VisibilityPubArgumentPtr;
+    type Green = VisibilityPubArgumentGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::TerminalIdentifier => {
+                VisibilityPubArgument::Simple(TerminalIdentifier::from_syntax_node(db, node))
+            }
+            SyntaxKind::VisibilityPubArgumentIn => {
+                VisibilityPubArgument::In(VisibilityPubArgumentIn::from_syntax_node(db, node))
+            }
+            _ => panic!(
+                "Unexpected syntax kind {:?} when constructing {}.",
+                kind, "VisibilityPubArgument"
+            ),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            VisibilityPubArgument::Simple(x) => x.as_syntax_node(),
+            VisibilityPubArgument::In(x) => x.as_syntax_node(),
+        }
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        VisibilityDefaultPtr(self.node.0.stable_ptr)
+        VisibilityPubArgumentPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl VisibilityPubArgument {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::TerminalIdentifier => true,
+            SyntaxKind::VisibilityPubArgumentIn => true,
+            _ => false,
+        }
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct VisibilityPubArgumentClause {
+pub struct VisibilityPubArgumentIn {
     node: SyntaxNode,
     children: Arc<Vec<SyntaxNode>>,
 }
-impl VisibilityPubArgumentClause {
-    pub const INDEX_LPAREN: usize = 0;
-    pub const INDEX_ARGUMENT: usize = 1;
-    pub const INDEX_RPAREN: usize = 2;
+impl VisibilityPubArgumentIn {
+    pub const INDEX_IN_KW: usize = 0;
+    pub const INDEX_PATH: usize = 1;
     pub fn new_green(
         db: &dyn SyntaxGroup,
-        lparen: TerminalLParenGreen,
-        argument: TerminalIdentifierGreen,
-        rparen: TerminalRParenGreen,
-    ) -> VisibilityPubArgumentClauseGreen {
-        let children: Vec<GreenId> = vec![lparen.0, argument.0, rparen.0];
+        in_kw: TerminalIdentifierGreen,
+        path: ExprPathGreen,
+    ) -> VisibilityPubArgumentInGreen {
+        let children: Vec<GreenId> = vec![in_kw.0, path.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
-        VisibilityPubArgumentClauseGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::VisibilityPubArgumentClause,
+        VisibilityPubArgumentInGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::VisibilityPubArgumentIn,
             details: GreenNodeDetails::Node { children, width },
         })))
     }
 }
-impl VisibilityPubArgumentClause {
-    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
-        TerminalLParen::from_syntax_node(db, self.children[0].clone())
-    }
-    pub fn argument(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
-        TerminalIdentifier::from_syntax_node(db, self.children[1].clone())
+impl VisibilityPubArgumentIn {
+    pub fn in_kw(&self, db: &dyn SyntaxGroup) -> TerminalIdentifier {
+        TerminalIdentifier::from_syntax_node(db, self.children[0].clone())
     }
-    pub fn rparen(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
-        TerminalRParen::from_syntax_node(db, self.children[2].clone())
+    pub fn path(&self, db: &dyn SyntaxGroup) -> ExprPath {
+        ExprPath::from_syntax_node(db, self.children[1].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct VisibilityPubArgumentClausePtr(pub SyntaxStablePtrId);
-impl VisibilityPubArgumentClausePtr {
+pub struct VisibilityPubArgumentInPtr(pub SyntaxStablePtrId);
+impl VisibilityPubArgumentInPtr {
     pub fn untyped(&self) -> SyntaxStablePtrId {
         self.0
     }
-    pub fn lookup(&self, db: &dyn SyntaxGroup) -> VisibilityPubArgumentClause {
-        VisibilityPubArgumentClause::from_syntax_node(db, self.0.lookup(db))
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> VisibilityPubArgumentIn {
+        VisibilityPubArgumentIn::from_syntax_node(db, self.0.lookup(db))
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
-pub struct VisibilityPubArgumentClauseGreen(pub GreenId);
-impl TypedSyntaxNode for VisibilityPubArgumentClause {
-    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::VisibilityPubArgumentClause);
-    type StablePtr = VisibilityPubArgumentClausePtr;
-    type Green = VisibilityPubArgumentClauseGreen;
+pub struct VisibilityPubArgumentInGreen(pub GreenId);
+impl TypedSyntaxNode for VisibilityPubArgumentIn {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::VisibilityPubArgumentIn);
+    type StablePtr = VisibilityPubArgumentInPtr;
+    type Green = VisibilityPubArgumentInGreen;
     fn missing(db: &dyn SyntaxGroup) -> Self::Green {
-        VisibilityPubArgumentClauseGreen(db.intern_green(Arc::new(GreenNode {
-            kind: SyntaxKind::VisibilityPubArgumentClause,
+        VisibilityPubArgumentInGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::VisibilityPubArgumentIn,
             details: GreenNodeDetails::Node {
-                children: vec![
-                    TerminalLParen::missing(db).0,
-                    TerminalIdentifier::missing(db).0,
-                    TerminalRParen::missing(db).0,
-                ],
+                children: vec![TerminalIdentifier::missing(db).0, ExprPath::missing(db).0],
                 width: TextWidth::default(),
             },
         })))
@@ -9574,10 +10833,10 @@ impl TypedSyntaxNode for VisibilityPubArgumentClause {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::VisibilityPubArgumentClause,
+            SyntaxKind::VisibilityPubArgumentIn,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::VisibilityPubArgumentClause
+            SyntaxKind::VisibilityPubArgumentIn
         );
         let children = db.get_children(node.clone());
         Self { node, children }
@@ -9586,7 +10845,7 @@ impl TypedSyntaxNode for VisibilityPubArgumentClause {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        VisibilityPubArgumentClausePtr(self.node.0.stable_ptr)
+        VisibilityPubArgumentInPtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -12516,9 +13775,7 @@ impl ItemStruct {
     pub const INDEX_STRUCT_KW: usize = 2;
     pub const INDEX_NAME: usize = 3;
     pub const INDEX_GENERIC_PARAMS: usize = 4;
-    pub const INDEX_LBRACE: usize = 5;
-    pub const INDEX_MEMBERS: usize = 6;
-    pub const INDEX_RBRACE: usize = 7;
+    pub const INDEX_BODY: usize = 5;
     pub fn new_green(
         db: &dyn SyntaxGroup,
         attributes: AttributeListGreen,
@@ -12526,20 +13783,10 @@ impl ItemStruct {
         struct_kw: TerminalStructGreen,
         name: TerminalIdentifierGreen,
         generic_params: OptionWrappedGenericParamListGreen,
-        lbrace: TerminalLBraceGreen,
-        members: MemberListGreen,
-        rbrace: TerminalRBraceGreen,
+        body: StructBodyGreen,
     ) -> ItemStructGreen {
-        let children: Vec<GreenId> = vec![
-            attributes.0,
-            visibility.0,
-            struct_kw.0,
-            name.0,
-            generic_params.0,
-            lbrace.0,
-            members.0,
-            rbrace.0,
-        ];
+        let children: Vec<GreenId> =
+            vec![attributes.0, visibility.0, struct_kw.0, name.0, generic_params.0, body.0];
         let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
         ItemStructGreen(db.intern_green(Arc::new(GreenNode {
             kind: SyntaxKind::ItemStruct,
@@ -12563,14 +13810,8 @@ impl ItemStruct {
     pub fn generic_params(&self, db: &dyn SyntaxGroup) -> OptionWrappedGenericParamList {
         OptionWrappedGenericParamList::from_syntax_node(db, self.children[4].clone())
     }
-    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
-        TerminalLBrace::from_syntax_node(db, self.children[5].clone())
-    }
-    pub fn members(&self, db: &dyn SyntaxGroup) -> MemberList {
-        MemberList::from_syntax_node(db, self.children[6].clone())
-    }
-    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
-        TerminalRBrace::from_syntax_node(db, self.children[7].clone())
+    pub fn body(&self, db: &dyn SyntaxGroup) -> StructBody {
+        StructBody::from_syntax_node(db, self.children[5].clone())
     }
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -12607,6 +13848,178 @@ impl TypedSyntaxNode for ItemStruct {
                     TerminalStruct::missing(db).0,
                     TerminalIdentifier::missing(db).0,
                     OptionWrappedGenericParamList::missing(db).0,
+                    StructBody::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::ItemStruct,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::ItemStruct
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        ItemStructPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum StructBody {
+    Named(StructBodyNamed),
+    Tuple(StructBodyTuple),
+    Unit(TerminalSemicolon),
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructBodyPtr(pub SyntaxStablePtrId);
+impl StructBodyPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> StructBody {
+        StructBody::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+impl From<StructBodyNamedPtr> for StructBodyPtr {
+    fn from(value: StructBodyNamedPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<StructBodyTuplePtr> for StructBodyPtr {
+    fn from(value: StructBodyTuplePtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonPtr> for StructBodyPtr {
+    fn from(value: TerminalSemicolonPtr) -> Self {
+        Self(value.0)
+    }
+}
+impl From<StructBodyNamedGreen> for StructBodyGreen {
+    fn from(value: StructBodyNamedGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<StructBodyTupleGreen> for StructBodyGreen {
+    fn from(value: StructBodyTupleGreen) -> Self {
+        Self(value.0)
+    }
+}
+impl From<TerminalSemicolonGreen> for StructBodyGreen {
+    fn from(value: TerminalSemicolonGreen) -> Self {
+        Self(value.0)
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructBodyGreen(pub GreenId);
+impl TypedSyntaxNode for StructBody {
+    const OPTIONAL_KIND: Option<SyntaxKind> = None;
+    type StablePtr = StructBodyPtr;
+    type Green = StructBodyGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        panic!("No missing variant.");
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        match kind {
+            SyntaxKind::StructBodyNamed => {
+                StructBody::Named(StructBodyNamed::from_syntax_node(db, node))
+            }
+            SyntaxKind::StructBodyTuple => {
+                StructBody::Tuple(StructBodyTuple::from_syntax_node(db, node))
+            }
+            SyntaxKind::TerminalSemicolon => {
+                StructBody::Unit(TerminalSemicolon::from_syntax_node(db, node))
+            }
+            _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "StructBody"),
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        match self {
+            StructBody::Named(x) => x.as_syntax_node(),
+            StructBody::Tuple(x) => x.as_syntax_node(),
+            StructBody::Unit(x) => x.as_syntax_node(),
+        }
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StructBodyPtr(self.as_syntax_node().0.stable_ptr)
+    }
+}
+impl StructBody {
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_variant(kind: SyntaxKind) -> bool {
+        match kind {
+            SyntaxKind::StructBodyNamed => true,
+            SyntaxKind::StructBodyTuple => true,
+            SyntaxKind::TerminalSemicolon => true,
+            _ => false,
+        }
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StructBodyNamed {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl StructBodyNamed {
+    pub const INDEX_LBRACE: usize = 0;
+    pub const INDEX_MEMBERS: usize = 1;
+    pub const INDEX_RBRACE: usize = 2;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        lbrace: TerminalLBraceGreen,
+        members: MemberListGreen,
+        rbrace: TerminalRBraceGreen,
+    ) -> StructBodyNamedGreen {
+        let children: Vec<GreenId> = vec![lbrace.0, members.0, rbrace.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StructBodyNamedGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::StructBodyNamed,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+}
+impl StructBodyNamed {
+    pub fn lbrace(&self, db: &dyn SyntaxGroup) -> TerminalLBrace {
+        TerminalLBrace::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn members(&self, db: &dyn SyntaxGroup) -> MemberList {
+        MemberList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rbrace(&self, db: &dyn SyntaxGroup) -> TerminalRBrace {
+        TerminalRBrace::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructBodyNamedPtr(pub SyntaxStablePtrId);
+impl StructBodyNamedPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> StructBodyNamed {
+        StructBodyNamed::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructBodyNamedGreen(pub GreenId);
+impl TypedSyntaxNode for StructBodyNamed {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructBodyNamed);
+    type StablePtr = StructBodyNamedPtr;
+    type Green = StructBodyNamedGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StructBodyNamedGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::StructBodyNamed,
+            details: GreenNodeDetails::Node {
+                children: vec![
                     TerminalLBrace::missing(db).0,
                     MemberList::missing(db).0,
                     TerminalRBrace::missing(db).0,
@@ -12619,10 +14032,98 @@ impl TypedSyntaxNode for ItemStruct {
         let kind = node.kind(db);
         assert_eq!(
             kind,
-            SyntaxKind::ItemStruct,
+            SyntaxKind::StructBodyNamed,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::StructBodyNamed
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        StructBodyNamedPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct StructBodyTuple {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl StructBodyTuple {
+    pub const INDEX_LPAREN: usize = 0;
+    pub const INDEX_MEMBERS: usize = 1;
+    pub const INDEX_RPAREN: usize = 2;
+    pub const INDEX_SEMICOLON: usize = 3;
+    pub fn new_green(
+        db: &dyn SyntaxGroup,
+        lparen: TerminalLParenGreen,
+        members: TupleMemberListGreen,
+        rparen: TerminalRParenGreen,
+        semicolon: TerminalSemicolonGreen,
+    ) -> StructBodyTupleGreen {
+        let children: Vec<GreenId> = vec![lparen.0, members.0, rparen.0, semicolon.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        StructBodyTupleGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::StructBodyTuple,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+}
+impl StructBodyTuple {
+    pub fn lparen(&self, db: &dyn SyntaxGroup) -> TerminalLParen {
+        TerminalLParen::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn members(&self, db: &dyn SyntaxGroup) -> TupleMemberList {
+        TupleMemberList::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn rparen(&self, db: &dyn SyntaxGroup) -> TerminalRParen {
+        TerminalRParen::from_syntax_node(db, self.children[2].clone())
+    }
+    pub fn semicolon(&self, db: &dyn SyntaxGroup) -> TerminalSemicolon {
+        TerminalSemicolon::from_syntax_node(db, self.children[3].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructBodyTuplePtr(pub SyntaxStablePtrId);
+impl StructBodyTuplePtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> StructBodyTuple {
+        StructBodyTuple::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct StructBodyTupleGreen(pub GreenId);
+impl TypedSyntaxNode for StructBodyTuple {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::StructBodyTuple);
+    type StablePtr = StructBodyTuplePtr;
+    type Green = StructBodyTupleGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        StructBodyTupleGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::StructBodyTuple,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    TerminalLParen::missing(db).0,
+                    TupleMemberList::missing(db).0,
+                    TerminalRParen::missing(db).0,
+                    TerminalSemicolon::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::StructBodyTuple,
             "Unexpected SyntaxKind {:?}. Expected {:?}.",
             kind,
-            SyntaxKind::ItemStruct
+            SyntaxKind::StructBodyTuple
         );
         let children = db.get_children(node.clone());
         Self { node, children }
@@ -12631,7 +14132,7 @@ impl TypedSyntaxNode for ItemStruct {
         self.node.clone()
     }
     fn stable_ptr(&self) -> Self::StablePtr {
-        ItemStructPtr(self.node.0.stable_ptr)
+        StructBodyTuplePtr(self.node.0.stable_ptr)
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -13000,6 +14501,7 @@ pub enum UsePath {
     Leaf(UsePathLeaf),
     Single(UsePathSingle),
     Multi(UsePathMulti),
+    Star(UsePathStar),
 }
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct UsePathPtr(pub SyntaxStablePtrId);
@@ -13026,6 +14528,11 @@ impl From<UsePathMultiPtr> for UsePathPtr {
         Self(value.0)
     }
 }
+impl From<UsePathStarPtr> for UsePathPtr {
+    fn from(value: UsePathStarPtr) -> Self {
+        Self(value.0)
+    }
+}
 impl From<UsePathLeafGreen> for UsePathGreen {
     fn from(value: UsePathLeafGreen) -> Self {
         Self(value.0)
@@ -13041,6 +14548,11 @@ impl From<UsePathMultiGreen> for UsePathGreen {
         Self(value.0)
     }
 }
+impl From<UsePathStarGreen> for UsePathGreen {
+    fn from(value: UsePathStarGreen) -> Self {
+        Self(value.0)
+    }
+}
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct UsePathGreen(pub GreenId);
 impl TypedSyntaxNode for UsePath {
@@ -13056,6 +14568,7 @@ impl TypedSyntaxNode for UsePath {
             SyntaxKind::UsePathLeaf => UsePath::Leaf(UsePathLeaf::from_syntax_node(db, node)),
             SyntaxKind::UsePathSingle => UsePath::Single(UsePathSingle::from_syntax_node(db, node)),
             SyntaxKind::UsePathMulti => UsePath::Multi(UsePathMulti::from_syntax_node(db, node)),
+            SyntaxKind::UsePathStar => UsePath::Star(UsePathStar::from_syntax_node(db, node)),
             _ => panic!("Unexpected syntax kind {:?} when constructing {}.", kind, "UsePath"),
         }
     }
@@ -13064,6 +14577,7 @@ impl TypedSyntaxNode for UsePath {
             UsePath::Leaf(x) => x.as_syntax_node(),
             UsePath::Single(x) => x.as_syntax_node(),
             UsePath::Multi(x) => x.as_syntax_node(),
+            UsePath::Star(x) => x.as_syntax_node(),
         }
     }
     fn stable_ptr(&self) -> Self::StablePtr {
@@ -13077,6 +14591,7 @@ impl UsePath {
             SyntaxKind::UsePathLeaf => true,
             SyntaxKind::UsePathSingle => true,
             SyntaxKind::UsePathMulti => true,
+            SyntaxKind::UsePathStar => true,
             _ => false,
         }
     }
@@ -13335,6 +14850,71 @@ impl TypedSyntaxNode for UsePathMulti {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct UsePathStar {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl UsePathStar {
+    pub const INDEX_STAR: usize = 0;
+    pub fn new_green(db: &dyn SyntaxGroup, star: TerminalMulGreen) -> UsePathStarGreen {
+        let children: Vec<GreenId> = vec![star.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        UsePathStarGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::UsePathStar,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+}
+impl UsePathStar {
+    pub fn star(&self, db: &dyn SyntaxGroup) -> TerminalMul {
+        TerminalMul::from_syntax_node(db, self.children[0].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct UsePathStarPtr(pub SyntaxStablePtrId);
+impl UsePathStarPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> UsePathStar {
+        UsePathStar::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct UsePathStarGreen(pub GreenId);
+impl TypedSyntaxNode for UsePathStar {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::UsePathStar);
+    type StablePtr = UsePathStarPtr;
+    type Green = UsePathStarGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        UsePathStarGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::UsePathStar,
+            details: GreenNodeDetails::Node {
+                children: vec![TerminalMul::missing(db).0],
+                width: TextWidth::default(),
+            },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::UsePathStar,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::UsePathStar
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        UsePathStarPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct UsePathList(ElementList<UsePath, 2>);
 impl Deref for UsePathList {
     type Target = ElementList<UsePath, 2>;
@@ -17161,6 +18741,151 @@ impl TypedSyntaxNode for TerminalLoop {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenFor {
+    node: SyntaxNode,
+}
+impl Token for TokenFor {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenForGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::TokenFor,
+            details: GreenNodeDetails::Token(text),
+        })))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(
+            &db.lookup_intern_green(self.node.0.green).details,
+            GreenNodeDetails::Token
+        )
+        .clone()
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenForPtr(pub SyntaxStablePtrId);
+impl TokenForPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> TokenFor {
+        TokenFor::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenForGreen(pub GreenId);
+impl TokenForGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(&db.lookup_intern_green(self.0).details, GreenNodeDetails::Token).clone()
+    }
+}
+impl TypedSyntaxNode for TokenFor {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenFor);
+    type StablePtr = TokenForPtr;
+    type Green = TokenForGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenForGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenFor)
+            }
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenForPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalFor {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl Terminal for TerminalFor {
+    const KIND: SyntaxKind = SyntaxKind::TerminalFor;
+    type TokenType = TokenFor;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalFor as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalForGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::TerminalFor,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalFor {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenFor {
+        TokenFor::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalForPtr(pub SyntaxStablePtrId);
+impl TerminalForPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> TerminalFor {
+        TerminalFor::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalForGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalFor {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalFor);
+    type StablePtr = TerminalForPtr;
+    type Green = TerminalForGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalForGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::TerminalFor,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenFor::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalFor,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalFor
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TerminalForPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct TokenImpl {
     node: SyntaxNode,
 }
@@ -21512,6 +23237,151 @@ impl TypedSyntaxNode for TerminalDotDot {
     }
 }
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TokenDotDotEq {
+    node: SyntaxNode,
+}
+impl Token for TokenDotDotEq {
+    fn new_green(db: &dyn SyntaxGroup, text: SmolStr) -> Self::Green {
+        TokenDotDotEqGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::TokenDotDotEq,
+            details: GreenNodeDetails::Token(text),
+        })))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(
+            &db.lookup_intern_green(self.node.0.green).details,
+            GreenNodeDetails::Token
+        )
+        .clone()
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenDotDotEqPtr(pub SyntaxStablePtrId);
+impl TokenDotDotEqPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> TokenDotDotEq {
+        TokenDotDotEq::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TokenDotDotEqGreen(pub GreenId);
+impl TokenDotDotEqGreen {
+    pub fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        extract_matches!(&db.lookup_intern_green(self.0).details, GreenNodeDetails::Token).clone()
+    }
+}
+impl TypedSyntaxNode for TokenDotDotEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TokenDotDotEq);
+    type StablePtr = TokenDotDotEqPtr;
+    type Green = TokenDotDotEqGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TokenDotDotEqGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::TokenMissing,
+            details: GreenNodeDetails::Token("".into()),
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        match db.lookup_intern_green(node.0.green).details {
+            GreenNodeDetails::Token(_) => Self { node },
+            GreenNodeDetails::Node { .. } => {
+                panic!("Expected a token {:?}, not an internal node", SyntaxKind::TokenDotDotEq)
+            }
+        }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TokenDotDotEqPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TerminalDotDotEq {
+    node: SyntaxNode,
+    children: Arc<Vec<SyntaxNode>>,
+}
+impl Terminal for TerminalDotDotEq {
+    const KIND: SyntaxKind = SyntaxKind::TerminalDotDotEq;
+    type TokenType = TokenDotDotEq;
+    fn new_green(
+        db: &dyn SyntaxGroup,
+        leading_trivia: TriviaGreen,
+        token: <<TerminalDotDotEq as Terminal>::TokenType as TypedSyntaxNode>::Green,
+        trailing_trivia: TriviaGreen,
+    ) -> Self::Green {
+        let children: Vec<GreenId> = vec![leading_trivia.0, token.0, trailing_trivia.0];
+        let width = children.iter().copied().map(|id| db.lookup_intern_green(id).width()).sum();
+        TerminalDotDotEqGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::TerminalDotDotEq,
+            details: GreenNodeDetails::Node { children, width },
+        })))
+    }
+    fn text(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        self.token(db).text(db)
+    }
+}
+impl TerminalDotDotEq {
+    pub fn leading_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[0].clone())
+    }
+    pub fn token(&self, db: &dyn SyntaxGroup) -> TokenDotDotEq {
+        TokenDotDotEq::from_syntax_node(db, self.children[1].clone())
+    }
+    pub fn trailing_trivia(&self, db: &dyn SyntaxGroup) -> Trivia {
+        Trivia::from_syntax_node(db, self.children[2].clone())
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalDotDotEqPtr(pub SyntaxStablePtrId);
+impl TerminalDotDotEqPtr {
+    pub fn untyped(&self) -> SyntaxStablePtrId {
+        self.0
+    }
+    pub fn lookup(&self, db: &dyn SyntaxGroup) -> TerminalDotDotEq {
+        TerminalDotDotEq::from_syntax_node(db, self.0.lookup(db))
+    }
+}
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TerminalDotDotEqGreen(pub GreenId);
+impl TypedSyntaxNode for TerminalDotDotEq {
+    const OPTIONAL_KIND: Option<SyntaxKind> = Some(SyntaxKind::TerminalDotDotEq);
+    type StablePtr = TerminalDotDotEqPtr;
+    type Green = TerminalDotDotEqGreen;
+    fn missing(db: &dyn SyntaxGroup) -> Self::Green {
+        TerminalDotDotEqGreen(db.intern_green(Arc::new(GreenNode {
+            kind: SyntaxKind::TerminalDotDotEq,
+            details: GreenNodeDetails::Node {
+                children: vec![
+                    Trivia::missing(db).0,
+                    TokenDotDotEq::missing(db).0,
+                    Trivia::missing(db).0,
+                ],
+                width: TextWidth::default(),
+            },
+        })))
+    }
+    fn from_syntax_node(db: &dyn SyntaxGroup, node: SyntaxNode) -> Self {
+        let kind = node.kind(db);
+        assert_eq!(
+            kind,
+            SyntaxKind::TerminalDotDotEq,
+            "Unexpected SyntaxKind {:?}. Expected {:?}.",
+            kind,
+            SyntaxKind::TerminalDotDotEq
+        );
+        let children = db.get_children(node.clone());
+        Self { node, children }
+    }
+    fn as_syntax_node(&self) -> SyntaxNode {
+        self.node.clone()
+    }
+    fn stable_ptr(&self) -> Self::StablePtr {
+        TerminalDotDotEqPtr(self.node.0.stable_ptr)
+    }
+}
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct TokenEndOfFile {
     node: SyntaxNode,
 }
@@ -363,6 +363,18 @@ impl QueryAttrs for Member {
     }
 }
 
+impl QueryAttrs for ast::TupleMember {
+    fn attributes_elements(&self, db: &dyn SyntaxGroup) -> Vec<Attribute> {
+        self.attributes(db).elements(db)
+    }
+}
+
+impl QueryAttrs for ast::StructMember {
+    fn attributes_elements(&self, db: &dyn SyntaxGroup) -> Vec<Attribute> {
+        self.attributes(db).elements(db)
+    }
+}
+
 impl QueryAttrs for Variant {
     fn attributes_elements(&self, db: &dyn SyntaxGroup) -> Vec<Attribute> {
         self.attributes(db).elements(db)
@@ -452,6 +464,9 @@ impl QueryAttrs for SyntaxNode {
             SyntaxKind::Member => {
                 ast::Member::from_syntax_node(db, self.clone()).attributes_elements(db)
             }
+            SyntaxKind::TupleMember => {
+                ast::TupleMember::from_syntax_node(db, self.clone()).attributes_elements(db)
+            }
             SyntaxKind::Variant => {
                 ast::Variant::from_syntax_node(db, self.clone()).attributes_elements(db)
             }
@@ -595,3 +610,65 @@ impl BodyItems for ast::ImplBody {
         self.items(db).elements(db)
     }
 }
+
+impl ast::ItemStruct {
+    /// Returns the members of the struct, in their order of declaration. Unit structs have no
+    /// members.
+    pub fn members(&self, db: &dyn SyntaxGroup) -> Vec<ast::StructMember> {
+        match self.body(db) {
+            ast::StructBody::Named(body) => {
+                body.members(db).elements(db).into_iter().map(ast::StructMember::Named).collect()
+            }
+            ast::StructBody::Tuple(body) => {
+                body.members(db).elements(db).into_iter().map(ast::StructMember::Tuple).collect()
+            }
+            ast::StructBody::Unit(_) => vec![],
+        }
+    }
+}
+
+impl ast::StructMember {
+    /// Returns the name of the member - its identifier, or its position for tuple struct members.
+    pub fn name(&self, db: &dyn SyntaxGroup) -> SmolStr {
+        match self {
+            ast::StructMember::Named(member) => member.name(db).text(db),
+            ast::StructMember::Tuple(member) => member.position(db).to_string().into(),
+        }
+    }
+
+    /// Returns the attributes of the member.
+    pub fn attributes(&self, db: &dyn SyntaxGroup) -> AttributeList {
+        match self {
+            ast::StructMember::Named(member) => member.attributes(db),
+            ast::StructMember::Tuple(member) => member.attributes(db),
+        }
+    }
+
+    /// Returns the visibility of the member.
+    pub fn visibility(&self, db: &dyn SyntaxGroup) -> ast::Visibility {
+        match self {
+            ast::StructMember::Named(member) => member.visibility(db),
+            ast::StructMember::Tuple(member) => member.visibility(db),
+        }
+    }
+
+    /// Returns the type expression of the member.
+    pub fn type_expr(&self, db: &dyn SyntaxGroup) -> ast::Expr {
+        match self {
+            ast::StructMember::Named(member) => member.type_clause(db).ty(db),
+            ast::StructMember::Tuple(member) => member.ty(db),
+        }
+    }
+}
+
+impl ast::TupleMember {
+    /// Returns the position of the member in its tuple struct.
+    pub fn position(&self, db: &dyn SyntaxGroup) -> usize {
+        let member_list = self.as_syntax_node().parent().expect("A tuple member has a parent.");
+        ast::TupleMemberList::from_syntax_node(db, member_list)
+            .elements(db)
+            .iter()
+            .position(|member| member.stable_ptr() == self.stable_ptr())
+            .expect("A tuple member is an element of its parent list.")
+    }
+}
@@ -89,6 +89,9 @@ pub fn get_key_fields(kind: SyntaxKind, children: &[GreenId]) -> Vec<GreenId> {
         SyntaxKind::ExprWhile => {
             vec![]
         }
+        SyntaxKind::ExprFor => {
+            vec![]
+        }
         SyntaxKind::ElseClause => {
             vec![]
         }
@@ -104,6 +107,16 @@ pub fn get_key_fields(kind: SyntaxKind, children: &[GreenId]) -> Vec<GreenId> {
         SyntaxKind::ExprInlineMacro => {
             vec![]
         }
+        SyntaxKind::ExprClosure => {
+            vec![]
+        }
+        SyntaxKind::ClosureParamWrapperNAry => {
+            vec![]
+        }
+        SyntaxKind::ClosureParamList => vec![],
+        SyntaxKind::ClosureParam => {
+            vec![]
+        }
         SyntaxKind::StructArgExpr => {
             vec![]
         }
@@ -133,6 +146,9 @@ pub fn get_key_fields(kind: SyntaxKind, children: &[GreenId]) -> Vec<GreenId> {
             vec![]
         }
         SyntaxKind::PatternStructParamList => vec![],
+        SyntaxKind::PatternTupleStruct => {
+            vec![]
+        }
         SyntaxKind::PatternTuple => {
             vec![]
         }
@@ -169,6 +185,12 @@ pub fn get_key_fields(kind: SyntaxKind, children: &[GreenId]) -> Vec<GreenId> {
         SyntaxKind::StatementLet => {
             vec![/* pattern */ children[2]]
         }
+        SyntaxKind::LetElseClause => {
+            vec![]
+        }
+        SyntaxKind::OptionLetElseClauseEmpty => {
+            vec![]
+        }
         SyntaxKind::OptionTerminalSemicolonEmpty => {
             vec![]
         }
@@ -212,6 +234,10 @@ pub fn get_key_fields(kind: SyntaxKind, children: &[GreenId]) -> Vec<GreenId> {
             vec![/* name */ children[2]]
         }
         SyntaxKind::MemberList => vec![],
+        SyntaxKind::TupleMember => {
+            vec![]
+        }
+        SyntaxKind::TupleMemberList => vec![],
         SyntaxKind::Variant => {
             vec![/* name */ children[1]]
         }
@@ -230,6 +256,9 @@ pub fn get_key_fields(kind: SyntaxKind, children: &[GreenId]) -> Vec<GreenId> {
         SyntaxKind::VisibilityPubArgumentClause => {
             vec![]
         }
+        SyntaxKind::VisibilityPubArgumentIn => {
+            vec![]
+        }
         SyntaxKind::OptionVisibilityPubArgumentClauseEmpty => {
             vec![]
         }
@@ -298,6 +327,12 @@ pub fn get_key_fields(kind: SyntaxKind, children: &[GreenId]) -> Vec<GreenId> {
         SyntaxKind::ItemStruct => {
             vec![/* name */ children[3]]
         }
+        SyntaxKind::StructBodyNamed => {
+            vec![]
+        }
+        SyntaxKind::StructBodyTuple => {
+            vec![]
+        }
         SyntaxKind::ItemEnum => {
             vec![/* name */ children[3]]
         }
@@ -316,6 +351,9 @@ pub fn get_key_fields(kind: SyntaxKind, children: &[GreenId]) -> Vec<GreenId> {
         SyntaxKind::UsePathMulti => {
             vec![]
         }
+        SyntaxKind::UsePathStar => {
+            vec![]
+        }
         SyntaxKind::UsePathList => vec![],
         SyntaxKind::AliasClause => {
             vec![/* alias */ children[1]]
@@ -417,6 +455,10 @@ pub fn get_key_fields(kind: SyntaxKind, children: &[GreenId]) -> Vec<GreenId> {
         SyntaxKind::TerminalLoop => {
             vec![]
         }
+        SyntaxKind::TokenFor => vec![],
+        SyntaxKind::TerminalFor => {
+            vec![]
+        }
         SyntaxKind::TokenImpl => vec![],
         SyntaxKind::TerminalImpl => {
             vec![]
@@ -537,6 +579,10 @@ pub fn get_key_fields(kind: SyntaxKind, children: &[GreenId]) -> Vec<GreenId> {
         SyntaxKind::TerminalDotDot => {
             vec![]
         }
+        SyntaxKind::TokenDotDotEq => vec![],
+        SyntaxKind::TerminalDotDotEq => {
+            vec![]
+        }
         SyntaxKind::TokenEndOfFile => vec![],
         SyntaxKind::TerminalEndOfFile => {
             vec![]
@@ -33,11 +33,16 @@ pub enum SyntaxKind {
     ConditionExpr,
     ExprLoop,
     ExprWhile,
+    ExprFor,
     ElseClause,
     OptionElseClauseEmpty,
     ExprErrorPropagate,
     ExprIndexed,
     ExprInlineMacro,
+    ExprClosure,
+    ClosureParamWrapperNAry,
+    ClosureParamList,
+    ClosureParam,
     StructArgExpr,
     OptionStructArgExprEmpty,
     StructArgSingle,
@@ -49,6 +54,7 @@ pub enum SyntaxKind {
     PatternIdentifier,
     PatternStruct,
     PatternStructParamList,
+    PatternTupleStruct,
     PatternTuple,
     PatternList,
     PatternListOr,
@@ -63,6 +69,8 @@ pub enum SyntaxKind {
     StatementList,
     StatementMissing,
     StatementLet,
+    LetElseClause,
+    OptionLetElseClauseEmpty,
     OptionTerminalSemicolonEmpty,
     StatementExpr,
     StatementContinue,
@@ -80,6 +88,8 @@ pub enum SyntaxKind {
     FunctionSignature,
     Member,
     MemberList,
+    TupleMember,
+    TupleMemberList,
     Variant,
     VariantList,
     ModuleItemList,
@@ -88,6 +98,7 @@ pub enum SyntaxKind {
     AttributeList,
     VisibilityDefault,
     VisibilityPubArgumentClause,
+    VisibilityPubArgumentIn,
     OptionVisibilityPubArgumentClauseEmpty,
     VisibilityPub,
     ItemModule,
@@ -112,12 +123,15 @@ pub enum SyntaxKind {
     ImplItemMissing,
     ItemImplAlias,
     ItemStruct,
+    StructBodyNamed,
+    StructBodyTuple,
     ItemEnum,
     ItemTypeAlias,
     ItemUse,
     UsePathLeaf,
     UsePathSingle,
     UsePathMulti,
+    UsePathStar,
     UsePathList,
     AliasClause,
     OptionAliasClauseEmpty,
@@ -163,6 +177,8 @@ pub enum SyntaxKind {
     TerminalWhile,
     TokenLoop,
     TerminalLoop,
+    TokenFor,
+    TerminalFor,
     TokenImpl,
     TerminalImpl,
     TokenImplicits,
@@ -223,6 +239,8 @@ pub enum SyntaxKind {
     TerminalDot,
     TokenDotDot,
     TerminalDotDot,
+    TokenDotDotEq,
+    TerminalDotDotEq,
     TokenEndOfFile,
     TerminalEndOfFile,
     TokenEq,
@@ -312,6 +330,7 @@ impl SyntaxKind {
                 | SyntaxKind::TokenIf
                 | SyntaxKind::TokenWhile
                 | SyntaxKind::TokenLoop
+                | SyntaxKind::TokenFor
                 | SyntaxKind::TokenImpl
                 | SyntaxKind::TokenImplicits
                 | SyntaxKind::TokenLet
@@ -342,6 +361,7 @@ impl SyntaxKind {
                 | SyntaxKind::TokenDivEq
                 | SyntaxKind::TokenDot
                 | SyntaxKind::TokenDotDot
+                | SyntaxKind::TokenDotDotEq
                 | SyntaxKind::TokenEndOfFile
                 | SyntaxKind::TokenEq
                 | SyntaxKind::TokenEqEq
@@ -398,6 +418,7 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalIf
                 | SyntaxKind::TerminalWhile
                 | SyntaxKind::TerminalLoop
+                | SyntaxKind::TerminalFor
                 | SyntaxKind::TerminalImpl
                 | SyntaxKind::TerminalImplicits
                 | SyntaxKind::TerminalLet
@@ -428,6 +449,7 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalDivEq
                 | SyntaxKind::TerminalDot
                 | SyntaxKind::TerminalDotDot
+                | SyntaxKind::TerminalDotDotEq
                 | SyntaxKind::TerminalEndOfFile
                 | SyntaxKind::TerminalEq
                 | SyntaxKind::TerminalEqEq
@@ -475,6 +497,7 @@ impl SyntaxKind {
                 | SyntaxKind::TokenIf
                 | SyntaxKind::TokenWhile
                 | SyntaxKind::TokenLoop
+                | SyntaxKind::TokenFor
                 | SyntaxKind::TokenImpl
                 | SyntaxKind::TokenImplicits
                 | SyntaxKind::TokenLet
@@ -508,6 +531,7 @@ impl SyntaxKind {
                 | SyntaxKind::TerminalIf
                 | SyntaxKind::TerminalWhile
                 | SyntaxKind::TerminalLoop
+                | SyntaxKind::TerminalFor
                 | SyntaxKind::TerminalImpl
                 | SyntaxKind::TerminalImplicits
                 | SyntaxKind::TerminalLet
@@ -1,7 +1,9 @@
 use cairo_lang_defs::patcher::{PatchBuilder, RewriteNode};
 use cairo_lang_defs::plugin::{
-    InlineMacroExprPlugin, InlinePluginResult, NamedPlugin, PluginDiagnostic, PluginGeneratedFile,
+    InlineMacroExprPlugin, InlineMacroSignature, InlinePluginResult, NamedPlugin, PluginDiagnostic,
+    PluginGeneratedFile,
 };
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_semantic::inline_macros::{
     escape_node, try_extract_unnamed_arg, unsupported_bracket_diagnostic,
 };
@@ -26,10 +28,13 @@ trait CompareAssertionPlugin: NamedPlugin {
         if arguments.len() < 2 {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    arguments_syntax.lparen(db).stable_ptr().untyped(),
-                    format!("Macro `{}` requires at least 2 arguments.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        arguments_syntax.lparen(db).stable_ptr().untyped(),
+                        format!("Macro `{}` requires at least 2 arguments.", Self::NAME),
+                    )
+                    .with_error_code(error_code!("E0600")),
+                ],
             };
         }
         let (lhs, rest) = arguments.split_first().unwrap();
@@ -37,19 +42,31 @@ trait CompareAssertionPlugin: NamedPlugin {
         let Some(lhs) = try_extract_unnamed_arg(db, lhs) else {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    lhs.stable_ptr().untyped(),
-                    format!("Macro `{}` requires the first argument to be unnamed.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        lhs.stable_ptr().untyped(),
+                        format!(
+                            "Macro `{}` requires the first argument to be unnamed.",
+                            Self::NAME
+                        ),
+                    )
+                    .with_error_code(error_code!("E0601")),
+                ],
             };
         };
         let Some(rhs) = try_extract_unnamed_arg(db, rhs) else {
             return InlinePluginResult {
                 code: None,
-                diagnostics: vec![PluginDiagnostic::error(
-                    rhs.stable_ptr().untyped(),
-                    format!("Macro `{}` requires the second argument to be unnamed.", Self::NAME),
-                )],
+                diagnostics: vec![
+                    PluginDiagnostic::error(
+                        rhs.stable_ptr().untyped(),
+                        format!(
+                            "Macro `{}` requires the second argument to be unnamed.",
+                            Self::NAME
+                        ),
+                    )
+                    .with_error_code(error_code!("E0602")),
+                ],
             };
         };
         let f = format!("__formatter_for_{}_macro_", Self::NAME);
@@ -190,6 +207,13 @@ macro_rules! define_compare_assert_macro {
             ) -> InlinePluginResult {
                 CompareAssertionPlugin::generate_code(self, db, syntax)
             }
+
+            fn signature(&self) -> Option<InlineMacroSignature> {
+                Some(InlineMacroSignature {
+                    params: vec!["lhs", "rhs", "fmt", "args"],
+                    variadic: true,
+                })
+            }
         }
     };
 }
@@ -49,6 +49,8 @@ const STATIC_GAS_ARG: &str = "static";
 /// * `main_crate_ids` - [`CrateId`]s to compile. Use `db.intern_crate(CrateLongId::Real(name))` in
 ///   order to obtain [`CrateId`] from its name.
 /// * `test_crate_ids` - [`CrateId`]s to find tests cases in. Must be a subset of `main_crate_ids`.
+/// * `class_hashes` - The class hashes of the contracts as compiled for deployment, by the full
+///   path of their modules. See [`cairo_lang_starknet::compile::class_hashes_path`].
 /// # Returns
 /// * `Ok(TestCompilation)` - The compiled test cases with metadata.
 /// * `Err(anyhow::Error)` - Compilation failed.
@@ -57,6 +59,7 @@ pub fn compile_test_prepared_db(
     starknet: bool,
     main_crate_ids: Vec<CrateId>,
     test_crate_ids: Vec<CrateId>,
+    class_hashes: &OrderedHashMap<String, Felt252>,
 ) -> Result<TestCompilation> {
     let all_entry_points = if starknet {
         find_contracts(db, &main_crate_ids)
@@ -119,7 +122,7 @@ pub fn compile_test_prepared_db(
             )
         })
         .collect_vec();
-    let contracts_info = get_contracts_info(db, main_crate_ids.clone(), &replacer)?;
+    let contracts_info = get_contracts_info(db, main_crate_ids.clone(), &replacer, class_hashes)?;
 
     Ok(TestCompilation {
         named_tests,
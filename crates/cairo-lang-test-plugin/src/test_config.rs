@@ -1,5 +1,6 @@
 use cairo_felt::{felt_str, Felt252};
 use cairo_lang_defs::plugin::PluginDiagnostic;
+use cairo_lang_diagnostics::error_code;
 use cairo_lang_syntax::attribute::structured::{Attribute, AttributeArg, AttributeArgVariant};
 use cairo_lang_syntax::node::db::SyntaxGroup;
 use cairo_lang_syntax::node::{ast, TypedSyntaxNode};
@@ -54,25 +55,34 @@ pub fn try_extract_test_config(
     let mut diagnostics = vec![];
     if let Some(attr) = test_attr {
         if !attr.args.is_empty() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.id_stable_ptr.untyped(),
-                "Attribute should not have arguments.".into(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.id_stable_ptr.untyped(),
+                    "Attribute should not have arguments.".into(),
+                )
+                .with_error_code(error_code!("E0603")),
+            );
         }
     } else {
         for attr in [ignore_attr, available_gas_attr, should_panic_attr].into_iter().flatten() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.id_stable_ptr.untyped(),
-                "Attribute should only appear on tests.".into(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.id_stable_ptr.untyped(),
+                    "Attribute should only appear on tests.".into(),
+                )
+                .with_error_code(error_code!("E0604")),
+            );
         }
     }
     let ignored = if let Some(attr) = ignore_attr {
         if !attr.args.is_empty() {
-            diagnostics.push(PluginDiagnostic::error(
-                attr.id_stable_ptr.untyped(),
-                "Attribute should not have arguments.".into(),
-            ));
+            diagnostics.push(
+                PluginDiagnostic::error(
+                    attr.id_stable_ptr.untyped(),
+                    "Attribute should not have arguments.".into(),
+                )
+                .with_error_code(error_code!("E0603")),
+            );
         }
         true
     } else {
@@ -86,12 +96,16 @@ pub fn try_extract_test_config(
             (
                 true,
                 extract_panic_bytes(db, attr).on_none(|| {
-                    diagnostics.push(PluginDiagnostic::error(
-                        attr.args_stable_ptr.untyped(),
-                        "Expected panic must be of the form `expected: <tuple of felt252s and \
-                         strings>` or `expected: \"some string\"` or `expected: <some felt252>`."
-                            .into(),
-                    ));
+                    diagnostics.push(
+                        PluginDiagnostic::error(
+                            attr.args_stable_ptr.untyped(),
+                            "Expected panic must be of the form `expected: <tuple of felt252s and \
+                             strings>` or `expected: \"some string\"` or `expected: <some \
+                             felt252>`."
+                                .into(),
+                        )
+                        .with_error_code(error_code!("E0605")),
+                    );
                 }),
             )
         }
@@ -148,13 +162,16 @@ fn extract_available_gas(
         _ => None,
     }
     .on_none(|| {
-        diagnostics.push(PluginDiagnostic::error(
-            attr.args_stable_ptr.untyped(),
-            format!(
-                "Attribute should have a single non-negative literal in `i64` range or \
-                 `{STATIC_GAS_ARG}`."
-            ),
-        ))
+        diagnostics.push(
+            PluginDiagnostic::error(
+                attr.args_stable_ptr.untyped(),
+                format!(
+                    "Attribute should have a single non-negative literal in `i64` range or \
+                     `{STATIC_GAS_ARG}`."
+                ),
+            )
+            .with_error_code(error_code!("E0606")),
+        )
     })
 }
 
@@ -6,8 +6,10 @@ use anyhow::{bail, Context, Result};
 use cairo_felt::Felt252;
 use cairo_lang_compiler::db::RootDatabase;
 use cairo_lang_compiler::diagnostics::DiagnosticsReporter;
-use cairo_lang_compiler::project::setup_project;
+use cairo_lang_compiler::diagnostics_format::DiagnosticsFormat;
+use cairo_lang_compiler::project::{setup_project, update_crate_lint_levels};
 use cairo_lang_filesystem::cfg::{Cfg, CfgSet};
+use cairo_lang_filesystem::db::LintLevel;
 use cairo_lang_filesystem::ids::CrateId;
 use cairo_lang_runner::casm_run::format_next_item;
 use cairo_lang_runner::profiling::{
@@ -19,6 +21,7 @@ use cairo_lang_sierra::ids::FunctionId;
 use cairo_lang_sierra::program::{Program, StatementIdx};
 use cairo_lang_sierra_generator::db::SierraGenGroup;
 use cairo_lang_sierra_to_casm::metadata::MetadataComputationConfig;
+use cairo_lang_starknet::compile::class_hashes_path;
 use cairo_lang_starknet::contract::ContractInfo;
 use cairo_lang_starknet::starknet_plugin_suite;
 use cairo_lang_test_plugin::test_config::{PanicExpectation, TestExpectation};
@@ -52,13 +55,19 @@ impl TestRunner {
     /// * `include_ignored` - Include ignored tests as well
     /// * `ignored` - Run ignored tests only
     /// * `starknet` - Add the starknet plugin to run the tests
+    /// * `allow_warnings` - Allow the compilation to succeed with warnings
+    /// * `diagnostics_format` - The format in which compilation diagnostics are reported
+    /// * `lint_levels` - Lint levels to set for the compiled crates
     pub fn new(
         path: &Path,
         starknet: bool,
         allow_warnings: bool,
+        diagnostics_format: DiagnosticsFormat,
+        lint_levels: &[(String, LintLevel)],
         config: TestRunConfig,
     ) -> Result<Self> {
-        let compiler = TestCompiler::try_new(path, starknet, allow_warnings)?;
+        let compiler =
+            TestCompiler::try_new(path, starknet, allow_warnings, diagnostics_format, lint_levels)?;
         Ok(Self { compiler, config })
     }
 
@@ -177,6 +186,9 @@ pub struct TestCompiler {
     pub main_crate_ids: Vec<CrateId>,
     pub test_crate_ids: Vec<CrateId>,
     pub starknet: bool,
+    /// The class hashes of the contracts as compiled for deployment, by the full path of their
+    /// modules.
+    pub class_hashes: OrderedHashMap<String, Felt252>,
 }
 
 impl TestCompiler {
@@ -186,7 +198,16 @@ impl TestCompiler {
     ///
     /// * `path` - The path to compile and run its tests
     /// * `starknet` - Add the starknet plugin to run the tests
-    pub fn try_new(path: &Path, starknet: bool, allow_warnings: bool) -> Result<Self> {
+    /// * `allow_warnings` - Allow the compilation to succeed with warnings
+    /// * `diagnostics_format` - The format in which compilation diagnostics are reported
+    /// * `lint_levels` - Lint levels to set for the compiled crates
+    pub fn try_new(
+        path: &Path,
+        starknet: bool,
+        allow_warnings: bool,
+        diagnostics_format: DiagnosticsFormat,
+        lint_levels: &[(String, LintLevel)],
+    ) -> Result<Self> {
         let db = &mut {
             let mut b = RootDatabase::builder();
             b.detect_corelib();
@@ -200,19 +221,26 @@ impl TestCompiler {
         };
 
         let main_crate_ids = setup_project(db, Path::new(&path))?;
-        let mut reporter = DiagnosticsReporter::stderr().with_crates(&main_crate_ids);
+        update_crate_lint_levels(db, &main_crate_ids, lint_levels)?;
+        let mut reporter = DiagnosticsReporter::stderr_with_format(diagnostics_format)
+            .with_crates(&main_crate_ids);
         if allow_warnings {
             reporter = reporter.allow_warnings();
         }
         if reporter.check(db) {
             bail!("failed to compile: {}", path.display());
         }
+        // The test database is built with the `test` cfg, so the contracts are compiled again as
+        // deployed to get their actual class hashes.
+        let class_hashes =
+            if starknet { class_hashes_path(path)? } else { OrderedHashMap::default() };
 
         Ok(Self {
             db: db.snapshot(),
             test_crate_ids: main_crate_ids.clone(),
             main_crate_ids,
             starknet,
+            class_hashes,
         })
     }
 
@@ -223,6 +251,7 @@ impl TestCompiler {
             self.starknet,
             self.main_crate_ids.clone(),
             self.test_crate_ids.clone(),
+            &self.class_hashes,
         )
     }
 }
@@ -1,4 +1,6 @@
 use cairo_felt::{felt_str, Felt252};
+use cairo_lang_compiler::diagnostics_format::DiagnosticsFormat;
+use cairo_lang_starknet::compile::starknet_compile_contract_class;
 use cairo_lang_utils::byte_array::BYTE_ARRAY_MAGIC;
 use itertools::Itertools;
 
@@ -9,7 +11,8 @@ fn test_compiled_serialization() {
     use std::path::PathBuf;
     let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data");
 
-    let compiler = TestCompiler::try_new(&path, true, false).unwrap();
+    let compiler =
+        TestCompiler::try_new(&path, true, false, DiagnosticsFormat::Human, &[]).unwrap();
     let compiled = compiler.build().unwrap();
     let serialized = serde_json::to_string_pretty(&compiled).unwrap();
     let deserialized: TestCompilation = serde_json::from_str(&serialized).unwrap();
@@ -23,6 +26,20 @@ fn test_compiled_serialization() {
     );
 }
 
+#[test]
+fn test_class_hash() {
+    use std::path::PathBuf;
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data");
+
+    let compiler =
+        TestCompiler::try_new(&path, true, false, DiagnosticsFormat::Human, &[]).unwrap();
+    let compiled = compiler.build().unwrap();
+    let class_hashes = compiled.contracts_info.values().map(|info| info.class_hash.clone());
+    // The class hash `starknet-compile --class-hash` prints.
+    let contract_class = starknet_compile_contract_class(path, None, None, None).unwrap();
+    assert_eq!(class_hashes.collect_vec(), vec![Some(contract_class.class_hash())]);
+}
+
 #[test]
 fn test_format_for_panic() {
     // Valid short string.
@@ -27,6 +27,13 @@ mod Balance {
             self.value.write(self.value.read() + a);
         }
     }
+
+    // Only exists in tests, so it is not part of the deployed class.
+    #[cfg(test)]
+    #[external(v0)]
+    fn reset(ref self: ContractState) {
+        self.value.write(0);
+    }
 }
 
 #[cfg(test)]